@@ -1,4 +1,52 @@
-from typing import Optional, List
+from typing import Optional, List, Tuple
+
+class SearchOptions(object):
+
+    def __init__(self):
+
+        """
+        Builder of the search options, by default: mixed UTF-16 planes, non-overlapping, all matches, no limit
+
+        Each method returns a new SearchOptions object, the calls can be chained: SearchOptions().overlapping(True).limit(1024)
+        """
+
+        pass
+
+    def planes(self, planes: str) -> SearchOptions:
+
+        """
+        :param planes: Label of the accepted UTF-16 planes (bmp, supplementary, mixed), used only by the UTF-16 search, ValueError is raised for an unknown label
+        :return: New search options
+        """
+
+        pass
+
+    def overlapping(self, overlapping: bool) -> SearchOptions:
+
+        """
+        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
+        :return: New search options
+        """
+
+        pass
+
+    def all_matches(self, all_matches: bool) -> SearchOptions:
+
+        """
+        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
+        :return: New search options
+        """
+
+        pass
+
+    def limit(self, limit: Optional[int]) -> SearchOptions:
+
+        """
+        :param limit: Limit of the maximum length of the array sequence for search (in bytes), None - without limit
+        :return: New search options
+        """
+
+        pass
 
 class ASCII(object):
 
@@ -21,6 +69,18 @@ class ASCII(object):
 
         pass
 
+    @staticmethod
+    def validate_ascii(array: bytes) -> Optional[Tuple[int, str]]:
+
+        """
+        The function checks the source byte sequence for coherence with the ASCII encoding and reports the first violation
+
+        :param array: Encoded byte/s sequence
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first invalid sequence
+        """
+
+        pass
+
     @staticmethod
     def search_pattern(array: bytes, pattern: bytes, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
 
@@ -39,7 +99,41 @@ class ASCII(object):
 
         pass
 
-class UTF8(object):
+    @staticmethod
+    def search_pattern_ignore_ascii_case(array: bytes, pattern: bytes, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+
+        """
+        Pattern search function in the source byte array, letters A-Z and a-z are compared without regard to case
+
+        Encoding coherence is not checked, before passing arguments, you should make sure that the data matches the format being presented (is_ascii)
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
+        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
+        :param limit: Limit of the maximum length of the array sequence for search (in bytes)
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+    @staticmethod
+    def search(array: bytes, pattern: bytes, options: SearchOptions) -> List[int]:
+
+        """
+        Pattern search function in the source byte array, the search behaviour is taken from the options
+
+        Encoding coherence is not checked, before passing arguments, you should make sure that the data matches the format being presented (is_ascii)
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param options: Search options (overlapping, all_matches, limit), built with the SearchOptions builder
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+class ISO8859_1(object):
 
     @staticmethod
     def __new__(*args, **kwargs):
@@ -49,36 +143,82 @@ class UTF8(object):
         pass
 
     @staticmethod
-    def is_utf8(array: bytes) -> bool:
+    def to_utf8(array: bytes) -> bytes:
 
         """
-        The function checks the source byte sequence for coherence with the UTF-8 encoding
+        The function converts the source ISO-8859-1 byte sequence to UTF-8, every byte is mapped to the code point of the same value
 
         :param array: Encoded byte/s sequence
-        :return: Result of checking the sequence for compliance with the encoding format
+        :return: UTF-8 encoded bytes sequence
         """
 
         pass
 
     @staticmethod
-    def search_pattern(array: bytes, pattern: bytes, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+    def to_utf16(array: bytes, endian: bool) -> bytes:
 
         """
-        Pattern search function in the source byte array
+        The function converts the source ISO-8859-1 byte sequence to UTF-16 BE/LE, every byte is mapped to the code point of the same value
 
-        Encoding coherence is not checked, before passing arguments, you should make sure that the data matches the format being presented (is_utf8)
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the resulting bytes sequence (0:False - BE, 1:True - LE)
+        :return: UTF-16 encoded bytes sequence
+        """
 
-        :param array: Encoded source byte sequence
-        :param pattern: Encoded byte sequence of the pattern
-        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
-        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
-        :param limit: Limit of the maximum length of the array sequence for search (in bytes - variable length is not taken into account - for now)
-        :return: List of start indices, byte of the pattern in the source byte sequence
+        pass
+
+    @staticmethod
+    def to_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source ISO-8859-1 byte sequence to UTF-16 BE/LE, every byte is mapped to the code point of the same value
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the resulting bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: UTF-16 encoded bytes sequence
         """
 
         pass
 
-class UTF16(object):
+    @staticmethod
+    def from_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to ISO-8859-1, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: ISO-8859-1 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to ISO-8859-1, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: ISO-8859-1 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to ISO-8859-1, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: ISO-8859-1 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+class SingleByte(object):
 
     @staticmethod
     def __new__(*args, **kwargs):
@@ -88,45 +228,153 @@ class UTF16(object):
         pass
 
     @staticmethod
-    def is_utf16(array: bytes, endian: bool, omp: bool, only: bool) -> bool:
+    def is_single_byte(array: bytes, codepage: str) -> bool:
 
         """
-        The function checks the source byte sequence for coherence with the UTF-16 BE/LE encoding
+        The function checks the source byte sequence for coherence with the single-byte codepage, bytes left undefined by the codepage are rejected
 
-        :param array: Encoded bytes sequence
-        :param endian: Byte order of the encoded bytes sequence (0:False - BE, 1:True - LE)
-        :param omp: Enable/Disable over multilingual plane
-        :param only: Enable/Disable selected multilingual plane: 1) OMP & Only == True = surrogates pairs 2) Only == True = basic pairs 3) Only == False = basic + surrogates pairs
+        :param array: Encoded byte/s sequence
+        :param codepage: Label of the single-byte codepage (windows-1250..windows-1258, iso-8859-2..iso-8859-16, koi8-r, koi8-u, ibm866), ValueError is raised for an unknown label
         :return: Result of checking the sequence for compliance with the encoding format
         """
 
         pass
 
     @staticmethod
-    def search_pattern(array: bytes, pattern: bytes, omp: bool, only: bool, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+    def validate_single_byte(array: bytes, codepage: str) -> Optional[Tuple[int, str]]:
 
         """
-        Pattern search function in the source byte array
+        The function checks the source byte sequence for coherence with the single-byte codepage and reports the first violation
 
-        Encoding coherence is not checked, before passing arguments, you should make sure that the data matches the format being presented (is_utf16)
+        :param array: Encoded byte/s sequence
+        :param codepage: Label of the single-byte codepage (windows-1250..windows-1258, iso-8859-2..iso-8859-16, koi8-r, koi8-u, ibm866), ValueError is raised for an unknown label
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first undefined byte
+        """
 
-        It is forbidden to combine (OMP & Only == True) with regular pairs, you must guarantee through validation, coherence and only then use this flags
-        - minimal consequences is UB, memory integrity corruption, memory out of bounds. Use is not prohibited if the number of elements is divisible by 4 without remainder
+        pass
 
-        :param array: Encoded source byte sequence in BE/LE format (array and pattern must be in the same byte order)
-        :param pattern: Encoded byte sequence of the pattern in BE/LE format (array and pattern must be in the same byte order)
-        :param omp: Speeds up search for surrogate pairs by at least 2x compared to normal pairs (omp = True, only = True)
-        :param only: Enable/Disable selected multilingual plane: 1) OMP & Only == True = surrogates pairs 2) Only == True = basic pairs 3) Only == False = basic + surrogates pairs
+    @staticmethod
+    def to_utf8(array: bytes, codepage: str, replacement: bool) -> bytes:
+
+        """
+        The function converts the source single-byte sequence to UTF-8
+
+        :param array: Encoded byte/s sequence
+        :param codepage: Label of the single-byte codepage (windows-1250..windows-1258, iso-8859-2..iso-8859-16, koi8-r, koi8-u, ibm866), ValueError is raised for an unknown label
+        :param replacement: Flag, replaces undefined bytes with U+FFFD instead of raising an error
+        :return: UTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first undefined byte is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16(array: bytes, codepage: str, endian: bool, replacement: bool) -> bytes:
+
+        """
+        The function converts the source single-byte sequence to UTF-16 BE/LE
+
+        :param array: Encoded byte/s sequence
+        :param codepage: Label of the single-byte codepage (windows-1250..windows-1258, iso-8859-2..iso-8859-16, koi8-r, koi8-u, ibm866), ValueError is raised for an unknown label
+        :param endian: Byte order of the resulting bytes sequence (0:False - BE, 1:True - LE)
+        :param replacement: Flag, replaces undefined bytes with U+FFFD instead of raising an error
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first undefined byte is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16_with(array: bytes, codepage: str, endian: str, replacement: bool) -> bytes:
+
+        """
+        The function converts the source single-byte sequence to UTF-16 BE/LE
+
+        :param array: Encoded byte/s sequence
+        :param codepage: Label of the single-byte codepage (windows-1250..windows-1258, iso-8859-2..iso-8859-16, koi8-r, koi8-u, ibm866), ValueError is raised for an unknown label
+        :param endian: Label of the byte order of the resulting bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :param replacement: Flag, replaces undefined bytes with U+FFFD instead of raising an error
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first undefined byte is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf8(array: bytes, codepage: str, replacement: bool) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to the single-byte codepage, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param codepage: Label of the single-byte codepage (windows-1250..windows-1258, iso-8859-2..iso-8859-16, koi8-r, koi8-u, ibm866), ValueError is raised for an unknown label
+        :param replacement: Flag, replaces unmappable characters with '?' instead of raising an error
+        :return: Single-byte encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16(array: bytes, codepage: str, endian: bool, replacement: bool) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to the single-byte codepage, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param codepage: Label of the single-byte codepage (windows-1250..windows-1258, iso-8859-2..iso-8859-16, koi8-r, koi8-u, ibm866), ValueError is raised for an unknown label
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :param replacement: Flag, replaces unmappable characters with '?' instead of raising an error
+        :return: Single-byte encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16_with(array: bytes, codepage: str, endian: str, replacement: bool) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to the single-byte codepage, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param codepage: Label of the single-byte codepage (windows-1250..windows-1258, iso-8859-2..iso-8859-16, koi8-r, koi8-u, ibm866), ValueError is raised for an unknown label
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :param replacement: Flag, replaces unmappable characters with '?' instead of raising an error
+        :return: Single-byte encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def search_pattern(array: bytes, pattern: bytes, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+
+        """
+        Pattern search function in the source byte array
+
+        Every byte of a single-byte codepage is a whole character, so any match is aligned to the character boundaries
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
         :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
         :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
-        :param limit: Limit of the maximum length of the array sequence for search (in encoding, limit = 3 char (1 BMP, 1 OMP) = 6 bytes)
+        :param limit: Limit of the maximum length of the array sequence for search (in bytes)
         :return: List of start indices, byte of the pattern in the source byte sequence
         """
 
         pass
 
+    @staticmethod
+    def search(array: bytes, pattern: bytes, options: SearchOptions) -> List[int]:
 
-class UTF32(object):
+        """
+        Pattern search function in the source byte array, the search behaviour is taken from the options
+
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param options: Search options (overlapping, all_matches, limit), built with the SearchOptions builder
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+class SHIFTJIS(object):
 
     @staticmethod
     def __new__(*args, **kwargs):
@@ -136,39 +384,2585 @@ class UTF32(object):
         pass
 
     @staticmethod
-    def is_utf32(array: bytes, endian: bool) -> bool:
+    def is_shift_jis(array: bytes) -> bool:
 
         """
-        The function checks the source byte sequence for coherence with the UTF-32 BE/LE encoding
+        The function checks the source byte sequence for coherence with the Shift_JIS encoding
 
-        :param array: Encoded bytes sequence
-        :param endian: Byte order of the encoded bytes sequence (0:False - BE, 1:True - LE)
+        :param array: Encoded byte/s sequence
         :return: Result of checking the sequence for compliance with the encoding format
         """
 
         pass
 
     @staticmethod
-    def search_pattern(array: bytes, pattern: bytes, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+    def validate_shift_jis(array: bytes) -> Optional[Tuple[int, str]]:
 
         """
-        Pattern search function in the source byte array
+        The function checks the source byte sequence for coherence with the Shift_JIS encoding and reports the first violation
 
-        Encoding coherence is not checked, before passing arguments, you should make sure that the data matches the format being presented (is_utf32)
+        :param array: Encoded byte/s sequence
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first invalid sequence
+        """
 
-        :param array: Encoded source byte sequence in BE/LE format (array and pattern must be in the same byte order)
-        :param pattern: Encoded byte sequence of the pattern in BE/LE format (array and pattern must be in the same byte order)
-        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
-        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
-        :param limit: Limit of the maximum length of the array sequence for search (in encoding, limit = 3 char = 12 bytes)
-        :return: List of start indices, byte of the pattern in the source byte sequence
+        pass
+
+    @staticmethod
+    def to_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source Shift_JIS byte sequence to UTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: UTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source Shift_JIS byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the resulting bytes sequence (0:False - BE, 1:True - LE)
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source Shift_JIS byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the resulting bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to Shift_JIS, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: Shift_JIS encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to Shift_JIS, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: Shift_JIS encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to Shift_JIS, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: Shift_JIS encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def search_pattern(array: bytes, pattern: bytes, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+
+        """
+        Pattern search function in the source byte array
+
+        Only matches that start and end on character boundaries are reported, so a pattern never matches the trail byte of a double-byte character
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
+        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
+        :param limit: Limit of the maximum length of the array sequence for search (in bytes)
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+    @staticmethod
+    def search(array: bytes, pattern: bytes, options: SearchOptions) -> List[int]:
+
+        """
+        Pattern search function in the source byte array, the search behaviour is taken from the options
+
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param options: Search options (overlapping, all_matches, limit), built with the SearchOptions builder
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+class EUCJP(object):
+
+    @staticmethod
+    def __new__(*args, **kwargs):
+        pass
+
+    def __init__(self, *args, **kwargs):
+        pass
+
+    @staticmethod
+    def is_euc_jp(array: bytes) -> bool:
+
+        """
+        The function checks the source byte sequence for coherence with the EUC-JP encoding
+
+        :param array: Encoded byte/s sequence
+        :return: Result of checking the sequence for compliance with the encoding format
+        """
+
+        pass
+
+    @staticmethod
+    def validate_euc_jp(array: bytes) -> Optional[Tuple[int, str]]:
+
+        """
+        The function checks the source byte sequence for coherence with the EUC-JP encoding and reports the first violation
+
+        :param array: Encoded byte/s sequence
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first invalid sequence
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source EUC-JP byte sequence to UTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: UTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source EUC-JP byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the resulting bytes sequence (0:False - BE, 1:True - LE)
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source EUC-JP byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the resulting bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to EUC-JP, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: EUC-JP encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to EUC-JP, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: EUC-JP encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to EUC-JP, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: EUC-JP encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def search_pattern(array: bytes, pattern: bytes, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+
+        """
+        Pattern search function in the source byte array
+
+        Only matches that start and end on character boundaries are reported, so a pattern never matches across the bytes of a multi-byte character
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
+        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
+        :param limit: Limit of the maximum length of the array sequence for search (in bytes)
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+    @staticmethod
+    def search(array: bytes, pattern: bytes, options: SearchOptions) -> List[int]:
+
+        """
+        Pattern search function in the source byte array, the search behaviour is taken from the options
+
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param options: Search options (overlapping, all_matches, limit), built with the SearchOptions builder
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+class ISO2022JP(object):
+
+    @staticmethod
+    def __new__(*args, **kwargs):
+        pass
+
+    def __init__(self, *args, **kwargs):
+        pass
+
+    @staticmethod
+    def is_iso2022_jp(array: bytes) -> bool:
+
+        """
+        The function checks the source byte sequence for coherence with the ISO-2022-JP encoding
+
+        :param array: Encoded byte/s sequence
+        :return: Result of checking the sequence for compliance with the encoding format
+        """
+
+        pass
+
+    @staticmethod
+    def validate_iso2022_jp(array: bytes) -> Optional[Tuple[int, str]]:
+
+        """
+        The function checks the source byte sequence for coherence with the ISO-2022-JP encoding and reports the first violation
+
+        :param array: Encoded byte/s sequence
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first invalid sequence
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source ISO-2022-JP byte sequence to UTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: UTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source ISO-2022-JP byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the resulting bytes sequence (0:False - BE, 1:True - LE)
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source ISO-2022-JP byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the resulting bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to ISO-2022-JP, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: ISO-2022-JP encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to ISO-2022-JP, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: ISO-2022-JP encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to ISO-2022-JP, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: ISO-2022-JP encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def search_pattern(array: bytes, pattern: bytes, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+
+        """
+        Pattern search function in the source byte array
+
+        Only matches that start and end on character boundaries are reported, escape sequences and double-byte characters are never matched partially
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
+        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
+        :param limit: Limit of the maximum length of the array sequence for search (in bytes)
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+    @staticmethod
+    def search(array: bytes, pattern: bytes, options: SearchOptions) -> List[int]:
+
+        """
+        Pattern search function in the source byte array, the search behaviour is taken from the options
+
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param options: Search options (overlapping, all_matches, limit), built with the SearchOptions builder
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+class GB18030(object):
+
+    @staticmethod
+    def __new__(*args, **kwargs):
+        pass
+
+    def __init__(self, *args, **kwargs):
+        pass
+
+    @staticmethod
+    def is_gb18030(array: bytes) -> bool:
+
+        """
+        The function checks the source byte sequence for coherence with the GB18030 encoding
+
+        :param array: Encoded byte/s sequence
+        :return: Result of checking the sequence for compliance with the encoding format
+        """
+
+        pass
+
+    @staticmethod
+    def validate_gb18030(array: bytes) -> Optional[Tuple[int, str]]:
+
+        """
+        The function checks the source byte sequence for coherence with the GB18030 encoding and reports the first violation
+
+        :param array: Encoded byte/s sequence
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first invalid sequence
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source GB18030 byte sequence to UTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: UTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source GB18030 byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the resulting bytes sequence (0:False - BE, 1:True - LE)
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source GB18030 byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the resulting bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to GB18030, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: GB18030 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to GB18030, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: GB18030 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to GB18030, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: GB18030 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def search_pattern(array: bytes, pattern: bytes, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+
+        """
+        Pattern search function in the source byte array
+
+        Only matches that start and end on character boundaries are reported, multi-byte characters are never matched partially
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
+        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
+        :param limit: Limit of the maximum length of the array sequence for search (in bytes)
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+    @staticmethod
+    def search(array: bytes, pattern: bytes, options: SearchOptions) -> List[int]:
+
+        """
+        Pattern search function in the source byte array, the search behaviour is taken from the options
+
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param options: Search options (overlapping, all_matches, limit), built with the SearchOptions builder
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+class GBK(object):
+
+    @staticmethod
+    def __new__(*args, **kwargs):
+        pass
+
+    def __init__(self, *args, **kwargs):
+        pass
+
+    @staticmethod
+    def is_gbk(array: bytes) -> bool:
+
+        """
+        The function checks the source byte sequence for coherence with the GBK encoding, four-byte GB18030 sequences are accepted as in the WHATWG GBK decoder
+
+        :param array: Encoded byte/s sequence
+        :return: Result of checking the sequence for compliance with the encoding format
+        """
+
+        pass
+
+    @staticmethod
+    def validate_gbk(array: bytes) -> Optional[Tuple[int, str]]:
+
+        """
+        The function checks the source byte sequence for coherence with the GBK encoding and reports the first violation, four-byte GB18030 sequences are accepted as in the WHATWG GBK decoder
+
+        :param array: Encoded byte/s sequence
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first invalid sequence
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source GBK byte sequence to UTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: UTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source GBK byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the resulting bytes sequence (0:False - BE, 1:True - LE)
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source GBK byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the resulting bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to GBK, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: GBK encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to GBK, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: GBK encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to GBK, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: GBK encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def search_pattern(array: bytes, pattern: bytes, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+
+        """
+        Pattern search function in the source byte array
+
+        Only matches that start and end on character boundaries are reported, multi-byte characters are never matched partially
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
+        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
+        :param limit: Limit of the maximum length of the array sequence for search (in bytes)
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+    @staticmethod
+    def search(array: bytes, pattern: bytes, options: SearchOptions) -> List[int]:
+
+        """
+        Pattern search function in the source byte array, the search behaviour is taken from the options
+
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param options: Search options (overlapping, all_matches, limit), built with the SearchOptions builder
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+class BIG5(object):
+
+    @staticmethod
+    def __new__(*args, **kwargs):
+        pass
+
+    def __init__(self, *args, **kwargs):
+        pass
+
+    @staticmethod
+    def is_big5(array: bytes) -> bool:
+
+        """
+        The function checks the source byte sequence for coherence with the Big5 encoding
+
+        :param array: Encoded byte/s sequence
+        :return: Result of checking the sequence for compliance with the encoding format
+        """
+
+        pass
+
+    @staticmethod
+    def validate_big5(array: bytes) -> Optional[Tuple[int, str]]:
+
+        """
+        The function checks the source byte sequence for coherence with the Big5 encoding and reports the first violation
+
+        :param array: Encoded byte/s sequence
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first invalid sequence
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source Big5 byte sequence to UTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: UTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source Big5 byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the resulting bytes sequence (0:False - BE, 1:True - LE)
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source Big5 byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the resulting bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to Big5, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: Big5 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to Big5, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: Big5 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to Big5, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: Big5 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def search_pattern(array: bytes, pattern: bytes, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+
+        """
+        Pattern search function in the source byte array
+
+        Only matches that start and end on character boundaries are reported, multi-byte characters are never matched partially
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
+        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
+        :param limit: Limit of the maximum length of the array sequence for search (in bytes)
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+    @staticmethod
+    def search(array: bytes, pattern: bytes, options: SearchOptions) -> List[int]:
+
+        """
+        Pattern search function in the source byte array, the search behaviour is taken from the options
+
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param options: Search options (overlapping, all_matches, limit), built with the SearchOptions builder
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+class EUCKR(object):
+
+    @staticmethod
+    def __new__(*args, **kwargs):
+        pass
+
+    def __init__(self, *args, **kwargs):
+        pass
+
+    @staticmethod
+    def is_euc_kr(array: bytes) -> bool:
+
+        """
+        The function checks the source byte sequence for coherence with the EUC-KR encoding (UHC / Windows-949 superset)
+
+        :param array: Encoded byte/s sequence
+        :return: Result of checking the sequence for compliance with the encoding format
+        """
+
+        pass
+
+    @staticmethod
+    def validate_euc_kr(array: bytes) -> Optional[Tuple[int, str]]:
+
+        """
+        The function checks the source byte sequence for coherence with the EUC-KR encoding and reports the first violation
+
+        :param array: Encoded byte/s sequence
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first invalid sequence
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf8(array: bytes, replacement: bool) -> bytes:
+
+        """
+        The function converts the source EUC-KR byte sequence to UTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param replacement: Flag, replaces invalid sequences with U+FFFD instead of raising an error
+        :return: UTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16(array: bytes, endian: bool, replacement: bool) -> bytes:
+
+        """
+        The function converts the source EUC-KR byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the resulting bytes sequence (0:False - BE, 1:True - LE)
+        :param replacement: Flag, replaces invalid sequences with U+FFFD instead of raising an error
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16_with(array: bytes, endian: str, replacement: bool) -> bytes:
+
+        """
+        The function converts the source EUC-KR byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the resulting bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :param replacement: Flag, replaces invalid sequences with U+FFFD instead of raising an error
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf8(array: bytes, replacement: bool) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to EUC-KR, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param replacement: Flag, replaces unmappable characters with '?' instead of raising an error
+        :return: EUC-KR encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16(array: bytes, endian: bool, replacement: bool) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to EUC-KR, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :param replacement: Flag, replaces unmappable characters with '?' instead of raising an error
+        :return: EUC-KR encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16_with(array: bytes, endian: str, replacement: bool) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to EUC-KR, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :param replacement: Flag, replaces unmappable characters with '?' instead of raising an error
+        :return: EUC-KR encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def search_pattern(array: bytes, pattern: bytes, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+
+        """
+        Pattern search function in the source byte array
+
+        Only matches that start and end on character boundaries are reported, multi-byte characters are never matched partially
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
+        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
+        :param limit: Limit of the maximum length of the array sequence for search (in bytes)
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+    @staticmethod
+    def search(array: bytes, pattern: bytes, options: SearchOptions) -> List[int]:
+
+        """
+        Pattern search function in the source byte array, the search behaviour is taken from the options
+
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param options: Search options (overlapping, all_matches, limit), built with the SearchOptions builder
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+class CESU8(object):
+
+    @staticmethod
+    def __new__(*args, **kwargs):
+        pass
+
+    def __init__(self, *args, **kwargs):
+        pass
+
+    @staticmethod
+    def is_cesu8(array: bytes) -> bool:
+
+        """
+        The function checks the source byte sequence for coherence with the CESU-8 encoding (supplementary characters as six-byte surrogate pairs)
+
+        :param array: Encoded byte/s sequence
+        :return: Result of checking the sequence for compliance with the encoding format
+        """
+
+        pass
+
+    @staticmethod
+    def validate_cesu8(array: bytes) -> Optional[Tuple[int, str]]:
+
+        """
+        The function checks the source byte sequence for coherence with the CESU-8 encoding (supplementary characters as six-byte surrogate pairs) and reports the first violation
+
+        :param array: Encoded byte/s sequence
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first invalid sequence
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source CESU-8 byte sequence to UTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: UTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source CESU-8 byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the resulting bytes sequence (0:False - BE, 1:True - LE)
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source CESU-8 byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the resulting bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to CESU-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: CESU-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to CESU-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: CESU-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to CESU-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: CESU-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+class MUTF8(object):
+
+    @staticmethod
+    def __new__(*args, **kwargs):
+        pass
+
+    def __init__(self, *args, **kwargs):
+        pass
+
+    @staticmethod
+    def is_mutf8(array: bytes) -> bool:
+
+        """
+        The function checks the source byte sequence for coherence with the MUTF-8 encoding (Java modified UTF-8, NUL as C0 80 and supplementary characters as six-byte surrogate pairs)
+
+        :param array: Encoded byte/s sequence
+        :return: Result of checking the sequence for compliance with the encoding format
+        """
+
+        pass
+
+    @staticmethod
+    def validate_mutf8(array: bytes) -> Optional[Tuple[int, str]]:
+
+        """
+        The function checks the source byte sequence for coherence with the MUTF-8 encoding (Java modified UTF-8, NUL as C0 80 and supplementary characters as six-byte surrogate pairs) and reports the first violation
+
+        :param array: Encoded byte/s sequence
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first invalid sequence
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source MUTF-8 byte sequence to UTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: UTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source MUTF-8 byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the resulting bytes sequence (0:False - BE, 1:True - LE)
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source MUTF-8 byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the resulting bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to MUTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: MUTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to MUTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: MUTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to MUTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: MUTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+class WTF8(object):
+
+    @staticmethod
+    def __new__(*args, **kwargs):
+        pass
+
+    def __init__(self, *args, **kwargs):
+        pass
+
+    @staticmethod
+    def is_wtf8(array: bytes) -> bool:
+
+        """
+        The function checks the source byte sequence for coherence with the WTF-8 encoding (UTF-8 extended with unpaired surrogates)
+
+        :param array: Encoded byte/s sequence
+        :return: Result of checking the sequence for compliance with the encoding format
+        """
+
+        pass
+
+    @staticmethod
+    def validate_wtf8(array: bytes) -> Optional[Tuple[int, str]]:
+
+        """
+        The function checks the source byte sequence for coherence with the WTF-8 encoding (UTF-8 extended with unpaired surrogates) and reports the first violation
+
+        :param array: Encoded byte/s sequence
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first invalid sequence
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf8(array: bytes, replacement: bool) -> bytes:
+
+        """
+        The function converts the source WTF-8 byte sequence to UTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param replacement: Flag, replaces unpaired surrogates with U+FFFD instead of raising an error
+        :return: UTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source WTF-8 byte sequence to UTF-16 BE/LE, unpaired surrogates are preserved
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the resulting bytes sequence (0:False - BE, 1:True - LE)
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source WTF-8 byte sequence to UTF-16 BE/LE, unpaired surrogates are preserved
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the resulting bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf8(array: bytes) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to WTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :return: WTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to WTF-8, unpaired surrogates are preserved
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: WTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to WTF-8, unpaired surrogates are preserved
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: WTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+class UTF7(object):
+
+    @staticmethod
+    def __new__(*args, **kwargs):
+        pass
+
+    def __init__(self, *args, **kwargs):
+        pass
+
+    @staticmethod
+    def is_utf7(array: bytes, variant: str) -> bool:
+
+        """
+        The function checks the source byte sequence for coherence with the UTF-7 encoding
+
+        :param array: Encoded byte/s sequence
+        :param variant: Label of the UTF-7 variant (utf-7 - RFC 2152, imap-utf-7 - RFC 3501 modified UTF-7), ValueError is raised for an unknown label
+        :return: Result of checking the sequence for compliance with the encoding format
+        """
+
+        pass
+
+    @staticmethod
+    def validate_utf7(array: bytes, variant: str) -> Optional[Tuple[int, str]]:
+
+        """
+        The function checks the source byte sequence for coherence with the UTF-7 encoding and reports the first violation
+
+        :param array: Encoded byte/s sequence
+        :param variant: Label of the UTF-7 variant (utf-7 - RFC 2152, imap-utf-7 - RFC 3501 modified UTF-7), ValueError is raised for an unknown label
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first invalid sequence
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf8(array: bytes, variant: str) -> bytes:
+
+        """
+        The function converts the source UTF-7 byte sequence to UTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param variant: Label of the UTF-7 variant (utf-7 - RFC 2152, imap-utf-7 - RFC 3501 modified UTF-7), ValueError is raised for an unknown label
+        :return: UTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16(array: bytes, endian: bool, variant: str) -> bytes:
+
+        """
+        The function converts the source UTF-7 byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the resulting bytes sequence (0:False - BE, 1:True - LE)
+        :param variant: Label of the UTF-7 variant (utf-7 - RFC 2152, imap-utf-7 - RFC 3501 modified UTF-7), ValueError is raised for an unknown label
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16_with(array: bytes, endian: str, variant: str) -> bytes:
+
+        """
+        The function converts the source UTF-7 byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the resulting bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :param variant: Label of the UTF-7 variant (utf-7 - RFC 2152, imap-utf-7 - RFC 3501 modified UTF-7), ValueError is raised for an unknown label
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf8(array: bytes, variant: str) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to UTF-7, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param variant: Label of the UTF-7 variant (utf-7 - RFC 2152, imap-utf-7 - RFC 3501 modified UTF-7), ValueError is raised for an unknown label
+        :return: UTF-7 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16(array: bytes, endian: bool, variant: str) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to UTF-7, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :param variant: Label of the UTF-7 variant (utf-7 - RFC 2152, imap-utf-7 - RFC 3501 modified UTF-7), ValueError is raised for an unknown label
+        :return: UTF-7 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf16_with(array: bytes, endian: str, variant: str) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to UTF-7, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :param variant: Label of the UTF-7 variant (utf-7 - RFC 2152, imap-utf-7 - RFC 3501 modified UTF-7), ValueError is raised for an unknown label
+        :return: UTF-7 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+class EBCDIC(object):
+
+    @staticmethod
+    def __new__(*args, **kwargs):
+        pass
+
+    def __init__(self, *args, **kwargs):
+        pass
+
+    @staticmethod
+    def is_ebcdic_text(array: bytes, codepage: str) -> bool:
+
+        """
+        The function heuristically checks whether the source byte sequence is text encoded in the EBCDIC codepage
+
+        :param array: Encoded byte/s sequence
+        :param codepage: Label of the EBCDIC codepage (ibm037, ibm500, ibm1047, ibm1140), ValueError is raised for an unknown label
+        :return: Result of checking the sequence for looking like EBCDIC text (no unexpected control characters, mostly ASCII repertoire)
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf8(array: bytes, codepage: str, newline: str) -> bytes:
+
+        """
+        The function converts the source EBCDIC byte sequence to UTF-8
+
+        :param array: Encoded byte/s sequence
+        :param codepage: Label of the EBCDIC codepage (ibm037, ibm500, ibm1047, ibm1140), ValueError is raised for an unknown label
+        :param newline: Label of the newline mapping (nel - 0x15 is NEL and 0x25 is LF, lf - 0x15 is LF and 0x25 is NEL), ValueError is raised for an unknown label
+        :return: UTF-8 encoded bytes sequence
+        """
+
+        pass
+
+    @staticmethod
+    def to_ascii(array: bytes, codepage: str, newline: str) -> bytes:
+
+        """
+        The function converts the source EBCDIC byte sequence to ASCII
+
+        :param array: Encoded byte/s sequence
+        :param codepage: Label of the EBCDIC codepage (ibm037, ibm500, ibm1047, ibm1140), ValueError is raised for an unknown label
+        :param newline: Label of the newline mapping (nel - 0x15 is NEL and 0x25 is LF, lf - 0x15 is LF and 0x25 is NEL), ValueError is raised for an unknown label
+        :return: ASCII encoded bytes sequence, ValueError with the byte offset of the first byte outside the ASCII repertoire is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_utf8(array: bytes, codepage: str, newline: str) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to EBCDIC, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param codepage: Label of the EBCDIC codepage (ibm037, ibm500, ibm1047, ibm1140), ValueError is raised for an unknown label
+        :param newline: Label of the newline mapping (nel - 0x15 is NEL and 0x25 is LF, lf - 0x15 is LF and 0x25 is NEL), ValueError is raised for an unknown label
+        :return: EBCDIC encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid or unmappable sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def from_ascii(array: bytes, codepage: str, newline: str) -> bytes:
+
+        """
+        The function converts the source ASCII byte sequence to EBCDIC
+
+        :param array: Encoded byte/s sequence
+        :param codepage: Label of the EBCDIC codepage (ibm037, ibm500, ibm1047, ibm1140), ValueError is raised for an unknown label
+        :param newline: Label of the newline mapping (nel - 0x15 is NEL and 0x25 is LF, lf - 0x15 is LF and 0x25 is NEL), ValueError is raised for an unknown label
+        :return: EBCDIC encoded bytes sequence, ValueError with the byte offset of the first non-ASCII byte is raised otherwise
+        """
+
+        pass
+
+class UTF8(object):
+
+    @staticmethod
+    def __new__(*args, **kwargs):
+        pass
+
+    def __init__(self, *args, **kwargs):
+        pass
+
+    @staticmethod
+    def is_utf8(array: bytes) -> bool:
+
+        """
+        The function checks the source byte sequence for coherence with the UTF-8 encoding
+
+        :param array: Encoded byte/s sequence
+        :return: Result of checking the sequence for compliance with the encoding format
+        """
+
+        pass
+
+    @staticmethod
+    def validate_utf8(array: bytes) -> Optional[Tuple[int, str]]:
+
+        """
+        The function checks the source byte sequence for coherence with the UTF-8 encoding and reports the first violation
+
+        :param array: Encoded byte/s sequence
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first invalid sequence
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the resulting bytes sequence (0:False - BE, 1:True - LE)
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to UTF-16 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the resulting bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf32(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to UTF-32 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the resulting bytes sequence (0:False - BE, 1:True - LE)
+        :return: UTF-32 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf32_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source UTF-8 byte sequence to UTF-32 BE/LE, the sequence is validated during the conversion
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the resulting bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: UTF-32 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_string_lossy(array: bytes) -> str:
+
+        """
+        The function decodes the source UTF-8 byte sequence, each maximal invalid subpart is replaced with U+FFFD (Encoding Standard)
+
+        :param array: Encoded byte/s sequence
+        :return: Decoded string
+        """
+
+        pass
+
+    @staticmethod
+    def count_chars(array: bytes) -> int:
+
+        """
+        The function counts the code points of the source UTF-8 byte sequence (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :return: Number of code points
+        """
+
+        pass
+
+    @staticmethod
+    def stats(array: bytes) -> Tuple[int, int, int, int]:
+
+        """
+        The function counts the code points of the source UTF-8 byte sequence per plane (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :return: Number of ASCII, BMP (non-ASCII), supplementary code points and UTF-16 surrogate code units
+        """
+
+        pass
+
+    @staticmethod
+    def utf16_length_from_utf8(array: bytes) -> int:
+
+        """
+        The function computes the length of the source UTF-8 byte sequence after conversion to UTF-16 without converting it (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :return: Number of UTF-16 code units
+        """
+
+        pass
+
+    @staticmethod
+    def utf32_length_from_utf8(array: bytes) -> int:
+
+        """
+        The function computes the length of the source UTF-8 byte sequence after conversion to UTF-32 without converting it (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :return: Number of UTF-32 code units
+        """
+
+        pass
+
+    @staticmethod
+    def search_pattern(array: bytes, pattern: bytes, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+
+        """
+        Pattern search function in the source byte array
+
+        Encoding coherence is not checked, before passing arguments, you should make sure that the data matches the format being presented (is_utf8)
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
+        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
+        :param limit: Limit of the maximum length of the array sequence for search (in bytes - variable length is not taken into account - for now)
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+    @staticmethod
+    def search_pattern_case_folded(array: bytes, pattern: bytes, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+
+        """
+        Pattern search function in the source byte array, code points are compared after Unicode simple case folding (CaseFolding.txt, C + S)
+
+        Invalid sequences never match, the source is folded in bounded windows rather than as a whole
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
+        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
+        :param limit: Limit of the maximum length of the array sequence for search (in bytes)
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+    @staticmethod
+    def search(array: bytes, pattern: bytes, options: SearchOptions) -> List[int]:
+
+        """
+        Pattern search function in the source byte array, the search behaviour is taken from the options
+
+        Encoding coherence is not checked, before passing arguments, you should make sure that the data matches the format being presented (is_utf8)
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param options: Search options (overlapping, all_matches, limit), built with the SearchOptions builder
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+class UTF16(object):
+
+    @staticmethod
+    def __new__(*args, **kwargs):
+        pass
+
+    def __init__(self, *args, **kwargs):
+        pass
+
+    @staticmethod
+    def is_utf16_with(array: bytes, endian: str, planes: str) -> bool:
+
+        """
+        The function checks the source byte sequence for coherence with the UTF-16 encoding
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :param planes: Label of the accepted planes (bmp - basic pairs only, supplementary - surrogate pairs only, mixed - basic + surrogate pairs), ValueError is raised for an unknown label
+        :return: Result of checking the sequence for compliance with the encoding format
+        """
+
+        pass
+
+    @staticmethod
+    def validate_utf16_with(array: bytes, endian: str, planes: str) -> Optional[Tuple[int, str]]:
+
+        """
+        The function checks the source byte sequence for coherence with the UTF-16 encoding and reports the first violation
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :param planes: Label of the accepted planes (bmp - basic pairs only, supplementary - surrogate pairs only, mixed - basic + surrogate pairs), ValueError is raised for an unknown label
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first invalid sequence
+        """
+
+        pass
+
+    @staticmethod
+    def is_utf16(array: bytes, endian: bool, omp: bool, only: bool) -> bool:
+
+        """
+        The function checks the source byte sequence for coherence with the UTF-16 BE/LE encoding
+
+        :param array: Encoded bytes sequence
+        :param endian: Byte order of the encoded bytes sequence (0:False - BE, 1:True - LE)
+        :param omp: Enable/Disable over multilingual plane
+        :param only: Enable/Disable selected multilingual plane: 1) OMP & Only == True = surrogates pairs 2) Only == True = basic pairs 3) Only == False = basic + surrogates pairs
+        :return: Result of checking the sequence for compliance with the encoding format
+        """
+
+        pass
+
+    @staticmethod
+    def validate_utf16(array: bytes, endian: bool, omp: bool, only: bool) -> Optional[Tuple[int, str]]:
+
+        """
+        The function checks the source byte sequence for coherence with the UTF-16 BE/LE encoding and reports the first violation
+
+        :param array: Encoded bytes sequence
+        :param endian: Byte order of the encoded bytes sequence (0:False - BE, 1:True - LE)
+        :param omp: Enable/Disable over multilingual plane
+        :param only: Enable/Disable selected multilingual plane: 1) OMP & Only == True = surrogates pairs 2) Only == True = basic pairs 3) Only == False = basic + surrogates pairs
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first invalid sequence
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf8(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to UTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded bytes sequence
+        :param endian: Byte order of the encoded bytes sequence (0:False - BE, 1:True - LE)
+        :return: UTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf8_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to UTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded bytes sequence
+        :param endian: Label of the byte order of the encoded bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: UTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf32(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to UTF-32 with the same byte order, the sequence is validated during the conversion
+
+        :param array: Encoded bytes sequence
+        :param endian: Byte order of the encoded and resulting bytes sequences (0:False - BE, 1:True - LE)
+        :return: UTF-32 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf32_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source UTF-16 BE/LE byte sequence to UTF-32 with the same byte order, the sequence is validated during the conversion
+
+        :param array: Encoded bytes sequence
+        :param endian: Label of the byte order of the encoded and resulting bytes sequences bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: UTF-32 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_string_lossy(array: bytes, endian: bool) -> str:
+
+        """
+        The function decodes the source UTF-16 BE/LE byte sequence, each invalid code unit (and a truncated tail) is replaced with U+FFFD (Encoding Standard)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: Decoded string
+        """
+
+        pass
+
+    @staticmethod
+    def to_string_lossy_with(array: bytes, endian: str) -> str:
+
+        """
+        The function decodes the source UTF-16 BE/LE byte sequence, each invalid code unit (and a truncated tail) is replaced with U+FFFD (Encoding Standard)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: Decoded string
+        """
+
+        pass
+
+    @staticmethod
+    def lone_surrogates(array: bytes, endian: bool) -> List[int]:
+
+        """
+        The function accepts ill-formed UTF-16 BE/LE (unpaired surrogates, as in JavaScript strings or Windows filenames) and reports every unpaired surrogate
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: Byte offsets of the unpaired surrogates (empty if the sequence is well-formed), ValueError is raised for an odd-length sequence
+        """
+
+        pass
+
+    @staticmethod
+    def lone_surrogates_with(array: bytes, endian: str) -> List[int]:
+
+        """
+        The function accepts ill-formed UTF-16 BE/LE (unpaired surrogates, as in JavaScript strings or Windows filenames) and reports every unpaired surrogate
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: Byte offsets of the unpaired surrogates (empty if the sequence is well-formed), ValueError is raised for an odd-length sequence
+        """
+
+        pass
+
+    @staticmethod
+    def to_well_formed(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function replaces every unpaired surrogate of the source UTF-16 BE/LE byte sequence with U+FFFD, the rest of the sequence is copied unchanged
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source and resulting bytes sequence (0:False - BE, 1:True - LE)
+        :return: Well-formed UTF-16 encoded bytes sequence, ValueError is raised for an odd-length sequence
+        """
+
+        pass
+
+    @staticmethod
+    def to_well_formed_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function replaces every unpaired surrogate of the source UTF-16 BE/LE byte sequence with U+FFFD, the rest of the sequence is copied unchanged
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source and resulting bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: Well-formed UTF-16 encoded bytes sequence, ValueError is raised for an odd-length sequence
+        """
+
+        pass
+
+    @staticmethod
+    def count_chars(array: bytes, endian: bool) -> int:
+
+        """
+        The function counts the code points of the source UTF-16 byte sequence (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: Number of code points
+        """
+
+        pass
+
+    @staticmethod
+    def count_chars_with(array: bytes, endian: str) -> int:
+
+        """
+        The function counts the code points of the source UTF-16 byte sequence (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: Number of code points
+        """
+
+        pass
+
+    @staticmethod
+    def stats(array: bytes, endian: bool) -> Tuple[int, int, int, int]:
+
+        """
+        The function counts the code points of the source UTF-16 BE/LE byte sequence per plane (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: Number of ASCII, BMP (non-ASCII), supplementary code points and UTF-16 surrogate code units
+        """
+
+        pass
+
+    @staticmethod
+    def stats_with(array: bytes, endian: str) -> Tuple[int, int, int, int]:
+
+        """
+        The function counts the code points of the source UTF-16 BE/LE byte sequence per plane (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: Number of ASCII, BMP (non-ASCII), supplementary code points and UTF-16 surrogate code units
+        """
+
+        pass
+
+    @staticmethod
+    def utf8_length_from_utf16(array: bytes, endian: bool) -> int:
+
+        """
+        The function computes the length of the source UTF-16 BE/LE byte sequence after conversion to UTF-8 without converting it (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: Number of UTF-8 bytes
+        """
+
+        pass
+
+    @staticmethod
+    def utf8_length_from_utf16_with(array: bytes, endian: str) -> int:
+
+        """
+        The function computes the length of the source UTF-16 BE/LE byte sequence after conversion to UTF-8 without converting it (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: Number of UTF-8 bytes
+        """
+
+        pass
+
+    @staticmethod
+    def utf32_length_from_utf16(array: bytes, endian: bool) -> int:
+
+        """
+        The function computes the length of the source UTF-16 BE/LE byte sequence after conversion to UTF-32 without converting it (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: Number of UTF-32 code units
+        """
+
+        pass
+
+    @staticmethod
+    def utf32_length_from_utf16_with(array: bytes, endian: str) -> int:
+
+        """
+        The function computes the length of the source UTF-16 BE/LE byte sequence after conversion to UTF-32 without converting it (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: Number of UTF-32 code units
+        """
+
+        pass
+
+    @staticmethod
+    def search_pattern(array: bytes, pattern: bytes, omp: bool, only: bool, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+
+        """
+        Pattern search function in the source byte array
+
+        Encoding coherence is not checked, before passing arguments, you should make sure that the data matches the format being presented (is_utf16)
+
+        It is forbidden to combine (OMP & Only == True) with regular pairs, you must guarantee through validation, coherence and only then use this flags
+        - minimal consequences is UB, memory integrity corruption, memory out of bounds. Use is not prohibited if the number of elements is divisible by 4 without remainder
+
+        :param array: Encoded source byte sequence in BE/LE format (array and pattern must be in the same byte order)
+        :param pattern: Encoded byte sequence of the pattern in BE/LE format (array and pattern must be in the same byte order)
+        :param omp: Speeds up search for surrogate pairs by at least 2x compared to normal pairs (omp = True, only = True)
+        :param only: Enable/Disable selected multilingual plane: 1) OMP & Only == True = surrogates pairs 2) Only == True = basic pairs 3) Only == False = basic + surrogates pairs
+        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
+        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
+        :param limit: Limit of the maximum length of the array sequence for search (in encoding, limit = 3 char (1 BMP, 1 OMP) = 6 bytes)
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+    @staticmethod
+    def search_pattern_case_folded(array: bytes, pattern: bytes, endian: bool, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+
+        """
+        Pattern search function in the source byte array, code points are compared after Unicode simple case folding (CaseFolding.txt, C + S)
+
+        Invalid sequences never match, the source is folded in bounded windows rather than as a whole
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param endian: Byte order of the source bytes sequence and the pattern (0:False - BE, 1:True - LE)
+        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
+        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
+        :param limit: Limit of the maximum length of the array sequence for search (in code units)
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+    @staticmethod
+    def search_pattern_case_folded_with(array: bytes, pattern: bytes, endian: str, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+
+        """
+        Pattern search function in the source byte array, code points are compared after Unicode simple case folding (CaseFolding.txt, C + S)
+
+        Invalid sequences never match, the source is folded in bounded windows rather than as a whole
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param endian: Label of the byte order of the source bytes sequence and the pattern (little, big, native), ValueError is raised for an unknown label
+        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
+        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
+        :param limit: Limit of the maximum length of the array sequence for search (in code units)
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+    @staticmethod
+    def search(array: bytes, pattern: bytes, options: SearchOptions) -> List[int]:
+
+        """
+        Pattern search function in the source byte array, the search behaviour is taken from the options
+
+        Encoding coherence is not checked, before passing arguments, you should make sure that the data matches the format being presented (is_utf16)
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param options: Search options (planes, overlapping, all_matches, limit), built with the SearchOptions builder
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+
+class UTF32(object):
+
+    @staticmethod
+    def __new__(*args, **kwargs):
+        pass
+
+    def __init__(self, *args, **kwargs):
+        pass
+
+    @staticmethod
+    def is_utf32_with(array: bytes, endian: str) -> bool:
+
+        """
+        The function checks the source byte sequence for coherence with the UTF-32 encoding
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: Result of checking the sequence for compliance with the encoding format
+        """
+
+        pass
+
+    @staticmethod
+    def validate_utf32_with(array: bytes, endian: str) -> Optional[Tuple[int, str]]:
+
+        """
+        The function checks the source byte sequence for coherence with the UTF-32 encoding and reports the first violation
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first invalid sequence
+        """
+
+        pass
+
+    @staticmethod
+    def is_utf32(array: bytes, endian: bool) -> bool:
+
+        """
+        The function checks the source byte sequence for coherence with the UTF-32 BE/LE encoding
+
+        :param array: Encoded bytes sequence
+        :param endian: Byte order of the encoded bytes sequence (0:False - BE, 1:True - LE)
+        :return: Result of checking the sequence for compliance with the encoding format
+        """
+
+        pass
+
+    @staticmethod
+    def validate_utf32(array: bytes, endian: bool) -> Optional[Tuple[int, str]]:
+
+        """
+        The function checks the source byte sequence for coherence with the UTF-32 BE/LE encoding and reports the first violation
+
+        :param array: Encoded bytes sequence
+        :param endian: Byte order of the encoded bytes sequence (0:False - BE, 1:True - LE)
+        :return: None if the sequence complies with the encoding format, otherwise the byte offset and the kind of the first invalid sequence
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf8(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source UTF-32 BE/LE byte sequence to UTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded bytes sequence
+        :param endian: Byte order of the encoded bytes sequence (0:False - BE, 1:True - LE)
+        :return: UTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf8_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source UTF-32 BE/LE byte sequence to UTF-8, the sequence is validated during the conversion
+
+        :param array: Encoded bytes sequence
+        :param endian: Label of the byte order of the encoded bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: UTF-8 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16(array: bytes, endian: bool) -> bytes:
+
+        """
+        The function converts the source UTF-32 BE/LE byte sequence to UTF-16 with the same byte order, the sequence is validated during the conversion
+
+        :param array: Encoded bytes sequence
+        :param endian: Byte order of the encoded and resulting bytes sequences (0:False - BE, 1:True - LE)
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_utf16_with(array: bytes, endian: str) -> bytes:
+
+        """
+        The function converts the source UTF-32 BE/LE byte sequence to UTF-16 with the same byte order, the sequence is validated during the conversion
+
+        :param array: Encoded bytes sequence
+        :param endian: Label of the byte order of the encoded and resulting bytes sequences bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: UTF-16 encoded bytes sequence, ValueError with the byte offset and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    @staticmethod
+    def to_string_lossy(array: bytes, endian: bool) -> str:
+
+        """
+        The function decodes the source UTF-32 BE/LE byte sequence, each invalid code unit (and a truncated tail) is replaced with U+FFFD (Encoding Standard)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: Decoded string
+        """
+
+        pass
+
+    @staticmethod
+    def to_string_lossy_with(array: bytes, endian: str) -> str:
+
+        """
+        The function decodes the source UTF-32 BE/LE byte sequence, each invalid code unit (and a truncated tail) is replaced with U+FFFD (Encoding Standard)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: Decoded string
+        """
+
+        pass
+
+    @staticmethod
+    def count_chars(array: bytes) -> int:
+
+        """
+        The function counts the code points of the source UTF-32 byte sequence (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :return: Number of code points
+        """
+
+        pass
+
+    @staticmethod
+    def stats(array: bytes, endian: bool) -> Tuple[int, int, int, int]:
+
+        """
+        The function counts the code points of the source UTF-32 BE/LE byte sequence per plane (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: Number of ASCII, BMP (non-ASCII), supplementary code points and UTF-16 surrogate code units
+        """
+
+        pass
+
+    @staticmethod
+    def stats_with(array: bytes, endian: str) -> Tuple[int, int, int, int]:
+
+        """
+        The function counts the code points of the source UTF-32 BE/LE byte sequence per plane (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: Number of ASCII, BMP (non-ASCII), supplementary code points and UTF-16 surrogate code units
+        """
+
+        pass
+
+    @staticmethod
+    def utf8_length_from_utf32(array: bytes, endian: bool) -> int:
+
+        """
+        The function computes the length of the source UTF-32 BE/LE byte sequence after conversion to UTF-8 without converting it (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: Number of UTF-8 bytes
+        """
+
+        pass
+
+    @staticmethod
+    def utf8_length_from_utf32_with(array: bytes, endian: str) -> int:
+
+        """
+        The function computes the length of the source UTF-32 BE/LE byte sequence after conversion to UTF-8 without converting it (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: Number of UTF-8 bytes
+        """
+
+        pass
+
+    @staticmethod
+    def utf16_length_from_utf32(array: bytes, endian: bool) -> int:
+
+        """
+        The function computes the length of the source UTF-32 BE/LE byte sequence after conversion to UTF-16 without converting it (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :return: Number of UTF-16 code units
+        """
+
+        pass
+
+    @staticmethod
+    def utf16_length_from_utf32_with(array: bytes, endian: str) -> int:
+
+        """
+        The function computes the length of the source UTF-32 BE/LE byte sequence after conversion to UTF-16 without converting it (encoding coherence is not checked)
+
+        :param array: Encoded byte/s sequence
+        :param endian: Label of the byte order of the source bytes sequence bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: Number of UTF-16 code units
+        """
+
+        pass
+
+    @staticmethod
+    def search_pattern(array: bytes, pattern: bytes, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+
+        """
+        Pattern search function in the source byte array
+
+        Encoding coherence is not checked, before passing arguments, you should make sure that the data matches the format being presented (is_utf32)
+
+        :param array: Encoded source byte sequence in BE/LE format (array and pattern must be in the same byte order)
+        :param pattern: Encoded byte sequence of the pattern in BE/LE format (array and pattern must be in the same byte order)
+        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
+        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
+        :param limit: Limit of the maximum length of the array sequence for search (in encoding, limit = 3 char = 12 bytes)
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+    @staticmethod
+    def search_pattern_case_folded(array: bytes, pattern: bytes, endian: bool, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+
+        """
+        Pattern search function in the source byte array, code points are compared after Unicode simple case folding (CaseFolding.txt, C + S)
+
+        Invalid sequences never match, the source is folded in bounded windows rather than as a whole
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param endian: Byte order of the source bytes sequence and the pattern (0:False - BE, 1:True - LE)
+        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
+        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
+        :param limit: Limit of the maximum length of the array sequence for search (in code points)
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+    @staticmethod
+    def search_pattern_case_folded_with(array: bytes, pattern: bytes, endian: str, overlapping: bool, all_matches: bool, limit: Optional[int] = None) -> List[int]:
+
+        """
+        Pattern search function in the source byte array, code points are compared after Unicode simple case folding (CaseFolding.txt, C + S)
+
+        Invalid sequences never match, the source is folded in bounded windows rather than as a whole
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param endian: Label of the byte order of the source bytes sequence and the pattern (little, big, native), ValueError is raised for an unknown label
+        :param overlapping: Flag that allows to include/exclude search for overlapping occurrences of pattern in the source byte sequence
+        :param all_matches: Flag, allows you to find all occurrences of the pattern in the source byte sequence
+        :param limit: Limit of the maximum length of the array sequence for search (in code points)
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+    @staticmethod
+    def search(array: bytes, pattern: bytes, options: SearchOptions) -> List[int]:
+
+        """
+        Pattern search function in the source byte array, the search behaviour is taken from the options
+
+        Encoding coherence is not checked, before passing arguments, you should make sure that the data matches the format being presented (is_utf32)
+
+        :param array: Encoded source byte sequence
+        :param pattern: Encoded byte sequence of the pattern
+        :param options: Search options (overlapping, all_matches, limit), built with the SearchOptions builder
+        :return: List of start indices, byte of the pattern in the source byte sequence
+        """
+
+        pass
+
+class Utf8Validator(object):
+
+    def __init__(self):
+
+        """
+        Incremental UTF-8 validator, the source sequence is passed in chunks of arbitrary length (code units and sequences may be split between chunks)
+
+        """
+
+        pass
+
+    def feed(self, array: bytes) -> None:
+
+        """
+        The function validates the next chunk of the source sequence
+
+        :param array: Encoded byte/s chunk
+        :return: None, ValueError with the byte offset (from the start of the stream) and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    def finish(self) -> None:
+
+        """
+        The function completes validation, an unfinished sequence at the end of the stream is reported as truncated
+
+        :return: None, ValueError with the byte offset (from the start of the stream) and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+class Utf16Validator(object):
+
+    def __init__(self, endian: bool, omp: bool, only: bool):
+
+        """
+        Incremental UTF-16 validator, the source sequence is passed in chunks of arbitrary length (code units and sequences may be split between chunks)
+
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        :param omp: Enable/Disable over multilingual plane
+        :param only: Enable/Disable selected multilingual plane: 1) OMP & Only == True = surrogates pairs 2) Only == True = basic pairs 3) Only == False = basic + surrogates pairs
+        """
+
+        pass
+
+    @staticmethod
+    def with_options(endian: str, planes: str) -> Utf16Validator:
+
+        """
+        Incremental UTF-16 validator built from the typed options
+
+        :param endian: Label of the byte order of the source bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :param planes: Label of the accepted planes (bmp - basic pairs only, supplementary - surrogate pairs only, mixed - basic + surrogate pairs), ValueError is raised for an unknown label
+        :return: New validator
+        """
+
+        pass
+
+    def feed(self, array: bytes) -> None:
+
+        """
+        The function validates the next chunk of the source sequence
+
+        :param array: Encoded byte/s chunk
+        :return: None, ValueError with the byte offset (from the start of the stream) and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    def finish(self) -> None:
+
+        """
+        The function completes validation, an unfinished sequence at the end of the stream is reported as truncated
+
+        :return: None, ValueError with the byte offset (from the start of the stream) and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+class Utf32Validator(object):
+
+    def __init__(self, endian: bool):
+
+        """
+        Incremental UTF-32 validator, the source sequence is passed in chunks of arbitrary length (code units and sequences may be split between chunks)
+
+        :param endian: Byte order of the source bytes sequence (0:False - BE, 1:True - LE)
+        """
+
+        pass
+
+    @staticmethod
+    def with_options(endian: str) -> Utf32Validator:
+
+        """
+        Incremental UTF-32 validator built from the typed options
+
+        :param endian: Label of the byte order of the source bytes sequence (little, big, native), ValueError is raised for an unknown label
+        :return: New validator
+        """
+
+        pass
+
+    def feed(self, array: bytes) -> None:
+
+        """
+        The function validates the next chunk of the source sequence
+
+        :param array: Encoded byte/s chunk
+        :return: None, ValueError with the byte offset (from the start of the stream) and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+    def finish(self) -> None:
+
+        """
+        The function completes validation, an unfinished sequence at the end of the stream is reported as truncated
+
+        :return: None, ValueError with the byte offset (from the start of the stream) and the kind of the first invalid sequence is raised otherwise
+        """
+
+        pass
+
+class Detection(object):
+
+    @staticmethod
+    def __new__(*args, **kwargs):
+        pass
+
+    def __init__(self, *args, **kwargs):
+        pass
+
+    @staticmethod
+    def detect(array: bytes) -> Tuple[str, float, int]:
+
+        """
+        The function detects the most likely encoding of the source byte sequence, the BOM is checked first, then the validators and the byte distribution heuristics
+
+        :param array: Encoded byte/s sequence
+        :return: Name of the encoding (unknown, ascii, utf-8, utf-16le, utf-16be, utf-32le or utf-32be), confidence from 0.0 to 1.0 and the length of the BOM in bytes
+        """
+
+        pass
+
+class SimdLevel(object):
+
+    @staticmethod
+    def __new__(*args, **kwargs):
+        pass
+
+    def __init__(self, *args, **kwargs):
+        pass
+
+    @staticmethod
+    def selected() -> str:
+
+        """
+        The function returns the instruction set selected for the current processor
+
+        :return: Name of the selected instruction set (universal, sse2, avx2, avx512 or neon)
         """
 
         pass
 
 __all__ = [
+    'SearchOptions',
     'ASCII',
+    'ISO8859_1',
+    'SingleByte',
+    'SHIFTJIS',
+    'EUCJP',
+    'ISO2022JP',
+    'GB18030',
+    'GBK',
+    'BIG5',
+    'EUCKR',
+    'CESU8',
+    'MUTF8',
+    'WTF8',
+    'UTF7',
+    'EBCDIC',
     'UTF8',
     'UTF16',
     'UTF32',
+    'Utf8Validator',
+    'Utf16Validator',
+    'Utf32Validator',
+    'Detection',
+    'SimdLevel',
 ]
@@ -30,7 +30,25 @@
 use crate::functors::{
     codings::{
         ASCII,
-        UTF8, UTF16, UTF32
+        ISO8859_1,
+        SingleByte, Codepage, Policy,
+        SHIFTJIS, EUCJP, ISO2022JP,
+        GB18030, GBK, BIG5,
+        EUCKR,
+        CESU8, MUTF8, WTF8,
+        UTF7, Utf7Variant,
+        EBCDIC, EbcdicCodepage, Newline,
+        Endian, Utf16Planes, SearchOptions,
+        UTF8, UTF16, UTF32,
+        Utf8Validator, Utf16Validator, Utf32Validator,
+        Detection,
+        Statistics
+    }
+};
+
+use crate::functors::{
+    dispatch::{
+        SimdLevel
     }
 };
 
@@ -46,8 +64,11 @@ use crate::functors::{
 };
 
 use pyo3::{
-    PyResult, Bound,
+    PyResult, PyRef, Bound, Python,
     pymodule, pyclass, pymethods,
+    exceptions::{
+        PyValueError
+    },
     types::{
         PyModule,
         PyModuleMethods,
@@ -61,6 +82,60 @@ use pyo3::{
     }
 };
 
+#[pyclass(name="SearchOptions")]
+#[derive(Clone)]
+struct SearchOptionsWrapper {
+    options: SearchOptions
+}
+
+impl SearchOptionsWrapper {
+    fn endian_from_label(label: &str) -> PyResult<Endian> {
+        return match Endian::from_label(label) {
+            Some(endian) => Ok(endian),
+            None => Err(PyValueError::new_err(format!("unknown endian label: {}", label)))
+        };
+    }
+
+    fn planes_from_label(label: &str) -> PyResult<Utf16Planes> {
+        return match Utf16Planes::from_label(label) {
+            Some(planes) => Ok(planes),
+            None => Err(PyValueError::new_err(format!("unknown UTF-16 planes label: {}", label)))
+        };
+    }
+}
+
+#[pymethods]
+impl SearchOptionsWrapper {
+
+    #[new]
+    pub fn new_ffi() -> SearchOptionsWrapper {
+        return SearchOptionsWrapper { options: SearchOptions::new() };
+    }
+
+    #[pyo3(name = "planes")]
+    pub fn planes_ffi(&self, planes: &str) -> PyResult<SearchOptionsWrapper> {
+        return Ok(SearchOptionsWrapper { options: self.options.planes(SearchOptionsWrapper::planes_from_label(planes)?) });
+    }
+
+    #[pyo3(name = "overlapping")]
+    pub fn overlapping_ffi(&self, overlapping: &Bound<'_, PyBool>) -> SearchOptionsWrapper {
+        return SearchOptionsWrapper { options: self.options.overlapping(overlapping.extract::<bool>().expect("[SearchOptions | overlapping_ffi | ERROR]: Can't extract overlapping")) };
+    }
+
+    #[pyo3(name = "all_matches")]
+    pub fn all_matches_ffi(&self, all_matches: &Bound<'_, PyBool>) -> SearchOptionsWrapper {
+        return SearchOptionsWrapper { options: self.options.all_matches(all_matches.extract::<bool>().expect("[SearchOptions | all_matches_ffi | ERROR]: Can't extract all_matches")) };
+    }
+
+    #[pyo3(name = "limit")]
+    pub fn limit_ffi(&self, limit: &Bound<'_, PyAny>) -> SearchOptionsWrapper {
+        return SearchOptionsWrapper { options: self.options.limit(
+            if limit.is_instance_of::<PyNone>() { None }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[SearchOptions | limit_ffi | ERROR]: Can't extract limit")) } else { None } }
+        ) };
+    }
+}
+
 #[pyclass(name="ASCII")]
 struct ASCIIWrapper;
 
@@ -73,6 +148,15 @@ impl ASCIIWrapper {
         return ASCII::is_ascii_from_byte_array(bytes.as_bytes());
     }
 
+    #[staticmethod]
+    #[pyo3(name = "validate_ascii")]
+    pub fn validate_ascii_ffi(bytes: &Bound<'_, PyBytes>) -> Option<(usize, &'static str)> {
+        return match ASCII::validate_ascii_from_byte_array(bytes.as_bytes()) {
+            Ok(()) => None,
+            Err(error) => Some((error.offset(), error.kind().as_str()))
+        };
+    }
+
     #[staticmethod]
     #[pyo3(name = "search_pattern")]
     pub fn search_pattern_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> Vec<usize> {
@@ -85,101 +169,2200 @@ impl ASCIIWrapper {
             else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[ASCII | search_pattern_ffi | ERROR]: Can't extract limit")) } else { None } }
         )
     }
+
+    #[staticmethod]
+    #[pyo3(name = "search_pattern_ignore_ascii_case")]
+    pub fn search_pattern_ignore_ascii_case_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> Vec<usize> {
+        return ASCII::search_pattern_ignore_ascii_case(
+            bytes.as_bytes(),
+            pattern_bytes.as_bytes(),
+            overlapping.extract::<bool>().expect("[ASCII | search_pattern_ignore_ascii_case_ffi | ERROR]: Can't extract overlapping"),
+            all_matches.extract::<bool>().expect("[ASCII | search_pattern_ignore_ascii_case_ffi | ERROR]: Can't extract all_matches"),
+            if limit.is_instance_of::<PyNone>() { None }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[ASCII | search_pattern_ignore_ascii_case_ffi | ERROR]: Can't extract limit")) } else { None } }
+        )
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search")]
+    pub fn search_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, options: PyRef<'_, SearchOptionsWrapper>) -> Vec<usize> {
+        return ASCII::search(bytes.as_bytes(), pattern_bytes.as_bytes(), options.options);
+    }
 }
 
-#[pyclass(name="UTF8")]
-struct UTF8Wrapper;
+#[pyclass(name="ISO8859_1")]
+struct ISO8859_1Wrapper;
 
 #[pymethods]
-impl UTF8Wrapper {
+impl ISO8859_1Wrapper {
 
     #[staticmethod]
-    #[pyo3(name = "is_utf8")]
-    pub fn is_utf8_ffi(bytes: &Bound<'_, PyBytes>) -> bool {
-        return UTF8::is_utf8(bytes.as_bytes());
+    #[pyo3(name = "to_utf8")]
+    pub fn to_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> Bound<'py, PyBytes> {
+        return PyBytes::new(python, &ISO8859_1::to_utf8(bytes.as_bytes()));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16")]
+    pub fn to_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> Bound<'py, PyBytes> {
+        let units: Vec<u16> = ISO8859_1::to_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[ISO-8859-1 | to_utf16_ffi | ERROR]: Can't extract endian")
+        );
+
+        return PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16_with")]
+    pub fn to_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        let units: Vec<u16> = ISO8859_1::to_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        );
+
+        return Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>()));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf8")]
+    pub fn from_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match ISO8859_1::from_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16")]
+    pub fn from_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match ISO8859_1::from_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[ISO-8859-1 | from_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16_with")]
+    pub fn from_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match ISO8859_1::from_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+}
+
+#[pyclass(name="SingleByte")]
+struct SingleByteWrapper;
+
+impl SingleByteWrapper {
+    fn codepage_from_label(label: &str) -> PyResult<Codepage> {
+        return match Codepage::from_label(label) {
+            Some(codepage) => Ok(codepage),
+            None => Err(PyValueError::new_err(format!("unknown codepage label: {}", label)))
+        };
+    }
+
+    fn policy_from_flag(replacement: &Bound<'_, PyBool>) -> Policy {
+        return if replacement.extract::<bool>().expect("[SingleByte | policy_from_flag | ERROR]: Can't extract replacement") { Policy::Replacement } else { Policy::Error };
+    }
+}
+
+#[pymethods]
+impl SingleByteWrapper {
+
+    #[staticmethod]
+    #[pyo3(name = "is_single_byte")]
+    pub fn is_single_byte_ffi(bytes: &Bound<'_, PyBytes>, codepage: &str) -> PyResult<bool> {
+        return Ok(SingleByte::is_single_byte(bytes.as_bytes(), SingleByteWrapper::codepage_from_label(codepage)?));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "validate_single_byte")]
+    pub fn validate_single_byte_ffi(bytes: &Bound<'_, PyBytes>, codepage: &str) -> PyResult<Option<(usize, &'static str)>> {
+        return match SingleByte::validate_single_byte(bytes.as_bytes(), SingleByteWrapper::codepage_from_label(codepage)?) {
+            Ok(()) => Ok(None),
+            Err(error) => Ok(Some((error.offset(), error.kind().as_str())))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf8")]
+    pub fn to_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, codepage: &str, replacement: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match SingleByte::to_utf8(bytes.as_bytes(), SingleByteWrapper::codepage_from_label(codepage)?, SingleByteWrapper::policy_from_flag(replacement)) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16")]
+    pub fn to_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, codepage: &str, endian: &Bound<'py, PyBool>, replacement: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match SingleByte::to_utf16(
+            bytes.as_bytes(),
+            SingleByteWrapper::codepage_from_label(codepage)?,
+            endian.extract::<bool>().expect("[SingleByte | to_utf16_ffi | ERROR]: Can't extract endian"),
+            SingleByteWrapper::policy_from_flag(replacement)
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16_with")]
+    pub fn to_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, codepage: &str, endian: &str, replacement: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match SingleByte::to_utf16_with(
+            bytes.as_bytes(),
+            SingleByteWrapper::codepage_from_label(codepage)?,
+            SearchOptionsWrapper::endian_from_label(endian)?,
+            SingleByteWrapper::policy_from_flag(replacement)
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf8")]
+    pub fn from_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, codepage: &str, replacement: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match SingleByte::from_utf8(bytes.as_bytes(), SingleByteWrapper::codepage_from_label(codepage)?, SingleByteWrapper::policy_from_flag(replacement)) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16")]
+    pub fn from_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, codepage: &str, endian: &Bound<'py, PyBool>, replacement: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match SingleByte::from_utf16(
+            bytes.as_bytes(),
+            SingleByteWrapper::codepage_from_label(codepage)?,
+            endian.extract::<bool>().expect("[SingleByte | from_utf16_ffi | ERROR]: Can't extract endian"),
+            SingleByteWrapper::policy_from_flag(replacement)
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16_with")]
+    pub fn from_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, codepage: &str, endian: &str, replacement: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match SingleByte::from_utf16_with(
+            bytes.as_bytes(),
+            SingleByteWrapper::codepage_from_label(codepage)?,
+            SearchOptionsWrapper::endian_from_label(endian)?,
+            SingleByteWrapper::policy_from_flag(replacement)
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
     }
 
     #[staticmethod]
     #[pyo3(name = "search_pattern")]
     pub fn search_pattern_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> Vec<usize> {
-        return UTF8::search_pattern(
+        return SingleByte::search_pattern(
             bytes.as_bytes(),
             pattern_bytes.as_bytes(),
-            overlapping.extract::<bool>().expect("[UTF-8 | search_pattern_ffi | ERROR]: Can't extract overlapping"),
-            all_matches.extract::<bool>().expect("[UTF-8 | search_pattern_ffi | ERROR]: Can't extract all_matches"),
+            overlapping.extract::<bool>().expect("[SingleByte | search_pattern_ffi | ERROR]: Can't extract overlapping"),
+            all_matches.extract::<bool>().expect("[SingleByte | search_pattern_ffi | ERROR]: Can't extract all_matches"),
             if limit.is_instance_of::<PyNone>() { None }
-            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[UTF-8 | search_pattern_ffi | ERROR]: Can't extract limit")) } else { None } }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[SingleByte | search_pattern_ffi | ERROR]: Can't extract limit")) } else { None } }
         )
     }
+
+    #[staticmethod]
+    #[pyo3(name = "search")]
+    pub fn search_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, options: PyRef<'_, SearchOptionsWrapper>) -> Vec<usize> {
+        return SingleByte::search(bytes.as_bytes(), pattern_bytes.as_bytes(), options.options);
+    }
 }
 
-#[pyclass(name="UTF16")]
-struct UTF16Wrapper;
+#[pyclass(name="SHIFTJIS")]
+struct SHIFTJISWrapper;
 
 #[pymethods]
-impl UTF16Wrapper {
+impl SHIFTJISWrapper {
 
     #[staticmethod]
-    #[pyo3(name = "is_utf16")]
-    pub fn is_utf16_ffi(bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>, omp: &Bound<'_, PyBool>, only: &Bound<'_, PyBool>) -> bool {
-        return UTF16::is_utf16_from_byte_array(
+    #[pyo3(name = "is_shift_jis")]
+    pub fn is_shift_jis_ffi(bytes: &Bound<'_, PyBytes>) -> bool {
+        return SHIFTJIS::is_shift_jis(bytes.as_bytes());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "validate_shift_jis")]
+    pub fn validate_shift_jis_ffi(bytes: &Bound<'_, PyBytes>) -> Option<(usize, &'static str)> {
+        return match SHIFTJIS::validate_shift_jis(bytes.as_bytes()) {
+            Ok(()) => None,
+            Err(error) => Some((error.offset(), error.kind().as_str()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf8")]
+    pub fn to_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match SHIFTJIS::to_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16")]
+    pub fn to_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match SHIFTJIS::to_utf16(
             bytes.as_bytes(),
-            endian.extract::<bool>().expect("[UTF-16 | is_utf16_ffi | ERROR]: Can't extract endian"),
-            omp.extract::<bool>().expect("[UTF-16 | is_utf16_ffi | ERROR]: Can't extract omp"),
-            only.extract::<bool>().expect("[UTF-16 | is_utf16_ffi | ERROR]: Can't extract only"));
+            endian.extract::<bool>().expect("[Shift_JIS | to_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16_with")]
+    pub fn to_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match SHIFTJIS::to_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf8")]
+    pub fn from_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match SHIFTJIS::from_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16")]
+    pub fn from_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match SHIFTJIS::from_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[Shift_JIS | from_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16_with")]
+    pub fn from_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match SHIFTJIS::from_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
     }
 
     #[staticmethod]
     #[pyo3(name = "search_pattern")]
-    pub fn search_pattern_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, omp: &Bound<'_, PyBool>, only: &Bound<'_, PyBool>, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> Vec<usize> {
-        return UTF16::search_pattern(
+    pub fn search_pattern_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> Vec<usize> {
+        return SHIFTJIS::search_pattern(
             bytes.as_bytes(),
             pattern_bytes.as_bytes(),
-            omp.extract::<bool>().expect("[UTF-16 | is_utf16_ffi | ERROR]: Can't extract omp"),
-            only.extract::<bool>().expect("[UTF-16 | is_utf16_ffi | ERROR]: Can't extract only"),
-            overlapping.extract::<bool>().expect("[UTF-16 | search_pattern_ffi | ERROR]: Can't extract overlapping"),
-            all_matches.extract::<bool>().expect("[UTF-16 | search_pattern_ffi | ERROR]: Can't extract all_matches"),
+            overlapping.extract::<bool>().expect("[Shift_JIS | search_pattern_ffi | ERROR]: Can't extract overlapping"),
+            all_matches.extract::<bool>().expect("[Shift_JIS | search_pattern_ffi | ERROR]: Can't extract all_matches"),
             if limit.is_instance_of::<PyNone>() { None }
-            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[UTF-16 | search_pattern_ffi | ERROR]: Can't extract limit")) } else { None } }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[Shift_JIS | search_pattern_ffi | ERROR]: Can't extract limit")) } else { None } }
         )
     }
+
+    #[staticmethod]
+    #[pyo3(name = "search")]
+    pub fn search_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, options: PyRef<'_, SearchOptionsWrapper>) -> Vec<usize> {
+        return SHIFTJIS::search(bytes.as_bytes(), pattern_bytes.as_bytes(), options.options);
+    }
 }
 
-#[pyclass(name="UTF32")]
-struct UTF32Wrapper;
+#[pyclass(name="EUCJP")]
+struct EUCJPWrapper;
 
 #[pymethods]
-impl UTF32Wrapper {
+impl EUCJPWrapper {
 
     #[staticmethod]
-    #[pyo3(name = "is_utf32")]
-    pub fn is_utf32_ffi(bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>) -> bool {
-        return UTF32::is_utf32_from_byte_array(
+    #[pyo3(name = "is_euc_jp")]
+    pub fn is_euc_jp_ffi(bytes: &Bound<'_, PyBytes>) -> bool {
+        return EUCJP::is_euc_jp(bytes.as_bytes());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "validate_euc_jp")]
+    pub fn validate_euc_jp_ffi(bytes: &Bound<'_, PyBytes>) -> Option<(usize, &'static str)> {
+        return match EUCJP::validate_euc_jp(bytes.as_bytes()) {
+            Ok(()) => None,
+            Err(error) => Some((error.offset(), error.kind().as_str()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf8")]
+    pub fn to_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match EUCJP::to_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16")]
+    pub fn to_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match EUCJP::to_utf16(
             bytes.as_bytes(),
-            endian.extract::<bool>().expect("[UTF-32 | is_utf32_ffi | ERROR]: Can't extract endian")
-        );
+            endian.extract::<bool>().expect("[EUC-JP | to_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16_with")]
+    pub fn to_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match EUCJP::to_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf8")]
+    pub fn from_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match EUCJP::from_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16")]
+    pub fn from_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match EUCJP::from_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[EUC-JP | from_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16_with")]
+    pub fn from_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match EUCJP::from_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
     }
 
     #[staticmethod]
     #[pyo3(name = "search_pattern")]
     pub fn search_pattern_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> Vec<usize> {
-        return UTF32::search_pattern(
+        return EUCJP::search_pattern(
             bytes.as_bytes(),
             pattern_bytes.as_bytes(),
-            overlapping.extract::<bool>().expect("[UTF-32 | search_pattern_ffi | ERROR]: Can't extract overlapping"),
-            all_matches.extract::<bool>().expect("[UTF-32 | search_pattern_ffi | ERROR]: Can't extract all_matches"),
+            overlapping.extract::<bool>().expect("[EUC-JP | search_pattern_ffi | ERROR]: Can't extract overlapping"),
+            all_matches.extract::<bool>().expect("[EUC-JP | search_pattern_ffi | ERROR]: Can't extract all_matches"),
             if limit.is_instance_of::<PyNone>() { None }
-            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[UTF-32 | search_pattern_ffi | ERROR]: Can't extract limit")) } else { None } }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[EUC-JP | search_pattern_ffi | ERROR]: Can't extract limit")) } else { None } }
         )
     }
+
+    #[staticmethod]
+    #[pyo3(name = "search")]
+    pub fn search_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, options: PyRef<'_, SearchOptionsWrapper>) -> Vec<usize> {
+        return EUCJP::search(bytes.as_bytes(), pattern_bytes.as_bytes(), options.options);
+    }
+}
+
+#[pyclass(name="ISO2022JP")]
+struct ISO2022JPWrapper;
+
+#[pymethods]
+impl ISO2022JPWrapper {
+
+    #[staticmethod]
+    #[pyo3(name = "is_iso2022_jp")]
+    pub fn is_iso2022_jp_ffi(bytes: &Bound<'_, PyBytes>) -> bool {
+        return ISO2022JP::is_iso2022_jp(bytes.as_bytes());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "validate_iso2022_jp")]
+    pub fn validate_iso2022_jp_ffi(bytes: &Bound<'_, PyBytes>) -> Option<(usize, &'static str)> {
+        return match ISO2022JP::validate_iso2022_jp(bytes.as_bytes()) {
+            Ok(()) => None,
+            Err(error) => Some((error.offset(), error.kind().as_str()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf8")]
+    pub fn to_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match ISO2022JP::to_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16")]
+    pub fn to_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match ISO2022JP::to_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[ISO-2022-JP | to_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16_with")]
+    pub fn to_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match ISO2022JP::to_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf8")]
+    pub fn from_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match ISO2022JP::from_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16")]
+    pub fn from_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match ISO2022JP::from_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[ISO-2022-JP | from_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16_with")]
+    pub fn from_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match ISO2022JP::from_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search_pattern")]
+    pub fn search_pattern_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> Vec<usize> {
+        return ISO2022JP::search_pattern(
+            bytes.as_bytes(),
+            pattern_bytes.as_bytes(),
+            overlapping.extract::<bool>().expect("[ISO-2022-JP | search_pattern_ffi | ERROR]: Can't extract overlapping"),
+            all_matches.extract::<bool>().expect("[ISO-2022-JP | search_pattern_ffi | ERROR]: Can't extract all_matches"),
+            if limit.is_instance_of::<PyNone>() { None }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[ISO-2022-JP | search_pattern_ffi | ERROR]: Can't extract limit")) } else { None } }
+        )
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search")]
+    pub fn search_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, options: PyRef<'_, SearchOptionsWrapper>) -> Vec<usize> {
+        return ISO2022JP::search(bytes.as_bytes(), pattern_bytes.as_bytes(), options.options);
+    }
+}
+
+#[pyclass(name="GB18030")]
+struct GB18030Wrapper;
+
+#[pymethods]
+impl GB18030Wrapper {
+
+    #[staticmethod]
+    #[pyo3(name = "is_gb18030")]
+    pub fn is_gb18030_ffi(bytes: &Bound<'_, PyBytes>) -> bool {
+        return GB18030::is_gb18030(bytes.as_bytes());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "validate_gb18030")]
+    pub fn validate_gb18030_ffi(bytes: &Bound<'_, PyBytes>) -> Option<(usize, &'static str)> {
+        return match GB18030::validate_gb18030(bytes.as_bytes()) {
+            Ok(()) => None,
+            Err(error) => Some((error.offset(), error.kind().as_str()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf8")]
+    pub fn to_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match GB18030::to_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16")]
+    pub fn to_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match GB18030::to_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[GB18030 | to_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16_with")]
+    pub fn to_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match GB18030::to_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf8")]
+    pub fn from_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match GB18030::from_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16")]
+    pub fn from_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match GB18030::from_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[GB18030 | from_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16_with")]
+    pub fn from_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match GB18030::from_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search_pattern")]
+    pub fn search_pattern_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> Vec<usize> {
+        return GB18030::search_pattern(
+            bytes.as_bytes(),
+            pattern_bytes.as_bytes(),
+            overlapping.extract::<bool>().expect("[GB18030 | search_pattern_ffi | ERROR]: Can't extract overlapping"),
+            all_matches.extract::<bool>().expect("[GB18030 | search_pattern_ffi | ERROR]: Can't extract all_matches"),
+            if limit.is_instance_of::<PyNone>() { None }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[GB18030 | search_pattern_ffi | ERROR]: Can't extract limit")) } else { None } }
+        )
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search")]
+    pub fn search_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, options: PyRef<'_, SearchOptionsWrapper>) -> Vec<usize> {
+        return GB18030::search(bytes.as_bytes(), pattern_bytes.as_bytes(), options.options);
+    }
+}
+
+#[pyclass(name="GBK")]
+struct GBKWrapper;
+
+#[pymethods]
+impl GBKWrapper {
+
+    #[staticmethod]
+    #[pyo3(name = "is_gbk")]
+    pub fn is_gbk_ffi(bytes: &Bound<'_, PyBytes>) -> bool {
+        return GBK::is_gbk(bytes.as_bytes());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "validate_gbk")]
+    pub fn validate_gbk_ffi(bytes: &Bound<'_, PyBytes>) -> Option<(usize, &'static str)> {
+        return match GBK::validate_gbk(bytes.as_bytes()) {
+            Ok(()) => None,
+            Err(error) => Some((error.offset(), error.kind().as_str()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf8")]
+    pub fn to_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match GBK::to_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16")]
+    pub fn to_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match GBK::to_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[GBK | to_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16_with")]
+    pub fn to_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match GBK::to_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf8")]
+    pub fn from_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match GBK::from_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16")]
+    pub fn from_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match GBK::from_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[GBK | from_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16_with")]
+    pub fn from_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match GBK::from_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search_pattern")]
+    pub fn search_pattern_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> Vec<usize> {
+        return GBK::search_pattern(
+            bytes.as_bytes(),
+            pattern_bytes.as_bytes(),
+            overlapping.extract::<bool>().expect("[GBK | search_pattern_ffi | ERROR]: Can't extract overlapping"),
+            all_matches.extract::<bool>().expect("[GBK | search_pattern_ffi | ERROR]: Can't extract all_matches"),
+            if limit.is_instance_of::<PyNone>() { None }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[GBK | search_pattern_ffi | ERROR]: Can't extract limit")) } else { None } }
+        )
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search")]
+    pub fn search_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, options: PyRef<'_, SearchOptionsWrapper>) -> Vec<usize> {
+        return GBK::search(bytes.as_bytes(), pattern_bytes.as_bytes(), options.options);
+    }
+}
+
+#[pyclass(name="BIG5")]
+struct BIG5Wrapper;
+
+#[pymethods]
+impl BIG5Wrapper {
+
+    #[staticmethod]
+    #[pyo3(name = "is_big5")]
+    pub fn is_big5_ffi(bytes: &Bound<'_, PyBytes>) -> bool {
+        return BIG5::is_big5(bytes.as_bytes());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "validate_big5")]
+    pub fn validate_big5_ffi(bytes: &Bound<'_, PyBytes>) -> Option<(usize, &'static str)> {
+        return match BIG5::validate_big5(bytes.as_bytes()) {
+            Ok(()) => None,
+            Err(error) => Some((error.offset(), error.kind().as_str()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf8")]
+    pub fn to_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match BIG5::to_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16")]
+    pub fn to_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match BIG5::to_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[Big5 | to_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16_with")]
+    pub fn to_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match BIG5::to_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf8")]
+    pub fn from_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match BIG5::from_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16")]
+    pub fn from_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match BIG5::from_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[Big5 | from_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16_with")]
+    pub fn from_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match BIG5::from_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search_pattern")]
+    pub fn search_pattern_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> Vec<usize> {
+        return BIG5::search_pattern(
+            bytes.as_bytes(),
+            pattern_bytes.as_bytes(),
+            overlapping.extract::<bool>().expect("[Big5 | search_pattern_ffi | ERROR]: Can't extract overlapping"),
+            all_matches.extract::<bool>().expect("[Big5 | search_pattern_ffi | ERROR]: Can't extract all_matches"),
+            if limit.is_instance_of::<PyNone>() { None }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[Big5 | search_pattern_ffi | ERROR]: Can't extract limit")) } else { None } }
+        )
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search")]
+    pub fn search_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, options: PyRef<'_, SearchOptionsWrapper>) -> Vec<usize> {
+        return BIG5::search(bytes.as_bytes(), pattern_bytes.as_bytes(), options.options);
+    }
+}
+
+
+#[pyclass(name="EUCKR")]
+struct EUCKRWrapper;
+
+impl EUCKRWrapper {
+    fn policy_from_flag(replacement: &Bound<'_, PyBool>) -> Policy {
+        return if replacement.extract::<bool>().expect("[EUC-KR | policy_from_flag | ERROR]: Can't extract replacement") { Policy::Replacement } else { Policy::Error };
+    }
+}
+
+#[pymethods]
+impl EUCKRWrapper {
+
+    #[staticmethod]
+    #[pyo3(name = "is_euc_kr")]
+    pub fn is_euc_kr_ffi(bytes: &Bound<'_, PyBytes>) -> bool {
+        return EUCKR::is_euc_kr(bytes.as_bytes());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "validate_euc_kr")]
+    pub fn validate_euc_kr_ffi(bytes: &Bound<'_, PyBytes>) -> Option<(usize, &'static str)> {
+        return match EUCKR::validate_euc_kr(bytes.as_bytes()) {
+            Ok(()) => None,
+            Err(error) => Some((error.offset(), error.kind().as_str()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf8")]
+    pub fn to_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, replacement: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match EUCKR::to_utf8(bytes.as_bytes(), EUCKRWrapper::policy_from_flag(replacement)) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16")]
+    pub fn to_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>, replacement: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match EUCKR::to_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[EUC-KR | to_utf16_ffi | ERROR]: Can't extract endian"),
+            EUCKRWrapper::policy_from_flag(replacement)
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16_with")]
+    pub fn to_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str, replacement: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match EUCKR::to_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?,
+            EUCKRWrapper::policy_from_flag(replacement)
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf8")]
+    pub fn from_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, replacement: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match EUCKR::from_utf8(bytes.as_bytes(), EUCKRWrapper::policy_from_flag(replacement)) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16")]
+    pub fn from_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>, replacement: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match EUCKR::from_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[EUC-KR | from_utf16_ffi | ERROR]: Can't extract endian"),
+            EUCKRWrapper::policy_from_flag(replacement)
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16_with")]
+    pub fn from_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str, replacement: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match EUCKR::from_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?,
+            EUCKRWrapper::policy_from_flag(replacement)
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search_pattern")]
+    pub fn search_pattern_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> Vec<usize> {
+        return EUCKR::search_pattern(
+            bytes.as_bytes(),
+            pattern_bytes.as_bytes(),
+            overlapping.extract::<bool>().expect("[EUC-KR | search_pattern_ffi | ERROR]: Can't extract overlapping"),
+            all_matches.extract::<bool>().expect("[EUC-KR | search_pattern_ffi | ERROR]: Can't extract all_matches"),
+            if limit.is_instance_of::<PyNone>() { None }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[EUC-KR | search_pattern_ffi | ERROR]: Can't extract limit")) } else { None } }
+        )
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search")]
+    pub fn search_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, options: PyRef<'_, SearchOptionsWrapper>) -> Vec<usize> {
+        return EUCKR::search(bytes.as_bytes(), pattern_bytes.as_bytes(), options.options);
+    }
+}
+
+
+#[pyclass(name="CESU8")]
+struct CESU8Wrapper;
+
+#[pymethods]
+impl CESU8Wrapper {
+
+    #[staticmethod]
+    #[pyo3(name = "is_cesu8")]
+    pub fn is_cesu8_ffi(bytes: &Bound<'_, PyBytes>) -> bool {
+        return CESU8::is_cesu8(bytes.as_bytes());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "validate_cesu8")]
+    pub fn validate_cesu8_ffi(bytes: &Bound<'_, PyBytes>) -> Option<(usize, &'static str)> {
+        return match CESU8::validate_cesu8(bytes.as_bytes()) {
+            Ok(()) => None,
+            Err(error) => Some((error.offset(), error.kind().as_str()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf8")]
+    pub fn to_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match CESU8::to_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16")]
+    pub fn to_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match CESU8::to_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[CESU-8 | to_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16_with")]
+    pub fn to_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match CESU8::to_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf8")]
+    pub fn from_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match CESU8::from_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16")]
+    pub fn from_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match CESU8::from_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[CESU-8 | from_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16_with")]
+    pub fn from_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match CESU8::from_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+}
+
+#[pyclass(name="MUTF8")]
+struct MUTF8Wrapper;
+
+#[pymethods]
+impl MUTF8Wrapper {
+
+    #[staticmethod]
+    #[pyo3(name = "is_mutf8")]
+    pub fn is_mutf8_ffi(bytes: &Bound<'_, PyBytes>) -> bool {
+        return MUTF8::is_mutf8(bytes.as_bytes());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "validate_mutf8")]
+    pub fn validate_mutf8_ffi(bytes: &Bound<'_, PyBytes>) -> Option<(usize, &'static str)> {
+        return match MUTF8::validate_mutf8(bytes.as_bytes()) {
+            Ok(()) => None,
+            Err(error) => Some((error.offset(), error.kind().as_str()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf8")]
+    pub fn to_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match MUTF8::to_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16")]
+    pub fn to_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match MUTF8::to_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[MUTF-8 | to_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16_with")]
+    pub fn to_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match MUTF8::to_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf8")]
+    pub fn from_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match MUTF8::from_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16")]
+    pub fn from_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match MUTF8::from_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[MUTF-8 | from_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16_with")]
+    pub fn from_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match MUTF8::from_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+}
+
+#[pyclass(name="WTF8")]
+struct WTF8Wrapper;
+
+impl WTF8Wrapper {
+    fn policy_from_flag(replacement: &Bound<'_, PyBool>) -> Policy {
+        return if replacement.extract::<bool>().expect("[WTF-8 | policy_from_flag | ERROR]: Can't extract replacement") { Policy::Replacement } else { Policy::Error };
+    }
+}
+
+#[pymethods]
+impl WTF8Wrapper {
+
+    #[staticmethod]
+    #[pyo3(name = "is_wtf8")]
+    pub fn is_wtf8_ffi(bytes: &Bound<'_, PyBytes>) -> bool {
+        return WTF8::is_wtf8(bytes.as_bytes());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "validate_wtf8")]
+    pub fn validate_wtf8_ffi(bytes: &Bound<'_, PyBytes>) -> Option<(usize, &'static str)> {
+        return match WTF8::validate_wtf8(bytes.as_bytes()) {
+            Ok(()) => None,
+            Err(error) => Some((error.offset(), error.kind().as_str()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf8")]
+    pub fn to_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, replacement: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match WTF8::to_utf8(bytes.as_bytes(), WTF8Wrapper::policy_from_flag(replacement)) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16")]
+    pub fn to_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match WTF8::to_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[WTF-8 | to_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16_with")]
+    pub fn to_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match WTF8::to_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf8")]
+    pub fn from_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyBytes>> {
+        return match WTF8::from_utf8(bytes.as_bytes()) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16")]
+    pub fn from_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match WTF8::from_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[WTF-8 | from_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16_with")]
+    pub fn from_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match WTF8::from_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+}
+
+#[pyclass(name="UTF7")]
+struct UTF7Wrapper;
+
+impl UTF7Wrapper {
+    fn variant_from_label(label: &str) -> PyResult<Utf7Variant> {
+        return match Utf7Variant::from_label(label) {
+            Some(variant) => Ok(variant),
+            None => Err(PyValueError::new_err(format!("unknown UTF-7 variant label: {}", label)))
+        };
+    }
+}
+
+#[pymethods]
+impl UTF7Wrapper {
+
+    #[staticmethod]
+    #[pyo3(name = "is_utf7")]
+    pub fn is_utf7_ffi(bytes: &Bound<'_, PyBytes>, variant: &str) -> PyResult<bool> {
+        return Ok(UTF7::is_utf7(bytes.as_bytes(), UTF7Wrapper::variant_from_label(variant)?));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "validate_utf7")]
+    pub fn validate_utf7_ffi(bytes: &Bound<'_, PyBytes>, variant: &str) -> PyResult<Option<(usize, &'static str)>> {
+        return match UTF7::validate_utf7(bytes.as_bytes(), UTF7Wrapper::variant_from_label(variant)?) {
+            Ok(()) => Ok(None),
+            Err(error) => Ok(Some((error.offset(), error.kind().as_str())))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf8")]
+    pub fn to_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, variant: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF7::to_utf8(bytes.as_bytes(), UTF7Wrapper::variant_from_label(variant)?) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16")]
+    pub fn to_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>, variant: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF7::to_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-7 | to_utf16_ffi | ERROR]: Can't extract endian"),
+            UTF7Wrapper::variant_from_label(variant)?
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16_with")]
+    pub fn to_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str, variant: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF7::to_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?,
+            UTF7Wrapper::variant_from_label(variant)?
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf8")]
+    pub fn from_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, variant: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF7::from_utf8(bytes.as_bytes(), UTF7Wrapper::variant_from_label(variant)?) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16")]
+    pub fn from_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>, variant: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF7::from_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-7 | from_utf16_ffi | ERROR]: Can't extract endian"),
+            UTF7Wrapper::variant_from_label(variant)?
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf16_with")]
+    pub fn from_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str, variant: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF7::from_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?,
+            UTF7Wrapper::variant_from_label(variant)?
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+}
+
+#[pyclass(name="EBCDIC")]
+struct EBCDICWrapper;
+
+impl EBCDICWrapper {
+    fn codepage_from_label(label: &str) -> PyResult<EbcdicCodepage> {
+        return match EbcdicCodepage::from_label(label) {
+            Some(codepage) => Ok(codepage),
+            None => Err(PyValueError::new_err(format!("unknown EBCDIC codepage label: {}", label)))
+        };
+    }
+
+    fn newline_from_label(label: &str) -> PyResult<Newline> {
+        return match Newline::from_label(label) {
+            Some(newline) => Ok(newline),
+            None => Err(PyValueError::new_err(format!("unknown newline label: {}", label)))
+        };
+    }
+}
+
+#[pymethods]
+impl EBCDICWrapper {
+
+    #[staticmethod]
+    #[pyo3(name = "is_ebcdic_text")]
+    pub fn is_ebcdic_text_ffi(bytes: &Bound<'_, PyBytes>, codepage: &str) -> PyResult<bool> {
+        return Ok(EBCDIC::is_ebcdic_text(bytes.as_bytes(), EBCDICWrapper::codepage_from_label(codepage)?));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf8")]
+    pub fn to_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, codepage: &str, newline: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return Ok(PyBytes::new(python, &EBCDIC::to_utf8(bytes.as_bytes(), EBCDICWrapper::codepage_from_label(codepage)?, EBCDICWrapper::newline_from_label(newline)?)));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_ascii")]
+    pub fn to_ascii_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, codepage: &str, newline: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match EBCDIC::to_ascii(bytes.as_bytes(), EBCDICWrapper::codepage_from_label(codepage)?, EBCDICWrapper::newline_from_label(newline)?) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_utf8")]
+    pub fn from_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, codepage: &str, newline: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match EBCDIC::from_utf8(bytes.as_bytes(), EBCDICWrapper::codepage_from_label(codepage)?, EBCDICWrapper::newline_from_label(newline)?) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_ascii")]
+    pub fn from_ascii_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, codepage: &str, newline: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match EBCDIC::from_ascii(bytes.as_bytes(), EBCDICWrapper::codepage_from_label(codepage)?, EBCDICWrapper::newline_from_label(newline)?) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+}
+
+#[pyclass(name="UTF8")]
+struct UTF8Wrapper;
+
+#[pymethods]
+impl UTF8Wrapper {
+
+    #[staticmethod]
+    #[pyo3(name = "is_utf8")]
+    pub fn is_utf8_ffi(bytes: &Bound<'_, PyBytes>) -> bool {
+        return UTF8::is_utf8(bytes.as_bytes());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "validate_utf8")]
+    pub fn validate_utf8_ffi(bytes: &Bound<'_, PyBytes>) -> Option<(usize, &'static str)> {
+        return match UTF8::validate_utf8(bytes.as_bytes()) {
+            Ok(()) => None,
+            Err(error) => Some((error.offset(), error.kind().as_str()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16")]
+    pub fn to_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF8::to_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-8 | to_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16_with")]
+    pub fn to_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF8::to_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf32")]
+    pub fn to_utf32_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF8::to_utf32(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-8 | to_utf32_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(codes) => Ok(PyBytes::new(python, &codes.iter().flat_map(|code| code.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf32_with")]
+    pub fn to_utf32_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF8::to_utf32_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(codes) => Ok(PyBytes::new(python, &codes.iter().flat_map(|code| code.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_string_lossy")]
+    pub fn to_string_lossy_ffi(bytes: &Bound<'_, PyBytes>) -> String {
+        return UTF8::to_string_lossy(bytes.as_bytes());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "count_chars")]
+    pub fn count_chars_ffi(bytes: &Bound<'_, PyBytes>) -> usize {
+        return UTF8::count_chars(bytes.as_bytes());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "stats")]
+    pub fn stats_ffi(bytes: &Bound<'_, PyBytes>) -> (usize, usize, usize, usize) {
+        let statistics: Statistics = UTF8::stats(bytes.as_bytes());
+
+        return (statistics.ascii(), statistics.bmp(), statistics.supplementary(), statistics.surrogates());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "utf16_length_from_utf8")]
+    pub fn utf16_length_from_utf8_ffi(bytes: &Bound<'_, PyBytes>) -> usize {
+        return UTF8::utf16_length_from_utf8(bytes.as_bytes());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "utf32_length_from_utf8")]
+    pub fn utf32_length_from_utf8_ffi(bytes: &Bound<'_, PyBytes>) -> usize {
+        return UTF8::utf32_length_from_utf8(bytes.as_bytes());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search_pattern")]
+    pub fn search_pattern_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> Vec<usize> {
+        return UTF8::search_pattern(
+            bytes.as_bytes(),
+            pattern_bytes.as_bytes(),
+            overlapping.extract::<bool>().expect("[UTF-8 | search_pattern_ffi | ERROR]: Can't extract overlapping"),
+            all_matches.extract::<bool>().expect("[UTF-8 | search_pattern_ffi | ERROR]: Can't extract all_matches"),
+            if limit.is_instance_of::<PyNone>() { None }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[UTF-8 | search_pattern_ffi | ERROR]: Can't extract limit")) } else { None } }
+        )
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search_pattern_case_folded")]
+    pub fn search_pattern_case_folded_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> Vec<usize> {
+        return UTF8::search_pattern_case_folded(
+            bytes.as_bytes(),
+            pattern_bytes.as_bytes(),
+            overlapping.extract::<bool>().expect("[UTF-8 | search_pattern_case_folded_ffi | ERROR]: Can't extract overlapping"),
+            all_matches.extract::<bool>().expect("[UTF-8 | search_pattern_case_folded_ffi | ERROR]: Can't extract all_matches"),
+            if limit.is_instance_of::<PyNone>() { None }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[UTF-8 | search_pattern_case_folded_ffi | ERROR]: Can't extract limit")) } else { None } }
+        )
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search")]
+    pub fn search_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, options: PyRef<'_, SearchOptionsWrapper>) -> Vec<usize> {
+        return UTF8::search(bytes.as_bytes(), pattern_bytes.as_bytes(), options.options);
+    }
+}
+
+#[pyclass(name="UTF16")]
+struct UTF16Wrapper;
+
+#[pymethods]
+impl UTF16Wrapper {
+
+    #[staticmethod]
+    #[pyo3(name = "is_utf16_with")]
+    pub fn is_utf16_with_ffi(bytes: &Bound<'_, PyBytes>, endian: &str, planes: &str) -> PyResult<bool> {
+        return Ok(UTF16::is_utf16_with(bytes.as_bytes(), SearchOptionsWrapper::endian_from_label(endian)?, SearchOptionsWrapper::planes_from_label(planes)?));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "validate_utf16_with")]
+    pub fn validate_utf16_with_ffi(bytes: &Bound<'_, PyBytes>, endian: &str, planes: &str) -> PyResult<Option<(usize, &'static str)>> {
+        return match UTF16::validate_utf16_with(bytes.as_bytes(), SearchOptionsWrapper::endian_from_label(endian)?, SearchOptionsWrapper::planes_from_label(planes)?) {
+            Ok(()) => Ok(None),
+            Err(error) => Ok(Some((error.offset(), error.kind().as_str())))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "is_utf16")]
+    pub fn is_utf16_ffi(bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>, omp: &Bound<'_, PyBool>, only: &Bound<'_, PyBool>) -> bool {
+        return UTF16::is_utf16_from_byte_array(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-16 | is_utf16_ffi | ERROR]: Can't extract endian"),
+            omp.extract::<bool>().expect("[UTF-16 | is_utf16_ffi | ERROR]: Can't extract omp"),
+            only.extract::<bool>().expect("[UTF-16 | is_utf16_ffi | ERROR]: Can't extract only"));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "validate_utf16")]
+    pub fn validate_utf16_ffi(bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>, omp: &Bound<'_, PyBool>, only: &Bound<'_, PyBool>) -> Option<(usize, &'static str)> {
+        return match UTF16::validate_utf16_from_byte_array(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-16 | validate_utf16_ffi | ERROR]: Can't extract endian"),
+            omp.extract::<bool>().expect("[UTF-16 | validate_utf16_ffi | ERROR]: Can't extract omp"),
+            only.extract::<bool>().expect("[UTF-16 | validate_utf16_ffi | ERROR]: Can't extract only")
+        ) {
+            Ok(()) => None,
+            Err(error) => Some((error.offset(), error.kind().as_str()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf8")]
+    pub fn to_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF16::to_utf8(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-16 | to_utf8_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf8_with")]
+    pub fn to_utf8_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF16::to_utf8_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf32")]
+    pub fn to_utf32_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF16::to_utf32(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-16 | to_utf32_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(codes) => Ok(PyBytes::new(python, &codes.iter().flat_map(|code| code.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf32_with")]
+    pub fn to_utf32_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF16::to_utf32_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(codes) => Ok(PyBytes::new(python, &codes.iter().flat_map(|code| code.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_string_lossy")]
+    pub fn to_string_lossy_ffi(bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>) -> String {
+        return UTF16::to_string_lossy(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-16 | to_string_lossy_ffi | ERROR]: Can't extract endian")
+        );
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_string_lossy_with")]
+    pub fn to_string_lossy_with_ffi(bytes: &Bound<'_, PyBytes>, endian: &str) -> PyResult<String> {
+        return Ok(UTF16::to_string_lossy_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "lone_surrogates")]
+    pub fn lone_surrogates_ffi(bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>) -> PyResult<Vec<usize>> {
+        return match UTF16::lone_surrogates(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-16 | lone_surrogates_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(indices) => Ok(indices),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "lone_surrogates_with")]
+    pub fn lone_surrogates_with_ffi(bytes: &Bound<'_, PyBytes>, endian: &str) -> PyResult<Vec<usize>> {
+        return match UTF16::lone_surrogates_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(indices) => Ok(indices),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_well_formed")]
+    pub fn to_well_formed_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF16::to_well_formed(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-16 | to_well_formed_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_well_formed_with")]
+    pub fn to_well_formed_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF16::to_well_formed_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "count_chars")]
+    pub fn count_chars_ffi(bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>) -> usize {
+        return UTF16::count_chars(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-16 | count_chars_ffi | ERROR]: Can't extract endian")
+        );
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "count_chars_with")]
+    pub fn count_chars_with_ffi(bytes: &Bound<'_, PyBytes>, endian: &str) -> PyResult<usize> {
+        return Ok(UTF16::count_chars_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "stats")]
+    pub fn stats_ffi(bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>) -> (usize, usize, usize, usize) {
+        let statistics: Statistics = UTF16::stats(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-16 | stats_ffi | ERROR]: Can't extract endian")
+        );
+
+        return (statistics.ascii(), statistics.bmp(), statistics.supplementary(), statistics.surrogates());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "stats_with")]
+    pub fn stats_with_ffi(bytes: &Bound<'_, PyBytes>, endian: &str) -> PyResult<(usize, usize, usize, usize)> {
+        let statistics: Statistics = UTF16::stats_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        );
+
+        return Ok((statistics.ascii(), statistics.bmp(), statistics.supplementary(), statistics.surrogates()));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "utf8_length_from_utf16")]
+    pub fn utf8_length_from_utf16_ffi(bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>) -> usize {
+        return UTF16::utf8_length_from_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-16 | utf8_length_from_utf16_ffi | ERROR]: Can't extract endian")
+        );
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "utf8_length_from_utf16_with")]
+    pub fn utf8_length_from_utf16_with_ffi(bytes: &Bound<'_, PyBytes>, endian: &str) -> PyResult<usize> {
+        return Ok(UTF16::utf8_length_from_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "utf32_length_from_utf16")]
+    pub fn utf32_length_from_utf16_ffi(bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>) -> usize {
+        return UTF16::utf32_length_from_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-16 | utf32_length_from_utf16_ffi | ERROR]: Can't extract endian")
+        );
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "utf32_length_from_utf16_with")]
+    pub fn utf32_length_from_utf16_with_ffi(bytes: &Bound<'_, PyBytes>, endian: &str) -> PyResult<usize> {
+        return Ok(UTF16::utf32_length_from_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search_pattern")]
+    pub fn search_pattern_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, omp: &Bound<'_, PyBool>, only: &Bound<'_, PyBool>, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> Vec<usize> {
+        return UTF16::search_pattern(
+            bytes.as_bytes(),
+            pattern_bytes.as_bytes(),
+            omp.extract::<bool>().expect("[UTF-16 | is_utf16_ffi | ERROR]: Can't extract omp"),
+            only.extract::<bool>().expect("[UTF-16 | is_utf16_ffi | ERROR]: Can't extract only"),
+            overlapping.extract::<bool>().expect("[UTF-16 | search_pattern_ffi | ERROR]: Can't extract overlapping"),
+            all_matches.extract::<bool>().expect("[UTF-16 | search_pattern_ffi | ERROR]: Can't extract all_matches"),
+            if limit.is_instance_of::<PyNone>() { None }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[UTF-16 | search_pattern_ffi | ERROR]: Can't extract limit")) } else { None } }
+        )
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search_pattern_case_folded")]
+    pub fn search_pattern_case_folded_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> Vec<usize> {
+        return UTF16::search_pattern_case_folded(
+            bytes.as_bytes(),
+            pattern_bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-16 | search_pattern_case_folded_ffi | ERROR]: Can't extract endian"),
+            overlapping.extract::<bool>().expect("[UTF-16 | search_pattern_case_folded_ffi | ERROR]: Can't extract overlapping"),
+            all_matches.extract::<bool>().expect("[UTF-16 | search_pattern_case_folded_ffi | ERROR]: Can't extract all_matches"),
+            if limit.is_instance_of::<PyNone>() { None }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[UTF-16 | search_pattern_case_folded_ffi | ERROR]: Can't extract limit")) } else { None } }
+        )
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search_pattern_case_folded_with")]
+    pub fn search_pattern_case_folded_with_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, endian: &str, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> PyResult<Vec<usize>> {
+        return Ok(UTF16::search_pattern_case_folded_with(
+            bytes.as_bytes(),
+            pattern_bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?,
+            overlapping.extract::<bool>().expect("[UTF-16 | search_pattern_case_folded_with_ffi | ERROR]: Can't extract overlapping"),
+            all_matches.extract::<bool>().expect("[UTF-16 | search_pattern_case_folded_with_ffi | ERROR]: Can't extract all_matches"),
+            if limit.is_instance_of::<PyNone>() { None }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[UTF-16 | search_pattern_case_folded_with_ffi | ERROR]: Can't extract limit")) } else { None } }
+        ));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search")]
+    pub fn search_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, options: PyRef<'_, SearchOptionsWrapper>) -> Vec<usize> {
+        return UTF16::search(bytes.as_bytes(), pattern_bytes.as_bytes(), options.options);
+    }
+}
+
+#[pyclass(name="UTF32")]
+struct UTF32Wrapper;
+
+#[pymethods]
+impl UTF32Wrapper {
+
+    #[staticmethod]
+    #[pyo3(name = "is_utf32_with")]
+    pub fn is_utf32_with_ffi(bytes: &Bound<'_, PyBytes>, endian: &str) -> PyResult<bool> {
+        return Ok(UTF32::is_utf32_with(bytes.as_bytes(), SearchOptionsWrapper::endian_from_label(endian)?));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "validate_utf32_with")]
+    pub fn validate_utf32_with_ffi(bytes: &Bound<'_, PyBytes>, endian: &str) -> PyResult<Option<(usize, &'static str)>> {
+        return match UTF32::validate_utf32_with(bytes.as_bytes(), SearchOptionsWrapper::endian_from_label(endian)?) {
+            Ok(()) => Ok(None),
+            Err(error) => Ok(Some((error.offset(), error.kind().as_str())))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "is_utf32")]
+    pub fn is_utf32_ffi(bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>) -> bool {
+        return UTF32::is_utf32_from_byte_array(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-32 | is_utf32_ffi | ERROR]: Can't extract endian")
+        );
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "validate_utf32")]
+    pub fn validate_utf32_ffi(bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>) -> Option<(usize, &'static str)> {
+        return match UTF32::validate_utf32_from_byte_array(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-32 | validate_utf32_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(()) => None,
+            Err(error) => Some((error.offset(), error.kind().as_str()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf8")]
+    pub fn to_utf8_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF32::to_utf8(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-32 | to_utf8_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf8_with")]
+    pub fn to_utf8_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF32::to_utf8_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(array) => Ok(PyBytes::new(python, &array)),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16")]
+    pub fn to_utf16_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &Bound<'py, PyBool>) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF32::to_utf16(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-32 | to_utf16_ffi | ERROR]: Can't extract endian")
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_utf16_with")]
+    pub fn to_utf16_with_ffi<'py>(python: Python<'py>, bytes: &Bound<'py, PyBytes>, endian: &str) -> PyResult<Bound<'py, PyBytes>> {
+        return match UTF32::to_utf16_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ) {
+            Ok(units) => Ok(PyBytes::new(python, &units.iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>())),
+            Err(error) => Err(PyValueError::new_err(error.to_string()))
+        };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_string_lossy")]
+    pub fn to_string_lossy_ffi(bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>) -> String {
+        return UTF32::to_string_lossy(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-32 | to_string_lossy_ffi | ERROR]: Can't extract endian")
+        );
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "to_string_lossy_with")]
+    pub fn to_string_lossy_with_ffi(bytes: &Bound<'_, PyBytes>, endian: &str) -> PyResult<String> {
+        return Ok(UTF32::to_string_lossy_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "count_chars")]
+    pub fn count_chars_ffi(bytes: &Bound<'_, PyBytes>) -> usize {
+        return UTF32::count_chars(bytes.as_bytes());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "stats")]
+    pub fn stats_ffi(bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>) -> (usize, usize, usize, usize) {
+        let statistics: Statistics = UTF32::stats(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-32 | stats_ffi | ERROR]: Can't extract endian")
+        );
+
+        return (statistics.ascii(), statistics.bmp(), statistics.supplementary(), statistics.surrogates());
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "stats_with")]
+    pub fn stats_with_ffi(bytes: &Bound<'_, PyBytes>, endian: &str) -> PyResult<(usize, usize, usize, usize)> {
+        let statistics: Statistics = UTF32::stats_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        );
+
+        return Ok((statistics.ascii(), statistics.bmp(), statistics.supplementary(), statistics.surrogates()));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "utf8_length_from_utf32")]
+    pub fn utf8_length_from_utf32_ffi(bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>) -> usize {
+        return UTF32::utf8_length_from_utf32(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-32 | utf8_length_from_utf32_ffi | ERROR]: Can't extract endian")
+        );
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "utf8_length_from_utf32_with")]
+    pub fn utf8_length_from_utf32_with_ffi(bytes: &Bound<'_, PyBytes>, endian: &str) -> PyResult<usize> {
+        return Ok(UTF32::utf8_length_from_utf32_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "utf16_length_from_utf32")]
+    pub fn utf16_length_from_utf32_ffi(bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>) -> usize {
+        return UTF32::utf16_length_from_utf32(
+            bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-32 | utf16_length_from_utf32_ffi | ERROR]: Can't extract endian")
+        );
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "utf16_length_from_utf32_with")]
+    pub fn utf16_length_from_utf32_with_ffi(bytes: &Bound<'_, PyBytes>, endian: &str) -> PyResult<usize> {
+        return Ok(UTF32::utf16_length_from_utf32_with(
+            bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?
+        ));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search_pattern")]
+    pub fn search_pattern_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> Vec<usize> {
+        return UTF32::search_pattern(
+            bytes.as_bytes(),
+            pattern_bytes.as_bytes(),
+            overlapping.extract::<bool>().expect("[UTF-32 | search_pattern_ffi | ERROR]: Can't extract overlapping"),
+            all_matches.extract::<bool>().expect("[UTF-32 | search_pattern_ffi | ERROR]: Can't extract all_matches"),
+            if limit.is_instance_of::<PyNone>() { None }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[UTF-32 | search_pattern_ffi | ERROR]: Can't extract limit")) } else { None } }
+        )
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search_pattern_case_folded")]
+    pub fn search_pattern_case_folded_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, endian: &Bound<'_, PyBool>, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> Vec<usize> {
+        return UTF32::search_pattern_case_folded(
+            bytes.as_bytes(),
+            pattern_bytes.as_bytes(),
+            endian.extract::<bool>().expect("[UTF-32 | search_pattern_case_folded_ffi | ERROR]: Can't extract endian"),
+            overlapping.extract::<bool>().expect("[UTF-32 | search_pattern_case_folded_ffi | ERROR]: Can't extract overlapping"),
+            all_matches.extract::<bool>().expect("[UTF-32 | search_pattern_case_folded_ffi | ERROR]: Can't extract all_matches"),
+            if limit.is_instance_of::<PyNone>() { None }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[UTF-32 | search_pattern_case_folded_ffi | ERROR]: Can't extract limit")) } else { None } }
+        )
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search_pattern_case_folded_with")]
+    pub fn search_pattern_case_folded_with_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, endian: &str, overlapping: &Bound<'_, PyBool>, all_matches: &Bound<'_, PyBool>, limit: &Bound<'_, PyAny>) -> PyResult<Vec<usize>> {
+        return Ok(UTF32::search_pattern_case_folded_with(
+            bytes.as_bytes(),
+            pattern_bytes.as_bytes(),
+            SearchOptionsWrapper::endian_from_label(endian)?,
+            overlapping.extract::<bool>().expect("[UTF-32 | search_pattern_case_folded_with_ffi | ERROR]: Can't extract overlapping"),
+            all_matches.extract::<bool>().expect("[UTF-32 | search_pattern_case_folded_with_ffi | ERROR]: Can't extract all_matches"),
+            if limit.is_instance_of::<PyNone>() { None }
+            else { if limit.is_instance_of::<PyInt>() { Some(limit.extract::<usize>().expect("[UTF-32 | search_pattern_case_folded_with_ffi | ERROR]: Can't extract limit")) } else { None } }
+        ));
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "search")]
+    pub fn search_ffi(bytes: &Bound<'_, PyBytes>, pattern_bytes: &Bound<'_, PyBytes>, options: PyRef<'_, SearchOptionsWrapper>) -> Vec<usize> {
+        return UTF32::search(bytes.as_bytes(), pattern_bytes.as_bytes(), options.options);
+    }
+}
+
+#[pyclass(name="Utf8Validator")]
+struct Utf8ValidatorWrapper {
+    validator: Utf8Validator
+}
+
+#[pymethods]
+impl Utf8ValidatorWrapper {
+
+    #[new]
+    pub fn new_ffi() -> Utf8ValidatorWrapper {
+        return Utf8ValidatorWrapper { validator: Utf8Validator::new() };
+    }
+
+    #[pyo3(name = "feed")]
+    pub fn feed_ffi(&mut self, bytes: &Bound<'_, PyBytes>) -> PyResult<()> {
+        return self.validator.feed(bytes.as_bytes()).map_err(|error| PyValueError::new_err(error.to_string()));
+    }
+
+    #[pyo3(name = "finish")]
+    pub fn finish_ffi(&self) -> PyResult<()> {
+        return self.validator.clone().finish().map_err(|error| PyValueError::new_err(error.to_string()));
+    }
+}
+
+#[pyclass(name="Utf16Validator")]
+struct Utf16ValidatorWrapper {
+    validator: Utf16Validator
+}
+
+#[pymethods]
+impl Utf16ValidatorWrapper {
+
+    #[new]
+    pub fn new_ffi(endian: &Bound<'_, PyBool>, omp: &Bound<'_, PyBool>, only: &Bound<'_, PyBool>) -> Utf16ValidatorWrapper {
+        return Utf16ValidatorWrapper { validator: Utf16Validator::new(
+            endian.extract::<bool>().expect("[UTF-16 | Utf16Validator::new_ffi | ERROR]: Can't extract endian"),
+            omp.extract::<bool>().expect("[UTF-16 | Utf16Validator::new_ffi | ERROR]: Can't extract omp"),
+            only.extract::<bool>().expect("[UTF-16 | Utf16Validator::new_ffi | ERROR]: Can't extract only")
+        ) };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "with_options")]
+    pub fn with_options_ffi(endian: &str, planes: &str) -> PyResult<Utf16ValidatorWrapper> {
+        return Ok(Utf16ValidatorWrapper { validator: Utf16Validator::with(SearchOptionsWrapper::endian_from_label(endian)?, SearchOptionsWrapper::planes_from_label(planes)?) });
+    }
+
+    #[pyo3(name = "feed")]
+    pub fn feed_ffi(&mut self, bytes: &Bound<'_, PyBytes>) -> PyResult<()> {
+        return self.validator.feed(bytes.as_bytes()).map_err(|error| PyValueError::new_err(error.to_string()));
+    }
+
+    #[pyo3(name = "finish")]
+    pub fn finish_ffi(&self) -> PyResult<()> {
+        return self.validator.clone().finish().map_err(|error| PyValueError::new_err(error.to_string()));
+    }
+}
+
+#[pyclass(name="Utf32Validator")]
+struct Utf32ValidatorWrapper {
+    validator: Utf32Validator
+}
+
+#[pymethods]
+impl Utf32ValidatorWrapper {
+
+    #[new]
+    pub fn new_ffi(endian: &Bound<'_, PyBool>) -> Utf32ValidatorWrapper {
+        return Utf32ValidatorWrapper { validator: Utf32Validator::new(
+            endian.extract::<bool>().expect("[UTF-32 | Utf32Validator::new_ffi | ERROR]: Can't extract endian")
+        ) };
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "with_options")]
+    pub fn with_options_ffi(endian: &str) -> PyResult<Utf32ValidatorWrapper> {
+        return Ok(Utf32ValidatorWrapper { validator: Utf32Validator::with(SearchOptionsWrapper::endian_from_label(endian)?) });
+    }
+
+    #[pyo3(name = "feed")]
+    pub fn feed_ffi(&mut self, bytes: &Bound<'_, PyBytes>) -> PyResult<()> {
+        return self.validator.feed(bytes.as_bytes()).map_err(|error| PyValueError::new_err(error.to_string()));
+    }
+
+    #[pyo3(name = "finish")]
+    pub fn finish_ffi(&self) -> PyResult<()> {
+        return self.validator.clone().finish().map_err(|error| PyValueError::new_err(error.to_string()));
+    }
+}
+
+#[pyclass(name="Detection")]
+struct DetectionWrapper;
+
+#[pymethods]
+impl DetectionWrapper {
+
+    #[staticmethod]
+    #[pyo3(name = "detect")]
+    pub fn detect_ffi(bytes: &Bound<'_, PyBytes>) -> (&'static str, f32, usize) {
+        let detection: Detection = Detection::detect(bytes.as_bytes());
+
+        return (detection.encoding().as_str(), detection.confidence(), detection.bom_length());
+    }
+}
+
+#[pyclass(name="SimdLevel")]
+struct SimdLevelWrapper;
+
+#[pymethods]
+impl SimdLevelWrapper {
+
+    #[staticmethod]
+    #[pyo3(name = "selected")]
+    pub fn selected_ffi() -> &'static str {
+        return SimdLevel::selected().as_str();
+    }
 }
 
 #[pymodule]
 fn COXave(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<SearchOptionsWrapper>().expect("Class SearchOptions cannot be added!");
     module.add_class::<ASCIIWrapper>().expect("Class ASCII cannot be added!");
+    module.add_class::<ISO8859_1Wrapper>().expect("Class ISO8859_1 cannot be added!");
+    module.add_class::<SingleByteWrapper>().expect("Class SingleByte cannot be added!");
+    module.add_class::<SHIFTJISWrapper>().expect("Class SHIFTJIS cannot be added!");
+    module.add_class::<EUCJPWrapper>().expect("Class EUCJP cannot be added!");
+    module.add_class::<ISO2022JPWrapper>().expect("Class ISO2022JP cannot be added!");
+    module.add_class::<GB18030Wrapper>().expect("Class GB18030 cannot be added!");
+    module.add_class::<GBKWrapper>().expect("Class GBK cannot be added!");
+    module.add_class::<BIG5Wrapper>().expect("Class BIG5 cannot be added!");
+    module.add_class::<EUCKRWrapper>().expect("Class EUCKR cannot be added!");
+    module.add_class::<CESU8Wrapper>().expect("Class CESU8 cannot be added!");
+    module.add_class::<MUTF8Wrapper>().expect("Class MUTF8 cannot be added!");
+    module.add_class::<WTF8Wrapper>().expect("Class WTF8 cannot be added!");
+    module.add_class::<UTF7Wrapper>().expect("Class UTF7 cannot be added!");
+    module.add_class::<EBCDICWrapper>().expect("Class EBCDIC cannot be added!");
     module.add_class::<UTF8Wrapper>().expect("Class UTF8 cannot be added!");
     module.add_class::<UTF16Wrapper>().expect("Class UTF16 cannot be added!");
     module.add_class::<UTF32Wrapper>().expect("Class UTF32 cannot be added!");
+    module.add_class::<Utf8ValidatorWrapper>().expect("Class Utf8Validator cannot be added!");
+    module.add_class::<Utf16ValidatorWrapper>().expect("Class Utf16Validator cannot be added!");
+    module.add_class::<Utf32ValidatorWrapper>().expect("Class Utf32Validator cannot be added!");
+    module.add_class::<DetectionWrapper>().expect("Class Detection cannot be added!");
+    module.add_class::<SimdLevelWrapper>().expect("Class SimdLevel cannot be added!");
 
     return Ok(());
 }
\ No newline at end of file
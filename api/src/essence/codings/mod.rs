@@ -27,11 +27,35 @@
  */
 
 mod ascii;
+mod iso8859_1;
+mod single_byte;
+mod japanese;
+mod chinese;
+mod korean;
+mod variants;
+mod utf7;
+mod ebcdic;
+mod options;
 mod utf8;
 mod utf16;
 mod utf32;
+mod error;
+mod detection;
+mod statistics;
 
 pub use ascii::{ASCII};
-pub use utf8::{UTF8};
-pub use utf16::{UTF16};
-pub use utf32::{UTF32};
+pub use iso8859_1::{ISO8859_1};
+pub use single_byte::{SingleByte, Codepage};
+pub use japanese::{SHIFTJIS, EUCJP, ISO2022JP};
+pub use chinese::{GB18030, GBK, BIG5};
+pub use korean::{EUCKR};
+pub use variants::{CESU8, MUTF8, WTF8};
+pub use utf7::{UTF7, Utf7Variant, Utf7State};
+pub use ebcdic::{EBCDIC, EbcdicCodepage, Newline};
+pub use options::{Endian, Utf16Planes, SearchOptions};
+pub use utf8::{UTF8, Utf8Validator};
+pub use utf16::{UTF16, Utf16Validator};
+pub use utf32::{UTF32, Utf32Validator};
+pub use error::{EncodingError, EncodingErrorKind, Policy};
+pub use detection::{Encoding, Detection};
+pub use statistics::{Statistics};
@@ -26,4 +26,21 @@
  * THE SOFTWARE.
  */
 
+use crate::{
+    essence::{
+        EncodingError
+    }
+};
+
 pub struct UTF16;
+
+#[derive(Debug, Clone)]
+pub struct Utf16Validator {
+    pub(crate) pending: [u8; 4_usize],
+    pub(crate) pending_length: usize,
+    pub(crate) consumed: usize,
+    pub(crate) endian: bool,
+    pub(crate) omp: bool,
+    pub(crate) only: bool,
+    pub(crate) error: Option<EncodingError>
+}
@@ -26,4 +26,19 @@
  * THE SOFTWARE.
  */
 
+use crate::{
+    essence::{
+        EncodingError
+    }
+};
+
 pub struct UTF32;
+
+#[derive(Debug, Clone)]
+pub struct Utf32Validator {
+    pub(crate) pending: [u8; 4_usize],
+    pub(crate) pending_length: usize,
+    pub(crate) consumed: usize,
+    pub(crate) endian: bool,
+    pub(crate) error: Option<EncodingError>
+}
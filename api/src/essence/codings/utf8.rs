@@ -26,4 +26,18 @@
  * THE SOFTWARE.
  */
 
+use crate::{
+    essence::{
+        EncodingError
+    }
+};
+
 pub struct UTF8;
+
+#[derive(Debug, Clone)]
+pub struct Utf8Validator {
+    pub(crate) pending: [u8; 4_usize],
+    pub(crate) pending_length: usize,
+    pub(crate) consumed: usize,
+    pub(crate) error: Option<EncodingError>
+}
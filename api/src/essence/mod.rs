@@ -28,14 +28,44 @@
 
 mod codings;
 mod search;
+mod dispatch;
 
 pub use codings::{
     ASCII,
-    UTF8, UTF16, UTF32
+    ISO8859_1,
+    SingleByte, Codepage,
+    SHIFTJIS, EUCJP, ISO2022JP,
+    GB18030, GBK, BIG5,
+    EUCKR,
+    CESU8, MUTF8, WTF8,
+    UTF7, Utf7Variant, Utf7State,
+    EBCDIC, EbcdicCodepage, Newline,
+    Endian, Utf16Planes, SearchOptions,
+    UTF8, UTF16, UTF32,
+    Utf8Validator, Utf16Validator, Utf32Validator,
+    EncodingError, EncodingErrorKind, Policy,
+    Encoding, Detection,
+    Statistics
 };
 
 pub use search::{
     byte::{
-        ByteSearch
+        ByteSearch,
+        ByteMatches,
+        ByteMatchesRev
+    },
+    multi::{
+        MultiSearch,
+        MultiSearcher
+    },
+    finder::{
+        Finder,
+        FinderMatches
+    }
+};
+
+pub use dispatch::{
+    level::{
+        SimdLevel
     }
 };
@@ -35,3 +35,19 @@ use core::{
 pub struct ByteSearch<T> {
     __marker: PhantomData<T>
 }
+
+pub struct ByteMatches<'a, T> {
+    pub(crate) array: &'a [u8],
+    pub(crate) pattern: &'a [u8],
+    pub(crate) position: usize,
+    pub(crate) overlapping: bool,
+    pub(crate) __marker: PhantomData<T>
+}
+
+pub struct ByteMatchesRev<'a, T> {
+    pub(crate) array: &'a [u8],
+    pub(crate) pattern: &'a [u8],
+    pub(crate) end: usize,
+    pub(crate) overlapping: bool,
+    pub(crate) __marker: PhantomData<T>
+}
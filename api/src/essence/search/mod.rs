@@ -27,3 +27,5 @@
  */
 
 pub mod byte;
+pub mod multi;
+pub mod finder;
@@ -31,7 +31,8 @@ mod universal;
 #[cfg(feature = "universal")]
 pub use universal::{
     codings,
-    search
+    search,
+    dispatch
 };
 
 #[cfg(all(
@@ -46,7 +47,8 @@ mod platform;
 ))]
 pub use platform::{
     codings,
-    search
+    search,
+    dispatch
 };
 
 #[cfg(all(
@@ -55,4 +57,5 @@ pub use platform::{
 ))]
 pub use universal::{
     codings as non_simd_codings,
+    search as non_simd_search
 };
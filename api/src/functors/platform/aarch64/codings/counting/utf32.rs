@@ -0,0 +1,89 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF32,
+        Statistics
+    }
+};
+
+use core::{
+    arch::{
+        aarch64::{
+            uint32x4_t,
+            vld1q_u8, vrev32q_u8, vreinterpretq_u32_u8,
+            vdupq_n_u32, vcltq_u32,
+            vshrq_n_u32, vaddvq_u32
+        }
+    }
+};
+
+impl UTF32 {
+    fn count_classes_neon(array: &[u8], endian: bool) -> (usize, usize, usize) {
+        let (length, mut index, swap): (usize, usize, bool) = (array.len() - array.len() % 4_usize, 0_usize, endian == cfg!(target_endian = "big"));
+
+        let (mut ascii, mut narrow, mut bmp): (usize, usize, usize) = (0_usize, 0_usize, 0_usize);
+
+        let (ascii_bound, narrow_bound, bmp_bound): (uint32x4_t, uint32x4_t, uint32x4_t) = unsafe { (vdupq_n_u32(0x00000080), vdupq_n_u32(0x00000800), vdupq_n_u32(0x00010000)) };
+
+        while index + 16_usize <= length {
+            let value: uint32x4_t = if swap {
+                unsafe { vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(array.as_ptr().add(index)))) }
+            } else {
+                unsafe { vreinterpretq_u32_u8(vld1q_u8(array.as_ptr().add(index))) }
+            };
+
+            unsafe {
+                ascii += vaddvq_u32(vshrq_n_u32(vcltq_u32(value, ascii_bound), 31)) as usize;
+                narrow += vaddvq_u32(vshrq_n_u32(vcltq_u32(value, narrow_bound), 31)) as usize;
+                bmp += vaddvq_u32(vshrq_n_u32(vcltq_u32(value, bmp_bound), 31)) as usize;
+            }
+
+            index += 16_usize;
+        }
+
+        let (tail_ascii, tail_narrow, tail_bmp): (usize, usize, usize) = UTF32::count_classes_from(array, index, length, endian);
+
+        return (ascii + tail_ascii, narrow + tail_narrow, bmp + tail_bmp);
+    }
+
+    pub fn stats(array: &[u8], endian: bool) -> Statistics {
+        return UTF32::statistics_from(array.len() / 4_usize, UTF32::count_classes_neon(array, endian));
+    }
+
+    pub fn utf8_length_from_utf32(array: &[u8], endian: bool) -> usize {
+        let (ascii, narrow, bmp): (usize, usize, usize) = UTF32::count_classes_neon(array, endian);
+
+        return array.len() / 4_usize * 4_usize - ascii - narrow - bmp;
+    }
+
+    pub fn utf16_length_from_utf32(array: &[u8], endian: bool) -> usize {
+        return array.len() / 4_usize * 2_usize - UTF32::count_classes_neon(array, endian).2;
+    }
+}
@@ -0,0 +1,140 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF16
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+use core::{
+    arch::{
+        aarch64::{
+            uint16x8_t, uint32x4_t,
+            vld1q_u8, vst1_u8, vst1q_u32,
+            vrev16q_u8, vrev32q_u8,
+            vreinterpretq_u16_u8, vreinterpretq_u8_u32, vreinterpretq_u32_u8,
+            vget_low_u16, vget_high_u16,
+            vmovl_u16,
+            vmovn_u16,
+            vmaxvq_u16
+        }
+    }
+};
+
+impl UTF16 {
+    pub fn to_utf8_into(array: &[u8], output: &mut [u8], endian: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 2_usize, output.len());
+
+        let (mut read, mut written, swap): (usize, usize, bool) = (0_usize, 0_usize, endian == cfg!(target_endian = "big"));
+
+        while read + 16_usize <= length {
+            let value: uint16x8_t = if swap {
+                unsafe { vreinterpretq_u16_u8(vrev16q_u8(vld1q_u8(array.as_ptr().add(read)))) }
+            } else {
+                unsafe { vreinterpretq_u16_u8(vld1q_u8(array.as_ptr().add(read))) }
+            };
+
+            if unsafe { vmaxvq_u16(value) } < 0x80 {
+                if written + 8_usize > capacity { break; }
+
+                unsafe { vst1_u8(output.as_mut_ptr().add(written), vmovn_u16(value)) };
+
+                read += 16_usize; written += 8_usize;
+            } else if UTF16::is_utf16_bmp_16x8(unsafe { array.as_ptr().add(read) }, 16_usize, endian) {
+                if written + 24_usize > capacity { break; }
+
+                written = UTF16::transcode_bmp_utf8_from(array, read, read + 16_usize, output, written, endian);
+
+                read += 16_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = UTF16::transcode_utf8_from(array, read, read + 16_usize, output, written, endian)?;
+
+                if next_read < read + 16_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return UTF16::transcode_utf8_from(array, read, length, output, written, endian);
+    }
+
+    fn to_utf32_into_neon(array: &[u8], output: &mut [u32], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 2_usize, output.len());
+
+        let (mut read, mut written, swap): (usize, usize, bool) = (0_usize, 0_usize, endian == cfg!(target_endian = "big"));
+
+        while read + 16_usize <= length && written + 8_usize <= capacity {
+            if UTF16::is_utf16_bmp_16x8(unsafe { array.as_ptr().add(read) }, 16_usize, endian) {
+                let value: uint16x8_t = if swap {
+                    unsafe { vreinterpretq_u16_u8(vrev16q_u8(vld1q_u8(array.as_ptr().add(read)))) }
+                } else {
+                    unsafe { vreinterpretq_u16_u8(vld1q_u8(array.as_ptr().add(read))) }
+                };
+
+                let (low, high): (uint32x4_t, uint32x4_t) = unsafe { (vmovl_u16(vget_low_u16(value)), vmovl_u16(vget_high_u16(value))) };
+
+                let (low, high): (uint32x4_t, uint32x4_t) = if swap {
+                    unsafe { (vreinterpretq_u32_u8(vrev32q_u8(vreinterpretq_u8_u32(low))), vreinterpretq_u32_u8(vrev32q_u8(vreinterpretq_u8_u32(high)))) }
+                } else {
+                    (low, high)
+                };
+
+                unsafe {
+                    vst1q_u32(output.as_mut_ptr().add(written), low);
+                    vst1q_u32(output.as_mut_ptr().add(written + 4_usize), high);
+                }
+
+                read += 16_usize; written += 8_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = UTF16::transcode_utf32_from(array, read, read + 16_usize, output, written, endian, checked)?;
+
+                if next_read < read + 16_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return UTF16::transcode_utf32_from(array, read, length, output, written, endian, checked);
+    }
+
+    pub fn to_utf32_into(array: &[u8], output: &mut [u32], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return UTF16::to_utf32_into_neon(array, output, endian, true);
+    }
+
+    pub fn to_utf32_into_unchecked(array: &[u8], output: &mut [u32], endian: bool) -> (usize, usize) {
+        return UTF16::to_utf32_into_neon(array, output, endian, false).unwrap_or_default();
+    }
+}
@@ -0,0 +1,162 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF32
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+use core::{
+    arch::{
+        aarch64::{
+            uint16x8_t, uint32x4_t,
+            vld1q_u8, vst1q_u8, vst1q_u16,
+            vrev16q_u8, vrev32q_u8,
+            vreinterpretq_u8_u16, vreinterpretq_u16_u8, vreinterpretq_u32_u8,
+            vorrq_u32,
+            vmovn_u16, vmovn_u32,
+            vcombine_u8, vcombine_u16,
+            vmaxvq_u32
+        }
+    }
+};
+
+impl UTF32 {
+    fn load_code_points(array: &[u8], read: usize, swap: bool) -> [uint32x4_t; 4_usize] {
+        let mut block: [uint32x4_t; 4_usize] = unsafe { [vreinterpretq_u32_u8(vld1q_u8(array.as_ptr().add(read))); 4_usize] };
+
+        let mut index: usize = 0_usize;
+
+        while index < 4_usize {
+            block[index] = if swap {
+                unsafe { vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(array.as_ptr().add(read + index * 16_usize)))) }
+            } else {
+                unsafe { vreinterpretq_u32_u8(vld1q_u8(array.as_ptr().add(read + index * 16_usize))) }
+            };
+
+            index += 1_usize;
+        }
+
+        return block;
+    }
+
+    fn to_utf8_into_neon(array: &[u8], output: &mut [u8], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 4_usize, output.len());
+
+        let (mut read, mut written, swap): (usize, usize, bool) = (0_usize, 0_usize, endian == cfg!(target_endian = "big"));
+
+        while read + 64_usize <= length {
+            if checked && !UTF32::is_utf32_32x4(unsafe { array.as_ptr().add(read) }, 64_usize, endian) { break; }
+
+            let block: [uint32x4_t; 4_usize] = UTF32::load_code_points(array, read, swap);
+
+            if unsafe { vmaxvq_u32(vorrq_u32(vorrq_u32(block[0], block[1]), vorrq_u32(block[2], block[3]))) } < 0x80 {
+                if written + 16_usize > capacity { break; }
+
+                unsafe {
+                    vst1q_u8(
+                        output.as_mut_ptr().add(written),
+                        vcombine_u8(
+                            vmovn_u16(vcombine_u16(vmovn_u32(block[0]), vmovn_u32(block[1]))),
+                            vmovn_u16(vcombine_u16(vmovn_u32(block[2]), vmovn_u32(block[3])))
+                        )
+                    )
+                };
+
+                read += 64_usize; written += 16_usize;
+            } else {
+                if written + 64_usize > capacity { break; }
+
+                (read, written) = UTF32::transcode_utf8_from(array, read, read + 64_usize, output, written, endian, false)?;
+            }
+        }
+
+        return UTF32::transcode_utf8_from(array, read, length, output, written, endian, checked);
+    }
+
+    fn to_utf16_into_neon(array: &[u8], output: &mut [u16], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 4_usize, output.len());
+
+        let (mut read, mut written, swap): (usize, usize, bool) = (0_usize, 0_usize, endian == cfg!(target_endian = "big"));
+
+        while read + 64_usize <= length {
+            if checked && !UTF32::is_utf32_32x4(unsafe { array.as_ptr().add(read) }, 64_usize, endian) { break; }
+
+            let block: [uint32x4_t; 4_usize] = UTF32::load_code_points(array, read, swap);
+
+            if unsafe { vmaxvq_u32(vorrq_u32(vorrq_u32(block[0], block[1]), vorrq_u32(block[2], block[3]))) } < 0x10000 {
+                if written + 16_usize > capacity { break; }
+
+                let (low, high): (uint16x8_t, uint16x8_t) = unsafe { (vcombine_u16(vmovn_u32(block[0]), vmovn_u32(block[1])), vcombine_u16(vmovn_u32(block[2]), vmovn_u32(block[3]))) };
+
+                let (low, high): (uint16x8_t, uint16x8_t) = if swap {
+                    unsafe { (vreinterpretq_u16_u8(vrev16q_u8(vreinterpretq_u8_u16(low))), vreinterpretq_u16_u8(vrev16q_u8(vreinterpretq_u8_u16(high)))) }
+                } else {
+                    (low, high)
+                };
+
+                unsafe {
+                    vst1q_u16(output.as_mut_ptr().add(written), low);
+                    vst1q_u16(output.as_mut_ptr().add(written + 8_usize), high);
+                }
+
+                read += 64_usize; written += 16_usize;
+            } else {
+                if written + 32_usize > capacity { break; }
+
+                (read, written) = UTF32::transcode_utf16_from(array, read, read + 64_usize, output, written, endian, false)?;
+            }
+        }
+
+        return UTF32::transcode_utf16_from(array, read, length, output, written, endian, checked);
+    }
+
+    pub fn to_utf8_into(array: &[u8], output: &mut [u8], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return UTF32::to_utf8_into_neon(array, output, endian, true);
+    }
+
+    pub fn to_utf8_into_unchecked(array: &[u8], output: &mut [u8], endian: bool) -> (usize, usize) {
+        return UTF32::to_utf8_into_neon(array, output, endian, false).unwrap_or_default();
+    }
+
+    pub fn to_utf16_into(array: &[u8], output: &mut [u16], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return UTF32::to_utf16_into_neon(array, output, endian, true);
+    }
+
+    pub fn to_utf16_into_unchecked(array: &[u8], output: &mut [u16], endian: bool) -> (usize, usize) {
+        return UTF32::to_utf16_into_neon(array, output, endian, false).unwrap_or_default();
+    }
+}
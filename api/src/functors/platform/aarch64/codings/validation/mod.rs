@@ -27,9 +27,11 @@
  */
 
 mod ascii;
+mod utf8;
 mod utf32;
 mod utf16;
 
 pub use ascii::{ASCII};
+pub use utf8::{UTF8};
 pub use utf16::{UTF16};
 pub use utf32::{UTF32};
@@ -32,6 +32,13 @@ pub use crate::{
     }
 };
 
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
 use core::{
     mem::{
         transmute
@@ -59,6 +66,7 @@ impl UTF16 {
 
     const __ENCODING_REGULAR_PAIR_BYTES:   usize = 2_usize;
     const __ENCODING_SURROGATE_PAIR_BYTES: usize = 4_usize;
+    const __VALIDATION_WINDOW_BYTES:       usize = 65536_usize;
 
     fn is_utf16_bmp_16x4(array: *const u8, length: usize, endian: bool) -> bool {
         let mut offset: usize = 0_usize;
@@ -95,7 +103,7 @@ impl UTF16 {
         return true;
     }
 
-    fn is_utf16_bmp_16x8(array: *const u8, length: usize, endian: bool) -> bool {
+    pub(crate) fn is_utf16_bmp_16x8(array: *const u8, length: usize, endian: bool) -> bool {
         let mut offset: usize = 0_usize;
 
         let (bad_range_mask, bad_result_mask): (uint16x8_t, uint16x8_t) =
@@ -425,6 +433,26 @@ impl UTF16 {
         return true;
     }
 
+    pub(crate) fn surrogate_free_prefix(array: &[u8], endian: bool) -> usize {
+        let mut index: usize = 0_usize;
+
+        let (part_of_surrogate_mask, surrogate_mask): (uint16x8_t, uint16x8_t) = if endian == cfg!(target_endian = "little") {
+            unsafe { (vdupq_n_u16(0xF800), vdupq_n_u16(0xD800)) }
+        } else {
+            unsafe { (vdupq_n_u16(0x00F8), vdupq_n_u16(0x00D8)) }
+        };
+
+        while index + 16_usize <= array.len() {
+            let value: uint16x8_t = unsafe { vreinterpretq_u16_u8(vld1q_u8(array.as_ptr().add(index))) };
+
+            if unsafe { vmaxvq_u16(vceqq_u16(vandq_u16(value, part_of_surrogate_mask), surrogate_mask)) != 0_u16 } { break; }
+
+            index += 16_usize;
+        }
+
+        return index;
+    }
+
     pub fn is_utf16_from_byte_array(array: &[u8], endian: bool, omp: bool, only: bool) -> bool {
 
         let length: usize = array.len();
@@ -552,4 +580,37 @@ impl UTF16 {
 
         return result;
     }
+
+    pub fn validate_utf16_from_byte_array(array: &[u8], endian: bool, omp: bool, only: bool) -> Result<(), EncodingError> {
+        let length: usize = array.len();
+
+        if length == 0_usize { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        let aligned_length: usize = if omp && only {
+            length - length % UTF16::__ENCODING_SURROGATE_PAIR_BYTES
+        } else {
+            length - length % UTF16::__ENCODING_REGULAR_PAIR_BYTES
+        };
+
+        let mut start: usize = 0_usize;
+
+        while start < aligned_length {
+            let mut end: usize = if aligned_length - start > UTF16::__VALIDATION_WINDOW_BYTES { start + UTF16::__VALIDATION_WINDOW_BYTES } else { aligned_length };
+
+            if !only && end != aligned_length && ((if endian { array[end - 1_usize] } else { array[end - 2_usize] }) & 0xFC) == 0xD8 {
+                end -= UTF16::__ENCODING_REGULAR_PAIR_BYTES;
+            }
+
+            // The locator reads past the window, so a lead just before a deferred one is judged by its real follower.
+            if !UTF16::is_utf16_from_byte_array(&array[start..end], endian, omp, only) {
+                UTF16::locate_invalid_utf16(&array[start..aligned_length], start, endian, omp, only)?;
+            }
+
+            start = end;
+        }
+
+        if aligned_length != length { return Err(EncodingError::new(aligned_length, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok(());
+    }
 }
\ No newline at end of file
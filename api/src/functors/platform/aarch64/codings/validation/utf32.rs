@@ -32,6 +32,13 @@ pub use crate::{
     }
 };
 
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
 use core::{
     arch::{
         aarch64::{
@@ -52,6 +59,7 @@ use core::{
 impl UTF32 {
 
     const __ENCODING_BYTES: usize = 4_usize;
+    const __VALIDATION_WINDOW_BYTES: usize = 65536_usize;
 
     fn is_utf32_32x2(array: *const u8, length: usize, endian: bool) -> bool {
 
@@ -89,7 +97,7 @@ impl UTF32 {
         return true;
     }
 
-    fn is_utf32_32x4(array: *const u8, length: usize, endian: bool) -> bool {
+    pub(crate) fn is_utf32_32x4(array: *const u8, length: usize, endian: bool) -> bool {
 
         let mut offset: usize = 0_usize;
 
@@ -163,4 +171,28 @@ impl UTF32 {
 
         return result;
     }
+
+    pub fn validate_utf32_from_byte_array(array: &[u8], endian: bool) -> Result<(), EncodingError> {
+        let length: usize = array.len();
+
+        if length == 0_usize { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        let aligned_length: usize = length - length % UTF32::__ENCODING_BYTES;
+
+        let mut start: usize = 0_usize;
+
+        while start < aligned_length {
+            let end: usize = if aligned_length - start > UTF32::__VALIDATION_WINDOW_BYTES { start + UTF32::__VALIDATION_WINDOW_BYTES } else { aligned_length };
+
+            if !UTF32::is_utf32_from_byte_array(&array[start..end], endian) {
+                UTF32::locate_invalid_utf32(&array[start..end], start, endian)?;
+            }
+
+            start = end;
+        }
+
+        if aligned_length != length { return Err(EncodingError::new(aligned_length, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok(());
+    }
 }
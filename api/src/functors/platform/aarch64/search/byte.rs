@@ -28,7 +28,9 @@
 
 pub use crate::{
     essence::{
-        ByteSearch
+        ByteSearch,
+        ByteMatches,
+        ByteMatchesRev
     }
 };
 
@@ -36,6 +38,9 @@ use core::{
     mem::{
         transmute,
     },
+    marker::{
+        PhantomData
+    },
     arch::{
         aarch64::{
             uint8x16_t, uint16x8_t, uint32x4_t,
@@ -44,6 +49,8 @@ use core::{
             vandq_u8, vandq_u16, vandq_u32,
             vmvnq_u8, vmvnq_u16, vmvnq_u32,
             vceqq_u8, vceqq_u16, vceqq_u32,
+            vcgtq_u8, vcgtq_u16, vcgtq_u32,
+            vorrq_u8, vorrq_u16, vorrq_u32,
             vst1q_u8, vst1q_u16, vst1q_u32,
             vmaxvq_u8, vmaxvq_u16, vmaxvq_u32
         }
@@ -51,7 +58,7 @@ use core::{
 };
 
 macro_rules! generate_search {
-    ($t:ty, $t_default:expr, $not_ignore_mask:expr, $t_size:expr, $precision:ty, $register_size:expr, $load:expr, $store:expr, $dup_one_t:expr, $eq_compare:expr, $bitwise_and:expr, $bitwise_not:expr, $vector_max:expr) => {
+    ($t:ty, $t_default:expr, $not_ignore_mask:expr, $t_size:expr, $precision:ty, $register_size:expr, $load:expr, $store:expr, $dup_one_t:expr, $eq_compare:expr, $bitwise_and:expr, $bitwise_not:expr, $vector_max:expr, $greater_than:expr, $bitwise_or:expr) => {
         impl ByteSearch<$t> {
 
             const fn is_search_possible(array: &[$t], pattern: &[$t], limit: Option<usize>) -> (usize, usize, bool) {
@@ -83,7 +90,7 @@ macro_rules! generate_search {
                 };
             }
 
-            pub fn search_single(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize>
+            pub(crate) fn search_first(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Option<usize>
             where $t: Copy + Sized {
 
                 const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
@@ -94,14 +101,12 @@ macro_rules! generate_search {
                     (1_usize * $register_size) / $t_size
                 ];
 
-                let mut search_result: Vec<usize> = Vec::<usize>::new();
-
                 let (array, pattern): (&[$t], &[$t]) = (
                     unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(array_ptr.as_ptr()), array_ptr.len() / $t_size) },
                     unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(pattern_ptr.as_ptr()), pattern_ptr.len() / $t_size) }
                 );
 
-                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return search_result; }
+                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return None; }
 
                 let (mut array_ptr, mut array_index, mut remains_length, mut index_of_match): (*const $t, usize, usize, usize) = (array.as_ptr(), 0_usize, array_length, 0_usize);
 
@@ -131,7 +136,7 @@ macro_rules! generate_search {
                                 for i in 0..4 {
                                     if $vector_max($load(matches[i].as_ptr())) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -158,7 +163,7 @@ macro_rules! generate_search {
                                 for i in 0..3 {
                                     if $vector_max($load(matches[i].as_ptr())) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -183,7 +188,7 @@ macro_rules! generate_search {
                                 for i in 0..2 {
                                     if $vector_max($load(matches[i].as_ptr())) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -201,7 +206,7 @@ macro_rules! generate_search {
 
                                 if $vector_max($load(matches[0].as_ptr())) != zero {
                                     while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                        if matches[0][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                         else { index_of_match += 1_usize; }
                                     }
                                     index_of_match = 0_usize;
@@ -225,7 +230,7 @@ macro_rules! generate_search {
 
                             if $vector_max($load(matches[0].as_ptr())) != zero {
                                 while index_of_match < remains_length {
-                                    if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                    if matches[0][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                     else { index_of_match += 1_usize; }
                                 }
                             }
@@ -264,7 +269,7 @@ macro_rules! generate_search {
                                 for i in 0..4 {
                                     if $vector_max($load(matches[i].as_ptr())) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -299,7 +304,7 @@ macro_rules! generate_search {
                                 for i in 0..3 {
                                     if $vector_max($load(matches[i].as_ptr())) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -329,7 +334,7 @@ macro_rules! generate_search {
                                 for i in 0..2 {
                                     if $vector_max($load(matches[i].as_ptr())) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -353,7 +358,7 @@ macro_rules! generate_search {
 
                                 if $vector_max($load(matches[0].as_ptr())) != zero {
                                     while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                        if matches[0][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                         else { index_of_match += 1_usize; }
                                     }
                                     index_of_match = 0_usize;
@@ -382,7 +387,7 @@ macro_rules! generate_search {
 
                             if $vector_max($load(matches[0].as_ptr())) != zero {
                                 while index_of_match < remains_length {
-                                    if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                    if matches[0][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                     else { index_of_match += 1_usize; }
                                 }
                             }
@@ -424,7 +429,7 @@ macro_rules! generate_search {
                                                         $load(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)),
                                                         $load(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))
                                                     ))) == 0 {
-                                                        search_result.push((array_index + index_of_match) * $t_size); return search_result;
+                                                        return Some((array_index + index_of_match) * $t_size);
                                                     }
                                                 }
                                             }
@@ -454,7 +459,7 @@ macro_rules! generate_search {
                                                     $load(array_ptr.add(index_of_match)),
                                                     pattern_loaded
                                                 ))) == 0 {
-                                                    search_result.push((array_index + index_of_match) * $t_size); return search_result;
+                                                    return Some((array_index + index_of_match) * $t_size);
                                                 }
                                             }
                                             index_of_match += 1_usize;
@@ -486,7 +491,7 @@ macro_rules! generate_search {
                                                 if $vector_max($bitwise_and($bitwise_not($eq_compare(
                                                     $load(array_ptr.add(index_of_match)), pattern_loaded),
                                                 ), pattern_ignore_mask)) == 0 {
-                                                    search_result.push((array_index + index_of_match) * $t_size); return search_result;
+                                                    return Some((array_index + index_of_match) * $t_size);
                                                 }
                                             }
                                             index_of_match += 1_usize;
@@ -530,7 +535,7 @@ macro_rules! generate_search {
                                                     $load(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)),
                                                     $load(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))
                                                 ))) == 0 {
-                                                    search_result.push((array_index + index_of_match) * $t_size); return search_result;
+                                                    return Some((array_index + index_of_match) * $t_size);
                                                 }
                                             }
                                         }
@@ -547,7 +552,7 @@ macro_rules! generate_search {
                                                 $load(array_ptr.add(index_of_match)),
                                                 pattern_loaded
                                             ))) == 0 {
-                                                search_result.push((array_index + index_of_match) * $t_size);
+                                                return Some((array_index + index_of_match) * $t_size);
                                             }
                                         }
                                         index_of_match += 1_usize;
@@ -566,7 +571,7 @@ macro_rules! generate_search {
                                             if $vector_max($bitwise_and($bitwise_not($eq_compare(
                                                 $load(array_ptr.add(index_of_match)), pattern_loaded),
                                             ), pattern_ignore_mask)) == 0 {
-                                                search_result.push((array_index + index_of_match) * $t_size); return search_result;
+                                                return Some((array_index + index_of_match) * $t_size);
                                             }
                                         }
                                         index_of_match += 1_usize;
@@ -577,6 +582,165 @@ macro_rules! generate_search {
                     }
                 }
 
+                return None;
+            }
+
+            pub fn search_single(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize> {
+                return ByteSearch::<$t>::search_first(array_ptr, pattern_ptr, limit).into_iter().collect::<Vec<usize>>();
+            }
+
+            pub(crate) fn search_last_offset(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Option<usize>
+            where $t: Copy + Sized {
+
+                const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
+
+                let (array, pattern): (&[$t], &[$t]) = (
+                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(array_ptr.as_ptr()), array_ptr.len() / $t_size) },
+                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(pattern_ptr.as_ptr()), pattern_ptr.len() / $t_size) }
+                );
+
+                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return None; }
+
+                let (mut remains_length, last_pattern_index): (usize, usize) = (array_length, pattern_length - 1_usize);
+
+                let mut matches: [$t; COUNT_OF_VALUES_IN_REGISTER] = [$t_default; COUNT_OF_VALUES_IN_REGISTER];
+
+                unsafe {
+                    let (first_mask, last_mask): ($precision, $precision) = ($dup_one_t(pattern[0]), $dup_one_t(pattern[last_pattern_index]));
+
+                    while remains_length >= COUNT_OF_VALUES_IN_REGISTER {
+                        let array_index: usize = remains_length - COUNT_OF_VALUES_IN_REGISTER;
+
+                        let candidates: $precision = $bitwise_and(
+                            $eq_compare($load(array.as_ptr().add(array_index)), first_mask),
+                            $eq_compare($load(array.as_ptr().add(array_index + last_pattern_index)), last_mask)
+                        );
+
+                        if $vector_max(candidates) != $t_default {
+                            $store(matches.as_mut_ptr(), candidates);
+
+                            let mut index_of_match: usize = COUNT_OF_VALUES_IN_REGISTER;
+
+                            while index_of_match > 0_usize {
+                                index_of_match -= 1_usize;
+
+                                if matches[index_of_match] != $t_default && array[array_index + index_of_match..array_index + index_of_match + pattern_length] == *pattern { return Some((array_index + index_of_match) * $t_size); }
+                            }
+                        }
+
+                        remains_length = array_index;
+                    }
+                }
+
+                while remains_length > 0_usize {
+                    remains_length -= 1_usize;
+
+                    if array[remains_length..remains_length + pattern_length] == *pattern { return Some(remains_length * $t_size); }
+                }
+
+                return None;
+            }
+
+            pub fn search_last(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize> {
+                return ByteSearch::<$t>::search_last_offset(array_ptr, pattern_ptr, limit).into_iter().collect::<Vec<usize>>();
+            }
+
+            const fn fold_ascii(value: $t) -> $t {
+                return if value > 0x40 && value < 0x5B { value | 0x20 } else { value };
+            }
+
+            fn is_folded_match(array: &[$t], index: usize, pattern: &[$t]) -> bool {
+                return array[index..index + pattern.len()].iter().zip(pattern.iter()).all(|(&value, &expected)| ByteSearch::<$t>::fold_ascii(value) == ByteSearch::<$t>::fold_ascii(expected));
+            }
+
+            pub(crate) fn search_first_ignore_ascii_case(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Option<usize>
+            where $t: Copy + Sized {
+
+                const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
+
+                let (array, pattern): (&[$t], &[$t]) = (
+                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(array_ptr.as_ptr()), array_ptr.len() / $t_size) },
+                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(pattern_ptr.as_ptr()), pattern_ptr.len() / $t_size) }
+                );
+
+                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return None; }
+
+                let (mut array_index, last_pattern_index): (usize, usize) = (0_usize, pattern_length - 1_usize);
+
+                let mut matches: [$t; COUNT_OF_VALUES_IN_REGISTER] = [$t_default; COUNT_OF_VALUES_IN_REGISTER];
+
+                unsafe {
+                    let (lower_bound, upper_bound, case_bit): ($precision, $precision, $precision) = ($dup_one_t(0x40), $dup_one_t(0x5B), $dup_one_t(0x20));
+
+                    let (first_mask, last_mask): ($precision, $precision) = ($dup_one_t(ByteSearch::<$t>::fold_ascii(pattern[0])), $dup_one_t(ByteSearch::<$t>::fold_ascii(pattern[last_pattern_index])));
+
+                    while array_index + COUNT_OF_VALUES_IN_REGISTER <= array_length {
+                        let (first, last): ($precision, $precision) = (
+                            $load(array.as_ptr().add(array_index)),
+                            $load(array.as_ptr().add(array_index + last_pattern_index))
+                        );
+
+                        let candidates: $precision = $bitwise_and(
+                            $eq_compare($bitwise_or(first, $bitwise_and($bitwise_and($greater_than(first, lower_bound), $greater_than(upper_bound, first)), case_bit)), first_mask),
+                            $eq_compare($bitwise_or(last, $bitwise_and($bitwise_and($greater_than(last, lower_bound), $greater_than(upper_bound, last)), case_bit)), last_mask)
+                        );
+
+                        if $vector_max(candidates) != $t_default {
+                            $store(matches.as_mut_ptr(), candidates);
+
+                            for (index_of_match, &candidate) in matches.iter().enumerate() {
+                                if candidate != $t_default && ByteSearch::<$t>::is_folded_match(array, array_index + index_of_match, pattern) { return Some((array_index + index_of_match) * $t_size); }
+                            }
+                        }
+
+                        array_index += COUNT_OF_VALUES_IN_REGISTER;
+                    }
+                }
+
+                while array_index < array_length {
+                    if ByteSearch::<$t>::is_folded_match(array, array_index, pattern) { return Some(array_index * $t_size); }
+
+                    array_index += 1_usize;
+                }
+
+                return None;
+            }
+
+
+            pub fn search_single_ignore_ascii_case(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize> {
+                return ByteSearch::<$t>::search_first_ignore_ascii_case(array_ptr, pattern_ptr, limit).into_iter().collect::<Vec<usize>>();
+            }
+
+            pub fn search_all_ignore_ascii_case(array: &[u8], pattern: &[u8], limit: Option<usize>) -> Vec<usize> {
+                return ByteSearch::<$t>::search_ignore_ascii_case(array, pattern, limit, false);
+            }
+
+            pub fn search_all_overlapping_ignore_ascii_case(array: &[u8], pattern: &[u8], limit: Option<usize>) -> Vec<usize> {
+                return ByteSearch::<$t>::search_ignore_ascii_case(array, pattern, limit, true);
+            }
+
+            fn search_ignore_ascii_case(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>, overlapping: bool) -> Vec<usize> {
+                let mut search_result: Vec<usize> = Vec::<usize>::new();
+
+                let (array, pattern): (&[$t], &[$t]) = (
+                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(array_ptr.as_ptr()), array_ptr.len() / size_of::<$t>()) },
+                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(pattern_ptr.as_ptr()), pattern_ptr.len() / size_of::<$t>()) }
+                );
+
+                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return search_result; }
+
+                let (array_ptr, step, mut position): (&[u8], usize, usize) = (
+                    &array_ptr[..(array_length + pattern_length - 1_usize) * size_of::<$t>()],
+                    if overlapping { 1_usize } else { pattern_length } * size_of::<$t>(),
+                    0_usize
+                );
+
+                while let Some(offset) = ByteSearch::<$t>::search_first_ignore_ascii_case(&array_ptr[position..], pattern_ptr, None) {
+                    search_result.push(position + offset);
+
+                    position += offset + step;
+                }
+
                 return search_result;
             }
 
@@ -1584,10 +1748,58 @@ macro_rules! generate_search {
 
                 return search_result;
             }
+
+            pub fn find_iter<'a>(array: &'a [u8], pattern: &'a [u8]) -> ByteMatches<'a, $t> {
+                return ByteMatches { array, pattern, position: 0_usize, overlapping: false, __marker: PhantomData };
+            }
+
+            pub fn find_iter_overlapping<'a>(array: &'a [u8], pattern: &'a [u8]) -> ByteMatches<'a, $t> {
+                return ByteMatches { array, pattern, position: 0_usize, overlapping: true, __marker: PhantomData };
+            }
+
+            pub fn rfind_iter<'a>(array: &'a [u8], pattern: &'a [u8]) -> ByteMatchesRev<'a, $t> {
+                return ByteMatchesRev { array, pattern, end: array.len(), overlapping: false, __marker: PhantomData };
+            }
+
+            pub fn rfind_iter_overlapping<'a>(array: &'a [u8], pattern: &'a [u8]) -> ByteMatchesRev<'a, $t> {
+                return ByteMatchesRev { array, pattern, end: array.len(), overlapping: true, __marker: PhantomData };
+            }
+
+            pub fn count(array: &[u8], pattern: &[u8]) -> usize {
+                return ByteSearch::<$t>::find_iter(array, pattern).count();
+            }
+
+            pub fn count_overlapping(array: &[u8], pattern: &[u8]) -> usize {
+                return ByteSearch::<$t>::find_iter_overlapping(array, pattern).count();
+            }
+        }
+
+        impl<'a> Iterator for ByteMatches<'a, $t> {
+            type Item = usize;
+
+            fn next(&mut self) -> Option<usize> {
+                let offset: usize = self.position + ByteSearch::<$t>::search_first(&self.array[self.position..], self.pattern, None)?;
+
+                self.position = offset + if self.overlapping { size_of::<$t>() } else { self.pattern.len() - self.pattern.len() % size_of::<$t>() };
+
+                return Some(offset);
+            }
+        }
+
+        impl<'a> Iterator for ByteMatchesRev<'a, $t> {
+            type Item = usize;
+
+            fn next(&mut self) -> Option<usize> {
+                let offset: usize = ByteSearch::<$t>::search_last_offset(&self.array[..self.end], self.pattern, None)?;
+
+                self.end = if self.overlapping { offset + self.pattern.len() - self.pattern.len() % size_of::<$t>() - size_of::<$t>() } else { offset };
+
+                return Some(offset);
+            }
         }
     };
 }
 
-generate_search!(u8, 0_u8, 0xFF, size_of::<u8>(), uint8x16_t, size_of::<uint8x16_t>(), vld1q_u8, vst1q_u8, vdupq_n_u8, vceqq_u8, vandq_u8, vmvnq_u8, vmaxvq_u8);
-generate_search!(u16, 0_u16, 0xFFFF, size_of::<u16>(), uint16x8_t, size_of::<uint16x8_t>(), vld1q_u16, vst1q_u16, vdupq_n_u16, vceqq_u16, vandq_u16, vmvnq_u16, vmaxvq_u16);
-generate_search!(u32, 0_u32, 0xFFFFFFFF, size_of::<u32>(), uint32x4_t, size_of::<uint32x4_t>(), vld1q_u32, vst1q_u32, vdupq_n_u32, vceqq_u32, vandq_u32, vmvnq_u32, vmaxvq_u32);
+generate_search!(u8, 0_u8, 0xFF, size_of::<u8>(), uint8x16_t, size_of::<uint8x16_t>(), vld1q_u8, vst1q_u8, vdupq_n_u8, vceqq_u8, vandq_u8, vmvnq_u8, vmaxvq_u8, vcgtq_u8, vorrq_u8);
+generate_search!(u16, 0_u16, 0xFFFF, size_of::<u16>(), uint16x8_t, size_of::<uint16x8_t>(), vld1q_u16, vst1q_u16, vdupq_n_u16, vceqq_u16, vandq_u16, vmvnq_u16, vmaxvq_u16, vcgtq_u16, vorrq_u16);
+generate_search!(u32, 0_u32, 0xFFFFFFFF, size_of::<u32>(), uint32x4_t, size_of::<uint32x4_t>(), vld1q_u32, vst1q_u32, vdupq_n_u32, vceqq_u32, vandq_u32, vmvnq_u32, vmaxvq_u32, vcgtq_u32, vorrq_u32);
@@ -0,0 +1,113 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        Finder,
+        FinderMatches
+    }
+};
+
+use crate::{
+    functors::{
+        universal::{
+            search::{
+                generate_finder
+            }
+        }
+    }
+};
+
+use core::{
+    arch::{
+        aarch64::{
+            uint8x16_t, uint16x8_t, uint32x4_t,
+            int8x16_t, int16x8_t, int32x4_t,
+            vld1q_u8, vld1q_u16, vld1q_u32,
+            vld1q_s8, vld1q_s16, vld1q_s32,
+            vdupq_n_u8, vdupq_n_u16, vdupq_n_u32,
+            vdupq_n_s8, vdupq_n_s16, vdupq_n_s32,
+            vandq_u8, vandq_u16, vandq_u32,
+            vceqq_u8, vceqq_u16, vceqq_u32,
+            vceqq_s8, vceqq_s16, vceqq_s32,
+            vst1q_u8, vst1q_u16, vst1q_u32,
+            vmaxvq_u8, vmaxvq_u16, vmaxvq_u32
+        }
+    }
+};
+
+macro_rules! generate_prefilter {
+    ($t:ty, $t_size:expr, $precision:ty, $mask:ty, $mask_t:ty, $register_size:expr, $load:expr, $store:expr, $dup_one_t:expr, $eq_compare:expr, $bitwise_and:expr, $vector_max:expr) => {
+        impl Finder<$t> {
+            pub(crate) fn next_candidate(&self, array: &[$t], mut position: usize) -> Option<usize> {
+
+                const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
+
+                let ((first, second), last_position): ((usize, usize), usize) = (self.rare, array.len() - self.needle.len());
+
+                let mut matches: [$mask_t; COUNT_OF_VALUES_IN_REGISTER] = [0; COUNT_OF_VALUES_IN_REGISTER];
+
+                unsafe {
+                    let (first_mask, second_mask): ($precision, $precision) = ($dup_one_t(self.needle[first]), $dup_one_t(self.needle[second]));
+
+                    while position + COUNT_OF_VALUES_IN_REGISTER <= last_position + 1_usize {
+                        let candidates: $mask = $bitwise_and(
+                            $eq_compare($load(array.as_ptr().add(position + first)), first_mask),
+                            $eq_compare($load(array.as_ptr().add(position + second)), second_mask)
+                        );
+
+                        if $vector_max(candidates) != 0 {
+                            $store(matches.as_mut_ptr(), candidates);
+
+                            if let Some(index_of_match) = matches.iter().position(|&candidate| candidate != 0) { return Some(position + index_of_match); }
+                        }
+
+                        position += COUNT_OF_VALUES_IN_REGISTER;
+                    }
+                }
+
+                while position <= last_position {
+                    if array[position + first] == self.needle[first] && array[position + second] == self.needle[second] { return Some(position); }
+
+                    position += 1_usize;
+                }
+
+                return None;
+            }
+        }
+
+        generate_finder!($t);
+    };
+}
+
+generate_prefilter!(u8, size_of::<u8>(), uint8x16_t, uint8x16_t, u8, size_of::<uint8x16_t>(), vld1q_u8, vst1q_u8, vdupq_n_u8, vceqq_u8, vandq_u8, vmaxvq_u8);
+generate_prefilter!(u16, size_of::<u16>(), uint16x8_t, uint16x8_t, u16, size_of::<uint16x8_t>(), vld1q_u16, vst1q_u16, vdupq_n_u16, vceqq_u16, vandq_u16, vmaxvq_u16);
+generate_prefilter!(u32, size_of::<u32>(), uint32x4_t, uint32x4_t, u32, size_of::<uint32x4_t>(), vld1q_u32, vst1q_u32, vdupq_n_u32, vceqq_u32, vandq_u32, vmaxvq_u32);
+generate_prefilter!(i8, size_of::<i8>(), int8x16_t, uint8x16_t, u8, size_of::<uint8x16_t>(), vld1q_s8, vst1q_u8, vdupq_n_s8, vceqq_s8, vandq_u8, vmaxvq_u8);
+generate_prefilter!(i16, size_of::<i16>(), int16x8_t, uint16x8_t, u16, size_of::<uint16x8_t>(), vld1q_s16, vst1q_u16, vdupq_n_s16, vceqq_s16, vandq_u16, vmaxvq_u16);
+generate_prefilter!(i32, size_of::<i32>(), int32x4_t, uint32x4_t, u32, size_of::<uint32x4_t>(), vld1q_s32, vst1q_u32, vdupq_n_s32, vceqq_s32, vandq_u32, vmaxvq_u32);
@@ -0,0 +1,106 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        MultiSearcher
+    }
+};
+
+use core::{
+    arch::{
+        aarch64::{
+            uint8x16_t,
+            vld1q_u8, vst1q_u8,
+            vdupq_n_u8, vandq_u8,
+            vshrq_n_u8, vqtbl1q_u8
+        }
+    }
+};
+
+impl MultiSearcher {
+
+    const __REGISTER_SIZE: usize = size_of::<uint8x16_t>();
+
+    fn packed_search(&self, array: &[u8], all_matches: bool) -> Vec<(usize, usize)> {
+        let mut search_result: Vec<(usize, usize)> = Vec::new();
+
+        let (array, fingerprint): (&[u8], usize) = (&array[..array.len() - array.len() % self.width], self.fingerprint);
+
+        let (mut index, mut resume, mut candidates): (usize, usize, [u64; 2_usize]) = (0_usize, 0_usize, [0_u64; 2_usize]);
+
+        unsafe {
+            let (tables, nibble_mask): ([uint8x16_t; 6_usize], uint8x16_t) = {
+                let mut tables: [uint8x16_t; 6_usize] = [vdupq_n_u8(0); 6_usize];
+
+                for (table, mask) in tables.iter_mut().zip(self.masks.iter()) { *table = vld1q_u8(mask.as_ptr()); }
+
+                (tables, vdupq_n_u8(0x0F))
+            };
+
+            while index + fingerprint - 1_usize + MultiSearcher::__REGISTER_SIZE <= array.len() {
+                let mut buckets: uint8x16_t = vdupq_n_u8(0xFF);
+
+                for offset in 0_usize..fingerprint {
+                    let value: uint8x16_t = vld1q_u8(array.as_ptr().add(index + offset));
+
+                    buckets = vandq_u8(buckets, vandq_u8(
+                        vqtbl1q_u8(tables[offset * 2_usize], vandq_u8(value, nibble_mask)),
+                        vqtbl1q_u8(tables[offset * 2_usize + 1_usize], vshrq_n_u8(value, 4))
+                    ));
+                }
+
+                vst1q_u8(candidates.as_mut_ptr() as *mut u8, buckets);
+
+                for (word, lanes) in candidates.iter().enumerate() {
+                    let mut lanes: u64 = *lanes;
+
+                    while lanes != 0_u64 {
+                        let lane: usize = lanes.trailing_zeros() as usize / 8_usize;
+
+                        if !self.verify(array, index + word * 8_usize + lane, (lanes >> (lane * 8_usize)) as u8, all_matches, &mut resume, &mut search_result) { return search_result; }
+
+                        lanes &= !(0xFF_u64 << (lane * 8_usize));
+                    }
+                }
+
+                index += MultiSearcher::__REGISTER_SIZE;
+            }
+        }
+
+        self.packed_search_from(array, index, all_matches, &mut resume, &mut search_result);
+
+        return search_result;
+    }
+
+    pub(crate) fn search_matches(&self, array: &[u8], all_matches: bool) -> Vec<(usize, usize)> {
+        if self.fingerprint == 0_usize { return self.automaton_search(array, all_matches); }
+
+        return self.packed_search(array, all_matches);
+    }
+}
@@ -0,0 +1,98 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF16,
+        Statistics
+    }
+};
+
+use core::{
+    mem::{
+        transmute
+    },
+    arch::{
+        arm::{
+            uint16x8_t,
+            vld1q_u8, vrev16q_u8, vreinterpretq_u16_u8,
+            vdupq_n_u16, vandq_u16, vceqq_u16,
+            vcltq_u16
+        }
+    }
+};
+
+impl UTF16 {
+    fn count_classes_neon(array: &[u8], endian: bool) -> (usize, usize, usize, usize) {
+        let (length, mut index, swap): (usize, usize, bool) = (array.len() - array.len() % 2_usize, 0_usize, endian == cfg!(target_endian = "big"));
+
+        let (mut ascii, mut narrow, mut surrogates, mut trails): (usize, usize, usize, usize) = (0_usize, 0_usize, 0_usize, 0_usize);
+
+        let (ascii_bound, narrow_bound, surrogate_mask, surrogate_bound, trail_mask, trail_bound): (uint16x8_t, uint16x8_t, uint16x8_t, uint16x8_t, uint16x8_t, uint16x8_t) =
+        unsafe { (vdupq_n_u16(0x0080), vdupq_n_u16(0x0800), vdupq_n_u16(0xF800), vdupq_n_u16(0xD800), vdupq_n_u16(0xFC00), vdupq_n_u16(0xDC00)) };
+
+        while index + 16_usize <= length {
+            let value: uint16x8_t = if swap {
+                unsafe { vreinterpretq_u16_u8(vrev16q_u8(vld1q_u8(array.as_ptr().add(index)))) }
+            } else {
+                unsafe { vreinterpretq_u16_u8(vld1q_u8(array.as_ptr().add(index))) }
+            };
+
+            unsafe {
+                ascii += (transmute::<uint16x8_t, u128>(vcltq_u16(value, ascii_bound)).count_ones() / 16_u32) as usize;
+                narrow += (transmute::<uint16x8_t, u128>(vcltq_u16(value, narrow_bound)).count_ones() / 16_u32) as usize;
+                surrogates += (transmute::<uint16x8_t, u128>(vceqq_u16(vandq_u16(value, surrogate_mask), surrogate_bound)).count_ones() / 16_u32) as usize;
+                trails += (transmute::<uint16x8_t, u128>(vceqq_u16(vandq_u16(value, trail_mask), trail_bound)).count_ones() / 16_u32) as usize;
+            }
+
+            index += 16_usize;
+        }
+
+        let (tail_ascii, tail_narrow, tail_surrogates, tail_trails): (usize, usize, usize, usize) = UTF16::count_classes_from(array, index, length, endian);
+
+        return (ascii + tail_ascii, narrow + tail_narrow, surrogates + tail_surrogates, trails + tail_trails);
+    }
+
+    pub fn count_chars(array: &[u8], endian: bool) -> usize {
+        return array.len() / 2_usize - UTF16::count_classes_neon(array, endian).3;
+    }
+
+    pub fn stats(array: &[u8], endian: bool) -> Statistics {
+        return UTF16::statistics_from(array.len() / 2_usize, UTF16::count_classes_neon(array, endian));
+    }
+
+    pub fn utf8_length_from_utf16(array: &[u8], endian: bool) -> usize {
+        let (ascii, narrow, surrogates, _): (usize, usize, usize, usize) = UTF16::count_classes_neon(array, endian);
+
+        return array.len() / 2_usize * 3_usize - ascii - narrow - surrogates;
+    }
+
+    pub fn utf32_length_from_utf16(array: &[u8], endian: bool) -> usize {
+        return array.len() / 2_usize - UTF16::count_classes_neon(array, endian).3;
+    }
+}
@@ -0,0 +1,92 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF8,
+        Statistics
+    }
+};
+
+use core::{
+    mem::{
+        transmute
+    },
+    arch::{
+        arm::{
+            int8x16_t, uint8x16_t,
+            vld1q_s8, vdupq_n_s8,
+            vcgtq_s8
+        }
+    }
+};
+
+impl UTF8 {
+    fn count_classes_neon(array: &[u8]) -> (usize, usize, usize) {
+        let (length, mut index): (usize, usize) = (array.len(), 0_usize);
+
+        let (mut leads, mut ascii, mut above): (usize, usize, usize) = (0_usize, 0_usize, 0_usize);
+
+        let (leads_bound, ascii_bound, above_bound): (int8x16_t, int8x16_t, int8x16_t) =
+        unsafe { (vdupq_n_s8(-0x41), vdupq_n_s8(-0x01), vdupq_n_s8(-0x11)) }; // 0xBF, 0xFF, 0xEF
+
+        while index + 16_usize <= length {
+            let value: int8x16_t = unsafe { vld1q_s8(array.as_ptr().add(index) as *const i8) };
+
+            unsafe {
+                leads += (transmute::<uint8x16_t, u128>(vcgtq_s8(value, leads_bound)).count_ones() / 8_u32) as usize;
+                ascii += (transmute::<uint8x16_t, u128>(vcgtq_s8(value, ascii_bound)).count_ones() / 8_u32) as usize;
+                above += (transmute::<uint8x16_t, u128>(vcgtq_s8(value, above_bound)).count_ones() / 8_u32) as usize;
+            }
+
+            index += 16_usize;
+        }
+
+        let (tail_leads, tail_ascii, tail_above): (usize, usize, usize) = UTF8::count_classes_from(array, index, length);
+
+        return (leads + tail_leads, ascii + tail_ascii, above + tail_above);
+    }
+
+    pub fn count_chars(array: &[u8]) -> usize {
+        return UTF8::count_classes_neon(array).0;
+    }
+
+    pub fn stats(array: &[u8]) -> Statistics {
+        return UTF8::statistics_from(UTF8::count_classes_neon(array));
+    }
+
+    pub fn utf16_length_from_utf8(array: &[u8]) -> usize {
+        let (leads, ascii, above): (usize, usize, usize) = UTF8::count_classes_neon(array);
+
+        return leads + above - ascii;
+    }
+
+    pub fn utf32_length_from_utf8(array: &[u8]) -> usize {
+        return UTF8::count_classes_neon(array).0;
+    }
+}
@@ -54,4 +54,16 @@ impl UTF8 {
             return ByteSearch::<u8>::search_single(array, pattern, limit);
         }
     }
+
+    pub fn search_pattern_ignore_ascii_case(array: &[u8], pattern: &[u8], overlapping: bool, all_matches: bool, limit: Option<usize>) -> Vec<usize> {
+        if all_matches {
+            if overlapping {
+                return ByteSearch::<u8>::search_all_overlapping_ignore_ascii_case(array, pattern, limit);
+            } else {
+                return ByteSearch::<u8>::search_all_ignore_ascii_case(array, pattern, limit);
+            }
+        } else {
+            return ByteSearch::<u8>::search_single_ignore_ascii_case(array, pattern, limit);
+        }
+    }
 }
@@ -0,0 +1,204 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        EBCDIC,
+        EbcdicCodepage,
+        Newline
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+use core::{
+    mem::{
+        transmute
+    },
+    arch::{
+        arm::{
+            uint8x8_t, uint8x8x4_t, uint8x16_t,
+            vld1q_u8, vst1q_u8, vld1_u8_x4,
+            vtbl4_u8, vtbx4_u8,
+            vsub_u8, vdup_n_u8,
+            vget_low_u8, vget_high_u8, vcombine_u8,
+            vandq_u8, vdupq_n_u8
+        }
+    }
+};
+
+impl EBCDIC {
+    fn tables(table: &[u8; 256_usize]) -> [uint8x8x4_t; 8_usize] {
+        let (mut tables, mut group): ([uint8x8x4_t; 8_usize], usize) = (unsafe { [vld1_u8_x4(table.as_ptr()); 8_usize] }, 1_usize);
+
+        while group < 8_usize {
+            tables[group] = unsafe { vld1_u8_x4(table.as_ptr().add(group * 32_usize)) };
+
+            group += 1_usize;
+        }
+
+        return tables;
+    }
+
+    fn translate_half(value: uint8x8_t, tables: &[uint8x8x4_t; 8_usize]) -> uint8x8_t {
+        let (mut result, mut index, offset): (uint8x8_t, uint8x8_t, uint8x8_t) = unsafe { (vtbl4_u8(tables[0], value), value, vdup_n_u8(32)) };
+
+        let mut group: usize = 1_usize;
+
+        while group < 8_usize {
+            index = unsafe { vsub_u8(index, offset) };
+
+            result = unsafe { vtbx4_u8(result, tables[group], index) }; // out of range lanes keep the previous group
+
+            group += 1_usize;
+        }
+
+        return result;
+    }
+
+    fn translate(value: uint8x16_t, tables: &[uint8x8x4_t; 8_usize]) -> uint8x16_t {
+        return unsafe { vcombine_u8(EBCDIC::translate_half(vget_low_u8(value), tables), EBCDIC::translate_half(vget_high_u8(value), tables)) };
+    }
+
+    pub fn to_utf8_into(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> (usize, usize) {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let tables: [uint8x8x4_t; 8_usize] = EBCDIC::tables(&codepage.translate_table(newline));
+
+        while read + 16_usize <= length {
+            let value: uint8x16_t = EBCDIC::translate(unsafe { vld1q_u8(array.as_ptr().add(read)) }, &tables);
+
+            if unsafe { transmute::<uint8x16_t, u128>(vandq_u8(value, vdupq_n_u8(0x80))) } == 0_u128 {
+                if written + 16_usize > capacity { break; }
+
+                unsafe { vst1q_u8(output.as_mut_ptr().add(written), value) };
+
+                read += 16_usize; written += 16_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = EBCDIC::decode_utf8_from(array, read, read + 16_usize, output, written, codepage, newline);
+
+                if next_read < read + 16_usize { return (next_read, next_written); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return EBCDIC::decode_utf8_from(array, read, length, output, written, codepage, newline);
+    }
+
+    pub fn to_ascii_into(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let tables: [uint8x8x4_t; 8_usize] = EBCDIC::tables(&codepage.translate_table(newline));
+
+        while read + 16_usize <= length {
+            let value: uint8x16_t = EBCDIC::translate(unsafe { vld1q_u8(array.as_ptr().add(read)) }, &tables);
+
+            if unsafe { transmute::<uint8x16_t, u128>(vandq_u8(value, vdupq_n_u8(0x80))) } == 0_u128 {
+                if written + 16_usize > capacity { break; }
+
+                unsafe { vst1q_u8(output.as_mut_ptr().add(written), value) };
+
+                read += 16_usize; written += 16_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = EBCDIC::decode_ascii_from(array, read, read + 16_usize, output, written, codepage, newline)?;
+
+                if next_read < read + 16_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return EBCDIC::decode_ascii_from(array, read, length, output, written, codepage, newline);
+    }
+
+    pub fn from_utf8_into(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let tables: [uint8x8x4_t; 8_usize] = EBCDIC::tables(&codepage.ascii_table(newline));
+
+        while read + 16_usize <= length {
+            let value: uint8x16_t = unsafe { vld1q_u8(array.as_ptr().add(read)) };
+
+            if unsafe { transmute::<uint8x16_t, u128>(vandq_u8(value, vdupq_n_u8(0x80))) } == 0_u128 {
+                if written + 16_usize > capacity { break; }
+
+                unsafe { vst1q_u8(output.as_mut_ptr().add(written), EBCDIC::translate(value, &tables)) };
+
+                read += 16_usize; written += 16_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = EBCDIC::encode_utf8_from(array, read, read + 16_usize, output, written, codepage, newline)?;
+
+                if next_read < read + 16_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return EBCDIC::encode_utf8_from(array, read, length, output, written, codepage, newline);
+    }
+
+    pub fn from_ascii_into(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let tables: [uint8x8x4_t; 8_usize] = EBCDIC::tables(&codepage.ascii_table(newline));
+
+        while read + 16_usize <= length {
+            let value: uint8x16_t = unsafe { vld1q_u8(array.as_ptr().add(read)) };
+
+            if unsafe { transmute::<uint8x16_t, u128>(vandq_u8(value, vdupq_n_u8(0x80))) } == 0_u128 {
+                if written + 16_usize > capacity { break; }
+
+                unsafe { vst1q_u8(output.as_mut_ptr().add(written), EBCDIC::translate(value, &tables)) };
+
+                read += 16_usize; written += 16_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = EBCDIC::encode_ascii_from(array, read, read + 16_usize, output, written, codepage, newline)?;
+
+                if next_read < read + 16_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return EBCDIC::encode_ascii_from(array, read, length, output, written, codepage, newline);
+    }
+}
@@ -32,6 +32,13 @@ pub use crate::{
     }
 };
 
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
 use core::{
     mem::{
         transmute
@@ -48,6 +55,8 @@ use core::{
 
 impl ASCII {
 
+    const __VALIDATION_WINDOW_BYTES: usize = 65536_usize;
+
     fn is_ascii_8x8(array: *const u8, length: usize) -> bool {
         let (mut index, mask): (usize, uint8x8_t) = (0_usize, unsafe { vdup_n_u8(0x80) });
 
@@ -101,4 +110,24 @@ impl ASCII {
 
         return result;
     }
+
+    pub fn validate_ascii_from_byte_array(array: &[u8]) -> Result<(), EncodingError> {
+        let length: usize = array.len();
+
+        if length == 0_usize { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        let mut start: usize = 0_usize;
+
+        while start < length {
+            let end: usize = if length - start > ASCII::__VALIDATION_WINDOW_BYTES { start + ASCII::__VALIDATION_WINDOW_BYTES } else { length };
+
+            if !ASCII::is_ascii_from_byte_array(&array[start..end]) {
+                ASCII::locate_invalid_ascii(&array[start..end], start)?;
+            }
+
+            start = end;
+        }
+
+        return Ok(());
+    }
 }
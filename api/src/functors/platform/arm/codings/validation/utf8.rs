@@ -0,0 +1,173 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF8
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+use core::{
+    mem::{
+        transmute
+    },
+    arch::{
+        arm::{
+            uint8x16_t,
+            vld1q_u8,
+            vdupq_n_u8,
+            vextq_u8,
+            vorrq_u8, vandq_u8, veorq_u8,
+            vceqq_u8, vcltq_u8, vcgtq_u8
+        }
+    }
+};
+
+impl UTF8 {
+
+    const __ENCODING_MAX_FOLLOWING_BYTES: usize = 3_usize;
+    const __VALIDATION_WINDOW_BYTES: usize = 65536_usize;
+    const __INCOMPLETE_MASK: [u8; 16_usize] = [
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xEF, 0xDF, 0xBF
+    ];
+
+    fn is_utf8_8x16(array: *const u8, length: usize, previous: &mut uint8x16_t) -> bool {
+        let mut index: usize = 0_usize;
+
+        let (following_mask, following_result, second_lead_mask, third_lead_mask, four_lead_mask, max_lead_mask, overlong_mask): (uint8x16_t, uint8x16_t, uint8x16_t, uint8x16_t, uint8x16_t, uint8x16_t, uint8x16_t) = unsafe {
+            (vdupq_n_u8(0xC0), vdupq_n_u8(0x80), vdupq_n_u8(0xBF), vdupq_n_u8(0xDF), vdupq_n_u8(0xEF), vdupq_n_u8(0xF4), vdupq_n_u8(0xFE))
+        };
+
+        let (three_lead, three_surrogate_lead, four_lead, four_max_lead): (uint8x16_t, uint8x16_t, uint8x16_t, uint8x16_t) = unsafe {
+            (vdupq_n_u8(0xE0), vdupq_n_u8(0xED), vdupq_n_u8(0xF0), vdupq_n_u8(0xF4))
+        };
+
+        let (three_min_following, three_max_following, four_min_following, four_max_following): (uint8x16_t, uint8x16_t, uint8x16_t, uint8x16_t) = unsafe {
+            (vdupq_n_u8(0xA0), vdupq_n_u8(0x9F), vdupq_n_u8(0x90), vdupq_n_u8(0x8F))
+        };
+
+        let incomplete_mask: uint8x16_t = unsafe { vld1q_u8(UTF8::__INCOMPLETE_MASK.as_ptr()) };
+
+        while index < length {
+            let value: uint8x16_t = unsafe { vld1q_u8(array.add(index)) };
+
+            if unsafe { transmute::<uint8x16_t, u128>(vandq_u8(value, following_result)) } == 0_u128 {
+                if unsafe { transmute::<uint8x16_t, u128>(vcgtq_u8(*previous, incomplete_mask)) } != 0_u128 { return false; }
+            } else {
+                let (first_previous, second_previous, third_previous): (uint8x16_t, uint8x16_t, uint8x16_t) = unsafe {
+                    (vextq_u8::<15>(*previous, value), vextq_u8::<14>(*previous, value), vextq_u8::<13>(*previous, value))
+                };
+
+                let required: uint8x16_t = unsafe {
+                    vorrq_u8(
+                        vorrq_u8(vcgtq_u8(first_previous, second_lead_mask), vcgtq_u8(second_previous, third_lead_mask)),
+                        vcgtq_u8(third_previous, four_lead_mask)
+                    )
+                };
+
+                let error: uint8x16_t = unsafe {
+                    vorrq_u8(
+                        vorrq_u8(
+                            veorq_u8(required, vceqq_u8(vandq_u8(value, following_mask), following_result)),
+                            vorrq_u8(vcgtq_u8(value, max_lead_mask), vceqq_u8(vandq_u8(value, overlong_mask), following_mask))
+                        ),
+                        vorrq_u8(
+                            vorrq_u8(
+                                vandq_u8(vceqq_u8(first_previous, three_lead), vcltq_u8(value, three_min_following)),
+                                vandq_u8(vceqq_u8(first_previous, three_surrogate_lead), vcgtq_u8(value, three_max_following))
+                            ),
+                            vorrq_u8(
+                                vandq_u8(vceqq_u8(first_previous, four_lead), vcltq_u8(value, four_min_following)),
+                                vandq_u8(vceqq_u8(first_previous, four_max_lead), vcgtq_u8(value, four_max_following))
+                            )
+                        )
+                    )
+                };
+
+                if unsafe { transmute::<uint8x16_t, u128>(error) } != 0_u128 { return false; }
+            }
+
+            *previous = value;
+
+            index += 16_usize;
+        }
+
+        return true;
+    }
+
+    pub fn is_utf8(array: &[u8]) -> bool {
+        let length: usize = array.len();
+
+        if length == 0_usize { return false; }
+
+        let (mut index, indivisible, mut previous): (usize, usize, uint8x16_t) = (0_usize, length % 16_usize, unsafe { vdupq_n_u8(0x00) });
+
+        let remains_length: usize = length - indivisible;
+
+        if remains_length != 0_usize && !UTF8::is_utf8_8x16(array.as_ptr(), remains_length, &mut previous) { return false; }
+
+        let indivisible_code_array: [u8; 16_usize] = {
+            let mut indivisible_code_array: [u8; 16_usize] = [0_u8; 16_usize];
+            while index < indivisible { indivisible_code_array[index] = array[remains_length + index]; index += 1_usize; }
+            indivisible_code_array
+        };
+
+        return UTF8::is_utf8_8x16(indivisible_code_array.as_ptr(), 16_usize, &mut previous);
+    }
+
+    pub fn validate_utf8(array: &[u8]) -> Result<(), EncodingError> {
+        let length: usize = array.len();
+
+        if length == 0_usize { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        let mut start: usize = 0_usize;
+
+        while start < length {
+            let mut end: usize = if length - start > UTF8::__VALIDATION_WINDOW_BYTES { start + UTF8::__VALIDATION_WINDOW_BYTES } else { length };
+
+            while end != length && end + UTF8::__ENCODING_MAX_FOLLOWING_BYTES > start + UTF8::__VALIDATION_WINDOW_BYTES && UTF8::is_following(array[end]) {
+                end -= 1_usize;
+            }
+
+            if !UTF8::is_utf8(&array[start..end]) {
+                UTF8::locate_invalid_utf8(&array[start..end], start)?;
+            }
+
+            start = end;
+        }
+
+        return Ok(());
+    }
+}
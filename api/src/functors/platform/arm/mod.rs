@@ -28,3 +28,4 @@
 
 pub mod codings;
 pub mod search;
+pub mod dispatch;
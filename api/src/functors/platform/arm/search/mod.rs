@@ -27,5 +27,7 @@
  */
 
 mod byte;
+mod multi;
+mod finder;
 
-pub use byte::{ByteSearch};
+pub use byte::{ByteSearch, ByteMatches, ByteMatchesRev};
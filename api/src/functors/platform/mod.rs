@@ -48,6 +48,7 @@ macro_rules! import_functions {
     ($platform:ident) => {
         pub use self::$platform::{codings};
         pub use self::$platform::{search};
+        pub use self::$platform::{dispatch};
     };
 }
 
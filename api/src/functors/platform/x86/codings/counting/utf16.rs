@@ -0,0 +1,188 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF16,
+        Statistics
+    }
+};
+
+use crate::{
+    functors::{
+        platform::{
+            dispatch::{
+                dispatch
+            }
+        }
+    }
+};
+
+use core::{
+    arch::{
+        x86::{
+            __m512i,
+            _mm512_loadu_si512,
+            _mm512_set1_epi16,
+            _mm512_and_si512,
+            _mm512_cmpeq_epi16_mask,
+            __m256i,
+            _mm256_loadu_si256,
+            _mm256_set1_epi16,
+            _mm256_and_si256,
+            _mm256_cmpeq_epi16,
+            _mm256_movemask_epi8,
+            __m128i,
+            _mm_loadu_si128,
+            _mm_set1_epi16,
+            _mm_and_si128,
+            _mm_cmpeq_epi16,
+            _mm_movemask_epi8
+        }
+    }
+};
+
+impl UTF16 {
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn count_classes_avx512(array: &[u8], endian: bool) -> (usize, usize, usize, usize) {
+        let (length, mut index): (usize, usize) = (array.len() - array.len() % 2_usize, 0_usize);
+
+        let (mut ascii, mut narrow, mut surrogates, mut trails): (usize, usize, usize, usize) = (0_usize, 0_usize, 0_usize, 0_usize);
+
+        let (ascii_mask, surrogate_mask, surrogate_bound, trail_mask, trail_bound): (__m512i, __m512i, __m512i, __m512i, __m512i) = if endian {
+            unsafe { (_mm512_set1_epi16(-0x0080), _mm512_set1_epi16(-0x0800), _mm512_set1_epi16(-0x2800), _mm512_set1_epi16(-0x0400), _mm512_set1_epi16(-0x2400)) } // 0xFF80, 0xF800, 0xD800, 0xFC00, 0xDC00
+        } else {
+            unsafe { (_mm512_set1_epi16(-0x7F01), _mm512_set1_epi16(0x00F8), _mm512_set1_epi16(0x00D8), _mm512_set1_epi16(0x00FC), _mm512_set1_epi16(0x00DC)) } // 0x80FF, 0x00F8, 0x00D8, 0x00FC, 0x00DC
+        };
+
+        let zero: __m512i = unsafe { _mm512_set1_epi16(0x0000) };
+
+        while index + 64_usize <= length {
+            let value: __m512i = unsafe { _mm512_loadu_si512(array.as_ptr().add(index) as *const __m512i) };
+
+            unsafe {
+                ascii += _mm512_cmpeq_epi16_mask(_mm512_and_si512(value, ascii_mask), zero).count_ones() as usize;
+                narrow += _mm512_cmpeq_epi16_mask(_mm512_and_si512(value, surrogate_mask), zero).count_ones() as usize;
+                surrogates += _mm512_cmpeq_epi16_mask(_mm512_and_si512(value, surrogate_mask), surrogate_bound).count_ones() as usize;
+                trails += _mm512_cmpeq_epi16_mask(_mm512_and_si512(value, trail_mask), trail_bound).count_ones() as usize;
+            }
+
+            index += 64_usize;
+        }
+
+        let (tail_ascii, tail_narrow, tail_surrogates, tail_trails): (usize, usize, usize, usize) = UTF16::count_classes_from(array, index, length, endian);
+
+        return (ascii + tail_ascii, narrow + tail_narrow, surrogates + tail_surrogates, trails + tail_trails);
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn count_classes_avx2(array: &[u8], endian: bool) -> (usize, usize, usize, usize) {
+        let (length, mut index): (usize, usize) = (array.len() - array.len() % 2_usize, 0_usize);
+
+        let (mut ascii, mut narrow, mut surrogates, mut trails): (usize, usize, usize, usize) = (0_usize, 0_usize, 0_usize, 0_usize);
+
+        let (ascii_mask, surrogate_mask, surrogate_bound, trail_mask, trail_bound): (__m256i, __m256i, __m256i, __m256i, __m256i) = if endian {
+            unsafe { (_mm256_set1_epi16(-0x0080), _mm256_set1_epi16(-0x0800), _mm256_set1_epi16(-0x2800), _mm256_set1_epi16(-0x0400), _mm256_set1_epi16(-0x2400)) } // 0xFF80, 0xF800, 0xD800, 0xFC00, 0xDC00
+        } else {
+            unsafe { (_mm256_set1_epi16(-0x7F01), _mm256_set1_epi16(0x00F8), _mm256_set1_epi16(0x00D8), _mm256_set1_epi16(0x00FC), _mm256_set1_epi16(0x00DC)) } // 0x80FF, 0x00F8, 0x00D8, 0x00FC, 0x00DC
+        };
+
+        let zero: __m256i = unsafe { _mm256_set1_epi16(0x0000) };
+
+        while index + 32_usize <= length {
+            let value: __m256i = unsafe { _mm256_loadu_si256(array.as_ptr().add(index) as *const __m256i) };
+
+            unsafe {
+                ascii += _mm256_movemask_epi8(_mm256_cmpeq_epi16(_mm256_and_si256(value, ascii_mask), zero)).count_ones() as usize / 2_usize;
+                narrow += _mm256_movemask_epi8(_mm256_cmpeq_epi16(_mm256_and_si256(value, surrogate_mask), zero)).count_ones() as usize / 2_usize;
+                surrogates += _mm256_movemask_epi8(_mm256_cmpeq_epi16(_mm256_and_si256(value, surrogate_mask), surrogate_bound)).count_ones() as usize / 2_usize;
+                trails += _mm256_movemask_epi8(_mm256_cmpeq_epi16(_mm256_and_si256(value, trail_mask), trail_bound)).count_ones() as usize / 2_usize;
+            }
+
+            index += 32_usize;
+        }
+
+        let (tail_ascii, tail_narrow, tail_surrogates, tail_trails): (usize, usize, usize, usize) = UTF16::count_classes_from(array, index, length, endian);
+
+        return (ascii + tail_ascii, narrow + tail_narrow, surrogates + tail_surrogates, trails + tail_trails);
+    }
+
+    #[target_feature(enable = "sse2")]
+    #[allow(unused_unsafe)]
+    unsafe fn count_classes_sse2(array: &[u8], endian: bool) -> (usize, usize, usize, usize) {
+        let (length, mut index): (usize, usize) = (array.len() - array.len() % 2_usize, 0_usize);
+
+        let (mut ascii, mut narrow, mut surrogates, mut trails): (usize, usize, usize, usize) = (0_usize, 0_usize, 0_usize, 0_usize);
+
+        let (ascii_mask, surrogate_mask, surrogate_bound, trail_mask, trail_bound): (__m128i, __m128i, __m128i, __m128i, __m128i) = if endian {
+            unsafe { (_mm_set1_epi16(-0x0080), _mm_set1_epi16(-0x0800), _mm_set1_epi16(-0x2800), _mm_set1_epi16(-0x0400), _mm_set1_epi16(-0x2400)) } // 0xFF80, 0xF800, 0xD800, 0xFC00, 0xDC00
+        } else {
+            unsafe { (_mm_set1_epi16(-0x7F01), _mm_set1_epi16(0x00F8), _mm_set1_epi16(0x00D8), _mm_set1_epi16(0x00FC), _mm_set1_epi16(0x00DC)) } // 0x80FF, 0x00F8, 0x00D8, 0x00FC, 0x00DC
+        };
+
+        let zero: __m128i = unsafe { _mm_set1_epi16(0x0000) };
+
+        while index + 16_usize <= length {
+            let value: __m128i = unsafe { _mm_loadu_si128(array.as_ptr().add(index) as *const __m128i) };
+
+            unsafe {
+                ascii += _mm_movemask_epi8(_mm_cmpeq_epi16(_mm_and_si128(value, ascii_mask), zero)).count_ones() as usize / 2_usize;
+                narrow += _mm_movemask_epi8(_mm_cmpeq_epi16(_mm_and_si128(value, surrogate_mask), zero)).count_ones() as usize / 2_usize;
+                surrogates += _mm_movemask_epi8(_mm_cmpeq_epi16(_mm_and_si128(value, surrogate_mask), surrogate_bound)).count_ones() as usize / 2_usize;
+                trails += _mm_movemask_epi8(_mm_cmpeq_epi16(_mm_and_si128(value, trail_mask), trail_bound)).count_ones() as usize / 2_usize;
+            }
+
+            index += 16_usize;
+        }
+
+        let (tail_ascii, tail_narrow, tail_surrogates, tail_trails): (usize, usize, usize, usize) = UTF16::count_classes_from(array, index, length, endian);
+
+        return (ascii + tail_ascii, narrow + tail_narrow, surrogates + tail_surrogates, trails + tail_trails);
+    }
+
+    pub fn count_chars(array: &[u8], endian: bool) -> usize {
+        return array.len() / 2_usize - dispatch!(unsafe fn(array: &[u8], endian: bool) -> (usize, usize, usize, usize), UTF16::count_classes_sse2, UTF16::count_classes_avx2, UTF16::count_classes_avx512).3;
+    }
+
+    pub fn stats(array: &[u8], endian: bool) -> Statistics {
+        return UTF16::statistics_from(array.len() / 2_usize, dispatch!(unsafe fn(array: &[u8], endian: bool) -> (usize, usize, usize, usize), UTF16::count_classes_sse2, UTF16::count_classes_avx2, UTF16::count_classes_avx512));
+    }
+
+    pub fn utf8_length_from_utf16(array: &[u8], endian: bool) -> usize {
+        let (ascii, narrow, surrogates, _): (usize, usize, usize, usize) = dispatch!(unsafe fn(array: &[u8], endian: bool) -> (usize, usize, usize, usize), UTF16::count_classes_sse2, UTF16::count_classes_avx2, UTF16::count_classes_avx512);
+
+        return array.len() / 2_usize * 3_usize - ascii - narrow - surrogates;
+    }
+
+    pub fn utf32_length_from_utf16(array: &[u8], endian: bool) -> usize {
+        return array.len() / 2_usize - dispatch!(unsafe fn(array: &[u8], endian: bool) -> (usize, usize, usize, usize), UTF16::count_classes_sse2, UTF16::count_classes_avx2, UTF16::count_classes_avx512).3;
+    }
+}
@@ -0,0 +1,181 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF32,
+        Statistics
+    }
+};
+
+use crate::{
+    functors::{
+        platform::{
+            dispatch::{
+                dispatch
+            }
+        }
+    }
+};
+
+use core::{
+    arch::{
+        x86::{
+            __m512i,
+            _mm512_loadu_si512,
+            _mm512_set1_epi32,
+            _mm512_and_si512,
+            _mm512_cmpeq_epi32_mask,
+            __m256i,
+            _mm256_loadu_si256,
+            _mm256_set1_epi32,
+            _mm256_and_si256,
+            _mm256_cmpeq_epi32,
+            _mm256_movemask_epi8,
+            __m128i,
+            _mm_loadu_si128,
+            _mm_set1_epi32,
+            _mm_and_si128,
+            _mm_cmpeq_epi32,
+            _mm_movemask_epi8
+        }
+    }
+};
+
+impl UTF32 {
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn count_classes_avx512(array: &[u8], endian: bool) -> (usize, usize, usize) {
+        let (length, mut index): (usize, usize) = (array.len() - array.len() % 4_usize, 0_usize);
+
+        let (mut ascii, mut narrow, mut bmp): (usize, usize, usize) = (0_usize, 0_usize, 0_usize);
+
+        let (ascii_mask, narrow_mask, bmp_mask): (__m512i, __m512i, __m512i) = if endian {
+            unsafe { (_mm512_set1_epi32(-0x00000080), _mm512_set1_epi32(-0x00000800), _mm512_set1_epi32(-0x00010000)) } // 0xFFFFFF80, 0xFFFFF800, 0xFFFF0000
+        } else {
+            unsafe { (_mm512_set1_epi32(-0x7F000001), _mm512_set1_epi32(0x00F8FFFF), _mm512_set1_epi32(0x0000FFFF)) } // 0x80FFFFFF, 0x00F8FFFF, 0x0000FFFF
+        };
+
+        let zero: __m512i = unsafe { _mm512_set1_epi32(0x00000000) };
+
+        while index + 64_usize <= length {
+            let value: __m512i = unsafe { _mm512_loadu_si512(array.as_ptr().add(index) as *const __m512i) };
+
+            unsafe {
+                ascii += _mm512_cmpeq_epi32_mask(_mm512_and_si512(value, ascii_mask), zero).count_ones() as usize;
+                narrow += _mm512_cmpeq_epi32_mask(_mm512_and_si512(value, narrow_mask), zero).count_ones() as usize;
+                bmp += _mm512_cmpeq_epi32_mask(_mm512_and_si512(value, bmp_mask), zero).count_ones() as usize;
+            }
+
+            index += 64_usize;
+        }
+
+        let (tail_ascii, tail_narrow, tail_bmp): (usize, usize, usize) = UTF32::count_classes_from(array, index, length, endian);
+
+        return (ascii + tail_ascii, narrow + tail_narrow, bmp + tail_bmp);
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn count_classes_avx2(array: &[u8], endian: bool) -> (usize, usize, usize) {
+        let (length, mut index): (usize, usize) = (array.len() - array.len() % 4_usize, 0_usize);
+
+        let (mut ascii, mut narrow, mut bmp): (usize, usize, usize) = (0_usize, 0_usize, 0_usize);
+
+        let (ascii_mask, narrow_mask, bmp_mask): (__m256i, __m256i, __m256i) = if endian {
+            unsafe { (_mm256_set1_epi32(-0x00000080), _mm256_set1_epi32(-0x00000800), _mm256_set1_epi32(-0x00010000)) } // 0xFFFFFF80, 0xFFFFF800, 0xFFFF0000
+        } else {
+            unsafe { (_mm256_set1_epi32(-0x7F000001), _mm256_set1_epi32(0x00F8FFFF), _mm256_set1_epi32(0x0000FFFF)) } // 0x80FFFFFF, 0x00F8FFFF, 0x0000FFFF
+        };
+
+        let zero: __m256i = unsafe { _mm256_set1_epi32(0x00000000) };
+
+        while index + 32_usize <= length {
+            let value: __m256i = unsafe { _mm256_loadu_si256(array.as_ptr().add(index) as *const __m256i) };
+
+            unsafe {
+                ascii += _mm256_movemask_epi8(_mm256_cmpeq_epi32(_mm256_and_si256(value, ascii_mask), zero)).count_ones() as usize / 4_usize;
+                narrow += _mm256_movemask_epi8(_mm256_cmpeq_epi32(_mm256_and_si256(value, narrow_mask), zero)).count_ones() as usize / 4_usize;
+                bmp += _mm256_movemask_epi8(_mm256_cmpeq_epi32(_mm256_and_si256(value, bmp_mask), zero)).count_ones() as usize / 4_usize;
+            }
+
+            index += 32_usize;
+        }
+
+        let (tail_ascii, tail_narrow, tail_bmp): (usize, usize, usize) = UTF32::count_classes_from(array, index, length, endian);
+
+        return (ascii + tail_ascii, narrow + tail_narrow, bmp + tail_bmp);
+    }
+
+    #[target_feature(enable = "sse2")]
+    #[allow(unused_unsafe)]
+    unsafe fn count_classes_sse2(array: &[u8], endian: bool) -> (usize, usize, usize) {
+        let (length, mut index): (usize, usize) = (array.len() - array.len() % 4_usize, 0_usize);
+
+        let (mut ascii, mut narrow, mut bmp): (usize, usize, usize) = (0_usize, 0_usize, 0_usize);
+
+        let (ascii_mask, narrow_mask, bmp_mask): (__m128i, __m128i, __m128i) = if endian {
+            unsafe { (_mm_set1_epi32(-0x00000080), _mm_set1_epi32(-0x00000800), _mm_set1_epi32(-0x00010000)) } // 0xFFFFFF80, 0xFFFFF800, 0xFFFF0000
+        } else {
+            unsafe { (_mm_set1_epi32(-0x7F000001), _mm_set1_epi32(0x00F8FFFF), _mm_set1_epi32(0x0000FFFF)) } // 0x80FFFFFF, 0x00F8FFFF, 0x0000FFFF
+        };
+
+        let zero: __m128i = unsafe { _mm_set1_epi32(0x00000000) };
+
+        while index + 16_usize <= length {
+            let value: __m128i = unsafe { _mm_loadu_si128(array.as_ptr().add(index) as *const __m128i) };
+
+            unsafe {
+                ascii += _mm_movemask_epi8(_mm_cmpeq_epi32(_mm_and_si128(value, ascii_mask), zero)).count_ones() as usize / 4_usize;
+                narrow += _mm_movemask_epi8(_mm_cmpeq_epi32(_mm_and_si128(value, narrow_mask), zero)).count_ones() as usize / 4_usize;
+                bmp += _mm_movemask_epi8(_mm_cmpeq_epi32(_mm_and_si128(value, bmp_mask), zero)).count_ones() as usize / 4_usize;
+            }
+
+            index += 16_usize;
+        }
+
+        let (tail_ascii, tail_narrow, tail_bmp): (usize, usize, usize) = UTF32::count_classes_from(array, index, length, endian);
+
+        return (ascii + tail_ascii, narrow + tail_narrow, bmp + tail_bmp);
+    }
+
+    pub fn stats(array: &[u8], endian: bool) -> Statistics {
+        return UTF32::statistics_from(array.len() / 4_usize, dispatch!(unsafe fn(array: &[u8], endian: bool) -> (usize, usize, usize), UTF32::count_classes_sse2, UTF32::count_classes_avx2, UTF32::count_classes_avx512));
+    }
+
+    pub fn utf8_length_from_utf32(array: &[u8], endian: bool) -> usize {
+        let (ascii, narrow, bmp): (usize, usize, usize) = dispatch!(unsafe fn(array: &[u8], endian: bool) -> (usize, usize, usize), UTF32::count_classes_sse2, UTF32::count_classes_avx2, UTF32::count_classes_avx512);
+
+        return array.len() / 4_usize * 4_usize - ascii - narrow - bmp;
+    }
+
+    pub fn utf16_length_from_utf32(array: &[u8], endian: bool) -> usize {
+        return array.len() / 4_usize * 2_usize - dispatch!(unsafe fn(array: &[u8], endian: bool) -> (usize, usize, usize), UTF32::count_classes_sse2, UTF32::count_classes_avx2, UTF32::count_classes_avx512).2;
+    }
+}
@@ -27,6 +27,8 @@
  */
 
 mod validation;
+mod transcoding;
+mod counting;
 mod search;
 
 mod unite {
@@ -0,0 +1,337 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF16
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+use crate::{
+    functors::{
+        platform::{
+            dispatch::{
+                dispatch
+            }
+        }
+    }
+};
+
+use core::{
+    arch::{
+        x86::{
+            __m512i,
+            _mm512_loadu_si512,
+            _mm512_set1_epi16,
+            _mm512_test_epi16_mask,
+            _mm512_srli_epi16,
+            _mm512_cvtepi16_epi8,
+            _mm512_storeu_si512,
+            _mm512_cvtepu16_epi32,
+            _mm512_castsi512_si256,
+            _mm512_extracti64x4_epi64,
+            _mm512_slli_epi32,
+            __m256i,
+            _mm256_loadu_si256,
+            _mm256_storeu_si256,
+            _mm256_set1_epi16,
+            _mm256_setzero_si256,
+            _mm256_and_si256,
+            _mm256_cmpeq_epi16,
+            _mm256_movemask_epi8,
+            _mm256_srli_epi16,
+            _mm256_castsi256_si128,
+            _mm256_extracti128_si256,
+            _mm256_cvtepu16_epi32,
+            _mm256_slli_epi32,
+            __m128i,
+            _mm_loadu_si128,
+            _mm_storeu_si128,
+            _mm_storel_epi64,
+            _mm_set1_epi16,
+            _mm_setzero_si128,
+            _mm_and_si128,
+            _mm_cmpeq_epi16,
+            _mm_movemask_epi8,
+            _mm_srli_epi16,
+            _mm_packus_epi16,
+            _mm_unpacklo_epi16,
+            _mm_unpackhi_epi16
+        }
+    }
+};
+
+impl UTF16 {
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf8_into_avx512(array: &[u8], output: &mut [u8], endian: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 2_usize, output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let not_ascii_mask: __m512i = unsafe { if endian { _mm512_set1_epi16(-0x0080) } else { _mm512_set1_epi16(-0x7F01) } }; // 0xFF80, 0x80FF
+
+        while read + 64_usize <= length {
+            let value: __m512i = unsafe { _mm512_loadu_si512(array.as_ptr().add(read) as *const __m512i) };
+
+            if unsafe { _mm512_test_epi16_mask(value, not_ascii_mask) } == 0_u32 {
+                if written + 32_usize > capacity { break; }
+
+                let units: __m512i = if endian { value } else { unsafe { _mm512_srli_epi16(value, 8) } };
+
+                unsafe { _mm256_storeu_si256(output.as_mut_ptr().add(written) as *mut __m256i, _mm512_cvtepi16_epi8(units)) };
+
+                read += 64_usize; written += 32_usize;
+            } else if unsafe { UTF16::is_utf16_bmp_16x32(&value, 1_usize, endian) } {
+                if written + 96_usize > capacity { break; }
+
+                written = UTF16::transcode_bmp_utf8_from(array, read, read + 64_usize, output, written, endian);
+
+                read += 64_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = UTF16::transcode_utf8_from(array, read, read + 64_usize, output, written, endian)?;
+
+                if next_read < read + 64_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return UTF16::transcode_utf8_from(array, read, length, output, written, endian);
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf8_into_avx2(array: &[u8], output: &mut [u8], endian: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 2_usize, output.len());
+
+        let (mut read, mut written, zero): (usize, usize, __m256i) = (0_usize, 0_usize, unsafe { _mm256_setzero_si256() });
+
+        let not_ascii_mask: __m256i = unsafe { if endian { _mm256_set1_epi16(-0x0080) } else { _mm256_set1_epi16(-0x7F01) } }; // 0xFF80, 0x80FF
+
+        while read + 32_usize <= length {
+            let value: __m256i = unsafe { _mm256_loadu_si256(array.as_ptr().add(read) as *const __m256i) };
+
+            if unsafe { _mm256_movemask_epi8(_mm256_cmpeq_epi16(_mm256_and_si256(value, not_ascii_mask), zero)) } == -1_i32 {
+                if written + 16_usize > capacity { break; }
+
+                let units: __m256i = if endian { value } else { unsafe { _mm256_srli_epi16(value, 8) } };
+
+                unsafe {
+                    _mm_storeu_si128(
+                        output.as_mut_ptr().add(written) as *mut __m128i,
+                        _mm_packus_epi16(_mm256_castsi256_si128(units), _mm256_extracti128_si256(units, 1))
+                    )
+                };
+
+                read += 32_usize; written += 16_usize;
+            } else if unsafe { UTF16::is_utf16_bmp_16x16(&value, 1_usize, endian) } {
+                if written + 48_usize > capacity { break; }
+
+                written = UTF16::transcode_bmp_utf8_from(array, read, read + 32_usize, output, written, endian);
+
+                read += 32_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = UTF16::transcode_utf8_from(array, read, read + 32_usize, output, written, endian)?;
+
+                if next_read < read + 32_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return UTF16::transcode_utf8_from(array, read, length, output, written, endian);
+    }
+
+    #[target_feature(enable = "sse2")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf8_into_sse2(array: &[u8], output: &mut [u8], endian: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 2_usize, output.len());
+
+        let (mut read, mut written, zero): (usize, usize, __m128i) = (0_usize, 0_usize, unsafe { _mm_setzero_si128() });
+
+        let not_ascii_mask: __m128i = unsafe { if endian { _mm_set1_epi16(-0x0080) } else { _mm_set1_epi16(-0x7F01) } }; // 0xFF80, 0x80FF
+
+        while read + 16_usize <= length {
+            let value: __m128i = unsafe { _mm_loadu_si128(array.as_ptr().add(read) as *const __m128i) };
+
+            if unsafe { _mm_movemask_epi8(_mm_cmpeq_epi16(_mm_and_si128(value, not_ascii_mask), zero)) } == 0xFFFF_i32 {
+                if written + 8_usize > capacity { break; }
+
+                let units: __m128i = if endian { value } else { unsafe { _mm_srli_epi16(value, 8) } };
+
+                unsafe { _mm_storel_epi64(output.as_mut_ptr().add(written) as *mut __m128i, _mm_packus_epi16(units, units)) };
+
+                read += 16_usize; written += 8_usize;
+            } else if unsafe { UTF16::is_utf16_bmp_16x8(&value, 1_usize, endian) } {
+                if written + 24_usize > capacity { break; }
+
+                written = UTF16::transcode_bmp_utf8_from(array, read, read + 16_usize, output, written, endian);
+
+                read += 16_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = UTF16::transcode_utf8_from(array, read, read + 16_usize, output, written, endian)?;
+
+                if next_read < read + 16_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return UTF16::transcode_utf8_from(array, read, length, output, written, endian);
+    }
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf32_into_avx512(array: &[u8], output: &mut [u32], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 2_usize, output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        while read + 64_usize <= length && written + 32_usize <= capacity {
+            let value: __m512i = unsafe { _mm512_loadu_si512(array.as_ptr().add(read) as *const __m512i) };
+
+            if unsafe { UTF16::is_utf16_bmp_16x32(&value, 1_usize, endian) } {
+                let (low, high): (__m512i, __m512i) = unsafe {
+                    (_mm512_cvtepu16_epi32(_mm512_castsi512_si256(value)), _mm512_cvtepu16_epi32(_mm512_extracti64x4_epi64(value, 1)))
+                };
+
+                let (low, high): (__m512i, __m512i) = if endian { (low, high) } else { unsafe { (_mm512_slli_epi32(low, 16), _mm512_slli_epi32(high, 16)) } };
+
+                unsafe {
+                    _mm512_storeu_si512(output.as_mut_ptr().add(written) as *mut __m512i, low);
+                    _mm512_storeu_si512(output.as_mut_ptr().add(written + 16_usize) as *mut __m512i, high);
+                }
+
+                read += 64_usize; written += 32_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = UTF16::transcode_utf32_from(array, read, read + 64_usize, output, written, endian, checked)?;
+
+                if next_read < read + 64_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return UTF16::transcode_utf32_from(array, read, length, output, written, endian, checked);
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf32_into_avx2(array: &[u8], output: &mut [u32], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 2_usize, output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        while read + 32_usize <= length && written + 16_usize <= capacity {
+            let value: __m256i = unsafe { _mm256_loadu_si256(array.as_ptr().add(read) as *const __m256i) };
+
+            if unsafe { UTF16::is_utf16_bmp_16x16(&value, 1_usize, endian) } {
+                let (low, high): (__m256i, __m256i) = unsafe {
+                    (_mm256_cvtepu16_epi32(_mm256_castsi256_si128(value)), _mm256_cvtepu16_epi32(_mm256_extracti128_si256(value, 1)))
+                };
+
+                let (low, high): (__m256i, __m256i) = if endian { (low, high) } else { unsafe { (_mm256_slli_epi32(low, 16), _mm256_slli_epi32(high, 16)) } };
+
+                unsafe {
+                    _mm256_storeu_si256(output.as_mut_ptr().add(written) as *mut __m256i, low);
+                    _mm256_storeu_si256(output.as_mut_ptr().add(written + 8_usize) as *mut __m256i, high);
+                }
+
+                read += 32_usize; written += 16_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = UTF16::transcode_utf32_from(array, read, read + 32_usize, output, written, endian, checked)?;
+
+                if next_read < read + 32_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return UTF16::transcode_utf32_from(array, read, length, output, written, endian, checked);
+    }
+
+    #[target_feature(enable = "sse2")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf32_into_sse2(array: &[u8], output: &mut [u32], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 2_usize, output.len());
+
+        let (mut read, mut written, zero): (usize, usize, __m128i) = (0_usize, 0_usize, unsafe { _mm_setzero_si128() });
+
+        while read + 16_usize <= length && written + 8_usize <= capacity {
+            let value: __m128i = unsafe { _mm_loadu_si128(array.as_ptr().add(read) as *const __m128i) };
+
+            if unsafe { UTF16::is_utf16_bmp_16x8(&value, 1_usize, endian) } {
+                let (low, high): (__m128i, __m128i) = if endian {
+                    unsafe { (_mm_unpacklo_epi16(value, zero), _mm_unpackhi_epi16(value, zero)) }
+                } else {
+                    unsafe { (_mm_unpacklo_epi16(zero, value), _mm_unpackhi_epi16(zero, value)) }
+                };
+
+                unsafe {
+                    _mm_storeu_si128(output.as_mut_ptr().add(written) as *mut __m128i, low);
+                    _mm_storeu_si128(output.as_mut_ptr().add(written + 4_usize) as *mut __m128i, high);
+                }
+
+                read += 16_usize; written += 8_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = UTF16::transcode_utf32_from(array, read, read + 16_usize, output, written, endian, checked)?;
+
+                if next_read < read + 16_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return UTF16::transcode_utf32_from(array, read, length, output, written, endian, checked);
+    }
+
+    pub fn to_utf8_into(array: &[u8], output: &mut [u8], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return dispatch!(unsafe fn(array: &[u8], output: &mut [u8], endian: bool) -> Result<(usize, usize), EncodingError>, UTF16::to_utf8_into_sse2, UTF16::to_utf8_into_avx2, UTF16::to_utf8_into_avx512);
+    }
+
+    pub fn to_utf32_into(array: &[u8], output: &mut [u32], endian: bool) -> Result<(usize, usize), EncodingError> {
+        let checked: bool = true;
+
+        return dispatch!(unsafe fn(array: &[u8], output: &mut [u32], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError>, UTF16::to_utf32_into_sse2, UTF16::to_utf32_into_avx2, UTF16::to_utf32_into_avx512);
+    }
+
+    pub fn to_utf32_into_unchecked(array: &[u8], output: &mut [u32], endian: bool) -> (usize, usize) {
+        let checked: bool = false;
+
+        return dispatch!(unsafe fn(array: &[u8], output: &mut [u32], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError>, UTF16::to_utf32_into_sse2, UTF16::to_utf32_into_avx2, UTF16::to_utf32_into_avx512).unwrap_or_default();
+    }
+}
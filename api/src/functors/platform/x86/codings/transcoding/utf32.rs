@@ -0,0 +1,365 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF32
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+use crate::{
+    functors::{
+        platform::{
+            dispatch::{
+                dispatch
+            }
+        }
+    }
+};
+
+use core::{
+    arch::{
+        x86::{
+            __m512i,
+            _mm512_loadu_si512,
+            _mm512_set1_epi32,
+            _mm512_test_epi32_mask,
+            _mm512_srli_epi32,
+            _mm512_cvtepi32_epi8,
+            _mm512_cvtepi32_epi16,
+            __m256i,
+            _mm256_loadu_si256,
+            _mm256_storeu_si256,
+            _mm256_set1_epi32,
+            _mm256_or_si256,
+            _mm256_testz_si256,
+            _mm256_srli_epi32,
+            _mm256_packs_epi32,
+            _mm256_packus_epi32,
+            _mm256_permute4x64_epi64,
+            _mm256_castsi256_si128,
+            _mm256_extracti128_si256,
+            __m128i,
+            _mm_loadu_si128,
+            _mm_storeu_si128,
+            _mm_set1_epi16,
+            _mm_set1_epi32,
+            _mm_setzero_si128,
+            _mm_or_si128,
+            _mm_and_si128,
+            _mm_xor_si128,
+            _mm_sub_epi32,
+            _mm_cmpeq_epi32,
+            _mm_movemask_epi8,
+            _mm_srli_epi32,
+            _mm_packs_epi32,
+            _mm_packus_epi16
+        }
+    }
+};
+
+impl UTF32 {
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf8_into_avx512(array: &[u8], output: &mut [u8], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 4_usize, output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let not_ascii_mask: __m512i = unsafe { if endian { _mm512_set1_epi32(-0x00000080) } else { _mm512_set1_epi32(-0x7F000001) } }; // 0xFFFFFF80, 0x80FFFFFF
+
+        while read + 64_usize <= length {
+            let value: __m512i = unsafe { _mm512_loadu_si512(array.as_ptr().add(read) as *const __m512i) };
+
+            if checked && !unsafe { UTF32::is_utf32_32x16(&value, 1_usize, endian) } { break; }
+
+            if unsafe { _mm512_test_epi32_mask(value, not_ascii_mask) } == 0_u16 {
+                if written + 16_usize > capacity { break; }
+
+                let value: __m512i = if endian { value } else { unsafe { _mm512_srli_epi32(value, 24) } };
+
+                unsafe { _mm_storeu_si128(output.as_mut_ptr().add(written) as *mut __m128i, _mm512_cvtepi32_epi8(value)) };
+
+                read += 64_usize; written += 16_usize;
+            } else {
+                if written + 64_usize > capacity { break; }
+
+                (read, written) = UTF32::transcode_utf8_from(array, read, read + 64_usize, output, written, endian, false)?;
+            }
+        }
+
+        return UTF32::transcode_utf8_from(array, read, length, output, written, endian, checked);
+    }
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf16_into_avx512(array: &[u8], output: &mut [u16], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 4_usize, output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let not_bmp_mask: __m512i = unsafe { if endian { _mm512_set1_epi32(-0x00010000) } else { _mm512_set1_epi32(0x0000FFFF) } }; // 0xFFFF0000, 0x0000FFFF
+
+        while read + 64_usize <= length {
+            let value: __m512i = unsafe { _mm512_loadu_si512(array.as_ptr().add(read) as *const __m512i) };
+
+            if checked && !unsafe { UTF32::is_utf32_32x16(&value, 1_usize, endian) } { break; }
+
+            if unsafe { _mm512_test_epi32_mask(value, not_bmp_mask) } == 0_u16 {
+                if written + 16_usize > capacity { break; }
+
+                let value: __m512i = if endian { value } else { unsafe { _mm512_srli_epi32(value, 16) } };
+
+                unsafe { _mm256_storeu_si256(output.as_mut_ptr().add(written) as *mut __m256i, _mm512_cvtepi32_epi16(value)) };
+
+                read += 64_usize; written += 16_usize;
+            } else {
+                if written + 32_usize > capacity { break; }
+
+                (read, written) = UTF32::transcode_utf16_from(array, read, read + 64_usize, output, written, endian, false)?;
+            }
+        }
+
+        return UTF32::transcode_utf16_from(array, read, length, output, written, endian, checked);
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf8_into_avx2(array: &[u8], output: &mut [u8], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 4_usize, output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let not_ascii_mask: __m256i = unsafe { if endian { _mm256_set1_epi32(-0x00000080) } else { _mm256_set1_epi32(-0x7F000001) } }; // 0xFFFFFF80, 0x80FFFFFF
+
+        while read + 64_usize <= length {
+            let block: [__m256i; 2_usize] = unsafe {
+                [
+                    _mm256_loadu_si256(array.as_ptr().add(read) as *const __m256i),
+                    _mm256_loadu_si256(array.as_ptr().add(read + 32_usize) as *const __m256i)
+                ]
+            };
+
+            if checked && !unsafe { UTF32::is_utf32_32x8(block.as_ptr(), block.len(), endian) } { break; }
+
+            if unsafe { _mm256_testz_si256(_mm256_or_si256(block[0], block[1]), not_ascii_mask) } == 1_i32 {
+                if written + 16_usize > capacity { break; }
+
+                let (low, high): (__m256i, __m256i) = if endian { (block[0], block[1]) } else { unsafe { (_mm256_srli_epi32(block[0], 24), _mm256_srli_epi32(block[1], 24)) } };
+
+                let units: __m256i = unsafe { _mm256_permute4x64_epi64(_mm256_packs_epi32(low, high), 0xD8) };
+
+                unsafe { _mm_storeu_si128(output.as_mut_ptr().add(written) as *mut __m128i, _mm_packus_epi16(_mm256_castsi256_si128(units), _mm256_extracti128_si256(units, 1))) };
+
+                read += 64_usize; written += 16_usize;
+            } else {
+                if written + 64_usize > capacity { break; }
+
+                (read, written) = UTF32::transcode_utf8_from(array, read, read + 64_usize, output, written, endian, false)?;
+            }
+        }
+
+        return UTF32::transcode_utf8_from(array, read, length, output, written, endian, checked);
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf16_into_avx2(array: &[u8], output: &mut [u16], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 4_usize, output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let not_bmp_mask: __m256i = unsafe { if endian { _mm256_set1_epi32(-0x00010000) } else { _mm256_set1_epi32(0x0000FFFF) } }; // 0xFFFF0000, 0x0000FFFF
+
+        while read + 64_usize <= length {
+            let block: [__m256i; 2_usize] = unsafe {
+                [
+                    _mm256_loadu_si256(array.as_ptr().add(read) as *const __m256i),
+                    _mm256_loadu_si256(array.as_ptr().add(read + 32_usize) as *const __m256i)
+                ]
+            };
+
+            if checked && !unsafe { UTF32::is_utf32_32x8(block.as_ptr(), block.len(), endian) } { break; }
+
+            if unsafe { _mm256_testz_si256(_mm256_or_si256(block[0], block[1]), not_bmp_mask) } == 1_i32 {
+                if written + 16_usize > capacity { break; }
+
+                let (low, high): (__m256i, __m256i) = if endian { (block[0], block[1]) } else { unsafe { (_mm256_srli_epi32(block[0], 16), _mm256_srli_epi32(block[1], 16)) } };
+
+                unsafe { _mm256_storeu_si256(output.as_mut_ptr().add(written) as *mut __m256i, _mm256_permute4x64_epi64(_mm256_packus_epi32(low, high), 0xD8)) };
+
+                read += 64_usize; written += 16_usize;
+            } else {
+                if written + 32_usize > capacity { break; }
+
+                (read, written) = UTF32::transcode_utf16_from(array, read, read + 64_usize, output, written, endian, false)?;
+            }
+        }
+
+        return UTF32::transcode_utf16_from(array, read, length, output, written, endian, checked);
+    }
+
+    #[target_feature(enable = "sse2")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf8_into_sse2(array: &[u8], output: &mut [u8], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 4_usize, output.len());
+
+        let (mut read, mut written, zero): (usize, usize, __m128i) = (0_usize, 0_usize, unsafe { _mm_setzero_si128() });
+
+        let not_ascii_mask: __m128i = unsafe { if endian { _mm_set1_epi32(-0x00000080) } else { _mm_set1_epi32(-0x7F000001) } }; // 0xFFFFFF80, 0x80FFFFFF
+
+        while read + 64_usize <= length {
+            let block: [__m128i; 4_usize] = unsafe {
+                [
+                    _mm_loadu_si128(array.as_ptr().add(read) as *const __m128i),
+                    _mm_loadu_si128(array.as_ptr().add(read + 16_usize) as *const __m128i),
+                    _mm_loadu_si128(array.as_ptr().add(read + 32_usize) as *const __m128i),
+                    _mm_loadu_si128(array.as_ptr().add(read + 48_usize) as *const __m128i)
+                ]
+            };
+
+            if checked && !unsafe { UTF32::is_utf32_32x4(block.as_ptr(), block.len(), endian) } { break; }
+
+            let combined: __m128i = unsafe { _mm_or_si128(_mm_or_si128(block[0], block[1]), _mm_or_si128(block[2], block[3])) };
+
+            if unsafe { _mm_movemask_epi8(_mm_cmpeq_epi32(_mm_and_si128(combined, not_ascii_mask), zero)) } == 0xFFFF_i32 {
+                if written + 16_usize > capacity { break; }
+
+                let block: [__m128i; 4_usize] = if endian {
+                    block
+                } else {
+                    unsafe { [_mm_srli_epi32(block[0], 24), _mm_srli_epi32(block[1], 24), _mm_srli_epi32(block[2], 24), _mm_srli_epi32(block[3], 24)] }
+                };
+
+                unsafe {
+                    _mm_storeu_si128(
+                        output.as_mut_ptr().add(written) as *mut __m128i,
+                        _mm_packus_epi16(_mm_packs_epi32(block[0], block[1]), _mm_packs_epi32(block[2], block[3]))
+                    )
+                };
+
+                read += 64_usize; written += 16_usize;
+            } else {
+                if written + 64_usize > capacity { break; }
+
+                (read, written) = UTF32::transcode_utf8_from(array, read, read + 64_usize, output, written, endian, false)?;
+            }
+        }
+
+        return UTF32::transcode_utf8_from(array, read, length, output, written, endian, checked);
+    }
+
+    #[target_feature(enable = "sse2")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf16_into_sse2(array: &[u8], output: &mut [u16], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 4_usize, output.len());
+
+        let (mut read, mut written, zero): (usize, usize, __m128i) = (0_usize, 0_usize, unsafe { _mm_setzero_si128() });
+
+        let not_bmp_mask: __m128i = unsafe { if endian { _mm_set1_epi32(-0x00010000) } else { _mm_set1_epi32(0x0000FFFF) } }; // 0xFFFF0000, 0x0000FFFF
+
+        let (signed_bias, unsigned_bias): (__m128i, __m128i) = unsafe { (_mm_set1_epi32(0x00008000), _mm_set1_epi16(-0x8000)) }; // 0x8000
+
+        while read + 64_usize <= length {
+            let block: [__m128i; 4_usize] = unsafe {
+                [
+                    _mm_loadu_si128(array.as_ptr().add(read) as *const __m128i),
+                    _mm_loadu_si128(array.as_ptr().add(read + 16_usize) as *const __m128i),
+                    _mm_loadu_si128(array.as_ptr().add(read + 32_usize) as *const __m128i),
+                    _mm_loadu_si128(array.as_ptr().add(read + 48_usize) as *const __m128i)
+                ]
+            };
+
+            if checked && !unsafe { UTF32::is_utf32_32x4(block.as_ptr(), block.len(), endian) } { break; }
+
+            let combined: __m128i = unsafe { _mm_or_si128(_mm_or_si128(block[0], block[1]), _mm_or_si128(block[2], block[3])) };
+
+            if unsafe { _mm_movemask_epi8(_mm_cmpeq_epi32(_mm_and_si128(combined, not_bmp_mask), zero)) } == 0xFFFF_i32 {
+                if written + 16_usize > capacity { break; }
+
+                let block: [__m128i; 4_usize] = if endian {
+                    unsafe { [_mm_sub_epi32(block[0], signed_bias), _mm_sub_epi32(block[1], signed_bias), _mm_sub_epi32(block[2], signed_bias), _mm_sub_epi32(block[3], signed_bias)] }
+                } else {
+                    unsafe {
+                        [
+                            _mm_sub_epi32(_mm_srli_epi32(block[0], 16), signed_bias),
+                            _mm_sub_epi32(_mm_srli_epi32(block[1], 16), signed_bias),
+                            _mm_sub_epi32(_mm_srli_epi32(block[2], 16), signed_bias),
+                            _mm_sub_epi32(_mm_srli_epi32(block[3], 16), signed_bias)
+                        ]
+                    }
+                };
+
+                unsafe {
+                    _mm_storeu_si128(output.as_mut_ptr().add(written) as *mut __m128i, _mm_xor_si128(_mm_packs_epi32(block[0], block[1]), unsigned_bias));
+                    _mm_storeu_si128(output.as_mut_ptr().add(written + 8_usize) as *mut __m128i, _mm_xor_si128(_mm_packs_epi32(block[2], block[3]), unsigned_bias));
+                }
+
+                read += 64_usize; written += 16_usize;
+            } else {
+                if written + 32_usize > capacity { break; }
+
+                (read, written) = UTF32::transcode_utf16_from(array, read, read + 64_usize, output, written, endian, false)?;
+            }
+        }
+
+        return UTF32::transcode_utf16_from(array, read, length, output, written, endian, checked);
+    }
+
+    pub fn to_utf8_into(array: &[u8], output: &mut [u8], endian: bool) -> Result<(usize, usize), EncodingError> {
+        let checked: bool = true;
+
+        return dispatch!(unsafe fn(array: &[u8], output: &mut [u8], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError>, UTF32::to_utf8_into_sse2, UTF32::to_utf8_into_avx2, UTF32::to_utf8_into_avx512);
+    }
+
+    pub fn to_utf8_into_unchecked(array: &[u8], output: &mut [u8], endian: bool) -> (usize, usize) {
+        let checked: bool = false;
+
+        return dispatch!(unsafe fn(array: &[u8], output: &mut [u8], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError>, UTF32::to_utf8_into_sse2, UTF32::to_utf8_into_avx2, UTF32::to_utf8_into_avx512).unwrap_or_default();
+    }
+
+    pub fn to_utf16_into(array: &[u8], output: &mut [u16], endian: bool) -> Result<(usize, usize), EncodingError> {
+        let checked: bool = true;
+
+        return dispatch!(unsafe fn(array: &[u8], output: &mut [u16], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError>, UTF32::to_utf16_into_sse2, UTF32::to_utf16_into_avx2, UTF32::to_utf16_into_avx512);
+    }
+
+    pub fn to_utf16_into_unchecked(array: &[u8], output: &mut [u16], endian: bool) -> (usize, usize) {
+        let checked: bool = false;
+
+        return dispatch!(unsafe fn(array: &[u8], output: &mut [u16], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError>, UTF32::to_utf16_into_sse2, UTF32::to_utf16_into_avx2, UTF32::to_utf16_into_avx512).unwrap_or_default();
+    }
+}
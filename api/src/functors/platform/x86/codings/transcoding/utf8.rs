@@ -0,0 +1,341 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF8
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+use crate::{
+    functors::{
+        platform::{
+            dispatch::{
+                dispatch
+            }
+        }
+    }
+};
+
+use core::{
+    arch::{
+        x86::{
+            __m512i,
+            _mm512_loadu_si512,
+            _mm512_storeu_si512,
+            _mm512_movepi8_mask,
+            _mm512_cvtepu8_epi16,
+            _mm512_castsi512_si256,
+            _mm512_extracti64x4_epi64,
+            _mm512_slli_epi16,
+            _mm512_cvtepu8_epi32,
+            _mm512_extracti32x4_epi32,
+            _mm512_slli_epi32,
+            __m256i,
+            _mm256_loadu_si256,
+            _mm256_storeu_si256,
+            _mm256_movemask_epi8,
+            _mm256_cvtepu8_epi16,
+            _mm256_castsi256_si128,
+            _mm256_extracti128_si256,
+            _mm256_slli_epi16,
+            _mm256_cvtepu8_epi32,
+            _mm256_slli_epi32,
+            __m128i,
+            _mm_loadu_si128,
+            _mm_storeu_si128,
+            _mm_movemask_epi8,
+            _mm_setzero_si128,
+            _mm_unpacklo_epi8,
+            _mm_unpackhi_epi8,
+            _mm_unpacklo_epi16,
+            _mm_unpackhi_epi16,
+            _mm_srli_si128,
+            _mm_slli_epi32
+        }
+    }
+};
+
+impl UTF8 {
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf16_into_avx512(array: &[u8], output: &mut [u16], endian: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        while read + 64_usize <= length && written + 64_usize <= capacity {
+            let value: __m512i = unsafe { _mm512_loadu_si512(array.as_ptr().add(read) as *const __m512i) };
+
+            if unsafe { _mm512_movepi8_mask(value) } == 0_u64 {
+                let (low, high): (__m512i, __m512i) = unsafe {
+                    (_mm512_cvtepu8_epi16(_mm512_castsi512_si256(value)), _mm512_cvtepu8_epi16(_mm512_extracti64x4_epi64(value, 1)))
+                };
+
+                let (low, high): (__m512i, __m512i) = if endian { (low, high) } else { unsafe { (_mm512_slli_epi16(low, 8), _mm512_slli_epi16(high, 8)) } };
+
+                unsafe {
+                    _mm512_storeu_si512(output.as_mut_ptr().add(written) as *mut __m512i, low);
+                    _mm512_storeu_si512(output.as_mut_ptr().add(written + 32_usize) as *mut __m512i, high);
+                }
+
+                read += 64_usize; written += 64_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = UTF8::transcode_utf16_from(array, read, read + 64_usize, output, written, endian)?;
+
+                if next_read < read + 64_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return UTF8::transcode_utf16_from(array, read, length, output, written, endian);
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf16_into_avx2(array: &[u8], output: &mut [u16], endian: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        while read + 32_usize <= length && written + 32_usize <= capacity {
+            let value: __m256i = unsafe { _mm256_loadu_si256(array.as_ptr().add(read) as *const __m256i) };
+
+            if unsafe { _mm256_movemask_epi8(value) } == 0_i32 {
+                let (low, high): (__m256i, __m256i) = unsafe {
+                    (_mm256_cvtepu8_epi16(_mm256_castsi256_si128(value)), _mm256_cvtepu8_epi16(_mm256_extracti128_si256(value, 1)))
+                };
+
+                let (low, high): (__m256i, __m256i) = if endian { (low, high) } else { unsafe { (_mm256_slli_epi16(low, 8), _mm256_slli_epi16(high, 8)) } };
+
+                unsafe {
+                    _mm256_storeu_si256(output.as_mut_ptr().add(written) as *mut __m256i, low);
+                    _mm256_storeu_si256(output.as_mut_ptr().add(written + 16_usize) as *mut __m256i, high);
+                }
+
+                read += 32_usize; written += 32_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = UTF8::transcode_utf16_from(array, read, read + 32_usize, output, written, endian)?;
+
+                if next_read < read + 32_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return UTF8::transcode_utf16_from(array, read, length, output, written, endian);
+    }
+
+    #[target_feature(enable = "sse2")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf16_into_sse2(array: &[u8], output: &mut [u16], endian: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written, zero): (usize, usize, __m128i) = (0_usize, 0_usize, unsafe { _mm_setzero_si128() });
+
+        while read + 16_usize <= length && written + 16_usize <= capacity {
+            let value: __m128i = unsafe { _mm_loadu_si128(array.as_ptr().add(read) as *const __m128i) };
+
+            if unsafe { _mm_movemask_epi8(value) } == 0_i32 {
+                let (low, high): (__m128i, __m128i) = if endian {
+                    unsafe { (_mm_unpacklo_epi8(value, zero), _mm_unpackhi_epi8(value, zero)) }
+                } else {
+                    unsafe { (_mm_unpacklo_epi8(zero, value), _mm_unpackhi_epi8(zero, value)) }
+                };
+
+                unsafe {
+                    _mm_storeu_si128(output.as_mut_ptr().add(written) as *mut __m128i, low);
+                    _mm_storeu_si128(output.as_mut_ptr().add(written + 8_usize) as *mut __m128i, high);
+                }
+
+                read += 16_usize; written += 16_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = UTF8::transcode_utf16_from(array, read, read + 16_usize, output, written, endian)?;
+
+                if next_read < read + 16_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return UTF8::transcode_utf16_from(array, read, length, output, written, endian);
+    }
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf32_into_avx512(array: &[u8], output: &mut [u32], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        while read + 64_usize <= length && written + 64_usize <= capacity {
+            let value: __m512i = unsafe { _mm512_loadu_si512(array.as_ptr().add(read) as *const __m512i) };
+
+            if unsafe { _mm512_movepi8_mask(value) } == 0_u64 {
+                let codes: [__m512i; 4_usize] = unsafe {
+                    [
+                        _mm512_cvtepu8_epi32(_mm512_extracti32x4_epi32(value, 0)),
+                        _mm512_cvtepu8_epi32(_mm512_extracti32x4_epi32(value, 1)),
+                        _mm512_cvtepu8_epi32(_mm512_extracti32x4_epi32(value, 2)),
+                        _mm512_cvtepu8_epi32(_mm512_extracti32x4_epi32(value, 3))
+                    ]
+                };
+
+                let mut index: usize = 0_usize;
+
+                while index < 4_usize {
+                    let code: __m512i = if endian { codes[index] } else { unsafe { _mm512_slli_epi32(codes[index], 24) } };
+
+                    unsafe { _mm512_storeu_si512(output.as_mut_ptr().add(written + index * 16_usize) as *mut __m512i, code) };
+
+                    index += 1_usize;
+                }
+
+                read += 64_usize; written += 64_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = UTF8::transcode_utf32_from(array, read, read + 64_usize, output, written, endian, checked)?;
+
+                if next_read < read + 64_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return UTF8::transcode_utf32_from(array, read, length, output, written, endian, checked);
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf32_into_avx2(array: &[u8], output: &mut [u32], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        while read + 32_usize <= length && written + 32_usize <= capacity {
+            let value: __m256i = unsafe { _mm256_loadu_si256(array.as_ptr().add(read) as *const __m256i) };
+
+            if unsafe { _mm256_movemask_epi8(value) } == 0_i32 {
+                let (low, high): (__m128i, __m128i) = unsafe { (_mm256_castsi256_si128(value), _mm256_extracti128_si256(value, 1)) };
+
+                let codes: [__m256i; 4_usize] = unsafe {
+                    [
+                        _mm256_cvtepu8_epi32(low),
+                        _mm256_cvtepu8_epi32(_mm_srli_si128(low, 8)),
+                        _mm256_cvtepu8_epi32(high),
+                        _mm256_cvtepu8_epi32(_mm_srli_si128(high, 8))
+                    ]
+                };
+
+                let mut index: usize = 0_usize;
+
+                while index < 4_usize {
+                    let code: __m256i = if endian { codes[index] } else { unsafe { _mm256_slli_epi32(codes[index], 24) } };
+
+                    unsafe { _mm256_storeu_si256(output.as_mut_ptr().add(written + index * 8_usize) as *mut __m256i, code) };
+
+                    index += 1_usize;
+                }
+
+                read += 32_usize; written += 32_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = UTF8::transcode_utf32_from(array, read, read + 32_usize, output, written, endian, checked)?;
+
+                if next_read < read + 32_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return UTF8::transcode_utf32_from(array, read, length, output, written, endian, checked);
+    }
+
+    #[target_feature(enable = "sse2")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf32_into_sse2(array: &[u8], output: &mut [u32], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written, zero): (usize, usize, __m128i) = (0_usize, 0_usize, unsafe { _mm_setzero_si128() });
+
+        while read + 16_usize <= length && written + 16_usize <= capacity {
+            let value: __m128i = unsafe { _mm_loadu_si128(array.as_ptr().add(read) as *const __m128i) };
+
+            if unsafe { _mm_movemask_epi8(value) } == 0_i32 {
+                let (low, high): (__m128i, __m128i) = unsafe { (_mm_unpacklo_epi8(value, zero), _mm_unpackhi_epi8(value, zero)) };
+
+                let codes: [__m128i; 4_usize] = unsafe {
+                    [_mm_unpacklo_epi16(low, zero), _mm_unpackhi_epi16(low, zero), _mm_unpacklo_epi16(high, zero), _mm_unpackhi_epi16(high, zero)]
+                };
+
+                let mut index: usize = 0_usize;
+
+                while index < 4_usize {
+                    let code: __m128i = if endian { codes[index] } else { unsafe { _mm_slli_epi32(codes[index], 24) } };
+
+                    unsafe { _mm_storeu_si128(output.as_mut_ptr().add(written + index * 4_usize) as *mut __m128i, code) };
+
+                    index += 1_usize;
+                }
+
+                read += 16_usize; written += 16_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = UTF8::transcode_utf32_from(array, read, read + 16_usize, output, written, endian, checked)?;
+
+                if next_read < read + 16_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return UTF8::transcode_utf32_from(array, read, length, output, written, endian, checked);
+    }
+
+    pub fn to_utf16_into(array: &[u8], output: &mut [u16], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return dispatch!(unsafe fn(array: &[u8], output: &mut [u16], endian: bool) -> Result<(usize, usize), EncodingError>, UTF8::to_utf16_into_sse2, UTF8::to_utf16_into_avx2, UTF8::to_utf16_into_avx512);
+    }
+
+    pub fn to_utf32_into(array: &[u8], output: &mut [u32], endian: bool) -> Result<(usize, usize), EncodingError> {
+        let checked: bool = true;
+
+        return dispatch!(unsafe fn(array: &[u8], output: &mut [u32], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError>, UTF8::to_utf32_into_sse2, UTF8::to_utf32_into_avx2, UTF8::to_utf32_into_avx512);
+    }
+
+    pub fn to_utf32_into_unchecked(array: &[u8], output: &mut [u32], endian: bool) -> (usize, usize) {
+        let checked: bool = false;
+
+        return dispatch!(unsafe fn(array: &[u8], output: &mut [u32], endian: bool, checked: bool) -> Result<(usize, usize), EncodingError>, UTF8::to_utf32_into_sse2, UTF8::to_utf32_into_avx2, UTF8::to_utf32_into_avx512).unwrap_or_default();
+    }
+}
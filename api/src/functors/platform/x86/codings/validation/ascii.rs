@@ -32,31 +32,32 @@ pub use crate::{
     }
 };
 
-#[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
-use core::{
-    arch::{
-        x86::{
-            _mm512_loadu_si512,
-            _mm512_movepi8_mask
-        }
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
     }
 };
 
-#[cfg(all(target_feature = "avx", target_feature = "avx2", not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-use core::{
-    arch::{
-        x86::{
-            __m256i,
-            _mm256_loadu_si256,
-            _mm256_movemask_epi8
+use crate::{
+    functors::{
+        platform::{
+            dispatch::{
+                dispatch
+            }
         }
     }
 };
 
-#[cfg(all(target_feature = "sse2", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
 use core::{
     arch::{
         x86::{
+            __m512i,
+            _mm512_loadu_si512,
+            _mm512_movepi8_mask,
+            __m256i,
+            _mm256_loadu_si256,
+            _mm256_movemask_epi8,
             __m128i,
             _mm_loadu_si128,
             _mm_movemask_epi8
@@ -66,17 +67,19 @@ use core::{
 
 impl ASCII {
 
-    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
-    fn is_ascii_8x64(array: *const u8, length: usize) -> bool {
+    const __VALIDATION_WINDOW_BYTES: usize = 65536_usize;
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    unsafe fn is_ascii_8x64(array: *const u8, length: usize) -> bool {
         let mut index: usize = 0_usize;
 
-        while index < length { if unsafe { _mm512_movepi8_mask(_mm512_loadu_si512(array.add(index) as *const i32)) } != 0_u64 { return false; } else { index += 64_usize; } }
+        while index < length { if unsafe { _mm512_movepi8_mask(_mm512_loadu_si512(array.add(index) as *const __m512i)) } != 0_u64 { return false; } else { index += 64_usize; } }
 
         return true;
     }
 
-    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
-    pub fn is_ascii_from_byte_array(array: &[u8]) -> bool {
+    #[target_feature(enable = "avx512f,avx512bw")]
+    unsafe fn is_ascii_from_byte_array_avx512(array: &[u8]) -> bool {
         let length: usize = array.len();
 
         if length == 0_usize { return false; }
@@ -104,8 +107,8 @@ impl ASCII {
         return result;
     }
 
-    #[cfg(all(target_feature = "avx", target_feature = "avx2", not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-    fn is_ascii_8x32(array: *const u8, length: usize) -> bool {
+    #[target_feature(enable = "avx,avx2")]
+    unsafe fn is_ascii_8x32(array: *const u8, length: usize) -> bool {
         let mut index: usize = 0_usize;
 
         while index < length { if unsafe { _mm256_movemask_epi8(_mm256_loadu_si256(array.add(index) as *const __m256i)) } != 0_i32 { return false; } else { index += 32_usize; } }
@@ -113,8 +116,8 @@ impl ASCII {
         return true;
     }
 
-    #[cfg(all(target_feature = "avx", target_feature = "avx2", not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-    pub fn is_ascii_from_byte_array(array: &[u8]) -> bool {
+    #[target_feature(enable = "avx,avx2")]
+    unsafe fn is_ascii_from_byte_array_avx2(array: &[u8]) -> bool {
         let length: usize = array.len();
 
         if length == 0_usize { return false; }
@@ -142,8 +145,8 @@ impl ASCII {
         return result;
     }
 
-    #[cfg(all(target_feature = "sse2", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-    fn is_ascii_8x16(array: *const u8, length: usize) -> bool {
+    #[target_feature(enable = "sse2")]
+    unsafe fn is_ascii_8x16(array: *const u8, length: usize) -> bool {
         let mut index: usize = 0_usize;
 
         while index < length { if unsafe { _mm_movemask_epi8(_mm_loadu_si128(array.add(index) as *const __m128i)) } != 0_i32 { return false; } else { index += 16_usize; } }
@@ -151,8 +154,8 @@ impl ASCII {
         return true;
     }
 
-    #[cfg(all(target_feature = "sse2", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-    pub fn is_ascii_from_byte_array(array: &[u8]) -> bool {
+    #[target_feature(enable = "sse2")]
+    unsafe fn is_ascii_from_byte_array_sse2(array: &[u8]) -> bool {
         let length: usize = array.len();
 
         if length == 0_usize { return false; }
@@ -179,4 +182,28 @@ impl ASCII {
 
         return result;
     }
+
+    pub fn is_ascii_from_byte_array(array: &[u8]) -> bool {
+        return dispatch!(unsafe fn(array: &[u8]) -> bool, ASCII::is_ascii_from_byte_array_sse2, ASCII::is_ascii_from_byte_array_avx2, ASCII::is_ascii_from_byte_array_avx512);
+    }
+
+    pub fn validate_ascii_from_byte_array(array: &[u8]) -> Result<(), EncodingError> {
+        let length: usize = array.len();
+
+        if length == 0_usize { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        let mut start: usize = 0_usize;
+
+        while start < length {
+            let end: usize = if length - start > ASCII::__VALIDATION_WINDOW_BYTES { start + ASCII::__VALIDATION_WINDOW_BYTES } else { length };
+
+            if !ASCII::is_ascii_from_byte_array(&array[start..end]) {
+                ASCII::locate_invalid_ascii(&array[start..end], start)?;
+            }
+
+            start = end;
+        }
+
+        return Ok(());
+    }
 }
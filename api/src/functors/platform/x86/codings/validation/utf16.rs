@@ -32,7 +32,23 @@ pub use crate::{
     }
 };
 
-#[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+use crate::{
+    functors::{
+        platform::{
+            dispatch::{
+                dispatch
+            }
+        }
+    }
+};
+
 use core::{
     hint::{
         black_box
@@ -53,20 +69,6 @@ use core::{
             _mm512_and_si512,
             _mm512_set_epi8,
             _mm512_shuffle_epi8,
-        }
-    }
-};
-
-#[cfg(all(target_feature = "avx", target_feature = "avx2", not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-use core::{
-    hint::{
-        black_box
-    },
-    mem::{
-        transmute
-    },
-    arch::{
-        x86::{
             __m256i,
             _mm256_loadu_si256,
             _mm256_set_epi16,
@@ -80,21 +82,7 @@ use core::{
             _mm256_set_epi8,
             _mm256_shuffle_epi8,
             _mm256_permute2x128_si256,
-            _mm256_alignr_epi8
-        }
-    }
-};
-
-#[cfg(all(target_feature = "sse2", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-use core::{
-    hint::{
-        black_box
-    },
-    mem::{
-        transmute
-    },
-    arch::{
-        x86::{
+            _mm256_alignr_epi8,
             __m128i,
             _mm_loadu_si128,
             _mm_set_epi16,
@@ -112,34 +100,28 @@ use core::{
     }
 };
 
-#[cfg(all(target_feature = "sse2", target_feature = "ssse3", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-use core::{
-    arch::{
-        x86::{
-            _mm_set_epi8,
-            _mm_shuffle_epi8
-        }
-    }
-};
-
-#[cfg(all(target_feature = "sse2", not(target_feature = "ssse3"), not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
 use core::{
     arch::{
         x86::{
             _mm_sra_epi16,
-            _mm_sll_epi16,
+            _mm_sll_epi16
         }
     }
 };
 
+// Shifts a register of code units by one unit across the 128-bit lanes, masking with `zero`.
+type CrossLaneShift256 = fn(value: __m256i, zero: __m256i) -> __m256i;
+
 impl UTF16 {
 
     const __ENCODING_REGULAR_PAIR_BYTES:   usize = 2_usize;
     const __ENCODING_SURROGATE_PAIR_BYTES: usize = 4_usize;
+    const __VALIDATION_WINDOW_BYTES:       usize = 65536_usize;
 
-    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
-    fn is_utf16_bmp_16x32(array: &[__m512i], endian: bool) -> bool {
-        let (mut index, length): (usize, usize) = (0_usize, array.len());
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    pub(crate) unsafe fn is_utf16_bmp_16x32(array: *const __m512i, length: usize, endian: bool) -> bool {
+        let mut index: usize = 0_usize;
 
         let (bad_range_mask, bad_result_mask): (__m512i, __m512i) =
             unsafe { (_mm512_set1_epi16(-0x0800), _mm512_set1_epi16(-0x2800)) }; // 0xF800, 0xD800
@@ -154,9 +136,9 @@ impl UTF16 {
         if endian {
             while index < length {
                 let value: __m512i = if cfg!(target_endian = "big") {
-                    unsafe { _mm512_shuffle_epi8(_mm512_loadu_si512(black_box(&array[index] as *const __m512i as *const i32)), swap_endian) }
+                    unsafe { _mm512_shuffle_epi8(_mm512_loadu_si512(black_box(array.add(index))), swap_endian) }
                 } else {
-                    unsafe { _mm512_loadu_si512(black_box(&array[index] as *const __m512i as *const i32)) }
+                    unsafe { _mm512_loadu_si512(black_box(array.add(index))) }
                 };
 
                 if unsafe { _mm512_cmpeq_epi16_mask(_mm512_and_si512(value, bad_range_mask), bad_result_mask) } > 0_u32 { return false; }
@@ -166,9 +148,9 @@ impl UTF16 {
         } else {
             while index < length {
                 let value: __m512i = if cfg!(target_endian = "big") {
-                    unsafe { _mm512_loadu_si512(black_box(&array[index] as *const __m512i as *const i32)) }
+                    unsafe { _mm512_loadu_si512(black_box(array.add(index))) }
                 } else {
-                    unsafe { _mm512_shuffle_epi8(_mm512_loadu_si512(black_box(&array[index] as *const __m512i as *const i32)), swap_endian) }
+                    unsafe { _mm512_shuffle_epi8(_mm512_loadu_si512(black_box(array.add(index))), swap_endian) }
                 };
 
                 if unsafe { _mm512_cmpeq_epi16_mask(_mm512_and_si512(value, bad_range_mask), bad_result_mask) } > 0_u32 { return false; }
@@ -180,10 +162,11 @@ impl UTF16 {
         return true;
     }
 
-    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
-    fn is_utf16_omp_16x32(array: &[__m512i], endian: bool) -> bool {
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn is_utf16_omp_16x32(array: *const __m512i, length: usize, endian: bool) -> bool {
 
-        let (mut index, length): (usize, usize) = (0_usize, array.len());
+        let mut index: usize = 0_usize;
 
         let (max_signed_mask, restricted_less_than_mask, restricted_big_than_mask): (__m512i, __m512i, __m512i) =
             // 0xFFFF: -0x0001
@@ -217,9 +200,9 @@ impl UTF16 {
         if endian {
             while index < length {
                 let value: __m512i = if cfg!(target_endian = "big") {
-                    unsafe { _mm512_shuffle_epi8(_mm512_loadu_si512(black_box(&array[index] as *const __m512i as *const i32)), swap_endian) }
+                    unsafe { _mm512_shuffle_epi8(_mm512_loadu_si512(black_box(array.add(index))), swap_endian) }
                 } else {
-                    unsafe { _mm512_loadu_si512(black_box(&array[index] as *const __m512i as *const i32)) }
+                    unsafe { _mm512_loadu_si512(black_box(array.add(index))) }
                 };
 
                 if unsafe { _mm512_cmpgt_epi16_mask(value, max_signed_mask) } != 0_u32 { return false; }
@@ -230,9 +213,9 @@ impl UTF16 {
         } else {
             while index < length {
                 let value: __m512i = if cfg!(target_endian = "big") {
-                    unsafe { _mm512_loadu_si512(black_box(&array[index] as *const __m512i as *const i32)) }
+                    unsafe { _mm512_loadu_si512(black_box(array.add(index))) }
                 } else {
-                    unsafe { _mm512_shuffle_epi8(_mm512_loadu_si512(black_box(&array[index] as *const __m512i as *const i32)), swap_endian) }
+                    unsafe { _mm512_shuffle_epi8(_mm512_loadu_si512(black_box(array.add(index))), swap_endian) }
                 };
 
                 if unsafe { _mm512_cmpgt_epi16_mask(value, max_signed_mask) } != 0_u32 { return false; }
@@ -245,10 +228,11 @@ impl UTF16 {
         return true;
     }
 
-    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
-    fn is_utf16_mixed_16x32(array: &[__m512i], endian: bool, mut continuation: bool) -> bool {
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn is_utf16_mixed_16x32(array: *const __m512i, length: usize, endian: bool, mut continuation: bool) -> bool {
 
-        let (mut index, length): (usize, usize) = (0_usize, array.len());
+        let mut index: usize = 0_usize;
 
         let (any_part_surrogate_detect_mask, following_surrogate_detect_mask): (__m512i, __m512i) = unsafe {
             (_mm512_set1_epi16(-0x0800), _mm512_set1_epi16(-0x0400)) // 0xF800, 0xFC00
@@ -270,9 +254,9 @@ impl UTF16 {
         if endian {
             while index < length {
                 let value: __m512i = if cfg!(target_endian = "big") {
-                    unsafe { _mm512_shuffle_epi8(_mm512_loadu_si512(black_box(&array[index] as *const __m512i as *const i32)), swap_endian) }
+                    unsafe { _mm512_shuffle_epi8(_mm512_loadu_si512(black_box(array.add(index))), swap_endian) }
                 } else {
-                    unsafe { _mm512_loadu_si512(black_box(&array[index] as *const __m512i as *const i32)) }
+                    unsafe { _mm512_loadu_si512(black_box(array.add(index))) }
                 };
 
                 let any_surrogate_mask: __mmask32 = unsafe { _mm512_cmpeq_epi16_mask(_mm512_and_si512(value, any_part_surrogate_detect_mask), high_surrogate_detect_mask) };
@@ -358,9 +342,9 @@ impl UTF16 {
         } else {
             while index < length {
                 let value: __m512i = if cfg!(target_endian = "big") {
-                    unsafe { _mm512_loadu_si512(black_box(&array[index] as *const __m512i as *const i32)) }
+                    unsafe { _mm512_loadu_si512(black_box(array.add(index))) }
                 } else {
-                    unsafe { _mm512_shuffle_epi8(_mm512_loadu_si512(black_box(&array[index] as *const __m512i as *const i32)), swap_endian) }
+                    unsafe { _mm512_shuffle_epi8(_mm512_loadu_si512(black_box(array.add(index))), swap_endian) }
                 };
 
                 let any_surrogate_mask: __mmask32 = unsafe { _mm512_cmpeq_epi16_mask(_mm512_and_si512(value, any_part_surrogate_detect_mask), high_surrogate_detect_mask) };
@@ -448,8 +432,8 @@ impl UTF16 {
         return true;
     }
 
-    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
-    pub fn is_utf16_from_byte_array(array: &[u8], endian: bool, omp: bool, only: bool) -> bool {
+    #[target_feature(enable = "avx512f,avx512bw")]
+    unsafe fn is_utf16_from_byte_array_avx512(array: &[u8], endian: bool, omp: bool, only: bool) -> bool {
 
         let length: usize = array.len();
 
@@ -490,7 +474,7 @@ impl UTF16 {
                         }
                     };
 
-                    result &= UTF16::is_utf16_omp_16x32(&[indivisible_code_array], endian);
+                    result &= UTF16::is_utf16_omp_16x32(&indivisible_code_array, 1_usize, endian);
                 } else {
                     let indivisible_code_array: __m512i = {
                         let mut indivisible_code_array: [u8; 64_usize] = [0_u8; 64_usize];
@@ -498,7 +482,7 @@ impl UTF16 {
                         unsafe { transmute::<[u8; 64_usize], __m512i>(indivisible_code_array) }
                     };
 
-                    result &= UTF16::is_utf16_bmp_16x32(&[indivisible_code_array], endian);
+                    result &= UTF16::is_utf16_bmp_16x32(&indivisible_code_array, 1_usize, endian);
                 }
             } else {
                 if length < 65_usize {
@@ -508,7 +492,7 @@ impl UTF16 {
                         unsafe { transmute::<[u8; 64_usize], __m512i>(indivisible_code_array) }
                     };
 
-                    result &= UTF16::is_utf16_mixed_16x32(&[indivisible_code_array], endian, false);
+                    result &= UTF16::is_utf16_mixed_16x32(&indivisible_code_array, 1_usize, endian, false);
                 } else {
                     let align_indivisible: usize = indivisible + indivisible % 2_usize;
 
@@ -525,7 +509,7 @@ impl UTF16 {
                             unsafe { transmute::<[u8; 64_usize], __m512i>(indivisible_code_array) }
                         };
 
-                        result &= UTF16::is_utf16_mixed_16x32(&[indivisible_code_array], endian, false);
+                        result &= UTF16::is_utf16_mixed_16x32(&indivisible_code_array, 1_usize, endian, false);
                     } else {
                         continuation = true;
 
@@ -536,7 +520,7 @@ impl UTF16 {
                                 unsafe { transmute::<[u8; 64_usize], __m512i>(indivisible_code_array) }
                             };
 
-                            result &= UTF16::is_utf16_mixed_16x32(&[indivisible_code_array], endian, false);
+                            result &= UTF16::is_utf16_mixed_16x32(&indivisible_code_array, 1_usize, endian, false);
                         }
                     }
                 }
@@ -549,12 +533,12 @@ impl UTF16 {
             if remains_length != 0_usize {
                 if only {
                     if omp {
-                        result &= UTF16::is_utf16_omp_16x32(unsafe { core::slice::from_raw_parts::<__m512i>(transmute::<*const u8, *const __m512i>(array.as_ptr().add(indivisible)), remains_length / 64_usize) }, endian);
+                        result &= UTF16::is_utf16_omp_16x32(unsafe { transmute::<*const u8, *const __m512i>(array.as_ptr().add(indivisible)) }, remains_length / 64_usize, endian);
                     } else {
-                        result &= UTF16::is_utf16_bmp_16x32(unsafe { core::slice::from_raw_parts::<__m512i>(transmute::<*const u8, *const __m512i>(array.as_ptr().add(indivisible)), remains_length / 64_usize) }, endian);
+                        result &= UTF16::is_utf16_bmp_16x32(unsafe { transmute::<*const u8, *const __m512i>(array.as_ptr().add(indivisible)) }, remains_length / 64_usize, endian);
                     }
                 } else {
-                    result &= UTF16::is_utf16_mixed_16x32(unsafe { core::slice::from_raw_parts::<__m512i>(transmute::<*const u8, *const __m512i>(array.as_ptr().add(indivisible)), remains_length / 64_usize) }, endian, continuation);
+                    result &= UTF16::is_utf16_mixed_16x32(unsafe { transmute::<*const u8, *const __m512i>(array.as_ptr().add(indivisible)) }, remains_length / 64_usize, endian, continuation);
                 }
             }
         }
@@ -562,10 +546,11 @@ impl UTF16 {
         return result;
     }
 
-    #[cfg(all(target_feature = "avx", target_feature = "avx2", not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-    fn is_utf16_bmp_16x16(array: &[__m256i], endian: bool) -> bool {
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    pub(crate) unsafe fn is_utf16_bmp_16x16(array: *const __m256i, length: usize, endian: bool) -> bool {
 
-        let (mut index, length): (usize, usize) = (0_usize, array.len());
+        let mut index: usize = 0_usize;
 
         let (bad_range_mask, bad_result_mask): (__m256i, __m256i) =
             unsafe { (_mm256_set1_epi16(-0x0800), _mm256_set1_epi16(-0x2800)) }; // 0xF800, 0xD800
@@ -578,9 +563,9 @@ impl UTF16 {
         if endian {
             while index < length {
                 let value: __m256i = if cfg!(target_endian = "big") {
-                    unsafe { _mm256_shuffle_epi8(_mm256_loadu_si256(black_box(&array[index])), swap_endian) }
+                    unsafe { _mm256_shuffle_epi8(_mm256_loadu_si256(black_box(array.add(index))), swap_endian) }
                 } else {
-                    unsafe { _mm256_loadu_si256(black_box(&array[index])) }
+                    unsafe { _mm256_loadu_si256(black_box(array.add(index))) }
                 };
 
                 if unsafe { _mm256_movemask_epi8(_mm256_cmpeq_epi16(_mm256_and_si256(value, bad_range_mask), bad_result_mask)) } != 0_i32 { return false; }
@@ -590,9 +575,9 @@ impl UTF16 {
         } else {
             while index < length {
                 let value: __m256i = if cfg!(target_endian = "big") {
-                    unsafe { _mm256_loadu_si256(black_box(&array[index])) }
+                    unsafe { _mm256_loadu_si256(black_box(array.add(index))) }
                 } else {
-                    unsafe { _mm256_shuffle_epi8(_mm256_loadu_si256(black_box(&array[index])), swap_endian) }
+                    unsafe { _mm256_shuffle_epi8(_mm256_loadu_si256(black_box(array.add(index))), swap_endian) }
                 };
 
                 if unsafe { _mm256_movemask_epi8(_mm256_cmpeq_epi16(_mm256_and_si256(value, bad_range_mask), bad_result_mask)) } != 0_i32 { return false; }
@@ -604,10 +589,11 @@ impl UTF16 {
         return true;
     }
 
-    #[cfg(all(target_feature = "avx", target_feature = "avx2", not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-    fn is_utf16_omp_16x16(array: &[__m256i], endian: bool) -> bool {
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn is_utf16_omp_16x16(array: *const __m256i, length: usize, endian: bool) -> bool {
 
-        let (mut index, length): (usize, usize) = (0_usize, array.len());
+        let mut index: usize = 0_usize;
 
         let (max_signed_mask, restricted_less_than_mask, restricted_big_than_mask): (__m256i, __m256i, __m256i) =
             // 0xFFFF: -0x0001
@@ -635,23 +621,22 @@ impl UTF16 {
         if endian {
             while index < length {
                 let value: __m256i = if cfg!(target_endian = "big") {
-                    unsafe { _mm256_shuffle_epi8(_mm256_loadu_si256(black_box(&array[index])), swap_endian) }
+                    unsafe { _mm256_shuffle_epi8(_mm256_loadu_si256(black_box(array.add(index))), swap_endian) }
                 } else {
-                    unsafe { _mm256_loadu_si256(black_box(&array[index])) }
+                    unsafe { _mm256_loadu_si256(black_box(array.add(index))) }
                 };
 
                 if unsafe { _mm256_movemask_epi8(_mm256_cmpgt_epi16(value, max_signed_mask)) } != 0_i32 { return false; }
                 else if unsafe { _mm256_movemask_epi8(_mm256_or_si256(_mm256_cmpgt_epi16(restricted_less_than_mask, value), _mm256_cmpgt_epi16(value, restricted_big_than_mask))) } != 0_i32 { return false; }
 
-
                 index += 1_usize;
             }
         } else {
             while index < length {
                 let value: __m256i = if cfg!(target_endian = "big") {
-                    unsafe { _mm256_loadu_si256(black_box(&array[index])) }
+                    unsafe { _mm256_loadu_si256(black_box(array.add(index))) }
                 } else {
-                    unsafe { _mm256_shuffle_epi8(_mm256_loadu_si256(black_box(&array[index])), swap_endian) }
+                    unsafe { _mm256_shuffle_epi8(_mm256_loadu_si256(black_box(array.add(index))), swap_endian) }
                 };
 
                 if unsafe { _mm256_movemask_epi8(_mm256_cmpgt_epi16(value, max_signed_mask)) } != 0_i32 { return false; }
@@ -664,10 +649,11 @@ impl UTF16 {
         return true;
     }
 
-    #[cfg(all(target_feature = "avx", target_feature = "avx2", not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-    fn is_utf16_mixed_16x16(array: &[__m256i], endian: bool, mut continuation: bool) -> bool {
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn is_utf16_mixed_16x16(array: *const __m256i, length: usize, endian: bool, mut continuation: bool) -> bool {
 
-        let (mut index, length): (usize, usize) = (0_usize, array.len());
+        let mut index: usize = 0_usize;
 
         let (any_part_surrogate_detect_mask, following_surrogate_detect_mask): (__m256i, __m256i) = unsafe {
             (_mm256_set1_epi16(-0x0800), _mm256_set1_epi16(-0x0400)) // 0xF800, 0xFC00
@@ -719,7 +705,7 @@ impl UTF16 {
             )
         };
 
-        let (shift_left, shift_right): (fn(value: __m256i, zero: __m256i) -> __m256i, fn(value: __m256i, zero: __m256i) -> __m256i) =
+        let (shift_left, shift_right): (CrossLaneShift256, CrossLaneShift256) =
             (
                 |value: __m256i, zero: __m256i| -> __m256i { unsafe { _mm256_and_si256(_mm256_alignr_epi8::<14>(value, _mm256_permute2x128_si256::<1>(value, value)), zero) } },
                 |value: __m256i, zero: __m256i| -> __m256i { unsafe { _mm256_and_si256(_mm256_alignr_epi8::<2>(_mm256_permute2x128_si256::<1>(value, value), value), zero) } }
@@ -728,9 +714,9 @@ impl UTF16 {
         if endian {
             while index < length {
                 let value: __m256i = if cfg!(target_endian = "big") {
-                    unsafe { _mm256_shuffle_epi8(_mm256_loadu_si256(black_box(&array[index])), swap_endian) }
+                    unsafe { _mm256_shuffle_epi8(_mm256_loadu_si256(black_box(array.add(index))), swap_endian) }
                 } else {
-                    unsafe { _mm256_loadu_si256(black_box(&array[index])) }
+                    unsafe { _mm256_loadu_si256(black_box(array.add(index))) }
                 };
 
                 let any_surrogate_mask: __m256i = unsafe { _mm256_cmpeq_epi16(_mm256_and_si256(value, any_part_surrogate_detect_mask), high_surrogate_detect_mask) };
@@ -820,9 +806,9 @@ impl UTF16 {
         } else {
             while index < length {
                 let value: __m256i = if cfg!(target_endian = "big") {
-                    unsafe { _mm256_loadu_si256(black_box(&array[index])) }
+                    unsafe { _mm256_loadu_si256(black_box(array.add(index))) }
                 } else {
-                    unsafe { _mm256_shuffle_epi8(_mm256_loadu_si256(black_box(&array[index])), swap_endian) }
+                    unsafe { _mm256_shuffle_epi8(_mm256_loadu_si256(black_box(array.add(index))), swap_endian) }
                 };
 
                 let any_surrogate_mask: __m256i = unsafe { _mm256_cmpeq_epi16(_mm256_and_si256(value, any_part_surrogate_detect_mask), high_surrogate_detect_mask) };
@@ -914,8 +900,8 @@ impl UTF16 {
         return true;
     }
 
-    #[cfg(all(target_feature = "avx", target_feature = "avx2", not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-    pub fn is_utf16_from_byte_array(array: &[u8], endian: bool, omp: bool, only: bool) -> bool {
+    #[target_feature(enable = "avx,avx2")]
+    unsafe fn is_utf16_from_byte_array_avx2(array: &[u8], endian: bool, omp: bool, only: bool) -> bool {
 
         let length: usize = array.len();
 
@@ -952,7 +938,7 @@ impl UTF16 {
                         }
                     };
 
-                    result &= UTF16::is_utf16_omp_16x16(&[indivisible_code_array], endian);
+                    result &= UTF16::is_utf16_omp_16x16(&indivisible_code_array, 1_usize, endian);
                 } else {
                     let indivisible_code_array: __m256i = {
                         let mut indivisible_code_array: [u8; 32_usize] = [0_u8; 32_usize];
@@ -960,7 +946,7 @@ impl UTF16 {
                         unsafe { transmute::<[u8; 32_usize], __m256i>(indivisible_code_array) }
                     };
 
-                    result &= UTF16::is_utf16_bmp_16x16(&[indivisible_code_array], endian);
+                    result &= UTF16::is_utf16_bmp_16x16(&indivisible_code_array, 1_usize, endian);
                 }
             } else {
                 if length < 33_usize {
@@ -970,7 +956,7 @@ impl UTF16 {
                         unsafe { transmute::<[u8; 32_usize], __m256i>(indivisible_code_array) }
                     };
 
-                    result &= UTF16::is_utf16_mixed_16x16(&[indivisible_code_array], endian, false);
+                    result &= UTF16::is_utf16_mixed_16x16(&indivisible_code_array, 1_usize, endian, false);
                 } else {
                     let align_indivisible: usize = indivisible + indivisible % 2_usize;
 
@@ -987,7 +973,7 @@ impl UTF16 {
                             unsafe { transmute::<[u8; 32_usize], __m256i>(indivisible_code_array) }
                         };
 
-                        result &= UTF16::is_utf16_mixed_16x16(&[indivisible_code_array], endian, false);
+                        result &= UTF16::is_utf16_mixed_16x16(&indivisible_code_array, 1_usize, endian, false);
                     } else {
                         continuation = true;
 
@@ -998,7 +984,7 @@ impl UTF16 {
                                 unsafe { transmute::<[u8; 32_usize], __m256i>(indivisible_code_array) }
                             };
 
-                            result &= UTF16::is_utf16_mixed_16x16(&[indivisible_code_array], endian, false);
+                            result &= UTF16::is_utf16_mixed_16x16(&indivisible_code_array, 1_usize, endian, false);
                         }
                     }
                 }
@@ -1011,12 +997,12 @@ impl UTF16 {
             if remains_length != 0_usize {
                 if only {
                     if omp {
-                        result &= UTF16::is_utf16_omp_16x16(unsafe { core::slice::from_raw_parts::<__m256i>(transmute::<*const u8, *const __m256i>(array.as_ptr().add(indivisible)), remains_length / 32_usize) }, endian);
+                        result &= UTF16::is_utf16_omp_16x16(unsafe { transmute::<*const u8, *const __m256i>(array.as_ptr().add(indivisible)) }, remains_length / 32_usize, endian);
                     } else {
-                        result &= UTF16::is_utf16_bmp_16x16(unsafe { core::slice::from_raw_parts::<__m256i>(transmute::<*const u8, *const __m256i>(array.as_ptr().add(indivisible)), remains_length / 32_usize) }, endian);
+                        result &= UTF16::is_utf16_bmp_16x16(unsafe { transmute::<*const u8, *const __m256i>(array.as_ptr().add(indivisible)) }, remains_length / 32_usize, endian);
                     }
                 } else {
-                    result &= UTF16::is_utf16_mixed_16x16(unsafe { core::slice::from_raw_parts::<__m256i>(transmute::<*const u8, *const __m256i>(array.as_ptr().add(indivisible)), remains_length / 32_usize) }, endian, continuation);
+                    result &= UTF16::is_utf16_mixed_16x16(unsafe { transmute::<*const u8, *const __m256i>(array.as_ptr().add(indivisible)) }, remains_length / 32_usize, endian, continuation);
                 }
             }
         }
@@ -1024,17 +1010,14 @@ impl UTF16 {
         return result;
     }
 
-    #[cfg(all(target_feature = "sse2", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-    fn is_utf16_bmp_16x8(array: &[__m128i], endian: bool) -> bool {
-        let (mut index, length): (usize, usize) = (0_usize, array.len());
+    #[target_feature(enable = "sse2")]
+    #[allow(unused_unsafe)]
+    pub(crate) unsafe fn is_utf16_bmp_16x8(array: *const __m128i, length: usize, endian: bool) -> bool {
+        let mut index: usize = 0_usize;
 
         let (bad_range_mask, bad_result_mask): (__m128i, __m128i) =
         unsafe { (_mm_set1_epi16(-0x0800), _mm_set1_epi16(-0x2800)) }; // 0xF800, 0xD800
 
-        #[cfg(target_feature = "ssse3")]
-        let swap_endian: __m128i = unsafe { _mm_set_epi8(14, 15, 12, 13, 10, 11, 8, 9, 6, 7, 4, 5, 2, 3, 0, 1) };
-
-        #[cfg(all(target_feature = "sse2", not(target_feature = "ssse3")))]
         fn swap_endian(value: __m128i) -> __m128i {
             let shift_sides: __m128i = unsafe { _mm_set_epi16(0, 0, 0, 0, 0, 0, 0, 8) };
 
@@ -1056,13 +1039,9 @@ impl UTF16 {
         if endian {
             while index < length {
                 let value: __m128i = if cfg!(target_endian = "big") {
-                    #[cfg(target_feature = "ssse3")]
-                    unsafe { _mm_shuffle_epi8(_mm_loadu_si128(black_box(&array[index])), swap_endian) }
-
-                    #[cfg(all(target_feature = "sse", target_feature = "sse2", not(target_feature = "ssse3")))]
-                    unsafe { swap_endian(_mm_loadu_si128(black_box(&array[index]))) }
+                    unsafe { swap_endian(_mm_loadu_si128(black_box(array.add(index)))) }
                 } else {
-                    unsafe { _mm_loadu_si128(black_box(&array[index])) }
+                    unsafe { _mm_loadu_si128(black_box(array.add(index))) }
                 };
 
                 if unsafe { _mm_movemask_epi8(_mm_cmpeq_epi16(_mm_and_si128(value, bad_range_mask), bad_result_mask)) } != 0_i32 { return false; }
@@ -1072,13 +1051,9 @@ impl UTF16 {
         } else {
             while index < length {
                 let value: __m128i = if cfg!(target_endian = "big") {
-                    unsafe { _mm_loadu_si128(black_box(&array[index])) }
+                    unsafe { _mm_loadu_si128(black_box(array.add(index))) }
                 } else {
-                    #[cfg(target_feature = "ssse3")]
-                    unsafe { _mm_shuffle_epi8(_mm_loadu_si128(black_box(&array[index])), swap_endian) }
-
-                    #[cfg(all(target_feature = "sse", target_feature = "sse2", not(target_feature = "ssse3")))]
-                    unsafe { swap_endian(_mm_loadu_si128(black_box(&array[index]))) }
+                    unsafe { swap_endian(_mm_loadu_si128(black_box(array.add(index)))) }
                 };
 
                 if unsafe { _mm_movemask_epi8(_mm_cmpeq_epi16(_mm_and_si128(value, bad_range_mask), bad_result_mask)) } != 0_i32 { return false; }
@@ -1090,9 +1065,10 @@ impl UTF16 {
         return true;
     }
 
-    #[cfg(all(target_feature = "sse2", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-    fn is_utf16_omp_16x8(array: &[__m128i], endian: bool) -> bool {
-        let (mut index, length): (usize, usize) = (0_usize, array.len());
+    #[target_feature(enable = "sse2")]
+    #[allow(unused_unsafe)]
+    unsafe fn is_utf16_omp_16x8(array: *const __m128i, length: usize, endian: bool) -> bool {
+        let mut index: usize = 0_usize;
 
         let (max_signed_mask, restricted_less_than_mask, restricted_big_than_mask): (__m128i, __m128i, __m128i) =
             unsafe {
@@ -1103,10 +1079,6 @@ impl UTF16 {
                 )
             };
 
-        #[cfg(target_feature = "ssse3")]
-        let swap_endian: __m128i = unsafe { _mm_set_epi8(14, 15, 12, 13, 10, 11, 8, 9, 6, 7, 4, 5, 2, 3, 0, 1) };
-
-        #[cfg(all(target_feature = "sse2", not(target_feature = "ssse3")))]
         fn swap_endian(value: __m128i) -> __m128i {
             let shift_sides: __m128i = unsafe { _mm_set_epi16(0, 0, 0, 0, 0, 0, 0, 8) };
 
@@ -1128,13 +1100,9 @@ impl UTF16 {
         if endian {
             while index < length {
                 let value: __m128i = if cfg!(target_endian = "big") {
-                    #[cfg(target_feature = "ssse3")]
-                    unsafe { _mm_shuffle_epi8(_mm_loadu_si128(black_box(&array[index])), swap_endian) }
-
-                    #[cfg(all(target_feature = "sse", target_feature = "sse2", not(target_feature = "ssse3")))]
-                    unsafe { swap_endian(_mm_loadu_si128(black_box(&array[index]))) }
+                    unsafe { swap_endian(_mm_loadu_si128(black_box(array.add(index)))) }
                 } else {
-                    unsafe { _mm_loadu_si128(black_box(&array[index])) }
+                    unsafe { _mm_loadu_si128(black_box(array.add(index))) }
                 };
 
                 if unsafe { _mm_movemask_epi8(_mm_cmpgt_epi16(value, max_signed_mask)) } != 0_i32 { return false; }
@@ -1145,19 +1113,14 @@ impl UTF16 {
         } else {
             while index < length {
                 let value: __m128i = if cfg!(target_endian = "big") {
-                    unsafe { _mm_loadu_si128(black_box(&array[index])) }
+                    unsafe { _mm_loadu_si128(black_box(array.add(index))) }
                 } else {
-                    #[cfg(target_feature = "ssse3")]
-                    unsafe { _mm_shuffle_epi8(_mm_loadu_si128(black_box(&array[index])), swap_endian) }
-
-                    #[cfg(all(target_feature = "sse", target_feature = "sse2", not(target_feature = "ssse3")))]
-                    unsafe { swap_endian(_mm_loadu_si128(black_box(&array[index]))) }
+                    unsafe { swap_endian(_mm_loadu_si128(black_box(array.add(index)))) }
                 };
 
                 if unsafe { _mm_movemask_epi8(_mm_cmpgt_epi16(value, max_signed_mask)) } != 0_i32 { return false; }
                 else if unsafe { _mm_movemask_epi8(_mm_or_si128(_mm_cmpgt_epi16(restricted_less_than_mask, value), _mm_cmplt_epi16(restricted_big_than_mask, value))) } != 0_i32 { return false; }
 
-
                 index += 1_usize;
             }
         }
@@ -1165,10 +1128,11 @@ impl UTF16 {
         return true;
     }
 
-    #[cfg(all(target_feature = "sse2", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-    fn is_utf16_mixed_16x8(array: &[__m128i], endian: bool, mut continuation: bool) -> bool {
+    #[target_feature(enable = "sse2")]
+    #[allow(unused_unsafe)]
+    unsafe fn is_utf16_mixed_16x8(array: *const __m128i, length: usize, endian: bool, mut continuation: bool) -> bool {
 
-        let (mut index, length): (usize, usize) = (0_usize, array.len());
+        let mut index: usize = 0_usize;
 
         let (any_part_surrogate_detect_mask, following_surrogate_detect_mask): (__m128i, __m128i) = unsafe {
             (_mm_set1_epi16(-0x0800), _mm_set1_epi16(-0x0400)) // 0xF800, 0xFC00
@@ -1188,10 +1152,6 @@ impl UTF16 {
 
         let and_not_mask: __m128i = unsafe { _mm_set1_epi16(-0x0001) }; // 0xFFFF: -0x0001
 
-        #[cfg(target_feature = "ssse3")]
-        let swap_endian: __m128i = unsafe { _mm_set_epi8(14, 15, 12, 13, 10, 11, 8, 9, 6, 7, 4, 5, 2, 3, 0, 1) };
-
-        #[cfg(all(target_feature = "sse2", not(target_feature = "ssse3")))]
         fn swap_endian(value: __m128i) -> __m128i {
             let shift_sides: __m128i = unsafe { _mm_set_epi16(0, 0, 0, 0, 0, 0, 0, 8) };
 
@@ -1213,13 +1173,9 @@ impl UTF16 {
         if endian {
             while index < length {
                 let value: __m128i = if cfg!(target_endian = "big") {
-                    #[cfg(target_feature = "ssse3")]
-                    unsafe { _mm_shuffle_epi8(_mm_loadu_si128(black_box(&array[index])), swap_endian) }
-
-                    #[cfg(all(target_feature = "sse", target_feature = "sse2", not(target_feature = "ssse3")))]
-                    unsafe { swap_endian(_mm_loadu_si128(black_box(&array[index]))) }
+                    unsafe { swap_endian(_mm_loadu_si128(black_box(array.add(index)))) }
                 } else {
-                    unsafe { _mm_loadu_si128(black_box(&array[index])) }
+                    unsafe { _mm_loadu_si128(black_box(array.add(index))) }
                 };
 
                 let any_surrogate_mask: __m128i = unsafe { _mm_cmpeq_epi16(_mm_and_si128(value, any_part_surrogate_detect_mask), high_surrogate_detect_mask) };
@@ -1309,13 +1265,9 @@ impl UTF16 {
         } else {
             while index < length {
                 let value: __m128i = if cfg!(target_endian = "big") {
-                    unsafe { _mm_loadu_si128(black_box(&array[index])) }
+                    unsafe { _mm_loadu_si128(black_box(array.add(index))) }
                 } else {
-                    #[cfg(target_feature = "ssse3")]
-                    unsafe { _mm_shuffle_epi8(_mm_loadu_si128(black_box(&array[index])), swap_endian) }
-
-                    #[cfg(all(target_feature = "sse", target_feature = "sse2", not(target_feature = "ssse3")))]
-                    unsafe { swap_endian(_mm_loadu_si128(black_box(&array[index]))) }
+                    unsafe { swap_endian(_mm_loadu_si128(black_box(array.add(index)))) }
                 };
 
                 let any_surrogate_mask: __m128i = unsafe { _mm_cmpeq_epi16(_mm_and_si128(value, any_part_surrogate_detect_mask), high_surrogate_detect_mask) };
@@ -1407,8 +1359,8 @@ impl UTF16 {
         return true;
     }
 
-    #[cfg(all(target_feature = "sse2", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-    pub fn is_utf16_from_byte_array(array: &[u8], endian: bool, omp: bool, only: bool) -> bool {
+    #[target_feature(enable = "sse2")]
+    unsafe fn is_utf16_from_byte_array_sse2(array: &[u8], endian: bool, omp: bool, only: bool) -> bool {
 
         let length: usize = array.len();
 
@@ -1439,7 +1391,7 @@ impl UTF16 {
                         }
                     };
 
-                    result &= UTF16::is_utf16_omp_16x8(&[indivisible_code_array], endian);
+                    result &= UTF16::is_utf16_omp_16x8(&indivisible_code_array, 1_usize, endian);
                 } else {
                     let indivisible_code_array: __m128i = {
                         let mut indivisible_code_array: [u8; 16_usize] = [0_u8; 16_usize];
@@ -1447,7 +1399,7 @@ impl UTF16 {
                         unsafe { transmute::<[u8; 16_usize], __m128i>(indivisible_code_array) }
                     };
 
-                    result &= UTF16::is_utf16_bmp_16x8(&[indivisible_code_array], endian);
+                    result &= UTF16::is_utf16_bmp_16x8(&indivisible_code_array, 1_usize, endian);
                 }
             } else {
                 if length < 17_usize {
@@ -1457,7 +1409,7 @@ impl UTF16 {
                         unsafe { transmute::<[u8; 16_usize], __m128i>(indivisible_code_array) }
                     };
 
-                    result &= UTF16::is_utf16_mixed_16x8(&[indivisible_code_array], endian, false);
+                    result &= UTF16::is_utf16_mixed_16x8(&indivisible_code_array, 1_usize, endian, false);
                 } else {
                     let align_indivisible: usize = indivisible + indivisible % 2_usize;
 
@@ -1474,7 +1426,7 @@ impl UTF16 {
                             unsafe { transmute::<[u8; 16_usize], __m128i>(indivisible_code_array) }
                         };
 
-                        result &= UTF16::is_utf16_mixed_16x8(&[indivisible_code_array], endian, false);
+                        result &= UTF16::is_utf16_mixed_16x8(&indivisible_code_array, 1_usize, endian, false);
                     } else {
                         continuation = true;
 
@@ -1485,7 +1437,7 @@ impl UTF16 {
                                 unsafe { transmute::<[u8; 16_usize], __m128i>(indivisible_code_array) }
                             };
 
-                            result &= UTF16::is_utf16_mixed_16x8(&[indivisible_code_array], endian, false);
+                            result &= UTF16::is_utf16_mixed_16x8(&indivisible_code_array, 1_usize, endian, false);
                         }
                     }
                 }
@@ -1498,16 +1450,123 @@ impl UTF16 {
             if remains_length != 0_usize {
                 if only {
                     if omp {
-                        result &= UTF16::is_utf16_omp_16x8(unsafe { core::slice::from_raw_parts::<__m128i>(transmute::<*const u8, *const __m128i>(array.as_ptr().add(indivisible)), remains_length / 16_usize) }, endian);
+                        result &= UTF16::is_utf16_omp_16x8(unsafe { transmute::<*const u8, *const __m128i>(array.as_ptr().add(indivisible)) }, remains_length / 16_usize, endian);
                     } else {
-                        result &= UTF16::is_utf16_bmp_16x8(unsafe { core::slice::from_raw_parts::<__m128i>(transmute::<*const u8, *const __m128i>(array.as_ptr().add(indivisible)), remains_length / 16_usize) }, endian);
+                        result &= UTF16::is_utf16_bmp_16x8(unsafe { transmute::<*const u8, *const __m128i>(array.as_ptr().add(indivisible)) }, remains_length / 16_usize, endian);
                     }
                 } else {
-                    result &= UTF16::is_utf16_mixed_16x8(unsafe { core::slice::from_raw_parts::<__m128i>(transmute::<*const u8, *const __m128i>(array.as_ptr().add(indivisible)), remains_length / 16_usize) }, endian, continuation);
+                    result &= UTF16::is_utf16_mixed_16x8(unsafe { transmute::<*const u8, *const __m128i>(array.as_ptr().add(indivisible)) }, remains_length / 16_usize, endian, continuation);
                 }
             }
         }
 
         return result;
     }
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn surrogate_free_prefix_avx512(array: &[u8], endian: bool) -> usize {
+        let mut index: usize = 0_usize;
+
+        let (part_of_surrogate_mask, surrogate_mask): (__m512i, __m512i) = if endian == cfg!(target_endian = "little") {
+            unsafe { (_mm512_set1_epi16(-0x0800), _mm512_set1_epi16(-0x2800)) } // 0xF800, 0xD800
+        } else {
+            unsafe { (_mm512_set1_epi16(0x00F8), _mm512_set1_epi16(0x00D8)) }
+        };
+
+        while index + 64_usize <= array.len() {
+            let value: __m512i = unsafe { _mm512_loadu_si512(transmute::<*const u8, *const __m512i>(array.as_ptr().add(index))) };
+
+            if unsafe { _mm512_cmpeq_epi16_mask(_mm512_and_si512(value, part_of_surrogate_mask), surrogate_mask) } != 0_u32 { break; }
+
+            index += 64_usize;
+        }
+
+        return index;
+    }
+
+    #[target_feature(enable = "avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn surrogate_free_prefix_avx2(array: &[u8], endian: bool) -> usize {
+        let mut index: usize = 0_usize;
+
+        let (part_of_surrogate_mask, surrogate_mask): (__m256i, __m256i) = if endian == cfg!(target_endian = "little") {
+            unsafe { (_mm256_set1_epi16(-0x0800), _mm256_set1_epi16(-0x2800)) } // 0xF800, 0xD800
+        } else {
+            unsafe { (_mm256_set1_epi16(0x00F8), _mm256_set1_epi16(0x00D8)) }
+        };
+
+        while index + 32_usize <= array.len() {
+            let value: __m256i = unsafe { _mm256_loadu_si256(transmute::<*const u8, *const __m256i>(array.as_ptr().add(index))) };
+
+            if unsafe { _mm256_movemask_epi8(_mm256_cmpeq_epi16(_mm256_and_si256(value, part_of_surrogate_mask), surrogate_mask)) } != 0_i32 { break; }
+
+            index += 32_usize;
+        }
+
+        return index;
+    }
+
+    #[target_feature(enable = "sse2")]
+    #[allow(unused_unsafe)]
+    unsafe fn surrogate_free_prefix_sse2(array: &[u8], endian: bool) -> usize {
+        let mut index: usize = 0_usize;
+
+        let (part_of_surrogate_mask, surrogate_mask): (__m128i, __m128i) = if endian == cfg!(target_endian = "little") {
+            unsafe { (_mm_set1_epi16(-0x0800), _mm_set1_epi16(-0x2800)) } // 0xF800, 0xD800
+        } else {
+            unsafe { (_mm_set1_epi16(0x00F8), _mm_set1_epi16(0x00D8)) }
+        };
+
+        while index + 16_usize <= array.len() {
+            let value: __m128i = unsafe { _mm_loadu_si128(transmute::<*const u8, *const __m128i>(array.as_ptr().add(index))) };
+
+            if unsafe { _mm_movemask_epi8(_mm_cmpeq_epi16(_mm_and_si128(value, part_of_surrogate_mask), surrogate_mask)) } != 0_i32 { break; }
+
+            index += 16_usize;
+        }
+
+        return index;
+    }
+
+    pub(crate) fn surrogate_free_prefix(array: &[u8], endian: bool) -> usize {
+        return dispatch!(unsafe fn(array: &[u8], endian: bool) -> usize, UTF16::surrogate_free_prefix_sse2, UTF16::surrogate_free_prefix_avx2, UTF16::surrogate_free_prefix_avx512);
+    }
+
+    pub fn is_utf16_from_byte_array(array: &[u8], endian: bool, omp: bool, only: bool) -> bool {
+        return dispatch!(unsafe fn(array: &[u8], endian: bool, omp: bool, only: bool) -> bool, UTF16::is_utf16_from_byte_array_sse2, UTF16::is_utf16_from_byte_array_avx2, UTF16::is_utf16_from_byte_array_avx512);
+    }
+
+    pub fn validate_utf16_from_byte_array(array: &[u8], endian: bool, omp: bool, only: bool) -> Result<(), EncodingError> {
+        let length: usize = array.len();
+
+        if length == 0_usize { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        let aligned_length: usize = if omp && only {
+            length - length % UTF16::__ENCODING_SURROGATE_PAIR_BYTES
+        } else {
+            length - length % UTF16::__ENCODING_REGULAR_PAIR_BYTES
+        };
+
+        let mut start: usize = 0_usize;
+
+        while start < aligned_length {
+            let mut end: usize = if aligned_length - start > UTF16::__VALIDATION_WINDOW_BYTES { start + UTF16::__VALIDATION_WINDOW_BYTES } else { aligned_length };
+
+            if !only && end != aligned_length && ((if endian { array[end - 1_usize] } else { array[end - 2_usize] }) & 0xFC) == 0xD8 {
+                end -= UTF16::__ENCODING_REGULAR_PAIR_BYTES;
+            }
+
+            // The locator reads past the window, so a lead just before a deferred one is judged by its real follower.
+            if !UTF16::is_utf16_from_byte_array(&array[start..end], endian, omp, only) {
+                UTF16::locate_invalid_utf16(&array[start..aligned_length], start, endian, omp, only)?;
+            }
+
+            start = end;
+        }
+
+        if aligned_length != length { return Err(EncodingError::new(aligned_length, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok(());
+    }
 }
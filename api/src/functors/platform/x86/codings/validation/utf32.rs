@@ -32,7 +32,23 @@ pub use crate::{
     }
 };
 
-#[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+use crate::{
+    functors::{
+        platform::{
+            dispatch::{
+                dispatch
+            }
+        }
+    }
+};
+
 use core::{
     hint::{
         black_box
@@ -51,21 +67,7 @@ use core::{
             _mm512_cmplt_epi32_mask,
             _mm512_cmpgt_epi32_mask,
             _mm512_cmpeq_epi32_mask,
-            _mm512_maskz_mov_epi32
-        }
-    }
-};
-
-#[cfg(all(target_feature = "avx", target_feature = "avx2", not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-use core::{
-    hint::{
-        black_box
-    },
-    mem::{
-        transmute
-    },
-    arch::{
-        x86::{
+            _mm512_maskz_mov_epi32,
             __m256i,
             _mm256_loadu_si256,
             _mm256_set1_epi32,
@@ -75,20 +77,6 @@ use core::{
             _mm256_shuffle_epi8,
             _mm256_cmpgt_epi32,
             _mm256_cmpeq_epi32,
-        }
-    }
-};
-
-#[cfg(all(target_feature = "sse2", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-use core::{
-    hint::{
-        black_box
-    },
-    mem::{
-        transmute
-    },
-    arch::{
-        x86::{
             __m128i,
             _mm_loadu_si128,
             _mm_set1_epi32,
@@ -96,22 +84,11 @@ use core::{
             _mm_cmpgt_epi32,
             _mm_and_si128,
             _mm_cmpeq_epi32,
-            _mm_movemask_epi8,
-        }
-    }
-};
-
-#[cfg(all(target_feature = "sse2", target_feature = "ssse3", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-use core::{
-    arch::{
-        x86::{
-            _mm_set_epi8,
-            _mm_shuffle_epi8
+            _mm_movemask_epi8
         }
     }
 };
 
-#[cfg(all(target_feature = "sse2", not(target_feature = "ssse3"), not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
 use core::{
     arch::{
         x86::{
@@ -126,10 +103,12 @@ use core::{
 impl UTF32 {
 
     const __ENCODING_BYTES: usize = 4_usize;
+    const __VALIDATION_WINDOW_BYTES: usize = 65536_usize;
 
-    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
-    fn is_utf32_32x16(array: &[__m512i], endian: bool) -> bool {
-        let (mut index, length): (usize, usize) = (0_usize, array.len());
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    pub(crate) unsafe fn is_utf32_32x16(array: *const __m512i, length: usize, endian: bool) -> bool {
+        let mut index: usize = 0_usize;
 
         let (max_signed_mask, max_unsigned_mask, range_mask, bad_range_mask, bad_result_mask, mask_to_vector): (__m512i, __m512i, __m512i, __m512i, __m512i, __m512i) =
         unsafe {
@@ -150,9 +129,9 @@ impl UTF32 {
         if endian {
             while index < length {
                 let value: __m512i = if cfg!(target_endian = "big") {
-                    unsafe { _mm512_shuffle_epi8(_mm512_loadu_si512(black_box(&array[index] as *const __m512i as *const i32)), swap_endian) }
+                    unsafe { _mm512_shuffle_epi8(_mm512_loadu_si512(black_box(array.add(index))), swap_endian) }
                 } else {
-                    unsafe { _mm512_loadu_si512(black_box(&array[index] as *const __m512i as *const i32)) }
+                    unsafe { _mm512_loadu_si512(black_box(array.add(index))) }
                 };
 
                 if unsafe { _mm512_cmplt_epi32_mask(value, max_signed_mask) } != 0_u16 { return false; }
@@ -164,9 +143,9 @@ impl UTF32 {
         } else {
             while index < length {
                 let value: __m512i = if cfg!(target_endian = "big") {
-                    unsafe { _mm512_loadu_si512(black_box(&array[index] as *const __m512i as *const i32)) }
+                    unsafe { _mm512_loadu_si512(black_box(array.add(index))) }
                 } else {
-                    unsafe { _mm512_shuffle_epi8(_mm512_loadu_si512(black_box(&array[index] as *const __m512i as *const i32)), swap_endian) }
+                    unsafe { _mm512_shuffle_epi8(_mm512_loadu_si512(black_box(array.add(index))), swap_endian) }
                 };
 
                 if unsafe { _mm512_cmplt_epi32_mask(value, max_signed_mask) } != 0_u16 { return false; }
@@ -180,8 +159,8 @@ impl UTF32 {
         return true;
     }
 
-    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
-    pub fn is_utf32_from_byte_array(array: &[u8], endian: bool) -> bool {
+    #[target_feature(enable = "avx512f,avx512bw")]
+    unsafe fn is_utf32_from_byte_array_avx512(array: &[u8], endian: bool) -> bool {
 
         let length: usize = array.len();
 
@@ -196,23 +175,24 @@ impl UTF32 {
                 unsafe { transmute::<[u8; 64_usize], __m512i>(indivisible_code_array) }
             };
 
-            result &= UTF32::is_utf32_32x16(&[indivisible_code_array], endian);
+            result &= UTF32::is_utf32_32x16(&indivisible_code_array, 1_usize, endian);
         }
 
         if result {
             let remains_length: usize = length - indivisible;
 
             if remains_length != 0_usize {
-                result &= UTF32::is_utf32_32x16(unsafe { core::slice::from_raw_parts::<__m512i>(transmute::<*const u8, *const __m512i>(array.as_ptr().add(indivisible)), remains_length / 64_usize) }, endian);
+                result &= UTF32::is_utf32_32x16(unsafe { transmute::<*const u8, *const __m512i>(array.as_ptr().add(indivisible)) }, remains_length / 64_usize, endian);
             }
         }
 
         return result;
     }
 
-    #[cfg(all(target_feature = "avx", target_feature = "avx2", not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-    fn is_utf32_32x8(array: &[__m256i], endian: bool) -> bool {
-        let (mut index, length): (usize, usize) = (0_usize, array.len());
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    pub(crate) unsafe fn is_utf32_32x8(array: *const __m256i, length: usize, endian: bool) -> bool {
+        let mut index: usize = 0_usize;
 
         let (min_mask, max_unsigned_mask, range_mask, bad_range_mask, bad_result_mask): (__m256i, __m256i, __m256i, __m256i, __m256i) =
         unsafe { (_mm256_set1_epi32(0x0000000), _mm256_set1_epi32(0x0010FFFF), _mm256_set1_epi32(0x0000FFFF), _mm256_set1_epi32(0x0000F800), _mm256_set1_epi32(0x0000D800)) };
@@ -225,9 +205,9 @@ impl UTF32 {
         if endian {
             while index < length {
                 let value: __m256i = if cfg!(target_endian = "big") {
-                    unsafe { _mm256_shuffle_epi8(_mm256_loadu_si256(black_box(&array[index])), swap_endian) }
+                    unsafe { _mm256_shuffle_epi8(_mm256_loadu_si256(black_box(array.add(index))), swap_endian) }
                 } else {
-                    unsafe { _mm256_loadu_si256(black_box(&array[index])) }
+                    unsafe { _mm256_loadu_si256(black_box(array.add(index))) }
                 };
 
                 if unsafe { _mm256_movemask_epi8(_mm256_cmpgt_epi32(min_mask, value)) } != 0_i32 { return false; }
@@ -239,9 +219,9 @@ impl UTF32 {
         } else {
             while index < length {
                 let value: __m256i = if cfg!(target_endian = "big") {
-                    unsafe { _mm256_loadu_si256(black_box(&array[index])) }
+                    unsafe { _mm256_loadu_si256(black_box(array.add(index))) }
                 } else {
-                    unsafe { _mm256_shuffle_epi8(_mm256_loadu_si256(black_box(&array[index])), swap_endian) }
+                    unsafe { _mm256_shuffle_epi8(_mm256_loadu_si256(black_box(array.add(index))), swap_endian) }
                 };
 
                 if unsafe { _mm256_movemask_epi8(_mm256_cmpgt_epi32(min_mask, value)) } != 0_i32 { return false; }
@@ -255,8 +235,8 @@ impl UTF32 {
         return true;
     }
 
-    #[cfg(all(target_feature = "avx", target_feature = "avx2", not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-    pub fn is_utf32_from_byte_array(array: &[u8], endian: bool) -> bool {
+    #[target_feature(enable = "avx,avx2")]
+    unsafe fn is_utf32_from_byte_array_avx2(array: &[u8], endian: bool) -> bool {
         let length: usize = array.len();
 
         let (mut index, indivisible, mut result): (usize, usize, bool) = (0_usize, length % 32_usize, true);
@@ -270,31 +250,28 @@ impl UTF32 {
                 unsafe { transmute::<[u8; 32_usize], __m256i>(indivisible_code_array) }
             };
 
-            result &= UTF32::is_utf32_32x8(&[indivisible_code_array], endian);
+            result &= UTF32::is_utf32_32x8(&indivisible_code_array, 1_usize, endian);
         }
 
         if result {
             let remains_length: usize = length - indivisible;
 
             if remains_length != 0_usize {
-                result &= UTF32::is_utf32_32x8(unsafe { core::slice::from_raw_parts::<__m256i>(transmute::<*const u8, *const __m256i>(array.as_ptr().add(indivisible)), remains_length / 32_usize) }, endian);
+                result &= UTF32::is_utf32_32x8(unsafe { transmute::<*const u8, *const __m256i>(array.as_ptr().add(indivisible)) }, remains_length / 32_usize, endian);
             }
         }
 
         return result;
     }
 
-    #[cfg(all(target_feature = "sse2", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-    fn is_utf32_32x4(array: &[__m128i], endian: bool) -> bool {
-        let (mut index, length): (usize, usize) = (0_usize, array.len());
+    #[target_feature(enable = "sse2")]
+    #[allow(unused_unsafe)]
+    pub(crate) unsafe fn is_utf32_32x4(array: *const __m128i, length: usize, endian: bool) -> bool {
+        let mut index: usize = 0_usize;
 
         let (max_signed_mask, max_unsigned_mask, range_mask, bad_range_mask, bad_result_mask): (__m128i, __m128i, __m128i, __m128i, __m128i) =
         unsafe { (_mm_set1_epi32(0x00000000), _mm_set1_epi32(0x0010FFFF), _mm_set1_epi32(0x00010000), _mm_set1_epi32(0x0000F800), _mm_set1_epi32(0x0000D800)) };
 
-        #[cfg(target_feature = "ssse3")]
-        let swap_endian: __m128i = unsafe { _mm_set_epi8(12, 13, 14, 15, 8, 9, 10, 11, 4, 5, 6, 7, 0, 1, 2, 3) };
-
-        #[cfg(all(target_feature = "sse2", not(target_feature = "ssse3")))]
         fn swap_endian(value: __m128i) -> __m128i {
             let (shift_sides, shift_middle): (__m128i, __m128i) = unsafe {
                 (_mm_set_epi32(0, 0, 0, 24), _mm_set_epi32(0, 0, 0, 8))
@@ -326,13 +303,9 @@ impl UTF32 {
         if endian {
             while index < length {
                 let value: __m128i = if cfg!(target_endian = "big") {
-                    #[cfg(target_feature = "ssse3")]
-                    unsafe { _mm_shuffle_epi8(_mm_loadu_si128(black_box(&array[index])), swap_endian) }
-
-                    #[cfg(all(target_feature = "sse", target_feature = "sse2", not(target_feature = "ssse3")))]
-                    unsafe { swap_endian(_mm_loadu_si128(black_box(&array[index]))) }
+                    unsafe { swap_endian(_mm_loadu_si128(black_box(array.add(index)))) }
                 } else {
-                    unsafe { _mm_loadu_si128(black_box(&array[index])) }
+                    unsafe { _mm_loadu_si128(black_box(array.add(index))) }
                 };
 
                 if unsafe { _mm_movemask_epi8(_mm_cmplt_epi32(value, max_signed_mask)) } != 0_i32 { return false; }
@@ -344,13 +317,9 @@ impl UTF32 {
         } else {
             while index < length {
                 let value: __m128i = if cfg!(target_endian = "big") {
-                    unsafe { _mm_loadu_si128(black_box(&array[index])) }
+                    unsafe { _mm_loadu_si128(black_box(array.add(index))) }
                 } else {
-                    #[cfg(target_feature = "ssse3")]
-                    unsafe { _mm_shuffle_epi8(_mm_loadu_si128(black_box(&array[index])), swap_endian) }
-
-                    #[cfg(all(target_feature = "sse", target_feature = "sse2", not(target_feature = "ssse3")))]
-                    unsafe { swap_endian(_mm_loadu_si128(black_box(&array[index]))) }
+                    unsafe { swap_endian(_mm_loadu_si128(black_box(array.add(index)))) }
                 };
 
                 if unsafe { _mm_movemask_epi8(_mm_cmplt_epi32(value, max_signed_mask)) } != 0_i32 { return false; }
@@ -364,8 +333,8 @@ impl UTF32 {
         return true;
     }
 
-    #[cfg(all(target_feature = "sse2", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-    pub fn is_utf32_from_byte_array(array: &[u8], endian: bool) -> bool {
+    #[target_feature(enable = "sse2")]
+    unsafe fn is_utf32_from_byte_array_sse2(array: &[u8], endian: bool) -> bool {
 
         let length: usize = array.len();
 
@@ -380,17 +349,45 @@ impl UTF32 {
                 unsafe { transmute::<[u8; 16_usize], __m128i>(indivisible_code_array) }
             };
 
-            result &= UTF32::is_utf32_32x4(&[indivisible_code_array], endian);
+            result &= UTF32::is_utf32_32x4(&indivisible_code_array, 1_usize, endian);
         }
 
         if result {
             let remains_length: usize = length - indivisible;
 
             if remains_length != 0_usize {
-                result &= UTF32::is_utf32_32x4(unsafe { core::slice::from_raw_parts::<__m128i>(transmute::<*const u8, *const __m128i>(array.as_ptr().add(indivisible)), remains_length / 16_usize) }, endian);
+                result &= UTF32::is_utf32_32x4(unsafe { transmute::<*const u8, *const __m128i>(array.as_ptr().add(indivisible)) }, remains_length / 16_usize, endian);
             }
         }
 
         return result;
     }
+
+    pub fn is_utf32_from_byte_array(array: &[u8], endian: bool) -> bool {
+        return dispatch!(unsafe fn(array: &[u8], endian: bool) -> bool, UTF32::is_utf32_from_byte_array_sse2, UTF32::is_utf32_from_byte_array_avx2, UTF32::is_utf32_from_byte_array_avx512);
+    }
+
+    pub fn validate_utf32_from_byte_array(array: &[u8], endian: bool) -> Result<(), EncodingError> {
+        let length: usize = array.len();
+
+        if length == 0_usize { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        let aligned_length: usize = length - length % UTF32::__ENCODING_BYTES;
+
+        let mut start: usize = 0_usize;
+
+        while start < aligned_length {
+            let end: usize = if aligned_length - start > UTF32::__VALIDATION_WINDOW_BYTES { start + UTF32::__VALIDATION_WINDOW_BYTES } else { aligned_length };
+
+            if !UTF32::is_utf32_from_byte_array(&array[start..end], endian) {
+                UTF32::locate_invalid_utf32(&array[start..end], start, endian)?;
+            }
+
+            start = end;
+        }
+
+        if aligned_length != length { return Err(EncodingError::new(aligned_length, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok(());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,76 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        SimdLevel
+    }
+};
+
+#[cfg(test)]
+std::thread_local! {
+    static FORCED: core::cell::Cell<Option<SimdLevel>> = const { core::cell::Cell::new(None) };
+}
+
+impl SimdLevel {
+
+    pub fn selected() -> SimdLevel {
+        #[cfg(test)]
+        if let Some(level) = FORCED.with(|forced| forced.get()) { return level; }
+
+        if is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512bw") {
+            return SimdLevel::AVX512;
+        } else if is_x86_feature_detected!("avx") && is_x86_feature_detected!("avx2") {
+            return SimdLevel::AVX2;
+        } else {
+            return SimdLevel::SSE2;
+        }
+    }
+
+    /// Runs `function` with every dispatched kernel on this thread forced to `level`.
+    #[cfg(test)]
+    pub(crate) fn forced<R>(level: SimdLevel, function: impl FnOnce() -> R) -> R {
+        FORCED.with(|forced| forced.set(Some(level)));
+
+        let result: R = function();
+
+        FORCED.with(|forced| forced.set(None));
+
+        return result;
+    }
+
+    /// Levels up to the selected one, all of which the running CPU supports.
+    #[cfg(test)]
+    pub(crate) fn available() -> Vec<SimdLevel> {
+        return match SimdLevel::selected() {
+            SimdLevel::AVX512 => vec![SimdLevel::SSE2, SimdLevel::AVX2, SimdLevel::AVX512],
+            SimdLevel::AVX2 => vec![SimdLevel::SSE2, SimdLevel::AVX2],
+            _ => vec![SimdLevel::SSE2]
+        };
+    }
+}
@@ -0,0 +1,171 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        MultiSearcher
+    }
+};
+
+use crate::{
+    functors::{
+        platform::{
+            dispatch::{
+                dispatch
+            }
+        }
+    }
+};
+
+use core::{
+    arch::{
+        x86::{
+            __m512i,
+            _mm512_loadu_si512,
+            _mm512_storeu_si512,
+            _mm512_set1_epi8,
+            _mm512_and_si512,
+            _mm512_srli_epi16,
+            _mm512_shuffle_epi8,
+            __m256i,
+            _mm256_loadu_si256,
+            _mm256_storeu_si256,
+            _mm256_set1_epi8,
+            _mm256_and_si256,
+            _mm256_srli_epi16,
+            _mm256_shuffle_epi8,
+            __m128i,
+            _mm_loadu_si128,
+            _mm_storeu_si128,
+            _mm_set1_epi8,
+            _mm_and_si128,
+            _mm_srli_epi16,
+            _mm_shuffle_epi8
+        }
+    }
+};
+
+macro_rules! generate_packed {
+    ($feature:literal, $packed_search:ident, $precision:ty, $register_size:expr, $load:expr, $store:expr, $dup_one_t:expr, $bitwise_and:expr, $shift_right:expr, $shuffle:expr) => {
+        impl MultiSearcher {
+
+            #[target_feature(enable = $feature)]
+            unsafe fn $packed_search(searcher: &MultiSearcher, array: &[u8], all_matches: bool) -> Vec<(usize, usize)> {
+
+                const COUNT_OF_WORDS_IN_REGISTER: usize = $register_size / size_of::<u64>();
+
+                let mut search_result: Vec<(usize, usize)> = Vec::new();
+
+                let (array, fingerprint): (&[u8], usize) = (&array[..array.len() - array.len() % searcher.width], searcher.fingerprint);
+
+                let (mut index, mut resume, mut candidates): (usize, usize, [u64; COUNT_OF_WORDS_IN_REGISTER]) = (0_usize, 0_usize, [0_u64; COUNT_OF_WORDS_IN_REGISTER]);
+
+                unsafe {
+                    let (tables, nibble_mask): ([$precision; 6_usize], $precision) = {
+                        let (mut tables, mut repeated): ([$precision; 6_usize], [u8; $register_size]) = ([$dup_one_t(0); 6_usize], [0_u8; $register_size]);
+
+                        for table in 0_usize..6_usize {
+                            for lane in 0_usize..$register_size { repeated[lane] = searcher.masks[table][lane % 16_usize]; }
+
+                            tables[table] = $load(repeated.as_ptr() as *const $precision);
+                        }
+
+                        (tables, $dup_one_t(0x0F))
+                    };
+
+                    while index + fingerprint - 1_usize + $register_size <= array.len() {
+                        let mut buckets: $precision = $dup_one_t(-1);
+
+                        for offset in 0_usize..fingerprint {
+                            let value: $precision = $load(array.as_ptr().add(index + offset) as *const $precision);
+
+                            buckets = $bitwise_and(buckets, $bitwise_and(
+                                $shuffle(tables[offset * 2_usize], $bitwise_and(value, nibble_mask)),
+                                $shuffle(tables[offset * 2_usize + 1_usize], $bitwise_and($shift_right(value, 4), nibble_mask))
+                            ));
+                        }
+
+                        $store(candidates.as_mut_ptr() as *mut $precision, buckets);
+
+                        for word in 0_usize..COUNT_OF_WORDS_IN_REGISTER {
+                            let mut lanes: u64 = candidates[word];
+
+                            while lanes != 0_u64 {
+                                let lane: usize = lanes.trailing_zeros() as usize / 8_usize;
+
+                                if !searcher.verify(array, index + word * 8_usize + lane, (lanes >> (lane * 8_usize)) as u8, all_matches, &mut resume, &mut search_result) { return search_result; }
+
+                                lanes &= !(0xFF_u64 << (lane * 8_usize));
+                            }
+                        }
+
+                        index += $register_size;
+                    }
+                }
+
+                searcher.packed_search_from(array, index, all_matches, &mut resume, &mut search_result);
+
+                return search_result;
+            }
+        }
+    };
+}
+
+generate_packed!("avx512f,avx512bw", packed_search_avx512, __m512i, 64_usize, _mm512_loadu_si512, _mm512_storeu_si512, _mm512_set1_epi8, _mm512_and_si512, _mm512_srli_epi16, _mm512_shuffle_epi8);
+generate_packed!("avx,avx2", packed_search_avx2, __m256i, 32_usize, _mm256_loadu_si256, _mm256_storeu_si256, _mm256_set1_epi8, _mm256_and_si256, _mm256_srli_epi16, _mm256_shuffle_epi8);
+generate_packed!("sse2,ssse3", packed_search_ssse3, __m128i, 16_usize, _mm_loadu_si128, _mm_storeu_si128, _mm_set1_epi8, _mm_and_si128, _mm_srli_epi16, _mm_shuffle_epi8);
+
+impl MultiSearcher {
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    unsafe fn search_matches_avx512(searcher: &MultiSearcher, array: &[u8], all_matches: bool) -> Vec<(usize, usize)> {
+        if searcher.fingerprint == 0_usize { return searcher.automaton_search(array, all_matches); }
+
+        return unsafe { MultiSearcher::packed_search_avx512(searcher, array, all_matches) };
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    unsafe fn search_matches_avx2(searcher: &MultiSearcher, array: &[u8], all_matches: bool) -> Vec<(usize, usize)> {
+        if searcher.fingerprint == 0_usize { return searcher.automaton_search(array, all_matches); }
+
+        return unsafe { MultiSearcher::packed_search_avx2(searcher, array, all_matches) };
+    }
+
+    #[target_feature(enable = "sse2")]
+    unsafe fn search_matches_sse2(searcher: &MultiSearcher, array: &[u8], all_matches: bool) -> Vec<(usize, usize)> {
+        if searcher.fingerprint == 0_usize || !is_x86_feature_detected!("ssse3") { return searcher.automaton_search(array, all_matches); }
+
+        return unsafe { MultiSearcher::packed_search_ssse3(searcher, array, all_matches) };
+    }
+
+    pub(crate) fn search_matches(&self, array: &[u8], all_matches: bool) -> Vec<(usize, usize)> {
+        let searcher: &MultiSearcher = self;
+
+        return dispatch!(unsafe fn(searcher: &MultiSearcher, array: &[u8], all_matches: bool) -> Vec<(usize, usize)>, MultiSearcher::search_matches_sse2, MultiSearcher::search_matches_avx2, MultiSearcher::search_matches_avx512);
+    }
+}
@@ -0,0 +1,344 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+// Every SIMD level the CPU supports is forced in turn and checked against a scalar reference.
+
+use crate::{
+    essence::{
+        SimdLevel,
+        EncodingError, EncodingErrorKind,
+        EBCDIC, EbcdicCodepage, Newline
+    },
+    functors::{
+        platform::{
+            codings::{
+                ASCII,
+                UTF8, UTF16, UTF32
+            },
+            search::{
+                ByteSearch
+            }
+        },
+        universal::{
+            search::{
+                Finder
+            }
+        }
+    }
+};
+
+const __LENGTHS: [usize; 16_usize] = [1_usize, 2_usize, 3_usize, 4_usize, 15_usize, 16_usize, 17_usize, 31_usize, 32_usize, 33_usize, 63_usize, 64_usize, 65_usize, 127_usize, 200_usize, 1031_usize];
+
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u32 {
+        self.0 = self.0.wrapping_mul(6364136223846793005_u64).wrapping_add(1442695040888963407_u64);
+
+        return (self.0 >> 32) as u32;
+    }
+
+    fn char(&mut self) -> char {
+        let value: u32 = self.next();
+
+        let code: u32 = match value % 8_u32 {
+            0_u32..=3_u32 => value >> 8 & 0x7F,
+            4_u32 => 0x80 + (value >> 8) % 0x780,
+            5_u32 => 0x800 + (value >> 8) % 0xF800,
+            6_u32 => 0x10000 + (value >> 8) % 0x100000,
+            _ => (value >> 8) % 0x20
+        };
+
+        return char::from_u32(code).unwrap_or('\u{FFFD}');
+    }
+
+    fn text(&mut self, length: usize) -> String {
+        return (0_usize..length).map(|_| self.char()).collect::<String>();
+    }
+
+    fn corrupt(&mut self, array: &mut [u8]) {
+        if !array.is_empty() && self.next().is_multiple_of(2_u32) {
+            let index: usize = self.next() as usize % array.len();
+
+            array[index] = self.next() as u8;
+        }
+    }
+}
+
+fn each_level(mut test: impl FnMut(SimdLevel)) {
+    for level in SimdLevel::available() {
+        SimdLevel::forced(level, || test(level));
+    }
+}
+
+fn utf16_bytes(units: &[u16], endian: bool) -> Vec<u8> {
+    return units.iter().flat_map(|&unit| if endian { unit.to_le_bytes() } else { unit.to_be_bytes() }).collect();
+}
+
+fn utf32_bytes(codes: &[u32], endian: bool) -> Vec<u8> {
+    return codes.iter().flat_map(|&code| if endian { code.to_le_bytes() } else { code.to_be_bytes() }).collect();
+}
+
+fn naive_search(array: &[u8], pattern: &[u8], step: usize, overlapping: bool) -> Vec<usize> {
+    let (mut matches, mut index): (Vec<usize>, usize) = (Vec::<usize>::new(), 0_usize);
+
+    while index + pattern.len() <= array.len() {
+        if &array[index..index + pattern.len()] == pattern {
+            matches.push(index);
+
+            index += if overlapping { step } else { pattern.len() };
+        } else {
+            index += step;
+        }
+    }
+
+    return matches;
+}
+
+#[test]
+fn validation_matches_scalar_reference() {
+    each_level(|level| {
+        let mut random: Random = Random(0x5EED_u64);
+
+        for length in __LENGTHS {
+            for _ in 0_usize..16_usize {
+                let mut utf8: Vec<u8> = random.text(length).into_bytes();
+                random.corrupt(&mut utf8);
+
+                assert_eq!(ASCII::is_ascii_from_byte_array(&utf8), utf8.is_ascii(), "{:?} ascii {:?}", level, utf8);
+                assert_eq!(UTF8::is_utf8(&utf8), core::str::from_utf8(&utf8).is_ok(), "{:?} utf-8 {:?}", level, utf8);
+
+                if let Err(error) = core::str::from_utf8(&utf8) {
+                    assert_eq!(UTF8::validate_utf8(&utf8).unwrap_err().offset(), error.valid_up_to(), "{:?} utf-8 {:?}", level, utf8);
+                }
+
+                let mut units: Vec<u16> = random.text(length).encode_utf16().collect();
+                if random.next().is_multiple_of(2_u32) { let index: usize = random.next() as usize % units.len(); units[index] = 0xD800 | random.next() as u16 & 0x07FF; }
+
+                let mut codes: Vec<u32> = random.text(length).chars().map(|code| code as u32).collect();
+                if random.next().is_multiple_of(2_u32) { let index: usize = random.next() as usize % codes.len(); codes[index] = random.next() >> (random.next() % 16_u32); }
+
+                for endian in [true, false] {
+                    let expected: bool = char::decode_utf16(units.iter().copied()).all(|code| code.is_ok());
+
+                    assert_eq!(UTF16::is_utf16_from_byte_array(&utf16_bytes(&units, endian), endian, false, false), expected, "{:?} utf-16 {:04X?}", level, units);
+                    assert_eq!(UTF16::validate_utf16_from_byte_array(&utf16_bytes(&units, endian), endian, false, false).is_ok(), expected, "{:?} utf-16 {:04X?}", level, units);
+
+                    let expected: bool = codes.iter().all(|&code| char::from_u32(code).is_some());
+
+                    assert_eq!(UTF32::is_utf32_from_byte_array(&utf32_bytes(&codes, endian), endian), expected, "{:?} utf-32 {:08X?}", level, codes);
+                    assert_eq!(UTF32::validate_utf32_from_byte_array(&utf32_bytes(&codes, endian), endian).is_ok(), expected, "{:?} utf-32 {:08X?}", level, codes);
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn validation_window_boundary() {
+    // The trailing lead of a 64 KiB window is deferred to the next one; the lead before it must still see its real follower.
+    each_level(|level| {
+        let mut units: Vec<u16> = vec![0x0041_u16; 40000_usize];
+
+        (units[32766_usize], units[32767_usize]) = (0xD800_u16, 0xD800_u16);
+
+        for endian in [true, false] {
+            let error: EncodingError = UTF16::validate_utf16_from_byte_array(&utf16_bytes(&units, endian), endian, false, false).unwrap_err();
+
+            assert_eq!((error.offset(), error.kind()), (65532_usize, EncodingErrorKind::UnpairedLead), "{:?}", level);
+        }
+    });
+}
+
+#[test]
+fn transcoding_matches_scalar_reference() {
+    each_level(|level| {
+        let mut random: Random = Random(0xC0DE_u64);
+
+        for length in __LENGTHS {
+            for _ in 0_usize..8_usize {
+                let text: String = random.text(length);
+
+                let (units, codes): (Vec<u16>, Vec<u32>) = (text.encode_utf16().collect(), text.chars().map(|code| code as u32).collect());
+
+                for endian in [true, false] {
+                    let swap16 = |unit: u16| if endian { u16::from_le(unit) } else { u16::from_be(unit) };
+                    let swap32 = |code: u32| if endian { u32::from_le(code) } else { u32::from_be(code) };
+
+                    let mut output16: Vec<u16> = vec![0_u16; units.len()];
+                    assert_eq!(UTF8::to_utf16_into(text.as_bytes(), &mut output16, endian).unwrap(), (text.len(), units.len()), "{:?}", level);
+                    assert_eq!(output16.iter().map(|&unit| swap16(unit)).collect::<Vec<u16>>(), units, "{:?} utf-8 -> utf-16", level);
+
+                    let mut output32: Vec<u32> = vec![0_u32; codes.len()];
+                    assert_eq!(UTF8::to_utf32_into(text.as_bytes(), &mut output32, endian).unwrap(), (text.len(), codes.len()), "{:?}", level);
+                    assert_eq!(output32.iter().map(|&code| swap32(code)).collect::<Vec<u32>>(), codes, "{:?} utf-8 -> utf-32", level);
+
+                    let mut output8: Vec<u8> = vec![0_u8; text.len()];
+                    assert_eq!(UTF16::to_utf8_into(&utf16_bytes(&units, endian), &mut output8, endian).unwrap().1, text.len(), "{:?}", level);
+                    assert_eq!(output8, text.as_bytes(), "{:?} utf-16 -> utf-8", level);
+
+                    let mut output32: Vec<u32> = vec![0_u32; codes.len()];
+                    assert_eq!(UTF16::to_utf32_into(&utf16_bytes(&units, endian), &mut output32, endian).unwrap().1, codes.len(), "{:?}", level);
+                    assert_eq!(output32.iter().map(|&code| swap32(code)).collect::<Vec<u32>>(), codes, "{:?} utf-16 -> utf-32", level);
+
+                    let mut output8: Vec<u8> = vec![0_u8; text.len()];
+                    assert_eq!(UTF32::to_utf8_into(&utf32_bytes(&codes, endian), &mut output8, endian).unwrap().1, text.len(), "{:?}", level);
+                    assert_eq!(output8, text.as_bytes(), "{:?} utf-32 -> utf-8", level);
+
+                    let mut output16: Vec<u16> = vec![0_u16; units.len()];
+                    assert_eq!(UTF32::to_utf16_into(&utf32_bytes(&codes, endian), &mut output16, endian).unwrap().1, units.len(), "{:?}", level);
+                    assert_eq!(output16.iter().map(|&unit| swap16(unit)).collect::<Vec<u16>>(), units, "{:?} utf-32 -> utf-16", level);
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn single_byte_transcoding_matches_scalar_reference() {
+    each_level(|level| {
+        let mut random: Random = Random(0xB17E_u64);
+
+        assert_eq!(crate::essence::ISO8859_1::from_utf8_into(b"", &mut []).unwrap(), (0_usize, 0_usize), "{:?}", level);
+        assert_eq!(crate::essence::ISO8859_1::from_utf16_into(b"", &mut [], true).unwrap(), (0_usize, 0_usize), "{:?}", level);
+
+        for length in __LENGTHS {
+            let latin1: Vec<u8> = (0_usize..length).map(|_| if random.next().is_multiple_of(4_u32) { random.next() as u8 } else { random.next() as u8 & 0x7F }).collect();
+            let text: String = latin1.iter().map(|&byte| byte as char).collect::<String>();
+
+            let mut output: Vec<u8> = vec![0_u8; text.len()];
+            assert_eq!(crate::essence::ISO8859_1::to_utf8_into(&latin1, &mut output), (latin1.len(), text.len()), "{:?}", level);
+            assert_eq!(output, text.as_bytes(), "{:?} iso-8859-1 -> utf-8", level);
+
+            let mut output: Vec<u8> = vec![0_u8; latin1.len()];
+            assert_eq!(crate::essence::ISO8859_1::from_utf8_into(text.as_bytes(), &mut output).unwrap(), (text.len(), latin1.len()), "{:?}", level);
+            assert_eq!(output, latin1, "{:?} utf-8 -> iso-8859-1", level);
+
+            for codepage in [EbcdicCodepage::IBM037, EbcdicCodepage::IBM500, EbcdicCodepage::IBM1047, EbcdicCodepage::IBM1140] {
+                for newline in [Newline::Nel, Newline::Lf] {
+                    let mut expected: Vec<u8> = Vec::<u8>::new();
+
+                    for byte in latin1.iter() {
+                        let mut buffer: [u8; 4_usize] = [0_u8; 4_usize];
+                        let (_, written): (usize, usize) = EBCDIC::to_utf8_into(core::slice::from_ref(byte), &mut buffer, codepage, newline);
+                        expected.extend_from_slice(&buffer[..written]);
+                    }
+
+                    let mut output: Vec<u8> = vec![0_u8; expected.len()];
+                    assert_eq!(EBCDIC::to_utf8_into(&latin1, &mut output, codepage, newline), (latin1.len(), expected.len()), "{:?} {:?}", level, codepage);
+                    assert_eq!(output, expected, "{:?} {:?} {:?} -> utf-8", level, codepage, newline);
+
+                    let mut output: Vec<u8> = vec![0_u8; latin1.len()];
+                    assert_eq!(EBCDIC::from_utf8_into(&expected, &mut output, codepage, newline).unwrap().1, latin1.len(), "{:?} {:?}", level, codepage);
+                    assert_eq!(output, latin1, "{:?} utf-8 -> {:?} {:?}", level, codepage, newline);
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn counting_matches_scalar_reference() {
+    each_level(|level| {
+        let mut random: Random = Random(0xC0C0_u64);
+
+        for length in __LENGTHS {
+            let text: String = random.text(length);
+
+            let (units, codes): (Vec<u16>, Vec<u32>) = (text.encode_utf16().collect(), text.chars().map(|code| code as u32).collect());
+
+            assert_eq!(UTF8::count_chars(text.as_bytes()), codes.len(), "{:?}", level);
+            assert_eq!(UTF8::utf16_length_from_utf8(text.as_bytes()), units.len(), "{:?}", level);
+            assert_eq!(UTF8::utf32_length_from_utf8(text.as_bytes()), codes.len(), "{:?}", level);
+
+            for endian in [true, false] {
+                assert_eq!(UTF16::count_chars(&utf16_bytes(&units, endian), endian), codes.len(), "{:?}", level);
+                assert_eq!(UTF16::utf8_length_from_utf16(&utf16_bytes(&units, endian), endian), text.len(), "{:?}", level);
+                assert_eq!(UTF16::utf32_length_from_utf16(&utf16_bytes(&units, endian), endian), codes.len(), "{:?}", level);
+                assert_eq!(UTF32::utf8_length_from_utf32(&utf32_bytes(&codes, endian), endian), text.len(), "{:?}", level);
+                assert_eq!(UTF32::utf16_length_from_utf32(&utf32_bytes(&codes, endian), endian), units.len(), "{:?}", level);
+            }
+        }
+    });
+}
+
+#[test]
+fn search_matches_scalar_reference() {
+    each_level(|level| {
+        let mut random: Random = Random(0xF1D0_u64);
+
+        for length in __LENGTHS {
+            let array: Vec<u8> = (0_usize..length).map(|_| b"aab"[random.next() as usize % 3_usize]).collect();
+
+            for pattern_length in [1_usize, 2_usize, 3_usize, 5_usize, 17_usize] {
+                let pattern: Vec<u8> = (0_usize..pattern_length).map(|_| b"ab"[random.next() as usize % 2_usize]).collect();
+
+                let (expected, overlapping): (Vec<usize>, Vec<usize>) = (naive_search(&array, &pattern, 1_usize, false), naive_search(&array, &pattern, 1_usize, true));
+
+                assert_eq!(ByteSearch::<i8>::search_all(&array, &pattern, None), expected, "{:?} {:?} in {:?}", level, pattern, array);
+                assert_eq!(ByteSearch::<i8>::search_all_overlapping(&array, &pattern, None), overlapping, "{:?} {:?} in {:?}", level, pattern, array);
+                assert_eq!(ByteSearch::<i8>::search_single(&array, &pattern, None), overlapping.first().copied().into_iter().collect::<Vec<usize>>(), "{:?}", level);
+                assert_eq!(ByteSearch::<i8>::search_last(&array, &pattern, None), overlapping.last().copied().into_iter().collect::<Vec<usize>>(), "{:?}", level);
+                assert_eq!(Finder::<i8>::new(&pattern).find_iter_overlapping(&array).collect::<Vec<usize>>(), overlapping, "{:?} {:?} in {:?}", level, pattern, array);
+                assert_eq!(Finder::<u8>::new(&pattern).find_iter_overlapping(&array).collect::<Vec<usize>>(), overlapping, "{:?} {:?} in {:?}", level, pattern, array);
+
+                let (high_array, high_pattern): (Vec<u16>, Vec<u16>) = (array.iter().map(|&byte| 0xFF00_u16 | byte as u16).collect(), pattern.iter().map(|&byte| 0xFF00_u16 | byte as u16).collect());
+                let (high_array, high_pattern): (Vec<u8>, Vec<u8>) = (utf16_bytes(&high_array, true), utf16_bytes(&high_pattern, true));
+
+                assert_eq!(Finder::<i16>::new(&high_pattern).find_iter(&high_array).collect::<Vec<usize>>(), naive_search(&high_array, &high_pattern, 2_usize, false), "{:?} {:?} in {:?}", level, pattern, array);
+                assert_eq!(Finder::<u16>::new(&high_pattern).find_iter(&high_array).collect::<Vec<usize>>(), naive_search(&high_array, &high_pattern, 2_usize, false), "{:?} {:?} in {:?}", level, pattern, array);
+
+                let (wide_array, wide_pattern): (Vec<u32>, Vec<u32>) = (array.iter().map(|&byte| byte as u32).collect(), pattern.iter().map(|&byte| byte as u32).collect());
+                let (wide_array, wide_pattern): (Vec<u8>, Vec<u8>) = (utf32_bytes(&wide_array, true), utf32_bytes(&wide_pattern, true));
+
+                assert_eq!(Finder::<i32>::new(&wide_pattern).find_iter(&wide_array).collect::<Vec<usize>>(), naive_search(&wide_array, &wide_pattern, 4_usize, false), "{:?} {:?} in {:?}", level, pattern, array);
+                assert_eq!(Finder::<u32>::new(&wide_pattern).find_iter(&wide_array).collect::<Vec<usize>>(), naive_search(&wide_array, &wide_pattern, 4_usize, false), "{:?} {:?} in {:?}", level, pattern, array);
+            }
+        }
+    });
+}
+
+#[test]
+fn lossy_matches_scalar_reference() {
+    each_level(|level| {
+        let mut random: Random = Random(0x1055_u64);
+
+        for length in __LENGTHS {
+            let units: Vec<u16> = (0_usize..length).map(|_| match random.next() % 8_u32 {
+                0_u32 => 0xD800 | random.next() as u16 & 0x07FF,
+                _ => random.next() as u16 & 0x7FFF
+            }).collect();
+
+            let expected: Vec<u16> = char::decode_utf16(units.iter().copied()).map(|code| code.unwrap_or('\u{FFFD}')).collect::<String>().encode_utf16().collect();
+
+            for endian in [true, false] {
+                assert_eq!(UTF16::to_well_formed(&utf16_bytes(&units, endian), endian).unwrap(), utf16_bytes(&expected, endian), "{:?} {:04X?}", level, units);
+            }
+        }
+    });
+}
@@ -0,0 +1,167 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF8,
+        Statistics
+    }
+};
+
+use crate::{
+    functors::{
+        platform::{
+            dispatch::{
+                dispatch
+            }
+        }
+    }
+};
+
+use core::{
+    arch::{
+        x86_64::{
+            __m512i,
+            _mm512_loadu_si512,
+            _mm512_set1_epi8,
+            _mm512_cmpgt_epi8_mask,
+            __m256i,
+            _mm256_loadu_si256,
+            _mm256_set1_epi8,
+            _mm256_cmpgt_epi8,
+            _mm256_movemask_epi8,
+            __m128i,
+            _mm_loadu_si128,
+            _mm_set1_epi8,
+            _mm_cmpgt_epi8,
+            _mm_movemask_epi8
+        }
+    }
+};
+
+impl UTF8 {
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn count_classes_avx512(array: &[u8]) -> (usize, usize, usize) {
+        let (length, mut index): (usize, usize) = (array.len(), 0_usize);
+
+        let (mut leads, mut ascii, mut above): (usize, usize, usize) = (0_usize, 0_usize, 0_usize);
+
+        let (leads_bound, ascii_bound, above_bound): (__m512i, __m512i, __m512i) =
+        unsafe { (_mm512_set1_epi8(-0x41), _mm512_set1_epi8(-0x01), _mm512_set1_epi8(-0x11)) }; // 0xBF, 0xFF, 0xEF
+
+        while index + 64_usize <= length {
+            let value: __m512i = unsafe { _mm512_loadu_si512(array.as_ptr().add(index) as *const __m512i) };
+
+            unsafe {
+                leads += _mm512_cmpgt_epi8_mask(value, leads_bound).count_ones() as usize;
+                ascii += _mm512_cmpgt_epi8_mask(value, ascii_bound).count_ones() as usize;
+                above += _mm512_cmpgt_epi8_mask(value, above_bound).count_ones() as usize;
+            }
+
+            index += 64_usize;
+        }
+
+        let (tail_leads, tail_ascii, tail_above): (usize, usize, usize) = UTF8::count_classes_from(array, index, length);
+
+        return (leads + tail_leads, ascii + tail_ascii, above + tail_above);
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn count_classes_avx2(array: &[u8]) -> (usize, usize, usize) {
+        let (length, mut index): (usize, usize) = (array.len(), 0_usize);
+
+        let (mut leads, mut ascii, mut above): (usize, usize, usize) = (0_usize, 0_usize, 0_usize);
+
+        let (leads_bound, ascii_bound, above_bound): (__m256i, __m256i, __m256i) =
+        unsafe { (_mm256_set1_epi8(-0x41), _mm256_set1_epi8(-0x01), _mm256_set1_epi8(-0x11)) }; // 0xBF, 0xFF, 0xEF
+
+        while index + 32_usize <= length {
+            let value: __m256i = unsafe { _mm256_loadu_si256(array.as_ptr().add(index) as *const __m256i) };
+
+            unsafe {
+                leads += _mm256_movemask_epi8(_mm256_cmpgt_epi8(value, leads_bound)).count_ones() as usize;
+                ascii += _mm256_movemask_epi8(_mm256_cmpgt_epi8(value, ascii_bound)).count_ones() as usize;
+                above += _mm256_movemask_epi8(_mm256_cmpgt_epi8(value, above_bound)).count_ones() as usize;
+            }
+
+            index += 32_usize;
+        }
+
+        let (tail_leads, tail_ascii, tail_above): (usize, usize, usize) = UTF8::count_classes_from(array, index, length);
+
+        return (leads + tail_leads, ascii + tail_ascii, above + tail_above);
+    }
+
+    #[target_feature(enable = "sse2")]
+    #[allow(unused_unsafe)]
+    unsafe fn count_classes_sse2(array: &[u8]) -> (usize, usize, usize) {
+        let (length, mut index): (usize, usize) = (array.len(), 0_usize);
+
+        let (mut leads, mut ascii, mut above): (usize, usize, usize) = (0_usize, 0_usize, 0_usize);
+
+        let (leads_bound, ascii_bound, above_bound): (__m128i, __m128i, __m128i) =
+        unsafe { (_mm_set1_epi8(-0x41), _mm_set1_epi8(-0x01), _mm_set1_epi8(-0x11)) }; // 0xBF, 0xFF, 0xEF
+
+        while index + 16_usize <= length {
+            let value: __m128i = unsafe { _mm_loadu_si128(array.as_ptr().add(index) as *const __m128i) };
+
+            unsafe {
+                leads += _mm_movemask_epi8(_mm_cmpgt_epi8(value, leads_bound)).count_ones() as usize;
+                ascii += _mm_movemask_epi8(_mm_cmpgt_epi8(value, ascii_bound)).count_ones() as usize;
+                above += _mm_movemask_epi8(_mm_cmpgt_epi8(value, above_bound)).count_ones() as usize;
+            }
+
+            index += 16_usize;
+        }
+
+        let (tail_leads, tail_ascii, tail_above): (usize, usize, usize) = UTF8::count_classes_from(array, index, length);
+
+        return (leads + tail_leads, ascii + tail_ascii, above + tail_above);
+    }
+
+    pub fn count_chars(array: &[u8]) -> usize {
+        return dispatch!(unsafe fn(array: &[u8]) -> (usize, usize, usize), UTF8::count_classes_sse2, UTF8::count_classes_avx2, UTF8::count_classes_avx512).0;
+    }
+
+    pub fn stats(array: &[u8]) -> Statistics {
+        return UTF8::statistics_from(dispatch!(unsafe fn(array: &[u8]) -> (usize, usize, usize), UTF8::count_classes_sse2, UTF8::count_classes_avx2, UTF8::count_classes_avx512));
+    }
+
+    pub fn utf16_length_from_utf8(array: &[u8]) -> usize {
+        let (leads, ascii, above): (usize, usize, usize) = dispatch!(unsafe fn(array: &[u8]) -> (usize, usize, usize), UTF8::count_classes_sse2, UTF8::count_classes_avx2, UTF8::count_classes_avx512);
+
+        return leads + above - ascii;
+    }
+
+    pub fn utf32_length_from_utf8(array: &[u8]) -> usize {
+        return dispatch!(unsafe fn(array: &[u8]) -> (usize, usize, usize), UTF8::count_classes_sse2, UTF8::count_classes_avx2, UTF8::count_classes_avx512).0;
+    }
+}
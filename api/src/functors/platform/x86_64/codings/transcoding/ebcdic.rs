@@ -0,0 +1,596 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        EBCDIC,
+        EbcdicCodepage,
+        Newline
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+use crate::{
+    functors::{
+        platform::{
+            dispatch::{
+                dispatch
+            }
+        }
+    }
+};
+
+use core::{
+    arch::{
+        x86_64::{
+            __m512i,
+            _mm512_loadu_si512,
+            _mm512_storeu_si512,
+            _mm512_movepi8_mask,
+            _mm512_broadcast_i32x4,
+            _mm512_setzero_si512,
+            _mm512_set1_epi8,
+            _mm512_srli_epi16,
+            _mm512_and_si512,
+            _mm512_cmpeq_epi8_mask,
+            _mm512_mask_shuffle_epi8,
+            __m256i,
+            _mm256_loadu_si256,
+            _mm256_storeu_si256,
+            _mm256_movemask_epi8,
+            _mm256_broadcastsi128_si256,
+            _mm256_setzero_si256,
+            _mm256_set1_epi8,
+            _mm256_srli_epi16,
+            _mm256_and_si256,
+            _mm256_cmpeq_epi8,
+            _mm256_shuffle_epi8,
+            _mm256_blendv_epi8,
+            __m128i,
+            _mm_loadu_si128,
+            _mm_storeu_si128,
+            _mm_movemask_epi8,
+            _mm_setzero_si128,
+            _mm_set1_epi8,
+            _mm_srli_epi16,
+            _mm_and_si128,
+            _mm_or_si128,
+            _mm_cmpeq_epi8,
+            _mm_shuffle_epi8
+        }
+    }
+};
+
+impl EBCDIC {
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn tables_avx512(table: &[u8; 256_usize]) -> [__m512i; 16_usize] {
+        let (mut tables, mut index): ([__m512i; 16_usize], usize) = (unsafe { [_mm512_setzero_si512(); 16_usize] }, 0_usize);
+
+        while index < 16_usize {
+            tables[index] = unsafe { _mm512_broadcast_i32x4(_mm_loadu_si128(table.as_ptr().add(index * 16_usize) as *const __m128i)) };
+
+            index += 1_usize;
+        }
+
+        return tables;
+    }
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn translate_avx512(value: __m512i, tables: &[__m512i; 16_usize]) -> __m512i {
+        let nibble: __m512i = unsafe { _mm512_set1_epi8(0x0F) };
+
+        let (low, high): (__m512i, __m512i) = unsafe { (_mm512_and_si512(value, nibble), _mm512_and_si512(_mm512_srli_epi16(value, 4), nibble)) };
+
+        let (mut result, mut index): (__m512i, usize) = (unsafe { _mm512_setzero_si512() }, 0_usize);
+
+        while index < 16_usize {
+            result = unsafe { _mm512_mask_shuffle_epi8(result, _mm512_cmpeq_epi8_mask(high, _mm512_set1_epi8(index as i8)), tables[index], low) };
+
+            index += 1_usize;
+        }
+
+        return result;
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn tables_avx2(table: &[u8; 256_usize]) -> [__m256i; 16_usize] {
+        let (mut tables, mut index): ([__m256i; 16_usize], usize) = (unsafe { [_mm256_setzero_si256(); 16_usize] }, 0_usize);
+
+        while index < 16_usize {
+            tables[index] = unsafe { _mm256_broadcastsi128_si256(_mm_loadu_si128(table.as_ptr().add(index * 16_usize) as *const __m128i)) };
+
+            index += 1_usize;
+        }
+
+        return tables;
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn translate_avx2(value: __m256i, tables: &[__m256i; 16_usize]) -> __m256i {
+        let nibble: __m256i = unsafe { _mm256_set1_epi8(0x0F) };
+
+        let (low, high): (__m256i, __m256i) = unsafe { (_mm256_and_si256(value, nibble), _mm256_and_si256(_mm256_srli_epi16(value, 4), nibble)) };
+
+        let (mut result, mut index): (__m256i, usize) = (unsafe { _mm256_setzero_si256() }, 0_usize);
+
+        while index < 16_usize {
+            result = unsafe { _mm256_blendv_epi8(result, _mm256_shuffle_epi8(tables[index], low), _mm256_cmpeq_epi8(high, _mm256_set1_epi8(index as i8))) };
+
+            index += 1_usize;
+        }
+
+        return result;
+    }
+
+    #[target_feature(enable = "sse2,ssse3")]
+    #[allow(unused_unsafe)]
+    unsafe fn tables_ssse3(table: &[u8; 256_usize]) -> [__m128i; 16_usize] {
+        let (mut tables, mut index): ([__m128i; 16_usize], usize) = (unsafe { [_mm_setzero_si128(); 16_usize] }, 0_usize);
+
+        while index < 16_usize {
+            tables[index] = unsafe { _mm_loadu_si128(table.as_ptr().add(index * 16_usize) as *const __m128i) };
+
+            index += 1_usize;
+        }
+
+        return tables;
+    }
+
+    #[target_feature(enable = "sse2,ssse3")]
+    #[allow(unused_unsafe)]
+    unsafe fn translate_ssse3(value: __m128i, tables: &[__m128i; 16_usize]) -> __m128i {
+        let nibble: __m128i = unsafe { _mm_set1_epi8(0x0F) };
+
+        let (low, high): (__m128i, __m128i) = unsafe { (_mm_and_si128(value, nibble), _mm_and_si128(_mm_srli_epi16(value, 4), nibble)) };
+
+        let (mut result, mut index): (__m128i, usize) = (unsafe { _mm_setzero_si128() }, 0_usize);
+
+        while index < 16_usize {
+            result = unsafe { _mm_or_si128(result, _mm_and_si128(_mm_shuffle_epi8(tables[index], low), _mm_cmpeq_epi8(high, _mm_set1_epi8(index as i8)))) };
+
+            index += 1_usize;
+        }
+
+        return result;
+    }
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf8_into_avx512(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> (usize, usize) {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let tables: [__m512i; 16_usize] = unsafe { EBCDIC::tables_avx512(&codepage.translate_table(newline)) };
+
+        while read + 64_usize <= length {
+            let value: __m512i = unsafe { EBCDIC::translate_avx512(_mm512_loadu_si512(array.as_ptr().add(read) as *const __m512i), &tables) };
+
+            if unsafe { _mm512_movepi8_mask(value) } == 0_u64 {
+                if written + 64_usize > capacity { break; }
+
+                unsafe { _mm512_storeu_si512(output.as_mut_ptr().add(written) as *mut __m512i, value) };
+
+                read += 64_usize; written += 64_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = EBCDIC::decode_utf8_from(array, read, read + 64_usize, output, written, codepage, newline);
+
+                if next_read < read + 64_usize { return (next_read, next_written); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return EBCDIC::decode_utf8_from(array, read, length, output, written, codepage, newline);
+    }
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_ascii_into_avx512(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let tables: [__m512i; 16_usize] = unsafe { EBCDIC::tables_avx512(&codepage.translate_table(newline)) };
+
+        while read + 64_usize <= length {
+            let value: __m512i = unsafe { EBCDIC::translate_avx512(_mm512_loadu_si512(array.as_ptr().add(read) as *const __m512i), &tables) };
+
+            if unsafe { _mm512_movepi8_mask(value) } == 0_u64 {
+                if written + 64_usize > capacity { break; }
+
+                unsafe { _mm512_storeu_si512(output.as_mut_ptr().add(written) as *mut __m512i, value) };
+
+                read += 64_usize; written += 64_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = EBCDIC::decode_ascii_from(array, read, read + 64_usize, output, written, codepage, newline)?;
+
+                if next_read < read + 64_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return EBCDIC::decode_ascii_from(array, read, length, output, written, codepage, newline);
+    }
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn from_utf8_into_avx512(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let tables: [__m512i; 16_usize] = unsafe { EBCDIC::tables_avx512(&codepage.ascii_table(newline)) };
+
+        while read + 64_usize <= length {
+            let value: __m512i = unsafe { _mm512_loadu_si512(array.as_ptr().add(read) as *const __m512i) };
+
+            if unsafe { _mm512_movepi8_mask(value) } == 0_u64 {
+                if written + 64_usize > capacity { break; }
+
+                unsafe { _mm512_storeu_si512(output.as_mut_ptr().add(written) as *mut __m512i, EBCDIC::translate_avx512(value, &tables)) };
+
+                read += 64_usize; written += 64_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = EBCDIC::encode_utf8_from(array, read, read + 64_usize, output, written, codepage, newline)?;
+
+                if next_read < read + 64_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return EBCDIC::encode_utf8_from(array, read, length, output, written, codepage, newline);
+    }
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn from_ascii_into_avx512(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let tables: [__m512i; 16_usize] = unsafe { EBCDIC::tables_avx512(&codepage.ascii_table(newline)) };
+
+        while read + 64_usize <= length {
+            let value: __m512i = unsafe { _mm512_loadu_si512(array.as_ptr().add(read) as *const __m512i) };
+
+            if unsafe { _mm512_movepi8_mask(value) } == 0_u64 {
+                if written + 64_usize > capacity { break; }
+
+                unsafe { _mm512_storeu_si512(output.as_mut_ptr().add(written) as *mut __m512i, EBCDIC::translate_avx512(value, &tables)) };
+
+                read += 64_usize; written += 64_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = EBCDIC::encode_ascii_from(array, read, read + 64_usize, output, written, codepage, newline)?;
+
+                if next_read < read + 64_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return EBCDIC::encode_ascii_from(array, read, length, output, written, codepage, newline);
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf8_into_avx2(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> (usize, usize) {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let tables: [__m256i; 16_usize] = unsafe { EBCDIC::tables_avx2(&codepage.translate_table(newline)) };
+
+        while read + 32_usize <= length {
+            let value: __m256i = unsafe { EBCDIC::translate_avx2(_mm256_loadu_si256(array.as_ptr().add(read) as *const __m256i), &tables) };
+
+            if unsafe { _mm256_movemask_epi8(value) } == 0_i32 {
+                if written + 32_usize > capacity { break; }
+
+                unsafe { _mm256_storeu_si256(output.as_mut_ptr().add(written) as *mut __m256i, value) };
+
+                read += 32_usize; written += 32_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = EBCDIC::decode_utf8_from(array, read, read + 32_usize, output, written, codepage, newline);
+
+                if next_read < read + 32_usize { return (next_read, next_written); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return EBCDIC::decode_utf8_from(array, read, length, output, written, codepage, newline);
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_ascii_into_avx2(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let tables: [__m256i; 16_usize] = unsafe { EBCDIC::tables_avx2(&codepage.translate_table(newline)) };
+
+        while read + 32_usize <= length {
+            let value: __m256i = unsafe { EBCDIC::translate_avx2(_mm256_loadu_si256(array.as_ptr().add(read) as *const __m256i), &tables) };
+
+            if unsafe { _mm256_movemask_epi8(value) } == 0_i32 {
+                if written + 32_usize > capacity { break; }
+
+                unsafe { _mm256_storeu_si256(output.as_mut_ptr().add(written) as *mut __m256i, value) };
+
+                read += 32_usize; written += 32_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = EBCDIC::decode_ascii_from(array, read, read + 32_usize, output, written, codepage, newline)?;
+
+                if next_read < read + 32_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return EBCDIC::decode_ascii_from(array, read, length, output, written, codepage, newline);
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn from_utf8_into_avx2(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let tables: [__m256i; 16_usize] = unsafe { EBCDIC::tables_avx2(&codepage.ascii_table(newline)) };
+
+        while read + 32_usize <= length {
+            let value: __m256i = unsafe { _mm256_loadu_si256(array.as_ptr().add(read) as *const __m256i) };
+
+            if unsafe { _mm256_movemask_epi8(value) } == 0_i32 {
+                if written + 32_usize > capacity { break; }
+
+                unsafe { _mm256_storeu_si256(output.as_mut_ptr().add(written) as *mut __m256i, EBCDIC::translate_avx2(value, &tables)) };
+
+                read += 32_usize; written += 32_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = EBCDIC::encode_utf8_from(array, read, read + 32_usize, output, written, codepage, newline)?;
+
+                if next_read < read + 32_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return EBCDIC::encode_utf8_from(array, read, length, output, written, codepage, newline);
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn from_ascii_into_avx2(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let tables: [__m256i; 16_usize] = unsafe { EBCDIC::tables_avx2(&codepage.ascii_table(newline)) };
+
+        while read + 32_usize <= length {
+            let value: __m256i = unsafe { _mm256_loadu_si256(array.as_ptr().add(read) as *const __m256i) };
+
+            if unsafe { _mm256_movemask_epi8(value) } == 0_i32 {
+                if written + 32_usize > capacity { break; }
+
+                unsafe { _mm256_storeu_si256(output.as_mut_ptr().add(written) as *mut __m256i, EBCDIC::translate_avx2(value, &tables)) };
+
+                read += 32_usize; written += 32_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = EBCDIC::encode_ascii_from(array, read, read + 32_usize, output, written, codepage, newline)?;
+
+                if next_read < read + 32_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return EBCDIC::encode_ascii_from(array, read, length, output, written, codepage, newline);
+    }
+
+    #[target_feature(enable = "sse2,ssse3")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_utf8_into_ssse3(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> (usize, usize) {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let tables: [__m128i; 16_usize] = unsafe { EBCDIC::tables_ssse3(&codepage.translate_table(newline)) };
+
+        while read + 16_usize <= length {
+            let value: __m128i = unsafe { EBCDIC::translate_ssse3(_mm_loadu_si128(array.as_ptr().add(read) as *const __m128i), &tables) };
+
+            if unsafe { _mm_movemask_epi8(value) } == 0_i32 {
+                if written + 16_usize > capacity { break; }
+
+                unsafe { _mm_storeu_si128(output.as_mut_ptr().add(written) as *mut __m128i, value) };
+
+                read += 16_usize; written += 16_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = EBCDIC::decode_utf8_from(array, read, read + 16_usize, output, written, codepage, newline);
+
+                if next_read < read + 16_usize { return (next_read, next_written); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return EBCDIC::decode_utf8_from(array, read, length, output, written, codepage, newline);
+    }
+
+    #[target_feature(enable = "sse2,ssse3")]
+    #[allow(unused_unsafe)]
+    unsafe fn to_ascii_into_ssse3(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let tables: [__m128i; 16_usize] = unsafe { EBCDIC::tables_ssse3(&codepage.translate_table(newline)) };
+
+        while read + 16_usize <= length {
+            let value: __m128i = unsafe { EBCDIC::translate_ssse3(_mm_loadu_si128(array.as_ptr().add(read) as *const __m128i), &tables) };
+
+            if unsafe { _mm_movemask_epi8(value) } == 0_i32 {
+                if written + 16_usize > capacity { break; }
+
+                unsafe { _mm_storeu_si128(output.as_mut_ptr().add(written) as *mut __m128i, value) };
+
+                read += 16_usize; written += 16_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = EBCDIC::decode_ascii_from(array, read, read + 16_usize, output, written, codepage, newline)?;
+
+                if next_read < read + 16_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return EBCDIC::decode_ascii_from(array, read, length, output, written, codepage, newline);
+    }
+
+    #[target_feature(enable = "sse2,ssse3")]
+    #[allow(unused_unsafe)]
+    unsafe fn from_utf8_into_ssse3(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let tables: [__m128i; 16_usize] = unsafe { EBCDIC::tables_ssse3(&codepage.ascii_table(newline)) };
+
+        while read + 16_usize <= length {
+            let value: __m128i = unsafe { _mm_loadu_si128(array.as_ptr().add(read) as *const __m128i) };
+
+            if unsafe { _mm_movemask_epi8(value) } == 0_i32 {
+                if written + 16_usize > capacity { break; }
+
+                unsafe { _mm_storeu_si128(output.as_mut_ptr().add(written) as *mut __m128i, EBCDIC::translate_ssse3(value, &tables)) };
+
+                read += 16_usize; written += 16_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = EBCDIC::encode_utf8_from(array, read, read + 16_usize, output, written, codepage, newline)?;
+
+                if next_read < read + 16_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return EBCDIC::encode_utf8_from(array, read, length, output, written, codepage, newline);
+    }
+
+    #[target_feature(enable = "sse2,ssse3")]
+    #[allow(unused_unsafe)]
+    unsafe fn from_ascii_into_ssse3(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut read, mut written): (usize, usize) = (0_usize, 0_usize);
+
+        let tables: [__m128i; 16_usize] = unsafe { EBCDIC::tables_ssse3(&codepage.ascii_table(newline)) };
+
+        while read + 16_usize <= length {
+            let value: __m128i = unsafe { _mm_loadu_si128(array.as_ptr().add(read) as *const __m128i) };
+
+            if unsafe { _mm_movemask_epi8(value) } == 0_i32 {
+                if written + 16_usize > capacity { break; }
+
+                unsafe { _mm_storeu_si128(output.as_mut_ptr().add(written) as *mut __m128i, EBCDIC::translate_ssse3(value, &tables)) };
+
+                read += 16_usize; written += 16_usize;
+            } else {
+                let (next_read, next_written): (usize, usize) = EBCDIC::encode_ascii_from(array, read, read + 16_usize, output, written, codepage, newline)?;
+
+                if next_read < read + 16_usize { return Ok((next_read, next_written)); }
+
+                (read, written) = (next_read, next_written);
+            }
+        }
+
+        return EBCDIC::encode_ascii_from(array, read, length, output, written, codepage, newline);
+    }
+
+    #[target_feature(enable = "sse2")]
+    unsafe fn to_utf8_into_sse2(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> (usize, usize) {
+        if is_x86_feature_detected!("ssse3") { return unsafe { EBCDIC::to_utf8_into_ssse3(array, output, codepage, newline) }; }
+
+        return EBCDIC::decode_utf8_from(array, 0_usize, array.len(), output, 0_usize, codepage, newline);
+    }
+
+    #[target_feature(enable = "sse2")]
+    unsafe fn to_ascii_into_sse2(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        if is_x86_feature_detected!("ssse3") { return unsafe { EBCDIC::to_ascii_into_ssse3(array, output, codepage, newline) }; }
+
+        return EBCDIC::decode_ascii_from(array, 0_usize, array.len(), output, 0_usize, codepage, newline);
+    }
+
+    #[target_feature(enable = "sse2")]
+    unsafe fn from_utf8_into_sse2(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        if is_x86_feature_detected!("ssse3") { return unsafe { EBCDIC::from_utf8_into_ssse3(array, output, codepage, newline) }; }
+
+        return EBCDIC::encode_utf8_from(array, 0_usize, array.len(), output, 0_usize, codepage, newline);
+    }
+
+    #[target_feature(enable = "sse2")]
+    unsafe fn from_ascii_into_sse2(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        if is_x86_feature_detected!("ssse3") { return unsafe { EBCDIC::from_ascii_into_ssse3(array, output, codepage, newline) }; }
+
+        return EBCDIC::encode_ascii_from(array, 0_usize, array.len(), output, 0_usize, codepage, newline);
+    }
+
+    pub fn to_utf8_into(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> (usize, usize) {
+        return dispatch!(unsafe fn(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> (usize, usize), EBCDIC::to_utf8_into_sse2, EBCDIC::to_utf8_into_avx2, EBCDIC::to_utf8_into_avx512);
+    }
+
+    pub fn to_ascii_into(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        return dispatch!(unsafe fn(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError>, EBCDIC::to_ascii_into_sse2, EBCDIC::to_ascii_into_avx2, EBCDIC::to_ascii_into_avx512);
+    }
+
+    pub fn from_utf8_into(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        return dispatch!(unsafe fn(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError>, EBCDIC::from_utf8_into_sse2, EBCDIC::from_utf8_into_avx2, EBCDIC::from_utf8_into_avx512);
+    }
+
+    pub fn from_ascii_into(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        return dispatch!(unsafe fn(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError>, EBCDIC::from_ascii_into_sse2, EBCDIC::from_ascii_into_avx2, EBCDIC::from_ascii_into_avx512);
+    }
+}
@@ -0,0 +1,479 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF8
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+use crate::{
+    functors::{
+        platform::{
+            dispatch::{
+                dispatch
+            }
+        }
+    }
+};
+
+use core::{
+    arch::{
+        x86_64::{
+            __m512i,
+            _mm512_loadu_si512,
+            _mm512_setzero_si512,
+            _mm512_set1_epi8,
+            _mm512_set_epi8,
+            _mm512_set_epi32,
+            _mm512_and_si512,
+            _mm512_alignr_epi8,
+            _mm512_permutex2var_epi32,
+            _mm512_movepi8_mask,
+            _mm512_cmpeq_epi8_mask,
+            _mm512_cmpgt_epu8_mask,
+            _mm512_cmplt_epu8_mask,
+            _mm512_cmplt_epi8_mask,
+            __m256i,
+            _mm256_loadu_si256,
+            _mm256_setzero_si256,
+            _mm256_set1_epi8,
+            _mm256_set_epi8,
+            _mm256_or_si256,
+            _mm256_and_si256,
+            _mm256_alignr_epi8,
+            _mm256_permute2x128_si256,
+            _mm256_subs_epu8,
+            _mm256_cmpeq_epi8,
+            _mm256_cmpgt_epi8,
+            _mm256_movemask_epi8,
+            _mm256_testz_si256,
+            __m128i,
+            _mm_loadu_si128,
+            _mm_setzero_si128,
+            _mm_set1_epi8,
+            _mm_set_epi8,
+            _mm_or_si128,
+            _mm_and_si128,
+            _mm_slli_si128,
+            _mm_srli_si128,
+            _mm_subs_epu8,
+            _mm_cmpeq_epi8,
+            _mm_cmplt_epi8,
+            _mm_cmpgt_epi8,
+            _mm_movemask_epi8
+        }
+    }
+};
+
+impl UTF8 {
+
+    const __ENCODING_MAX_FOLLOWING_BYTES: usize = 3_usize;
+    const __VALIDATION_WINDOW_BYTES: usize = 65536_usize;
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn is_utf8_8x64(array: *const u8, length: usize, previous: &mut __m512i) -> bool {
+        let mut index: usize = 0_usize;
+
+        let (following_mask, second_lead_mask, third_lead_mask, four_lead_mask, max_lead_mask, overlong_mask): (__m512i, __m512i, __m512i, __m512i, __m512i, __m512i) = unsafe {
+            (
+                _mm512_set1_epi8(-0x40), // 0xC0
+                _mm512_set1_epi8(-0x41), // 0xBF
+                _mm512_set1_epi8(-0x21), // 0xDF
+                _mm512_set1_epi8(-0x11), // 0xEF
+                _mm512_set1_epi8(-0x0C), // 0xF4
+                _mm512_set1_epi8(-0x02)  // 0xFE
+            )
+        };
+
+        let (three_lead, three_surrogate_lead, four_lead, four_max_lead): (__m512i, __m512i, __m512i, __m512i) = unsafe {
+            (
+                _mm512_set1_epi8(-0x20), // 0xE0
+                _mm512_set1_epi8(-0x13), // 0xED
+                _mm512_set1_epi8(-0x10), // 0xF0
+                _mm512_set1_epi8(-0x0C)  // 0xF4
+            )
+        };
+
+        let (three_min_following, three_max_following, four_min_following, four_max_following): (__m512i, __m512i, __m512i, __m512i) = unsafe {
+            (
+                _mm512_set1_epi8(-0x60), // 0xA0
+                _mm512_set1_epi8(-0x61), // 0x9F
+                _mm512_set1_epi8(-0x70), // 0x90
+                _mm512_set1_epi8(-0x71)  // 0x8F
+            )
+        };
+
+        let (incomplete_mask, previous_lanes): (__m512i, __m512i) = unsafe {
+            (
+                _mm512_set_epi8(
+                    -0x41, -0x21, -0x11, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01,
+                    -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01,
+                    -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01,
+                    -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01
+                ),
+                _mm512_set_epi32(27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16, 15, 14, 13, 12)
+            )
+        };
+
+        while index < length {
+            let value: __m512i = unsafe { _mm512_loadu_si512(array.add(index) as *const __m512i) };
+
+            if unsafe { _mm512_movepi8_mask(value) } == 0_u64 {
+                if unsafe { _mm512_cmpgt_epu8_mask(*previous, incomplete_mask) } != 0_u64 { return false; }
+            } else {
+                let shifted_previous: __m512i = unsafe { _mm512_permutex2var_epi32(*previous, previous_lanes, value) };
+
+                let (first_previous, second_previous, third_previous): (__m512i, __m512i, __m512i) = unsafe {
+                    (
+                        _mm512_alignr_epi8::<15>(value, shifted_previous),
+                        _mm512_alignr_epi8::<14>(value, shifted_previous),
+                        _mm512_alignr_epi8::<13>(value, shifted_previous)
+                    )
+                };
+
+                let required: u64 = unsafe {
+                    _mm512_cmpgt_epu8_mask(first_previous, second_lead_mask)
+                    | _mm512_cmpgt_epu8_mask(second_previous, third_lead_mask)
+                    | _mm512_cmpgt_epu8_mask(third_previous, four_lead_mask)
+                };
+
+                let error: u64 = unsafe {
+                    (required ^ _mm512_cmplt_epi8_mask(value, following_mask))
+                    | _mm512_cmpgt_epu8_mask(value, max_lead_mask)
+                    | _mm512_cmpeq_epi8_mask(_mm512_and_si512(value, overlong_mask), following_mask)
+                    | (_mm512_cmpeq_epi8_mask(first_previous, three_lead) & _mm512_cmplt_epu8_mask(value, three_min_following))
+                    | (_mm512_cmpeq_epi8_mask(first_previous, three_surrogate_lead) & _mm512_cmpgt_epu8_mask(value, three_max_following))
+                    | (_mm512_cmpeq_epi8_mask(first_previous, four_lead) & _mm512_cmplt_epu8_mask(value, four_min_following))
+                    | (_mm512_cmpeq_epi8_mask(first_previous, four_max_lead) & _mm512_cmpgt_epu8_mask(value, four_max_following))
+                };
+
+                if error != 0_u64 { return false; }
+            }
+
+            *previous = value;
+
+            index += 64_usize;
+        }
+
+        return true;
+    }
+
+    #[target_feature(enable = "avx512f,avx512bw")]
+    #[allow(unused_unsafe)]
+    unsafe fn is_utf8_avx512(array: &[u8]) -> bool {
+        let length: usize = array.len();
+
+        if length == 0_usize { return false; }
+
+        let (mut index, indivisible, mut previous): (usize, usize, __m512i) = (0_usize, length % 64_usize, unsafe { _mm512_setzero_si512() });
+
+        let remains_length: usize = length - indivisible;
+
+        if remains_length != 0_usize && !UTF8::is_utf8_8x64(array.as_ptr(), remains_length, &mut previous) { return false; }
+
+        let indivisible_code_array: [u8; 64_usize] = {
+            let mut indivisible_code_array: [u8; 64_usize] = [0_u8; 64_usize];
+            while index < indivisible { indivisible_code_array[index] = array[remains_length + index]; index += 1_usize; }
+            indivisible_code_array
+        };
+
+        return UTF8::is_utf8_8x64(indivisible_code_array.as_ptr(), 64_usize, &mut previous);
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn is_utf8_8x32(array: *const u8, length: usize, previous: &mut __m256i) -> bool {
+        let mut index: usize = 0_usize;
+
+        let (following_mask, second_lead_mask, third_lead_mask, four_lead_mask, max_lead_mask, overlong_mask): (__m256i, __m256i, __m256i, __m256i, __m256i, __m256i) = unsafe {
+            (
+                _mm256_set1_epi8(-0x40), // 0xC0
+                _mm256_set1_epi8(-0x41), // 0xBF
+                _mm256_set1_epi8(-0x21), // 0xDF
+                _mm256_set1_epi8(-0x11), // 0xEF
+                _mm256_set1_epi8(-0x0C), // 0xF4
+                _mm256_set1_epi8(-0x02)  // 0xFE
+            )
+        };
+
+        let (three_lead, three_surrogate_lead, four_lead, four_max_lead): (__m256i, __m256i, __m256i, __m256i) = unsafe {
+            (
+                _mm256_set1_epi8(-0x20), // 0xE0
+                _mm256_set1_epi8(-0x13), // 0xED
+                _mm256_set1_epi8(-0x10), // 0xF0
+                _mm256_set1_epi8(-0x0C)  // 0xF4
+            )
+        };
+
+        let (three_min_following, three_max_following, four_min_following, four_max_following): (__m256i, __m256i, __m256i, __m256i) = unsafe {
+            (
+                _mm256_set1_epi8(-0x60), // 0xA0
+                _mm256_set1_epi8(-0x61), // 0x9F
+                _mm256_set1_epi8(-0x70), // 0x90
+                _mm256_set1_epi8(-0x71)  // 0x8F
+            )
+        };
+
+        let (zero_mask, incomplete_mask): (__m256i, __m256i) = unsafe {
+            (
+                _mm256_setzero_si256(),
+                _mm256_set_epi8(
+                    -0x41, -0x21, -0x11, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01,
+                    -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01
+                )
+            )
+        };
+
+        while index < length {
+            let value: __m256i = unsafe { _mm256_loadu_si256(array.add(index) as *const __m256i) };
+
+            if unsafe { _mm256_movemask_epi8(value) } == 0_i32 {
+                let incomplete: __m256i = unsafe { _mm256_subs_epu8(*previous, incomplete_mask) };
+
+                if unsafe { _mm256_testz_si256(incomplete, incomplete) } == 0_i32 { return false; }
+            } else {
+                let shifted_previous: __m256i = unsafe { _mm256_permute2x128_si256::<0x21>(*previous, value) };
+
+                let (first_previous, second_previous, third_previous): (__m256i, __m256i, __m256i) = unsafe {
+                    (
+                        _mm256_alignr_epi8::<15>(value, shifted_previous),
+                        _mm256_alignr_epi8::<14>(value, shifted_previous),
+                        _mm256_alignr_epi8::<13>(value, shifted_previous)
+                    )
+                };
+
+                let required: __m256i = unsafe {
+                    _mm256_or_si256(
+                        _mm256_or_si256(_mm256_subs_epu8(first_previous, second_lead_mask), _mm256_subs_epu8(second_previous, third_lead_mask)),
+                        _mm256_subs_epu8(third_previous, four_lead_mask)
+                    )
+                };
+
+                let error: __m256i = unsafe {
+                    _mm256_or_si256(
+                        _mm256_or_si256(
+                            _mm256_cmpeq_epi8(_mm256_cmpeq_epi8(required, zero_mask), _mm256_cmpgt_epi8(following_mask, value)),
+                            _mm256_or_si256(_mm256_subs_epu8(value, max_lead_mask), _mm256_cmpeq_epi8(_mm256_and_si256(value, overlong_mask), following_mask))
+                        ),
+                        _mm256_or_si256(
+                            _mm256_or_si256(
+                                _mm256_and_si256(_mm256_cmpeq_epi8(first_previous, three_lead), _mm256_cmpgt_epi8(three_min_following, value)),
+                                _mm256_and_si256(_mm256_cmpeq_epi8(first_previous, three_surrogate_lead), _mm256_cmpgt_epi8(value, three_max_following))
+                            ),
+                            _mm256_or_si256(
+                                _mm256_and_si256(_mm256_cmpeq_epi8(first_previous, four_lead), _mm256_cmpgt_epi8(four_min_following, value)),
+                                _mm256_and_si256(_mm256_cmpeq_epi8(first_previous, four_max_lead), _mm256_cmpgt_epi8(value, four_max_following))
+                            )
+                        )
+                    )
+                };
+
+                if unsafe { _mm256_testz_si256(error, error) } == 0_i32 { return false; }
+            }
+
+            *previous = value;
+
+            index += 32_usize;
+        }
+
+        return true;
+    }
+
+    #[target_feature(enable = "avx,avx2")]
+    #[allow(unused_unsafe)]
+    unsafe fn is_utf8_avx2(array: &[u8]) -> bool {
+        let length: usize = array.len();
+
+        if length == 0_usize { return false; }
+
+        let (mut index, indivisible, mut previous): (usize, usize, __m256i) = (0_usize, length % 32_usize, unsafe { _mm256_setzero_si256() });
+
+        let remains_length: usize = length - indivisible;
+
+        if remains_length != 0_usize && !UTF8::is_utf8_8x32(array.as_ptr(), remains_length, &mut previous) { return false; }
+
+        let indivisible_code_array: [u8; 32_usize] = {
+            let mut indivisible_code_array: [u8; 32_usize] = [0_u8; 32_usize];
+            while index < indivisible { indivisible_code_array[index] = array[remains_length + index]; index += 1_usize; }
+            indivisible_code_array
+        };
+
+        return UTF8::is_utf8_8x32(indivisible_code_array.as_ptr(), 32_usize, &mut previous);
+    }
+
+    #[target_feature(enable = "sse2")]
+    #[allow(unused_unsafe)]
+    unsafe fn is_utf8_8x16(array: *const u8, length: usize, previous: &mut __m128i) -> bool {
+        let mut index: usize = 0_usize;
+
+        let (following_mask, second_lead_mask, third_lead_mask, four_lead_mask, max_lead_mask, overlong_mask): (__m128i, __m128i, __m128i, __m128i, __m128i, __m128i) = unsafe {
+            (
+                _mm_set1_epi8(-0x40), // 0xC0
+                _mm_set1_epi8(-0x41), // 0xBF
+                _mm_set1_epi8(-0x21), // 0xDF
+                _mm_set1_epi8(-0x11), // 0xEF
+                _mm_set1_epi8(-0x0C), // 0xF4
+                _mm_set1_epi8(-0x02)  // 0xFE
+            )
+        };
+
+        let (three_lead, three_surrogate_lead, four_lead, four_max_lead): (__m128i, __m128i, __m128i, __m128i) = unsafe {
+            (
+                _mm_set1_epi8(-0x20), // 0xE0
+                _mm_set1_epi8(-0x13), // 0xED
+                _mm_set1_epi8(-0x10), // 0xF0
+                _mm_set1_epi8(-0x0C)  // 0xF4
+            )
+        };
+
+        let (three_min_following, three_max_following, four_min_following, four_max_following): (__m128i, __m128i, __m128i, __m128i) = unsafe {
+            (
+                _mm_set1_epi8(-0x60), // 0xA0
+                _mm_set1_epi8(-0x61), // 0x9F
+                _mm_set1_epi8(-0x70), // 0x90
+                _mm_set1_epi8(-0x71)  // 0x8F
+            )
+        };
+
+        let (zero_mask, incomplete_mask): (__m128i, __m128i) = unsafe {
+            (
+                _mm_setzero_si128(),
+                _mm_set_epi8(-0x41, -0x21, -0x11, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01, -0x01)
+            )
+        };
+
+        while index < length {
+            let value: __m128i = unsafe { _mm_loadu_si128(array.add(index) as *const __m128i) };
+
+            if unsafe { _mm_movemask_epi8(value) } == 0_i32 {
+                if unsafe { _mm_movemask_epi8(_mm_cmpeq_epi8(_mm_subs_epu8(*previous, incomplete_mask), zero_mask)) } != 0xFFFF_i32 { return false; }
+            } else {
+                let (first_previous, second_previous, third_previous): (__m128i, __m128i, __m128i) = unsafe {
+                    (
+                        _mm_or_si128(_mm_slli_si128::<1>(value), _mm_srli_si128::<15>(*previous)),
+                        _mm_or_si128(_mm_slli_si128::<2>(value), _mm_srli_si128::<14>(*previous)),
+                        _mm_or_si128(_mm_slli_si128::<3>(value), _mm_srli_si128::<13>(*previous))
+                    )
+                };
+
+                let required: __m128i = unsafe {
+                    _mm_or_si128(
+                        _mm_or_si128(_mm_subs_epu8(first_previous, second_lead_mask), _mm_subs_epu8(second_previous, third_lead_mask)),
+                        _mm_subs_epu8(third_previous, four_lead_mask)
+                    )
+                };
+
+                let error: __m128i = unsafe {
+                    _mm_or_si128(
+                        _mm_or_si128(
+                            _mm_cmpeq_epi8(_mm_cmpeq_epi8(required, zero_mask), _mm_cmplt_epi8(value, following_mask)),
+                            _mm_or_si128(_mm_subs_epu8(value, max_lead_mask), _mm_cmpeq_epi8(_mm_and_si128(value, overlong_mask), following_mask))
+                        ),
+                        _mm_or_si128(
+                            _mm_or_si128(
+                                _mm_and_si128(_mm_cmpeq_epi8(first_previous, three_lead), _mm_cmplt_epi8(value, three_min_following)),
+                                _mm_and_si128(_mm_cmpeq_epi8(first_previous, three_surrogate_lead), _mm_cmpgt_epi8(value, three_max_following))
+                            ),
+                            _mm_or_si128(
+                                _mm_and_si128(_mm_cmpeq_epi8(first_previous, four_lead), _mm_cmplt_epi8(value, four_min_following)),
+                                _mm_and_si128(_mm_cmpeq_epi8(first_previous, four_max_lead), _mm_cmpgt_epi8(value, four_max_following))
+                            )
+                        )
+                    )
+                };
+
+                if unsafe { _mm_movemask_epi8(_mm_cmpeq_epi8(error, zero_mask)) } != 0xFFFF_i32 { return false; }
+            }
+
+            *previous = value;
+
+            index += 16_usize;
+        }
+
+        return true;
+    }
+
+    #[target_feature(enable = "sse2")]
+    #[allow(unused_unsafe)]
+    unsafe fn is_utf8_sse2(array: &[u8]) -> bool {
+        let length: usize = array.len();
+
+        if length == 0_usize { return false; }
+
+        let (mut index, indivisible, mut previous): (usize, usize, __m128i) = (0_usize, length % 16_usize, unsafe { _mm_setzero_si128() });
+
+        let remains_length: usize = length - indivisible;
+
+        if remains_length != 0_usize && !UTF8::is_utf8_8x16(array.as_ptr(), remains_length, &mut previous) { return false; }
+
+        let indivisible_code_array: [u8; 16_usize] = {
+            let mut indivisible_code_array: [u8; 16_usize] = [0_u8; 16_usize];
+            while index < indivisible { indivisible_code_array[index] = array[remains_length + index]; index += 1_usize; }
+            indivisible_code_array
+        };
+
+        return UTF8::is_utf8_8x16(indivisible_code_array.as_ptr(), 16_usize, &mut previous);
+    }
+
+    pub fn is_utf8(array: &[u8]) -> bool {
+        return dispatch!(unsafe fn(array: &[u8]) -> bool, UTF8::is_utf8_sse2, UTF8::is_utf8_avx2, UTF8::is_utf8_avx512);
+    }
+
+    pub fn validate_utf8(array: &[u8]) -> Result<(), EncodingError> {
+        let length: usize = array.len();
+
+        if length == 0_usize { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        let mut start: usize = 0_usize;
+
+        while start < length {
+            let mut end: usize = if length - start > UTF8::__VALIDATION_WINDOW_BYTES { start + UTF8::__VALIDATION_WINDOW_BYTES } else { length };
+
+            while end != length && end + UTF8::__ENCODING_MAX_FOLLOWING_BYTES > start + UTF8::__VALIDATION_WINDOW_BYTES && UTF8::is_following(array[end]) {
+                end -= 1_usize;
+            }
+
+            if !UTF8::is_utf8(&array[start..end]) {
+                UTF8::locate_invalid_utf8(&array[start..end], start)?;
+            }
+
+            start = end;
+        }
+
+        return Ok(());
+    }
+}
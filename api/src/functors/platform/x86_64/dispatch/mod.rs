@@ -0,0 +1,58 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+mod level;
+
+pub use level::{SimdLevel};
+
+macro_rules! dispatch {
+    (unsafe fn($($argument:ident: $argument_type:ty),*) -> $result:ty, $sse2:path, $avx2:path, $avx512:path) => {{
+        static FUNCTION: core::sync::atomic::AtomicPtr<()> = core::sync::atomic::AtomicPtr::new(core::ptr::null_mut());
+
+        // Tests force every level in turn, so they must not reuse the cached kernel.
+        let mut function: *mut () = if cfg!(test) { core::ptr::null_mut() } else { FUNCTION.load(core::sync::atomic::Ordering::Relaxed) };
+
+        if function.is_null() {
+            function = match $crate::essence::SimdLevel::selected() {
+                $crate::essence::SimdLevel::AVX512 => ($avx512 as unsafe fn($($argument_type),*) -> $result) as *mut (),
+                $crate::essence::SimdLevel::AVX2 => ($avx2 as unsafe fn($($argument_type),*) -> $result) as *mut (),
+                _ => ($sse2 as unsafe fn($($argument_type),*) -> $result) as *mut ()
+            };
+
+            FUNCTION.store(function, core::sync::atomic::Ordering::Relaxed);
+        }
+
+        // Callers already inside an `unsafe` block would otherwise see this one as redundant.
+        #[allow(unused_unsafe)]
+        let result: $result = unsafe { core::mem::transmute::<*mut (), unsafe fn($($argument_type),*) -> $result>(function)($($argument),*) };
+
+        result
+    }};
+}
+
+pub(crate) use dispatch;
@@ -28,3 +28,7 @@
 
 pub mod codings;
 pub mod search;
+pub mod dispatch;
+
+#[cfg(test)]
+mod tests;
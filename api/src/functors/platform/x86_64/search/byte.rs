@@ -28,14 +28,28 @@
 
 pub use crate::{
     essence::{
-        ByteSearch
+        ByteSearch,
+        ByteMatches,
+        ByteMatchesRev
+    }
+};
+
+use crate::{
+    functors::{
+        platform::{
+            dispatch::{
+                dispatch
+            }
+        }
     }
 };
 
-#[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
 use core::{
     mem::{
-        transmute,
+        transmute
+    },
+    marker::{
+        PhantomData
     },
     arch::{
         x86_64::{
@@ -44,90 +58,103 @@ use core::{
             __mmask32,
             __mmask64,
             _mm512_loadu_si512,
-            _mm512_set1_epi8, _mm512_set1_epi16, _mm512_set1_epi32,
+            _mm512_set1_epi8,
+            _mm512_set1_epi16,
+            _mm512_set1_epi32,
             _mm512_and_si512,
             _mm512_andnot_si512,
-            _mm512_cmpeq_epi8_mask, _mm512_cmpeq_epi16_mask, _mm512_cmpeq_epi32_mask,
+            _mm512_cmpeq_epi8_mask,
+            _mm512_cmpeq_epi16_mask,
+            _mm512_cmpeq_epi32_mask,
+            _mm512_cmpgt_epi8_mask,
+            _mm512_cmpgt_epi16_mask,
+            _mm512_cmpgt_epi32_mask,
+            _mm512_cmplt_epi8_mask,
+            _mm512_cmplt_epi16_mask,
+            _mm512_cmplt_epi32_mask,
+            _mm512_or_si512,
             _mm512_storeu_si512,
-            _mm512_maskz_mov_epi8, _mm512_maskz_mov_epi16, _mm512_maskz_mov_epi32,
-            _mm512_movepi8_mask, _mm512_movepi16_mask, _mm512_movepi32_mask
-        }
-    }
-};
-
-#[cfg(all(target_feature = "avx", target_feature = "avx2", not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-use core::{
-    mem::{
-        transmute,
-    },
-    arch::{
-        x86_64::{
+            _mm512_maskz_mov_epi8,
+            _mm512_maskz_mov_epi16,
+            _mm512_maskz_mov_epi32,
+            _mm512_movepi8_mask,
+            _mm512_movepi16_mask,
+            _mm512_movepi32_mask,
             __m256i,
             _mm256_loadu_si256,
-            _mm256_set1_epi8, _mm256_set1_epi16, _mm256_set1_epi32,
+            _mm256_set1_epi8,
+            _mm256_set1_epi16,
+            _mm256_set1_epi32,
             _mm256_and_si256,
             _mm256_andnot_si256,
-            _mm256_cmpeq_epi8, _mm256_cmpeq_epi16, _mm256_cmpeq_epi32,
+            _mm256_cmpeq_epi8,
+            _mm256_cmpeq_epi16,
+            _mm256_cmpeq_epi32,
+            _mm256_cmpgt_epi8,
+            _mm256_cmpgt_epi16,
+            _mm256_cmpgt_epi32,
+            _mm256_or_si256,
             _mm256_storeu_si256,
-            _mm256_movemask_epi8
-        }
-    }
-};
-
-#[cfg(all(target_feature = "sse2", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-use core::{
-    mem::{
-        transmute,
-    },
-    arch::{
-        x86_64::{
+            _mm256_movemask_epi8,
             __m128i,
             _mm_loadu_si128,
-            _mm_set1_epi8, _mm_set1_epi16, _mm_set1_epi32,
+            _mm_set1_epi8,
+            _mm_set1_epi16,
+            _mm_set1_epi32,
             _mm_and_si128,
             _mm_andnot_si128,
-            _mm_cmpeq_epi8, _mm_cmpeq_epi16, _mm_cmpeq_epi32,
+            _mm_cmpeq_epi8,
+            _mm_cmpeq_epi16,
+            _mm_cmpeq_epi32,
+            _mm_cmpgt_epi8,
+            _mm_cmpgt_epi16,
+            _mm_cmpgt_epi32,
+            _mm_or_si128,
             _mm_storeu_si128,
             _mm_movemask_epi8
         }
     }
 };
 
-#[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
-macro_rules! generate_search {
-    ($t:ty, $t_default:expr, $not_ignore_mask:expr, $t_size:expr, $precision:ty, $register_size:expr, $mask:ty, $load:expr, $store:expr, $dup_one_t:expr, $eq_compare:expr, $bitwise_and:expr, $bitwise_not_and:expr, $vector_to_scalar:expr, $mask_to_vector:expr) => {
-        impl ByteSearch<$t> {
+impl<T> ByteSearch<T> {
 
-            const fn is_search_possible(array: &[$t], pattern: &[$t], limit: Option<usize>) -> (usize, usize, bool) {
-                return match limit {
-                    Some(limit) => {
-                        let (mut array_length, pattern_length): (usize, usize) = (array.len(), pattern.len());
+    const fn is_search_possible(array: &[T], pattern: &[T], limit: Option<usize>) -> (usize, usize, bool) {
+        return match limit {
+            Some(limit) => {
+                let (mut array_length, pattern_length): (usize, usize) = (array.len(), pattern.len());
 
-                        if (pattern_length == 0_usize) || (array_length == 0_usize) || (pattern_length > array_length) {
-                            return (0_usize, 0_usize, false);
-                        } else if limit > 0_usize {
-                            if limit >= array_length { (array_length - (pattern_length - 1_usize), pattern_length, true) }
-                            else {
-                                array_length -= limit;
+                if (pattern_length == 0_usize) || (array_length == 0_usize) || (pattern_length > array_length) {
+                    return (0_usize, 0_usize, false);
+                } else if limit > 0_usize {
+                    if limit >= array_length { (array_length - (pattern_length - 1_usize), pattern_length, true) }
+                    else {
+                        array_length -= limit;
 
-                                if pattern_length > array_length { return (0_usize, 0_usize, false); }
-                                else { (array_length - (pattern_length - 1_usize), pattern_length, true) }
-                            }
-                        } else { return (0_usize, 0_usize, false); }
+                        if pattern_length > array_length { return (0_usize, 0_usize, false); }
+                        else { (array_length - (pattern_length - 1_usize), pattern_length, true) }
                     }
-                    None => {
-                        let (array_length, pattern_length): (usize, usize) = (array.len(), pattern.len());
+                } else { return (0_usize, 0_usize, false); }
+            }
+            None => {
+                let (array_length, pattern_length): (usize, usize) = (array.len(), pattern.len());
 
-                        if (pattern_length == 0_usize) || (array_length == 0_usize) || (pattern_length > array_length) {
-                            return (0_usize, 0_usize, false);
-                        }
+                if (pattern_length == 0_usize) || (array_length == 0_usize) || (pattern_length > array_length) {
+                    return (0_usize, 0_usize, false);
+                }
 
-                        (array_length - (pattern_length - 1_usize), pattern_length, true)
-                    }
-                };
+                (array_length - (pattern_length - 1_usize), pattern_length, true)
             }
+        };
+    }
+}
+
+macro_rules! generate_search_avx512 {
+    ($feature:literal, $search_first:ident, $search_last:ident, $search_folded:ident, $search_all:ident, $search_all_overlapping:ident, $t:ty, $t_default:expr, $not_ignore_mask:expr, $t_size:expr, $precision:ty, $register_size:expr, $mask:ty, $load:expr, $store:expr, $dup_one_t:expr, $eq_compare:expr, $bitwise_and:expr, $bitwise_not_and:expr, $vector_to_scalar:expr, $mask_to_vector:expr, $greater_than:expr, $less_than:expr, $bitwise_or:expr) => {
+        impl ByteSearch<$t> {
 
-            pub fn search_single(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize>
+            #[target_feature(enable = $feature)]
+            #[allow(unused_unsafe)]
+            unsafe fn $search_first(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Option<usize>
             where $t: Copy + Sized {
 
                 const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
@@ -138,26 +165,24 @@ macro_rules! generate_search {
                     (1_usize * $register_size) / $t_size
                 ];
 
-                let mut search_result: Vec<usize> = Vec::<usize>::new();
-
                 let (array, pattern): (&[$t], &[$t]) = (
                     unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(array_ptr.as_ptr()), array_ptr.len() / $t_size) },
                     unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(pattern_ptr.as_ptr()), pattern_ptr.len() / $t_size) }
                 );
 
-                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return search_result; }
+                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return None; }
 
                 let (mut array_ptr, mut array_index, mut remains_length, mut index_of_match): (*const $t, usize, usize, usize) = (array.as_ptr(), 0_usize, array_length, 0_usize);
 
                 let (mut matches, mask_to_vector_mask, and_not_mask, zero): ([[$t; COUNT_OF_VALUES_IN_REGISTER]; 4_usize], $precision, $precision, $mask) = ([[$t_default; COUNT_OF_VALUES_IN_REGISTER]; 4_usize], unsafe { $dup_one_t($not_ignore_mask) }, unsafe { $dup_one_t($not_ignore_mask) }, 0);
 
-                let (load_match_ptr, write_match_ptr): ([*const i32; 4_usize], [*mut $precision; 4_usize]) = unsafe {
+                let (load_match_ptr, write_match_ptr): ([*const $precision; 4_usize], [*mut $precision; 4_usize]) = unsafe {
                     (
                         [
-                            transmute::<*const $t, *const i32>(matches[0].as_ptr()),
-                            transmute::<*const $t, *const i32>(matches[1].as_ptr()),
-                            transmute::<*const $t, *const i32>(matches[2].as_ptr()),
-                            transmute::<*const $t, *const i32>(matches[3].as_ptr())
+                            transmute::<*const $t, *const $precision>(matches[0].as_ptr()),
+                            transmute::<*const $t, *const $precision>(matches[1].as_ptr()),
+                            transmute::<*const $t, *const $precision>(matches[2].as_ptr()),
+                            transmute::<*const $t, *const $precision>(matches[3].as_ptr())
                         ],
                         [
                             transmute::<*mut $t, *mut $precision>(matches[0].as_mut_ptr()),
@@ -176,10 +201,10 @@ macro_rules! generate_search {
                         if remains_length >= PRECISION_LENGTH[3] {
                             while remains_length >= PRECISION_LENGTH[0] {
                                 let (first, second, third, four): ($precision, $precision, $precision, $precision) = {
-                                    let first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let four: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let four: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
                                     (first, second, third, four)
                                 };
@@ -192,7 +217,7 @@ macro_rules! generate_search {
                                 for i in 0..4 {
                                     if $vector_to_scalar($load(load_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -205,9 +230,9 @@ macro_rules! generate_search {
 
                             if remains_length >= PRECISION_LENGTH[1] {
                                 let (first, second, third): ($precision, $precision, $precision) = {
-                                    let first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
                                     (first, second, third)
                                 };
@@ -219,7 +244,7 @@ macro_rules! generate_search {
                                 for i in 0..3 {
                                     if $vector_to_scalar($load(load_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -232,8 +257,8 @@ macro_rules! generate_search {
 
                             if remains_length >= PRECISION_LENGTH[2] {
                                 let (first, second): ($precision, $precision) = {
-                                    let first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
                                     (first, second)
                                 };
@@ -244,7 +269,7 @@ macro_rules! generate_search {
                                 for i in 0..2 {
                                     if $vector_to_scalar($load(load_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -256,13 +281,13 @@ macro_rules! generate_search {
                             }
 
                             if remains_length >= PRECISION_LENGTH[3] {
-                                let first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
+                                let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
 
                                 $store(write_match_ptr[0], $mask_to_vector($eq_compare(first, pattern_mask), mask_to_vector_mask));
 
                                 if $vector_to_scalar($load(load_match_ptr[0])) != zero {
                                     while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                        if matches[0][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                         else { index_of_match += 1_usize; }
                                     }
                                     index_of_match = 0_usize;
@@ -280,13 +305,13 @@ macro_rules! generate_search {
                                 indivisible_part
                             };
 
-                            let first: $precision = $load(transmute::<*const $t, *const i32>(aligned_array.as_ptr()));
+                            let first: $precision = $load(transmute::<*const $t, *const $precision>(aligned_array.as_ptr()));
 
                             $store(write_match_ptr[0], $mask_to_vector($eq_compare(first, pattern_mask), mask_to_vector_mask));
 
                             if $vector_to_scalar($load(load_match_ptr[0])) != zero {
                                 while index_of_match < remains_length {
-                                    if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                    if matches[0][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                     else { index_of_match += 1_usize; }
                                 }
                             }
@@ -298,20 +323,20 @@ macro_rules! generate_search {
                         if remains_length >= PRECISION_LENGTH[3] + 1_usize {
                             while remains_length >= PRECISION_LENGTH[0] + 1_usize {
                                 let (s_first, e_first, s_second, e_second, s_third, e_third, s_four, e_four): ($precision, $precision, $precision, $precision, $precision, $precision, $precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
-                                    let s_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
-                                    let s_third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
-                                    let s_four: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_four: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_four: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_four: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
                                     (s_first, e_first, s_second, e_second, s_third, e_third, s_four, e_four)
@@ -325,7 +350,7 @@ macro_rules! generate_search {
                                 for i in 0..4 {
                                     if $vector_to_scalar($load(load_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -338,16 +363,16 @@ macro_rules! generate_search {
 
                             if remains_length >= PRECISION_LENGTH[1] + 1_usize {
                                 let (s_first, e_first, s_second, e_second, s_third, e_third): ($precision, $precision, $precision, $precision, $precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
-                                    let s_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
-                                    let s_third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
                                     (s_first, e_first, s_second, e_second, s_third, e_third)
@@ -360,7 +385,7 @@ macro_rules! generate_search {
                                 for i in 0..3 {
                                     if $vector_to_scalar($load(load_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -373,12 +398,12 @@ macro_rules! generate_search {
 
                             if remains_length >= PRECISION_LENGTH[2] + 1_usize {
                                 let (s_first, e_first, s_second, e_second): ($precision, $precision, $precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
-                                    let s_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
                                     (s_first, e_first, s_second, e_second)
@@ -390,7 +415,7 @@ macro_rules! generate_search {
                                 for i in 0..2 {
                                     if $vector_to_scalar($load(load_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -403,8 +428,8 @@ macro_rules! generate_search {
 
                             if remains_length >= PRECISION_LENGTH[3] + 1_usize {
                                 let (s_first, e_first): ($precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
                                     (s_first, e_first)
@@ -414,7 +439,7 @@ macro_rules! generate_search {
 
                                 if $vector_to_scalar($load(load_match_ptr[0])) != zero {
                                     while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                        if matches[0][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                         else { index_of_match += 1_usize; }
                                     }
                                     index_of_match = 0_usize;
@@ -433,8 +458,8 @@ macro_rules! generate_search {
                             };
 
                             let (s_first, e_first): ($precision, $precision) = {
-                                let s_first: $precision = $load(transmute::<*const $t, *const i32>(aligned_array.as_ptr()));
-                                let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                let s_first: $precision = $load(transmute::<*const $t, *const $precision>(aligned_array.as_ptr()));
+                                let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
 
                                 (s_first, e_first)
                             };
@@ -443,7 +468,7 @@ macro_rules! generate_search {
 
                             if $vector_to_scalar($load(load_match_ptr[0])) != zero {
                                 while index_of_match < remains_length {
-                                    if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                    if matches[0][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                     else { index_of_match += 1_usize; }
                                 }
                             }
@@ -458,8 +483,8 @@ macro_rules! generate_search {
                             if pattern_length > COUNT_OF_VALUES_IN_REGISTER {
                                 while array_index < last_array_index_multiple_of_register {
                                     let (s_first, e_first): ($precision, $precision) = {
-                                        let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                        let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(last_pattern_index)));
+                                        let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                        let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(last_pattern_index)));
 
                                         (s_first, e_first)
                                     };
@@ -473,8 +498,8 @@ macro_rules! generate_search {
 
                                                 for i in 0_usize..middle_pattern_parts - 1_usize {
                                                     if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                        $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match + 1_usize + i * COUNT_OF_VALUES_IN_REGISTER))),
-                                                        $load(transmute::<*const $t, *const i32>(pattern.as_ptr().add(1_usize + i * COUNT_OF_VALUES_IN_REGISTER)))
+                                                        $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + 1_usize + i * COUNT_OF_VALUES_IN_REGISTER))),
+                                                        $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(1_usize + i * COUNT_OF_VALUES_IN_REGISTER)))
                                                     ), mask_to_vector_mask), and_not_mask)) != 0 {
                                                         result &= false; break
                                                     }
@@ -482,10 +507,10 @@ macro_rules! generate_search {
 
                                                 if result {
                                                     if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                        $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
-                                                        $load(transmute::<*const $t, *const i32>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
+                                                        $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
+                                                        $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
                                                     ), mask_to_vector_mask), and_not_mask)) == 0 {
-                                                        search_result.push((array_index + index_of_match) * $t_size); return search_result;
+                                                        return Some((array_index + index_of_match) * $t_size);
                                                     }
                                                 }
                                             }
@@ -496,12 +521,12 @@ macro_rules! generate_search {
                                     array_index += COUNT_OF_VALUES_IN_REGISTER; array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
                                 }
                             } else if pattern_length == COUNT_OF_VALUES_IN_REGISTER {
-                                let pattern_loaded: $precision = $load(transmute::<*const $t, *const i32>(pattern.as_ptr()));
+                                let pattern_loaded: $precision = $load(transmute::<*const $t, *const $precision>(pattern.as_ptr()));
 
                                 while array_index < last_array_index_multiple_of_register {
                                     let (s_first, e_first): ($precision, $precision) = {
-                                        let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                        let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(last_pattern_index)));
+                                        let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                        let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(last_pattern_index)));
 
                                         (s_first, e_first)
                                     };
@@ -512,10 +537,10 @@ macro_rules! generate_search {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                             if matches[0][index_of_match] != 0 {
                                                 if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                    $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match))),
+                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
                                                     pattern_loaded
                                                 ), mask_to_vector_mask), and_not_mask)) == 0 {
-                                                    search_result.push((array_index + index_of_match) * $t_size); return search_result;
+                                                    return Some((array_index + index_of_match) * $t_size);
                                                 }
                                             }
                                             index_of_match += 1_usize;
@@ -528,13 +553,13 @@ macro_rules! generate_search {
                                 let (pattern_loaded, pattern_ignore_mask): ($precision, $precision) = {
                                     let (mut pattern_array, mut ignore_mask): ([$t; COUNT_OF_VALUES_IN_REGISTER], [$t; COUNT_OF_VALUES_IN_REGISTER]) = ([$t_default; COUNT_OF_VALUES_IN_REGISTER], [$t_default; COUNT_OF_VALUES_IN_REGISTER]);
                                     for i in 0_usize..pattern_length { pattern_array[i] = pattern[i]; ignore_mask[i] = $not_ignore_mask; }
-                                    ($load(transmute::<*const $t, *const i32>(pattern_array.as_ptr())), $load(transmute::<*const $t, *const i32>(ignore_mask.as_ptr())))
+                                    ($load(transmute::<*const $t, *const $precision>(pattern_array.as_ptr())), $load(transmute::<*const $t, *const $precision>(ignore_mask.as_ptr())))
                                 };
 
                                 while array_index < last_array_index_multiple_of_register {
                                     let (s_first, e_first): ($precision, $precision) = {
-                                        let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                        let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(last_pattern_index)));
+                                        let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                        let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(last_pattern_index)));
 
                                         (s_first, e_first)
                                     };
@@ -545,10 +570,10 @@ macro_rules! generate_search {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                             if matches[0][index_of_match] != 0 {
                                                 if $vector_to_scalar($bitwise_and($bitwise_not_and($mask_to_vector($eq_compare(
-                                                    $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match))),
+                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
                                                     pattern_loaded
                                                 ), mask_to_vector_mask), and_not_mask), pattern_ignore_mask)) == 0 {
-                                                    search_result.push((array_index + index_of_match) * $t_size); return search_result;
+                                                    return Some((array_index + index_of_match) * $t_size);
                                                 }
                                             }
                                             index_of_match += 1_usize;
@@ -580,8 +605,8 @@ macro_rules! generate_search {
 
                                             for i in 0_usize..middle_pattern_parts - 1_usize {
                                                 if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                    $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match + 1_usize + i * COUNT_OF_VALUES_IN_REGISTER))),
-                                                    $load(transmute::<*const $t, *const i32>(pattern.as_ptr().add(1_usize + i * COUNT_OF_VALUES_IN_REGISTER)))
+                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + 1_usize + i * COUNT_OF_VALUES_IN_REGISTER))),
+                                                    $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(1_usize + i * COUNT_OF_VALUES_IN_REGISTER)))
                                                 ), mask_to_vector_mask), and_not_mask)) != 0 {
                                                     result &= false; break
                                                 }
@@ -589,10 +614,10 @@ macro_rules! generate_search {
 
                                             if result {
                                                 if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                    $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
-                                                    $load(transmute::<*const $t, *const i32>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
+                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
+                                                    $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
                                                 ), mask_to_vector_mask), and_not_mask)) == 0 {
-                                                    search_result.push((array_index + index_of_match) * $t_size); return search_result;
+                                                    return Some((array_index + index_of_match) * $t_size);
                                                 }
                                             }
                                         }
@@ -600,16 +625,16 @@ macro_rules! generate_search {
                                     }
                                 }
                             } else if pattern_length == COUNT_OF_VALUES_IN_REGISTER {
-                                let pattern_loaded: $precision = $load(transmute::<*const $t, *const i32>(pattern.as_ptr()));
+                                let pattern_loaded: $precision = $load(transmute::<*const $t, *const $precision>(pattern.as_ptr()));
 
                                 if $vector_to_scalar($load(load_match_ptr[0])) != zero {
                                     while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                         if matches[0][index_of_match] != 0 {
                                             if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match))),
+                                                $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
                                                 pattern_loaded
                                             ), mask_to_vector_mask), and_not_mask)) == 0 {
-                                                search_result.push((array_index + index_of_match) * $t_size);
+                                                return Some((array_index + index_of_match) * $t_size);
                                             }
                                         }
                                         index_of_match += 1_usize;
@@ -619,17 +644,17 @@ macro_rules! generate_search {
                                 let (pattern_loaded, pattern_ignore_mask): ($precision, $precision) = {
                                     let (mut pattern_array, mut ignore_mask): ([$t; COUNT_OF_VALUES_IN_REGISTER], [$t; COUNT_OF_VALUES_IN_REGISTER]) = ([$t_default; COUNT_OF_VALUES_IN_REGISTER], [$t_default; COUNT_OF_VALUES_IN_REGISTER]);
                                     for i in 0_usize..pattern_length { pattern_array[i] = pattern[i]; ignore_mask[i] = $not_ignore_mask; }
-                                    ($load(transmute::<*const $t, *const i32>(pattern_array.as_ptr())), $load(transmute::<*const $t, *const i32>(ignore_mask.as_ptr())))
+                                    ($load(transmute::<*const $t, *const $precision>(pattern_array.as_ptr())), $load(transmute::<*const $t, *const $precision>(ignore_mask.as_ptr())))
                                 };
 
                                 if $vector_to_scalar($load(load_match_ptr[0])) != zero {
                                     while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                         if matches[0][index_of_match] != 0 {
                                             if $vector_to_scalar($bitwise_and($bitwise_not_and($mask_to_vector($eq_compare(
-                                                $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match))),
+                                                $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
                                                 pattern_loaded
                                             ), mask_to_vector_mask), and_not_mask), pattern_ignore_mask)) == 0 {
-                                                search_result.push((array_index + index_of_match) * $t_size); return search_result;
+                                                return Some((array_index + index_of_match) * $t_size);
                                             }
                                         }
                                         index_of_match += 1_usize;
@@ -640,10 +665,107 @@ macro_rules! generate_search {
                     }
                 }
 
-                return search_result;
+                return None;
+            }
+
+            #[target_feature(enable = $feature)]
+            unsafe fn $search_last(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Option<usize>
+            where $t: Copy + Sized {
+
+                const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
+
+                let (array, pattern): (&[$t], &[$t]) = (
+                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(array_ptr.as_ptr()), array_ptr.len() / $t_size) },
+                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(pattern_ptr.as_ptr()), pattern_ptr.len() / $t_size) }
+                );
+
+                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return None; }
+
+                let (mut remains_length, last_pattern_index): (usize, usize) = (array_length, pattern_length - 1_usize);
+
+                unsafe {
+                    let (first_mask, last_mask): ($precision, $precision) = ($dup_one_t(pattern[0]), $dup_one_t(pattern[last_pattern_index]));
+
+                    while remains_length >= COUNT_OF_VALUES_IN_REGISTER {
+                        let array_index: usize = remains_length - COUNT_OF_VALUES_IN_REGISTER;
+
+                        let mut candidates: $mask = $eq_compare($load(transmute::<*const $t, *const $precision>(array.as_ptr().add(array_index))), first_mask)
+                            & $eq_compare($load(transmute::<*const $t, *const $precision>(array.as_ptr().add(array_index + last_pattern_index))), last_mask);
+
+                        while candidates != 0 {
+                            let index_of_match: usize = (<$mask>::BITS - 1_u32 - candidates.leading_zeros()) as usize;
+
+                            if array[array_index + index_of_match..array_index + index_of_match + pattern_length] == *pattern { return Some((array_index + index_of_match) * $t_size); }
+
+                            candidates &= ((1 as $mask) << index_of_match) - 1;
+                        }
+
+                        remains_length = array_index;
+                    }
+                }
+
+                while remains_length > 0_usize {
+                    remains_length -= 1_usize;
+
+                    if array[remains_length..remains_length + pattern_length] == *pattern { return Some(remains_length * $t_size); }
+                }
+
+                return None;
+            }
+
+            #[target_feature(enable = $feature)]
+            unsafe fn $search_folded(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Option<usize>
+            where $t: Copy + Sized {
+
+                const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
+
+                let (array, pattern): (&[$t], &[$t]) = (
+                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(array_ptr.as_ptr()), array_ptr.len() / $t_size) },
+                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(pattern_ptr.as_ptr()), pattern_ptr.len() / $t_size) }
+                );
+
+                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return None; }
+
+                let (mut array_index, last_pattern_index): (usize, usize) = (0_usize, pattern_length - 1_usize);
+
+                unsafe {
+                    let (lower_bound, upper_bound, case_bit): ($precision, $precision, $precision) = ($dup_one_t(0x40), $dup_one_t(0x5B), $dup_one_t(0x20));
+
+                    let (first_mask, last_mask): ($precision, $precision) = ($dup_one_t(ByteSearch::<$t>::fold_ascii(pattern[0])), $dup_one_t(ByteSearch::<$t>::fold_ascii(pattern[last_pattern_index])));
+
+                    while array_index + COUNT_OF_VALUES_IN_REGISTER <= array_length {
+                        let (first, last): ($precision, $precision) = (
+                            $load(transmute::<*const $t, *const $precision>(array.as_ptr().add(array_index))),
+                            $load(transmute::<*const $t, *const $precision>(array.as_ptr().add(array_index + last_pattern_index)))
+                        );
+
+                        let mut candidates: $mask = $eq_compare($bitwise_or(first, $mask_to_vector($greater_than(first, lower_bound) & $less_than(first, upper_bound), case_bit)), first_mask)
+                            & $eq_compare($bitwise_or(last, $mask_to_vector($greater_than(last, lower_bound) & $less_than(last, upper_bound), case_bit)), last_mask);
+
+                        while candidates != 0 {
+                            let index_of_match: usize = candidates.trailing_zeros() as usize;
+
+                            if ByteSearch::<$t>::is_folded_match(array, array_index + index_of_match, pattern) { return Some((array_index + index_of_match) * $t_size); }
+
+                            candidates &= candidates - 1;
+                        }
+
+                        array_index += COUNT_OF_VALUES_IN_REGISTER;
+                    }
+                }
+
+                while array_index < array_length {
+                    if ByteSearch::<$t>::is_folded_match(array, array_index, pattern) { return Some(array_index * $t_size); }
+
+                    array_index += 1_usize;
+                }
+
+                return None;
             }
 
-            pub fn search_all(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize>
+            #[target_feature(enable = $feature)]
+            #[allow(unused_unsafe)]
+            unsafe fn $search_all(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize>
             where $t: Copy + Sized {
 
                 const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
@@ -667,13 +789,13 @@ macro_rules! generate_search {
 
                 let (mut matches, mask_to_vector_mask, and_not_mask, zero): ([[$t; COUNT_OF_VALUES_IN_REGISTER]; 4_usize], $precision, $precision, $mask) = ([[$t_default; COUNT_OF_VALUES_IN_REGISTER]; 4_usize], unsafe { $dup_one_t($not_ignore_mask) }, unsafe { $dup_one_t($not_ignore_mask) }, 0);
 
-                let (load_match_ptr, write_match_ptr): ([*const i32; 4_usize], [*mut $precision; 4_usize]) = unsafe {
+                let (load_match_ptr, write_match_ptr): ([*const $precision; 4_usize], [*mut $precision; 4_usize]) = unsafe {
                     (
                         [
-                            transmute::<*const $t, *const i32>(matches[0].as_ptr()),
-                            transmute::<*const $t, *const i32>(matches[1].as_ptr()),
-                            transmute::<*const $t, *const i32>(matches[2].as_ptr()),
-                            transmute::<*const $t, *const i32>(matches[3].as_ptr())
+                            transmute::<*const $t, *const $precision>(matches[0].as_ptr()),
+                            transmute::<*const $t, *const $precision>(matches[1].as_ptr()),
+                            transmute::<*const $t, *const $precision>(matches[2].as_ptr()),
+                            transmute::<*const $t, *const $precision>(matches[3].as_ptr())
                         ],
                         [
                             transmute::<*mut $t, *mut $precision>(matches[0].as_mut_ptr()),
@@ -692,10 +814,10 @@ macro_rules! generate_search {
                         if remains_length >= PRECISION_LENGTH[3] {
                             while remains_length >= PRECISION_LENGTH[0] {
                                 let (first, second, third, four): ($precision, $precision, $precision, $precision) = {
-                                    let first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let four: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let four: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
                                     (first, second, third, four)
                                 };
@@ -721,9 +843,9 @@ macro_rules! generate_search {
 
                             if remains_length >= PRECISION_LENGTH[1] {
                                 let (first, second, third): ($precision, $precision, $precision) = {
-                                    let first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
                                     (first, second, third)
                                 };
@@ -748,8 +870,8 @@ macro_rules! generate_search {
 
                             if remains_length >= PRECISION_LENGTH[2] {
                                 let (first, second): ($precision, $precision) = {
-                                    let first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
                                     (first, second)
                                 };
@@ -772,7 +894,7 @@ macro_rules! generate_search {
                             }
 
                             if remains_length >= PRECISION_LENGTH[3] {
-                                let first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
+                                let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
 
                                 $store(write_match_ptr[0], $mask_to_vector($eq_compare(first, pattern_mask), mask_to_vector_mask));
 
@@ -796,7 +918,7 @@ macro_rules! generate_search {
                                 indivisible_part
                             };
 
-                            let first: $precision = $load(transmute::<*const $t, *const i32>(aligned_array.as_ptr()));
+                            let first: $precision = $load(transmute::<*const $t, *const $precision>(aligned_array.as_ptr()));
 
                             $store(write_match_ptr[0], $mask_to_vector($eq_compare(first, pattern_mask), mask_to_vector_mask));
 
@@ -814,20 +936,20 @@ macro_rules! generate_search {
                         if remains_length >= PRECISION_LENGTH[3] + 1_usize {
                             while remains_length >= PRECISION_LENGTH[0] + 1_usize {
                                 let (s_first, e_first, s_second, e_second, s_third, e_third, s_four, e_four): ($precision, $precision, $precision, $precision, $precision, $precision, $precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
-                                    let s_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
-                                    let s_third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
-                                    let s_four: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_four: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_four: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_four: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
                                     (s_first, e_first, s_second, e_second, s_third, e_third, s_four, e_four)
@@ -854,16 +976,16 @@ macro_rules! generate_search {
 
                             if remains_length >= PRECISION_LENGTH[1] + 1_usize {
                                 let (s_first, e_first, s_second, e_second, s_third, e_third): ($precision, $precision, $precision, $precision, $precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
-                                    let s_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
-                                    let s_third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
                                     (s_first, e_first, s_second, e_second, s_third, e_third)
@@ -889,12 +1011,12 @@ macro_rules! generate_search {
 
                             if remains_length >= PRECISION_LENGTH[2] + 1_usize {
                                 let (s_first, e_first, s_second, e_second): ($precision, $precision, $precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
-                                    let s_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
                                     (s_first, e_first, s_second, e_second)
@@ -919,8 +1041,8 @@ macro_rules! generate_search {
 
                             if remains_length >= PRECISION_LENGTH[3] + 1_usize {
                                 let (s_first, e_first): ($precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                    let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                    let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
                                     array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
                                     (s_first, e_first)
@@ -949,8 +1071,8 @@ macro_rules! generate_search {
                             };
 
                             let (s_first, e_first): ($precision, $precision) = {
-                                let s_first: $precision = $load(transmute::<*const $t, *const i32>(aligned_array.as_ptr()));
-                                let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
+                                let s_first: $precision = $load(transmute::<*const $t, *const $precision>(aligned_array.as_ptr()));
+                                let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
 
                                 (s_first, e_first)
                             };
@@ -977,8 +1099,8 @@ macro_rules! generate_search {
                             if pattern_length > COUNT_OF_VALUES_IN_REGISTER {
                                 while array_index < last_array_index_multiple_of_register {
                                     let (s_first, e_first): ($precision, $precision) = {
-                                        let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                        let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(last_pattern_index)));
+                                        let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                        let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(last_pattern_index)));
 
                                         (s_first, e_first)
                                     };
@@ -993,8 +1115,8 @@ macro_rules! generate_search {
 
                                                 for i in 0_usize..middle_pattern_parts - 1_usize {
                                                     if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                        $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match + 1_usize + i * COUNT_OF_VALUES_IN_REGISTER))),
-                                                        $load(transmute::<*const $t, *const i32>(pattern.as_ptr().add(1_usize + i * COUNT_OF_VALUES_IN_REGISTER)))
+                                                        $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + 1_usize + i * COUNT_OF_VALUES_IN_REGISTER))),
+                                                        $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(1_usize + i * COUNT_OF_VALUES_IN_REGISTER)))
                                                     ), mask_to_vector_mask), and_not_mask)) != 0 {
                                                         result &= false; break
                                                     }
@@ -1002,8 +1124,8 @@ macro_rules! generate_search {
 
                                                 if result {
                                                     if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                        $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
-                                                        $load(transmute::<*const $t, *const i32>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
+                                                        $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
+                                                        $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
                                                     ), mask_to_vector_mask), and_not_mask)) == 0 {
                                                         let index: usize = array_index + index_of_match;
                                                         search_result.push(index * $t_size); next_index_of_match = (index + pattern_length) % COUNT_OF_VALUES_IN_REGISTER; break;
@@ -1016,12 +1138,12 @@ macro_rules! generate_search {
                                     index_of_match = 0_usize; array_index += next_area; array_ptr = array_ptr.add(next_area);
                                 }
                             } else if pattern_length == COUNT_OF_VALUES_IN_REGISTER {
-                                let pattern_loaded: $precision = $load(transmute::<*const $t, *const i32>(pattern.as_ptr()));
+                                let pattern_loaded: $precision = $load(transmute::<*const $t, *const $precision>(pattern.as_ptr()));
 
                                 while array_index < last_array_index_multiple_of_register {
                                     let (s_first, e_first): ($precision, $precision) = {
-                                        let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                        let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(last_pattern_index)));
+                                        let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                        let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(last_pattern_index)));
 
                                         (s_first, e_first)
                                     };
@@ -1033,7 +1155,7 @@ macro_rules! generate_search {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                             if matches[0][index_of_match] != 0 {
                                                 if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                    $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match))),
+                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
                                                     pattern_loaded
                                                 ), mask_to_vector_mask), and_not_mask)) == 0 {
                                                     let index: usize = array_index + index_of_match;
@@ -1049,13 +1171,13 @@ macro_rules! generate_search {
                                 let (pattern_loaded, pattern_ignore_mask): ($precision, $precision) = {
                                     let (mut pattern_array, mut ignore_mask): ([$t; COUNT_OF_VALUES_IN_REGISTER], [$t; COUNT_OF_VALUES_IN_REGISTER]) = ([$t_default; COUNT_OF_VALUES_IN_REGISTER], [$t_default; COUNT_OF_VALUES_IN_REGISTER]);
                                     for i in 0_usize..pattern_length { pattern_array[i] = pattern[i]; ignore_mask[i] = $not_ignore_mask; }
-                                    ($load(transmute::<*const $t, *const i32>(pattern_array.as_ptr())), $load(transmute::<*const $t, *const i32>(ignore_mask.as_ptr())))
+                                    ($load(transmute::<*const $t, *const $precision>(pattern_array.as_ptr())), $load(transmute::<*const $t, *const $precision>(ignore_mask.as_ptr())))
                                 };
 
                                 while array_index < last_array_index_multiple_of_register {
                                     let (s_first, e_first): ($precision, $precision) = {
-                                        let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                        let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(last_pattern_index)));
+                                        let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
+                                        let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(last_pattern_index)));
 
                                         (s_first, e_first)
                                     };
@@ -1067,7 +1189,7 @@ macro_rules! generate_search {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                             if matches[0][index_of_match] != 0 {
                                                 if $vector_to_scalar($bitwise_and($bitwise_not_and($mask_to_vector($eq_compare(
-                                                    $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match))),
+                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
                                                     pattern_loaded
                                                 ), mask_to_vector_mask), and_not_mask), pattern_ignore_mask)) == 0 {
                                                     let index: usize = array_index + index_of_match;
@@ -1106,8 +1228,8 @@ macro_rules! generate_search {
 
                                             for i in 0_usize..middle_pattern_parts - 1_usize {
                                                 if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                    $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match + 1_usize + i * COUNT_OF_VALUES_IN_REGISTER))),
-                                                    $load(transmute::<*const $t, *const i32>(pattern.as_ptr().add(1_usize + i * COUNT_OF_VALUES_IN_REGISTER)))
+                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + 1_usize + i * COUNT_OF_VALUES_IN_REGISTER))),
+                                                    $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(1_usize + i * COUNT_OF_VALUES_IN_REGISTER)))
                                                 ), mask_to_vector_mask), and_not_mask)) != 0 {
                                                     result &= false; break
                                                 }
@@ -1115,8 +1237,8 @@ macro_rules! generate_search {
 
                                             if result {
                                                 if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                    $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
-                                                    $load(transmute::<*const $t, *const i32>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
+                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
+                                                    $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
                                                 ), mask_to_vector_mask), and_not_mask)) == 0 {
                                                     search_result.push((array_index + index_of_match) * $t_size); break;
                                                 }
@@ -1126,14 +1248,14 @@ macro_rules! generate_search {
                                     }
                                 }
                             } else if pattern_length == COUNT_OF_VALUES_IN_REGISTER {
-                                let pattern_loaded: $precision = $load(transmute::<*const $t, *const i32>(pattern.as_ptr()));
+                                let pattern_loaded: $precision = $load(transmute::<*const $t, *const $precision>(pattern.as_ptr()));
 
                                 if next_index_of_match != 0_usize { index_of_match += next_index_of_match; }
                                 if $vector_to_scalar($load(load_match_ptr[0])) != zero {
                                     while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                         if matches[0][index_of_match] != 0 {
                                             if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match))),
+                                                $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
                                                 pattern_loaded
                                             ), mask_to_vector_mask), and_not_mask)) == 0 {
                                                 search_result.push((array_index + index_of_match) * $t_size); break;
@@ -1146,7 +1268,7 @@ macro_rules! generate_search {
                                 let (pattern_loaded, pattern_ignore_mask): ($precision, $precision) = {
                                     let (mut pattern_array, mut ignore_mask): ([$t; COUNT_OF_VALUES_IN_REGISTER], [$t; COUNT_OF_VALUES_IN_REGISTER]) = ([$t_default; COUNT_OF_VALUES_IN_REGISTER], [$t_default; COUNT_OF_VALUES_IN_REGISTER]);
                                     for i in 0_usize..pattern_length { pattern_array[i] = pattern[i]; ignore_mask[i] = $not_ignore_mask; }
-                                    ($load(transmute::<*const $t, *const i32>(pattern_array.as_ptr())), $load(transmute::<*const $t, *const i32>(ignore_mask.as_ptr())))
+                                    ($load(transmute::<*const $t, *const $precision>(pattern_array.as_ptr())), $load(transmute::<*const $t, *const $precision>(ignore_mask.as_ptr())))
                                 };
 
                                 if next_index_of_match != 0_usize { index_of_match += next_index_of_match; }
@@ -1154,7 +1276,7 @@ macro_rules! generate_search {
                                     while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                         if matches[0][index_of_match] != 0 {
                                             if $vector_to_scalar($bitwise_and($bitwise_not_and($mask_to_vector($eq_compare(
-                                                $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match))),
+                                                $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
                                                 pattern_loaded
                                             ), mask_to_vector_mask), and_not_mask), pattern_ignore_mask)) == 0 {
                                                 search_result.push((array_index + index_of_match) * $t_size); index_of_match += pattern_length;
@@ -1170,7 +1292,9 @@ macro_rules! generate_search {
                 return search_result;
             }
 
-            pub fn search_all_overlapping(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize>
+            #[target_feature(enable = $feature)]
+            #[allow(unused_unsafe)]
+            unsafe fn $search_all_overlapping(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize>
             where $t: Copy + Sized {
 
                 const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
@@ -1194,13 +1318,13 @@ macro_rules! generate_search {
 
                 let (mut matches, mask_to_vector_mask, and_not_mask, zero): ([[$t; COUNT_OF_VALUES_IN_REGISTER]; 4_usize], $precision, $precision, $mask) = ([[$t_default; COUNT_OF_VALUES_IN_REGISTER]; 4_usize], unsafe { $dup_one_t($not_ignore_mask) }, unsafe { $dup_one_t($not_ignore_mask) }, 0);
 
-                let (load_match_ptr, write_match_ptr): ([*const i32; 4_usize], [*mut $precision; 4_usize]) = unsafe {
+                let (load_match_ptr, write_match_ptr): ([*const $precision; 4_usize], [*mut $precision; 4_usize]) = unsafe {
                     (
                         [
-                            transmute::<*const $t, *const i32>(matches[0].as_ptr()),
-                            transmute::<*const $t, *const i32>(matches[1].as_ptr()),
-                            transmute::<*const $t, *const i32>(matches[2].as_ptr()),
-                            transmute::<*const $t, *const i32>(matches[3].as_ptr())
+                            transmute::<*const $t, *const $precision>(matches[0].as_ptr()),
+                            transmute::<*const $t, *const $precision>(matches[1].as_ptr()),
+                            transmute::<*const $t, *const $precision>(matches[2].as_ptr()),
+                            transmute::<*const $t, *const $precision>(matches[3].as_ptr())
                         ],
                         [
                             transmute::<*mut $t, *mut $precision>(matches[0].as_mut_ptr()),
@@ -1219,1613 +1343,21 @@ macro_rules! generate_search {
                         if remains_length >= PRECISION_LENGTH[3] {
                             while remains_length >= PRECISION_LENGTH[0] {
                                 let (first, second, third, four): ($precision, $precision, $precision, $precision) = {
-                                    let first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let four: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
+                                    let four: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
 
                                     (first, second, third, four)
                                 };
 
                                 $store(write_match_ptr[0], $mask_to_vector($eq_compare(first, pattern_mask), mask_to_vector_mask));
                                 $store(write_match_ptr[1], $mask_to_vector($eq_compare(second, pattern_mask), mask_to_vector_mask));
-                                $store(write_match_ptr[2], $mask_to_vector($eq_compare(third, pattern_mask), mask_to_vector_mask));
-                                $store(write_match_ptr[3], $mask_to_vector($eq_compare(four, pattern_mask), mask_to_vector_mask));
-
-                                for i in 0..4 {
-                                    if $vector_to_scalar($load(load_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[0];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[1] {
-                                let (first, second, third): ($precision, $precision, $precision) = {
-                                    let first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (first, second, third)
-                                };
-
-                                $store(write_match_ptr[0], $mask_to_vector($eq_compare(first, pattern_mask), mask_to_vector_mask));
-                                $store(write_match_ptr[1], $mask_to_vector($eq_compare(second, pattern_mask), mask_to_vector_mask));
-                                $store(write_match_ptr[2], $mask_to_vector($eq_compare(third, pattern_mask), mask_to_vector_mask));
-
-                                for i in 0..3 {
-                                    if $vector_to_scalar($load(load_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[1];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[2] {
-                                let (first, second): ($precision, $precision) = {
-                                    let first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (first, second)
-                                };
-
-                                $store(write_match_ptr[0], $mask_to_vector($eq_compare(first, pattern_mask), mask_to_vector_mask));
-                                $store(write_match_ptr[1], $mask_to_vector($eq_compare(second, pattern_mask), mask_to_vector_mask));
-
-                                for i in 0..2 {
-                                    if $vector_to_scalar($load(load_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[2];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[3] {
-                                let first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-
-                                $store(write_match_ptr[0], $mask_to_vector($eq_compare(first, pattern_mask), mask_to_vector_mask));
-
-                                if $vector_to_scalar($load(load_match_ptr[0])) != zero {
-                                    while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
-                                        else { index_of_match += 1_usize; }
-                                    }
-                                    index_of_match = 0_usize;
-                                }
-                                array_index += COUNT_OF_VALUES_IN_REGISTER;
-
-                                remains_length -= PRECISION_LENGTH[3];
-                            }
-                        }
-
-                        if remains_length > 0_usize {
-                            let aligned_array: [$t; COUNT_OF_VALUES_IN_REGISTER] = {
-                                let mut indivisible_part: [$t; COUNT_OF_VALUES_IN_REGISTER] = [$t_default; COUNT_OF_VALUES_IN_REGISTER];
-                                for i in 0..remains_length { indivisible_part[i] = array[array_index + i]; }
-                                indivisible_part
-                            };
-
-                            let first: $precision = $load(transmute::<*const $t, *const i32>(aligned_array.as_ptr()));
-
-                            $store(write_match_ptr[0], $mask_to_vector($eq_compare(first, pattern_mask), mask_to_vector_mask));
-
-                            if $vector_to_scalar($load(load_match_ptr[0])) != zero {
-                                while index_of_match < remains_length {
-                                    if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
-                                    else { index_of_match += 1_usize; }
-                                }
-                            }
-                        }
-                    } else if pattern_length == 2_usize {
-
-                        let (start_pattern_mask, end_pattern_mask): ($precision, $precision) = ($dup_one_t(pattern[0]), $dup_one_t(pattern[1]));
-
-                        if remains_length >= PRECISION_LENGTH[3] + 1_usize {
-                            while remains_length >= PRECISION_LENGTH[0] + 1_usize {
-                                let (s_first, e_first, s_second, e_second, s_third, e_third, s_four, e_four): ($precision, $precision, $precision, $precision, $precision, $precision, $precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_four: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_four: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (s_first, e_first, s_second, e_second, s_third, e_third, s_four, e_four)
-                                };
-
-                                $store(write_match_ptr[0], $bitwise_and($mask_to_vector($eq_compare(s_first, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_first, end_pattern_mask), mask_to_vector_mask)));
-                                $store(write_match_ptr[1], $bitwise_and($mask_to_vector($eq_compare(s_second, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_second, end_pattern_mask), mask_to_vector_mask)));
-                                $store(write_match_ptr[2], $bitwise_and($mask_to_vector($eq_compare(s_third, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_third, end_pattern_mask), mask_to_vector_mask)));
-                                $store(write_match_ptr[3], $bitwise_and($mask_to_vector($eq_compare(s_four, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_four, end_pattern_mask), mask_to_vector_mask)));
-
-                                for i in 0..4 {
-                                    if $vector_to_scalar($load(load_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[0];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[1] + 1_usize {
-                                let (s_first, e_first, s_second, e_second, s_third, e_third): ($precision, $precision, $precision, $precision, $precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_third: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (s_first, e_first, s_second, e_second, s_third, e_third)
-                                };
-
-                                $store(write_match_ptr[0], $bitwise_and($mask_to_vector($eq_compare(s_first, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_first, end_pattern_mask), mask_to_vector_mask)));
-                                $store(write_match_ptr[1], $bitwise_and($mask_to_vector($eq_compare(s_second, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_second, end_pattern_mask), mask_to_vector_mask)));
-                                $store(write_match_ptr[2], $bitwise_and($mask_to_vector($eq_compare(s_third, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_third, end_pattern_mask), mask_to_vector_mask)));
-
-                                for i in 0..3 {
-                                    if $vector_to_scalar($load(load_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[1];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[2] + 1_usize {
-                                let (s_first, e_first, s_second, e_second): ($precision, $precision, $precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_second: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (s_first, e_first, s_second, e_second)
-                                };
-
-                                $store(write_match_ptr[0], $bitwise_and($mask_to_vector($eq_compare(s_first, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_first, end_pattern_mask), mask_to_vector_mask)));
-                                $store(write_match_ptr[1], $bitwise_and($mask_to_vector($eq_compare(s_second, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_second, end_pattern_mask), mask_to_vector_mask)));
-
-                                for i in 0..2 {
-                                    if $vector_to_scalar($load(load_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[2];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[3] + 1_usize {
-                                let (s_first, e_first): ($precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (s_first, e_first)
-                                };
-
-                                $store(write_match_ptr[0], $bitwise_and($mask_to_vector($eq_compare(s_first, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_first, end_pattern_mask), mask_to_vector_mask)));
-
-                                if $vector_to_scalar($load(load_match_ptr[0])) != zero {
-                                    while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
-                                        else { index_of_match += 1_usize; }
-                                    }
-                                    index_of_match = 0_usize;
-                                }
-                                array_index += COUNT_OF_VALUES_IN_REGISTER;
-
-                                remains_length -= PRECISION_LENGTH[3];
-                            }
-                        }
-
-                        if remains_length > 0_usize {
-                            let aligned_array: [$t; COUNT_OF_VALUES_IN_REGISTER + 1_usize] = {
-                                let mut indivisible_part: [$t; COUNT_OF_VALUES_IN_REGISTER + 1_usize] = [$t_default; COUNT_OF_VALUES_IN_REGISTER + 1_usize];
-                                for i in 0..remains_length { indivisible_part[i] = array[array_index + i]; }
-                                indivisible_part
-                            };
-
-                            let (s_first, e_first): ($precision, $precision) = {
-                                let s_first: $precision = $load(transmute::<*const $t, *const i32>(aligned_array.as_ptr()));
-                                let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(1_usize)));
-
-                                (s_first, e_first)
-                            };
-
-                            $store(write_match_ptr[0], $bitwise_and($mask_to_vector($eq_compare(s_first, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_first, end_pattern_mask), mask_to_vector_mask)));
-
-                            if $vector_to_scalar($load(load_match_ptr[0])) != zero {
-                                while index_of_match < remains_length {
-                                    if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
-                                    else { index_of_match += 1_usize; }
-                                }
-                            }
-                        }
-                    } else if pattern_length >= 3_usize {
-                        let (last_pattern_index, middle_pattern_length): (usize, usize) = (pattern_length - 1_usize, pattern_length - 2_usize);
-                        let middle_pattern_parts: usize = (middle_pattern_length + (COUNT_OF_VALUES_IN_REGISTER - 1_usize)) / COUNT_OF_VALUES_IN_REGISTER;
-                        let (start_pattern_mask, end_pattern_mask): ($precision, $precision) = ($dup_one_t(pattern[0]), $dup_one_t(pattern[last_pattern_index]));
-                        let last_array_index_multiple_of_register: usize = array_length - (array_length % COUNT_OF_VALUES_IN_REGISTER);
-
-                        if last_array_index_multiple_of_register != 0_usize {
-                            if pattern_length > COUNT_OF_VALUES_IN_REGISTER {
-                                while array_index < last_array_index_multiple_of_register {
-                                    let (s_first, e_first): ($precision, $precision) = {
-                                        let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                        let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(last_pattern_index)));
-
-                                        (s_first, e_first)
-                                    };
-
-                                    $store(write_match_ptr[0], $bitwise_and($mask_to_vector($eq_compare(s_first, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_first, end_pattern_mask), mask_to_vector_mask)));
-
-                                    if $vector_to_scalar($load(load_match_ptr[0])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[0][index_of_match] != 0 {
-                                                let mut result: bool = true;
-
-                                                for i in 0_usize..middle_pattern_parts - 1_usize {
-                                                    if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                        $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match + 1_usize + i * COUNT_OF_VALUES_IN_REGISTER))),
-                                                        $load(transmute::<*const $t, *const i32>(pattern.as_ptr().add(1_usize + i * COUNT_OF_VALUES_IN_REGISTER)))
-                                                    ), mask_to_vector_mask), and_not_mask)) != 0 {
-                                                        result &= false; break
-                                                    }
-                                                }
-
-                                                if result {
-                                                    if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                        $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
-                                                        $load(transmute::<*const $t, *const i32>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
-                                                    ), mask_to_vector_mask), and_not_mask)) == 0 {
-                                                        search_result.push((array_index + index_of_match) * $t_size);
-                                                    }
-                                                }
-                                            }
-                                            index_of_match += 1_usize;
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER; array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                }
-                            } else if pattern_length == COUNT_OF_VALUES_IN_REGISTER {
-                                let pattern_loaded: $precision = $load(transmute::<*const $t, *const i32>(pattern.as_ptr()));
-
-                                while array_index < last_array_index_multiple_of_register {
-                                    let (s_first, e_first): ($precision, $precision) = {
-                                        let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                        let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(last_pattern_index)));
-
-                                        (s_first, e_first)
-                                    };
-
-                                    $store(write_match_ptr[0], $bitwise_and($mask_to_vector($eq_compare(s_first, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_first, end_pattern_mask), mask_to_vector_mask)));
-
-                                    if $vector_to_scalar($load(load_match_ptr[0])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[0][index_of_match] != 0 {
-                                                if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                    $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match))),
-                                                    pattern_loaded
-                                                ), mask_to_vector_mask), and_not_mask)) == 0 {
-                                                    search_result.push((array_index + index_of_match) * $t_size);
-                                                }
-                                            }
-                                            index_of_match += 1_usize;
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER; array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                }
-                            } else if pattern_length < COUNT_OF_VALUES_IN_REGISTER {
-                                let (pattern_loaded, pattern_ignore_mask): ($precision, $precision) = {
-                                    let (mut pattern_array, mut ignore_mask): ([$t; COUNT_OF_VALUES_IN_REGISTER], [$t; COUNT_OF_VALUES_IN_REGISTER]) = ([$t_default; COUNT_OF_VALUES_IN_REGISTER], [$t_default; COUNT_OF_VALUES_IN_REGISTER]);
-                                    for i in 0_usize..pattern_length { pattern_array[i] = pattern[i]; ignore_mask[i] = $not_ignore_mask; }
-                                    ($load(transmute::<*const $t, *const i32>(pattern_array.as_ptr())), $load(transmute::<*const $t, *const i32>(ignore_mask.as_ptr())))
-                                };
-
-                                while array_index < last_array_index_multiple_of_register {
-                                    let (s_first, e_first): ($precision, $precision) = {
-                                        let s_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr));
-                                        let e_first: $precision = $load(transmute::<*const $t, *const i32>(array_ptr.add(last_pattern_index)));
-
-                                        (s_first, e_first)
-                                    };
-
-                                    $store(write_match_ptr[0], $bitwise_and($mask_to_vector($eq_compare(s_first, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_first, end_pattern_mask), mask_to_vector_mask)));
-
-                                    if $vector_to_scalar($load(load_match_ptr[0])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[0][index_of_match] != 0 {
-                                                if $vector_to_scalar($bitwise_and($bitwise_not_and($mask_to_vector($eq_compare(
-                                                    $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match))),
-                                                    pattern_loaded
-                                                ), mask_to_vector_mask), and_not_mask), pattern_ignore_mask)) == 0 {
-                                                    search_result.push((array_index + index_of_match) * $t_size);
-                                                }
-                                            }
-                                            index_of_match += 1_usize;
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER; array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                }
-                            }
-                        }
-
-                        if array_index <= array_length {
-                            matches[0] = core::mem::zeroed::<[$t; COUNT_OF_VALUES_IN_REGISTER]>();
-
-                            for i in 0..(array_length - array_index) {
-                                if array[array_index + i] == pattern[0] {
-                                    if array[array_index + i + last_pattern_index] == pattern[last_pattern_index] { matches[0][i] = $not_ignore_mask; }
-                                    else { matches[0][i] = 0; }
-                                } else {
-                                    matches[0][i] = 0;
-                                }
-                            }
-
-                            if pattern_length > COUNT_OF_VALUES_IN_REGISTER {
-                                if $vector_to_scalar($load(load_match_ptr[0])) != zero {
-                                    while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 {
-                                            let mut result: bool = true;
-
-                                            for i in 0_usize..middle_pattern_parts - 1_usize {
-                                                if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                    $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match + 1_usize + i * COUNT_OF_VALUES_IN_REGISTER))),
-                                                    $load(transmute::<*const $t, *const i32>(pattern.as_ptr().add(1_usize + i * COUNT_OF_VALUES_IN_REGISTER)))
-                                                ), mask_to_vector_mask), and_not_mask)) != 0 {
-                                                    result &= false; break
-                                                }
-                                            }
-
-                                            if result {
-                                                if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                    $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
-                                                    $load(transmute::<*const $t, *const i32>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
-                                                ), mask_to_vector_mask), and_not_mask)) == 0 {
-                                                    search_result.push((array_index + index_of_match) * $t_size);
-                                                }
-                                            }
-                                        }
-                                        index_of_match += 1_usize;
-                                    }
-                                }
-                            } else if pattern_length == COUNT_OF_VALUES_IN_REGISTER {
-                                let pattern_loaded: $precision = $load(transmute::<*const $t, *const i32>(pattern.as_ptr()));
-
-                                if $vector_to_scalar($load(load_match_ptr[0])) != zero {
-                                    while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 {
-                                            if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
-                                                $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match))),
-                                                pattern_loaded
-                                            ), mask_to_vector_mask), and_not_mask)) == 0 {
-                                                search_result.push((array_index + index_of_match) * $t_size);
-                                            }
-                                        }
-                                        index_of_match += 1_usize;
-                                    }
-                                }
-                            } else if pattern_length < COUNT_OF_VALUES_IN_REGISTER {
-                                let (pattern_loaded, pattern_ignore_mask): ($precision, $precision) = {
-                                    let (mut pattern_array, mut ignore_mask): ([$t; COUNT_OF_VALUES_IN_REGISTER], [$t; COUNT_OF_VALUES_IN_REGISTER]) = ([$t_default; COUNT_OF_VALUES_IN_REGISTER], [$t_default; COUNT_OF_VALUES_IN_REGISTER]);
-                                    for i in 0_usize..pattern_length { pattern_array[i] = pattern[i]; ignore_mask[i] = $not_ignore_mask; }
-                                    ($load(transmute::<*const $t, *const i32>(pattern_array.as_ptr())), $load(transmute::<*const $t, *const i32>(ignore_mask.as_ptr())))
-                                };
-
-                                if $vector_to_scalar($load(load_match_ptr[0])) != zero {
-                                    while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 {
-                                            if $vector_to_scalar($bitwise_and($bitwise_not_and($mask_to_vector($eq_compare(
-                                                $load(transmute::<*const $t, *const i32>(array_ptr.add(index_of_match))),
-                                                pattern_loaded
-                                            ), mask_to_vector_mask), and_not_mask), pattern_ignore_mask)) == 0 {
-                                                search_result.push((array_index + index_of_match) * $t_size);
-                                            }
-                                        }
-                                        index_of_match += 1_usize;
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-
-                return search_result;
-            }
-        }
-    };
-}
-
-#[cfg(all(target_feature = "avx", target_feature = "avx2", not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-macro_rules! generate_search {
-    ($t:ty, $t_default:expr, $not_ignore_mask:expr, $t_size:expr, $precision:ty, $register_size:expr, $load:expr, $store:expr, $dup_one_t:expr, $eq_compare:expr, $bitwise_and:expr, $bitwise_not_and:expr, $vector_to_scalar:expr) => {
-        impl ByteSearch<$t> {
-
-            const fn is_search_possible(array: &[$t], pattern: &[$t], limit: Option<usize>) -> (usize, usize, bool) {
-                return match limit {
-                    Some(limit) => {
-                        let (mut array_length, pattern_length): (usize, usize) = (array.len(), pattern.len());
-
-                        if (pattern_length == 0_usize) || (array_length == 0_usize) || (pattern_length > array_length) {
-                            return (0_usize, 0_usize, false);
-                        } else if limit > 0_usize {
-                            if limit >= array_length { (array_length - (pattern_length - 1_usize), pattern_length, true) }
-                            else {
-                                array_length -= limit;
-
-                                if pattern_length > array_length { return (0_usize, 0_usize, false); }
-                                else { (array_length - (pattern_length - 1_usize), pattern_length, true) }
-                            }
-                        } else { return (0_usize, 0_usize, false); }
-                    }
-                    None => {
-                        let (array_length, pattern_length): (usize, usize) = (array.len(), pattern.len());
-
-                        if (pattern_length == 0_usize) || (array_length == 0_usize) || (pattern_length > array_length) {
-                            return (0_usize, 0_usize, false);
-                        }
-
-                        (array_length - (pattern_length - 1_usize), pattern_length, true)
-                    }
-                };
-            }
-
-            pub fn search_single(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize>
-            where $t: Copy + Sized {
-
-                const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
-                const PRECISION_LENGTH: [usize; 4_usize] = [
-                    (4_usize * $register_size) / $t_size,
-                    (3_usize * $register_size) / $t_size,
-                    (2_usize * $register_size) / $t_size,
-                    (1_usize * $register_size) / $t_size
-                ];
-
-                let mut search_result: Vec<usize> = Vec::<usize>::new();
-
-                let (array, pattern): (&[$t], &[$t]) = (
-                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(array_ptr.as_ptr()), array_ptr.len() / $t_size) },
-                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(pattern_ptr.as_ptr()), pattern_ptr.len() / $t_size) }
-                );
-
-                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return search_result; }
-
-                let (mut array_ptr, mut array_index, mut remains_length, mut index_of_match): (*const $t, usize, usize, usize) = (array.as_ptr(), 0_usize, array_length, 0_usize);
-
-                let (mut matches, and_not_mask, zero): ([[$t; COUNT_OF_VALUES_IN_REGISTER]; 4_usize], $precision, i32) = ([[$t_default; COUNT_OF_VALUES_IN_REGISTER]; 4_usize], unsafe { $dup_one_t($not_ignore_mask) }, 0_i32);
-
-                let (read_match_ptr, write_match_ptr): ([*const $precision; 4_usize], [*mut $precision; 4_usize]) = unsafe {
-                    (
-                        [
-                            transmute::<*const $t, *const $precision>(matches[0].as_ptr()),
-                            transmute::<*const $t, *const $precision>(matches[1].as_ptr()),
-                            transmute::<*const $t, *const $precision>(matches[2].as_ptr()),
-                            transmute::<*const $t, *const $precision>(matches[3].as_ptr())
-                        ],
-                        [
-                            transmute::<*mut $t, *mut $precision>(matches[0].as_mut_ptr()),
-                            transmute::<*mut $t, *mut $precision>(matches[1].as_mut_ptr()),
-                            transmute::<*mut $t, *mut $precision>(matches[2].as_mut_ptr()),
-                            transmute::<*mut $t, *mut $precision>(matches[3].as_mut_ptr())
-                        ]
-                    )
-                };
-
-                unsafe {
-                    if pattern_length == 1_usize {
-
-                        let pattern_mask: $precision = $dup_one_t(pattern[0]);
-
-                        if remains_length >= PRECISION_LENGTH[3] {
-                            while remains_length >= PRECISION_LENGTH[0] {
-                                let (first, second, third, four): ($precision, $precision, $precision, $precision) = {
-                                    let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let four: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (first, second, third, four)
-                                };
-
-                                $store(write_match_ptr[0], $eq_compare(first, pattern_mask));
-                                $store(write_match_ptr[1], $eq_compare(second, pattern_mask));
-                                $store(write_match_ptr[2], $eq_compare(third, pattern_mask));
-                                $store(write_match_ptr[3], $eq_compare(four, pattern_mask));
-
-                                for i in 0..4 {
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[0];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[1] {
-                                let (first, second, third): ($precision, $precision, $precision) = {
-                                    let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (first, second, third)
-                                };
-
-                                $store(write_match_ptr[0], $eq_compare(first, pattern_mask));
-                                $store(write_match_ptr[1], $eq_compare(second, pattern_mask));
-                                $store(write_match_ptr[2], $eq_compare(third, pattern_mask));
-
-                                for i in 0..3 {
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[1];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[2] {
-                                let (first, second): ($precision, $precision) = {
-                                    let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (first, second)
-                                };
-
-                                $store(write_match_ptr[0], $eq_compare(first, pattern_mask));
-                                $store(write_match_ptr[1], $eq_compare(second, pattern_mask));
-
-                                for i in 0..2 {
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[2];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[3] {
-                                let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-
-                                $store(write_match_ptr[0], $eq_compare(first, pattern_mask));
-
-                                if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                    while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
-                                        else { index_of_match += 1_usize; }
-                                    }
-                                    index_of_match = 0_usize;
-                                }
-                                array_index += COUNT_OF_VALUES_IN_REGISTER;
-
-                                remains_length -= PRECISION_LENGTH[3];
-                            }
-                        }
-
-                        if remains_length > 0_usize {
-                            let aligned_array: [$t; COUNT_OF_VALUES_IN_REGISTER] = {
-                                let mut indivisible_part: [$t; COUNT_OF_VALUES_IN_REGISTER] = [$t_default; COUNT_OF_VALUES_IN_REGISTER];
-                                for i in 0..remains_length { indivisible_part[i] = array[array_index + i]; }
-                                indivisible_part
-                            };
-
-                            let first: $precision = $load(transmute::<*const $t, *const $precision>(aligned_array.as_ptr()));
-
-                            $store(write_match_ptr[0], $eq_compare(first, pattern_mask));
-
-                            if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                while index_of_match < remains_length {
-                                    if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
-                                    else { index_of_match += 1_usize; }
-                                }
-                            }
-                        }
-                    } else if pattern_length == 2_usize {
-
-                        let (start_pattern_mask, end_pattern_mask): ($precision, $precision) = ($dup_one_t(pattern[0]), $dup_one_t(pattern[1]));
-
-                        if remains_length >= PRECISION_LENGTH[3] + 1_usize {
-                            while remains_length >= PRECISION_LENGTH[0] + 1_usize {
-                                let (s_first, e_first, s_second, e_second, s_third, e_third, s_four, e_four): ($precision, $precision, $precision, $precision, $precision, $precision, $precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_four: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_four: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (s_first, e_first, s_second, e_second, s_third, e_third, s_four, e_four)
-                                };
-
-                                $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-                                $store(write_match_ptr[1], $bitwise_and($eq_compare(s_second, start_pattern_mask), $eq_compare(e_second, end_pattern_mask)));
-                                $store(write_match_ptr[2], $bitwise_and($eq_compare(s_third, start_pattern_mask), $eq_compare(e_third, end_pattern_mask)));
-                                $store(write_match_ptr[3], $bitwise_and($eq_compare(s_four, start_pattern_mask), $eq_compare(e_four, end_pattern_mask)));
-
-                                for i in 0..4 {
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[0];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[1] + 1_usize {
-                                let (s_first, e_first, s_second, e_second, s_third, e_third): ($precision, $precision, $precision, $precision, $precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (s_first, e_first, s_second, e_second, s_third, e_third)
-                                };
-
-                                $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-                                $store(write_match_ptr[1], $bitwise_and($eq_compare(s_second, start_pattern_mask), $eq_compare(e_second, end_pattern_mask)));
-                                $store(write_match_ptr[2], $bitwise_and($eq_compare(s_third, start_pattern_mask), $eq_compare(e_third, end_pattern_mask)));
-
-                                for i in 0..3 {
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[1];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[2] + 1_usize {
-                                let (s_first, e_first, s_second, e_second): ($precision, $precision, $precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (s_first, e_first, s_second, e_second)
-                                };
-
-                                $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-                                $store(write_match_ptr[1], $bitwise_and($eq_compare(s_second, start_pattern_mask), $eq_compare(e_second, end_pattern_mask)));
-
-                                for i in 0..2 {
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[2];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[3] + 1_usize {
-                                let (s_first, e_first): ($precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (s_first, e_first)
-                                };
-
-                                $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-
-                                if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                    while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
-                                        else { index_of_match += 1_usize; }
-                                    }
-                                    index_of_match = 0_usize;
-                                }
-                                array_index += COUNT_OF_VALUES_IN_REGISTER;
-
-                                remains_length -= PRECISION_LENGTH[3];
-                            }
-                        }
-
-                        if remains_length > 0_usize {
-                            let aligned_array: [$t; COUNT_OF_VALUES_IN_REGISTER + 1_usize] = {
-                                let mut indivisible_part: [$t; COUNT_OF_VALUES_IN_REGISTER + 1_usize] = [$t_default; COUNT_OF_VALUES_IN_REGISTER + 1_usize];
-                                for i in 0..remains_length { indivisible_part[i] = array[array_index + i]; }
-                                indivisible_part
-                            };
-
-                            let (s_first, e_first): ($precision, $precision) = {
-                                let s_first: $precision = $load(transmute::<*const $t, *const $precision>(aligned_array.as_ptr()));
-                                let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-
-                                (s_first, e_first)
-                            };
-
-                            $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-
-                            if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                while index_of_match < remains_length {
-                                    if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
-                                    else { index_of_match += 1_usize; }
-                                }
-                            }
-                        }
-                    } else if pattern_length >= 3_usize {
-                        let (last_pattern_index, middle_pattern_length): (usize, usize) = (pattern_length - 1_usize, pattern_length - 2_usize);
-                        let middle_pattern_parts: usize = (middle_pattern_length + (COUNT_OF_VALUES_IN_REGISTER - 1_usize)) / COUNT_OF_VALUES_IN_REGISTER;
-                        let (start_pattern_mask, end_pattern_mask): ($precision, $precision) = ($dup_one_t(pattern[0]), $dup_one_t(pattern[last_pattern_index]));
-                        let last_array_index_multiple_of_register: usize = array_length - (array_length % COUNT_OF_VALUES_IN_REGISTER);
-
-                        if last_array_index_multiple_of_register != 0_usize {
-                            if pattern_length > COUNT_OF_VALUES_IN_REGISTER {
-                                while array_index < last_array_index_multiple_of_register {
-                                    let (s_first, e_first): ($precision, $precision) = {
-                                        let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                        let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(last_pattern_index)));
-
-                                        (s_first, e_first)
-                                    };
-
-                                    $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-
-                                    if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[0][index_of_match] != 0 {
-                                                let mut result: bool = true;
-
-                                                for i in 0_usize..middle_pattern_parts - 1_usize {
-                                                    if $vector_to_scalar($bitwise_not_and($eq_compare(
-                                                        $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + 1_usize + i * COUNT_OF_VALUES_IN_REGISTER))),
-                                                        $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(1_usize + i * COUNT_OF_VALUES_IN_REGISTER)))
-                                                    ), and_not_mask)) != 0 {
-                                                        result &= false; break
-                                                    }
-                                                }
-
-                                                if result {
-                                                    if $vector_to_scalar($bitwise_not_and($eq_compare(
-                                                        $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
-                                                        $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
-                                                    ), and_not_mask)) == 0 {
-                                                        search_result.push((array_index + index_of_match) * $t_size); return search_result;
-                                                    }
-                                                }
-                                            }
-                                            index_of_match += 1_usize;
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER; array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                }
-                            } else if pattern_length == COUNT_OF_VALUES_IN_REGISTER {
-                                let pattern_loaded: $precision = $load(transmute::<*const $t, *const $precision>(pattern.as_ptr()));
-
-                                while array_index < last_array_index_multiple_of_register {
-                                    let (s_first, e_first): ($precision, $precision) = {
-                                        let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                        let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(last_pattern_index)));
-
-                                        (s_first, e_first)
-                                    };
-
-                                    $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-
-                                    if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[0][index_of_match] != 0 {
-                                                if $vector_to_scalar($bitwise_not_and($eq_compare(
-                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
-                                                    pattern_loaded
-                                                ), and_not_mask)) == 0 {
-                                                    search_result.push((array_index + index_of_match) * $t_size); return search_result;
-                                                }
-                                            }
-                                            index_of_match += 1_usize;
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER; array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                }
-                            } else if pattern_length < COUNT_OF_VALUES_IN_REGISTER {
-                                let (pattern_loaded, pattern_ignore_mask): ($precision, $precision) = {
-                                    let (mut pattern_array, mut ignore_mask): ([$t; COUNT_OF_VALUES_IN_REGISTER], [$t; COUNT_OF_VALUES_IN_REGISTER]) = ([$t_default; COUNT_OF_VALUES_IN_REGISTER], [$t_default; COUNT_OF_VALUES_IN_REGISTER]);
-                                    for i in 0_usize..pattern_length { pattern_array[i] = pattern[i]; ignore_mask[i] = $not_ignore_mask; }
-                                    ($load(transmute::<*const $t, *const $precision>(pattern_array.as_ptr())), $load(transmute::<*const $t, *const $precision>(ignore_mask.as_ptr())))
-                                };
-
-                                while array_index < last_array_index_multiple_of_register {
-                                    let (s_first, e_first): ($precision, $precision) = {
-                                        let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                        let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(last_pattern_index)));
-
-                                        (s_first, e_first)
-                                    };
-
-                                    $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-
-                                    if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[0][index_of_match] != 0 {
-                                                if $vector_to_scalar($bitwise_and($bitwise_not_and($eq_compare(
-                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))), pattern_loaded),
-                                                and_not_mask), pattern_ignore_mask)) == 0 {
-                                                    search_result.push((array_index + index_of_match) * $t_size); return search_result;
-                                                }
-                                            }
-                                            index_of_match += 1_usize;
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER; array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                }
-                            }
-                        }
-
-                        if array_index <= array_length {
-                            matches[0] = core::mem::zeroed::<[$t; COUNT_OF_VALUES_IN_REGISTER]>();
-
-                            for i in 0..(array_length - array_index) {
-                                if array[array_index + i] == pattern[0] {
-                                    if array[array_index + i + last_pattern_index] == pattern[last_pattern_index] { matches[0][i] = $not_ignore_mask; }
-                                    else { matches[0][i] = 0; }
-                                } else {
-                                    matches[0][i] = 0;
-                                }
-                            }
-
-                            if pattern_length > COUNT_OF_VALUES_IN_REGISTER {
-                                if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                    while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 {
-                                            let mut result: bool = true;
-
-                                            for i in 0_usize..middle_pattern_parts - 1_usize {
-                                                if $vector_to_scalar($bitwise_not_and($eq_compare(
-                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + 1_usize + i * COUNT_OF_VALUES_IN_REGISTER))),
-                                                    $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(1_usize + i * COUNT_OF_VALUES_IN_REGISTER)))
-                                                ), and_not_mask)) != 0 {
-                                                    result &= false; break
-                                                }
-                                            }
-
-                                            if result {
-                                                if $vector_to_scalar($bitwise_not_and($eq_compare(
-                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
-                                                    $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
-                                                ), and_not_mask)) == 0 {
-                                                    search_result.push((array_index + index_of_match) * $t_size); return search_result;
-                                                }
-                                            }
-                                        }
-                                        index_of_match += 1_usize;
-                                    }
-                                }
-                            } else if pattern_length == COUNT_OF_VALUES_IN_REGISTER {
-                                let pattern_loaded: $precision = $load(transmute::<*const $t, *const $precision>(pattern.as_ptr()));
-
-                                if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                    while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 {
-                                            if $vector_to_scalar($bitwise_not_and($eq_compare(
-                                                $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
-                                                pattern_loaded
-                                            ), and_not_mask)) == 0 {
-                                                search_result.push((array_index + index_of_match) * $t_size);
-                                            }
-                                        }
-                                        index_of_match += 1_usize;
-                                    }
-                                }
-                            } else if pattern_length < COUNT_OF_VALUES_IN_REGISTER {
-                                let (pattern_loaded, pattern_ignore_mask): ($precision, $precision) = {
-                                    let (mut pattern_array, mut ignore_mask): ([$t; COUNT_OF_VALUES_IN_REGISTER], [$t; COUNT_OF_VALUES_IN_REGISTER]) = ([$t_default; COUNT_OF_VALUES_IN_REGISTER], [$t_default; COUNT_OF_VALUES_IN_REGISTER]);
-                                    for i in 0_usize..pattern_length { pattern_array[i] = pattern[i]; ignore_mask[i] = $not_ignore_mask; }
-                                    ($load(transmute::<*const $t, *const $precision>(pattern_array.as_ptr())), $load(transmute::<*const $t, *const $precision>(ignore_mask.as_ptr())))
-                                };
-
-                                if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                    while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 {
-                                            if $vector_to_scalar($bitwise_and($bitwise_not_and($eq_compare(
-                                                $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))), pattern_loaded),
-                                            and_not_mask), pattern_ignore_mask)) == 0 {
-                                                search_result.push((array_index + index_of_match) * $t_size); return search_result;
-                                            }
-                                        }
-                                        index_of_match += 1_usize;
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-
-                return search_result;
-            }
-
-            pub fn search_all(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize>
-            where $t: Copy + Sized {
-
-                const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
-                const PRECISION_LENGTH: [usize; 4_usize] = [
-                    (4_usize * $register_size) / $t_size,
-                    (3_usize * $register_size) / $t_size,
-                    (2_usize * $register_size) / $t_size,
-                    (1_usize * $register_size) / $t_size
-                ];
-
-                let mut search_result: Vec<usize> = Vec::<usize>::new();
-
-                let (array, pattern): (&[$t], &[$t]) = (
-                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(array_ptr.as_ptr()), array_ptr.len() / $t_size) },
-                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(pattern_ptr.as_ptr()), pattern_ptr.len() / $t_size) }
-                );
-
-                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return search_result; }
-
-                let (mut array_ptr, mut array_index, mut remains_length, mut index_of_match): (*const $t, usize, usize, usize) = (array.as_ptr(), 0_usize, array_length, 0_usize);
-
-                let (mut matches, and_not_mask, zero): ([[$t; COUNT_OF_VALUES_IN_REGISTER]; 4_usize], $precision, i32) = ([[$t_default; COUNT_OF_VALUES_IN_REGISTER]; 4_usize], unsafe { $dup_one_t($not_ignore_mask) }, 0_i32);
-
-                let (read_match_ptr, write_match_ptr): ([*const $precision; 4_usize], [*mut $precision; 4_usize]) = unsafe {
-                    (
-                        [
-                            transmute::<*const $t, *const $precision>(matches[0].as_ptr()),
-                            transmute::<*const $t, *const $precision>(matches[1].as_ptr()),
-                            transmute::<*const $t, *const $precision>(matches[2].as_ptr()),
-                            transmute::<*const $t, *const $precision>(matches[3].as_ptr())
-                        ],
-                        [
-                            transmute::<*mut $t, *mut $precision>(matches[0].as_mut_ptr()),
-                            transmute::<*mut $t, *mut $precision>(matches[1].as_mut_ptr()),
-                            transmute::<*mut $t, *mut $precision>(matches[2].as_mut_ptr()),
-                            transmute::<*mut $t, *mut $precision>(matches[3].as_mut_ptr())
-                        ]
-                    )
-                };
-
-                unsafe {
-                    if pattern_length == 1_usize {
-
-                        let pattern_mask: $precision = $dup_one_t(pattern[0]);
-
-                        if remains_length >= PRECISION_LENGTH[3] {
-                            while remains_length >= PRECISION_LENGTH[0] {
-                                let (first, second, third, four): ($precision, $precision, $precision, $precision) = {
-                                    let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let four: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (first, second, third, four)
-                                };
-
-                                $store(write_match_ptr[0], $eq_compare(first, pattern_mask));
-                                $store(write_match_ptr[1], $eq_compare(second, pattern_mask));
-                                $store(write_match_ptr[2], $eq_compare(third, pattern_mask));
-                                $store(write_match_ptr[3], $eq_compare(four, pattern_mask));
-
-                                for i in 0..4 {
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[0];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[1] {
-                                let (first, second, third): ($precision, $precision, $precision) = {
-                                    let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (first, second, third)
-                                };
-
-                                $store(write_match_ptr[0], $eq_compare(first, pattern_mask));
-                                $store(write_match_ptr[1], $eq_compare(second, pattern_mask));
-                                $store(write_match_ptr[2], $eq_compare(third, pattern_mask));
-
-                                for i in 0..3 {
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[1];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[2] {
-                                let (first, second): ($precision, $precision) = {
-                                    let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (first, second)
-                                };
-
-                                $store(write_match_ptr[0], $eq_compare(first, pattern_mask));
-                                $store(write_match_ptr[1], $eq_compare(second, pattern_mask));
-
-                                for i in 0..2 {
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                        index_of_match = 0_usize;
-                                    }
-                                    array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[2];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[3] {
-                                let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-
-                                $store(write_match_ptr[0], $eq_compare(first, pattern_mask));
-
-                                if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                    while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
-                                        else { index_of_match += 1_usize; }
-                                    }
-                                    index_of_match = 0_usize;
-                                }
-                                array_index += COUNT_OF_VALUES_IN_REGISTER;
-
-                                remains_length -= PRECISION_LENGTH[3];
-                            }
-                        }
-
-                        if remains_length > 0_usize {
-                            let aligned_array: [$t; COUNT_OF_VALUES_IN_REGISTER] = {
-                                let mut indivisible_part: [$t; COUNT_OF_VALUES_IN_REGISTER] = [$t_default; COUNT_OF_VALUES_IN_REGISTER];
-                                for i in 0..remains_length { indivisible_part[i] = array[array_index + i]; }
-                                indivisible_part
-                            };
-
-                            let first: $precision = $load(transmute::<*const $t, *const $precision>(aligned_array.as_ptr()));
-
-                            $store(write_match_ptr[0], $eq_compare(first, pattern_mask));
-
-                            if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                while index_of_match < remains_length {
-                                    if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
-                                    else { index_of_match += 1_usize; }
-                                }
-                            }
-                        }
-                    } else if pattern_length == 2_usize {
-
-                        let (mut next_pass, start_pattern_mask, end_pattern_mask): (bool, $precision, $precision) = (false, $dup_one_t(pattern[0]), $dup_one_t(pattern[1]));
-
-                        if remains_length >= PRECISION_LENGTH[3] + 1_usize {
-                            while remains_length >= PRECISION_LENGTH[0] + 1_usize {
-                                let (s_first, e_first, s_second, e_second, s_third, e_third, s_four, e_four): ($precision, $precision, $precision, $precision, $precision, $precision, $precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_four: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_four: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (s_first, e_first, s_second, e_second, s_third, e_third, s_four, e_four)
-                                };
-
-                                $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-                                $store(write_match_ptr[1], $bitwise_and($eq_compare(s_second, start_pattern_mask), $eq_compare(e_second, end_pattern_mask)));
-                                $store(write_match_ptr[2], $bitwise_and($eq_compare(s_third, start_pattern_mask), $eq_compare(e_third, end_pattern_mask)));
-                                $store(write_match_ptr[3], $bitwise_and($eq_compare(s_four, start_pattern_mask), $eq_compare(e_four, end_pattern_mask)));
-
-                                for i in 0..4 {
-                                    if next_pass { index_of_match += 1_usize; next_pass = false; }
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 2_usize; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                    }
-                                    if index_of_match > COUNT_OF_VALUES_IN_REGISTER { next_pass = true; }; index_of_match = 0_usize; array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[0];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[1] + 1_usize {
-                                let (s_first, e_first, s_second, e_second, s_third, e_third): ($precision, $precision, $precision, $precision, $precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (s_first, e_first, s_second, e_second, s_third, e_third)
-                                };
-
-                                $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-                                $store(write_match_ptr[1], $bitwise_and($eq_compare(s_second, start_pattern_mask), $eq_compare(e_second, end_pattern_mask)));
-                                $store(write_match_ptr[2], $bitwise_and($eq_compare(s_third, start_pattern_mask), $eq_compare(e_third, end_pattern_mask)));
-
-                                for i in 0..3 {
-                                    if next_pass { index_of_match += 1_usize; next_pass = false; }
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 2_usize; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                    }
-                                    if index_of_match > COUNT_OF_VALUES_IN_REGISTER { next_pass = true; }; index_of_match = 0_usize; array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[1];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[2] + 1_usize {
-                                let (s_first, e_first, s_second, e_second): ($precision, $precision, $precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    let s_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (s_first, e_first, s_second, e_second)
-                                };
-
-                                $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-                                $store(write_match_ptr[1], $bitwise_and($eq_compare(s_second, start_pattern_mask), $eq_compare(e_second, end_pattern_mask)));
-
-                                for i in 0..2 {
-                                    if next_pass { index_of_match += 1_usize; next_pass = false; }
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 2_usize; }
-                                            else { index_of_match += 1_usize; }
-                                        }
-                                    }
-                                    if index_of_match > COUNT_OF_VALUES_IN_REGISTER { next_pass = true; }; index_of_match = 0_usize; array_index += COUNT_OF_VALUES_IN_REGISTER;
-                                }
-
-                                remains_length -= PRECISION_LENGTH[2];
-                            }
-
-                            if remains_length >= PRECISION_LENGTH[3] + 1_usize {
-                                let (s_first, e_first): ($precision, $precision) = {
-                                    let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                    let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-                                    array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (s_first, e_first)
-                                };
-
-                                $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-
-                                if next_pass { index_of_match += 1_usize; next_pass = false; }
-                                if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                    while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 2_usize; }
-                                        else { index_of_match += 1_usize; }
-                                    }
-                                }
-                                if index_of_match > COUNT_OF_VALUES_IN_REGISTER { next_pass = true; }; index_of_match = 0_usize; array_index += COUNT_OF_VALUES_IN_REGISTER;
-
-                                remains_length -= PRECISION_LENGTH[3];
-                            }
-                        }
-
-                        if remains_length > 0_usize {
-                            let aligned_array: [$t; COUNT_OF_VALUES_IN_REGISTER + 1_usize] = {
-                                let mut indivisible_part: [$t; COUNT_OF_VALUES_IN_REGISTER + 1_usize] = [$t_default; COUNT_OF_VALUES_IN_REGISTER + 1_usize];
-                                for i in 0..remains_length { indivisible_part[i] = array[array_index + i]; }
-                                indivisible_part
-                            };
-
-                            let (s_first, e_first): ($precision, $precision) = {
-                                let s_first: $precision = $load(transmute::<*const $t, *const $precision>(aligned_array.as_ptr()));
-                                let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(1_usize)));
-
-                                (s_first, e_first)
-                            };
-
-                            $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-
-                            if next_pass { index_of_match += 1_usize; }
-                            if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                    if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 2_usize; }
-                                    else { index_of_match += 1_usize; }
-                                }
-                            }
-                        }
-                    } else if pattern_length >= 3_usize {
-                        let (last_pattern_index, middle_pattern_length): (usize, usize) = (pattern_length - 1_usize, pattern_length - 2_usize);
-                        let middle_pattern_parts: usize = (middle_pattern_length + (COUNT_OF_VALUES_IN_REGISTER - 1_usize)) / COUNT_OF_VALUES_IN_REGISTER;
-                        let (start_pattern_mask, end_pattern_mask): ($precision, $precision) = ($dup_one_t(pattern[0]), $dup_one_t(pattern[last_pattern_index]));
-                        let last_array_index_multiple_of_register: usize = array_length - (array_length % COUNT_OF_VALUES_IN_REGISTER);
-
-                        let (next_area, mut next_index_of_match): (usize, usize) = (pattern_length - (pattern_length % COUNT_OF_VALUES_IN_REGISTER), 0_usize);
-
-                        if last_array_index_multiple_of_register != 0_usize {
-                            if pattern_length > COUNT_OF_VALUES_IN_REGISTER {
-                                while array_index < last_array_index_multiple_of_register {
-                                    let (s_first, e_first): ($precision, $precision) = {
-                                        let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                        let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(last_pattern_index)));
-
-                                        (s_first, e_first)
-                                    };
-
-                                    $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-
-                                    if next_index_of_match != 0_usize { index_of_match += next_index_of_match; next_index_of_match = 0_usize; }
-                                    if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[0][index_of_match] != 0 {
-                                                let mut result: bool = true;
-
-                                                for i in 0_usize..middle_pattern_parts - 1_usize {
-                                                    if $vector_to_scalar($bitwise_not_and($eq_compare(
-                                                        $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + 1_usize + i * COUNT_OF_VALUES_IN_REGISTER))),
-                                                        $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(1_usize + i * COUNT_OF_VALUES_IN_REGISTER)))
-                                                    ), and_not_mask)) != 0 {
-                                                        result &= false; break
-                                                    }
-                                                }
-
-                                                if result {
-                                                    if $vector_to_scalar($bitwise_not_and($eq_compare(
-                                                        $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
-                                                        $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
-                                                    ), and_not_mask)) == 0 {
-                                                        let index: usize = array_index + index_of_match;
-                                                        search_result.push(index * $t_size); next_index_of_match = (index + pattern_length) % COUNT_OF_VALUES_IN_REGISTER; break;
-                                                    }
-                                                }
-                                            }
-                                            index_of_match += 1_usize;
-                                        }
-                                    }
-                                    index_of_match = 0_usize; array_index += next_area; array_ptr = array_ptr.add(next_area);
-                                }
-                            } else if pattern_length == COUNT_OF_VALUES_IN_REGISTER {
-                                let pattern_loaded: $precision = $load(transmute::<*const $t, *const $precision>(pattern.as_ptr()));
-
-                                while array_index < last_array_index_multiple_of_register {
-                                    let (s_first, e_first): ($precision, $precision) = {
-                                        let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                        let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(last_pattern_index)));
-
-                                        (s_first, e_first)
-                                    };
-
-                                    $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-
-                                    if next_index_of_match != 0_usize { index_of_match += next_index_of_match; next_index_of_match = 0_usize; }
-                                    if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[0][index_of_match] != 0 {
-                                                if $vector_to_scalar($bitwise_not_and($eq_compare(
-                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
-                                                    pattern_loaded
-                                                ), and_not_mask)) == 0 {
-                                                    let index: usize = array_index + index_of_match;
-                                                    search_result.push(index * $t_size); next_index_of_match = (index + pattern_length) % COUNT_OF_VALUES_IN_REGISTER; break;
-                                                }
-                                            }
-                                            index_of_match += 1_usize;
-                                        }
-                                    }
-                                    index_of_match = 0_usize; array_index += COUNT_OF_VALUES_IN_REGISTER; array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                }
-                            } else if pattern_length < COUNT_OF_VALUES_IN_REGISTER {
-                                let (pattern_loaded, pattern_ignore_mask): ($precision, $precision) = {
-                                    let (mut pattern_array, mut ignore_mask): ([$t; COUNT_OF_VALUES_IN_REGISTER], [$t; COUNT_OF_VALUES_IN_REGISTER]) = ([$t_default; COUNT_OF_VALUES_IN_REGISTER], [$t_default; COUNT_OF_VALUES_IN_REGISTER]);
-                                    for i in 0_usize..pattern_length { pattern_array[i] = pattern[i]; ignore_mask[i] = $not_ignore_mask; }
-                                    ($load(transmute::<*const $t, *const $precision>(pattern_array.as_ptr())), $load(transmute::<*const $t, *const $precision>(ignore_mask.as_ptr())))
-                                };
-
-                                while array_index < last_array_index_multiple_of_register {
-                                    let (s_first, e_first): ($precision, $precision) = {
-                                        let s_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
-                                        let e_first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr.add(last_pattern_index)));
-
-                                        (s_first, e_first)
-                                    };
-
-                                    $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-
-                                    if next_index_of_match != 0_usize { index_of_match += next_index_of_match; next_index_of_match = 0_usize; }
-                                    if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                        while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[0][index_of_match] != 0 {
-                                                if $vector_to_scalar($bitwise_and($bitwise_not_and($eq_compare(
-                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))), pattern_loaded),
-                                                and_not_mask), pattern_ignore_mask)) == 0 {
-                                                    let index: usize = array_index + index_of_match;
-                                                    search_result.push(index * $t_size);
-
-                                                    if index_of_match + pattern_length > COUNT_OF_VALUES_IN_REGISTER {
-                                                        next_index_of_match = (index + pattern_length) % COUNT_OF_VALUES_IN_REGISTER; break;
-                                                    } else { index_of_match += pattern_length; }
-                                                } else { index_of_match += 1_usize; }
-                                            } else { index_of_match += 1_usize; }
-                                        }
-                                    }
-                                    index_of_match = 0_usize; array_index += COUNT_OF_VALUES_IN_REGISTER; array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                }
-                            }
-                        }
-
-                        if array_index <= array_length {
-                            matches[0] = core::mem::zeroed::<[$t; COUNT_OF_VALUES_IN_REGISTER]>();
-
-                            for i in 0..(array_length - array_index) {
-                                if array[array_index + i] == pattern[0] {
-                                    if array[array_index + i + last_pattern_index] == pattern[last_pattern_index] { matches[0][i] = $not_ignore_mask; }
-                                    else { matches[0][i] = 0; }
-                                } else {
-                                    matches[0][i] = 0;
-                                }
-                            }
-
-                            if pattern_length > COUNT_OF_VALUES_IN_REGISTER {
-                                if next_index_of_match != 0_usize { index_of_match += next_index_of_match; }
-                                if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                    while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 {
-                                            let mut result: bool = true;
-
-                                            for i in 0_usize..middle_pattern_parts - 1_usize {
-                                                if $vector_to_scalar($bitwise_not_and($eq_compare(
-                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + 1_usize + i * COUNT_OF_VALUES_IN_REGISTER))),
-                                                    $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(1_usize + i * COUNT_OF_VALUES_IN_REGISTER)))
-                                                ), and_not_mask)) != 0 {
-                                                    result &= false; break
-                                                }
-                                            }
-
-                                            if result {
-                                                if $vector_to_scalar($bitwise_not_and($eq_compare(
-                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
-                                                    $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
-                                                ), and_not_mask)) == 0 {
-                                                    search_result.push((array_index + index_of_match) * $t_size); break;
-                                                }
-                                            }
-                                        }
-                                        index_of_match += 1_usize;
-                                    }
-                                }
-                            } else if pattern_length == COUNT_OF_VALUES_IN_REGISTER {
-                                let pattern_loaded: $precision = $load(transmute::<*const $t, *const $precision>(pattern.as_ptr()));
-
-                                if next_index_of_match != 0_usize { index_of_match += next_index_of_match; }
-                                if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                    while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 {
-                                            if $vector_to_scalar($bitwise_not_and($eq_compare(
-                                                $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
-                                                pattern_loaded
-                                            ), and_not_mask)) == 0 {
-                                                search_result.push((array_index + index_of_match) * $t_size); break;
-                                            }
-                                        }
-                                        index_of_match += 1_usize;
-                                    }
-                                }
-                            } else if pattern_length < COUNT_OF_VALUES_IN_REGISTER {
-                                let (pattern_loaded, pattern_ignore_mask): ($precision, $precision) = {
-                                    let (mut pattern_array, mut ignore_mask): ([$t; COUNT_OF_VALUES_IN_REGISTER], [$t; COUNT_OF_VALUES_IN_REGISTER]) = ([$t_default; COUNT_OF_VALUES_IN_REGISTER], [$t_default; COUNT_OF_VALUES_IN_REGISTER]);
-                                    for i in 0_usize..pattern_length { pattern_array[i] = pattern[i]; ignore_mask[i] = $not_ignore_mask; }
-                                    ($load(transmute::<*const $t, *const $precision>(pattern_array.as_ptr())), $load(transmute::<*const $t, *const $precision>(ignore_mask.as_ptr())))
-                                };
-
-                                if next_index_of_match != 0_usize { index_of_match += next_index_of_match; }
-                                if $vector_to_scalar($load(read_match_ptr[0])) != zero {
-                                    while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 {
-                                            if $vector_to_scalar($bitwise_and($bitwise_not_and($eq_compare(
-                                                $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))), pattern_loaded),
-                                            and_not_mask), pattern_ignore_mask)) == 0 {
-                                                search_result.push((array_index + index_of_match) * $t_size); index_of_match += pattern_length;
-                                            } else { index_of_match += 1_usize; }
-                                        } else { index_of_match += 1_usize; }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-
-                return search_result;
-            }
-
-            pub fn search_all_overlapping(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize>
-            where $t: Copy + Sized {
-
-                const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
-                const PRECISION_LENGTH: [usize; 4_usize] = [
-                    (4_usize * $register_size) / $t_size,
-                    (3_usize * $register_size) / $t_size,
-                    (2_usize * $register_size) / $t_size,
-                    (1_usize * $register_size) / $t_size
-                ];
-
-                let mut search_result: Vec<usize> = Vec::<usize>::new();
-
-                let (array, pattern): (&[$t], &[$t]) = (
-                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(array_ptr.as_ptr()), array_ptr.len() / $t_size) },
-                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(pattern_ptr.as_ptr()), pattern_ptr.len() / $t_size) }
-                );
-
-                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return search_result; }
-
-                let (mut array_ptr, mut array_index, mut remains_length, mut index_of_match): (*const $t, usize, usize, usize) = (array.as_ptr(), 0_usize, array_length, 0_usize);
-
-                let (mut matches, and_not_mask, zero): ([[$t; COUNT_OF_VALUES_IN_REGISTER]; 4_usize], $precision, i32) = ([[$t_default; COUNT_OF_VALUES_IN_REGISTER]; 4_usize], unsafe { $dup_one_t($not_ignore_mask) }, 0_i32);
-
-                let (read_match_ptr, write_match_ptr): ([*const $precision; 4_usize], [*mut $precision; 4_usize]) = unsafe {
-                    (
-                        [
-                            transmute::<*const $t, *const $precision>(matches[0].as_ptr()),
-                            transmute::<*const $t, *const $precision>(matches[1].as_ptr()),
-                            transmute::<*const $t, *const $precision>(matches[2].as_ptr()),
-                            transmute::<*const $t, *const $precision>(matches[3].as_ptr())
-                        ],
-                        [
-                            transmute::<*mut $t, *mut $precision>(matches[0].as_mut_ptr()),
-                            transmute::<*mut $t, *mut $precision>(matches[1].as_mut_ptr()),
-                            transmute::<*mut $t, *mut $precision>(matches[2].as_mut_ptr()),
-                            transmute::<*mut $t, *mut $precision>(matches[3].as_mut_ptr())
-                        ]
-                    )
-                };
-
-                unsafe {
-                    if pattern_length == 1_usize {
-
-                        let pattern_mask: $precision = $dup_one_t(pattern[0]);
-
-                        if remains_length >= PRECISION_LENGTH[3] {
-                            while remains_length >= PRECISION_LENGTH[0] {
-                                let (first, second, third, four): ($precision, $precision, $precision, $precision) = {
-                                    let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let second: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let third: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-                                    let four: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr)); array_ptr = array_ptr.add(COUNT_OF_VALUES_IN_REGISTER);
-
-                                    (first, second, third, four)
-                                };
-
-                                $store(write_match_ptr[0], $eq_compare(first, pattern_mask));
-                                $store(write_match_ptr[1], $eq_compare(second, pattern_mask));
-                                $store(write_match_ptr[2], $eq_compare(third, pattern_mask));
-                                $store(write_match_ptr[3], $eq_compare(four, pattern_mask));
+                                $store(write_match_ptr[2], $mask_to_vector($eq_compare(third, pattern_mask), mask_to_vector_mask));
+                                $store(write_match_ptr[3], $mask_to_vector($eq_compare(four, pattern_mask), mask_to_vector_mask));
 
                                 for i in 0..4 {
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
+                                    if $vector_to_scalar($load(load_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                             if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
                                             else { index_of_match += 1_usize; }
@@ -2847,12 +1379,12 @@ macro_rules! generate_search {
                                     (first, second, third)
                                 };
 
-                                $store(write_match_ptr[0], $eq_compare(first, pattern_mask));
-                                $store(write_match_ptr[1], $eq_compare(second, pattern_mask));
-                                $store(write_match_ptr[2], $eq_compare(third, pattern_mask));
+                                $store(write_match_ptr[0], $mask_to_vector($eq_compare(first, pattern_mask), mask_to_vector_mask));
+                                $store(write_match_ptr[1], $mask_to_vector($eq_compare(second, pattern_mask), mask_to_vector_mask));
+                                $store(write_match_ptr[2], $mask_to_vector($eq_compare(third, pattern_mask), mask_to_vector_mask));
 
                                 for i in 0..3 {
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
+                                    if $vector_to_scalar($load(load_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                             if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
                                             else { index_of_match += 1_usize; }
@@ -2873,11 +1405,11 @@ macro_rules! generate_search {
                                     (first, second)
                                 };
 
-                                $store(write_match_ptr[0], $eq_compare(first, pattern_mask));
-                                $store(write_match_ptr[1], $eq_compare(second, pattern_mask));
+                                $store(write_match_ptr[0], $mask_to_vector($eq_compare(first, pattern_mask), mask_to_vector_mask));
+                                $store(write_match_ptr[1], $mask_to_vector($eq_compare(second, pattern_mask), mask_to_vector_mask));
 
                                 for i in 0..2 {
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
+                                    if $vector_to_scalar($load(load_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                             if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
                                             else { index_of_match += 1_usize; }
@@ -2893,9 +1425,9 @@ macro_rules! generate_search {
                             if remains_length >= PRECISION_LENGTH[3] {
                                 let first: $precision = $load(transmute::<*const $t, *const $precision>(array_ptr));
 
-                                $store(write_match_ptr[0], $eq_compare(first, pattern_mask));
+                                $store(write_match_ptr[0], $mask_to_vector($eq_compare(first, pattern_mask), mask_to_vector_mask));
 
-                                if $vector_to_scalar($load(read_match_ptr[0])) != zero {
+                                if $vector_to_scalar($load(load_match_ptr[0])) != zero {
                                     while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                         if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
                                         else { index_of_match += 1_usize; }
@@ -2917,9 +1449,9 @@ macro_rules! generate_search {
 
                             let first: $precision = $load(transmute::<*const $t, *const $precision>(aligned_array.as_ptr()));
 
-                            $store(write_match_ptr[0], $eq_compare(first, pattern_mask));
+                            $store(write_match_ptr[0], $mask_to_vector($eq_compare(first, pattern_mask), mask_to_vector_mask));
 
-                            if $vector_to_scalar($load(read_match_ptr[0])) != zero {
+                            if $vector_to_scalar($load(load_match_ptr[0])) != zero {
                                 while index_of_match < remains_length {
                                     if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
                                     else { index_of_match += 1_usize; }
@@ -2952,13 +1484,13 @@ macro_rules! generate_search {
                                     (s_first, e_first, s_second, e_second, s_third, e_third, s_four, e_four)
                                 };
 
-                                $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-                                $store(write_match_ptr[1], $bitwise_and($eq_compare(s_second, start_pattern_mask), $eq_compare(e_second, end_pattern_mask)));
-                                $store(write_match_ptr[2], $bitwise_and($eq_compare(s_third, start_pattern_mask), $eq_compare(e_third, end_pattern_mask)));
-                                $store(write_match_ptr[3], $bitwise_and($eq_compare(s_four, start_pattern_mask), $eq_compare(e_four, end_pattern_mask)));
+                                $store(write_match_ptr[0], $bitwise_and($mask_to_vector($eq_compare(s_first, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_first, end_pattern_mask), mask_to_vector_mask)));
+                                $store(write_match_ptr[1], $bitwise_and($mask_to_vector($eq_compare(s_second, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_second, end_pattern_mask), mask_to_vector_mask)));
+                                $store(write_match_ptr[2], $bitwise_and($mask_to_vector($eq_compare(s_third, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_third, end_pattern_mask), mask_to_vector_mask)));
+                                $store(write_match_ptr[3], $bitwise_and($mask_to_vector($eq_compare(s_four, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_four, end_pattern_mask), mask_to_vector_mask)));
 
                                 for i in 0..4 {
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
+                                    if $vector_to_scalar($load(load_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                             if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
                                             else { index_of_match += 1_usize; }
@@ -2988,12 +1520,12 @@ macro_rules! generate_search {
                                     (s_first, e_first, s_second, e_second, s_third, e_third)
                                 };
 
-                                $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-                                $store(write_match_ptr[1], $bitwise_and($eq_compare(s_second, start_pattern_mask), $eq_compare(e_second, end_pattern_mask)));
-                                $store(write_match_ptr[2], $bitwise_and($eq_compare(s_third, start_pattern_mask), $eq_compare(e_third, end_pattern_mask)));
+                                $store(write_match_ptr[0], $bitwise_and($mask_to_vector($eq_compare(s_first, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_first, end_pattern_mask), mask_to_vector_mask)));
+                                $store(write_match_ptr[1], $bitwise_and($mask_to_vector($eq_compare(s_second, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_second, end_pattern_mask), mask_to_vector_mask)));
+                                $store(write_match_ptr[2], $bitwise_and($mask_to_vector($eq_compare(s_third, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_third, end_pattern_mask), mask_to_vector_mask)));
 
                                 for i in 0..3 {
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
+                                    if $vector_to_scalar($load(load_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                             if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
                                             else { index_of_match += 1_usize; }
@@ -3019,11 +1551,11 @@ macro_rules! generate_search {
                                     (s_first, e_first, s_second, e_second)
                                 };
 
-                                $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
-                                $store(write_match_ptr[1], $bitwise_and($eq_compare(s_second, start_pattern_mask), $eq_compare(e_second, end_pattern_mask)));
+                                $store(write_match_ptr[0], $bitwise_and($mask_to_vector($eq_compare(s_first, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_first, end_pattern_mask), mask_to_vector_mask)));
+                                $store(write_match_ptr[1], $bitwise_and($mask_to_vector($eq_compare(s_second, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_second, end_pattern_mask), mask_to_vector_mask)));
 
                                 for i in 0..2 {
-                                    if $vector_to_scalar($load(read_match_ptr[i])) != zero {
+                                    if $vector_to_scalar($load(load_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                             if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
                                             else { index_of_match += 1_usize; }
@@ -3045,9 +1577,9 @@ macro_rules! generate_search {
                                     (s_first, e_first)
                                 };
 
-                                $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
+                                $store(write_match_ptr[0], $bitwise_and($mask_to_vector($eq_compare(s_first, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_first, end_pattern_mask), mask_to_vector_mask)));
 
-                                if $vector_to_scalar($load(read_match_ptr[0])) != zero {
+                                if $vector_to_scalar($load(load_match_ptr[0])) != zero {
                                     while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                         if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
                                         else { index_of_match += 1_usize; }
@@ -3074,9 +1606,9 @@ macro_rules! generate_search {
                                 (s_first, e_first)
                             };
 
-                            $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
+                            $store(write_match_ptr[0], $bitwise_and($mask_to_vector($eq_compare(s_first, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_first, end_pattern_mask), mask_to_vector_mask)));
 
-                            if $vector_to_scalar($load(read_match_ptr[0])) != zero {
+                            if $vector_to_scalar($load(load_match_ptr[0])) != zero {
                                 while index_of_match < remains_length {
                                     if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); index_of_match += 1_usize; }
                                     else { index_of_match += 1_usize; }
@@ -3099,27 +1631,27 @@ macro_rules! generate_search {
                                         (s_first, e_first)
                                     };
 
-                                    $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
+                                    $store(write_match_ptr[0], $bitwise_and($mask_to_vector($eq_compare(s_first, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_first, end_pattern_mask), mask_to_vector_mask)));
 
-                                    if $vector_to_scalar($load(read_match_ptr[0])) != zero {
+                                    if $vector_to_scalar($load(load_match_ptr[0])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                             if matches[0][index_of_match] != 0 {
                                                 let mut result: bool = true;
 
                                                 for i in 0_usize..middle_pattern_parts - 1_usize {
-                                                    if $vector_to_scalar($bitwise_not_and($eq_compare(
+                                                    if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
                                                         $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + 1_usize + i * COUNT_OF_VALUES_IN_REGISTER))),
                                                         $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(1_usize + i * COUNT_OF_VALUES_IN_REGISTER)))
-                                                    ), and_not_mask)) != 0 {
+                                                    ), mask_to_vector_mask), and_not_mask)) != 0 {
                                                         result &= false; break
                                                     }
                                                 }
 
                                                 if result {
-                                                    if $vector_to_scalar($bitwise_not_and($eq_compare(
+                                                    if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
                                                         $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
                                                         $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
-                                                    ), and_not_mask)) == 0 {
+                                                    ), mask_to_vector_mask), and_not_mask)) == 0 {
                                                         search_result.push((array_index + index_of_match) * $t_size);
                                                     }
                                                 }
@@ -3141,15 +1673,15 @@ macro_rules! generate_search {
                                         (s_first, e_first)
                                     };
 
-                                    $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
+                                    $store(write_match_ptr[0], $bitwise_and($mask_to_vector($eq_compare(s_first, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_first, end_pattern_mask), mask_to_vector_mask)));
 
-                                    if $vector_to_scalar($load(read_match_ptr[0])) != zero {
+                                    if $vector_to_scalar($load(load_match_ptr[0])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                             if matches[0][index_of_match] != 0 {
-                                                if $vector_to_scalar($bitwise_not_and($eq_compare(
+                                                if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
                                                     $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
                                                     pattern_loaded
-                                                ), and_not_mask)) == 0 {
+                                                ), mask_to_vector_mask), and_not_mask)) == 0 {
                                                     search_result.push((array_index + index_of_match) * $t_size);
                                                 }
                                             }
@@ -3174,14 +1706,15 @@ macro_rules! generate_search {
                                         (s_first, e_first)
                                     };
 
-                                    $store(write_match_ptr[0], $bitwise_and($eq_compare(s_first, start_pattern_mask), $eq_compare(e_first, end_pattern_mask)));
+                                    $store(write_match_ptr[0], $bitwise_and($mask_to_vector($eq_compare(s_first, start_pattern_mask), mask_to_vector_mask), $mask_to_vector($eq_compare(e_first, end_pattern_mask), mask_to_vector_mask)));
 
-                                    if $vector_to_scalar($load(read_match_ptr[0])) != zero {
+                                    if $vector_to_scalar($load(load_match_ptr[0])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                             if matches[0][index_of_match] != 0 {
-                                                if $vector_to_scalar($bitwise_and($bitwise_not_and($eq_compare(
-                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))), pattern_loaded),
-                                                and_not_mask), pattern_ignore_mask)) == 0 {
+                                                if $vector_to_scalar($bitwise_and($bitwise_not_and($mask_to_vector($eq_compare(
+                                                    $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
+                                                    pattern_loaded
+                                                ), mask_to_vector_mask), and_not_mask), pattern_ignore_mask)) == 0 {
                                                     search_result.push((array_index + index_of_match) * $t_size);
                                                 }
                                             }
@@ -3207,25 +1740,25 @@ macro_rules! generate_search {
                             }
 
                             if pattern_length > COUNT_OF_VALUES_IN_REGISTER {
-                                if $vector_to_scalar($load(read_match_ptr[0])) != zero {
+                                if $vector_to_scalar($load(load_match_ptr[0])) != zero {
                                     while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                         if matches[0][index_of_match] != 0 {
                                             let mut result: bool = true;
 
                                             for i in 0_usize..middle_pattern_parts - 1_usize {
-                                                if $vector_to_scalar($bitwise_not_and($eq_compare(
+                                                if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
                                                     $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + 1_usize + i * COUNT_OF_VALUES_IN_REGISTER))),
                                                     $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(1_usize + i * COUNT_OF_VALUES_IN_REGISTER)))
-                                                ), and_not_mask)) != 0 {
+                                                ), mask_to_vector_mask), and_not_mask)) != 0 {
                                                     result &= false; break
                                                 }
                                             }
 
                                             if result {
-                                                if $vector_to_scalar($bitwise_not_and($eq_compare(
+                                                if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
                                                     $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
                                                     $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
-                                                ), and_not_mask)) == 0 {
+                                                ), mask_to_vector_mask), and_not_mask)) == 0 {
                                                     search_result.push((array_index + index_of_match) * $t_size);
                                                 }
                                             }
@@ -3236,13 +1769,13 @@ macro_rules! generate_search {
                             } else if pattern_length == COUNT_OF_VALUES_IN_REGISTER {
                                 let pattern_loaded: $precision = $load(transmute::<*const $t, *const $precision>(pattern.as_ptr()));
 
-                                if $vector_to_scalar($load(read_match_ptr[0])) != zero {
+                                if $vector_to_scalar($load(load_match_ptr[0])) != zero {
                                     while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                         if matches[0][index_of_match] != 0 {
-                                            if $vector_to_scalar($bitwise_not_and($eq_compare(
+                                            if $vector_to_scalar($bitwise_not_and($mask_to_vector($eq_compare(
                                                 $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
                                                 pattern_loaded
-                                            ), and_not_mask)) == 0 {
+                                            ), mask_to_vector_mask), and_not_mask)) == 0 {
                                                 search_result.push((array_index + index_of_match) * $t_size);
                                             }
                                         }
@@ -3256,12 +1789,13 @@ macro_rules! generate_search {
                                     ($load(transmute::<*const $t, *const $precision>(pattern_array.as_ptr())), $load(transmute::<*const $t, *const $precision>(ignore_mask.as_ptr())))
                                 };
 
-                                if $vector_to_scalar($load(read_match_ptr[0])) != zero {
+                                if $vector_to_scalar($load(load_match_ptr[0])) != zero {
                                     while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
                                         if matches[0][index_of_match] != 0 {
-                                            if $vector_to_scalar($bitwise_and($bitwise_not_and($eq_compare(
-                                                $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))), pattern_loaded),
-                                            and_not_mask), pattern_ignore_mask)) == 0 {
+                                            if $vector_to_scalar($bitwise_and($bitwise_not_and($mask_to_vector($eq_compare(
+                                                $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
+                                                pattern_loaded
+                                            ), mask_to_vector_mask), and_not_mask), pattern_ignore_mask)) == 0 {
                                                 search_result.push((array_index + index_of_match) * $t_size);
                                             }
                                         }
@@ -3279,41 +1813,13 @@ macro_rules! generate_search {
     };
 }
 
-#[cfg(all(target_feature = "sse2", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
 macro_rules! generate_search {
-    ($t:ty, $t_default:expr, $not_ignore_mask:expr, $t_size:expr, $precision:ty, $register_size:expr, $load:expr, $store:expr, $dup_one_t:expr, $eq_compare:expr, $bitwise_and:expr, $bitwise_not_and:expr, $vector_to_scalar:expr) => {
+    ($feature:literal, $search_first:ident, $search_last:ident, $search_folded:ident, $search_all:ident, $search_all_overlapping:ident, $t:ty, $t_default:expr, $not_ignore_mask:expr, $t_size:expr, $precision:ty, $register_size:expr, $load:expr, $store:expr, $dup_one_t:expr, $eq_compare:expr, $bitwise_and:expr, $bitwise_not_and:expr, $vector_to_scalar:expr, $greater_than:expr, $bitwise_or:expr) => {
         impl ByteSearch<$t> {
 
-            const fn is_search_possible(array: &[$t], pattern: &[$t], limit: Option<usize>) -> (usize, usize, bool) {
-                return match limit {
-                    Some(limit) => {
-                        let (mut array_length, pattern_length): (usize, usize) = (array.len(), pattern.len());
-
-                        if (pattern_length == 0_usize) || (array_length == 0_usize) || (pattern_length > array_length) {
-                            return (0_usize, 0_usize, false);
-                        } else if limit > 0_usize {
-                            if limit >= array_length { (array_length - (pattern_length - 1_usize), pattern_length, true) }
-                            else {
-                                array_length -= limit;
-
-                                if pattern_length > array_length { return (0_usize, 0_usize, false); }
-                                else { (array_length - (pattern_length - 1_usize), pattern_length, true) }
-                            }
-                        } else { return (0_usize, 0_usize, false); }
-                    }
-                    None => {
-                        let (array_length, pattern_length): (usize, usize) = (array.len(), pattern.len());
-
-                        if (pattern_length == 0_usize) || (array_length == 0_usize) || (pattern_length > array_length) {
-                            return (0_usize, 0_usize, false);
-                        }
-
-                        (array_length - (pattern_length - 1_usize), pattern_length, true)
-                    }
-                };
-            }
-
-            pub fn search_single(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize>
+            #[target_feature(enable = $feature)]
+            #[allow(unused_unsafe)]
+            unsafe fn $search_first(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Option<usize>
             where $t: Copy + Sized {
 
                 const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
@@ -3324,14 +1830,12 @@ macro_rules! generate_search {
                     (1_usize * $register_size) / $t_size
                 ];
 
-                let mut search_result: Vec<usize> = Vec::<usize>::new();
-
                 let (array, pattern): (&[$t], &[$t]) = (
                     unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(array_ptr.as_ptr()), array_ptr.len() / $t_size) },
                     unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(pattern_ptr.as_ptr()), pattern_ptr.len() / $t_size) }
                 );
 
-                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return search_result; }
+                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return None; }
 
                 let (mut array_ptr, mut array_index, mut remains_length, mut index_of_match): (*const $t, usize, usize, usize) = (array.as_ptr(), 0_usize, array_length, 0_usize);
 
@@ -3378,7 +1882,7 @@ macro_rules! generate_search {
                                 for i in 0..4 {
                                     if $vector_to_scalar($load(read_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -3405,7 +1909,7 @@ macro_rules! generate_search {
                                 for i in 0..3 {
                                     if $vector_to_scalar($load(read_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -3430,7 +1934,7 @@ macro_rules! generate_search {
                                 for i in 0..2 {
                                     if $vector_to_scalar($load(read_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -3448,7 +1952,7 @@ macro_rules! generate_search {
 
                                 if $vector_to_scalar($load(read_match_ptr[0])) != zero {
                                     while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                        if matches[0][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                         else { index_of_match += 1_usize; }
                                     }
                                     index_of_match = 0_usize;
@@ -3472,7 +1976,7 @@ macro_rules! generate_search {
 
                             if $vector_to_scalar($load(read_match_ptr[0])) != zero {
                                 while index_of_match < remains_length {
-                                    if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                    if matches[0][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                     else { index_of_match += 1_usize; }
                                 }
                             }
@@ -3511,7 +2015,7 @@ macro_rules! generate_search {
                                 for i in 0..4 {
                                     if $vector_to_scalar($load(read_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -3546,7 +2050,7 @@ macro_rules! generate_search {
                                 for i in 0..3 {
                                     if $vector_to_scalar($load(read_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -3576,7 +2080,7 @@ macro_rules! generate_search {
                                 for i in 0..2 {
                                     if $vector_to_scalar($load(read_match_ptr[i])) != zero {
                                         while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                            if matches[i][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                            if matches[i][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                             else { index_of_match += 1_usize; }
                                         }
                                         index_of_match = 0_usize;
@@ -3600,7 +2104,7 @@ macro_rules! generate_search {
 
                                 if $vector_to_scalar($load(read_match_ptr[0])) != zero {
                                     while index_of_match < COUNT_OF_VALUES_IN_REGISTER {
-                                        if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                        if matches[0][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                         else { index_of_match += 1_usize; }
                                     }
                                     index_of_match = 0_usize;
@@ -3629,7 +2133,7 @@ macro_rules! generate_search {
 
                             if $vector_to_scalar($load(read_match_ptr[0])) != zero {
                                 while index_of_match < remains_length {
-                                    if matches[0][index_of_match] != 0 { search_result.push((array_index + index_of_match) * $t_size); return search_result; }
+                                    if matches[0][index_of_match] != 0 { return Some((array_index + index_of_match) * $t_size); }
                                     else { index_of_match += 1_usize; }
                                 }
                             }
@@ -3671,7 +2175,7 @@ macro_rules! generate_search {
                                                         $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
                                                         $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
                                                     ), and_not_mask)) == 0 {
-                                                        search_result.push((array_index + index_of_match) * $t_size); return search_result;
+                                                        return Some((array_index + index_of_match) * $t_size);
                                                     }
                                                 }
                                             }
@@ -3701,7 +2205,7 @@ macro_rules! generate_search {
                                                     $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
                                                     pattern_loaded
                                                 ), and_not_mask)) == 0 {
-                                                    search_result.push((array_index + index_of_match) * $t_size); return search_result;
+                                                    return Some((array_index + index_of_match) * $t_size);
                                                 }
                                             }
                                             index_of_match += 1_usize;
@@ -3733,7 +2237,7 @@ macro_rules! generate_search {
                                                 if $vector_to_scalar($bitwise_and($bitwise_not_and($eq_compare(
                                                     $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))), pattern_loaded),
                                                 and_not_mask), pattern_ignore_mask)) == 0 {
-                                                    search_result.push((array_index + index_of_match) * $t_size); return search_result;
+                                                    return Some((array_index + index_of_match) * $t_size);
                                                 }
                                             }
                                             index_of_match += 1_usize;
@@ -3777,7 +2281,7 @@ macro_rules! generate_search {
                                                     $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match + last_pattern_index - COUNT_OF_VALUES_IN_REGISTER))),
                                                     $load(transmute::<*const $t, *const $precision>(pattern.as_ptr().add(last_pattern_index - COUNT_OF_VALUES_IN_REGISTER)))
                                                 ), and_not_mask)) == 0 {
-                                                    search_result.push((array_index + index_of_match) * $t_size); return search_result;
+                                                    return Some((array_index + index_of_match) * $t_size);
                                                 }
                                             }
                                         }
@@ -3794,7 +2298,7 @@ macro_rules! generate_search {
                                                 $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))),
                                                 pattern_loaded
                                             ), and_not_mask)) == 0 {
-                                                search_result.push((array_index + index_of_match) * $t_size);
+                                                return Some((array_index + index_of_match) * $t_size);
                                             }
                                         }
                                         index_of_match += 1_usize;
@@ -3813,7 +2317,7 @@ macro_rules! generate_search {
                                             if $vector_to_scalar($bitwise_and($bitwise_not_and($eq_compare(
                                                 $load(transmute::<*const $t, *const $precision>(array_ptr.add(index_of_match))), pattern_loaded),
                                             and_not_mask), pattern_ignore_mask)) == 0 {
-                                                search_result.push((array_index + index_of_match) * $t_size); return search_result;
+                                                return Some((array_index + index_of_match) * $t_size);
                                             }
                                         }
                                         index_of_match += 1_usize;
@@ -3824,10 +2328,111 @@ macro_rules! generate_search {
                     }
                 }
 
-                return search_result;
+                return None;
+            }
+
+            #[target_feature(enable = $feature)]
+            unsafe fn $search_last(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Option<usize>
+            where $t: Copy + Sized {
+
+                const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
+
+                let (array, pattern): (&[$t], &[$t]) = (
+                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(array_ptr.as_ptr()), array_ptr.len() / $t_size) },
+                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(pattern_ptr.as_ptr()), pattern_ptr.len() / $t_size) }
+                );
+
+                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return None; }
+
+                let (mut remains_length, last_pattern_index): (usize, usize) = (array_length, pattern_length - 1_usize);
+
+                unsafe {
+                    let (first_mask, last_mask): ($precision, $precision) = ($dup_one_t(pattern[0]), $dup_one_t(pattern[last_pattern_index]));
+
+                    while remains_length >= COUNT_OF_VALUES_IN_REGISTER {
+                        let array_index: usize = remains_length - COUNT_OF_VALUES_IN_REGISTER;
+
+                        let mut candidates: u32 = $vector_to_scalar($bitwise_and(
+                            $eq_compare($load(transmute::<*const $t, *const $precision>(array.as_ptr().add(array_index))), first_mask),
+                            $eq_compare($load(transmute::<*const $t, *const $precision>(array.as_ptr().add(array_index + last_pattern_index))), last_mask)
+                        )) as u32;
+
+                        while candidates != 0_u32 {
+                            let index_of_match: usize = (31_u32 - candidates.leading_zeros()) as usize / $t_size;
+
+                            if array[array_index + index_of_match..array_index + index_of_match + pattern_length] == *pattern { return Some((array_index + index_of_match) * $t_size); }
+
+                            candidates &= (1_u32 << (index_of_match * $t_size)) - 1_u32;
+                        }
+
+                        remains_length = array_index;
+                    }
+                }
+
+                while remains_length > 0_usize {
+                    remains_length -= 1_usize;
+
+                    if array[remains_length..remains_length + pattern_length] == *pattern { return Some(remains_length * $t_size); }
+                }
+
+                return None;
+            }
+
+            #[target_feature(enable = $feature)]
+            unsafe fn $search_folded(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Option<usize>
+            where $t: Copy + Sized {
+
+                const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
+
+                let (array, pattern): (&[$t], &[$t]) = (
+                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(array_ptr.as_ptr()), array_ptr.len() / $t_size) },
+                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(pattern_ptr.as_ptr()), pattern_ptr.len() / $t_size) }
+                );
+
+                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return None; }
+
+                let (mut array_index, last_pattern_index): (usize, usize) = (0_usize, pattern_length - 1_usize);
+
+                unsafe {
+                    let (lower_bound, upper_bound, case_bit): ($precision, $precision, $precision) = ($dup_one_t(0x40), $dup_one_t(0x5B), $dup_one_t(0x20));
+
+                    let (first_mask, last_mask): ($precision, $precision) = ($dup_one_t(ByteSearch::<$t>::fold_ascii(pattern[0])), $dup_one_t(ByteSearch::<$t>::fold_ascii(pattern[last_pattern_index])));
+
+                    while array_index + COUNT_OF_VALUES_IN_REGISTER <= array_length {
+                        let (first, last): ($precision, $precision) = (
+                            $load(transmute::<*const $t, *const $precision>(array.as_ptr().add(array_index))),
+                            $load(transmute::<*const $t, *const $precision>(array.as_ptr().add(array_index + last_pattern_index)))
+                        );
+
+                        let mut candidates: u32 = $vector_to_scalar($bitwise_and(
+                            $eq_compare($bitwise_or(first, $bitwise_and($bitwise_and($greater_than(first, lower_bound), $greater_than(upper_bound, first)), case_bit)), first_mask),
+                            $eq_compare($bitwise_or(last, $bitwise_and($bitwise_and($greater_than(last, lower_bound), $greater_than(upper_bound, last)), case_bit)), last_mask)
+                        )) as u32;
+
+                        while candidates != 0_u32 {
+                            let index_of_match: usize = candidates.trailing_zeros() as usize / $t_size;
+
+                            if ByteSearch::<$t>::is_folded_match(array, array_index + index_of_match, pattern) { return Some((array_index + index_of_match) * $t_size); }
+
+                            candidates &= !(((1_u32 << $t_size) - 1_u32) << (index_of_match * $t_size));
+                        }
+
+                        array_index += COUNT_OF_VALUES_IN_REGISTER;
+                    }
+                }
+
+                while array_index < array_length {
+                    if ByteSearch::<$t>::is_folded_match(array, array_index, pattern) { return Some(array_index * $t_size); }
+
+                    array_index += 1_usize;
+                }
+
+                return None;
             }
 
-            pub fn search_all(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize>
+            #[target_feature(enable = $feature)]
+            #[allow(unused_unsafe)]
+            unsafe fn $search_all(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize>
             where $t: Copy + Sized {
 
                 const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
@@ -4352,7 +2957,9 @@ macro_rules! generate_search {
                 return search_result;
             }
 
-            pub fn search_all_overlapping(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize>
+            #[target_feature(enable = $feature)]
+            #[allow(unused_unsafe)]
+            unsafe fn $search_all_overlapping(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize>
             where $t: Copy + Sized {
 
                 const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
@@ -4869,23 +3476,146 @@ macro_rules! generate_search {
     };
 }
 
-#[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
-generate_search!(i8, 0_i8, -0x01, size_of::<i8>(), __m512i, size_of::<__m512i>(), __mmask64, _mm512_loadu_si512, _mm512_storeu_si512, _mm512_set1_epi8, _mm512_cmpeq_epi8_mask, _mm512_and_si512, _mm512_andnot_si512, _mm512_movepi8_mask, _mm512_maskz_mov_epi8);
-#[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
-generate_search!(i16, 0_i16, -0x0001, size_of::<i16>(), __m512i, size_of::<__m512i>(), __mmask32, _mm512_loadu_si512, _mm512_storeu_si512, _mm512_set1_epi16, _mm512_cmpeq_epi16_mask, _mm512_and_si512, _mm512_andnot_si512, _mm512_movepi16_mask, _mm512_maskz_mov_epi16);
-#[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
-generate_search!(i32, 0_i32, -0x00000001, size_of::<i32>(), __m512i, size_of::<__m512i>(), __mmask16, _mm512_loadu_si512, _mm512_storeu_si512, _mm512_set1_epi32, _mm512_cmpeq_epi32_mask, _mm512_and_si512, _mm512_andnot_si512, _mm512_movepi32_mask, _mm512_maskz_mov_epi32);
-
-#[cfg(all(target_feature = "avx", target_feature = "avx2", not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-generate_search!(i8, 0_i8, -0x01, size_of::<i8>(), __m256i, size_of::<__m256i>(), _mm256_loadu_si256, _mm256_storeu_si256, _mm256_set1_epi8, _mm256_cmpeq_epi8, _mm256_and_si256, _mm256_andnot_si256, _mm256_movemask_epi8);
-#[cfg(all(target_feature = "avx", target_feature = "avx2", not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-generate_search!(i16, 0_i16, -0x0001, size_of::<i16>(), __m256i, size_of::<__m256i>(), _mm256_loadu_si256, _mm256_storeu_si256, _mm256_set1_epi16, _mm256_cmpeq_epi16, _mm256_and_si256, _mm256_andnot_si256, _mm256_movemask_epi8);
-#[cfg(all(target_feature = "avx", target_feature = "avx2", not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-generate_search!(i32, 0_i32, -0x00000001, size_of::<i32>(), __m256i, size_of::<__m256i>(), _mm256_loadu_si256, _mm256_storeu_si256, _mm256_set1_epi32, _mm256_cmpeq_epi32, _mm256_and_si256, _mm256_andnot_si256, _mm256_movemask_epi8);
-
-#[cfg(all(target_feature = "sse2", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-generate_search!(i8, 0_i8, -0x01, size_of::<i8>(), __m128i, size_of::<__m128i>(), _mm_loadu_si128, _mm_storeu_si128, _mm_set1_epi8, _mm_cmpeq_epi8, _mm_and_si128, _mm_andnot_si128, _mm_movemask_epi8);
-#[cfg(all(target_feature = "sse2", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-generate_search!(i16, 0_i16, -0x0001, size_of::<i16>(), __m128i, size_of::<__m128i>(), _mm_loadu_si128, _mm_storeu_si128, _mm_set1_epi16, _mm_cmpeq_epi16, _mm_and_si128, _mm_andnot_si128, _mm_movemask_epi8);
-#[cfg(all(target_feature = "sse2", not(target_feature = "avx2"), not(target_feature = "avx512f"), not(target_feature = "avx512bw")))]
-generate_search!(i32, 0_i32, -0x00000001, size_of::<i32>(), __m128i, size_of::<__m128i>(), _mm_loadu_si128, _mm_storeu_si128, _mm_set1_epi32, _mm_cmpeq_epi32, _mm_and_si128, _mm_andnot_si128, _mm_movemask_epi8);
+macro_rules! generate_dispatch {
+    ($t:ty) => {
+        impl ByteSearch<$t> {
+
+            pub(crate) fn search_first(array: &[u8], pattern: &[u8], limit: Option<usize>) -> Option<usize> {
+                return dispatch!(unsafe fn(array: &[u8], pattern: &[u8], limit: Option<usize>) -> Option<usize>, ByteSearch::<$t>::search_first_sse2, ByteSearch::<$t>::search_first_avx2, ByteSearch::<$t>::search_first_avx512);
+            }
+
+            pub fn search_single(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize> {
+                return ByteSearch::<$t>::search_first(array_ptr, pattern_ptr, limit).into_iter().collect::<Vec<usize>>();
+            }
+
+            pub(crate) fn search_last_offset(array: &[u8], pattern: &[u8], limit: Option<usize>) -> Option<usize> {
+                return dispatch!(unsafe fn(array: &[u8], pattern: &[u8], limit: Option<usize>) -> Option<usize>, ByteSearch::<$t>::search_last_sse2, ByteSearch::<$t>::search_last_avx2, ByteSearch::<$t>::search_last_avx512);
+            }
+
+            pub fn search_last(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize> {
+                return ByteSearch::<$t>::search_last_offset(array_ptr, pattern_ptr, limit).into_iter().collect::<Vec<usize>>();
+            }
+
+            const fn fold_ascii(value: $t) -> $t {
+                return if value > 0x40 && value < 0x5B { value | 0x20 } else { value };
+            }
+
+            fn is_folded_match(array: &[$t], index: usize, pattern: &[$t]) -> bool {
+                return array[index..index + pattern.len()].iter().zip(pattern.iter()).all(|(&value, &expected)| ByteSearch::<$t>::fold_ascii(value) == ByteSearch::<$t>::fold_ascii(expected));
+            }
+
+            pub(crate) fn search_first_ignore_ascii_case(array: &[u8], pattern: &[u8], limit: Option<usize>) -> Option<usize> {
+                return dispatch!(unsafe fn(array: &[u8], pattern: &[u8], limit: Option<usize>) -> Option<usize>, ByteSearch::<$t>::search_folded_sse2, ByteSearch::<$t>::search_folded_avx2, ByteSearch::<$t>::search_folded_avx512);
+            }
+
+            pub fn search_single_ignore_ascii_case(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize> {
+                return ByteSearch::<$t>::search_first_ignore_ascii_case(array_ptr, pattern_ptr, limit).into_iter().collect::<Vec<usize>>();
+            }
+
+            pub fn search_all_ignore_ascii_case(array: &[u8], pattern: &[u8], limit: Option<usize>) -> Vec<usize> {
+                return ByteSearch::<$t>::search_ignore_ascii_case(array, pattern, limit, false);
+            }
+
+            pub fn search_all_overlapping_ignore_ascii_case(array: &[u8], pattern: &[u8], limit: Option<usize>) -> Vec<usize> {
+                return ByteSearch::<$t>::search_ignore_ascii_case(array, pattern, limit, true);
+            }
+
+            fn search_ignore_ascii_case(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>, overlapping: bool) -> Vec<usize> {
+                let mut search_result: Vec<usize> = Vec::<usize>::new();
+
+                let (array, pattern): (&[$t], &[$t]) = (
+                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(array_ptr.as_ptr()), array_ptr.len() / size_of::<$t>()) },
+                    unsafe { core::slice::from_raw_parts::<$t>(transmute::<*const u8, *const $t>(pattern_ptr.as_ptr()), pattern_ptr.len() / size_of::<$t>()) }
+                );
+
+                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return search_result; }
+
+                let (array_ptr, step, mut position): (&[u8], usize, usize) = (
+                    &array_ptr[..(array_length + pattern_length - 1_usize) * size_of::<$t>()],
+                    if overlapping { 1_usize } else { pattern_length } * size_of::<$t>(),
+                    0_usize
+                );
+
+                while let Some(offset) = ByteSearch::<$t>::search_first_ignore_ascii_case(&array_ptr[position..], pattern_ptr, None) {
+                    search_result.push(position + offset);
+
+                    position += offset + step;
+                }
+
+                return search_result;
+            }
+
+            pub fn search_all(array: &[u8], pattern: &[u8], limit: Option<usize>) -> Vec<usize> {
+                return dispatch!(unsafe fn(array: &[u8], pattern: &[u8], limit: Option<usize>) -> Vec<usize>, ByteSearch::<$t>::search_all_sse2, ByteSearch::<$t>::search_all_avx2, ByteSearch::<$t>::search_all_avx512);
+            }
+
+            pub fn search_all_overlapping(array: &[u8], pattern: &[u8], limit: Option<usize>) -> Vec<usize> {
+                return dispatch!(unsafe fn(array: &[u8], pattern: &[u8], limit: Option<usize>) -> Vec<usize>, ByteSearch::<$t>::search_all_overlapping_sse2, ByteSearch::<$t>::search_all_overlapping_avx2, ByteSearch::<$t>::search_all_overlapping_avx512);
+            }
+
+            pub fn find_iter<'a>(array: &'a [u8], pattern: &'a [u8]) -> ByteMatches<'a, $t> {
+                return ByteMatches { array, pattern, position: 0_usize, overlapping: false, __marker: PhantomData };
+            }
+
+            pub fn find_iter_overlapping<'a>(array: &'a [u8], pattern: &'a [u8]) -> ByteMatches<'a, $t> {
+                return ByteMatches { array, pattern, position: 0_usize, overlapping: true, __marker: PhantomData };
+            }
+
+            pub fn rfind_iter<'a>(array: &'a [u8], pattern: &'a [u8]) -> ByteMatchesRev<'a, $t> {
+                return ByteMatchesRev { array, pattern, end: array.len(), overlapping: false, __marker: PhantomData };
+            }
+
+            pub fn rfind_iter_overlapping<'a>(array: &'a [u8], pattern: &'a [u8]) -> ByteMatchesRev<'a, $t> {
+                return ByteMatchesRev { array, pattern, end: array.len(), overlapping: true, __marker: PhantomData };
+            }
+
+            pub fn count(array: &[u8], pattern: &[u8]) -> usize {
+                return ByteSearch::<$t>::find_iter(array, pattern).count();
+            }
+
+            pub fn count_overlapping(array: &[u8], pattern: &[u8]) -> usize {
+                return ByteSearch::<$t>::find_iter_overlapping(array, pattern).count();
+            }
+        }
+
+        impl<'a> Iterator for ByteMatches<'a, $t> {
+            type Item = usize;
+
+            fn next(&mut self) -> Option<usize> {
+                let offset: usize = self.position + ByteSearch::<$t>::search_first(&self.array[self.position..], self.pattern, None)?;
+
+                self.position = offset + if self.overlapping { size_of::<$t>() } else { self.pattern.len() - self.pattern.len() % size_of::<$t>() };
+
+                return Some(offset);
+            }
+        }
+
+        impl<'a> Iterator for ByteMatchesRev<'a, $t> {
+            type Item = usize;
+
+            fn next(&mut self) -> Option<usize> {
+                let offset: usize = ByteSearch::<$t>::search_last_offset(&self.array[..self.end], self.pattern, None)?;
+
+                self.end = if self.overlapping { offset + self.pattern.len() - self.pattern.len() % size_of::<$t>() - size_of::<$t>() } else { offset };
+
+                return Some(offset);
+            }
+        }
+    };
+}
+
+generate_search_avx512!("avx512f,avx512bw", search_first_avx512, search_last_avx512, search_folded_avx512, search_all_avx512, search_all_overlapping_avx512, i8, 0_i8, -0x01, size_of::<i8>(), __m512i, size_of::<__m512i>(), __mmask64, _mm512_loadu_si512, _mm512_storeu_si512, _mm512_set1_epi8, _mm512_cmpeq_epi8_mask, _mm512_and_si512, _mm512_andnot_si512, _mm512_movepi8_mask, _mm512_maskz_mov_epi8, _mm512_cmpgt_epi8_mask, _mm512_cmplt_epi8_mask, _mm512_or_si512);
+generate_search_avx512!("avx512f,avx512bw", search_first_avx512, search_last_avx512, search_folded_avx512, search_all_avx512, search_all_overlapping_avx512, i16, 0_i16, -0x0001, size_of::<i16>(), __m512i, size_of::<__m512i>(), __mmask32, _mm512_loadu_si512, _mm512_storeu_si512, _mm512_set1_epi16, _mm512_cmpeq_epi16_mask, _mm512_and_si512, _mm512_andnot_si512, _mm512_movepi16_mask, _mm512_maskz_mov_epi16, _mm512_cmpgt_epi16_mask, _mm512_cmplt_epi16_mask, _mm512_or_si512);
+generate_search_avx512!("avx512f,avx512bw", search_first_avx512, search_last_avx512, search_folded_avx512, search_all_avx512, search_all_overlapping_avx512, i32, 0_i32, -0x00000001, size_of::<i32>(), __m512i, size_of::<__m512i>(), __mmask16, _mm512_loadu_si512, _mm512_storeu_si512, _mm512_set1_epi32, _mm512_cmpeq_epi32_mask, _mm512_and_si512, _mm512_andnot_si512, _mm512_movepi32_mask, _mm512_maskz_mov_epi32, _mm512_cmpgt_epi32_mask, _mm512_cmplt_epi32_mask, _mm512_or_si512);
+
+generate_search!("avx,avx2", search_first_avx2, search_last_avx2, search_folded_avx2, search_all_avx2, search_all_overlapping_avx2, i8, 0_i8, -0x01, size_of::<i8>(), __m256i, size_of::<__m256i>(), _mm256_loadu_si256, _mm256_storeu_si256, _mm256_set1_epi8, _mm256_cmpeq_epi8, _mm256_and_si256, _mm256_andnot_si256, _mm256_movemask_epi8, _mm256_cmpgt_epi8, _mm256_or_si256);
+generate_search!("avx,avx2", search_first_avx2, search_last_avx2, search_folded_avx2, search_all_avx2, search_all_overlapping_avx2, i16, 0_i16, -0x0001, size_of::<i16>(), __m256i, size_of::<__m256i>(), _mm256_loadu_si256, _mm256_storeu_si256, _mm256_set1_epi16, _mm256_cmpeq_epi16, _mm256_and_si256, _mm256_andnot_si256, _mm256_movemask_epi8, _mm256_cmpgt_epi16, _mm256_or_si256);
+generate_search!("avx,avx2", search_first_avx2, search_last_avx2, search_folded_avx2, search_all_avx2, search_all_overlapping_avx2, i32, 0_i32, -0x00000001, size_of::<i32>(), __m256i, size_of::<__m256i>(), _mm256_loadu_si256, _mm256_storeu_si256, _mm256_set1_epi32, _mm256_cmpeq_epi32, _mm256_and_si256, _mm256_andnot_si256, _mm256_movemask_epi8, _mm256_cmpgt_epi32, _mm256_or_si256);
+
+generate_search!("sse2", search_first_sse2, search_last_sse2, search_folded_sse2, search_all_sse2, search_all_overlapping_sse2, i8, 0_i8, -0x01, size_of::<i8>(), __m128i, size_of::<__m128i>(), _mm_loadu_si128, _mm_storeu_si128, _mm_set1_epi8, _mm_cmpeq_epi8, _mm_and_si128, _mm_andnot_si128, _mm_movemask_epi8, _mm_cmpgt_epi8, _mm_or_si128);
+generate_search!("sse2", search_first_sse2, search_last_sse2, search_folded_sse2, search_all_sse2, search_all_overlapping_sse2, i16, 0_i16, -0x0001, size_of::<i16>(), __m128i, size_of::<__m128i>(), _mm_loadu_si128, _mm_storeu_si128, _mm_set1_epi16, _mm_cmpeq_epi16, _mm_and_si128, _mm_andnot_si128, _mm_movemask_epi8, _mm_cmpgt_epi16, _mm_or_si128);
+generate_search!("sse2", search_first_sse2, search_last_sse2, search_folded_sse2, search_all_sse2, search_all_overlapping_sse2, i32, 0_i32, -0x00000001, size_of::<i32>(), __m128i, size_of::<__m128i>(), _mm_loadu_si128, _mm_storeu_si128, _mm_set1_epi32, _mm_cmpeq_epi32, _mm_and_si128, _mm_andnot_si128, _mm_movemask_epi8, _mm_cmpgt_epi32, _mm_or_si128);
+
+generate_dispatch!(i8);
+generate_dispatch!(i16);
+generate_dispatch!(i32);
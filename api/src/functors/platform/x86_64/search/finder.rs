@@ -0,0 +1,203 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        Finder,
+        FinderMatches
+    }
+};
+
+use crate::{
+    functors::{
+        universal::{
+            search::{
+                generate_finder
+            }
+        },
+        platform::{
+            dispatch::{
+                dispatch
+            }
+        }
+    }
+};
+
+use core::{
+    arch::{
+        x86_64::{
+            __m512i,
+            __mmask16,
+            __mmask32,
+            __mmask64,
+            _mm512_loadu_si512,
+            _mm512_set1_epi8,
+            _mm512_set1_epi16,
+            _mm512_set1_epi32,
+            _mm512_cmpeq_epi8_mask,
+            _mm512_cmpeq_epi16_mask,
+            _mm512_cmpeq_epi32_mask,
+            __m256i,
+            _mm256_loadu_si256,
+            _mm256_set1_epi8,
+            _mm256_set1_epi16,
+            _mm256_set1_epi32,
+            _mm256_and_si256,
+            _mm256_cmpeq_epi8,
+            _mm256_cmpeq_epi16,
+            _mm256_cmpeq_epi32,
+            _mm256_movemask_epi8,
+            __m128i,
+            _mm_loadu_si128,
+            _mm_set1_epi8,
+            _mm_set1_epi16,
+            _mm_set1_epi32,
+            _mm_and_si128,
+            _mm_cmpeq_epi8,
+            _mm_cmpeq_epi16,
+            _mm_cmpeq_epi32,
+            _mm_movemask_epi8
+        }
+    }
+};
+
+macro_rules! generate_prefilter_avx512 {
+    ($feature:literal, $prefilter:ident, $t:ty, $t_size:expr, $precision:ty, $register_size:expr, $mask:ty, $load:expr, $dup_one_t:expr, $eq_compare:expr) => {
+        impl Finder<$t> {
+
+            #[target_feature(enable = $feature)]
+            unsafe fn $prefilter(finder: &Finder<$t>, array: &[$t], mut position: usize) -> Option<usize> {
+
+                const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
+
+                let ((first, second), last_position): ((usize, usize), usize) = (finder.rare, array.len() - finder.needle.len());
+
+                unsafe {
+                    let (first_mask, second_mask): ($precision, $precision) = ($dup_one_t(finder.needle[first] as _), $dup_one_t(finder.needle[second] as _));
+
+                    while position + COUNT_OF_VALUES_IN_REGISTER <= last_position + 1_usize {
+                        let candidates: $mask = $eq_compare($load(array.as_ptr().add(position + first) as *const $precision), first_mask)
+                            & $eq_compare($load(array.as_ptr().add(position + second) as *const $precision), second_mask);
+
+                        if candidates != 0 { return Some(position + candidates.trailing_zeros() as usize); }
+
+                        position += COUNT_OF_VALUES_IN_REGISTER;
+                    }
+                }
+
+                while position <= last_position {
+                    if array[position + first] == finder.needle[first] && array[position + second] == finder.needle[second] { return Some(position); }
+
+                    position += 1_usize;
+                }
+
+                return None;
+            }
+        }
+    };
+}
+
+macro_rules! generate_prefilter {
+    ($feature:literal, $prefilter:ident, $t:ty, $t_size:expr, $precision:ty, $register_size:expr, $load:expr, $dup_one_t:expr, $eq_compare:expr, $bitwise_and:expr, $vector_to_scalar:expr) => {
+        impl Finder<$t> {
+
+            #[target_feature(enable = $feature)]
+            unsafe fn $prefilter(finder: &Finder<$t>, array: &[$t], mut position: usize) -> Option<usize> {
+
+                const COUNT_OF_VALUES_IN_REGISTER: usize = $register_size / $t_size;
+
+                let ((first, second), last_position): ((usize, usize), usize) = (finder.rare, array.len() - finder.needle.len());
+
+                unsafe {
+                    let (first_mask, second_mask): ($precision, $precision) = ($dup_one_t(finder.needle[first] as _), $dup_one_t(finder.needle[second] as _));
+
+                    while position + COUNT_OF_VALUES_IN_REGISTER <= last_position + 1_usize {
+                        let candidates: u32 = $vector_to_scalar($bitwise_and(
+                            $eq_compare($load(array.as_ptr().add(position + first) as *const $precision), first_mask),
+                            $eq_compare($load(array.as_ptr().add(position + second) as *const $precision), second_mask)
+                        )) as u32;
+
+                        if candidates != 0_u32 { return Some(position + candidates.trailing_zeros() as usize / $t_size); }
+
+                        position += COUNT_OF_VALUES_IN_REGISTER;
+                    }
+                }
+
+                while position <= last_position {
+                    if array[position + first] == finder.needle[first] && array[position + second] == finder.needle[second] { return Some(position); }
+
+                    position += 1_usize;
+                }
+
+                return None;
+            }
+        }
+    };
+}
+
+macro_rules! generate_dispatch {
+    ($t:ty) => {
+        impl Finder<$t> {
+            pub(crate) fn next_candidate(&self, array: &[$t], position: usize) -> Option<usize> {
+                let finder: &Finder<$t> = self;
+
+                return dispatch!(unsafe fn(finder: &Finder<$t>, array: &[$t], position: usize) -> Option<usize>, Finder::<$t>::prefilter_sse2, Finder::<$t>::prefilter_avx2, Finder::<$t>::prefilter_avx512);
+            }
+        }
+
+        generate_finder!($t);
+    };
+}
+
+generate_prefilter_avx512!("avx512f,avx512bw", prefilter_avx512, i8, size_of::<i8>(), __m512i, size_of::<__m512i>(), __mmask64, _mm512_loadu_si512, _mm512_set1_epi8, _mm512_cmpeq_epi8_mask);
+generate_prefilter_avx512!("avx512f,avx512bw", prefilter_avx512, i16, size_of::<i16>(), __m512i, size_of::<__m512i>(), __mmask32, _mm512_loadu_si512, _mm512_set1_epi16, _mm512_cmpeq_epi16_mask);
+generate_prefilter_avx512!("avx512f,avx512bw", prefilter_avx512, i32, size_of::<i32>(), __m512i, size_of::<__m512i>(), __mmask16, _mm512_loadu_si512, _mm512_set1_epi32, _mm512_cmpeq_epi32_mask);
+generate_prefilter_avx512!("avx512f,avx512bw", prefilter_avx512, u8, size_of::<u8>(), __m512i, size_of::<__m512i>(), __mmask64, _mm512_loadu_si512, _mm512_set1_epi8, _mm512_cmpeq_epi8_mask);
+generate_prefilter_avx512!("avx512f,avx512bw", prefilter_avx512, u16, size_of::<u16>(), __m512i, size_of::<__m512i>(), __mmask32, _mm512_loadu_si512, _mm512_set1_epi16, _mm512_cmpeq_epi16_mask);
+generate_prefilter_avx512!("avx512f,avx512bw", prefilter_avx512, u32, size_of::<u32>(), __m512i, size_of::<__m512i>(), __mmask16, _mm512_loadu_si512, _mm512_set1_epi32, _mm512_cmpeq_epi32_mask);
+
+generate_prefilter!("avx,avx2", prefilter_avx2, i8, size_of::<i8>(), __m256i, size_of::<__m256i>(), _mm256_loadu_si256, _mm256_set1_epi8, _mm256_cmpeq_epi8, _mm256_and_si256, _mm256_movemask_epi8);
+generate_prefilter!("avx,avx2", prefilter_avx2, i16, size_of::<i16>(), __m256i, size_of::<__m256i>(), _mm256_loadu_si256, _mm256_set1_epi16, _mm256_cmpeq_epi16, _mm256_and_si256, _mm256_movemask_epi8);
+generate_prefilter!("avx,avx2", prefilter_avx2, i32, size_of::<i32>(), __m256i, size_of::<__m256i>(), _mm256_loadu_si256, _mm256_set1_epi32, _mm256_cmpeq_epi32, _mm256_and_si256, _mm256_movemask_epi8);
+generate_prefilter!("avx,avx2", prefilter_avx2, u8, size_of::<u8>(), __m256i, size_of::<__m256i>(), _mm256_loadu_si256, _mm256_set1_epi8, _mm256_cmpeq_epi8, _mm256_and_si256, _mm256_movemask_epi8);
+generate_prefilter!("avx,avx2", prefilter_avx2, u16, size_of::<u16>(), __m256i, size_of::<__m256i>(), _mm256_loadu_si256, _mm256_set1_epi16, _mm256_cmpeq_epi16, _mm256_and_si256, _mm256_movemask_epi8);
+generate_prefilter!("avx,avx2", prefilter_avx2, u32, size_of::<u32>(), __m256i, size_of::<__m256i>(), _mm256_loadu_si256, _mm256_set1_epi32, _mm256_cmpeq_epi32, _mm256_and_si256, _mm256_movemask_epi8);
+
+generate_prefilter!("sse2", prefilter_sse2, i8, size_of::<i8>(), __m128i, size_of::<__m128i>(), _mm_loadu_si128, _mm_set1_epi8, _mm_cmpeq_epi8, _mm_and_si128, _mm_movemask_epi8);
+generate_prefilter!("sse2", prefilter_sse2, i16, size_of::<i16>(), __m128i, size_of::<__m128i>(), _mm_loadu_si128, _mm_set1_epi16, _mm_cmpeq_epi16, _mm_and_si128, _mm_movemask_epi8);
+generate_prefilter!("sse2", prefilter_sse2, i32, size_of::<i32>(), __m128i, size_of::<__m128i>(), _mm_loadu_si128, _mm_set1_epi32, _mm_cmpeq_epi32, _mm_and_si128, _mm_movemask_epi8);
+generate_prefilter!("sse2", prefilter_sse2, u8, size_of::<u8>(), __m128i, size_of::<__m128i>(), _mm_loadu_si128, _mm_set1_epi8, _mm_cmpeq_epi8, _mm_and_si128, _mm_movemask_epi8);
+generate_prefilter!("sse2", prefilter_sse2, u16, size_of::<u16>(), __m128i, size_of::<__m128i>(), _mm_loadu_si128, _mm_set1_epi16, _mm_cmpeq_epi16, _mm_and_si128, _mm_movemask_epi8);
+generate_prefilter!("sse2", prefilter_sse2, u32, size_of::<u32>(), __m128i, size_of::<__m128i>(), _mm_loadu_si128, _mm_set1_epi32, _mm_cmpeq_epi32, _mm_and_si128, _mm_movemask_epi8);
+
+generate_dispatch!(i8);
+generate_dispatch!(i16);
+generate_dispatch!(i32);
+generate_dispatch!(u8);
+generate_dispatch!(u16);
+generate_dispatch!(u32);
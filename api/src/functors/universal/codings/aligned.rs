@@ -0,0 +1,63 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        ASCII
+    }
+};
+
+impl ASCII {
+    pub(crate) fn search_pattern_aligned<S: Copy>(array: &[u8], pattern: &[u8], overlapping: bool, all_matches: bool, limit: Option<usize>, state: S, advance: fn(&[u8], usize, S) -> (usize, S)) -> Vec<usize> {
+        let mut search_result: Vec<usize> = Vec::<usize>::new();
+
+        let (mut start, mut start_state, mut end, mut end_state, mut next): (usize, S, usize, S, usize) = (0_usize, state, 0_usize, state, 0_usize);
+
+        for candidate in ASCII::search_pattern(array, pattern, true, true, limit) {
+            if candidate < next { continue; }
+
+            while start < candidate { (start, start_state) = advance(array, start, start_state); }
+
+            if start != candidate { continue; }
+
+            if end < start { (end, end_state) = (start, start_state); }
+
+            while end < candidate + pattern.len() { (end, end_state) = advance(array, end, end_state); }
+
+            if end != candidate + pattern.len() { continue; }
+
+            search_result.push(candidate);
+
+            if !all_matches { break; }
+
+            if !overlapping { next = end; }
+        }
+
+        return search_result;
+    }
+}
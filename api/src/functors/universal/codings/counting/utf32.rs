@@ -0,0 +1,81 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF32,
+        Statistics
+    }
+};
+
+impl UTF32 {
+    pub(crate) const fn count_classes_from(array: &[u8], mut index: usize, stop: usize, endian: bool) -> (usize, usize, usize) {
+        let (mut ascii, mut narrow, mut bmp): (usize, usize, usize) = (0_usize, 0_usize, 0_usize);
+
+        while index < stop {
+            let code: u32 = UTF32::read_code_unit(array, index, endian);
+
+            ascii += (code < 0x00000080) as usize;
+            narrow += (code < 0x00000800) as usize;
+            bmp += (code < 0x00010000) as usize;
+
+            index += 4_usize;
+        }
+
+        return (ascii, narrow, bmp);
+    }
+
+    pub(crate) const fn statistics_from(units: usize, classes: (usize, usize, usize)) -> Statistics {
+        let (ascii, _, bmp): (usize, usize, usize) = classes;
+
+        return Statistics::new(ascii, bmp - ascii, units - bmp, (units - bmp) * 2_usize);
+    }
+
+    pub const fn count_chars(array: &[u8]) -> usize {
+        return array.len() / 4_usize;
+    }
+
+    #[cfg(feature = "universal")]
+    pub const fn stats(array: &[u8], endian: bool) -> Statistics {
+        let length: usize = array.len() - array.len() % 4_usize;
+
+        return UTF32::statistics_from(length / 4_usize, UTF32::count_classes_from(array, 0_usize, length, endian));
+    }
+
+    #[cfg(feature = "universal")]
+    pub const fn utf8_length_from_utf32(array: &[u8], endian: bool) -> usize {
+        let (ascii, narrow, bmp): (usize, usize, usize) = UTF32::count_classes_from(array, 0_usize, array.len() - array.len() % 4_usize, endian);
+
+        return array.len() / 4_usize * 4_usize - ascii - narrow - bmp;
+    }
+
+    #[cfg(feature = "universal")]
+    pub const fn utf16_length_from_utf32(array: &[u8], endian: bool) -> usize {
+        return array.len() / 4_usize * 2_usize - UTF32::count_classes_from(array, 0_usize, array.len() - array.len() % 4_usize, endian).2;
+    }
+}
@@ -0,0 +1,80 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF8,
+        Statistics
+    }
+};
+
+impl UTF8 {
+    pub(crate) const fn count_classes_from(array: &[u8], mut index: usize, stop: usize) -> (usize, usize, usize) {
+        let (mut leads, mut ascii, mut above): (usize, usize, usize) = (0_usize, 0_usize, 0_usize);
+
+        while index < stop {
+            let code: i8 = array[index] as i8;
+
+            leads += (code > -0x41) as usize; // 0xBF
+            ascii += (code > -0x01) as usize; // 0xFF
+            above += (code > -0x11) as usize; // 0xEF
+
+            index += 1_usize;
+        }
+
+        return (leads, ascii, above);
+    }
+
+    pub(crate) const fn statistics_from(classes: (usize, usize, usize)) -> Statistics {
+        let (leads, ascii, above): (usize, usize, usize) = classes;
+
+        return Statistics::new(ascii, leads - above, above - ascii, (above - ascii) * 2_usize);
+    }
+
+    #[cfg(feature = "universal")]
+    pub const fn count_chars(array: &[u8]) -> usize {
+        return UTF8::count_classes_from(array, 0_usize, array.len()).0;
+    }
+
+    #[cfg(feature = "universal")]
+    pub const fn stats(array: &[u8]) -> Statistics {
+        return UTF8::statistics_from(UTF8::count_classes_from(array, 0_usize, array.len()));
+    }
+
+    #[cfg(feature = "universal")]
+    pub const fn utf16_length_from_utf8(array: &[u8]) -> usize {
+        let (leads, ascii, above): (usize, usize, usize) = UTF8::count_classes_from(array, 0_usize, array.len());
+
+        return leads + above - ascii;
+    }
+
+    #[cfg(feature = "universal")]
+    pub const fn utf32_length_from_utf8(array: &[u8]) -> usize {
+        return UTF8::count_classes_from(array, 0_usize, array.len()).0;
+    }
+}
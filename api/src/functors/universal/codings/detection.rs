@@ -0,0 +1,152 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use core::{
+    fmt::{
+        Display,
+        Formatter,
+        Result
+    }
+};
+
+pub use crate::{
+    essence::{
+        ASCII,
+        UTF8, UTF16, UTF32,
+        Encoding,
+        Detection
+    }
+};
+
+impl Encoding {
+    pub const fn as_str(&self) -> &'static str {
+        return match self {
+            Encoding::Unknown => "unknown",
+            Encoding::ASCII => "ascii",
+            Encoding::UTF8 => "utf-8",
+            Encoding::UTF16LE => "utf-16le",
+            Encoding::UTF16BE => "utf-16be",
+            Encoding::UTF32LE => "utf-32le",
+            Encoding::UTF32BE => "utf-32be"
+        };
+    }
+}
+
+impl Display for Encoding {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        return formatter.write_str(self.as_str());
+    }
+}
+
+impl Detection {
+
+    const __BYTE_ORDER_MARKS: [(&'static [u8], Encoding); 5_usize] = [
+        (&[0xFF, 0xFE, 0x00, 0x00], Encoding::UTF32LE),
+        (&[0x00, 0x00, 0xFE, 0xFF], Encoding::UTF32BE),
+        (&[0xEF, 0xBB, 0xBF], Encoding::UTF8),
+        (&[0xFF, 0xFE], Encoding::UTF16LE),
+        (&[0xFE, 0xFF], Encoding::UTF16BE)
+    ];
+
+    pub const fn encoding(&self) -> Encoding {
+        return self.encoding;
+    }
+
+    pub const fn confidence(&self) -> f32 {
+        return self.confidence;
+    }
+
+    pub const fn bom_length(&self) -> usize {
+        return self.bom;
+    }
+
+    fn is_valid(array: &[u8], encoding: Encoding) -> bool {
+        return match encoding {
+            Encoding::Unknown => false,
+            Encoding::ASCII => ASCII::is_ascii_from_byte_array(array),
+            Encoding::UTF8 => UTF8::is_utf8(array),
+            Encoding::UTF16LE => UTF16::is_utf16_from_byte_array(array, true, true, false),
+            Encoding::UTF16BE => UTF16::is_utf16_from_byte_array(array, false, true, false),
+            Encoding::UTF32LE => UTF32::is_utf32_from_byte_array(array, true),
+            Encoding::UTF32BE => UTF32::is_utf32_from_byte_array(array, false)
+        };
+    }
+
+    const fn is_control(code: u8) -> bool {
+        return code < 0x20 && code != 0x09 && code != 0x0A && code != 0x0C && code != 0x0D && code != 0x1B;
+    }
+
+    fn count_bytes(array: &[u8]) -> ([usize; 4_usize], [usize; 2_usize], usize) {
+        let (mut zeros, mut lows, mut controls): ([usize; 4_usize], [usize; 2_usize], usize) = ([0_usize; 4_usize], [0_usize; 2_usize], 0_usize);
+
+        let mut index: usize = 0_usize;
+
+        while index < array.len() {
+            zeros[index % 4_usize] += (array[index] == 0x00) as usize;
+            lows[index % 2_usize] += (array[index] < 0x20) as usize;
+            controls += Detection::is_control(array[index]) as usize;
+
+            index += 1_usize;
+        }
+
+        return (zeros, lows, controls);
+    }
+
+    pub fn detect(array: &[u8]) -> Detection {
+        if array.is_empty() { return Detection { encoding: Encoding::Unknown, confidence: 0.0_f32, bom: 0_usize }; }
+
+        for (mark, encoding) in Detection::__BYTE_ORDER_MARKS {
+            if array.starts_with(mark) && (array.len() == mark.len() || Detection::is_valid(&array[mark.len()..], encoding)) {
+                return Detection { encoding, confidence: 1.0_f32, bom: mark.len() };
+            }
+        }
+
+        let (length, (zeros, lows, controls)): (usize, ([usize; 4_usize], [usize; 2_usize], usize)) = (array.len(), Detection::count_bytes(array));
+
+        let (text, units): (f32, f32) = (1.0_f32 - controls as f32 / length as f32, (length / 2_usize) as f32);
+
+        let candidates: [(Encoding, f32); 6_usize] = [
+            (Encoding::ASCII, text),
+            (Encoding::UTF8, 0.95_f32 * text),
+            (Encoding::UTF32LE, 0.5_f32 + 0.45_f32 * (zeros[2] + zeros[3]) as f32 / units),
+            (Encoding::UTF32BE, 0.5_f32 + 0.45_f32 * (zeros[0] + zeros[1]) as f32 / units),
+            (Encoding::UTF16LE, 0.5_f32 + 0.45_f32 * (lows[1] as f32 - lows[0] as f32) / units),
+            (Encoding::UTF16BE, 0.5_f32 + 0.45_f32 * (lows[0] as f32 - lows[1] as f32) / units)
+        ];
+
+        let mut detection: Detection = Detection { encoding: Encoding::Unknown, confidence: 0.0_f32, bom: 0_usize };
+
+        for (encoding, confidence) in candidates {
+            if confidence > detection.confidence && Detection::is_valid(array, encoding) {
+                detection = Detection { encoding, confidence, bom: 0_usize };
+            }
+        }
+
+        return detection;
+    }
+}
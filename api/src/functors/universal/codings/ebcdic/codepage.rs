@@ -0,0 +1,178 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use core::{
+    fmt::{
+        Display,
+        Formatter,
+        Result
+    }
+};
+
+pub use crate::{
+    essence::{
+        EbcdicCodepage,
+        Newline
+    }
+};
+
+use crate::{
+    functors::{
+        universal::{
+            codings::{
+                tables::{
+                    ebcdic
+                }
+            }
+        }
+    }
+};
+
+impl EbcdicCodepage {
+    pub const fn as_str(&self) -> &'static str {
+        return match self {
+            EbcdicCodepage::IBM037 => "ibm037",
+            EbcdicCodepage::IBM500 => "ibm500",
+            EbcdicCodepage::IBM1047 => "ibm1047",
+            EbcdicCodepage::IBM1140 => "ibm1140"
+        };
+    }
+
+    pub fn from_label(label: &str) -> Option<EbcdicCodepage> {
+        return match label.trim().to_ascii_lowercase().as_str() {
+            "ibm037" | "ibm-037" | "ibm37" | "cp037" | "cp37" | "ebcdic-cp-us" | "ebcdic-cp-ca" | "csibm037" => Some(EbcdicCodepage::IBM037),
+            "ibm500" | "ibm-500" | "cp500" | "ebcdic-cp-be" | "ebcdic-cp-ch" | "csibm500" => Some(EbcdicCodepage::IBM500),
+            "ibm1047" | "ibm-1047" | "cp1047" | "ebcdic-latin1" => Some(EbcdicCodepage::IBM1047),
+            "ibm1140" | "ibm-1140" | "cp1140" | "ebcdic-us-37+euro" | "csibm01140" => Some(EbcdicCodepage::IBM1140),
+            _ => None
+        };
+    }
+
+    pub(crate) const fn decode_table(&self) -> &'static [u16; 256_usize] {
+        return match self {
+            EbcdicCodepage::IBM037 => &ebcdic::IBM037,
+            EbcdicCodepage::IBM500 => &ebcdic::IBM500,
+            EbcdicCodepage::IBM1047 => &ebcdic::IBM1047,
+            EbcdicCodepage::IBM1140 => &ebcdic::IBM1140
+        };
+    }
+
+    pub(crate) const fn encode_table(&self) -> &'static [u8; 256_usize] {
+        return match self {
+            EbcdicCodepage::IBM037 => &ebcdic::IBM037_ENCODE,
+            EbcdicCodepage::IBM500 => &ebcdic::IBM500_ENCODE,
+            EbcdicCodepage::IBM1047 => &ebcdic::IBM1047_ENCODE,
+            EbcdicCodepage::IBM1140 => &ebcdic::IBM1140_ENCODE
+        };
+    }
+
+    pub(crate) const fn decode_byte(&self, code: u8, newline: Newline) -> u32 {
+        return self.decode_table()[newline.swap(code) as usize] as u32;
+    }
+
+    pub(crate) const fn encode_code_point(&self, code: u32, newline: Newline) -> Option<u8> {
+        if code == 0x20AC && matches!(self, EbcdicCodepage::IBM1140) { return Some(0x9F); }
+
+        if code > 0xFF { return None; }
+
+        let byte: u8 = self.encode_table()[code as usize];
+
+        if byte == 0x00 && code != 0x00 { return None; }
+
+        return Some(newline.swap(byte));
+    }
+
+    pub(crate) const fn translate_table(&self, newline: Newline) -> [u8; 256_usize] {
+        let mut table: [u8; 256_usize] = [0_u8; 256_usize];
+
+        let mut index: usize = 0_usize;
+
+        while index < 256_usize {
+            let code: u32 = self.decode_byte(index as u8, newline);
+
+            table[index] = if code < 0x100 { code as u8 } else { 0xFF };
+
+            index += 1_usize;
+        }
+
+        return table;
+    }
+
+    pub(crate) const fn ascii_table(&self, newline: Newline) -> [u8; 256_usize] {
+        let mut table: [u8; 256_usize] = [0_u8; 256_usize];
+
+        let mut index: usize = 0_usize;
+
+        while index < 0x80 {
+            table[index] = match self.encode_code_point(index as u32, newline) {
+                Some(byte) => byte,
+                None => 0x00
+            };
+
+            index += 1_usize;
+        }
+
+        return table;
+    }
+}
+
+impl Newline {
+    pub const fn as_str(&self) -> &'static str {
+        return match self {
+            Newline::Nel => "nel",
+            Newline::Lf => "lf"
+        };
+    }
+
+    pub fn from_label(label: &str) -> Option<Newline> {
+        return match label.trim().to_ascii_lowercase().as_str() {
+            "nel" | "nl" | "u+0085" => Some(Newline::Nel),
+            "lf" | "u+000a" | "swaplfnl" => Some(Newline::Lf),
+            _ => None
+        };
+    }
+
+    pub(crate) const fn swap(&self, byte: u8) -> u8 {
+        return match self {
+            Newline::Nel => byte,
+            Newline::Lf => match byte { 0x15 => 0x25, 0x25 => 0x15, _ => byte } // NEL <-> LF
+        };
+    }
+}
+
+impl Display for EbcdicCodepage {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        return formatter.write_str(self.as_str());
+    }
+}
+
+impl Display for Newline {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        return formatter.write_str(self.as_str());
+    }
+}
@@ -0,0 +1,173 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        EBCDIC,
+        EbcdicCodepage,
+        Newline,
+        UTF8
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+impl EBCDIC {
+    pub(crate) fn decode_utf8_from(array: &[u8], mut read: usize, stop: usize, output: &mut [u8], mut written: usize, codepage: EbcdicCodepage, newline: Newline) -> (usize, usize) {
+        let capacity: usize = output.len();
+
+        while read < stop {
+            let code: u32 = codepage.decode_byte(array[read], newline);
+
+            if written + UTF8::length_of_code_point(code) > capacity { break; }
+
+            written = UTF8::write_code_point(code, output, written);
+
+            read += 1_usize;
+        }
+
+        return (read, written);
+    }
+
+    pub(crate) fn decode_ascii_from(array: &[u8], mut read: usize, stop: usize, output: &mut [u8], mut written: usize, codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        let capacity: usize = output.len();
+
+        while read < stop && written < capacity {
+            let code: u32 = codepage.decode_byte(array[read], newline);
+
+            if code > 0x7F { return Err(EncodingError::new(read, EncodingErrorKind::Unmappable)); }
+
+            output[written] = code as u8;
+
+            read += 1_usize; written += 1_usize;
+        }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn encode_utf8_from(array: &[u8], mut read: usize, stop: usize, output: &mut [u8], mut written: usize, codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        let capacity: usize = output.len();
+
+        while read < stop && written < capacity {
+            let (code, sequence): (u32, usize) = UTF8::read_sequence(array, read, 0_usize)?;
+
+            output[written] = match codepage.encode_code_point(code, newline) {
+                Some(byte) => byte,
+                None => return Err(EncodingError::new(read, EncodingErrorKind::Unmappable))
+            };
+
+            read += sequence; written += 1_usize;
+        }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn encode_ascii_from(array: &[u8], mut read: usize, stop: usize, output: &mut [u8], mut written: usize, codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        let capacity: usize = output.len();
+
+        while read < stop && written < capacity {
+            let code: u8 = array[read];
+
+            if code > 0x7F { return Err(EncodingError::new(read, EncodingErrorKind::OutOfRange)); }
+
+            output[written] = match codepage.encode_code_point(code as u32, newline) {
+                Some(byte) => byte,
+                None => return Err(EncodingError::new(read, EncodingErrorKind::Unmappable))
+            };
+
+            read += 1_usize; written += 1_usize;
+        }
+
+        return Ok((read, written));
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn to_utf8_into(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> (usize, usize) {
+        return EBCDIC::decode_utf8_from(array, 0_usize, array.len(), output, 0_usize, codepage, newline);
+    }
+
+    pub fn to_utf8(array: &[u8], codepage: EbcdicCodepage, newline: Newline) -> Vec<u8> {
+        let mut output: Vec<u8> = vec![0_u8; array.len() * 3_usize];
+
+        let (_, written): (usize, usize) = EBCDIC::to_utf8_into(array, &mut output, codepage, newline);
+
+        output.truncate(written);
+
+        return output;
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn to_ascii_into(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        return EBCDIC::decode_ascii_from(array, 0_usize, array.len(), output, 0_usize, codepage, newline);
+    }
+
+    pub fn to_ascii(array: &[u8], codepage: EbcdicCodepage, newline: Newline) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len()];
+
+        let (_, written): (usize, usize) = EBCDIC::to_ascii_into(array, &mut output, codepage, newline)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn from_utf8_into(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        return EBCDIC::encode_utf8_from(array, 0_usize, array.len(), output, 0_usize, codepage, newline);
+    }
+
+    pub fn from_utf8(array: &[u8], codepage: EbcdicCodepage, newline: Newline) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len()];
+
+        let (_, written): (usize, usize) = EBCDIC::from_utf8_into(array, &mut output, codepage, newline)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn from_ascii_into(array: &[u8], output: &mut [u8], codepage: EbcdicCodepage, newline: Newline) -> Result<(usize, usize), EncodingError> {
+        return EBCDIC::encode_ascii_from(array, 0_usize, array.len(), output, 0_usize, codepage, newline);
+    }
+
+    pub fn from_ascii(array: &[u8], codepage: EbcdicCodepage, newline: Newline) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len()];
+
+        let (_, written): (usize, usize) = EBCDIC::from_ascii_into(array, &mut output, codepage, newline)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+}
@@ -0,0 +1,63 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        EBCDIC,
+        EbcdicCodepage,
+        Newline
+    }
+};
+
+impl EBCDIC {
+    const fn is_text_code_point(code: u32) -> bool {
+        return match code {
+            0x09 | 0x0A | 0x0C | 0x0D | 0x85 => true,  // TAB, LF, FF, CR, NEL
+            0x00..=0x1F | 0x7F..=0x9F => false,
+            _ => true
+        };
+    }
+
+    pub const fn is_ebcdic_text(array: &[u8], codepage: EbcdicCodepage) -> bool {
+        if array.is_empty() { return false; }
+
+        let (length, mut index, mut ascii): (usize, usize, usize) = (array.len(), 0_usize, 0_usize);
+
+        while index < length {
+            let code: u32 = codepage.decode_byte(array[index], Newline::Nel);
+
+            if !EBCDIC::is_text_code_point(code) { return false; }
+
+            if code < 0x80 { ascii += 1_usize; }
+
+            index += 1_usize;
+        }
+
+        return ascii >= length - length / 4_usize;
+    }
+}
@@ -0,0 +1,87 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use core::{
+    fmt::{
+        Display,
+        Formatter,
+        Result
+    }
+};
+
+pub use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind,
+        Policy
+    }
+};
+
+impl EncodingErrorKind {
+    pub const fn as_str(&self) -> &'static str {
+        return match self {
+            EncodingErrorKind::Empty => "empty sequence",
+            EncodingErrorKind::TruncatedSequence => "truncated sequence",
+            EncodingErrorKind::OverlongForm => "overlong form",
+            EncodingErrorKind::Surrogate => "surrogate",
+            EncodingErrorKind::OutOfRange => "out of range",
+            EncodingErrorKind::UnpairedLead => "unpaired lead",
+            EncodingErrorKind::UnpairedTrail => "unpaired trail",
+            EncodingErrorKind::Unmappable => "unmappable character",
+            EncodingErrorKind::InvalidSequence => "invalid sequence"
+        };
+    }
+}
+
+impl EncodingError {
+    pub(crate) const fn new(offset: usize, kind: EncodingErrorKind) -> EncodingError {
+        return EncodingError { offset, kind };
+    }
+
+    pub const fn offset(&self) -> usize {
+        return self.offset;
+    }
+
+    pub const fn kind(&self) -> EncodingErrorKind {
+        return self.kind;
+    }
+}
+
+impl Display for EncodingErrorKind {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        return formatter.write_str(self.as_str());
+    }
+}
+
+impl Display for EncodingError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        return write!(formatter, "{} at byte offset {}", self.kind.as_str(), self.offset);
+    }
+}
+
+impl std::error::Error for EncodingError {}
@@ -0,0 +1,61 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF16,
+        UTF32
+    }
+};
+
+use crate::{
+    functors::{
+        universal::{
+            codings::{
+                tables::{
+                    case_folding
+                }
+            }
+        }
+    }
+};
+
+impl UTF16 {
+    fn read_folded(array: &[u8], index: usize, endian: bool) -> (u32, usize) {
+        return match UTF16::read_code_point(array, index, array.len(), 0_usize, endian) {
+            Ok((code, sequence)) => (case_folding::fold(code), sequence),
+            Err(_) => (u32::MAX, 2_usize)
+        };
+    }
+
+    pub fn search_pattern_case_folded(array: &[u8], pattern: &[u8], endian: bool, overlapping: bool, all_matches: bool, limit: Option<usize>) -> Vec<usize> {
+        let needle: Vec<u32> = UTF32::fold_pattern(&pattern[..pattern.len() - pattern.len() % 2_usize], endian, UTF16::read_folded);
+
+        return UTF32::search_folded(&array[..UTF32::limited_units(array.len() / 2_usize, limit) * 2_usize], &needle, endian, overlapping, all_matches, UTF16::read_folded);
+    }
+}
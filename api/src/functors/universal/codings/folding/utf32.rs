@@ -0,0 +1,131 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF32
+    }
+};
+
+use crate::{
+    functors::{
+        universal::{
+            codings::{
+                tables::{
+                    case_folding
+                }
+            }
+        }
+    }
+};
+
+impl UTF32 {
+    const __FOLDING_WINDOW_UNITS: usize = 4096_usize;
+
+    pub(crate) const fn limited_units(length: usize, limit: Option<usize>) -> usize {
+        return match limit {
+            Some(limit) => if limit == 0_usize { 0_usize } else if limit >= length { length } else { length - limit },
+            None => length
+        };
+    }
+
+    pub(crate) fn fold_pattern(pattern: &[u8], endian: bool, read: fn(&[u8], usize, bool) -> (u32, usize)) -> Vec<u32> {
+        let (mut needle, mut index): (Vec<u32>, usize) = (Vec::<u32>::with_capacity(pattern.len()), 0_usize);
+
+        while index < pattern.len() {
+            let (code, sequence): (u32, usize) = read(pattern, index, endian);
+
+            needle.push(code);
+
+            index += sequence;
+        }
+
+        return needle;
+    }
+
+    pub(crate) fn search_folded(array: &[u8], needle: &[u32], endian: bool, overlapping: bool, all_matches: bool, read: fn(&[u8], usize, bool) -> (u32, usize)) -> Vec<usize> {
+        let mut search_result: Vec<usize> = Vec::<usize>::new();
+
+        if needle.is_empty() || needle.contains(&u32::MAX) { return search_result; }
+
+        let capacity: usize = UTF32::__FOLDING_WINDOW_UNITS + needle.len() - 1_usize;
+
+        let (mut haystack, mut offsets): (Vec<u32>, Vec<usize>) = (Vec::<u32>::with_capacity(capacity), Vec::<usize>::with_capacity(capacity));
+
+        let pattern: &[u8] = unsafe { core::slice::from_raw_parts::<u8>(needle.as_ptr() as *const u8, size_of_val(needle)) };
+
+        let (mut index, mut base, mut next): (usize, usize, usize) = (0_usize, 0_usize, 0_usize);
+
+        loop {
+            while haystack.len() < capacity && index < array.len() {
+                let (code, sequence): (u32, usize) = read(array, index, endian);
+
+                haystack.push(code); offsets.push(index);
+
+                index += sequence;
+            }
+
+            let window: &[u8] = unsafe { core::slice::from_raw_parts::<u8>(haystack.as_ptr() as *const u8, size_of_val(haystack.as_slice())) };
+
+            for candidate in UTF32::search_pattern(window, pattern, true, all_matches, None) {
+                let position: usize = base + candidate / size_of::<u32>();
+
+                if position < next { continue; }
+
+                search_result.push(offsets[position - base]);
+
+                if !all_matches { return search_result; }
+
+                if !overlapping { next = position + needle.len(); }
+            }
+
+            if index >= array.len() || haystack.len() < needle.len() { break; }
+
+            let consumed: usize = haystack.len() - (needle.len() - 1_usize);
+
+            haystack.drain(..consumed); offsets.drain(..consumed);
+
+            base += consumed;
+        }
+
+        return search_result;
+    }
+
+    fn read_folded(array: &[u8], index: usize, endian: bool) -> (u32, usize) {
+        return match UTF32::read_code_point(array, index, 0_usize, endian) {
+            Ok(code) => (case_folding::fold(code), 4_usize),
+            Err(_) => (u32::MAX, 4_usize)
+        };
+    }
+
+    pub fn search_pattern_case_folded(array: &[u8], pattern: &[u8], endian: bool, overlapping: bool, all_matches: bool, limit: Option<usize>) -> Vec<usize> {
+        let needle: Vec<u32> = UTF32::fold_pattern(&pattern[..pattern.len() - pattern.len() % 4_usize], endian, UTF32::read_folded);
+
+        return UTF32::search_folded(&array[..UTF32::limited_units(array.len() / 4_usize, limit) * 4_usize], &needle, endian, overlapping, all_matches, UTF32::read_folded);
+    }
+}
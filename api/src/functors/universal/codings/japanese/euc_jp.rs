@@ -0,0 +1,282 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        EUCJP,
+        ASCII,
+        UTF8,
+        UTF16
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+use crate::{
+    functors::{
+        universal::{
+            codings::{
+                tables::{
+                    jis0208,
+                    jis0212
+                }
+            }
+        }
+    }
+};
+
+impl EUCJP {
+    const fn is_trail(code: u8) -> bool {
+        return code >= 0xA1 && code <= 0xFE;
+    }
+
+    pub(crate) const fn read_sequence(array: &[u8], index: usize) -> Result<(u32, usize), EncodingError> {
+        let (lead, length): (u8, usize) = (array[index], array.len());
+
+        if lead < 0x80 { return Ok((lead as u32, 1_usize)); }
+
+        if lead != 0x8E && lead != 0x8F && !EUCJP::is_trail(lead) { return Err(EncodingError::new(index, EncodingErrorKind::InvalidSequence)); }
+
+        if index + 1_usize >= length { return Err(EncodingError::new(index, EncodingErrorKind::TruncatedSequence)); }
+
+        let trail: u8 = array[index + 1_usize];
+
+        if lead == 0x8E {
+            if trail < 0xA1 || trail > 0xDF { return Err(EncodingError::new(index, EncodingErrorKind::InvalidSequence)); }
+
+            return Ok((0xFF61 + (trail - 0xA1) as u32, 2_usize));
+        }
+
+        if !EUCJP::is_trail(trail) { return Err(EncodingError::new(index, EncodingErrorKind::InvalidSequence)); }
+
+        if lead == 0x8F {
+            if index + 2_usize >= length { return Err(EncodingError::new(index, EncodingErrorKind::TruncatedSequence)); }
+
+            let last: u8 = array[index + 2_usize];
+
+            if !EUCJP::is_trail(last) { return Err(EncodingError::new(index, EncodingErrorKind::InvalidSequence)); }
+
+            let code: u32 = jis0212::code_point((trail - 0xA1) as usize * 94_usize + (last - 0xA1) as usize);
+
+            if code == 0 { return Err(EncodingError::new(index, EncodingErrorKind::Unmappable)); }
+
+            return Ok((code, 3_usize));
+        }
+
+        let code: u32 = jis0208::code_point((lead - 0xA1) as usize * 94_usize + (trail - 0xA1) as usize);
+
+        if code == 0 { return Err(EncodingError::new(index, EncodingErrorKind::Unmappable)); }
+
+        return Ok((code, 2_usize));
+    }
+
+    pub(crate) const fn next_boundary(array: &[u8], index: usize) -> usize {
+        return match EUCJP::read_sequence(array, index) {
+            Ok((_, sequence)) => index + sequence,
+            Err(error) => if matches!(error.kind, EncodingErrorKind::Unmappable) { index + if array[index] == 0x8F { 3_usize } else { 2_usize } } else { index + 1_usize }
+        };
+    }
+
+    pub(crate) fn encode_code_point(code: u32) -> Option<([u8; 2_usize], usize)> {
+        if code < 0x80 { return Some(([code as u8, 0x00], 1_usize)); }
+
+        if code == 0xA5 { return Some(([0x5C, 0x00], 1_usize)); }
+
+        if code == 0x203E { return Some(([0x7E, 0x00], 1_usize)); }
+
+        if (0xFF61..=0xFF9F).contains(&code) { return Some(([0x8E, (code - 0xFF61 + 0xA1) as u8], 2_usize)); }
+
+        let pointer: usize = jis0208::pointer(if code == 0x2212 { 0xFF0D } else { code })?;
+
+        return Some(([(pointer / 94_usize + 0xA1) as u8, (pointer % 94_usize + 0xA1) as u8], 2_usize));
+    }
+
+    pub(crate) const fn locate_invalid_euc_jp(array: &[u8], mut index: usize) -> Result<(), EncodingError> {
+        let length: usize = array.len();
+
+        while index < length {
+            match EUCJP::read_sequence(array, index) {
+                Ok((_, sequence)) => index += sequence,
+                Err(error) => return Err(error)
+            }
+        }
+
+        return Ok(());
+    }
+
+    pub(crate) fn decode_utf8_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        while read < length {
+            let (code, sequence): (u32, usize) = EUCJP::read_sequence(array, read)?;
+
+            if written + UTF8::length_of_code_point(code) > capacity { break; }
+
+            written = UTF8::write_code_point(code, output, written);
+
+            read += sequence;
+        }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn decode_utf16_from(array: &[u8], mut read: usize, output: &mut [u16], mut written: usize, endian: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        while read < length && written < capacity {
+            let (code, sequence): (u32, usize) = EUCJP::read_sequence(array, read)?;
+
+            written = UTF16::write_code_point(code, output, written, endian);
+
+            read += sequence;
+        }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn encode_utf8_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        while read < length {
+            let (code, sequence): (u32, usize) = UTF8::read_sequence(array, read, 0_usize)?;
+
+            let (bytes, size): ([u8; 2_usize], usize) = match EUCJP::encode_code_point(code) {
+                Some(encoded) => encoded,
+                None => return Err(EncodingError::new(read, EncodingErrorKind::Unmappable))
+            };
+
+            if written + size > capacity { break; }
+
+            output[written..written + size].copy_from_slice(&bytes[..size]);
+
+            read += sequence; written += size;
+        }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn encode_utf16_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize, endian: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 2_usize, output.len());
+
+        while read < length {
+            let (code, sequence): (u32, usize) = UTF16::read_code_point(array, read, length, 0_usize, endian)?;
+
+            let (bytes, size): ([u8; 2_usize], usize) = match EUCJP::encode_code_point(code) {
+                Some(encoded) => encoded,
+                None => return Err(EncodingError::new(read, EncodingErrorKind::Unmappable))
+            };
+
+            if written + size > capacity { break; }
+
+            output[written..written + size].copy_from_slice(&bytes[..size]);
+
+            read += sequence; written += size;
+        }
+
+        if read == length && length != array.len() { return Err(EncodingError::new(length, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok((read, written));
+    }
+
+    pub const fn is_euc_jp(array: &[u8]) -> bool {
+        if array.is_empty() { return false; }
+
+        return EUCJP::locate_invalid_euc_jp(array, 0_usize).is_ok();
+    }
+
+    pub const fn validate_euc_jp(array: &[u8]) -> Result<(), EncodingError> {
+        if array.is_empty() { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        return EUCJP::locate_invalid_euc_jp(array, 0_usize);
+    }
+
+    pub fn to_utf8_into(array: &[u8], output: &mut [u8]) -> Result<(usize, usize), EncodingError> {
+        return EUCJP::decode_utf8_from(array, 0_usize, output, 0_usize);
+    }
+
+    pub fn to_utf8(array: &[u8]) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len() * 3_usize];
+
+        let (_, written): (usize, usize) = EUCJP::to_utf8_into(array, &mut output)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn to_utf16_into(array: &[u8], output: &mut [u16], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return EUCJP::decode_utf16_from(array, 0_usize, output, 0_usize, endian);
+    }
+
+    pub fn to_utf16(array: &[u8], endian: bool) -> Result<Vec<u16>, EncodingError> {
+        let mut output: Vec<u16> = vec![0_u16; array.len()];
+
+        let (_, written): (usize, usize) = EUCJP::to_utf16_into(array, &mut output, endian)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn from_utf8_into(array: &[u8], output: &mut [u8]) -> Result<(usize, usize), EncodingError> {
+        return EUCJP::encode_utf8_from(array, 0_usize, output, 0_usize);
+    }
+
+    pub fn from_utf8(array: &[u8]) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len()];
+
+        let (_, written): (usize, usize) = EUCJP::from_utf8_into(array, &mut output)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn from_utf16_into(array: &[u8], output: &mut [u8], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return EUCJP::encode_utf16_from(array, 0_usize, output, 0_usize, endian);
+    }
+
+    pub fn from_utf16(array: &[u8], endian: bool) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len()];
+
+        let (_, written): (usize, usize) = EUCJP::from_utf16_into(array, &mut output, endian)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn search_pattern(array: &[u8], pattern: &[u8], overlapping: bool, all_matches: bool, limit: Option<usize>) -> Vec<usize> {
+        return ASCII::search_pattern_aligned(array, pattern, overlapping, all_matches, limit, (), |array: &[u8], index: usize, _: ()| (EUCJP::next_boundary(array, index), ()));
+    }
+}
@@ -0,0 +1,354 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        ISO2022JP,
+        ASCII,
+        UTF8,
+        UTF16
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+use crate::{
+    functors::{
+        universal::{
+            codings::{
+                tables::{
+                    jis0208
+                }
+            }
+        }
+    }
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Mode {
+    Ascii,
+    Roman,
+    Katakana,
+    JIS0208
+}
+
+impl ISO2022JP {
+    const fn escape(mode: Mode) -> [u8; 3_usize] {
+        return match mode {
+            Mode::Ascii => [0x1B, 0x28, 0x42], // ESC ( B
+            Mode::Roman => [0x1B, 0x28, 0x4A], // ESC ( J
+            Mode::Katakana => [0x1B, 0x28, 0x49], // ESC ( I
+            Mode::JIS0208 => [0x1B, 0x24, 0x42] // ESC $ B
+        };
+    }
+
+    pub(crate) const fn read_sequence(array: &[u8], index: usize, mode: Mode) -> Result<(Option<u32>, usize, Mode), EncodingError> {
+        let (code, length): (u8, usize) = (array[index], array.len());
+
+        if code == 0x1B {
+            if index + 2_usize >= length { return Err(EncodingError::new(index, EncodingErrorKind::TruncatedSequence)); }
+
+            let next: Mode = match (array[index + 1_usize], array[index + 2_usize]) {
+                (0x28, 0x42) => Mode::Ascii,
+                (0x28, 0x4A) => Mode::Roman,
+                (0x28, 0x49) => Mode::Katakana,
+                (0x24, 0x40) | (0x24, 0x42) => Mode::JIS0208,
+                _ => return Err(EncodingError::new(index, EncodingErrorKind::InvalidSequence))
+            };
+
+            return Ok((None, 3_usize, next));
+        }
+
+        return match mode {
+            Mode::Ascii | Mode::Roman => {
+                if code > 0x7F || code == 0x0E || code == 0x0F { return Err(EncodingError::new(index, EncodingErrorKind::InvalidSequence)); }
+
+                if matches!(mode, Mode::Roman) && code == 0x5C { return Ok((Some(0xA5), 1_usize, mode)); }
+
+                if matches!(mode, Mode::Roman) && code == 0x7E { return Ok((Some(0x203E), 1_usize, mode)); }
+
+                Ok((Some(code as u32), 1_usize, mode))
+            },
+            Mode::Katakana => {
+                if code < 0x21 || code > 0x5F { return Err(EncodingError::new(index, EncodingErrorKind::InvalidSequence)); }
+
+                Ok((Some(0xFF61 + (code - 0x21) as u32), 1_usize, mode))
+            },
+            Mode::JIS0208 => {
+                if code < 0x21 || code > 0x7E { return Err(EncodingError::new(index, EncodingErrorKind::InvalidSequence)); }
+
+                if index + 1_usize >= length { return Err(EncodingError::new(index, EncodingErrorKind::TruncatedSequence)); }
+
+                let trail: u8 = array[index + 1_usize];
+
+                if trail < 0x21 || trail > 0x7E { return Err(EncodingError::new(index, EncodingErrorKind::InvalidSequence)); }
+
+                let code: u32 = jis0208::code_point((code - 0x21) as usize * 94_usize + (trail - 0x21) as usize);
+
+                if code == 0 { return Err(EncodingError::new(index, EncodingErrorKind::Unmappable)); }
+
+                Ok((Some(code), 2_usize, mode))
+            }
+        };
+    }
+
+    pub(crate) const fn next_boundary(array: &[u8], index: usize, mode: Mode) -> (usize, Mode) {
+        return match ISO2022JP::read_sequence(array, index, mode) {
+            Ok((_, sequence, next)) => (index + sequence, next),
+            Err(_) => (index + 1_usize, mode)
+        };
+    }
+
+    pub(crate) fn encode_code_point(code: u32, mode: Mode) -> Option<(Mode, [u8; 2_usize], usize)> {
+        if code < 0x80 {
+            if code == 0x0E || code == 0x0F || code == 0x1B { return None; }
+
+            if matches!(mode, Mode::Roman) && code != 0x5C && code != 0x7E { return Some((Mode::Roman, [code as u8, 0x00], 1_usize)); }
+
+            return Some((Mode::Ascii, [code as u8, 0x00], 1_usize));
+        }
+
+        if code == 0xA5 { return Some((Mode::Roman, [0x5C, 0x00], 1_usize)); }
+
+        if code == 0x203E { return Some((Mode::Roman, [0x7E, 0x00], 1_usize)); }
+
+        let code: u32 = if (0xFF61..=0xFF9F).contains(&code) { jis0208::katakana(code) } else if code == 0x2212 { 0xFF0D } else { code };
+
+        let pointer: usize = jis0208::pointer(code)?;
+
+        return Some((Mode::JIS0208, [(pointer / 94_usize + 0x21) as u8, (pointer % 94_usize + 0x21) as u8], 2_usize));
+    }
+
+    pub(crate) const fn locate_invalid_iso2022_jp(array: &[u8], mut index: usize) -> Result<(), EncodingError> {
+        let (length, mut mode, mut escaped): (usize, Mode, bool) = (array.len(), Mode::Ascii, false);
+
+        while index < length {
+            match ISO2022JP::read_sequence(array, index, mode) {
+                Ok((code, sequence, next)) => {
+                    if code.is_none() && escaped { return Err(EncodingError::new(index, EncodingErrorKind::InvalidSequence)); }
+
+                    escaped = code.is_none(); mode = next; index += sequence;
+                },
+                Err(error) => return Err(error)
+            }
+        }
+
+        return Ok(());
+    }
+
+    pub(crate) fn decode_utf8_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut mode, mut escaped): (Mode, bool) = (Mode::Ascii, false);
+
+        while read < length {
+            let (code, sequence, next): (Option<u32>, usize, Mode) = ISO2022JP::read_sequence(array, read, mode)?;
+
+            match code {
+                Some(code) => {
+                    if written + UTF8::length_of_code_point(code) > capacity { break; }
+
+                    written = UTF8::write_code_point(code, output, written);
+                },
+                None => if escaped { return Err(EncodingError::new(read, EncodingErrorKind::InvalidSequence)); }
+            }
+
+            escaped = code.is_none(); mode = next; read += sequence;
+        }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn decode_utf16_from(array: &[u8], mut read: usize, output: &mut [u16], mut written: usize, endian: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        let (mut mode, mut escaped): (Mode, bool) = (Mode::Ascii, false);
+
+        while read < length {
+            let (code, sequence, next): (Option<u32>, usize, Mode) = ISO2022JP::read_sequence(array, read, mode)?;
+
+            match code {
+                Some(code) => {
+                    if written >= capacity { break; }
+
+                    written = UTF16::write_code_point(code, output, written, endian);
+                },
+                None => if escaped { return Err(EncodingError::new(read, EncodingErrorKind::InvalidSequence)); }
+            }
+
+            escaped = code.is_none(); mode = next; read += sequence;
+        }
+
+        return Ok((read, written));
+    }
+
+    fn write_encoded(encoded: (Mode, [u8; 2_usize], usize), mode: Mode, output: &mut [u8], mut written: usize) -> Option<usize> {
+        let (next, bytes, size): (Mode, [u8; 2_usize], usize) = encoded;
+
+        let (switch, tail): (usize, usize) = (if next != mode { 3_usize } else { 0_usize }, if next != Mode::Ascii { 3_usize } else { 0_usize });
+
+        if written + switch + size + tail > output.len() { return None; }
+
+        if switch != 0_usize { output[written..written + 3_usize].copy_from_slice(&ISO2022JP::escape(next)); written += 3_usize; }
+
+        output[written..written + size].copy_from_slice(&bytes[..size]);
+
+        return Some(written + size);
+    }
+
+    fn write_reset(mode: Mode, output: &mut [u8], written: usize) -> usize {
+        if mode == Mode::Ascii { return written; }
+
+        output[written..written + 3_usize].copy_from_slice(&ISO2022JP::escape(Mode::Ascii));
+
+        return written + 3_usize;
+    }
+
+    pub(crate) fn encode_utf8_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize) -> Result<(usize, usize), EncodingError> {
+        let (length, mut mode): (usize, Mode) = (array.len(), Mode::Ascii);
+
+        while read < length {
+            let (code, sequence): (u32, usize) = UTF8::read_sequence(array, read, 0_usize)?;
+
+            let encoded: (Mode, [u8; 2_usize], usize) = match ISO2022JP::encode_code_point(code, mode) {
+                Some(encoded) => encoded,
+                None => return Err(EncodingError::new(read, EncodingErrorKind::Unmappable))
+            };
+
+            written = match ISO2022JP::write_encoded(encoded, mode, output, written) {
+                Some(written) => written,
+                None => break
+            };
+
+            mode = encoded.0; read += sequence;
+        }
+
+        return Ok((read, ISO2022JP::write_reset(mode, output, written)));
+    }
+
+    pub(crate) fn encode_utf16_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize, endian: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, mut mode): (usize, Mode) = (array.len() - array.len() % 2_usize, Mode::Ascii);
+
+        while read < length {
+            let (code, sequence): (u32, usize) = UTF16::read_code_point(array, read, length, 0_usize, endian)?;
+
+            let encoded: (Mode, [u8; 2_usize], usize) = match ISO2022JP::encode_code_point(code, mode) {
+                Some(encoded) => encoded,
+                None => return Err(EncodingError::new(read, EncodingErrorKind::Unmappable))
+            };
+
+            written = match ISO2022JP::write_encoded(encoded, mode, output, written) {
+                Some(written) => written,
+                None => break
+            };
+
+            mode = encoded.0; read += sequence;
+        }
+
+        if read == length && length != array.len() { return Err(EncodingError::new(length, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok((read, ISO2022JP::write_reset(mode, output, written)));
+    }
+
+    pub const fn is_iso2022_jp(array: &[u8]) -> bool {
+        if array.is_empty() { return false; }
+
+        return ISO2022JP::locate_invalid_iso2022_jp(array, 0_usize).is_ok();
+    }
+
+    pub const fn validate_iso2022_jp(array: &[u8]) -> Result<(), EncodingError> {
+        if array.is_empty() { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        return ISO2022JP::locate_invalid_iso2022_jp(array, 0_usize);
+    }
+
+    pub fn to_utf8_into(array: &[u8], output: &mut [u8]) -> Result<(usize, usize), EncodingError> {
+        return ISO2022JP::decode_utf8_from(array, 0_usize, output, 0_usize);
+    }
+
+    pub fn to_utf8(array: &[u8]) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len() * 3_usize];
+
+        let (_, written): (usize, usize) = ISO2022JP::to_utf8_into(array, &mut output)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn to_utf16_into(array: &[u8], output: &mut [u16], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return ISO2022JP::decode_utf16_from(array, 0_usize, output, 0_usize, endian);
+    }
+
+    pub fn to_utf16(array: &[u8], endian: bool) -> Result<Vec<u16>, EncodingError> {
+        let mut output: Vec<u16> = vec![0_u16; array.len()];
+
+        let (_, written): (usize, usize) = ISO2022JP::to_utf16_into(array, &mut output, endian)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn from_utf8_into(array: &[u8], output: &mut [u8]) -> Result<(usize, usize), EncodingError> {
+        return ISO2022JP::encode_utf8_from(array, 0_usize, output, 0_usize);
+    }
+
+    pub fn from_utf8(array: &[u8]) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len() * 4_usize + 3_usize];
+
+        let (_, written): (usize, usize) = ISO2022JP::from_utf8_into(array, &mut output)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn from_utf16_into(array: &[u8], output: &mut [u8], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return ISO2022JP::encode_utf16_from(array, 0_usize, output, 0_usize, endian);
+    }
+
+    pub fn from_utf16(array: &[u8], endian: bool) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len() * 3_usize + 3_usize];
+
+        let (_, written): (usize, usize) = ISO2022JP::from_utf16_into(array, &mut output, endian)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn search_pattern(array: &[u8], pattern: &[u8], overlapping: bool, all_matches: bool, limit: Option<usize>) -> Vec<usize> {
+        return ASCII::search_pattern_aligned(array, pattern, overlapping, all_matches, limit, Mode::Ascii, ISO2022JP::next_boundary);
+    }
+}
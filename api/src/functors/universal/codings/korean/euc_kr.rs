@@ -0,0 +1,270 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        EUCKR,
+        ASCII,
+        UTF8,
+        UTF16
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind,
+        Policy
+    }
+};
+
+use crate::{
+    functors::{
+        universal::{
+            codings::{
+                tables::{
+                    euc_kr
+                }
+            }
+        }
+    }
+};
+
+impl EUCKR {
+    const fn is_lead(code: u8) -> bool {
+        return code >= 0x81 && code <= 0xFE;
+    }
+
+    pub(crate) const fn read_sequence(array: &[u8], index: usize) -> Result<(u32, usize), EncodingError> {
+        let (lead, length): (u8, usize) = (array[index], array.len());
+
+        if lead < 0x80 { return Ok((lead as u32, 1_usize)); }
+
+        if !EUCKR::is_lead(lead) { return Err(EncodingError::new(index, EncodingErrorKind::InvalidSequence)); }
+
+        if index + 1_usize >= length { return Err(EncodingError::new(index, EncodingErrorKind::TruncatedSequence)); }
+
+        let trail: u8 = array[index + 1_usize];
+
+        if trail < 0x41 || trail == 0xFF { return Err(EncodingError::new(index, EncodingErrorKind::InvalidSequence)); }
+
+        let code: u32 = euc_kr::code_point((lead - 0x81) as usize * 190_usize + (trail - 0x41) as usize);
+
+        if code == 0 { return Err(EncodingError::new(index, EncodingErrorKind::Unmappable)); }
+
+        return Ok((code, 2_usize));
+    }
+
+    pub(crate) const fn next_boundary(array: &[u8], index: usize) -> usize {
+        return match EUCKR::read_sequence(array, index) {
+            Ok((_, sequence)) => index + sequence,
+            Err(_) => if EUCKR::is_lead(array[index]) && index + 1_usize < array.len() && array[index + 1_usize] >= 0x80 { index + 2_usize } else { index + 1_usize }
+        };
+    }
+
+    const fn decode_code_point(array: &[u8], read: usize, policy: Policy) -> Result<(u32, usize), EncodingError> {
+        return match EUCKR::read_sequence(array, read) {
+            Ok(decoded) => Ok(decoded),
+            Err(error) => match policy {
+                Policy::Error => Err(error),
+                Policy::Replacement => Ok((0xFFFD, EUCKR::next_boundary(array, read) - read))
+            }
+        };
+    }
+
+    pub(crate) fn encode_code_point(code: u32) -> Option<([u8; 2_usize], usize)> {
+        if code < 0x80 { return Some(([code as u8, 0x00], 1_usize)); }
+
+        let pointer: usize = euc_kr::pointer(code)?;
+
+        return Some(([(pointer / 190_usize + 0x81) as u8, (pointer % 190_usize + 0x41) as u8], 2_usize));
+    }
+
+    fn encode_code_point_with(code: u32, read: usize, policy: Policy) -> Result<([u8; 2_usize], usize), EncodingError> {
+        return match EUCKR::encode_code_point(code) {
+            Some(encoded) => Ok(encoded),
+            None => match policy {
+                Policy::Error => Err(EncodingError::new(read, EncodingErrorKind::Unmappable)),
+                Policy::Replacement => Ok(([0x3F, 0x00], 1_usize)) // ?
+            }
+        };
+    }
+
+    pub(crate) const fn locate_invalid_euc_kr(array: &[u8], mut index: usize) -> Result<(), EncodingError> {
+        let length: usize = array.len();
+
+        while index < length {
+            match EUCKR::read_sequence(array, index) {
+                Ok((_, sequence)) => index += sequence,
+                Err(error) => return Err(error)
+            }
+        }
+
+        return Ok(());
+    }
+
+    pub(crate) fn decode_utf8_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        while read < length {
+            let (code, sequence): (u32, usize) = EUCKR::decode_code_point(array, read, policy)?;
+
+            if written + UTF8::length_of_code_point(code) > capacity { break; }
+
+            written = UTF8::write_code_point(code, output, written);
+
+            read += sequence;
+        }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn decode_utf16_from(array: &[u8], mut read: usize, output: &mut [u16], mut written: usize, endian: bool, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        while read < length && written < capacity {
+            let (code, sequence): (u32, usize) = EUCKR::decode_code_point(array, read, policy)?;
+
+            output[written] = UTF16::store_code_unit(code as u16, endian);
+
+            read += sequence; written += 1_usize;
+        }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn encode_utf8_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        while read < length {
+            let (code, sequence): (u32, usize) = UTF8::read_sequence(array, read, 0_usize)?;
+
+            let (bytes, size): ([u8; 2_usize], usize) = EUCKR::encode_code_point_with(code, read, policy)?;
+
+            if written + size > capacity { break; }
+
+            output[written..written + size].copy_from_slice(&bytes[..size]);
+
+            read += sequence; written += size;
+        }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn encode_utf16_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize, endian: bool, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 2_usize, output.len());
+
+        while read < length {
+            let (code, sequence): (u32, usize) = UTF16::read_code_point(array, read, length, 0_usize, endian)?;
+
+            let (bytes, size): ([u8; 2_usize], usize) = EUCKR::encode_code_point_with(code, read, policy)?;
+
+            if written + size > capacity { break; }
+
+            output[written..written + size].copy_from_slice(&bytes[..size]);
+
+            read += sequence; written += size;
+        }
+
+        if read == length && length != array.len() { return Err(EncodingError::new(length, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok((read, written));
+    }
+
+    pub const fn is_euc_kr(array: &[u8]) -> bool {
+        if array.is_empty() { return false; }
+
+        return EUCKR::locate_invalid_euc_kr(array, 0_usize).is_ok();
+    }
+
+    pub const fn validate_euc_kr(array: &[u8]) -> Result<(), EncodingError> {
+        if array.is_empty() { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        return EUCKR::locate_invalid_euc_kr(array, 0_usize);
+    }
+
+    pub fn to_utf8_into(array: &[u8], output: &mut [u8], policy: Policy) -> Result<(usize, usize), EncodingError> {
+        return EUCKR::decode_utf8_from(array, 0_usize, output, 0_usize, policy);
+    }
+
+    pub fn to_utf8(array: &[u8], policy: Policy) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len() * 3_usize];
+
+        let (_, written): (usize, usize) = EUCKR::to_utf8_into(array, &mut output, policy)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn to_utf16_into(array: &[u8], output: &mut [u16], endian: bool, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        return EUCKR::decode_utf16_from(array, 0_usize, output, 0_usize, endian, policy);
+    }
+
+    pub fn to_utf16(array: &[u8], endian: bool, policy: Policy) -> Result<Vec<u16>, EncodingError> {
+        let mut output: Vec<u16> = vec![0_u16; array.len()];
+
+        let (_, written): (usize, usize) = EUCKR::to_utf16_into(array, &mut output, endian, policy)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn from_utf8_into(array: &[u8], output: &mut [u8], policy: Policy) -> Result<(usize, usize), EncodingError> {
+        return EUCKR::encode_utf8_from(array, 0_usize, output, 0_usize, policy);
+    }
+
+    pub fn from_utf8(array: &[u8], policy: Policy) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len()];
+
+        let (_, written): (usize, usize) = EUCKR::from_utf8_into(array, &mut output, policy)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn from_utf16_into(array: &[u8], output: &mut [u8], endian: bool, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        return EUCKR::encode_utf16_from(array, 0_usize, output, 0_usize, endian, policy);
+    }
+
+    pub fn from_utf16(array: &[u8], endian: bool, policy: Policy) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len()];
+
+        let (_, written): (usize, usize) = EUCKR::from_utf16_into(array, &mut output, endian, policy)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn search_pattern(array: &[u8], pattern: &[u8], overlapping: bool, all_matches: bool, limit: Option<usize>) -> Vec<usize> {
+        return ASCII::search_pattern_aligned(array, pattern, overlapping, all_matches, limit, (), |array: &[u8], index: usize, _: ()| (EUCKR::next_boundary(array, index), ()));
+    }
+}
@@ -0,0 +1,92 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF16
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+impl UTF16 {
+    pub(crate) const fn read_code_unit(array: &[u8], index: usize, endian: bool) -> u16 {
+        return if endian { u16::from_le_bytes([array[index], array[index + 1_usize]]) } else { u16::from_be_bytes([array[index], array[index + 1_usize]]) };
+    }
+
+    pub(crate) const fn read_code_point(array: &[u8], index: usize, length: usize, base: usize, endian: bool) -> Result<(u32, usize), EncodingError> {
+        let code: u16 = UTF16::read_code_unit(array, index, endian);
+
+        if (code & 0xF800) != 0xD800 { return Ok((code as u32, 2_usize)); }
+        else if (code & 0xFC00) == 0xDC00 { return Err(EncodingError::new(base + index, EncodingErrorKind::UnpairedTrail)); }
+        else if index + 2_usize >= length { return Err(EncodingError::new(base + index, EncodingErrorKind::TruncatedSequence)); }
+
+        let following: u16 = UTF16::read_code_unit(array, index + 2_usize, endian);
+
+        if (following & 0xFC00) != 0xDC00 { return Err(EncodingError::new(base + index, EncodingErrorKind::UnpairedLead)); }
+
+        return Ok((0x10000 + ((((code & 0x03FF) as u32) << 10) | (following & 0x03FF) as u32), 4_usize));
+    }
+
+    pub(crate) const fn locate_invalid_utf16(array: &[u8], base: usize, endian: bool, omp: bool, only: bool) -> Result<(), EncodingError> {
+        let length: usize = array.len();
+
+        let (mut index, aligned_length): (usize, usize) = (0_usize, if omp && only { length - length % 4_usize } else { length - length % 2_usize });
+
+        while index < aligned_length {
+            let code: u16 = UTF16::read_code_unit(array, index, endian);
+
+            if only && !omp {
+                if (code & 0xF800) == 0xD800 { return Err(EncodingError::new(base + index, EncodingErrorKind::Surrogate)); }
+
+                index += 2_usize;
+            } else if (code & 0xF800) != 0xD800 {
+                if only { return Err(EncodingError::new(base + index, EncodingErrorKind::OutOfRange)); }
+
+                index += 2_usize;
+            } else if (code & 0xFC00) == 0xDC00 {
+                return Err(EncodingError::new(base + index, EncodingErrorKind::UnpairedTrail));
+            } else if index + 2_usize >= aligned_length {
+                return Err(EncodingError::new(base + index, EncodingErrorKind::TruncatedSequence));
+            } else {
+                if (UTF16::read_code_unit(array, index + 2_usize, endian) & 0xFC00) != 0xDC00 { return Err(EncodingError::new(base + index, EncodingErrorKind::UnpairedLead)); }
+
+                index += 4_usize;
+            }
+        }
+
+        if aligned_length != length { return Err(EncodingError::new(base + aligned_length, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok(());
+    }
+}
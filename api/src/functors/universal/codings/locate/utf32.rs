@@ -0,0 +1,69 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF32
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+impl UTF32 {
+    pub(crate) const fn read_code_point(array: &[u8], index: usize, base: usize, endian: bool) -> Result<u32, EncodingError> {
+        let bytes: [u8; 4_usize] = [array[index], array[index + 1_usize], array[index + 2_usize], array[index + 3_usize]];
+
+        let code: u32 = if endian { u32::from_le_bytes(bytes) } else { u32::from_be_bytes(bytes) };
+
+        if code > 0x0010FFFF { return Err(EncodingError::new(base + index, EncodingErrorKind::OutOfRange)); }
+        else if (code & 0xFFFFF800) == 0x0000D800 { return Err(EncodingError::new(base + index, EncodingErrorKind::Surrogate)); }
+
+        return Ok(code);
+    }
+
+    pub(crate) const fn locate_invalid_utf32(array: &[u8], base: usize, endian: bool) -> Result<(), EncodingError> {
+        let length: usize = array.len();
+
+        let (mut index, aligned_length): (usize, usize) = (0_usize, length - length % 4_usize);
+
+        while index < aligned_length {
+            if let Err(error) = UTF32::read_code_point(array, index, base, endian) { return Err(error); }
+
+            index += 4_usize;
+        }
+
+        if aligned_length != length { return Err(EncodingError::new(base + aligned_length, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok(());
+    }
+}
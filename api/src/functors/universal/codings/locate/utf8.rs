@@ -0,0 +1,103 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF8
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+impl UTF8 {
+    pub(crate) const fn read_sequence(array: &[u8], index: usize, base: usize) -> Result<(u32, usize), EncodingError> {
+        let (lead, length): (u8, usize) = (array[index], array.len());
+
+        if lead < 0x80 { return Ok((lead as u32, 1_usize)); }
+
+        let (following, lower, upper): (usize, u8, u8) = if lead < 0xC0 {
+            return Err(EncodingError::new(base + index, EncodingErrorKind::UnpairedTrail));
+        } else if lead < 0xC2 {
+            return Err(EncodingError::new(base + index, EncodingErrorKind::OverlongForm));
+        } else if lead < 0xE0 {
+            (1_usize, 0x80, 0xBF)
+        } else if lead == 0xE0 {
+            (2_usize, 0xA0, 0xBF)
+        } else if lead == 0xED {
+            (2_usize, 0x80, 0x9F)
+        } else if lead < 0xF0 {
+            (2_usize, 0x80, 0xBF)
+        } else if lead == 0xF0 {
+            (3_usize, 0x90, 0xBF)
+        } else if lead < 0xF4 {
+            (3_usize, 0x80, 0xBF)
+        } else if lead == 0xF4 {
+            (3_usize, 0x80, 0x8F)
+        } else {
+            return Err(EncodingError::new(base + index, EncodingErrorKind::OutOfRange));
+        };
+
+        if index + 1_usize >= length { return Err(EncodingError::new(base + index, EncodingErrorKind::TruncatedSequence)); }
+
+        let second: u8 = array[index + 1_usize];
+
+        if UTF8::is_not_following(second) { return Err(EncodingError::new(base + index, EncodingErrorKind::UnpairedLead)); }
+        else if second < lower { return Err(EncodingError::new(base + index, EncodingErrorKind::OverlongForm)); }
+        else if second > upper {
+            return Err(EncodingError::new(base + index, if lead == 0xED { EncodingErrorKind::Surrogate } else { EncodingErrorKind::OutOfRange }));
+        }
+
+        let (mut position, mut code): (usize, u32) = (2_usize, (((lead & (0x3F >> following)) as u32) << 6) | (second & 0x3F) as u32);
+
+        while position <= following {
+            if index + position >= length { return Err(EncodingError::new(base + index, EncodingErrorKind::TruncatedSequence)); }
+            else if UTF8::is_not_following(array[index + position]) { return Err(EncodingError::new(base + index, EncodingErrorKind::UnpairedLead)); }
+            else { code = (code << 6) | (array[index + position] & 0x3F) as u32; position += 1_usize; }
+        }
+
+        return Ok((code, following + 1_usize));
+    }
+
+    pub(crate) const fn locate_invalid_utf8(array: &[u8], base: usize) -> Result<(), EncodingError> {
+        let (mut index, length): (usize, usize) = (0_usize, array.len());
+
+        while index < length {
+            match UTF8::read_sequence(array, index, base) {
+                Ok((_, sequence)) => index += sequence,
+                Err(error) => return Err(error)
+            }
+        }
+
+        return Ok(());
+    }
+}
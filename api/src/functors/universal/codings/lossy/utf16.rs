@@ -0,0 +1,222 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF16
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+impl UTF16 {
+
+    const __LOSSY_WINDOW_BYTES:    usize = 4096_usize;
+    const __LOSSY_BLOCK_BYTES:     usize = 64_usize;
+    const __REPLACEMENT_CHARACTER: u16   = 0xFFFD;
+
+    const fn read_code_point_lossy(array: &[u8], index: usize, length: usize, endian: bool) -> (u32, usize) {
+        let code: u16 = UTF16::read_code_unit(array, index, endian);
+
+        if (code & 0xF800) != 0xD800 { return (code as u32, 2_usize); }
+        else if (code & 0xFC00) == 0xDC00 || index + 2_usize >= length { return (UTF16::__REPLACEMENT_CHARACTER as u32, 2_usize); }
+
+        let following: u16 = UTF16::read_code_unit(array, index + 2_usize, endian);
+
+        if (following & 0xFC00) != 0xDC00 { return (UTF16::__REPLACEMENT_CHARACTER as u32, 2_usize); }
+
+        return (0x10000 + ((((code & 0x03FF) as u32) << 10) | (following & 0x03FF) as u32), 4_usize);
+    }
+
+    const fn is_truncated_tail(array: &[u8], length: usize, endian: bool) -> bool {
+        return length != array.len() && (length == 0_usize || (UTF16::read_code_unit(array, length - 2_usize, endian) & 0xFC00) != 0xD800);
+    }
+
+    const fn window_end(array: &[u8], start: usize, length: usize, endian: bool) -> usize {
+        let end: usize = if length - start > UTF16::__LOSSY_WINDOW_BYTES { start + UTF16::__LOSSY_WINDOW_BYTES } else { length };
+
+        return if end < length && (UTF16::read_code_unit(array, end - 2_usize, endian) & 0xFC00) == 0xD800 { end - 2_usize } else { end };
+    }
+
+    pub fn to_string_lossy(array: &[u8], endian: bool) -> String {
+        let (length, mut start): (usize, usize) = (array.len() - array.len() % 2_usize, 0_usize);
+
+        let mut output: String = String::with_capacity(array.len() / 2_usize * 3_usize + 3_usize);
+
+        while start < length {
+            let end: usize = UTF16::window_end(array, start, length, endian);
+
+            if UTF16::is_utf16_from_byte_array(&array[start..end], endian, true, false) {
+                let buffer: &mut Vec<u8> = unsafe { output.as_mut_vec() };
+
+                let written: usize = buffer.len();
+
+                buffer.resize(written + (end - start) / 2_usize * 3_usize, 0_u8);
+
+                let (_, converted): (usize, usize) = UTF16::to_utf8_into(&array[start..end], &mut buffer[written..], endian).unwrap_or_default();
+
+                buffer.truncate(written + converted);
+
+                start = end;
+            } else {
+                while start < end {
+                    let (code, sequence): (u32, usize) = UTF16::read_code_point_lossy(array, start, length, endian);
+
+                    output.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+
+                    start += sequence;
+                }
+            }
+        }
+
+        if UTF16::is_truncated_tail(array, length, endian) { output.push(char::REPLACEMENT_CHARACTER); }
+
+        return output;
+    }
+
+    pub fn repair(array: &mut Vec<u8>, endian: bool) -> usize {
+        let (length, mut replacements): (usize, usize) = (array.len() - array.len() % 2_usize, 0_usize);
+
+        let mut start: usize = match UTF16::validate_utf16_from_byte_array(array, endian, true, false) {
+            Ok(()) => return 0_usize,
+            Err(error) => error.offset()
+        };
+
+        let replacement: [u8; 2_usize] = if endian { UTF16::__REPLACEMENT_CHARACTER.to_le_bytes() } else { UTF16::__REPLACEMENT_CHARACTER.to_be_bytes() };
+
+        let truncated: bool = UTF16::is_truncated_tail(array, length, endian);
+
+        while start < length {
+            let end: usize = UTF16::window_end(array, start, length, endian);
+
+            if UTF16::is_utf16_from_byte_array(&array[start..end], endian, true, false) {
+                start = end;
+            } else {
+                while start < end {
+                    let (code, sequence): (u32, usize) = UTF16::read_code_point_lossy(array, start, length, endian);
+
+                    if code == UTF16::__REPLACEMENT_CHARACTER as u32 && UTF16::read_code_unit(array, start, endian) != UTF16::__REPLACEMENT_CHARACTER {
+                        array[start] = replacement[0]; array[start + 1_usize] = replacement[1];
+
+                        replacements += 1_usize;
+                    }
+
+                    start += sequence;
+                }
+            }
+        }
+
+        if truncated {
+            array.truncate(length);
+            array.extend_from_slice(&replacement);
+
+            replacements += 1_usize;
+        } else {
+            array.truncate(length);
+        }
+
+        return replacements;
+    }
+
+    fn visit_lone_surrogates_scalar(array: &[u8], mut start: usize, end: usize, length: usize, endian: bool, visit: &mut impl FnMut(usize)) -> usize {
+        while start < end {
+            let code: u16 = UTF16::read_code_unit(array, start, endian);
+
+            if (code & 0xF800) != 0xD800 {
+                start += 2_usize;
+            } else if (code & 0xFC00) == 0xD800 && start + 2_usize < length && (UTF16::read_code_unit(array, start + 2_usize, endian) & 0xFC00) == 0xDC00 {
+                start += 4_usize;
+            } else {
+                visit(start);
+
+                start += 2_usize;
+            }
+        }
+
+        return start;
+    }
+
+    fn visit_lone_surrogates(array: &[u8], length: usize, endian: bool, mut visit: impl FnMut(usize)) {
+        let mut start: usize = 0_usize;
+
+        while start < length {
+            let end: usize = UTF16::window_end(array, start, length, endian);
+
+            if UTF16::is_utf16_from_byte_array(&array[start..end], endian, true, false) {
+                start = end;
+            } else {
+                let mut block: usize = UTF16::__LOSSY_BLOCK_BYTES;
+
+                while start < end {
+                    let skipped: usize = UTF16::surrogate_free_prefix(&array[start..end], endian);
+
+                    block = if skipped == 0_usize { block * 2_usize } else { UTF16::__LOSSY_BLOCK_BYTES };
+
+                    start += skipped;
+
+                    start = UTF16::visit_lone_surrogates_scalar(array, start, if end - start > block { start + block } else { end }, length, endian, &mut visit);
+                }
+            }
+        }
+    }
+
+    const fn check_ill_formed(array: &[u8]) -> Result<usize, EncodingError> {
+        let length: usize = array.len();
+
+        if length % 2_usize != 0_usize { return Err(EncodingError::new(length - 1_usize, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok(length);
+    }
+
+    pub fn lone_surrogates(array: &[u8], endian: bool) -> Result<Vec<usize>, EncodingError> {
+        let length: usize = UTF16::check_ill_formed(array)?;
+
+        let mut indices: Vec<usize> = Vec::new();
+
+        UTF16::visit_lone_surrogates(array, length, endian, |index: usize| indices.push(index));
+
+        return Ok(indices);
+    }
+
+    pub fn to_well_formed(array: &[u8], endian: bool) -> Result<Vec<u8>, EncodingError> {
+        let length: usize = UTF16::check_ill_formed(array)?;
+
+        let replacement: [u8; 2_usize] = if endian { UTF16::__REPLACEMENT_CHARACTER.to_le_bytes() } else { UTF16::__REPLACEMENT_CHARACTER.to_be_bytes() };
+
+        let mut output: Vec<u8> = array.to_vec();
+
+        UTF16::visit_lone_surrogates(array, length, endian, |index: usize| output[index..index + 2_usize].copy_from_slice(&replacement));
+
+        return Ok(output);
+    }
+}
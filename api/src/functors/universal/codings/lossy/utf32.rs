@@ -0,0 +1,115 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF32
+    }
+};
+
+impl UTF32 {
+
+    const __LOSSY_WINDOW_BYTES:    usize = 4096_usize;
+    const __REPLACEMENT_CHARACTER: u32   = 0xFFFD;
+
+    pub fn to_string_lossy(array: &[u8], endian: bool) -> String {
+        let (length, mut start): (usize, usize) = (array.len() - array.len() % 4_usize, 0_usize);
+
+        let mut output: String = String::with_capacity(length + 3_usize);
+
+        while start < length {
+            let end: usize = if length - start > UTF32::__LOSSY_WINDOW_BYTES { start + UTF32::__LOSSY_WINDOW_BYTES } else { length };
+
+            if UTF32::is_utf32_from_byte_array(&array[start..end], endian) {
+                let buffer: &mut Vec<u8> = unsafe { output.as_mut_vec() };
+
+                let written: usize = buffer.len();
+
+                buffer.resize(written + (end - start), 0_u8);
+
+                let (_, converted): (usize, usize) = UTF32::to_utf8_into_unchecked(&array[start..end], &mut buffer[written..], endian);
+
+                buffer.truncate(written + converted);
+            } else {
+                let mut index: usize = start;
+
+                while index < end {
+                    output.push(char::from_u32(UTF32::read_code_unit(array, index, endian)).unwrap_or(char::REPLACEMENT_CHARACTER));
+
+                    index += 4_usize;
+                }
+            }
+
+            start = end;
+        }
+
+        if length != array.len() { output.push(char::REPLACEMENT_CHARACTER); }
+
+        return output;
+    }
+
+    pub fn repair(array: &mut Vec<u8>, endian: bool) -> usize {
+        let (length, mut replacements): (usize, usize) = (array.len() - array.len() % 4_usize, 0_usize);
+
+        let mut start: usize = match UTF32::validate_utf32_from_byte_array(array, endian) {
+            Ok(()) => return 0_usize,
+            Err(error) => error.offset()
+        };
+
+        let replacement: [u8; 4_usize] = if endian { UTF32::__REPLACEMENT_CHARACTER.to_le_bytes() } else { UTF32::__REPLACEMENT_CHARACTER.to_be_bytes() };
+
+        while start < length {
+            let end: usize = if length - start > UTF32::__LOSSY_WINDOW_BYTES { start + UTF32::__LOSSY_WINDOW_BYTES } else { length };
+
+            if !UTF32::is_utf32_from_byte_array(&array[start..end], endian) {
+                let mut index: usize = start;
+
+                while index < end {
+                    if char::from_u32(UTF32::read_code_unit(array, index, endian)).is_none() {
+                        array[index..index + 4_usize].copy_from_slice(&replacement);
+
+                        replacements += 1_usize;
+                    }
+
+                    index += 4_usize;
+                }
+            }
+
+            start = end;
+        }
+
+        if length != array.len() {
+            array.truncate(length);
+            array.extend_from_slice(&replacement);
+
+            replacements += 1_usize;
+        }
+
+        return replacements;
+    }
+}
@@ -0,0 +1,143 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF8
+    }
+};
+
+impl UTF8 {
+
+    const __LOSSY_WINDOW_BYTES:     usize = 4096_usize;
+    const __REPLACEMENT_CHARACTER: [u8; 3_usize] = [0xEF, 0xBF, 0xBD];
+
+    const fn invalid_subpart_length(array: &[u8], index: usize) -> usize {
+        let (lead, length): (u8, usize) = (array[index], array.len());
+
+        let (following, lower, upper): (usize, u8, u8) = if lead < 0xC2 {
+            return 1_usize;
+        } else if lead < 0xE0 {
+            (1_usize, 0x80, 0xBF)
+        } else if lead == 0xE0 {
+            (2_usize, 0xA0, 0xBF)
+        } else if lead == 0xED {
+            (2_usize, 0x80, 0x9F)
+        } else if lead < 0xF0 {
+            (2_usize, 0x80, 0xBF)
+        } else if lead == 0xF0 {
+            (3_usize, 0x90, 0xBF)
+        } else if lead < 0xF4 {
+            (3_usize, 0x80, 0xBF)
+        } else if lead == 0xF4 {
+            (3_usize, 0x80, 0x8F)
+        } else {
+            return 1_usize;
+        };
+
+        if index + 1_usize >= length || array[index + 1_usize] < lower || array[index + 1_usize] > upper { return 1_usize; }
+
+        let mut position: usize = 2_usize;
+
+        while position <= following && index + position < length && UTF8::is_following(array[index + position]) { position += 1_usize; }
+
+        return position;
+    }
+
+    fn write_lossy_from(array: &[u8], mut index: usize, stop: usize, output: &mut Vec<u8>) -> (usize, usize) {
+        let mut replacements: usize = 0_usize;
+
+        while index < stop {
+            match UTF8::read_sequence(array, index, 0_usize) {
+                Ok((_, sequence)) => {
+                    output.extend_from_slice(&array[index..index + sequence]);
+
+                    index += sequence;
+                },
+                Err(_) => {
+                    output.extend_from_slice(&UTF8::__REPLACEMENT_CHARACTER);
+
+                    index += UTF8::invalid_subpart_length(array, index); replacements += 1_usize;
+                }
+            }
+        }
+
+        return (index, replacements);
+    }
+
+    fn write_lossy(array: &[u8], mut start: usize, output: &mut Vec<u8>) -> usize {
+        let (length, mut replacements): (usize, usize) = (array.len(), 0_usize);
+
+        while start < length {
+            let mut end: usize = if length - start > UTF8::__LOSSY_WINDOW_BYTES { start + UTF8::__LOSSY_WINDOW_BYTES } else { length };
+
+            let mut backward: usize = 0_usize;
+
+            while end < length && backward < 3_usize && UTF8::is_following(array[end]) { end -= 1_usize; backward += 1_usize; }
+
+            if UTF8::is_utf8(&array[start..end]) {
+                output.extend_from_slice(&array[start..end]);
+
+                start = end;
+            } else {
+                let (next, count): (usize, usize) = UTF8::write_lossy_from(array, start, end, output);
+
+                start = next; replacements += count;
+            }
+        }
+
+        return replacements;
+    }
+
+    pub fn to_string_lossy(array: &[u8]) -> String {
+        let mut output: Vec<u8> = Vec::with_capacity(array.len());
+
+        UTF8::write_lossy(array, 0_usize, &mut output);
+
+        return unsafe { String::from_utf8_unchecked(output) };
+    }
+
+    pub fn repair(array: &mut Vec<u8>) -> usize {
+        if array.is_empty() { return 0_usize; }
+
+        let offset: usize = match UTF8::validate_utf8(array) {
+            Ok(()) => return 0_usize,
+            Err(error) => error.offset()
+        };
+
+        let mut output: Vec<u8> = Vec::with_capacity(array.len() + UTF8::__REPLACEMENT_CHARACTER.len());
+
+        output.extend_from_slice(&array[..offset]);
+
+        let replacements: usize = UTF8::write_lossy(array, offset, &mut output);
+
+        *array = output;
+
+        return replacements;
+    }
+}
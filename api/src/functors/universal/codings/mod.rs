@@ -26,11 +26,100 @@
  * THE SOFTWARE.
  */
 
+mod error;
+mod locate;
 mod validation;
+mod transcoding;
+mod lossy;
+mod streaming;
+mod detection;
+mod statistics;
+mod counting;
+mod tables;
+mod single_byte;
+mod japanese;
+mod chinese;
+mod korean;
+mod variants;
+mod utf7;
+mod ebcdic;
+mod options;
+mod aligned;
+mod folding;
 
 #[cfg(feature = "universal")]
 mod search;
 
+pub use error::{
+    EncodingError,
+    EncodingErrorKind,
+    Policy
+};
+
+pub use detection::{
+    Encoding,
+    Detection
+};
+
+pub use statistics::{
+    Statistics
+};
+
+pub use transcoding::{
+    ISO8859_1
+};
+
+pub use single_byte::{
+    SingleByte,
+    Codepage
+};
+
+pub use japanese::{
+    SHIFTJIS,
+    EUCJP,
+    ISO2022JP
+};
+
+pub use chinese::{
+    GB18030,
+    GBK,
+    BIG5
+};
+
+pub use korean::{
+    EUCKR
+};
+
+pub use variants::{
+    CESU8,
+    MUTF8,
+    WTF8
+};
+
+pub use utf7::{
+    UTF7,
+    Utf7Variant,
+    Utf7State
+};
+
+pub use ebcdic::{
+    EBCDIC,
+    EbcdicCodepage,
+    Newline
+};
+
+pub use options::{
+    Endian,
+    Utf16Planes,
+    SearchOptions
+};
+
+pub use streaming::{
+    Utf8Validator,
+    Utf16Validator,
+    Utf32Validator
+};
+
 #[cfg(feature = "universal")]
 mod unite {
     use super::validation;
@@ -0,0 +1,78 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use core::{
+    fmt::{
+        Display,
+        Formatter,
+        Result
+    }
+};
+
+pub use crate::{
+    essence::{
+        Endian
+    }
+};
+
+impl Endian {
+    pub const fn as_str(&self) -> &'static str {
+        return match self {
+            Endian::Little => "little",
+            Endian::Big => "big",
+            Endian::Native => "native"
+        };
+    }
+
+    pub fn from_label(label: &str) -> Option<Endian> {
+        return match label.trim().to_ascii_lowercase().as_str() {
+            "little" | "le" => Some(Endian::Little),
+            "big" | "be" => Some(Endian::Big),
+            "native" | "ne" => Some(Endian::Native),
+            _ => None
+        };
+    }
+
+    pub const fn from_flag(endian: bool) -> Endian {
+        return if endian { Endian::Little } else { Endian::Big };
+    }
+
+    pub const fn is_little(&self) -> bool {
+        return match self {
+            Endian::Little => true,
+            Endian::Big => false,
+            Endian::Native => cfg!(target_endian = "little")
+        };
+    }
+}
+
+impl Display for Endian {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        return formatter.write_str(self.as_str());
+    }
+}
@@ -0,0 +1,64 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        Endian,
+        UTF16,
+        UTF32,
+        EncodingError
+    }
+};
+
+impl UTF16 {
+    pub fn to_string_lossy_with(array: &[u8], endian: Endian) -> String {
+        return UTF16::to_string_lossy(array, endian.is_little());
+    }
+
+    pub fn repair_with(array: &mut Vec<u8>, endian: Endian) -> usize {
+        return UTF16::repair(array, endian.is_little());
+    }
+
+    pub fn lone_surrogates_with(array: &[u8], endian: Endian) -> Result<Vec<usize>, EncodingError> {
+        return UTF16::lone_surrogates(array, endian.is_little());
+    }
+
+    pub fn to_well_formed_with(array: &[u8], endian: Endian) -> Result<Vec<u8>, EncodingError> {
+        return UTF16::to_well_formed(array, endian.is_little());
+    }
+}
+
+impl UTF32 {
+    pub fn to_string_lossy_with(array: &[u8], endian: Endian) -> String {
+        return UTF32::to_string_lossy(array, endian.is_little());
+    }
+
+    pub fn repair_with(array: &mut Vec<u8>, endian: Endian) -> usize {
+        return UTF32::repair(array, endian.is_little());
+    }
+}
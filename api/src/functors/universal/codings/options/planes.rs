@@ -0,0 +1,78 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use core::{
+    fmt::{
+        Display,
+        Formatter,
+        Result
+    }
+};
+
+pub use crate::{
+    essence::{
+        Utf16Planes
+    }
+};
+
+impl Utf16Planes {
+    pub const fn as_str(&self) -> &'static str {
+        return match self {
+            Utf16Planes::BmpOnly => "bmp",
+            Utf16Planes::SupplementaryOnly => "supplementary",
+            Utf16Planes::Mixed => "mixed"
+        };
+    }
+
+    pub fn from_label(label: &str) -> Option<Utf16Planes> {
+        return match label.trim().to_ascii_lowercase().as_str() {
+            "bmp" | "bmp-only" => Some(Utf16Planes::BmpOnly),
+            "supplementary" | "supplementary-only" | "omp" | "omp-only" => Some(Utf16Planes::SupplementaryOnly),
+            "mixed" => Some(Utf16Planes::Mixed),
+            _ => None
+        };
+    }
+
+    pub const fn from_flags(omp: bool, only: bool) -> Utf16Planes {
+        return if !omp { Utf16Planes::BmpOnly } else if only { Utf16Planes::SupplementaryOnly } else { Utf16Planes::Mixed };
+    }
+
+    pub const fn omp(&self) -> bool {
+        return !matches!(self, Utf16Planes::BmpOnly);
+    }
+
+    pub const fn only(&self) -> bool {
+        return !matches!(self, Utf16Planes::Mixed);
+    }
+}
+
+impl Display for Utf16Planes {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        return formatter.write_str(self.as_str());
+    }
+}
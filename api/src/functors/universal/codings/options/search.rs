@@ -0,0 +1,154 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        SearchOptions,
+        Utf16Planes,
+        ASCII,
+        UTF8,
+        UTF16,
+        UTF32,
+        SingleByte,
+        SHIFTJIS,
+        EUCJP,
+        ISO2022JP,
+        GB18030,
+        GBK,
+        BIG5,
+        EUCKR
+    }
+};
+
+impl SearchOptions {
+    pub const fn new() -> SearchOptions {
+        return SearchOptions { planes: Utf16Planes::Mixed, overlapping: false, all_matches: true, limit: None };
+    }
+
+    pub const fn planes(mut self, planes: Utf16Planes) -> SearchOptions {
+        self.planes = planes;
+
+        return self;
+    }
+
+    pub const fn overlapping(mut self, overlapping: bool) -> SearchOptions {
+        self.overlapping = overlapping;
+
+        return self;
+    }
+
+    pub const fn all_matches(mut self, all_matches: bool) -> SearchOptions {
+        self.all_matches = all_matches;
+
+        return self;
+    }
+
+    pub const fn limit(mut self, limit: Option<usize>) -> SearchOptions {
+        self.limit = limit;
+
+        return self;
+    }
+}
+
+impl Default for SearchOptions {
+    fn default() -> SearchOptions {
+        return SearchOptions::new();
+    }
+}
+
+impl UTF16 {
+    pub fn search(array: &[u8], pattern: &[u8], options: SearchOptions) -> Vec<usize> {
+        return UTF16::search_pattern(array, pattern, options.planes.omp(), options.planes.only(), options.overlapping, options.all_matches, options.limit);
+    }
+}
+
+impl ASCII {
+    pub fn search(array: &[u8], pattern: &[u8], options: SearchOptions) -> Vec<usize> {
+        return ASCII::search_pattern(array, pattern, options.overlapping, options.all_matches, options.limit);
+    }
+}
+
+impl UTF8 {
+    pub fn search(array: &[u8], pattern: &[u8], options: SearchOptions) -> Vec<usize> {
+        return UTF8::search_pattern(array, pattern, options.overlapping, options.all_matches, options.limit);
+    }
+}
+
+impl UTF32 {
+    pub fn search(array: &[u8], pattern: &[u8], options: SearchOptions) -> Vec<usize> {
+        return UTF32::search_pattern(array, pattern, options.overlapping, options.all_matches, options.limit);
+    }
+}
+
+impl SingleByte {
+    pub fn search(array: &[u8], pattern: &[u8], options: SearchOptions) -> Vec<usize> {
+        return SingleByte::search_pattern(array, pattern, options.overlapping, options.all_matches, options.limit);
+    }
+}
+
+impl SHIFTJIS {
+    pub fn search(array: &[u8], pattern: &[u8], options: SearchOptions) -> Vec<usize> {
+        return SHIFTJIS::search_pattern(array, pattern, options.overlapping, options.all_matches, options.limit);
+    }
+}
+
+impl EUCJP {
+    pub fn search(array: &[u8], pattern: &[u8], options: SearchOptions) -> Vec<usize> {
+        return EUCJP::search_pattern(array, pattern, options.overlapping, options.all_matches, options.limit);
+    }
+}
+
+impl ISO2022JP {
+    pub fn search(array: &[u8], pattern: &[u8], options: SearchOptions) -> Vec<usize> {
+        return ISO2022JP::search_pattern(array, pattern, options.overlapping, options.all_matches, options.limit);
+    }
+}
+
+impl GB18030 {
+    pub fn search(array: &[u8], pattern: &[u8], options: SearchOptions) -> Vec<usize> {
+        return GB18030::search_pattern(array, pattern, options.overlapping, options.all_matches, options.limit);
+    }
+}
+
+impl GBK {
+    pub fn search(array: &[u8], pattern: &[u8], options: SearchOptions) -> Vec<usize> {
+        return GBK::search_pattern(array, pattern, options.overlapping, options.all_matches, options.limit);
+    }
+}
+
+impl BIG5 {
+    pub fn search(array: &[u8], pattern: &[u8], options: SearchOptions) -> Vec<usize> {
+        return BIG5::search_pattern(array, pattern, options.overlapping, options.all_matches, options.limit);
+    }
+}
+
+impl EUCKR {
+    pub fn search(array: &[u8], pattern: &[u8], options: SearchOptions) -> Vec<usize> {
+        return EUCKR::search_pattern(array, pattern, options.overlapping, options.all_matches, options.limit);
+    }
+}
@@ -0,0 +1,378 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        Endian,
+        ISO8859_1,
+        UTF16,
+        UTF32,
+        UTF8,
+        SingleByte,
+        EUCJP,
+        ISO2022JP,
+        SHIFTJIS,
+        BIG5,
+        GB18030,
+        GBK,
+        EUCKR,
+        CESU8,
+        MUTF8,
+        WTF8,
+        UTF7,
+        Codepage,
+        EncodingError,
+        Policy,
+        Utf7Variant,
+        Utf7State
+    }
+};
+
+impl ISO8859_1 {
+    pub fn to_utf16_into_with(array: &[u8], output: &mut [u16], endian: Endian) -> (usize, usize) {
+        return ISO8859_1::to_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn to_utf16_with(array: &[u8], endian: Endian) -> Vec<u16> {
+        return ISO8859_1::to_utf16(array, endian.is_little());
+    }
+
+    pub fn from_utf16_into_with(array: &[u8], output: &mut [u8], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return ISO8859_1::from_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn from_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u8>, EncodingError> {
+        return ISO8859_1::from_utf16(array, endian.is_little());
+    }
+}
+
+impl UTF16 {
+    pub fn to_utf8_into_with(array: &[u8], output: &mut [u8], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return UTF16::to_utf8_into(array, output, endian.is_little());
+    }
+
+    pub fn to_utf8_with(array: &[u8], endian: Endian) -> Result<Vec<u8>, EncodingError> {
+        return UTF16::to_utf8(array, endian.is_little());
+    }
+
+    pub fn to_utf32_into_with(array: &[u8], output: &mut [u32], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return UTF16::to_utf32_into(array, output, endian.is_little());
+    }
+
+    pub fn to_utf32_into_unchecked_with(array: &[u8], output: &mut [u32], endian: Endian) -> (usize, usize) {
+        return UTF16::to_utf32_into_unchecked(array, output, endian.is_little());
+    }
+
+    pub fn to_utf32_with(array: &[u8], endian: Endian) -> Result<Vec<u32>, EncodingError> {
+        return UTF16::to_utf32(array, endian.is_little());
+    }
+
+    pub fn to_utf32_unchecked_with(array: &[u8], endian: Endian) -> Vec<u32> {
+        return UTF16::to_utf32_unchecked(array, endian.is_little());
+    }
+}
+
+impl UTF32 {
+    pub fn to_utf8_into_with(array: &[u8], output: &mut [u8], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return UTF32::to_utf8_into(array, output, endian.is_little());
+    }
+
+    pub fn to_utf8_into_unchecked_with(array: &[u8], output: &mut [u8], endian: Endian) -> (usize, usize) {
+        return UTF32::to_utf8_into_unchecked(array, output, endian.is_little());
+    }
+
+    pub fn to_utf16_into_with(array: &[u8], output: &mut [u16], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return UTF32::to_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn to_utf16_into_unchecked_with(array: &[u8], output: &mut [u16], endian: Endian) -> (usize, usize) {
+        return UTF32::to_utf16_into_unchecked(array, output, endian.is_little());
+    }
+
+    pub fn to_utf8_with(array: &[u8], endian: Endian) -> Result<Vec<u8>, EncodingError> {
+        return UTF32::to_utf8(array, endian.is_little());
+    }
+
+    pub fn to_utf8_unchecked_with(array: &[u8], endian: Endian) -> Vec<u8> {
+        return UTF32::to_utf8_unchecked(array, endian.is_little());
+    }
+
+    pub fn to_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u16>, EncodingError> {
+        return UTF32::to_utf16(array, endian.is_little());
+    }
+
+    pub fn to_utf16_unchecked_with(array: &[u8], endian: Endian) -> Vec<u16> {
+        return UTF32::to_utf16_unchecked(array, endian.is_little());
+    }
+}
+
+impl UTF8 {
+    pub fn to_utf16_into_with(array: &[u8], output: &mut [u16], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return UTF8::to_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn to_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u16>, EncodingError> {
+        return UTF8::to_utf16(array, endian.is_little());
+    }
+
+    pub fn to_utf32_into_with(array: &[u8], output: &mut [u32], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return UTF8::to_utf32_into(array, output, endian.is_little());
+    }
+
+    pub fn to_utf32_into_unchecked_with(array: &[u8], output: &mut [u32], endian: Endian) -> (usize, usize) {
+        return UTF8::to_utf32_into_unchecked(array, output, endian.is_little());
+    }
+
+    pub fn to_utf32_with(array: &[u8], endian: Endian) -> Result<Vec<u32>, EncodingError> {
+        return UTF8::to_utf32(array, endian.is_little());
+    }
+
+    pub fn to_utf32_unchecked_with(array: &[u8], endian: Endian) -> Vec<u32> {
+        return UTF8::to_utf32_unchecked(array, endian.is_little());
+    }
+}
+
+impl SingleByte {
+    pub fn to_utf16_into_with(array: &[u8], output: &mut [u16], codepage: Codepage, endian: Endian, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        return SingleByte::to_utf16_into(array, output, codepage, endian.is_little(), policy);
+    }
+
+    pub fn to_utf16_with(array: &[u8], codepage: Codepage, endian: Endian, policy: Policy) -> Result<Vec<u16>, EncodingError> {
+        return SingleByte::to_utf16(array, codepage, endian.is_little(), policy);
+    }
+
+    pub fn from_utf16_into_with(array: &[u8], output: &mut [u8], codepage: Codepage, endian: Endian, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        return SingleByte::from_utf16_into(array, output, codepage, endian.is_little(), policy);
+    }
+
+    pub fn from_utf16_with(array: &[u8], codepage: Codepage, endian: Endian, policy: Policy) -> Result<Vec<u8>, EncodingError> {
+        return SingleByte::from_utf16(array, codepage, endian.is_little(), policy);
+    }
+}
+
+impl EUCJP {
+    pub fn to_utf16_into_with(array: &[u8], output: &mut [u16], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return EUCJP::to_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn to_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u16>, EncodingError> {
+        return EUCJP::to_utf16(array, endian.is_little());
+    }
+
+    pub fn from_utf16_into_with(array: &[u8], output: &mut [u8], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return EUCJP::from_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn from_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u8>, EncodingError> {
+        return EUCJP::from_utf16(array, endian.is_little());
+    }
+}
+
+impl ISO2022JP {
+    pub fn to_utf16_into_with(array: &[u8], output: &mut [u16], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return ISO2022JP::to_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn to_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u16>, EncodingError> {
+        return ISO2022JP::to_utf16(array, endian.is_little());
+    }
+
+    pub fn from_utf16_into_with(array: &[u8], output: &mut [u8], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return ISO2022JP::from_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn from_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u8>, EncodingError> {
+        return ISO2022JP::from_utf16(array, endian.is_little());
+    }
+}
+
+impl SHIFTJIS {
+    pub fn to_utf16_into_with(array: &[u8], output: &mut [u16], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return SHIFTJIS::to_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn to_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u16>, EncodingError> {
+        return SHIFTJIS::to_utf16(array, endian.is_little());
+    }
+
+    pub fn from_utf16_into_with(array: &[u8], output: &mut [u8], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return SHIFTJIS::from_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn from_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u8>, EncodingError> {
+        return SHIFTJIS::from_utf16(array, endian.is_little());
+    }
+}
+
+impl BIG5 {
+    pub fn to_utf16_into_with(array: &[u8], output: &mut [u16], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return BIG5::to_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn to_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u16>, EncodingError> {
+        return BIG5::to_utf16(array, endian.is_little());
+    }
+
+    pub fn from_utf16_into_with(array: &[u8], output: &mut [u8], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return BIG5::from_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn from_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u8>, EncodingError> {
+        return BIG5::from_utf16(array, endian.is_little());
+    }
+}
+
+impl GB18030 {
+    pub fn to_utf16_into_with(array: &[u8], output: &mut [u16], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return GB18030::to_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn to_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u16>, EncodingError> {
+        return GB18030::to_utf16(array, endian.is_little());
+    }
+
+    pub fn from_utf16_into_with(array: &[u8], output: &mut [u8], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return GB18030::from_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn from_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u8>, EncodingError> {
+        return GB18030::from_utf16(array, endian.is_little());
+    }
+}
+
+impl GBK {
+    pub fn to_utf16_into_with(array: &[u8], output: &mut [u16], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return GBK::to_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn to_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u16>, EncodingError> {
+        return GBK::to_utf16(array, endian.is_little());
+    }
+
+    pub fn from_utf16_into_with(array: &[u8], output: &mut [u8], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return GBK::from_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn from_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u8>, EncodingError> {
+        return GBK::from_utf16(array, endian.is_little());
+    }
+}
+
+impl EUCKR {
+    pub fn to_utf16_into_with(array: &[u8], output: &mut [u16], endian: Endian, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        return EUCKR::to_utf16_into(array, output, endian.is_little(), policy);
+    }
+
+    pub fn to_utf16_with(array: &[u8], endian: Endian, policy: Policy) -> Result<Vec<u16>, EncodingError> {
+        return EUCKR::to_utf16(array, endian.is_little(), policy);
+    }
+
+    pub fn from_utf16_into_with(array: &[u8], output: &mut [u8], endian: Endian, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        return EUCKR::from_utf16_into(array, output, endian.is_little(), policy);
+    }
+
+    pub fn from_utf16_with(array: &[u8], endian: Endian, policy: Policy) -> Result<Vec<u8>, EncodingError> {
+        return EUCKR::from_utf16(array, endian.is_little(), policy);
+    }
+}
+
+impl CESU8 {
+    pub fn to_utf16_into_with(array: &[u8], output: &mut [u16], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return CESU8::to_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn to_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u16>, EncodingError> {
+        return CESU8::to_utf16(array, endian.is_little());
+    }
+
+    pub fn from_utf16_into_with(array: &[u8], output: &mut [u8], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return CESU8::from_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn from_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u8>, EncodingError> {
+        return CESU8::from_utf16(array, endian.is_little());
+    }
+}
+
+impl MUTF8 {
+    pub fn to_utf16_into_with(array: &[u8], output: &mut [u16], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return MUTF8::to_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn to_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u16>, EncodingError> {
+        return MUTF8::to_utf16(array, endian.is_little());
+    }
+
+    pub fn from_utf16_into_with(array: &[u8], output: &mut [u8], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return MUTF8::from_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn from_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u8>, EncodingError> {
+        return MUTF8::from_utf16(array, endian.is_little());
+    }
+}
+
+impl WTF8 {
+    pub fn to_utf16_into_with(array: &[u8], output: &mut [u16], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return WTF8::to_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn to_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u16>, EncodingError> {
+        return WTF8::to_utf16(array, endian.is_little());
+    }
+
+    pub fn from_utf16_into_with(array: &[u8], output: &mut [u8], endian: Endian) -> Result<(usize, usize), EncodingError> {
+        return WTF8::from_utf16_into(array, output, endian.is_little());
+    }
+
+    pub fn from_utf16_with(array: &[u8], endian: Endian) -> Result<Vec<u8>, EncodingError> {
+        return WTF8::from_utf16(array, endian.is_little());
+    }
+}
+
+impl UTF7 {
+    pub fn to_utf16_into_with(array: &[u8], output: &mut [u16], endian: Endian, variant: Utf7Variant) -> Result<(usize, usize), EncodingError> {
+        return UTF7::to_utf16_into(array, output, endian.is_little(), variant);
+    }
+
+    pub fn to_utf16_into_resumable_with(array: &[u8], output: &mut [u16], endian: Endian, variant: Utf7Variant, state: Utf7State) -> Result<(usize, usize, Utf7State), EncodingError> {
+        return UTF7::to_utf16_into_resumable(array, output, endian.is_little(), variant, state);
+    }
+
+    pub fn to_utf16_with(array: &[u8], endian: Endian, variant: Utf7Variant) -> Result<Vec<u16>, EncodingError> {
+        return UTF7::to_utf16(array, endian.is_little(), variant);
+    }
+
+    pub fn from_utf16_into_with(array: &[u8], output: &mut [u8], endian: Endian, variant: Utf7Variant) -> Result<(usize, usize), EncodingError> {
+        return UTF7::from_utf16_into(array, output, endian.is_little(), variant);
+    }
+
+    pub fn from_utf16_with(array: &[u8], endian: Endian, variant: Utf7Variant) -> Result<Vec<u8>, EncodingError> {
+        return UTF7::from_utf16(array, endian.is_little(), variant);
+    }
+}
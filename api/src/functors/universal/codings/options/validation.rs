@@ -0,0 +1,76 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        Endian,
+        Utf16Planes,
+        UTF16,
+        UTF32,
+        Utf16Validator,
+        Utf32Validator
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError
+    }
+};
+
+impl UTF16 {
+    pub fn is_utf16_with(array: &[u8], endian: Endian, planes: Utf16Planes) -> bool {
+        return UTF16::is_utf16_from_byte_array(array, endian.is_little(), planes.omp(), planes.only());
+    }
+
+    pub fn validate_utf16_with(array: &[u8], endian: Endian, planes: Utf16Planes) -> Result<(), EncodingError> {
+        return UTF16::validate_utf16_from_byte_array(array, endian.is_little(), planes.omp(), planes.only());
+    }
+}
+
+impl UTF32 {
+    pub fn is_utf32_with(array: &[u8], endian: Endian) -> bool {
+        return UTF32::is_utf32_from_byte_array(array, endian.is_little());
+    }
+
+    pub fn validate_utf32_with(array: &[u8], endian: Endian) -> Result<(), EncodingError> {
+        return UTF32::validate_utf32_from_byte_array(array, endian.is_little());
+    }
+}
+
+impl Utf16Validator {
+    pub const fn with(endian: Endian, planes: Utf16Planes) -> Utf16Validator {
+        return Utf16Validator::new(endian.is_little(), planes.omp(), planes.only());
+    }
+}
+
+impl Utf32Validator {
+    pub const fn with(endian: Endian) -> Utf32Validator {
+        return Utf32Validator::new(endian.is_little());
+    }
+}
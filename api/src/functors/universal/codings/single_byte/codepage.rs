@@ -0,0 +1,203 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use core::{
+    fmt::{
+        Display,
+        Formatter,
+        Result
+    }
+};
+
+pub use crate::{
+    essence::{
+        Codepage
+    }
+};
+
+use crate::{
+    functors::{
+        universal::{
+            codings::{
+                tables::{
+                    single_byte
+                }
+            }
+        }
+    }
+};
+
+impl Codepage {
+    pub const fn as_str(&self) -> &'static str {
+        return match self {
+            Codepage::WINDOWS1250 => "windows-1250",
+            Codepage::WINDOWS1251 => "windows-1251",
+            Codepage::WINDOWS1252 => "windows-1252",
+            Codepage::WINDOWS1253 => "windows-1253",
+            Codepage::WINDOWS1254 => "windows-1254",
+            Codepage::WINDOWS1255 => "windows-1255",
+            Codepage::WINDOWS1256 => "windows-1256",
+            Codepage::WINDOWS1257 => "windows-1257",
+            Codepage::WINDOWS1258 => "windows-1258",
+            Codepage::ISO8859_2 => "iso-8859-2",
+            Codepage::ISO8859_3 => "iso-8859-3",
+            Codepage::ISO8859_4 => "iso-8859-4",
+            Codepage::ISO8859_5 => "iso-8859-5",
+            Codepage::ISO8859_6 => "iso-8859-6",
+            Codepage::ISO8859_7 => "iso-8859-7",
+            Codepage::ISO8859_8 => "iso-8859-8",
+            Codepage::ISO8859_9 => "iso-8859-9",
+            Codepage::ISO8859_10 => "iso-8859-10",
+            Codepage::ISO8859_11 => "iso-8859-11",
+            Codepage::ISO8859_13 => "iso-8859-13",
+            Codepage::ISO8859_14 => "iso-8859-14",
+            Codepage::ISO8859_15 => "iso-8859-15",
+            Codepage::ISO8859_16 => "iso-8859-16",
+            Codepage::KOI8R => "koi8-r",
+            Codepage::KOI8U => "koi8-u",
+            Codepage::IBM866 => "ibm866"
+        };
+    }
+
+    pub fn from_label(label: &str) -> Option<Codepage> {
+        return match label.trim().to_ascii_lowercase().as_str() {
+            "windows-1250" | "cp1250" | "x-cp1250" => Some(Codepage::WINDOWS1250),
+            "windows-1251" | "cp1251" | "x-cp1251" => Some(Codepage::WINDOWS1251),
+            "windows-1252" | "cp1252" | "x-cp1252" => Some(Codepage::WINDOWS1252),
+            "windows-1253" | "cp1253" | "x-cp1253" => Some(Codepage::WINDOWS1253),
+            "windows-1254" | "cp1254" | "x-cp1254" => Some(Codepage::WINDOWS1254),
+            "windows-1255" | "cp1255" | "x-cp1255" => Some(Codepage::WINDOWS1255),
+            "windows-1256" | "cp1256" | "x-cp1256" => Some(Codepage::WINDOWS1256),
+            "windows-1257" | "cp1257" | "x-cp1257" => Some(Codepage::WINDOWS1257),
+            "windows-1258" | "cp1258" | "x-cp1258" => Some(Codepage::WINDOWS1258),
+            "iso-8859-2" | "iso8859-2" | "iso88592" | "iso_8859-2" | "latin2" | "l2" | "csisolatin2" => Some(Codepage::ISO8859_2),
+            "iso-8859-3" | "iso8859-3" | "iso88593" | "iso_8859-3" | "latin3" | "l3" | "csisolatin3" => Some(Codepage::ISO8859_3),
+            "iso-8859-4" | "iso8859-4" | "iso88594" | "iso_8859-4" | "latin4" | "l4" | "csisolatin4" => Some(Codepage::ISO8859_4),
+            "iso-8859-5" | "iso8859-5" | "iso88595" | "iso_8859-5" | "cyrillic" | "csisolatincyrillic" => Some(Codepage::ISO8859_5),
+            "iso-8859-6" | "iso8859-6" | "iso88596" | "iso_8859-6" | "arabic" | "csisolatinarabic" => Some(Codepage::ISO8859_6),
+            "iso-8859-7" | "iso8859-7" | "iso88597" | "iso_8859-7" | "greek" | "greek8" | "csisolatingreek" => Some(Codepage::ISO8859_7),
+            "iso-8859-8" | "iso8859-8" | "iso88598" | "iso_8859-8" | "hebrew" | "csisolatinhebrew" => Some(Codepage::ISO8859_8),
+            "iso-8859-9" | "iso8859-9" | "iso88599" | "iso_8859-9" | "latin5" | "l5" | "csisolatin5" => Some(Codepage::ISO8859_9),
+            "iso-8859-10" | "iso8859-10" | "iso885910" | "iso_8859-10" | "latin6" | "l6" | "csisolatin6" => Some(Codepage::ISO8859_10),
+            "iso-8859-11" | "iso8859-11" | "iso885911" | "iso_8859-11" | "tis-620" => Some(Codepage::ISO8859_11),
+            "iso-8859-13" | "iso8859-13" | "iso885913" | "iso_8859-13" => Some(Codepage::ISO8859_13),
+            "iso-8859-14" | "iso8859-14" | "iso885914" | "iso_8859-14" | "latin8" | "l8" => Some(Codepage::ISO8859_14),
+            "iso-8859-15" | "iso8859-15" | "iso885915" | "iso_8859-15" | "latin9" | "l9" => Some(Codepage::ISO8859_15),
+            "iso-8859-16" | "iso8859-16" | "iso885916" | "iso_8859-16" | "latin10" | "l10" => Some(Codepage::ISO8859_16),
+            "koi8-r" | "koi8r" | "koi8" | "koi" | "cskoi8r" => Some(Codepage::KOI8R),
+            "koi8-u" | "koi8-ru" => Some(Codepage::KOI8U),
+            "ibm866" | "cp866" | "866" | "csibm866" => Some(Codepage::IBM866),
+            _ => None
+        };
+    }
+
+    pub(crate) const fn decode_table(&self) -> &'static [u16; 128_usize] {
+        return match self {
+            Codepage::WINDOWS1250 => &single_byte::WINDOWS_1250,
+            Codepage::WINDOWS1251 => &single_byte::WINDOWS_1251,
+            Codepage::WINDOWS1252 => &single_byte::WINDOWS_1252,
+            Codepage::WINDOWS1253 => &single_byte::WINDOWS_1253,
+            Codepage::WINDOWS1254 => &single_byte::WINDOWS_1254,
+            Codepage::WINDOWS1255 => &single_byte::WINDOWS_1255,
+            Codepage::WINDOWS1256 => &single_byte::WINDOWS_1256,
+            Codepage::WINDOWS1257 => &single_byte::WINDOWS_1257,
+            Codepage::WINDOWS1258 => &single_byte::WINDOWS_1258,
+            Codepage::ISO8859_2 => &single_byte::ISO_8859_2,
+            Codepage::ISO8859_3 => &single_byte::ISO_8859_3,
+            Codepage::ISO8859_4 => &single_byte::ISO_8859_4,
+            Codepage::ISO8859_5 => &single_byte::ISO_8859_5,
+            Codepage::ISO8859_6 => &single_byte::ISO_8859_6,
+            Codepage::ISO8859_7 => &single_byte::ISO_8859_7,
+            Codepage::ISO8859_8 => &single_byte::ISO_8859_8,
+            Codepage::ISO8859_9 => &single_byte::ISO_8859_9,
+            Codepage::ISO8859_10 => &single_byte::ISO_8859_10,
+            Codepage::ISO8859_11 => &single_byte::ISO_8859_11,
+            Codepage::ISO8859_13 => &single_byte::ISO_8859_13,
+            Codepage::ISO8859_14 => &single_byte::ISO_8859_14,
+            Codepage::ISO8859_15 => &single_byte::ISO_8859_15,
+            Codepage::ISO8859_16 => &single_byte::ISO_8859_16,
+            Codepage::KOI8R => &single_byte::KOI8_R,
+            Codepage::KOI8U => &single_byte::KOI8_U,
+            Codepage::IBM866 => &single_byte::IBM866
+        };
+    }
+
+    pub(crate) const fn encode_table(&self) -> &'static [(u16, u8)] {
+        return match self {
+            Codepage::WINDOWS1250 => &single_byte::WINDOWS_1250_ENCODE,
+            Codepage::WINDOWS1251 => &single_byte::WINDOWS_1251_ENCODE,
+            Codepage::WINDOWS1252 => &single_byte::WINDOWS_1252_ENCODE,
+            Codepage::WINDOWS1253 => &single_byte::WINDOWS_1253_ENCODE,
+            Codepage::WINDOWS1254 => &single_byte::WINDOWS_1254_ENCODE,
+            Codepage::WINDOWS1255 => &single_byte::WINDOWS_1255_ENCODE,
+            Codepage::WINDOWS1256 => &single_byte::WINDOWS_1256_ENCODE,
+            Codepage::WINDOWS1257 => &single_byte::WINDOWS_1257_ENCODE,
+            Codepage::WINDOWS1258 => &single_byte::WINDOWS_1258_ENCODE,
+            Codepage::ISO8859_2 => &single_byte::ISO_8859_2_ENCODE,
+            Codepage::ISO8859_3 => &single_byte::ISO_8859_3_ENCODE,
+            Codepage::ISO8859_4 => &single_byte::ISO_8859_4_ENCODE,
+            Codepage::ISO8859_5 => &single_byte::ISO_8859_5_ENCODE,
+            Codepage::ISO8859_6 => &single_byte::ISO_8859_6_ENCODE,
+            Codepage::ISO8859_7 => &single_byte::ISO_8859_7_ENCODE,
+            Codepage::ISO8859_8 => &single_byte::ISO_8859_8_ENCODE,
+            Codepage::ISO8859_9 => &single_byte::ISO_8859_9_ENCODE,
+            Codepage::ISO8859_10 => &single_byte::ISO_8859_10_ENCODE,
+            Codepage::ISO8859_11 => &single_byte::ISO_8859_11_ENCODE,
+            Codepage::ISO8859_13 => &single_byte::ISO_8859_13_ENCODE,
+            Codepage::ISO8859_14 => &single_byte::ISO_8859_14_ENCODE,
+            Codepage::ISO8859_15 => &single_byte::ISO_8859_15_ENCODE,
+            Codepage::ISO8859_16 => &single_byte::ISO_8859_16_ENCODE,
+            Codepage::KOI8R => &single_byte::KOI8_R_ENCODE,
+            Codepage::KOI8U => &single_byte::KOI8_U_ENCODE,
+            Codepage::IBM866 => &single_byte::IBM866_ENCODE
+        };
+    }
+
+    pub(crate) const fn decode_byte(&self, code: u8) -> u32 {
+        return if code < 0x80 { code as u32 } else { self.decode_table()[(code & 0x7F) as usize] as u32 };
+    }
+
+    pub(crate) fn encode_code_point(&self, code: u32) -> Option<u8> {
+        if code < 0x80 { return Some(code as u8); }
+
+        if code > 0xFFFF { return None; }
+
+        let table: &'static [(u16, u8)] = self.encode_table();
+
+        return match table.binary_search_by_key(&(code as u16), |&(unit, _)| unit) {
+            Ok(index) => Some(table[index].1),
+            Err(_) => None
+        };
+    }
+}
+
+impl Display for Codepage {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        return formatter.write_str(self.as_str());
+    }
+}
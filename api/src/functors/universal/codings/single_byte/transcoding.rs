@@ -0,0 +1,181 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        SingleByte,
+        Codepage,
+        UTF8,
+        UTF16
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind,
+        Policy
+    }
+};
+
+impl SingleByte {
+    const fn decode_code_point(array: &[u8], read: usize, codepage: Codepage, policy: Policy) -> Result<u32, EncodingError> {
+        let (byte, code): (u8, u32) = (array[read], codepage.decode_byte(array[read]));
+
+        if code == 0 && byte != 0 {
+            return match policy {
+                Policy::Error => Err(EncodingError::new(read, EncodingErrorKind::Unmappable)),
+                Policy::Replacement => Ok(0xFFFD)
+            };
+        }
+
+        return Ok(code);
+    }
+
+    fn encode_code_point(code: u32, read: usize, codepage: Codepage, policy: Policy) -> Result<u8, EncodingError> {
+        return match codepage.encode_code_point(code) {
+            Some(byte) => Ok(byte),
+            None => match policy {
+                Policy::Error => Err(EncodingError::new(read, EncodingErrorKind::Unmappable)),
+                Policy::Replacement => Ok(0x3F) // ?
+            }
+        };
+    }
+
+    pub(crate) fn decode_utf8_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize, codepage: Codepage, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        while read < length {
+            let code: u32 = SingleByte::decode_code_point(array, read, codepage, policy)?;
+
+            if written + UTF8::length_of_code_point(code) > capacity { break; }
+
+            written = UTF8::write_code_point(code, output, written);
+
+            read += 1_usize;
+        }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn decode_utf16_from(array: &[u8], mut read: usize, output: &mut [u16], mut written: usize, codepage: Codepage, endian: bool, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        while read < length && written < capacity {
+            output[written] = UTF16::store_code_unit(SingleByte::decode_code_point(array, read, codepage, policy)? as u16, endian);
+
+            read += 1_usize; written += 1_usize;
+        }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn encode_utf8_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize, codepage: Codepage, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        while read < length && written < capacity {
+            let (code, sequence): (u32, usize) = UTF8::read_sequence(array, read, 0_usize)?;
+
+            output[written] = SingleByte::encode_code_point(code, read, codepage, policy)?;
+
+            read += sequence; written += 1_usize;
+        }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn encode_utf16_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize, codepage: Codepage, endian: bool, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 2_usize, output.len());
+
+        while read < length && written < capacity {
+            let (code, sequence): (u32, usize) = UTF16::read_code_point(array, read, length, 0_usize, endian)?;
+
+            output[written] = SingleByte::encode_code_point(code, read, codepage, policy)?;
+
+            read += sequence; written += 1_usize;
+        }
+
+        if read == length && length != array.len() { return Err(EncodingError::new(length, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok((read, written));
+    }
+
+    pub fn to_utf8_into(array: &[u8], output: &mut [u8], codepage: Codepage, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        return SingleByte::decode_utf8_from(array, 0_usize, output, 0_usize, codepage, policy);
+    }
+
+    pub fn to_utf8(array: &[u8], codepage: Codepage, policy: Policy) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len() * 3_usize];
+
+        let (_, written): (usize, usize) = SingleByte::to_utf8_into(array, &mut output, codepage, policy)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn to_utf16_into(array: &[u8], output: &mut [u16], codepage: Codepage, endian: bool, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        return SingleByte::decode_utf16_from(array, 0_usize, output, 0_usize, codepage, endian, policy);
+    }
+
+    pub fn to_utf16(array: &[u8], codepage: Codepage, endian: bool, policy: Policy) -> Result<Vec<u16>, EncodingError> {
+        let mut output: Vec<u16> = vec![0_u16; array.len()];
+
+        SingleByte::to_utf16_into(array, &mut output, codepage, endian, policy)?;
+
+        return Ok(output);
+    }
+
+    pub fn from_utf8_into(array: &[u8], output: &mut [u8], codepage: Codepage, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        return SingleByte::encode_utf8_from(array, 0_usize, output, 0_usize, codepage, policy);
+    }
+
+    pub fn from_utf8(array: &[u8], codepage: Codepage, policy: Policy) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len()];
+
+        let (_, written): (usize, usize) = SingleByte::from_utf8_into(array, &mut output, codepage, policy)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn from_utf16_into(array: &[u8], output: &mut [u8], codepage: Codepage, endian: bool, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        return SingleByte::encode_utf16_from(array, 0_usize, output, 0_usize, codepage, endian, policy);
+    }
+
+    pub fn from_utf16(array: &[u8], codepage: Codepage, endian: bool, policy: Policy) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len() / 2_usize];
+
+        let (_, written): (usize, usize) = SingleByte::from_utf16_into(array, &mut output, codepage, endian, policy)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+}
@@ -0,0 +1,67 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        SingleByte,
+        Codepage
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+impl SingleByte {
+    pub(crate) const fn locate_undefined(array: &[u8], mut index: usize, codepage: Codepage) -> Result<(), EncodingError> {
+        let length: usize = array.len();
+
+        while index < length {
+            if codepage.decode_byte(array[index]) == 0 && array[index] != 0 { return Err(EncodingError::new(index, EncodingErrorKind::Unmappable)); }
+
+            index += 1_usize;
+        }
+
+        return Ok(());
+    }
+
+    pub const fn is_single_byte(array: &[u8], codepage: Codepage) -> bool {
+        if array.is_empty() { return false; }
+
+        return SingleByte::locate_undefined(array, 0_usize, codepage).is_ok();
+    }
+
+    pub const fn validate_single_byte(array: &[u8], codepage: Codepage) -> Result<(), EncodingError> {
+        if array.is_empty() { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        return SingleByte::locate_undefined(array, 0_usize, codepage);
+    }
+}
@@ -0,0 +1,111 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF8,
+        Utf8Validator,
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+impl Utf8Validator {
+
+    pub const fn new() -> Utf8Validator {
+        return Utf8Validator { pending: [0_u8; 4_usize], pending_length: 0_usize, consumed: 0_usize, error: None };
+    }
+
+    fn check(&mut self, array: &[u8]) -> Result<usize, EncodingError> {
+        return match UTF8::validate_utf8(array) {
+            Ok(()) => Ok(array.len()),
+            Err(error) if error.kind() == EncodingErrorKind::TruncatedSequence => Ok(error.offset()),
+            Err(error) => {
+                let error: EncodingError = EncodingError::new(self.consumed + error.offset(), error.kind());
+
+                self.error = Some(error);
+
+                Err(error)
+            }
+        };
+    }
+
+    pub fn feed(&mut self, array: &[u8]) -> Result<(), EncodingError> {
+        if let Some(error) = self.error { return Err(error); }
+        else if array.is_empty() { return Ok(()); }
+
+        let (length, mut start): (usize, usize) = (array.len(), 0_usize);
+
+        if self.pending_length != 0_usize {
+            let (taken, mut head): (usize, [u8; 8_usize]) = (if length < 4_usize { length } else { 4_usize }, [0_u8; 8_usize]);
+
+            head[..self.pending_length].copy_from_slice(&self.pending[..self.pending_length]);
+            head[self.pending_length..self.pending_length + taken].copy_from_slice(&array[..taken]);
+
+            let valid: usize = self.check(&head[..self.pending_length + taken])?;
+
+            if valid < self.pending_length {
+                let remain: usize = self.pending_length + taken - valid;
+
+                self.pending[..remain].copy_from_slice(&head[valid..valid + remain]);
+
+                self.pending_length = remain; self.consumed += valid;
+
+                return Ok(());
+            }
+
+            start = valid - self.pending_length;
+
+            self.pending_length = 0_usize; self.consumed += valid;
+        }
+
+        if start < length {
+            let valid: usize = self.check(&array[start..])?;
+
+            self.pending_length = length - start - valid; self.consumed += valid;
+
+            self.pending[..self.pending_length].copy_from_slice(&array[start + valid..]);
+        }
+
+        return Ok(());
+    }
+
+    pub fn finish(self) -> Result<(), EncodingError> {
+        if let Some(error) = self.error { return Err(error); }
+        else if self.pending_length != 0_usize { return Err(EncodingError::new(self.consumed, EncodingErrorKind::TruncatedSequence)); }
+        else if self.consumed == 0_usize { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        return Ok(());
+    }
+}
+
+impl Default for Utf8Validator {
+    fn default() -> Utf8Validator {
+        return Utf8Validator::new();
+    }
+}
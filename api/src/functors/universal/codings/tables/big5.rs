@@ -0,0 +1,2609 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub(crate) const fn code_point(pointer: usize) -> u32 {
+    return if pointer < BIG5.len() { BIG5[pointer] } else { 0_u32 };
+}
+
+pub(crate) fn pointer(code: u32) -> Option<usize> {
+    return match BIG5_ENCODE.binary_search_by_key(&code, |&pointer| BIG5[pointer as usize]) {
+        Ok(index) => Some(BIG5_ENCODE[index] as usize),
+        Err(_) => None
+    };
+}
+
+pub(crate) static BIG5: [u32; 19782_usize] = [
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x043F0, 0x04C32, 0x04603, 0x045A6, 0x04578, 0x27267,
+    0x04D77, 0x045B3, 0x27CB1, 0x04CE2, 0x27CC5, 0x03B95, 0x04736, 0x04744, 0x04C47, 0x04C40, 0x242BF, 0x23617,
+    0x27352, 0x26E8B, 0x270D2, 0x04C57, 0x2A351, 0x0474F, 0x045DA, 0x04C85, 0x27C6C, 0x04D07, 0x04AA4, 0x046A1,
+    0x26B23, 0x07225, 0x25A54, 0x21A63, 0x23E06, 0x23F61, 0x0664D, 0x056FB, 0x00000, 0x07D95, 0x0591D, 0x28BB9,
+    0x03DF4, 0x09734, 0x27BEF, 0x05BDB, 0x21D5E, 0x05AA4, 0x03625, 0x29EB0, 0x05AD1, 0x05BB7, 0x05CFC, 0x0676E,
+    0x08593, 0x29945, 0x07461, 0x0749D, 0x03875, 0x21D53, 0x2369E, 0x26021, 0x03EEC, 0x258DE, 0x03AF5, 0x07AFC,
+    0x09F97, 0x24161, 0x2890D, 0x231EA, 0x20A8A, 0x2325E, 0x0430A, 0x08484, 0x09F96, 0x0942F, 0x04930, 0x08613,
+    0x05896, 0x0974A, 0x09218, 0x079D0, 0x07A32, 0x06660, 0x06A29, 0x0889D, 0x0744C, 0x07BC5, 0x06782, 0x07A2C,
+    0x0524F, 0x09046, 0x034E6, 0x073C4, 0x25DB9, 0x074C6, 0x09FC7, 0x057B3, 0x0492F, 0x0544C, 0x04131, 0x2368E,
+    0x05818, 0x07A72, 0x27B65, 0x08B8F, 0x046AE, 0x26E88, 0x04181, 0x25D99, 0x07BAE, 0x224BC, 0x09FC8, 0x224C1,
+    0x224C9, 0x224CC, 0x09FC9, 0x08504, 0x235BB, 0x040B4, 0x09FCA, 0x044E1, 0x2ADFF, 0x062C1, 0x0706E, 0x09FCB,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x031C0, 0x031C1, 0x031C2, 0x031C3, 0x031C4,
+    0x2010C, 0x031C5, 0x200D1, 0x200CD, 0x031C6, 0x031C7, 0x200CB, 0x21FE8, 0x031C8, 0x200CA, 0x031C9, 0x031CA,
+    0x031CB, 0x031CC, 0x2010E, 0x031CD, 0x031CE, 0x00100, 0x000C1, 0x001CD, 0x000C0, 0x00112, 0x000C9, 0x0011A,
+    0x000C8, 0x0014C, 0x000D3, 0x001D1, 0x000D2, 0x00000, 0x01EBE, 0x00000, 0x01EC0, 0x000CA, 0x00101, 0x000E1,
+    0x001CE, 0x000E0, 0x00251, 0x00113, 0x000E9, 0x0011B, 0x000E8, 0x0012B, 0x000ED, 0x001D0, 0x000EC, 0x0014D,
+    0x000F3, 0x001D2, 0x000F2, 0x0016B, 0x000FA, 0x001D4, 0x000F9, 0x001D6, 0x001D8, 0x001DA, 0x001DC, 0x000FC,
+    0x00000, 0x01EBF, 0x00000, 0x01EC1, 0x000EA, 0x00261, 0x023DA, 0x023DB, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x2A3A9, 0x21145, 0x00000, 0x0650A,
+    0x00000, 0x00000, 0x04E3D, 0x06EDD, 0x09D4E, 0x091DF, 0x00000, 0x00000, 0x27735, 0x06491, 0x04F1A, 0x04F28,
+    0x04FA8, 0x05156, 0x05174, 0x0519C, 0x051E4, 0x052A1, 0x052A8, 0x0533B, 0x0534E, 0x053D1, 0x053D8, 0x056E2,
+    0x058F0, 0x05904, 0x05907, 0x05932, 0x05934, 0x05B66, 0x05B9E, 0x05B9F, 0x05C9A, 0x05E86, 0x0603B, 0x06589,
+    0x067FE, 0x06804, 0x06865, 0x06D4E, 0x070BC, 0x07535, 0x07EA4, 0x07EAC, 0x07EBA, 0x07EC7, 0x07ECF, 0x07EDF,
+    0x07F06, 0x07F37, 0x0827A, 0x082CF, 0x0836F, 0x089C6, 0x08BBE, 0x08BE2, 0x08F66, 0x08F67, 0x08F6E, 0x07411,
+    0x07CFC, 0x07DCD, 0x06946, 0x07AC9, 0x05227, 0x00000, 0x00000, 0x00000, 0x00000, 0x0918C, 0x078B8, 0x0915E,
+    0x080BC, 0x00000, 0x08D0B, 0x080F6, 0x209E7, 0x00000, 0x00000, 0x0809F, 0x09EC7, 0x04CCD, 0x09DC9, 0x09E0C,
+    0x04C3E, 0x29DF6, 0x2700E, 0x09E0A, 0x2A133, 0x035C1, 0x00000, 0x06E9A, 0x0823E, 0x07519, 0x00000, 0x04911,
+    0x09A6C, 0x09A8F, 0x09F99, 0x07987, 0x2846C, 0x21DCA, 0x205D0, 0x22AE6, 0x04E24, 0x04E81, 0x04E80, 0x04E87,
+    0x04EBF, 0x04EEB, 0x04F37, 0x0344C, 0x04FBD, 0x03E48, 0x05003, 0x05088, 0x0347D, 0x03493, 0x034A5, 0x05186,
+    0x05905, 0x051DB, 0x051FC, 0x05205, 0x04E89, 0x05279, 0x05290, 0x05327, 0x035C7, 0x053A9, 0x03551, 0x053B0,
+    0x03553, 0x053C2, 0x05423, 0x0356D, 0x03572, 0x03681, 0x05493, 0x054A3, 0x054B4, 0x054B9, 0x054D0, 0x054EF,
+    0x05518, 0x05523, 0x05528, 0x03598, 0x0553F, 0x035A5, 0x035BF, 0x055D7, 0x035C5, 0x27D84, 0x05525, 0x00000,
+    0x20C42, 0x20D15, 0x2512B, 0x05590, 0x22CC6, 0x039EC, 0x20341, 0x08E46, 0x24DB8, 0x294E5, 0x04053, 0x280BE,
+    0x0777A, 0x22C38, 0x03A34, 0x047D5, 0x2815D, 0x269F2, 0x24DEA, 0x064DD, 0x20D7C, 0x20FB4, 0x20CD5, 0x210F4,
+    0x0648D, 0x08E7E, 0x20E96, 0x20C0B, 0x20F64, 0x22CA9, 0x28256, 0x244D3, 0x00000, 0x20D46, 0x29A4D, 0x280E9,
+    0x047F4, 0x24EA7, 0x22CC2, 0x09AB2, 0x03A67, 0x295F4, 0x03FED, 0x03506, 0x252C7, 0x297D4, 0x278C8, 0x22D44,
+    0x09D6E, 0x09815, 0x00000, 0x043D9, 0x260A5, 0x064B4, 0x054E3, 0x22D4C, 0x22BCA, 0x21077, 0x039FB, 0x2106F,
+    0x266DA, 0x26716, 0x279A0, 0x064EA, 0x25052, 0x20C43, 0x08E68, 0x221A1, 0x28B4C, 0x20731, 0x00000, 0x0480B,
+    0x201A9, 0x03FFA, 0x05873, 0x22D8D, 0x00000, 0x245C8, 0x204FC, 0x26097, 0x20F4C, 0x20D96, 0x05579, 0x040BB,
+    0x043BA, 0x00000, 0x04AB4, 0x22A66, 0x2109D, 0x081AA, 0x098F5, 0x20D9C, 0x06379, 0x039FE, 0x22775, 0x08DC0,
+    0x056A1, 0x0647C, 0x03E43, 0x00000, 0x2A601, 0x20E09, 0x22ACF, 0x22CC9, 0x00000, 0x210C8, 0x239C2, 0x03992,
+    0x03A06, 0x2829B, 0x03578, 0x25E49, 0x220C7, 0x05652, 0x20F31, 0x22CB2, 0x29720, 0x034BC, 0x06C3D, 0x24E3B,
+    0x00000, 0x00000, 0x27574, 0x22E8B, 0x22208, 0x2A65B, 0x28CCD, 0x20E7A, 0x20C34, 0x2681C, 0x07F93, 0x210CF,
+    0x22803, 0x22939, 0x035FB, 0x251E3, 0x20E8C, 0x20F8D, 0x20EAA, 0x03F93, 0x20F30, 0x20D47, 0x2114F, 0x20E4C,
+    0x00000, 0x20EAB, 0x20BA9, 0x20D48, 0x210C0, 0x2113D, 0x03FF9, 0x22696, 0x06432, 0x20FAD, 0x233F4, 0x27639,
+    0x22BCE, 0x20D7E, 0x20D7F, 0x22C51, 0x22C55, 0x03A18, 0x20E98, 0x210C7, 0x20F2E, 0x2A632, 0x26B50, 0x28CD2,
+    0x28D99, 0x28CCA, 0x095AA, 0x054CC, 0x082C4, 0x055B9, 0x00000, 0x29EC3, 0x09C26, 0x09AB6, 0x2775E, 0x22DEE,
+    0x07140, 0x0816D, 0x080EC, 0x05C1C, 0x26572, 0x08134, 0x03797, 0x0535F, 0x280BD, 0x091B6, 0x20EFA, 0x20E0F,
+    0x20E77, 0x20EFB, 0x035DD, 0x24DEB, 0x03609, 0x20CD6, 0x056AF, 0x227B5, 0x210C9, 0x20E10, 0x20E78, 0x21078,
+    0x21148, 0x28207, 0x21455, 0x20E79, 0x24E50, 0x22DA4, 0x05A54, 0x2101D, 0x2101E, 0x210F5, 0x210F6, 0x0579C,
+    0x20E11, 0x27694, 0x282CD, 0x20FB5, 0x20E7B, 0x2517E, 0x03703, 0x20FB6, 0x21180, 0x252D8, 0x2A2BD, 0x249DA,
+    0x2183A, 0x24177, 0x2827C, 0x05899, 0x05268, 0x0361A, 0x2573D, 0x07BB2, 0x05B68, 0x04800, 0x04B2C, 0x09F27,
+    0x049E7, 0x09C1F, 0x09B8D, 0x25B74, 0x2313D, 0x055FB, 0x035F2, 0x05689, 0x04E28, 0x05902, 0x21BC1, 0x2F878,
+    0x09751, 0x20086, 0x04E5B, 0x04EBB, 0x0353E, 0x05C23, 0x05F51, 0x05FC4, 0x038FA, 0x0624C, 0x06535, 0x06B7A,
+    0x06C35, 0x06C3A, 0x0706C, 0x0722B, 0x04E2C, 0x072AD, 0x248E9, 0x07F52, 0x0793B, 0x07CF9, 0x07F53, 0x2626A,
+    0x034C1, 0x00000, 0x2634B, 0x08002, 0x08080, 0x26612, 0x26951, 0x0535D, 0x08864, 0x089C1, 0x278B2, 0x08BA0,
+    0x08D1D, 0x09485, 0x09578, 0x0957F, 0x095E8, 0x28E0F, 0x097E6, 0x09875, 0x098CE, 0x098DE, 0x09963, 0x29810,
+    0x09C7C, 0x09E1F, 0x09EC4, 0x06B6F, 0x0F907, 0x04E37, 0x20087, 0x0961D, 0x06237, 0x094A2, 0x00000, 0x0503B,
+    0x06DFE, 0x29C73, 0x09FA6, 0x03DC9, 0x0888F, 0x2414E, 0x07077, 0x05CF5, 0x04B20, 0x251CD, 0x03559, 0x25D30,
+    0x06122, 0x28A32, 0x08FA7, 0x091F6, 0x07191, 0x06719, 0x073BA, 0x23281, 0x2A107, 0x03C8B, 0x21980, 0x04B10,
+    0x078E4, 0x07402, 0x051AE, 0x2870F, 0x04009, 0x06A63, 0x2A2BA, 0x04223, 0x0860F, 0x20A6F, 0x07A2A, 0x29947,
+    0x28AEA, 0x09755, 0x0704D, 0x05324, 0x2207E, 0x093F4, 0x076D9, 0x289E3, 0x09FA7, 0x077DD, 0x04EA3, 0x04FF0,
+    0x050BC, 0x04E2F, 0x04F17, 0x09FA8, 0x05434, 0x07D8B, 0x05892, 0x058D0, 0x21DB6, 0x05E92, 0x05E99, 0x05FC2,
+    0x22712, 0x0658B, 0x233F9, 0x06919, 0x06A43, 0x23C63, 0x06CFF, 0x00000, 0x07200, 0x24505, 0x0738C, 0x03EDB,
+    0x24A13, 0x05B15, 0x074B9, 0x08B83, 0x25CA4, 0x25695, 0x07A93, 0x07BEC, 0x07CC3, 0x07E6C, 0x082F8, 0x08597,
+    0x09FA9, 0x08890, 0x09FAA, 0x08EB9, 0x09FAB, 0x08FCF, 0x0855F, 0x099E0, 0x09221, 0x09FAC, 0x28DB9, 0x2143F,
+    0x04071, 0x042A2, 0x05A1A, 0x00000, 0x00000, 0x00000, 0x09868, 0x0676B, 0x04276, 0x0573D, 0x00000, 0x085D6,
+    0x2497B, 0x082BF, 0x2710D, 0x04C81, 0x26D74, 0x05D7B, 0x26B15, 0x26FBE, 0x09FAD, 0x09FAE, 0x05B96, 0x09FAF,
+    0x066E7, 0x07E5B, 0x06E57, 0x079CA, 0x03D88, 0x044C3, 0x23256, 0x22796, 0x0439A, 0x04536, 0x00000, 0x05CD5,
+    0x23B1A, 0x08AF9, 0x05C78, 0x03D12, 0x23551, 0x05D78, 0x09FB2, 0x07157, 0x04558, 0x240EC, 0x21E23, 0x04C77,
+    0x03978, 0x0344A, 0x201A4, 0x26C41, 0x08ACC, 0x04FB4, 0x20239, 0x059BF, 0x0816C, 0x09856, 0x298FA, 0x05F3B,
+    0x20B9F, 0x00000, 0x221C1, 0x2896D, 0x04102, 0x046BB, 0x29079, 0x03F07, 0x09FB3, 0x2A1B5, 0x040F8, 0x037D6,
+    0x046F7, 0x26C46, 0x0417C, 0x286B2, 0x273FF, 0x0456D, 0x038D4, 0x2549A, 0x04561, 0x0451B, 0x04D89, 0x04C7B,
+    0x04D76, 0x045EA, 0x03FC8, 0x24B0F, 0x03661, 0x044DE, 0x044BD, 0x041ED, 0x05D3E, 0x05D48, 0x05D56, 0x03DFC,
+    0x0380F, 0x05DA4, 0x05DB9, 0x03820, 0x03838, 0x05E42, 0x05EBD, 0x05F25, 0x05F83, 0x03908, 0x03914, 0x0393F,
+    0x0394D, 0x060D7, 0x0613D, 0x05CE5, 0x03989, 0x061B7, 0x061B9, 0x061CF, 0x039B8, 0x0622C, 0x06290, 0x062E5,
+    0x06318, 0x039F8, 0x056B1, 0x03A03, 0x063E2, 0x063FB, 0x06407, 0x0645A, 0x03A4B, 0x064C0, 0x05D15, 0x05621,
+    0x09F9F, 0x03A97, 0x06586, 0x03ABD, 0x065FF, 0x06653, 0x03AF2, 0x06692, 0x03B22, 0x06716, 0x03B42, 0x067A4,
+    0x06800, 0x03B58, 0x0684A, 0x06884, 0x03B72, 0x03B71, 0x03B7B, 0x06909, 0x06943, 0x0725C, 0x06964, 0x0699F,
+    0x06985, 0x03BBC, 0x069D6, 0x03BDD, 0x06A65, 0x06A74, 0x06A71, 0x06A82, 0x03BEC, 0x06A99, 0x03BF2, 0x06AAB,
+    0x06AB5, 0x06AD4, 0x06AF6, 0x06B81, 0x06BC1, 0x06BEA, 0x06C75, 0x06CAA, 0x03CCB, 0x06D02, 0x06D06, 0x06D26,
+    0x06D81, 0x03CEF, 0x06DA4, 0x06DB1, 0x06E15, 0x06E18, 0x06E29, 0x06E86, 0x289C0, 0x06EBB, 0x06EE2, 0x06EDA,
+    0x09F7F, 0x06EE8, 0x06EE9, 0x06F24, 0x06F34, 0x03D46, 0x23F41, 0x06F81, 0x06FBE, 0x03D6A, 0x03D75, 0x071B7,
+    0x05C99, 0x03D8A, 0x0702C, 0x03D91, 0x07050, 0x07054, 0x0706F, 0x0707F, 0x07089, 0x20325, 0x043C1, 0x035F1,
+    0x20ED8, 0x23ED7, 0x057BE, 0x26ED3, 0x0713E, 0x257E0, 0x0364E, 0x069A2, 0x28BE9, 0x05B74, 0x07A49, 0x258E1,
+    0x294D9, 0x07A65, 0x07A7D, 0x259AC, 0x07ABB, 0x07AB0, 0x07AC2, 0x07AC3, 0x071D1, 0x2648D, 0x041CA, 0x07ADA,
+    0x07ADD, 0x07AEA, 0x041EF, 0x054B2, 0x25C01, 0x07B0B, 0x07B55, 0x07B29, 0x2530E, 0x25CFE, 0x07BA2, 0x07B6F,
+    0x0839C, 0x25BB4, 0x26C7F, 0x07BD0, 0x08421, 0x07B92, 0x07BB8, 0x25D20, 0x03DAD, 0x25C65, 0x08492, 0x07BFA,
+    0x07C06, 0x07C35, 0x25CC1, 0x07C44, 0x07C83, 0x24882, 0x07CA6, 0x0667D, 0x24578, 0x07CC9, 0x07CC7, 0x07CE6,
+    0x07C74, 0x07CF3, 0x07CF5, 0x07CCE, 0x07E67, 0x0451D, 0x26E44, 0x07D5D, 0x26ED6, 0x0748D, 0x07D89, 0x07DAB,
+    0x07135, 0x07DB3, 0x07DD2, 0x24057, 0x26029, 0x07DE4, 0x03D13, 0x07DF5, 0x217F9, 0x07DE5, 0x2836D, 0x07E1D,
+    0x26121, 0x2615A, 0x07E6E, 0x07E92, 0x0432B, 0x0946C, 0x07E27, 0x07F40, 0x07F41, 0x07F47, 0x07936, 0x262D0,
+    0x099E1, 0x07F97, 0x26351, 0x07FA3, 0x21661, 0x20068, 0x0455C, 0x23766, 0x04503, 0x2833A, 0x07FFA, 0x26489,
+    0x08005, 0x08008, 0x0801D, 0x08028, 0x0802F, 0x2A087, 0x26CC3, 0x0803B, 0x0803C, 0x08061, 0x22714, 0x04989,
+    0x26626, 0x23DE3, 0x266E8, 0x06725, 0x080A7, 0x28A48, 0x08107, 0x0811A, 0x058B0, 0x226F6, 0x06C7F, 0x26498,
+    0x24FB8, 0x064E7, 0x2148A, 0x08218, 0x2185E, 0x06A53, 0x24A65, 0x24A95, 0x0447A, 0x08229, 0x20B0D, 0x26A52,
+    0x23D7E, 0x04FF9, 0x214FD, 0x084E2, 0x08362, 0x26B0A, 0x249A7, 0x23530, 0x21773, 0x23DF8, 0x082AA, 0x0691B,
+    0x2F994, 0x041DB, 0x0854B, 0x082D0, 0x0831A, 0x20E16, 0x217B4, 0x036C1, 0x2317D, 0x2355A, 0x0827B, 0x082E2,
+    0x08318, 0x23E8B, 0x26DA3, 0x26B05, 0x26B97, 0x235CE, 0x03DBF, 0x0831D, 0x055EC, 0x08385, 0x0450B, 0x26DA5,
+    0x083AC, 0x083C1, 0x083D3, 0x0347E, 0x26ED4, 0x06A57, 0x0855A, 0x03496, 0x26E42, 0x22EEF, 0x08458, 0x25BE4,
+    0x08471, 0x03DD3, 0x044E4, 0x06AA7, 0x0844A, 0x23CB5, 0x07958, 0x084A8, 0x26B96, 0x26E77, 0x26E43, 0x084DE,
+    0x0840F, 0x08391, 0x044A0, 0x08493, 0x084E4, 0x25C91, 0x04240, 0x25CC0, 0x04543, 0x08534, 0x05AF2, 0x26E99,
+    0x04527, 0x08573, 0x04516, 0x067BF, 0x08616, 0x28625, 0x2863B, 0x085C1, 0x27088, 0x08602, 0x21582, 0x270CD,
+    0x2F9B2, 0x0456A, 0x08628, 0x03648, 0x218A2, 0x053F7, 0x2739A, 0x0867E, 0x08771, 0x2A0F8, 0x087EE, 0x22C27,
+    0x087B1, 0x087DA, 0x0880F, 0x05661, 0x0866C, 0x06856, 0x0460F, 0x08845, 0x08846, 0x275E0, 0x23DB9, 0x275E4,
+    0x0885E, 0x0889C, 0x0465B, 0x088B4, 0x088B5, 0x063C1, 0x088C5, 0x07777, 0x2770F, 0x08987, 0x0898A, 0x089A6,
+    0x089A9, 0x089A7, 0x089BC, 0x28A25, 0x089E7, 0x27924, 0x27ABD, 0x08A9C, 0x07793, 0x091FE, 0x08A90, 0x27A59,
+    0x07AE9, 0x27B3A, 0x23F8F, 0x04713, 0x27B38, 0x0717C, 0x08B0C, 0x08B1F, 0x25430, 0x25565, 0x08B3F, 0x08B4C,
+    0x08B4D, 0x08AA9, 0x24A7A, 0x08B90, 0x08B9B, 0x08AAF, 0x216DF, 0x04615, 0x0884F, 0x08C9B, 0x27D54, 0x27D8F,
+    0x2F9D4, 0x03725, 0x27D53, 0x08CD6, 0x27D98, 0x27DBD, 0x08D12, 0x08D03, 0x21910, 0x08CDB, 0x0705C, 0x08D11,
+    0x24CC9, 0x03ED0, 0x08D77, 0x08DA9, 0x28002, 0x21014, 0x2498A, 0x03B7C, 0x281BC, 0x2710C, 0x07AE7, 0x08EAD,
+    0x08EB6, 0x08EC3, 0x092D4, 0x08F19, 0x08F2D, 0x28365, 0x28412, 0x08FA5, 0x09303, 0x2A29F, 0x20A50, 0x08FB3,
+    0x0492A, 0x289DE, 0x2853D, 0x23DBB, 0x05EF8, 0x23262, 0x08FF9, 0x2A014, 0x286BC, 0x28501, 0x22325, 0x03980,
+    0x26ED7, 0x09037, 0x2853C, 0x27ABE, 0x09061, 0x2856C, 0x2860B, 0x090A8, 0x28713, 0x090C4, 0x286E6, 0x090AE,
+    0x090FD, 0x09167, 0x03AF0, 0x091A9, 0x091C4, 0x07CAC, 0x28933, 0x21E89, 0x0920E, 0x06C9F, 0x09241, 0x09262,
+    0x255B9, 0x092B9, 0x28AC6, 0x23C9B, 0x28B0C, 0x255DB, 0x20D31, 0x0932C, 0x0936B, 0x28AE1, 0x28BEB, 0x0708F,
+    0x05AC3, 0x28AE2, 0x28AE5, 0x04965, 0x09244, 0x28BEC, 0x28C39, 0x28BFF, 0x09373, 0x0945B, 0x08EBC, 0x09585,
+    0x095A6, 0x09426, 0x095A0, 0x06FF6, 0x042B9, 0x2267A, 0x286D8, 0x2127C, 0x23E2E, 0x049DF, 0x06C1C, 0x0967B,
+    0x09696, 0x0416C, 0x096A3, 0x26ED5, 0x061DA, 0x096B6, 0x078F5, 0x28AE0, 0x096BD, 0x053CC, 0x049A1, 0x26CB8,
+    0x20274, 0x26410, 0x290AF, 0x290E5, 0x24AD1, 0x21915, 0x2330A, 0x09731, 0x08642, 0x09736, 0x04A0F, 0x0453D,
+    0x04585, 0x24AE9, 0x07075, 0x05B41, 0x0971B, 0x0975C, 0x291D5, 0x09757, 0x05B4A, 0x291EB, 0x0975F, 0x09425,
+    0x050D0, 0x230B7, 0x230BC, 0x09789, 0x0979F, 0x097B1, 0x097BE, 0x097C0, 0x097D2, 0x097E0, 0x2546C, 0x097EE,
+    0x0741C, 0x29433, 0x097FF, 0x097F5, 0x2941D, 0x2797A, 0x04AD1, 0x09834, 0x09833, 0x0984B, 0x09866, 0x03B0E,
+    0x27175, 0x03D51, 0x20630, 0x2415C, 0x25706, 0x098CA, 0x098B7, 0x098C8, 0x098C7, 0x04AFF, 0x26D27, 0x216D3,
+    0x055B0, 0x098E1, 0x098E6, 0x098EC, 0x09378, 0x09939, 0x24A29, 0x04B72, 0x29857, 0x29905, 0x099F5, 0x09A0C,
+    0x09A3B, 0x09A10, 0x09A58, 0x25725, 0x036C4, 0x290B1, 0x29BD5, 0x09AE0, 0x09AE2, 0x29B05, 0x09AF4, 0x04C0E,
+    0x09B14, 0x09B2D, 0x28600, 0x05034, 0x09B34, 0x269A8, 0x038C3, 0x2307D, 0x09B50, 0x09B40, 0x29D3E, 0x05A45,
+    0x21863, 0x09B8E, 0x2424B, 0x09C02, 0x09BFF, 0x09C0C, 0x29E68, 0x09DD4, 0x29FB7, 0x2A192, 0x2A1AB, 0x2A0E1,
+    0x2A123, 0x2A1DF, 0x09D7E, 0x09D83, 0x2A134, 0x09E0E, 0x06888, 0x09DC4, 0x2215B, 0x2A193, 0x2A220, 0x2193B,
+    0x2A233, 0x09D39, 0x2A0B9, 0x2A2B4, 0x09E90, 0x09E95, 0x09E9E, 0x09EA2, 0x04D34, 0x09EAA, 0x09EAF, 0x24364,
+    0x09EC1, 0x03B60, 0x039E5, 0x03D1D, 0x04F32, 0x037BE, 0x28C2B, 0x09F02, 0x09F08, 0x04B96, 0x09424, 0x26DA2,
+    0x09F17, 0x09F16, 0x09F39, 0x0569F, 0x0568A, 0x09F45, 0x099B8, 0x2908B, 0x097F2, 0x0847F, 0x09F62, 0x09F69,
+    0x07ADC, 0x09F8E, 0x07216, 0x04BBE, 0x24975, 0x249BB, 0x07177, 0x249F8, 0x24348, 0x24A51, 0x0739E, 0x28BDA,
+    0x218FA, 0x0799F, 0x2897E, 0x28E36, 0x09369, 0x093F3, 0x28A44, 0x092EC, 0x09381, 0x093CB, 0x2896C, 0x244B9,
+    0x07217, 0x03EEB, 0x07772, 0x07A43, 0x070D0, 0x24473, 0x243F8, 0x0717E, 0x217EF, 0x070A3, 0x218BE, 0x23599,
+    0x03EC7, 0x21885, 0x2542F, 0x217F8, 0x03722, 0x216FB, 0x21839, 0x036E1, 0x21774, 0x218D1, 0x25F4B, 0x03723,
+    0x216C0, 0x0575B, 0x24A25, 0x213FE, 0x212A8, 0x213C6, 0x214B6, 0x08503, 0x236A6, 0x08503, 0x08455, 0x24994,
+    0x27165, 0x23E31, 0x2555C, 0x23EFB, 0x27052, 0x044F4, 0x236EE, 0x2999D, 0x26F26, 0x067F9, 0x03733, 0x03C15,
+    0x03DE7, 0x0586C, 0x21922, 0x06810, 0x04057, 0x2373F, 0x240E1, 0x2408B, 0x2410F, 0x26C21, 0x054CB, 0x0569E,
+    0x266B1, 0x05692, 0x20FDF, 0x20BA8, 0x20E0D, 0x093C6, 0x28B13, 0x0939C, 0x04EF8, 0x0512B, 0x03819, 0x24436,
+    0x04EBC, 0x20465, 0x2037F, 0x04F4B, 0x04F8A, 0x25651, 0x05A68, 0x201AB, 0x203CB, 0x03999, 0x2030A, 0x20414,
+    0x03435, 0x04F29, 0x202C0, 0x28EB3, 0x20275, 0x08ADA, 0x2020C, 0x04E98, 0x050CD, 0x0510D, 0x04FA2, 0x04F03,
+    0x24A0E, 0x23E8A, 0x04F42, 0x0502E, 0x0506C, 0x05081, 0x04FCC, 0x04FE5, 0x05058, 0x050FC, 0x05159, 0x0515B,
+    0x0515D, 0x0515E, 0x06E76, 0x23595, 0x23E39, 0x23EBF, 0x06D72, 0x21884, 0x23E89, 0x051A8, 0x051C3, 0x205E0,
+    0x044DD, 0x204A3, 0x20492, 0x20491, 0x08D7A, 0x28A9C, 0x2070E, 0x05259, 0x052A4, 0x20873, 0x052E1, 0x0936E,
+    0x0467A, 0x0718C, 0x2438C, 0x20C20, 0x249AC, 0x210E4, 0x069D1, 0x20E1D, 0x07479, 0x03EDE, 0x07499, 0x07414,
+    0x07456, 0x07398, 0x04B8E, 0x24ABC, 0x2408D, 0x053D0, 0x03584, 0x0720F, 0x240C9, 0x055B4, 0x20345, 0x054CD,
+    0x20BC6, 0x0571D, 0x0925D, 0x096F4, 0x09366, 0x057DD, 0x0578D, 0x0577F, 0x0363E, 0x058CB, 0x05A99, 0x28A46,
+    0x216FA, 0x2176F, 0x21710, 0x05A2C, 0x059B8, 0x0928F, 0x05A7E, 0x05ACF, 0x05A12, 0x25946, 0x219F3, 0x21861,
+    0x24295, 0x036F5, 0x06D05, 0x07443, 0x05A21, 0x25E83, 0x05A81, 0x28BD7, 0x20413, 0x093E0, 0x0748C, 0x21303,
+    0x07105, 0x04972, 0x09408, 0x289FB, 0x093BD, 0x037A0, 0x05C1E, 0x05C9E, 0x05E5E, 0x05E48, 0x21996, 0x2197C,
+    0x23AEE, 0x05ECD, 0x05B4F, 0x21903, 0x21904, 0x03701, 0x218A0, 0x036DD, 0x216FE, 0x036D3, 0x0812A, 0x28A47,
+    0x21DBA, 0x23472, 0x289A8, 0x05F0C, 0x05F0E, 0x21927, 0x217AB, 0x05A6B, 0x2173B, 0x05B44, 0x08614, 0x275FD,
+    0x08860, 0x0607E, 0x22860, 0x2262B, 0x05FDB, 0x03EB8, 0x225AF, 0x225BE, 0x29088, 0x26F73, 0x061C0, 0x2003E,
+    0x20046, 0x2261B, 0x06199, 0x06198, 0x06075, 0x22C9B, 0x22D07, 0x246D4, 0x2914D, 0x06471, 0x24665, 0x22B6A,
+    0x03A29, 0x22B22, 0x23450, 0x298EA, 0x22E78, 0x06337, 0x2A45B, 0x064B6, 0x06331, 0x063D1, 0x249E3, 0x22D67,
+    0x062A4, 0x22CA1, 0x0643B, 0x0656B, 0x06972, 0x03BF4, 0x2308E, 0x232AD, 0x24989, 0x232AB, 0x0550D, 0x232E0,
+    0x218D9, 0x2943F, 0x066CE, 0x23289, 0x231B3, 0x03AE0, 0x04190, 0x25584, 0x28B22, 0x2558F, 0x216FC, 0x2555B,
+    0x25425, 0x078EE, 0x23103, 0x2182A, 0x23234, 0x03464, 0x2320F, 0x23182, 0x242C9, 0x0668E, 0x26D24, 0x0666B,
+    0x04B93, 0x06630, 0x27870, 0x21DEB, 0x06663, 0x232D2, 0x232E1, 0x0661E, 0x25872, 0x038D1, 0x2383A, 0x237BC,
+    0x03B99, 0x237A2, 0x233FE, 0x074D0, 0x03B96, 0x0678F, 0x2462A, 0x068B6, 0x0681E, 0x03BC4, 0x06ABE, 0x03863,
+    0x237D5, 0x24487, 0x06A33, 0x06A52, 0x06AC9, 0x06B05, 0x21912, 0x06511, 0x06898, 0x06A4C, 0x03BD7, 0x06A7A,
+    0x06B57, 0x23FC0, 0x23C9A, 0x093A0, 0x092F2, 0x28BEA, 0x28ACB, 0x09289, 0x2801E, 0x289DC, 0x09467, 0x06DA5,
+    0x06F0B, 0x249EC, 0x06D67, 0x23F7F, 0x03D8F, 0x06E04, 0x2403C, 0x05A3D, 0x06E0A, 0x05847, 0x06D24, 0x07842,
+    0x0713B, 0x2431A, 0x24276, 0x070F1, 0x07250, 0x07287, 0x07294, 0x2478F, 0x24725, 0x05179, 0x24AA4, 0x205EB,
+    0x0747A, 0x23EF8, 0x2365F, 0x24A4A, 0x24917, 0x25FE1, 0x03F06, 0x03EB1, 0x24ADF, 0x28C23, 0x23F35, 0x060A7,
+    0x03EF3, 0x074CC, 0x0743C, 0x09387, 0x07437, 0x0449F, 0x26DEA, 0x04551, 0x07583, 0x03F63, 0x24CD9, 0x24D06,
+    0x03F58, 0x07555, 0x07673, 0x2A5C6, 0x03B19, 0x07468, 0x28ACC, 0x249AB, 0x2498E, 0x03AFB, 0x03DCD, 0x24A4E,
+    0x03EFF, 0x249C5, 0x248F3, 0x091FA, 0x05732, 0x09342, 0x28AE3, 0x21864, 0x050DF, 0x25221, 0x251E7, 0x07778,
+    0x23232, 0x0770E, 0x0770F, 0x0777B, 0x24697, 0x23781, 0x03A5E, 0x248F0, 0x07438, 0x0749B, 0x03EBF, 0x24ABA,
+    0x24AC7, 0x040C8, 0x24A96, 0x261AE, 0x09307, 0x25581, 0x0781E, 0x0788D, 0x07888, 0x078D2, 0x073D0, 0x07959,
+    0x27741, 0x256E3, 0x0410E, 0x0799B, 0x08496, 0x079A5, 0x06A2D, 0x23EFA, 0x07A3A, 0x079F4, 0x0416E, 0x216E6,
+    0x04132, 0x09235, 0x079F1, 0x20D4C, 0x2498C, 0x20299, 0x23DBA, 0x2176E, 0x03597, 0x0556B, 0x03570, 0x036AA,
+    0x201D4, 0x20C0D, 0x07AE2, 0x05A59, 0x226F5, 0x25AAF, 0x25A9C, 0x05A0D, 0x2025B, 0x078F0, 0x05A2A, 0x25BC6,
+    0x07AFE, 0x041F9, 0x07C5D, 0x07C6D, 0x04211, 0x25BB3, 0x25EBC, 0x25EA6, 0x07CCD, 0x249F9, 0x217B0, 0x07C8E,
+    0x07C7C, 0x07CAE, 0x06AB2, 0x07DDC, 0x07E07, 0x07DD3, 0x07F4E, 0x26261, 0x2615C, 0x27B48, 0x07D97, 0x25E82,
+    0x0426A, 0x26B75, 0x20916, 0x067D6, 0x2004E, 0x235CF, 0x057C4, 0x26412, 0x263F8, 0x24962, 0x07FDD, 0x07B27,
+    0x2082C, 0x25AE9, 0x25D43, 0x07B0C, 0x25E0E, 0x099E6, 0x08645, 0x09A63, 0x06A1C, 0x2343F, 0x039E2, 0x249F7,
+    0x265AD, 0x09A1F, 0x265A0, 0x08480, 0x27127, 0x26CD1, 0x044EA, 0x08137, 0x04402, 0x080C6, 0x08109, 0x08142,
+    0x267B4, 0x098C3, 0x26A42, 0x08262, 0x08265, 0x26A51, 0x08453, 0x26DA7, 0x08610, 0x2721B, 0x05A86, 0x0417F,
+    0x21840, 0x05B2B, 0x218A1, 0x05AE4, 0x218D8, 0x086A0, 0x2F9BC, 0x23D8F, 0x0882D, 0x27422, 0x05A02, 0x0886E,
+    0x04F45, 0x08887, 0x088BF, 0x088E6, 0x08965, 0x0894D, 0x25683, 0x08954, 0x27785, 0x27784, 0x28BF5, 0x28BD9,
+    0x28B9C, 0x289F9, 0x03EAD, 0x084A3, 0x046F5, 0x046CF, 0x037F2, 0x08A3D, 0x08A1C, 0x29448, 0x05F4D, 0x0922B,
+    0x24284, 0x065D4, 0x07129, 0x070C4, 0x21845, 0x09D6D, 0x08C9F, 0x08CE9, 0x27DDC, 0x0599A, 0x077C3, 0x059F0,
+    0x0436E, 0x036D4, 0x08E2A, 0x08EA7, 0x24C09, 0x08F30, 0x08F4A, 0x042F4, 0x06C58, 0x06FBB, 0x22321, 0x0489B,
+    0x06F79, 0x06E8B, 0x217DA, 0x09BE9, 0x036B5, 0x2492F, 0x090BB, 0x09097, 0x05571, 0x04906, 0x091BB, 0x09404,
+    0x28A4B, 0x04062, 0x28AFC, 0x09427, 0x28C1D, 0x28C3B, 0x084E5, 0x08A2B, 0x09599, 0x095A7, 0x09597, 0x09596,
+    0x28D34, 0x07445, 0x03EC2, 0x248FF, 0x24A42, 0x243EA, 0x03EE7, 0x23225, 0x0968F, 0x28EE7, 0x28E66, 0x28E65,
+    0x03ECC, 0x249ED, 0x24A78, 0x23FEE, 0x07412, 0x0746B, 0x03EFC, 0x09741, 0x290B0, 0x06847, 0x04A1D, 0x29093,
+    0x257DF, 0x0975D, 0x09368, 0x28989, 0x28C26, 0x28B2F, 0x263BE, 0x092BA, 0x05B11, 0x08B69, 0x0493C, 0x073F9,
+    0x2421B, 0x0979B, 0x09771, 0x09938, 0x20F26, 0x05DC1, 0x28BC5, 0x24AB2, 0x0981F, 0x294DA, 0x092F6, 0x295D7,
+    0x091E5, 0x044C0, 0x28B50, 0x24A67, 0x28B64, 0x098DC, 0x28A45, 0x03F00, 0x0922A, 0x04925, 0x08414, 0x0993B,
+    0x0994D, 0x27B06, 0x03DFD, 0x0999B, 0x04B6F, 0x099AA, 0x09A5C, 0x28B65, 0x258C8, 0x06A8F, 0x09A21, 0x05AFE,
+    0x09A2F, 0x298F1, 0x04B90, 0x29948, 0x099BC, 0x04BBD, 0x04B97, 0x0937D, 0x05872, 0x21302, 0x05822, 0x249B8,
+    0x214E8, 0x07844, 0x2271F, 0x23DB8, 0x068C5, 0x03D7D, 0x09458, 0x03927, 0x06150, 0x22781, 0x2296B, 0x06107,
+    0x09C4F, 0x09C53, 0x09C7B, 0x09C35, 0x09C10, 0x09B7F, 0x09BCF, 0x29E2D, 0x09B9F, 0x2A1F5, 0x2A0FE, 0x09D21,
+    0x04CAE, 0x24104, 0x09E18, 0x04CB0, 0x09D0C, 0x2A1B4, 0x2A0ED, 0x2A0F3, 0x2992F, 0x09DA5, 0x084BD, 0x26E12,
+    0x26FDF, 0x26B82, 0x085FC, 0x04533, 0x26DA4, 0x26E84, 0x26DF0, 0x08420, 0x085EE, 0x26E00, 0x237D7, 0x26064,
+    0x079E2, 0x2359C, 0x23640, 0x0492D, 0x249DE, 0x03D62, 0x093DB, 0x092BE, 0x09348, 0x202BF, 0x078B9, 0x09277,
+    0x0944D, 0x04FE4, 0x03440, 0x09064, 0x2555D, 0x0783D, 0x07854, 0x078B6, 0x0784B, 0x21757, 0x231C9, 0x24941,
+    0x0369A, 0x04F72, 0x06FDA, 0x06FD9, 0x0701E, 0x0701E, 0x05414, 0x241B5, 0x057BB, 0x058F3, 0x0578A, 0x09D16,
+    0x057D7, 0x07134, 0x034AF, 0x241AC, 0x071EB, 0x26C40, 0x24F97, 0x05B28, 0x217B5, 0x28A49, 0x0610C, 0x05ACE,
+    0x05A0B, 0x042BC, 0x24488, 0x0372C, 0x04B7B, 0x289FC, 0x093BB, 0x093B8, 0x218D6, 0x20F1D, 0x08472, 0x26CC0,
+    0x21413, 0x242FA, 0x22C26, 0x243C1, 0x05994, 0x23DB7, 0x26741, 0x07DA8, 0x2615B, 0x260A4, 0x249B9, 0x2498B,
+    0x289FA, 0x092E5, 0x073E2, 0x03EE9, 0x074B4, 0x28B63, 0x2189F, 0x03EE1, 0x24AB3, 0x06AD8, 0x073F3, 0x073FB,
+    0x03ED6, 0x24A3E, 0x24A94, 0x217D9, 0x24A66, 0x203A7, 0x21424, 0x249E5, 0x07448, 0x24916, 0x070A5, 0x24976,
+    0x09284, 0x073E6, 0x0935F, 0x204FE, 0x09331, 0x28ACE, 0x28A16, 0x09386, 0x28BE7, 0x255D5, 0x04935, 0x28A82,
+    0x0716B, 0x24943, 0x20CFF, 0x056A4, 0x2061A, 0x20BEB, 0x20CB8, 0x05502, 0x079C4, 0x217FA, 0x07DFE, 0x216C2,
+    0x24A50, 0x21852, 0x0452E, 0x09401, 0x0370A, 0x28AC0, 0x249AD, 0x059B0, 0x218BF, 0x21883, 0x27484, 0x05AA1,
+    0x036E2, 0x23D5B, 0x036B0, 0x0925F, 0x05A79, 0x28A81, 0x21862, 0x09374, 0x03CCD, 0x20AB4, 0x04A96, 0x0398A,
+    0x050F4, 0x03D69, 0x03D4C, 0x2139C, 0x07175, 0x042FB, 0x28218, 0x06E0F, 0x290E4, 0x044EB, 0x06D57, 0x27E4F,
+    0x07067, 0x06CAF, 0x03CD6, 0x23FED, 0x23E2D, 0x06E02, 0x06F0C, 0x03D6F, 0x203F5, 0x07551, 0x036BC, 0x034C8,
+    0x04680, 0x03EDA, 0x04871, 0x059C4, 0x0926E, 0x0493E, 0x08F41, 0x28C1C, 0x26BC0, 0x05812, 0x057C8, 0x036D6,
+    0x21452, 0x070FE, 0x24362, 0x24A71, 0x22FE3, 0x212B0, 0x223BD, 0x068B9, 0x06967, 0x21398, 0x234E5, 0x27BF4,
+    0x236DF, 0x28A83, 0x237D6, 0x233FA, 0x24C9F, 0x06A1A, 0x236AD, 0x26CB7, 0x0843E, 0x044DF, 0x044CE, 0x26D26,
+    0x26D51, 0x26C82, 0x26FDE, 0x06F17, 0x27109, 0x0833D, 0x2173A, 0x083ED, 0x26C80, 0x27053, 0x217DB, 0x05989,
+    0x05A82, 0x217B3, 0x05A61, 0x05A71, 0x21905, 0x241FC, 0x0372D, 0x059EF, 0x2173C, 0x036C7, 0x0718E, 0x09390,
+    0x0669A, 0x242A5, 0x05A6E, 0x05A2B, 0x24293, 0x06A2B, 0x23EF9, 0x27736, 0x2445B, 0x242CA, 0x0711D, 0x24259,
+    0x289E1, 0x04FB0, 0x26D28, 0x05CC2, 0x244CE, 0x27E4D, 0x243BD, 0x06A0C, 0x24256, 0x21304, 0x070A6, 0x07133,
+    0x243E9, 0x03DA5, 0x06CDF, 0x2F825, 0x24A4F, 0x07E65, 0x059EB, 0x05D2F, 0x03DF3, 0x05F5C, 0x24A5D, 0x217DF,
+    0x07DA4, 0x08426, 0x05485, 0x23AFA, 0x23300, 0x20214, 0x0577E, 0x208D5, 0x20619, 0x03FE5, 0x21F9E, 0x2A2B6,
+    0x07003, 0x2915B, 0x05D70, 0x0738F, 0x07CD3, 0x28A59, 0x29420, 0x04FC8, 0x07FE7, 0x072CD, 0x07310, 0x27AF4,
+    0x07338, 0x07339, 0x256F6, 0x07341, 0x07348, 0x03EA9, 0x27B18, 0x0906C, 0x071F5, 0x248F2, 0x073E1, 0x081F6,
+    0x03ECA, 0x0770C, 0x03ED1, 0x06CA2, 0x056FD, 0x07419, 0x0741E, 0x0741F, 0x03EE2, 0x03EF0, 0x03EF4, 0x03EFA,
+    0x074D3, 0x03F0E, 0x03F53, 0x07542, 0x0756D, 0x07572, 0x0758D, 0x03F7C, 0x075C8, 0x075DC, 0x03FC0, 0x0764D,
+    0x03FD7, 0x07674, 0x03FDC, 0x0767A, 0x24F5C, 0x07188, 0x05623, 0x08980, 0x05869, 0x0401D, 0x07743, 0x04039,
+    0x06761, 0x04045, 0x035DB, 0x07798, 0x0406A, 0x0406F, 0x05C5E, 0x077BE, 0x077CB, 0x058F2, 0x07818, 0x070B9,
+    0x0781C, 0x040A8, 0x07839, 0x07847, 0x07851, 0x07866, 0x08448, 0x25535, 0x07933, 0x06803, 0x07932, 0x04103,
+    0x04109, 0x07991, 0x07999, 0x08FBB, 0x07A06, 0x08FBC, 0x04167, 0x07A91, 0x041B2, 0x07ABC, 0x08279, 0x041C4,
+    0x07ACF, 0x07ADB, 0x041CF, 0x04E21, 0x07B62, 0x07B6C, 0x07B7B, 0x07C12, 0x07C1B, 0x04260, 0x0427A, 0x07C7B,
+    0x07C9C, 0x0428C, 0x07CB8, 0x04294, 0x07CED, 0x08F93, 0x070C0, 0x20CCF, 0x07DCF, 0x07DD4, 0x07DD0, 0x07DFD,
+    0x07FAE, 0x07FB4, 0x0729F, 0x04397, 0x08020, 0x08025, 0x07B39, 0x0802E, 0x08031, 0x08054, 0x03DCC, 0x057B4,
+    0x070A0, 0x080B7, 0x080E9, 0x043ED, 0x0810C, 0x0732A, 0x0810E, 0x08112, 0x07560, 0x08114, 0x04401, 0x03B39,
+    0x08156, 0x08159, 0x0815A, 0x04413, 0x0583A, 0x0817C, 0x08184, 0x04425, 0x08193, 0x0442D, 0x081A5, 0x057EF,
+    0x081C1, 0x081E4, 0x08254, 0x0448F, 0x082A6, 0x08276, 0x082CA, 0x082D8, 0x082FF, 0x044B0, 0x08357, 0x09669,
+    0x0698A, 0x08405, 0x070F5, 0x08464, 0x060E3, 0x08488, 0x04504, 0x084BE, 0x084E1, 0x084F8, 0x08510, 0x08538,
+    0x08552, 0x0453B, 0x0856F, 0x08570, 0x085E0, 0x04577, 0x08672, 0x08692, 0x086B2, 0x086EF, 0x09645, 0x0878B,
+    0x04606, 0x04617, 0x088AE, 0x088FF, 0x08924, 0x08947, 0x08991, 0x27967, 0x08A29, 0x08A38, 0x08A94, 0x08AB4,
+    0x08C51, 0x08CD4, 0x08CF2, 0x08D1C, 0x04798, 0x0585F, 0x08DC3, 0x047ED, 0x04EEE, 0x08E3A, 0x055D8, 0x05754,
+    0x08E71, 0x055F5, 0x08EB0, 0x04837, 0x08ECE, 0x08EE2, 0x08EE4, 0x08EED, 0x08EF2, 0x08FB7, 0x08FC1, 0x08FCA,
+    0x08FCC, 0x09033, 0x099C4, 0x048AD, 0x098E0, 0x09213, 0x0491E, 0x09228, 0x09258, 0x0926B, 0x092B1, 0x092AE,
+    0x092BF, 0x092E3, 0x092EB, 0x092F3, 0x092F4, 0x092FD, 0x09343, 0x09384, 0x093AD, 0x04945, 0x04951, 0x09EBF,
+    0x09417, 0x05301, 0x0941D, 0x0942D, 0x0943E, 0x0496A, 0x09454, 0x09479, 0x0952D, 0x095A2, 0x049A7, 0x095F4,
+    0x09633, 0x049E5, 0x067A0, 0x04A24, 0x09740, 0x04A35, 0x097B2, 0x097C2, 0x05654, 0x04AE4, 0x060E8, 0x098B9,
+    0x04B19, 0x098F1, 0x05844, 0x0990E, 0x09919, 0x051B4, 0x0991C, 0x09937, 0x09942, 0x0995D, 0x09962, 0x04B70,
+    0x099C5, 0x04B9D, 0x09A3C, 0x09B0F, 0x07A83, 0x09B69, 0x09B81, 0x09BDD, 0x09BF1, 0x09BF4, 0x04C6D, 0x09C20,
+    0x0376F, 0x21BC2, 0x09D49, 0x09C3A, 0x09EFE, 0x05650, 0x09D93, 0x09DBD, 0x09DC0, 0x09DFC, 0x094F6, 0x08FB6,
+    0x09E7B, 0x09EAC, 0x09EB1, 0x09EBD, 0x09EC6, 0x094DC, 0x09EE2, 0x09EF1, 0x09EF8, 0x07AC8, 0x09F44, 0x20094,
+    0x202B7, 0x203A0, 0x0691A, 0x094C3, 0x059AC, 0x204D7, 0x05840, 0x094C1, 0x037B9, 0x205D5, 0x20615, 0x20676,
+    0x216BA, 0x05757, 0x07173, 0x20AC2, 0x20ACD, 0x20BBF, 0x0546A, 0x2F83B, 0x20BCB, 0x0549E, 0x20BFB, 0x20C3B,
+    0x20C53, 0x20C65, 0x20C7C, 0x060E7, 0x20C8D, 0x0567A, 0x20CB5, 0x20CDD, 0x20CED, 0x20D6F, 0x20DB2, 0x20DC8,
+    0x06955, 0x09C2F, 0x087A5, 0x20E04, 0x20E0E, 0x20ED7, 0x20F90, 0x20F2D, 0x20E73, 0x05C20, 0x20FBC, 0x05E0B,
+    0x2105C, 0x2104F, 0x21076, 0x0671E, 0x2107B, 0x21088, 0x21096, 0x03647, 0x210BF, 0x210D3, 0x2112F, 0x2113B,
+    0x05364, 0x084AD, 0x212E3, 0x21375, 0x21336, 0x08B81, 0x21577, 0x21619, 0x217C3, 0x217C7, 0x04E78, 0x070BB,
+    0x2182D, 0x2196A, 0x21A2D, 0x21A45, 0x21C2A, 0x21C70, 0x21CAC, 0x21EC8, 0x062C3, 0x21ED5, 0x21F15, 0x07198,
+    0x06855, 0x22045, 0x069E9, 0x036C8, 0x2227C, 0x223D7, 0x223FA, 0x2272A, 0x22871, 0x2294F, 0x082FD, 0x22967,
+    0x22993, 0x22AD5, 0x089A5, 0x22AE8, 0x08FA0, 0x22B0E, 0x097B8, 0x22B3F, 0x09847, 0x09ABD, 0x22C4C, 0x00000,
+    0x22C88, 0x22CB7, 0x25BE8, 0x22D08, 0x22D12, 0x22DB7, 0x22D95, 0x22E42, 0x22F74, 0x22FCC, 0x23033, 0x23066,
+    0x2331F, 0x233DE, 0x05FB1, 0x06648, 0x066BF, 0x27A79, 0x23567, 0x235F3, 0x07201, 0x249BA, 0x077D7, 0x2361A,
+    0x23716, 0x07E87, 0x20346, 0x058B5, 0x0670E, 0x06918, 0x23AA7, 0x27657, 0x25FE2, 0x23E11, 0x23EB9, 0x275FE,
+    0x2209A, 0x048D0, 0x04AB8, 0x24119, 0x28A9A, 0x242EE, 0x2430D, 0x2403B, 0x24334, 0x24396, 0x24A45, 0x205CA,
+    0x051D2, 0x20611, 0x0599F, 0x21EA8, 0x03BBE, 0x23CFF, 0x24404, 0x244D6, 0x05788, 0x24674, 0x0399B, 0x2472F,
+    0x285E8, 0x299C9, 0x03762, 0x221C3, 0x08B5E, 0x28B4E, 0x099D6, 0x24812, 0x248FB, 0x24A15, 0x07209, 0x24AC0,
+    0x20C78, 0x05965, 0x24EA5, 0x24F86, 0x20779, 0x08EDA, 0x2502C, 0x0528F, 0x0573F, 0x07171, 0x25299, 0x25419,
+    0x23F4A, 0x24AA7, 0x055BC, 0x25446, 0x2546E, 0x26B52, 0x091D4, 0x03473, 0x2553F, 0x27632, 0x2555E, 0x04718,
+    0x25562, 0x25566, 0x257C7, 0x2493F, 0x2585D, 0x05066, 0x034FB, 0x233CC, 0x060DE, 0x25903, 0x0477C, 0x28948,
+    0x25AAE, 0x25B89, 0x25C06, 0x21D90, 0x057A1, 0x07151, 0x06FB6, 0x26102, 0x27C12, 0x09056, 0x261B2, 0x24F9A,
+    0x08B62, 0x26402, 0x2644A, 0x05D5B, 0x26BF7, 0x08F36, 0x26484, 0x2191C, 0x08AEA, 0x249F6, 0x26488, 0x23FEF,
+    0x26512, 0x04BC0, 0x265BF, 0x266B5, 0x2271B, 0x09465, 0x257E1, 0x06195, 0x05A27, 0x2F8CD, 0x04FBB, 0x056B9,
+    0x24521, 0x266FC, 0x04E6A, 0x24934, 0x09656, 0x06D8F, 0x26CBD, 0x03618, 0x08977, 0x26799, 0x2686E, 0x26411,
+    0x2685E, 0x071DF, 0x268C7, 0x07B42, 0x290C0, 0x20A11, 0x26926, 0x09104, 0x26939, 0x07A45, 0x09DF0, 0x269FA,
+    0x09A26, 0x26A2D, 0x0365F, 0x26469, 0x20021, 0x07983, 0x26A34, 0x26B5B, 0x05D2C, 0x23519, 0x083CF, 0x26B9D,
+    0x046D0, 0x26CA4, 0x0753B, 0x08865, 0x26DAE, 0x058B6, 0x0371C, 0x2258D, 0x2704B, 0x271CD, 0x03C54, 0x27280,
+    0x27285, 0x09281, 0x2217A, 0x2728B, 0x09330, 0x272E6, 0x249D0, 0x06C39, 0x0949F, 0x27450, 0x20EF8, 0x08827,
+    0x088F5, 0x22926, 0x28473, 0x217B1, 0x06EB8, 0x24A2A, 0x21820, 0x039A4, 0x036B9, 0x05C10, 0x079E3, 0x0453F,
+    0x066B6, 0x29CAD, 0x298A4, 0x08943, 0x277CC, 0x27858, 0x056D6, 0x040DF, 0x2160A, 0x039A1, 0x2372F, 0x280E8,
+    0x213C5, 0x071AD, 0x08366, 0x279DD, 0x291A8, 0x05A67, 0x04CB7, 0x270AF, 0x289AB, 0x279FD, 0x27A0A, 0x27B0B,
+    0x27D66, 0x2417A, 0x07B43, 0x0797E, 0x28009, 0x06FB5, 0x2A2DF, 0x06A03, 0x28318, 0x053A2, 0x26E07, 0x093BF,
+    0x06836, 0x0975D, 0x2816F, 0x28023, 0x269B5, 0x213ED, 0x2322F, 0x28048, 0x05D85, 0x28C30, 0x28083, 0x05715,
+    0x09823, 0x28949, 0x05DAB, 0x24988, 0x065BE, 0x069D5, 0x053D2, 0x24AA5, 0x23F81, 0x03C11, 0x06736, 0x28090,
+    0x280F4, 0x2812E, 0x21FA1, 0x2814F, 0x28189, 0x281AF, 0x2821A, 0x28306, 0x2832F, 0x2838A, 0x035CA, 0x28468,
+    0x286AA, 0x048FA, 0x063E6, 0x28956, 0x07808, 0x09255, 0x289B8, 0x043F2, 0x289E7, 0x043DF, 0x289E8, 0x28B46,
+    0x28BD4, 0x059F8, 0x28C09, 0x08F0B, 0x28FC5, 0x290EC, 0x07B51, 0x29110, 0x2913C, 0x03DF7, 0x2915E, 0x24ACA,
+    0x08FD0, 0x0728F, 0x0568B, 0x294E7, 0x295E9, 0x295B0, 0x295B8, 0x29732, 0x298D1, 0x29949, 0x2996A, 0x299C3,
+    0x29A28, 0x29B0E, 0x29D5A, 0x29D9B, 0x07E9F, 0x29EF8, 0x29F23, 0x04CA4, 0x09547, 0x2A293, 0x071A2, 0x2A2FF,
+    0x04D91, 0x09012, 0x2A5CB, 0x04D9C, 0x20C9C, 0x08FBE, 0x055C1, 0x08FBA, 0x224B0, 0x08FB9, 0x24A93, 0x04509,
+    0x07E7F, 0x06F56, 0x06AB1, 0x04EEA, 0x034E4, 0x28B2C, 0x2789D, 0x0373A, 0x08E80, 0x217F5, 0x28024, 0x28B6C,
+    0x28B99, 0x27A3E, 0x266AF, 0x03DEB, 0x27655, 0x23CB7, 0x25635, 0x25956, 0x04E9A, 0x25E81, 0x26258, 0x056BF,
+    0x20E6D, 0x08E0E, 0x05B6D, 0x23E88, 0x24C9E, 0x063DE, 0x062D0, 0x217F6, 0x2187B, 0x06530, 0x0562D, 0x25C4A,
+    0x0541A, 0x25311, 0x03DC6, 0x29D98, 0x04C7D, 0x05622, 0x0561E, 0x07F49, 0x25ED8, 0x05975, 0x23D40, 0x08770,
+    0x04E1C, 0x20FEA, 0x20D49, 0x236BA, 0x08117, 0x09D5E, 0x08D18, 0x0763B, 0x09C45, 0x0764E, 0x077B9, 0x09345,
+    0x05432, 0x08148, 0x082F7, 0x05625, 0x08132, 0x08418, 0x080BD, 0x055EA, 0x07962, 0x05643, 0x05416, 0x20E9D,
+    0x035CE, 0x05605, 0x055F1, 0x066F1, 0x282E2, 0x0362D, 0x07534, 0x055F0, 0x055BA, 0x05497, 0x05572, 0x20C41,
+    0x20C96, 0x05ED0, 0x25148, 0x20E76, 0x22C62, 0x20EA2, 0x09EAB, 0x07D5A, 0x055DE, 0x21075, 0x0629D, 0x0976D,
+    0x05494, 0x08CCD, 0x071F6, 0x09176, 0x063FC, 0x063B9, 0x063FE, 0x05569, 0x22B43, 0x09C72, 0x22EB3, 0x0519A,
+    0x034DF, 0x20DA7, 0x051A7, 0x0544D, 0x0551E, 0x05513, 0x07666, 0x08E2D, 0x2688A, 0x075B1, 0x080B6, 0x08804,
+    0x08786, 0x088C7, 0x081B6, 0x0841C, 0x210C1, 0x044EC, 0x07304, 0x24706, 0x05B90, 0x0830B, 0x26893, 0x0567B,
+    0x226F4, 0x27D2F, 0x241A3, 0x27D73, 0x26ED0, 0x272B6, 0x09170, 0x211D9, 0x09208, 0x23CFC, 0x2A6A9, 0x20EAC,
+    0x20EF9, 0x07266, 0x21CA2, 0x0474E, 0x24FC2, 0x27FF9, 0x20FEB, 0x040FA, 0x09C5D, 0x0651F, 0x22DA0, 0x048F3,
+    0x247E0, 0x29D7C, 0x20FEC, 0x20E0A, 0x06062, 0x275A3, 0x20FED, 0x00000, 0x26048, 0x21187, 0x071A3, 0x07E8E,
+    0x09D50, 0x04E1A, 0x04E04, 0x03577, 0x05B0D, 0x06CB2, 0x05367, 0x036AC, 0x039DC, 0x0537D, 0x036A5, 0x24618,
+    0x0589A, 0x24B6E, 0x0822D, 0x0544B, 0x057AA, 0x25A95, 0x20979, 0x00000, 0x03A52, 0x22465, 0x07374, 0x29EAC,
+    0x04D09, 0x09BED, 0x23CFE, 0x29F30, 0x04C5B, 0x24FA9, 0x2959E, 0x29FDE, 0x0845C, 0x23DB6, 0x272B2, 0x267B3,
+    0x23720, 0x0632E, 0x07D25, 0x23EF7, 0x23E2C, 0x03A2A, 0x09008, 0x052CC, 0x03E74, 0x0367A, 0x045E9, 0x2048E,
+    0x07640, 0x05AF0, 0x20EB6, 0x0787A, 0x27F2E, 0x058A7, 0x040BF, 0x0567C, 0x09B8B, 0x05D74, 0x07654, 0x2A434,
+    0x09E85, 0x04CE1, 0x075F9, 0x037FB, 0x06119, 0x230DA, 0x243F2, 0x00000, 0x0565D, 0x212A9, 0x057A7, 0x24963,
+    0x29E06, 0x05234, 0x270AE, 0x035AD, 0x06C4A, 0x09D7C, 0x07C56, 0x09B39, 0x057DE, 0x2176C, 0x05C53, 0x064D3,
+    0x294D0, 0x26335, 0x27164, 0x086AD, 0x20D28, 0x26D22, 0x24AE2, 0x20D71, 0x00000, 0x051FE, 0x21F0F, 0x05D8E,
+    0x09703, 0x21DD1, 0x09E81, 0x0904C, 0x07B1F, 0x09B02, 0x05CD1, 0x07BA3, 0x06268, 0x06335, 0x09AFF, 0x07BCF,
+    0x09B2A, 0x07C7E, 0x09B2E, 0x07C42, 0x07C86, 0x09C15, 0x07BFC, 0x09B09, 0x09F17, 0x09C1B, 0x2493E, 0x09F5A,
+    0x05573, 0x05BC3, 0x04FFD, 0x09E98, 0x04FF2, 0x05260, 0x03E06, 0x052D1, 0x05767, 0x05056, 0x059B7, 0x05E12,
+    0x097C8, 0x09DAB, 0x08F5C, 0x05469, 0x097B4, 0x09940, 0x097BA, 0x0532C, 0x06130, 0x0692C, 0x053DA, 0x09C0A,
+    0x09D02, 0x04C3B, 0x09641, 0x06980, 0x050A6, 0x07546, 0x2176D, 0x099DA, 0x05273, 0x00000, 0x09159, 0x09681,
+    0x0915C, 0x00000, 0x09151, 0x28E97, 0x0637F, 0x26D23, 0x06ACA, 0x05611, 0x0918E, 0x0757A, 0x06285, 0x203FC,
+    0x0734F, 0x07C70, 0x25C21, 0x23CFD, 0x00000, 0x24919, 0x076D6, 0x09B9D, 0x04E2A, 0x20CD4, 0x083BE, 0x08842,
+    0x00000, 0x05C4A, 0x069C0, 0x050ED, 0x0577A, 0x0521F, 0x05DF5, 0x04ECE, 0x06C31, 0x201F2, 0x04F39, 0x0549C,
+    0x054DA, 0x0529A, 0x08D82, 0x035FE, 0x05F0C, 0x035F3, 0x00000, 0x06B52, 0x0917C, 0x09FA5, 0x09B97, 0x0982E,
+    0x098B4, 0x09ABA, 0x09EA8, 0x09E84, 0x0717A, 0x07B14, 0x00000, 0x06BFA, 0x08818, 0x07F78, 0x00000, 0x05620,
+    0x2A64A, 0x08E77, 0x09F53, 0x00000, 0x08DD4, 0x08E4F, 0x09E1C, 0x08E01, 0x06282, 0x2837D, 0x08E28, 0x08E75,
+    0x07AD3, 0x24A77, 0x07A3E, 0x078D8, 0x06CEA, 0x08A67, 0x07607, 0x28A5A, 0x09F26, 0x06CCE, 0x087D6, 0x075C3,
+    0x2A2B2, 0x07853, 0x2F840, 0x08D0C, 0x072E2, 0x07371, 0x08B2D, 0x07302, 0x074F1, 0x08CEB, 0x24ABB, 0x0862F,
+    0x05FBA, 0x088A0, 0x044B7, 0x00000, 0x2183B, 0x26E05, 0x00000, 0x08A7E, 0x2251B, 0x00000, 0x060FD, 0x07667,
+    0x09AD7, 0x09D44, 0x0936E, 0x09B8F, 0x087F5, 0x00000, 0x0880F, 0x08CF7, 0x0732C, 0x09721, 0x09BB0, 0x035D6,
+    0x072B2, 0x04C07, 0x07C51, 0x0994A, 0x26159, 0x06159, 0x04C04, 0x09E96, 0x0617D, 0x00000, 0x0575F, 0x0616F,
+    0x062A6, 0x06239, 0x062CE, 0x03A5C, 0x061E2, 0x053AA, 0x233F5, 0x06364, 0x06802, 0x035D2, 0x05D57, 0x28BC2,
+    0x08FDA, 0x28E39, 0x00000, 0x050D9, 0x21D46, 0x07906, 0x05332, 0x09638, 0x20F3B, 0x04065, 0x00000, 0x077FE,
+    0x00000, 0x07CC2, 0x25F1A, 0x07CDA, 0x07A2D, 0x08066, 0x08063, 0x07D4D, 0x07505, 0x074F2, 0x08994, 0x0821A,
+    0x0670C, 0x08062, 0x27486, 0x0805B, 0x074F0, 0x08103, 0x07724, 0x08989, 0x267CC, 0x07553, 0x26ED1, 0x087A9,
+    0x087CE, 0x081C8, 0x0878C, 0x08A49, 0x08CAD, 0x08B43, 0x0772B, 0x074F8, 0x084DA, 0x03635, 0x069B2, 0x08DA6,
+    0x00000, 0x089A9, 0x07468, 0x06DB9, 0x087C1, 0x24011, 0x074E7, 0x03DDB, 0x07176, 0x060A4, 0x0619C, 0x03CD1,
+    0x07162, 0x06077, 0x00000, 0x07F71, 0x28B2D, 0x07250, 0x060E9, 0x04B7E, 0x05220, 0x03C18, 0x23CC7, 0x25ED7,
+    0x27656, 0x25531, 0x21944, 0x212FE, 0x29903, 0x26DDC, 0x270AD, 0x05CC1, 0x261AD, 0x28A0F, 0x23677, 0x200EE,
+    0x26846, 0x24F0E, 0x04562, 0x05B1F, 0x2634C, 0x09F50, 0x09EA6, 0x2626B, 0x03000, 0x0FF0C, 0x03001, 0x03002,
+    0x0FF0E, 0x02027, 0x0FF1B, 0x0FF1A, 0x0FF1F, 0x0FF01, 0x0FE30, 0x02026, 0x02025, 0x0FE50, 0x0FE51, 0x0FE52,
+    0x000B7, 0x0FE54, 0x0FE55, 0x0FE56, 0x0FE57, 0x0FF5C, 0x02013, 0x0FE31, 0x02014, 0x0FE33, 0x02574, 0x0FE34,
+    0x0FE4F, 0x0FF08, 0x0FF09, 0x0FE35, 0x0FE36, 0x0FF5B, 0x0FF5D, 0x0FE37, 0x0FE38, 0x03014, 0x03015, 0x0FE39,
+    0x0FE3A, 0x03010, 0x03011, 0x0FE3B, 0x0FE3C, 0x0300A, 0x0300B, 0x0FE3D, 0x0FE3E, 0x03008, 0x03009, 0x0FE3F,
+    0x0FE40, 0x0300C, 0x0300D, 0x0FE41, 0x0FE42, 0x0300E, 0x0300F, 0x0FE43, 0x0FE44, 0x0FE59, 0x0FE5A, 0x0FE5B,
+    0x0FE5C, 0x0FE5D, 0x0FE5E, 0x02018, 0x02019, 0x0201C, 0x0201D, 0x0301D, 0x0301E, 0x02035, 0x02032, 0x0FF03,
+    0x0FF06, 0x0FF0A, 0x0203B, 0x000A7, 0x03003, 0x025CB, 0x025CF, 0x025B3, 0x025B2, 0x025CE, 0x02606, 0x02605,
+    0x025C7, 0x025C6, 0x025A1, 0x025A0, 0x025BD, 0x025BC, 0x032A3, 0x02105, 0x000AF, 0x0FFE3, 0x0FF3F, 0x002CD,
+    0x0FE49, 0x0FE4A, 0x0FE4D, 0x0FE4E, 0x0FE4B, 0x0FE4C, 0x0FE5F, 0x0FE60, 0x0FE61, 0x0FF0B, 0x0FF0D, 0x000D7,
+    0x000F7, 0x000B1, 0x0221A, 0x0FF1C, 0x0FF1E, 0x0FF1D, 0x02266, 0x02267, 0x02260, 0x0221E, 0x02252, 0x02261,
+    0x0FE62, 0x0FE63, 0x0FE64, 0x0FE65, 0x0FE66, 0x0FF5E, 0x02229, 0x0222A, 0x022A5, 0x02220, 0x0221F, 0x022BF,
+    0x033D2, 0x033D1, 0x0222B, 0x0222E, 0x02235, 0x02234, 0x02640, 0x02642, 0x02295, 0x02299, 0x02191, 0x02193,
+    0x02190, 0x02192, 0x02196, 0x02197, 0x02199, 0x02198, 0x02225, 0x02223, 0x0FF0F, 0x0FF3C, 0x02215, 0x0FE68,
+    0x0FF04, 0x0FFE5, 0x03012, 0x0FFE0, 0x0FFE1, 0x0FF05, 0x0FF20, 0x02103, 0x02109, 0x0FE69, 0x0FE6A, 0x0FE6B,
+    0x033D5, 0x0339C, 0x0339D, 0x0339E, 0x033CE, 0x033A1, 0x0338E, 0x0338F, 0x033C4, 0x000B0, 0x05159, 0x0515B,
+    0x0515E, 0x0515D, 0x05161, 0x05163, 0x055E7, 0x074E9, 0x07CCE, 0x02581, 0x02582, 0x02583, 0x02584, 0x02585,
+    0x02586, 0x02587, 0x02588, 0x0258F, 0x0258E, 0x0258D, 0x0258C, 0x0258B, 0x0258A, 0x02589, 0x0253C, 0x02534,
+    0x0252C, 0x02524, 0x0251C, 0x02594, 0x02500, 0x02502, 0x02595, 0x0250C, 0x02510, 0x02514, 0x02518, 0x0256D,
+    0x0256E, 0x02570, 0x0256F, 0x02550, 0x0255E, 0x0256A, 0x02561, 0x025E2, 0x025E3, 0x025E5, 0x025E4, 0x02571,
+    0x02572, 0x02573, 0x0FF10, 0x0FF11, 0x0FF12, 0x0FF13, 0x0FF14, 0x0FF15, 0x0FF16, 0x0FF17, 0x0FF18, 0x0FF19,
+    0x02160, 0x02161, 0x02162, 0x02163, 0x02164, 0x02165, 0x02166, 0x02167, 0x02168, 0x02169, 0x03021, 0x03022,
+    0x03023, 0x03024, 0x03025, 0x03026, 0x03027, 0x03028, 0x03029, 0x05341, 0x05344, 0x05345, 0x0FF21, 0x0FF22,
+    0x0FF23, 0x0FF24, 0x0FF25, 0x0FF26, 0x0FF27, 0x0FF28, 0x0FF29, 0x0FF2A, 0x0FF2B, 0x0FF2C, 0x0FF2D, 0x0FF2E,
+    0x0FF2F, 0x0FF30, 0x0FF31, 0x0FF32, 0x0FF33, 0x0FF34, 0x0FF35, 0x0FF36, 0x0FF37, 0x0FF38, 0x0FF39, 0x0FF3A,
+    0x0FF41, 0x0FF42, 0x0FF43, 0x0FF44, 0x0FF45, 0x0FF46, 0x0FF47, 0x0FF48, 0x0FF49, 0x0FF4A, 0x0FF4B, 0x0FF4C,
+    0x0FF4D, 0x0FF4E, 0x0FF4F, 0x0FF50, 0x0FF51, 0x0FF52, 0x0FF53, 0x0FF54, 0x0FF55, 0x0FF56, 0x0FF57, 0x0FF58,
+    0x0FF59, 0x0FF5A, 0x00391, 0x00392, 0x00393, 0x00394, 0x00395, 0x00396, 0x00397, 0x00398, 0x00399, 0x0039A,
+    0x0039B, 0x0039C, 0x0039D, 0x0039E, 0x0039F, 0x003A0, 0x003A1, 0x003A3, 0x003A4, 0x003A5, 0x003A6, 0x003A7,
+    0x003A8, 0x003A9, 0x003B1, 0x003B2, 0x003B3, 0x003B4, 0x003B5, 0x003B6, 0x003B7, 0x003B8, 0x003B9, 0x003BA,
+    0x003BB, 0x003BC, 0x003BD, 0x003BE, 0x003BF, 0x003C0, 0x003C1, 0x003C3, 0x003C4, 0x003C5, 0x003C6, 0x003C7,
+    0x003C8, 0x003C9, 0x03105, 0x03106, 0x03107, 0x03108, 0x03109, 0x0310A, 0x0310B, 0x0310C, 0x0310D, 0x0310E,
+    0x0310F, 0x03110, 0x03111, 0x03112, 0x03113, 0x03114, 0x03115, 0x03116, 0x03117, 0x03118, 0x03119, 0x0311A,
+    0x0311B, 0x0311C, 0x0311D, 0x0311E, 0x0311F, 0x03120, 0x03121, 0x03122, 0x03123, 0x03124, 0x03125, 0x03126,
+    0x03127, 0x03128, 0x03129, 0x002D9, 0x002C9, 0x002CA, 0x002C7, 0x002CB, 0x02400, 0x02401, 0x02402, 0x02403,
+    0x02404, 0x02405, 0x02406, 0x02407, 0x02408, 0x02409, 0x0240A, 0x0240B, 0x0240C, 0x0240D, 0x0240E, 0x0240F,
+    0x02410, 0x02411, 0x02412, 0x02413, 0x02414, 0x02415, 0x02416, 0x02417, 0x02418, 0x02419, 0x0241A, 0x0241B,
+    0x0241C, 0x0241D, 0x0241E, 0x0241F, 0x02421, 0x020AC, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x04E00,
+    0x04E59, 0x04E01, 0x04E03, 0x04E43, 0x04E5D, 0x04E86, 0x04E8C, 0x04EBA, 0x0513F, 0x05165, 0x0516B, 0x051E0,
+    0x05200, 0x05201, 0x0529B, 0x05315, 0x05341, 0x0535C, 0x053C8, 0x04E09, 0x04E0B, 0x04E08, 0x04E0A, 0x04E2B,
+    0x04E38, 0x051E1, 0x04E45, 0x04E48, 0x04E5F, 0x04E5E, 0x04E8E, 0x04EA1, 0x05140, 0x05203, 0x052FA, 0x05343,
+    0x053C9, 0x053E3, 0x0571F, 0x058EB, 0x05915, 0x05927, 0x05973, 0x05B50, 0x05B51, 0x05B53, 0x05BF8, 0x05C0F,
+    0x05C22, 0x05C38, 0x05C71, 0x05DDD, 0x05DE5, 0x05DF1, 0x05DF2, 0x05DF3, 0x05DFE, 0x05E72, 0x05EFE, 0x05F0B,
+    0x05F13, 0x0624D, 0x04E11, 0x04E10, 0x04E0D, 0x04E2D, 0x04E30, 0x04E39, 0x04E4B, 0x05C39, 0x04E88, 0x04E91,
+    0x04E95, 0x04E92, 0x04E94, 0x04EA2, 0x04EC1, 0x04EC0, 0x04EC3, 0x04EC6, 0x04EC7, 0x04ECD, 0x04ECA, 0x04ECB,
+    0x04EC4, 0x05143, 0x05141, 0x05167, 0x0516D, 0x0516E, 0x0516C, 0x05197, 0x051F6, 0x05206, 0x05207, 0x05208,
+    0x052FB, 0x052FE, 0x052FF, 0x05316, 0x05339, 0x05348, 0x05347, 0x05345, 0x0535E, 0x05384, 0x053CB, 0x053CA,
+    0x053CD, 0x058EC, 0x05929, 0x0592B, 0x0592A, 0x0592D, 0x05B54, 0x05C11, 0x05C24, 0x05C3A, 0x05C6F, 0x05DF4,
+    0x05E7B, 0x05EFF, 0x05F14, 0x05F15, 0x05FC3, 0x06208, 0x06236, 0x0624B, 0x0624E, 0x0652F, 0x06587, 0x06597,
+    0x065A4, 0x065B9, 0x065E5, 0x066F0, 0x06708, 0x06728, 0x06B20, 0x06B62, 0x06B79, 0x06BCB, 0x06BD4, 0x06BDB,
+    0x06C0F, 0x06C34, 0x0706B, 0x0722A, 0x07236, 0x0723B, 0x07247, 0x07259, 0x0725B, 0x072AC, 0x0738B, 0x04E19,
+    0x04E16, 0x04E15, 0x04E14, 0x04E18, 0x04E3B, 0x04E4D, 0x04E4F, 0x04E4E, 0x04EE5, 0x04ED8, 0x04ED4, 0x04ED5,
+    0x04ED6, 0x04ED7, 0x04EE3, 0x04EE4, 0x04ED9, 0x04EDE, 0x05145, 0x05144, 0x05189, 0x0518A, 0x051AC, 0x051F9,
+    0x051FA, 0x051F8, 0x0520A, 0x052A0, 0x0529F, 0x05305, 0x05306, 0x05317, 0x0531D, 0x04EDF, 0x0534A, 0x05349,
+    0x05361, 0x05360, 0x0536F, 0x0536E, 0x053BB, 0x053EF, 0x053E4, 0x053F3, 0x053EC, 0x053EE, 0x053E9, 0x053E8,
+    0x053FC, 0x053F8, 0x053F5, 0x053EB, 0x053E6, 0x053EA, 0x053F2, 0x053F1, 0x053F0, 0x053E5, 0x053ED, 0x053FB,
+    0x056DB, 0x056DA, 0x05916, 0x0592E, 0x05931, 0x05974, 0x05976, 0x05B55, 0x05B83, 0x05C3C, 0x05DE8, 0x05DE7,
+    0x05DE6, 0x05E02, 0x05E03, 0x05E73, 0x05E7C, 0x05F01, 0x05F18, 0x05F17, 0x05FC5, 0x0620A, 0x06253, 0x06254,
+    0x06252, 0x06251, 0x065A5, 0x065E6, 0x0672E, 0x0672C, 0x0672A, 0x0672B, 0x0672D, 0x06B63, 0x06BCD, 0x06C11,
+    0x06C10, 0x06C38, 0x06C41, 0x06C40, 0x06C3E, 0x072AF, 0x07384, 0x07389, 0x074DC, 0x074E6, 0x07518, 0x0751F,
+    0x07528, 0x07529, 0x07530, 0x07531, 0x07532, 0x07533, 0x0758B, 0x0767D, 0x076AE, 0x076BF, 0x076EE, 0x077DB,
+    0x077E2, 0x077F3, 0x0793A, 0x079BE, 0x07A74, 0x07ACB, 0x04E1E, 0x04E1F, 0x04E52, 0x04E53, 0x04E69, 0x04E99,
+    0x04EA4, 0x04EA6, 0x04EA5, 0x04EFF, 0x04F09, 0x04F19, 0x04F0A, 0x04F15, 0x04F0D, 0x04F10, 0x04F11, 0x04F0F,
+    0x04EF2, 0x04EF6, 0x04EFB, 0x04EF0, 0x04EF3, 0x04EFD, 0x04F01, 0x04F0B, 0x05149, 0x05147, 0x05146, 0x05148,
+    0x05168, 0x05171, 0x0518D, 0x051B0, 0x05217, 0x05211, 0x05212, 0x0520E, 0x05216, 0x052A3, 0x05308, 0x05321,
+    0x05320, 0x05370, 0x05371, 0x05409, 0x0540F, 0x0540C, 0x0540A, 0x05410, 0x05401, 0x0540B, 0x05404, 0x05411,
+    0x0540D, 0x05408, 0x05403, 0x0540E, 0x05406, 0x05412, 0x056E0, 0x056DE, 0x056DD, 0x05733, 0x05730, 0x05728,
+    0x0572D, 0x0572C, 0x0572F, 0x05729, 0x05919, 0x0591A, 0x05937, 0x05938, 0x05984, 0x05978, 0x05983, 0x0597D,
+    0x05979, 0x05982, 0x05981, 0x05B57, 0x05B58, 0x05B87, 0x05B88, 0x05B85, 0x05B89, 0x05BFA, 0x05C16, 0x05C79,
+    0x05DDE, 0x05E06, 0x05E76, 0x05E74, 0x05F0F, 0x05F1B, 0x05FD9, 0x05FD6, 0x0620E, 0x0620C, 0x0620D, 0x06210,
+    0x06263, 0x0625B, 0x06258, 0x06536, 0x065E9, 0x065E8, 0x065EC, 0x065ED, 0x066F2, 0x066F3, 0x06709, 0x0673D,
+    0x06734, 0x06731, 0x06735, 0x06B21, 0x06B64, 0x06B7B, 0x06C16, 0x06C5D, 0x06C57, 0x06C59, 0x06C5F, 0x06C60,
+    0x06C50, 0x06C55, 0x06C61, 0x06C5B, 0x06C4D, 0x06C4E, 0x07070, 0x0725F, 0x0725D, 0x0767E, 0x07AF9, 0x07C73,
+    0x07CF8, 0x07F36, 0x07F8A, 0x07FBD, 0x08001, 0x08003, 0x0800C, 0x08012, 0x08033, 0x0807F, 0x08089, 0x0808B,
+    0x0808C, 0x081E3, 0x081EA, 0x081F3, 0x081FC, 0x0820C, 0x0821B, 0x0821F, 0x0826E, 0x08272, 0x0827E, 0x0866B,
+    0x08840, 0x0884C, 0x08863, 0x0897F, 0x09621, 0x04E32, 0x04EA8, 0x04F4D, 0x04F4F, 0x04F47, 0x04F57, 0x04F5E,
+    0x04F34, 0x04F5B, 0x04F55, 0x04F30, 0x04F50, 0x04F51, 0x04F3D, 0x04F3A, 0x04F38, 0x04F43, 0x04F54, 0x04F3C,
+    0x04F46, 0x04F63, 0x04F5C, 0x04F60, 0x04F2F, 0x04F4E, 0x04F36, 0x04F59, 0x04F5D, 0x04F48, 0x04F5A, 0x0514C,
+    0x0514B, 0x0514D, 0x05175, 0x051B6, 0x051B7, 0x05225, 0x05224, 0x05229, 0x0522A, 0x05228, 0x052AB, 0x052A9,
+    0x052AA, 0x052AC, 0x05323, 0x05373, 0x05375, 0x0541D, 0x0542D, 0x0541E, 0x0543E, 0x05426, 0x0544E, 0x05427,
+    0x05446, 0x05443, 0x05433, 0x05448, 0x05442, 0x0541B, 0x05429, 0x0544A, 0x05439, 0x0543B, 0x05438, 0x0542E,
+    0x05435, 0x05436, 0x05420, 0x0543C, 0x05440, 0x05431, 0x0542B, 0x0541F, 0x0542C, 0x056EA, 0x056F0, 0x056E4,
+    0x056EB, 0x0574A, 0x05751, 0x05740, 0x0574D, 0x05747, 0x0574E, 0x0573E, 0x05750, 0x0574F, 0x0573B, 0x058EF,
+    0x0593E, 0x0599D, 0x05992, 0x059A8, 0x0599E, 0x059A3, 0x05999, 0x05996, 0x0598D, 0x059A4, 0x05993, 0x0598A,
+    0x059A5, 0x05B5D, 0x05B5C, 0x05B5A, 0x05B5B, 0x05B8C, 0x05B8B, 0x05B8F, 0x05C2C, 0x05C40, 0x05C41, 0x05C3F,
+    0x05C3E, 0x05C90, 0x05C91, 0x05C94, 0x05C8C, 0x05DEB, 0x05E0C, 0x05E8F, 0x05E87, 0x05E8A, 0x05EF7, 0x05F04,
+    0x05F1F, 0x05F64, 0x05F62, 0x05F77, 0x05F79, 0x05FD8, 0x05FCC, 0x05FD7, 0x05FCD, 0x05FF1, 0x05FEB, 0x05FF8,
+    0x05FEA, 0x06212, 0x06211, 0x06284, 0x06297, 0x06296, 0x06280, 0x06276, 0x06289, 0x0626D, 0x0628A, 0x0627C,
+    0x0627E, 0x06279, 0x06273, 0x06292, 0x0626F, 0x06298, 0x0626E, 0x06295, 0x06293, 0x06291, 0x06286, 0x06539,
+    0x0653B, 0x06538, 0x065F1, 0x066F4, 0x0675F, 0x0674E, 0x0674F, 0x06750, 0x06751, 0x0675C, 0x06756, 0x0675E,
+    0x06749, 0x06746, 0x06760, 0x06753, 0x06757, 0x06B65, 0x06BCF, 0x06C42, 0x06C5E, 0x06C99, 0x06C81, 0x06C88,
+    0x06C89, 0x06C85, 0x06C9B, 0x06C6A, 0x06C7A, 0x06C90, 0x06C70, 0x06C8C, 0x06C68, 0x06C96, 0x06C92, 0x06C7D,
+    0x06C83, 0x06C72, 0x06C7E, 0x06C74, 0x06C86, 0x06C76, 0x06C8D, 0x06C94, 0x06C98, 0x06C82, 0x07076, 0x0707C,
+    0x0707D, 0x07078, 0x07262, 0x07261, 0x07260, 0x072C4, 0x072C2, 0x07396, 0x0752C, 0x0752B, 0x07537, 0x07538,
+    0x07682, 0x076EF, 0x077E3, 0x079C1, 0x079C0, 0x079BF, 0x07A76, 0x07CFB, 0x07F55, 0x08096, 0x08093, 0x0809D,
+    0x08098, 0x0809B, 0x0809A, 0x080B2, 0x0826F, 0x08292, 0x0828B, 0x0828D, 0x0898B, 0x089D2, 0x08A00, 0x08C37,
+    0x08C46, 0x08C55, 0x08C9D, 0x08D64, 0x08D70, 0x08DB3, 0x08EAB, 0x08ECA, 0x08F9B, 0x08FB0, 0x08FC2, 0x08FC6,
+    0x08FC5, 0x08FC4, 0x05DE1, 0x09091, 0x090A2, 0x090AA, 0x090A6, 0x090A3, 0x09149, 0x091C6, 0x091CC, 0x09632,
+    0x0962E, 0x09631, 0x0962A, 0x0962C, 0x04E26, 0x04E56, 0x04E73, 0x04E8B, 0x04E9B, 0x04E9E, 0x04EAB, 0x04EAC,
+    0x04F6F, 0x04F9D, 0x04F8D, 0x04F73, 0x04F7F, 0x04F6C, 0x04F9B, 0x04F8B, 0x04F86, 0x04F83, 0x04F70, 0x04F75,
+    0x04F88, 0x04F69, 0x04F7B, 0x04F96, 0x04F7E, 0x04F8F, 0x04F91, 0x04F7A, 0x05154, 0x05152, 0x05155, 0x05169,
+    0x05177, 0x05176, 0x05178, 0x051BD, 0x051FD, 0x0523B, 0x05238, 0x05237, 0x0523A, 0x05230, 0x0522E, 0x05236,
+    0x05241, 0x052BE, 0x052BB, 0x05352, 0x05354, 0x05353, 0x05351, 0x05366, 0x05377, 0x05378, 0x05379, 0x053D6,
+    0x053D4, 0x053D7, 0x05473, 0x05475, 0x05496, 0x05478, 0x05495, 0x05480, 0x0547B, 0x05477, 0x05484, 0x05492,
+    0x05486, 0x0547C, 0x05490, 0x05471, 0x05476, 0x0548C, 0x0549A, 0x05462, 0x05468, 0x0548B, 0x0547D, 0x0548E,
+    0x056FA, 0x05783, 0x05777, 0x0576A, 0x05769, 0x05761, 0x05766, 0x05764, 0x0577C, 0x0591C, 0x05949, 0x05947,
+    0x05948, 0x05944, 0x05954, 0x059BE, 0x059BB, 0x059D4, 0x059B9, 0x059AE, 0x059D1, 0x059C6, 0x059D0, 0x059CD,
+    0x059CB, 0x059D3, 0x059CA, 0x059AF, 0x059B3, 0x059D2, 0x059C5, 0x05B5F, 0x05B64, 0x05B63, 0x05B97, 0x05B9A,
+    0x05B98, 0x05B9C, 0x05B99, 0x05B9B, 0x05C1A, 0x05C48, 0x05C45, 0x05C46, 0x05CB7, 0x05CA1, 0x05CB8, 0x05CA9,
+    0x05CAB, 0x05CB1, 0x05CB3, 0x05E18, 0x05E1A, 0x05E16, 0x05E15, 0x05E1B, 0x05E11, 0x05E78, 0x05E9A, 0x05E97,
+    0x05E9C, 0x05E95, 0x05E96, 0x05EF6, 0x05F26, 0x05F27, 0x05F29, 0x05F80, 0x05F81, 0x05F7F, 0x05F7C, 0x05FDD,
+    0x05FE0, 0x05FFD, 0x05FF5, 0x05FFF, 0x0600F, 0x06014, 0x0602F, 0x06035, 0x06016, 0x0602A, 0x06015, 0x06021,
+    0x06027, 0x06029, 0x0602B, 0x0601B, 0x06216, 0x06215, 0x0623F, 0x0623E, 0x06240, 0x0627F, 0x062C9, 0x062CC,
+    0x062C4, 0x062BF, 0x062C2, 0x062B9, 0x062D2, 0x062DB, 0x062AB, 0x062D3, 0x062D4, 0x062CB, 0x062C8, 0x062A8,
+    0x062BD, 0x062BC, 0x062D0, 0x062D9, 0x062C7, 0x062CD, 0x062B5, 0x062DA, 0x062B1, 0x062D8, 0x062D6, 0x062D7,
+    0x062C6, 0x062AC, 0x062CE, 0x0653E, 0x065A7, 0x065BC, 0x065FA, 0x06614, 0x06613, 0x0660C, 0x06606, 0x06602,
+    0x0660E, 0x06600, 0x0660F, 0x06615, 0x0660A, 0x06607, 0x0670D, 0x0670B, 0x0676D, 0x0678B, 0x06795, 0x06771,
+    0x0679C, 0x06773, 0x06777, 0x06787, 0x0679D, 0x06797, 0x0676F, 0x06770, 0x0677F, 0x06789, 0x0677E, 0x06790,
+    0x06775, 0x0679A, 0x06793, 0x0677C, 0x0676A, 0x06772, 0x06B23, 0x06B66, 0x06B67, 0x06B7F, 0x06C13, 0x06C1B,
+    0x06CE3, 0x06CE8, 0x06CF3, 0x06CB1, 0x06CCC, 0x06CE5, 0x06CB3, 0x06CBD, 0x06CBE, 0x06CBC, 0x06CE2, 0x06CAB,
+    0x06CD5, 0x06CD3, 0x06CB8, 0x06CC4, 0x06CB9, 0x06CC1, 0x06CAE, 0x06CD7, 0x06CC5, 0x06CF1, 0x06CBF, 0x06CBB,
+    0x06CE1, 0x06CDB, 0x06CCA, 0x06CAC, 0x06CEF, 0x06CDC, 0x06CD6, 0x06CE0, 0x07095, 0x0708E, 0x07092, 0x0708A,
+    0x07099, 0x0722C, 0x0722D, 0x07238, 0x07248, 0x07267, 0x07269, 0x072C0, 0x072CE, 0x072D9, 0x072D7, 0x072D0,
+    0x073A9, 0x073A8, 0x0739F, 0x073AB, 0x073A5, 0x0753D, 0x0759D, 0x07599, 0x0759A, 0x07684, 0x076C2, 0x076F2,
+    0x076F4, 0x077E5, 0x077FD, 0x0793E, 0x07940, 0x07941, 0x079C9, 0x079C8, 0x07A7A, 0x07A79, 0x07AFA, 0x07CFE,
+    0x07F54, 0x07F8C, 0x07F8B, 0x08005, 0x080BA, 0x080A5, 0x080A2, 0x080B1, 0x080A1, 0x080AB, 0x080A9, 0x080B4,
+    0x080AA, 0x080AF, 0x081E5, 0x081FE, 0x0820D, 0x082B3, 0x0829D, 0x08299, 0x082AD, 0x082BD, 0x0829F, 0x082B9,
+    0x082B1, 0x082AC, 0x082A5, 0x082AF, 0x082B8, 0x082A3, 0x082B0, 0x082BE, 0x082B7, 0x0864E, 0x08671, 0x0521D,
+    0x08868, 0x08ECB, 0x08FCE, 0x08FD4, 0x08FD1, 0x090B5, 0x090B8, 0x090B1, 0x090B6, 0x091C7, 0x091D1, 0x09577,
+    0x09580, 0x0961C, 0x09640, 0x0963F, 0x0963B, 0x09644, 0x09642, 0x096B9, 0x096E8, 0x09752, 0x0975E, 0x04E9F,
+    0x04EAD, 0x04EAE, 0x04FE1, 0x04FB5, 0x04FAF, 0x04FBF, 0x04FE0, 0x04FD1, 0x04FCF, 0x04FDD, 0x04FC3, 0x04FB6,
+    0x04FD8, 0x04FDF, 0x04FCA, 0x04FD7, 0x04FAE, 0x04FD0, 0x04FC4, 0x04FC2, 0x04FDA, 0x04FCE, 0x04FDE, 0x04FB7,
+    0x05157, 0x05192, 0x05191, 0x051A0, 0x0524E, 0x05243, 0x0524A, 0x0524D, 0x0524C, 0x0524B, 0x05247, 0x052C7,
+    0x052C9, 0x052C3, 0x052C1, 0x0530D, 0x05357, 0x0537B, 0x0539A, 0x053DB, 0x054AC, 0x054C0, 0x054A8, 0x054CE,
+    0x054C9, 0x054B8, 0x054A6, 0x054B3, 0x054C7, 0x054C2, 0x054BD, 0x054AA, 0x054C1, 0x054C4, 0x054C8, 0x054AF,
+    0x054AB, 0x054B1, 0x054BB, 0x054A9, 0x054A7, 0x054BF, 0x056FF, 0x05782, 0x0578B, 0x057A0, 0x057A3, 0x057A2,
+    0x057CE, 0x057AE, 0x05793, 0x05955, 0x05951, 0x0594F, 0x0594E, 0x05950, 0x059DC, 0x059D8, 0x059FF, 0x059E3,
+    0x059E8, 0x05A03, 0x059E5, 0x059EA, 0x059DA, 0x059E6, 0x05A01, 0x059FB, 0x05B69, 0x05BA3, 0x05BA6, 0x05BA4,
+    0x05BA2, 0x05BA5, 0x05C01, 0x05C4E, 0x05C4F, 0x05C4D, 0x05C4B, 0x05CD9, 0x05CD2, 0x05DF7, 0x05E1D, 0x05E25,
+    0x05E1F, 0x05E7D, 0x05EA0, 0x05EA6, 0x05EFA, 0x05F08, 0x05F2D, 0x05F65, 0x05F88, 0x05F85, 0x05F8A, 0x05F8B,
+    0x05F87, 0x05F8C, 0x05F89, 0x06012, 0x0601D, 0x06020, 0x06025, 0x0600E, 0x06028, 0x0604D, 0x06070, 0x06068,
+    0x06062, 0x06046, 0x06043, 0x0606C, 0x0606B, 0x0606A, 0x06064, 0x06241, 0x062DC, 0x06316, 0x06309, 0x062FC,
+    0x062ED, 0x06301, 0x062EE, 0x062FD, 0x06307, 0x062F1, 0x062F7, 0x062EF, 0x062EC, 0x062FE, 0x062F4, 0x06311,
+    0x06302, 0x0653F, 0x06545, 0x065AB, 0x065BD, 0x065E2, 0x06625, 0x0662D, 0x06620, 0x06627, 0x0662F, 0x0661F,
+    0x06628, 0x06631, 0x06624, 0x066F7, 0x067FF, 0x067D3, 0x067F1, 0x067D4, 0x067D0, 0x067EC, 0x067B6, 0x067AF,
+    0x067F5, 0x067E9, 0x067EF, 0x067C4, 0x067D1, 0x067B4, 0x067DA, 0x067E5, 0x067B8, 0x067CF, 0x067DE, 0x067F3,
+    0x067B0, 0x067D9, 0x067E2, 0x067DD, 0x067D2, 0x06B6A, 0x06B83, 0x06B86, 0x06BB5, 0x06BD2, 0x06BD7, 0x06C1F,
+    0x06CC9, 0x06D0B, 0x06D32, 0x06D2A, 0x06D41, 0x06D25, 0x06D0C, 0x06D31, 0x06D1E, 0x06D17, 0x06D3B, 0x06D3D,
+    0x06D3E, 0x06D36, 0x06D1B, 0x06CF5, 0x06D39, 0x06D27, 0x06D38, 0x06D29, 0x06D2E, 0x06D35, 0x06D0E, 0x06D2B,
+    0x070AB, 0x070BA, 0x070B3, 0x070AC, 0x070AF, 0x070AD, 0x070B8, 0x070AE, 0x070A4, 0x07230, 0x07272, 0x0726F,
+    0x07274, 0x072E9, 0x072E0, 0x072E1, 0x073B7, 0x073CA, 0x073BB, 0x073B2, 0x073CD, 0x073C0, 0x073B3, 0x0751A,
+    0x0752D, 0x0754F, 0x0754C, 0x0754E, 0x0754B, 0x075AB, 0x075A4, 0x075A5, 0x075A2, 0x075A3, 0x07678, 0x07686,
+    0x07687, 0x07688, 0x076C8, 0x076C6, 0x076C3, 0x076C5, 0x07701, 0x076F9, 0x076F8, 0x07709, 0x0770B, 0x076FE,
+    0x076FC, 0x07707, 0x077DC, 0x07802, 0x07814, 0x0780C, 0x0780D, 0x07946, 0x07949, 0x07948, 0x07947, 0x079B9,
+    0x079BA, 0x079D1, 0x079D2, 0x079CB, 0x07A7F, 0x07A81, 0x07AFF, 0x07AFD, 0x07C7D, 0x07D02, 0x07D05, 0x07D00,
+    0x07D09, 0x07D07, 0x07D04, 0x07D06, 0x07F38, 0x07F8E, 0x07FBF, 0x08004, 0x08010, 0x0800D, 0x08011, 0x08036,
+    0x080D6, 0x080E5, 0x080DA, 0x080C3, 0x080C4, 0x080CC, 0x080E1, 0x080DB, 0x080CE, 0x080DE, 0x080E4, 0x080DD,
+    0x081F4, 0x08222, 0x082E7, 0x08303, 0x08305, 0x082E3, 0x082DB, 0x082E6, 0x08304, 0x082E5, 0x08302, 0x08309,
+    0x082D2, 0x082D7, 0x082F1, 0x08301, 0x082DC, 0x082D4, 0x082D1, 0x082DE, 0x082D3, 0x082DF, 0x082EF, 0x08306,
+    0x08650, 0x08679, 0x0867B, 0x0867A, 0x0884D, 0x0886B, 0x08981, 0x089D4, 0x08A08, 0x08A02, 0x08A03, 0x08C9E,
+    0x08CA0, 0x08D74, 0x08D73, 0x08DB4, 0x08ECD, 0x08ECC, 0x08FF0, 0x08FE6, 0x08FE2, 0x08FEA, 0x08FE5, 0x08FED,
+    0x08FEB, 0x08FE4, 0x08FE8, 0x090CA, 0x090CE, 0x090C1, 0x090C3, 0x0914B, 0x0914A, 0x091CD, 0x09582, 0x09650,
+    0x0964B, 0x0964C, 0x0964D, 0x09762, 0x09769, 0x097CB, 0x097ED, 0x097F3, 0x09801, 0x098A8, 0x098DB, 0x098DF,
+    0x09996, 0x09999, 0x04E58, 0x04EB3, 0x0500C, 0x0500D, 0x05023, 0x04FEF, 0x05026, 0x05025, 0x04FF8, 0x05029,
+    0x05016, 0x05006, 0x0503C, 0x0501F, 0x0501A, 0x05012, 0x05011, 0x04FFA, 0x05000, 0x05014, 0x05028, 0x04FF1,
+    0x05021, 0x0500B, 0x05019, 0x05018, 0x04FF3, 0x04FEE, 0x0502D, 0x0502A, 0x04FFE, 0x0502B, 0x05009, 0x0517C,
+    0x051A4, 0x051A5, 0x051A2, 0x051CD, 0x051CC, 0x051C6, 0x051CB, 0x05256, 0x0525C, 0x05254, 0x0525B, 0x0525D,
+    0x0532A, 0x0537F, 0x0539F, 0x0539D, 0x053DF, 0x054E8, 0x05510, 0x05501, 0x05537, 0x054FC, 0x054E5, 0x054F2,
+    0x05506, 0x054FA, 0x05514, 0x054E9, 0x054ED, 0x054E1, 0x05509, 0x054EE, 0x054EA, 0x054E6, 0x05527, 0x05507,
+    0x054FD, 0x0550F, 0x05703, 0x05704, 0x057C2, 0x057D4, 0x057CB, 0x057C3, 0x05809, 0x0590F, 0x05957, 0x05958,
+    0x0595A, 0x05A11, 0x05A18, 0x05A1C, 0x05A1F, 0x05A1B, 0x05A13, 0x059EC, 0x05A20, 0x05A23, 0x05A29, 0x05A25,
+    0x05A0C, 0x05A09, 0x05B6B, 0x05C58, 0x05BB0, 0x05BB3, 0x05BB6, 0x05BB4, 0x05BAE, 0x05BB5, 0x05BB9, 0x05BB8,
+    0x05C04, 0x05C51, 0x05C55, 0x05C50, 0x05CED, 0x05CFD, 0x05CFB, 0x05CEA, 0x05CE8, 0x05CF0, 0x05CF6, 0x05D01,
+    0x05CF4, 0x05DEE, 0x05E2D, 0x05E2B, 0x05EAB, 0x05EAD, 0x05EA7, 0x05F31, 0x05F92, 0x05F91, 0x05F90, 0x06059,
+    0x06063, 0x06065, 0x06050, 0x06055, 0x0606D, 0x06069, 0x0606F, 0x06084, 0x0609F, 0x0609A, 0x0608D, 0x06094,
+    0x0608C, 0x06085, 0x06096, 0x06247, 0x062F3, 0x06308, 0x062FF, 0x0634E, 0x0633E, 0x0632F, 0x06355, 0x06342,
+    0x06346, 0x0634F, 0x06349, 0x0633A, 0x06350, 0x0633D, 0x0632A, 0x0632B, 0x06328, 0x0634D, 0x0634C, 0x06548,
+    0x06549, 0x06599, 0x065C1, 0x065C5, 0x06642, 0x06649, 0x0664F, 0x06643, 0x06652, 0x0664C, 0x06645, 0x06641,
+    0x066F8, 0x06714, 0x06715, 0x06717, 0x06821, 0x06838, 0x06848, 0x06846, 0x06853, 0x06839, 0x06842, 0x06854,
+    0x06829, 0x068B3, 0x06817, 0x0684C, 0x06851, 0x0683D, 0x067F4, 0x06850, 0x06840, 0x0683C, 0x06843, 0x0682A,
+    0x06845, 0x06813, 0x06818, 0x06841, 0x06B8A, 0x06B89, 0x06BB7, 0x06C23, 0x06C27, 0x06C28, 0x06C26, 0x06C24,
+    0x06CF0, 0x06D6A, 0x06D95, 0x06D88, 0x06D87, 0x06D66, 0x06D78, 0x06D77, 0x06D59, 0x06D93, 0x06D6C, 0x06D89,
+    0x06D6E, 0x06D5A, 0x06D74, 0x06D69, 0x06D8C, 0x06D8A, 0x06D79, 0x06D85, 0x06D65, 0x06D94, 0x070CA, 0x070D8,
+    0x070E4, 0x070D9, 0x070C8, 0x070CF, 0x07239, 0x07279, 0x072FC, 0x072F9, 0x072FD, 0x072F8, 0x072F7, 0x07386,
+    0x073ED, 0x07409, 0x073EE, 0x073E0, 0x073EA, 0x073DE, 0x07554, 0x0755D, 0x0755C, 0x0755A, 0x07559, 0x075BE,
+    0x075C5, 0x075C7, 0x075B2, 0x075B3, 0x075BD, 0x075BC, 0x075B9, 0x075C2, 0x075B8, 0x0768B, 0x076B0, 0x076CA,
+    0x076CD, 0x076CE, 0x07729, 0x0771F, 0x07720, 0x07728, 0x077E9, 0x07830, 0x07827, 0x07838, 0x0781D, 0x07834,
+    0x07837, 0x07825, 0x0782D, 0x07820, 0x0781F, 0x07832, 0x07955, 0x07950, 0x07960, 0x0795F, 0x07956, 0x0795E,
+    0x0795D, 0x07957, 0x0795A, 0x079E4, 0x079E3, 0x079E7, 0x079DF, 0x079E6, 0x079E9, 0x079D8, 0x07A84, 0x07A88,
+    0x07AD9, 0x07B06, 0x07B11, 0x07C89, 0x07D21, 0x07D17, 0x07D0B, 0x07D0A, 0x07D20, 0x07D22, 0x07D14, 0x07D10,
+    0x07D15, 0x07D1A, 0x07D1C, 0x07D0D, 0x07D19, 0x07D1B, 0x07F3A, 0x07F5F, 0x07F94, 0x07FC5, 0x07FC1, 0x08006,
+    0x08018, 0x08015, 0x08019, 0x08017, 0x0803D, 0x0803F, 0x080F1, 0x08102, 0x080F0, 0x08105, 0x080ED, 0x080F4,
+    0x08106, 0x080F8, 0x080F3, 0x08108, 0x080FD, 0x0810A, 0x080FC, 0x080EF, 0x081ED, 0x081EC, 0x08200, 0x08210,
+    0x0822A, 0x0822B, 0x08228, 0x0822C, 0x082BB, 0x0832B, 0x08352, 0x08354, 0x0834A, 0x08338, 0x08350, 0x08349,
+    0x08335, 0x08334, 0x0834F, 0x08332, 0x08339, 0x08336, 0x08317, 0x08340, 0x08331, 0x08328, 0x08343, 0x08654,
+    0x0868A, 0x086AA, 0x08693, 0x086A4, 0x086A9, 0x0868C, 0x086A3, 0x0869C, 0x08870, 0x08877, 0x08881, 0x08882,
+    0x0887D, 0x08879, 0x08A18, 0x08A10, 0x08A0E, 0x08A0C, 0x08A15, 0x08A0A, 0x08A17, 0x08A13, 0x08A16, 0x08A0F,
+    0x08A11, 0x08C48, 0x08C7A, 0x08C79, 0x08CA1, 0x08CA2, 0x08D77, 0x08EAC, 0x08ED2, 0x08ED4, 0x08ECF, 0x08FB1,
+    0x09001, 0x09006, 0x08FF7, 0x09000, 0x08FFA, 0x08FF4, 0x09003, 0x08FFD, 0x09005, 0x08FF8, 0x09095, 0x090E1,
+    0x090DD, 0x090E2, 0x09152, 0x0914D, 0x0914C, 0x091D8, 0x091DD, 0x091D7, 0x091DC, 0x091D9, 0x09583, 0x09662,
+    0x09663, 0x09661, 0x0965B, 0x0965D, 0x09664, 0x09658, 0x0965E, 0x096BB, 0x098E2, 0x099AC, 0x09AA8, 0x09AD8,
+    0x09B25, 0x09B32, 0x09B3C, 0x04E7E, 0x0507A, 0x0507D, 0x0505C, 0x05047, 0x05043, 0x0504C, 0x0505A, 0x05049,
+    0x05065, 0x05076, 0x0504E, 0x05055, 0x05075, 0x05074, 0x05077, 0x0504F, 0x0500F, 0x0506F, 0x0506D, 0x0515C,
+    0x05195, 0x051F0, 0x0526A, 0x0526F, 0x052D2, 0x052D9, 0x052D8, 0x052D5, 0x05310, 0x0530F, 0x05319, 0x0533F,
+    0x05340, 0x0533E, 0x053C3, 0x066FC, 0x05546, 0x0556A, 0x05566, 0x05544, 0x0555E, 0x05561, 0x05543, 0x0554A,
+    0x05531, 0x05556, 0x0554F, 0x05555, 0x0552F, 0x05564, 0x05538, 0x0552E, 0x0555C, 0x0552C, 0x05563, 0x05533,
+    0x05541, 0x05557, 0x05708, 0x0570B, 0x05709, 0x057DF, 0x05805, 0x0580A, 0x05806, 0x057E0, 0x057E4, 0x057FA,
+    0x05802, 0x05835, 0x057F7, 0x057F9, 0x05920, 0x05962, 0x05A36, 0x05A41, 0x05A49, 0x05A66, 0x05A6A, 0x05A40,
+    0x05A3C, 0x05A62, 0x05A5A, 0x05A46, 0x05A4A, 0x05B70, 0x05BC7, 0x05BC5, 0x05BC4, 0x05BC2, 0x05BBF, 0x05BC6,
+    0x05C09, 0x05C08, 0x05C07, 0x05C60, 0x05C5C, 0x05C5D, 0x05D07, 0x05D06, 0x05D0E, 0x05D1B, 0x05D16, 0x05D22,
+    0x05D11, 0x05D29, 0x05D14, 0x05D19, 0x05D24, 0x05D27, 0x05D17, 0x05DE2, 0x05E38, 0x05E36, 0x05E33, 0x05E37,
+    0x05EB7, 0x05EB8, 0x05EB6, 0x05EB5, 0x05EBE, 0x05F35, 0x05F37, 0x05F57, 0x05F6C, 0x05F69, 0x05F6B, 0x05F97,
+    0x05F99, 0x05F9E, 0x05F98, 0x05FA1, 0x05FA0, 0x05F9C, 0x0607F, 0x060A3, 0x06089, 0x060A0, 0x060A8, 0x060CB,
+    0x060B4, 0x060E6, 0x060BD, 0x060C5, 0x060BB, 0x060B5, 0x060DC, 0x060BC, 0x060D8, 0x060D5, 0x060C6, 0x060DF,
+    0x060B8, 0x060DA, 0x060C7, 0x0621A, 0x0621B, 0x06248, 0x063A0, 0x063A7, 0x06372, 0x06396, 0x063A2, 0x063A5,
+    0x06377, 0x06367, 0x06398, 0x063AA, 0x06371, 0x063A9, 0x06389, 0x06383, 0x0639B, 0x0636B, 0x063A8, 0x06384,
+    0x06388, 0x06399, 0x063A1, 0x063AC, 0x06392, 0x0638F, 0x06380, 0x0637B, 0x06369, 0x06368, 0x0637A, 0x0655D,
+    0x06556, 0x06551, 0x06559, 0x06557, 0x0555F, 0x0654F, 0x06558, 0x06555, 0x06554, 0x0659C, 0x0659B, 0x065AC,
+    0x065CF, 0x065CB, 0x065CC, 0x065CE, 0x0665D, 0x0665A, 0x06664, 0x06668, 0x06666, 0x0665E, 0x066F9, 0x052D7,
+    0x0671B, 0x06881, 0x068AF, 0x068A2, 0x06893, 0x068B5, 0x0687F, 0x06876, 0x068B1, 0x068A7, 0x06897, 0x068B0,
+    0x06883, 0x068C4, 0x068AD, 0x06886, 0x06885, 0x06894, 0x0689D, 0x068A8, 0x0689F, 0x068A1, 0x06882, 0x06B32,
+    0x06BBA, 0x06BEB, 0x06BEC, 0x06C2B, 0x06D8E, 0x06DBC, 0x06DF3, 0x06DD9, 0x06DB2, 0x06DE1, 0x06DCC, 0x06DE4,
+    0x06DFB, 0x06DFA, 0x06E05, 0x06DC7, 0x06DCB, 0x06DAF, 0x06DD1, 0x06DAE, 0x06DDE, 0x06DF9, 0x06DB8, 0x06DF7,
+    0x06DF5, 0x06DC5, 0x06DD2, 0x06E1A, 0x06DB5, 0x06DDA, 0x06DEB, 0x06DD8, 0x06DEA, 0x06DF1, 0x06DEE, 0x06DE8,
+    0x06DC6, 0x06DC4, 0x06DAA, 0x06DEC, 0x06DBF, 0x06DE6, 0x070F9, 0x07109, 0x0710A, 0x070FD, 0x070EF, 0x0723D,
+    0x0727D, 0x07281, 0x0731C, 0x0731B, 0x07316, 0x07313, 0x07319, 0x07387, 0x07405, 0x0740A, 0x07403, 0x07406,
+    0x073FE, 0x0740D, 0x074E0, 0x074F6, 0x074F7, 0x0751C, 0x07522, 0x07565, 0x07566, 0x07562, 0x07570, 0x0758F,
+    0x075D4, 0x075D5, 0x075B5, 0x075CA, 0x075CD, 0x0768E, 0x076D4, 0x076D2, 0x076DB, 0x07737, 0x0773E, 0x0773C,
+    0x07736, 0x07738, 0x0773A, 0x0786B, 0x07843, 0x0784E, 0x07965, 0x07968, 0x0796D, 0x079FB, 0x07A92, 0x07A95,
+    0x07B20, 0x07B28, 0x07B1B, 0x07B2C, 0x07B26, 0x07B19, 0x07B1E, 0x07B2E, 0x07C92, 0x07C97, 0x07C95, 0x07D46,
+    0x07D43, 0x07D71, 0x07D2E, 0x07D39, 0x07D3C, 0x07D40, 0x07D30, 0x07D33, 0x07D44, 0x07D2F, 0x07D42, 0x07D32,
+    0x07D31, 0x07F3D, 0x07F9E, 0x07F9A, 0x07FCC, 0x07FCE, 0x07FD2, 0x0801C, 0x0804A, 0x08046, 0x0812F, 0x08116,
+    0x08123, 0x0812B, 0x08129, 0x08130, 0x08124, 0x08202, 0x08235, 0x08237, 0x08236, 0x08239, 0x0838E, 0x0839E,
+    0x08398, 0x08378, 0x083A2, 0x08396, 0x083BD, 0x083AB, 0x08392, 0x0838A, 0x08393, 0x08389, 0x083A0, 0x08377,
+    0x0837B, 0x0837C, 0x08386, 0x083A7, 0x08655, 0x05F6A, 0x086C7, 0x086C0, 0x086B6, 0x086C4, 0x086B5, 0x086C6,
+    0x086CB, 0x086B1, 0x086AF, 0x086C9, 0x08853, 0x0889E, 0x08888, 0x088AB, 0x08892, 0x08896, 0x0888D, 0x0888B,
+    0x08993, 0x0898F, 0x08A2A, 0x08A1D, 0x08A23, 0x08A25, 0x08A31, 0x08A2D, 0x08A1F, 0x08A1B, 0x08A22, 0x08C49,
+    0x08C5A, 0x08CA9, 0x08CAC, 0x08CAB, 0x08CA8, 0x08CAA, 0x08CA7, 0x08D67, 0x08D66, 0x08DBE, 0x08DBA, 0x08EDB,
+    0x08EDF, 0x09019, 0x0900D, 0x0901A, 0x09017, 0x09023, 0x0901F, 0x0901D, 0x09010, 0x09015, 0x0901E, 0x09020,
+    0x0900F, 0x09022, 0x09016, 0x0901B, 0x09014, 0x090E8, 0x090ED, 0x090FD, 0x09157, 0x091CE, 0x091F5, 0x091E6,
+    0x091E3, 0x091E7, 0x091ED, 0x091E9, 0x09589, 0x0966A, 0x09675, 0x09673, 0x09678, 0x09670, 0x09674, 0x09676,
+    0x09677, 0x0966C, 0x096C0, 0x096EA, 0x096E9, 0x07AE0, 0x07ADF, 0x09802, 0x09803, 0x09B5A, 0x09CE5, 0x09E75,
+    0x09E7F, 0x09EA5, 0x09EBB, 0x050A2, 0x0508D, 0x05085, 0x05099, 0x05091, 0x05080, 0x05096, 0x05098, 0x0509A,
+    0x06700, 0x051F1, 0x05272, 0x05274, 0x05275, 0x05269, 0x052DE, 0x052DD, 0x052DB, 0x0535A, 0x053A5, 0x0557B,
+    0x05580, 0x055A7, 0x0557C, 0x0558A, 0x0559D, 0x05598, 0x05582, 0x0559C, 0x055AA, 0x05594, 0x05587, 0x0558B,
+    0x05583, 0x055B3, 0x055AE, 0x0559F, 0x0553E, 0x055B2, 0x0559A, 0x055BB, 0x055AC, 0x055B1, 0x0557E, 0x05589,
+    0x055AB, 0x05599, 0x0570D, 0x0582F, 0x0582A, 0x05834, 0x05824, 0x05830, 0x05831, 0x05821, 0x0581D, 0x05820,
+    0x058F9, 0x058FA, 0x05960, 0x05A77, 0x05A9A, 0x05A7F, 0x05A92, 0x05A9B, 0x05AA7, 0x05B73, 0x05B71, 0x05BD2,
+    0x05BCC, 0x05BD3, 0x05BD0, 0x05C0A, 0x05C0B, 0x05C31, 0x05D4C, 0x05D50, 0x05D34, 0x05D47, 0x05DFD, 0x05E45,
+    0x05E3D, 0x05E40, 0x05E43, 0x05E7E, 0x05ECA, 0x05EC1, 0x05EC2, 0x05EC4, 0x05F3C, 0x05F6D, 0x05FA9, 0x05FAA,
+    0x05FA8, 0x060D1, 0x060E1, 0x060B2, 0x060B6, 0x060E0, 0x0611C, 0x06123, 0x060FA, 0x06115, 0x060F0, 0x060FB,
+    0x060F4, 0x06168, 0x060F1, 0x0610E, 0x060F6, 0x06109, 0x06100, 0x06112, 0x0621F, 0x06249, 0x063A3, 0x0638C,
+    0x063CF, 0x063C0, 0x063E9, 0x063C9, 0x063C6, 0x063CD, 0x063D2, 0x063E3, 0x063D0, 0x063E1, 0x063D6, 0x063ED,
+    0x063EE, 0x06376, 0x063F4, 0x063EA, 0x063DB, 0x06452, 0x063DA, 0x063F9, 0x0655E, 0x06566, 0x06562, 0x06563,
+    0x06591, 0x06590, 0x065AF, 0x0666E, 0x06670, 0x06674, 0x06676, 0x0666F, 0x06691, 0x0667A, 0x0667E, 0x06677,
+    0x066FE, 0x066FF, 0x0671F, 0x0671D, 0x068FA, 0x068D5, 0x068E0, 0x068D8, 0x068D7, 0x06905, 0x068DF, 0x068F5,
+    0x068EE, 0x068E7, 0x068F9, 0x068D2, 0x068F2, 0x068E3, 0x068CB, 0x068CD, 0x0690D, 0x06912, 0x0690E, 0x068C9,
+    0x068DA, 0x0696E, 0x068FB, 0x06B3E, 0x06B3A, 0x06B3D, 0x06B98, 0x06B96, 0x06BBC, 0x06BEF, 0x06C2E, 0x06C2F,
+    0x06C2C, 0x06E2F, 0x06E38, 0x06E54, 0x06E21, 0x06E32, 0x06E67, 0x06E4A, 0x06E20, 0x06E25, 0x06E23, 0x06E1B,
+    0x06E5B, 0x06E58, 0x06E24, 0x06E56, 0x06E6E, 0x06E2D, 0x06E26, 0x06E6F, 0x06E34, 0x06E4D, 0x06E3A, 0x06E2C,
+    0x06E43, 0x06E1D, 0x06E3E, 0x06ECB, 0x06E89, 0x06E19, 0x06E4E, 0x06E63, 0x06E44, 0x06E72, 0x06E69, 0x06E5F,
+    0x07119, 0x0711A, 0x07126, 0x07130, 0x07121, 0x07136, 0x0716E, 0x0711C, 0x0724C, 0x07284, 0x07280, 0x07336,
+    0x07325, 0x07334, 0x07329, 0x0743A, 0x0742A, 0x07433, 0x07422, 0x07425, 0x07435, 0x07436, 0x07434, 0x0742F,
+    0x0741B, 0x07426, 0x07428, 0x07525, 0x07526, 0x0756B, 0x0756A, 0x075E2, 0x075DB, 0x075E3, 0x075D9, 0x075D8,
+    0x075DE, 0x075E0, 0x0767B, 0x0767C, 0x07696, 0x07693, 0x076B4, 0x076DC, 0x0774F, 0x077ED, 0x0785D, 0x0786C,
+    0x0786F, 0x07A0D, 0x07A08, 0x07A0B, 0x07A05, 0x07A00, 0x07A98, 0x07A97, 0x07A96, 0x07AE5, 0x07AE3, 0x07B49,
+    0x07B56, 0x07B46, 0x07B50, 0x07B52, 0x07B54, 0x07B4D, 0x07B4B, 0x07B4F, 0x07B51, 0x07C9F, 0x07CA5, 0x07D5E,
+    0x07D50, 0x07D68, 0x07D55, 0x07D2B, 0x07D6E, 0x07D72, 0x07D61, 0x07D66, 0x07D62, 0x07D70, 0x07D73, 0x05584,
+    0x07FD4, 0x07FD5, 0x0800B, 0x08052, 0x08085, 0x08155, 0x08154, 0x0814B, 0x08151, 0x0814E, 0x08139, 0x08146,
+    0x0813E, 0x0814C, 0x08153, 0x08174, 0x08212, 0x0821C, 0x083E9, 0x08403, 0x083F8, 0x0840D, 0x083E0, 0x083C5,
+    0x0840B, 0x083C1, 0x083EF, 0x083F1, 0x083F4, 0x08457, 0x0840A, 0x083F0, 0x0840C, 0x083CC, 0x083FD, 0x083F2,
+    0x083CA, 0x08438, 0x0840E, 0x08404, 0x083DC, 0x08407, 0x083D4, 0x083DF, 0x0865B, 0x086DF, 0x086D9, 0x086ED,
+    0x086D4, 0x086DB, 0x086E4, 0x086D0, 0x086DE, 0x08857, 0x088C1, 0x088C2, 0x088B1, 0x08983, 0x08996, 0x08A3B,
+    0x08A60, 0x08A55, 0x08A5E, 0x08A3C, 0x08A41, 0x08A54, 0x08A5B, 0x08A50, 0x08A46, 0x08A34, 0x08A3A, 0x08A36,
+    0x08A56, 0x08C61, 0x08C82, 0x08CAF, 0x08CBC, 0x08CB3, 0x08CBD, 0x08CC1, 0x08CBB, 0x08CC0, 0x08CB4, 0x08CB7,
+    0x08CB6, 0x08CBF, 0x08CB8, 0x08D8A, 0x08D85, 0x08D81, 0x08DCE, 0x08DDD, 0x08DCB, 0x08DDA, 0x08DD1, 0x08DCC,
+    0x08DDB, 0x08DC6, 0x08EFB, 0x08EF8, 0x08EFC, 0x08F9C, 0x0902E, 0x09035, 0x09031, 0x09038, 0x09032, 0x09036,
+    0x09102, 0x090F5, 0x09109, 0x090FE, 0x09163, 0x09165, 0x091CF, 0x09214, 0x09215, 0x09223, 0x09209, 0x0921E,
+    0x0920D, 0x09210, 0x09207, 0x09211, 0x09594, 0x0958F, 0x0958B, 0x09591, 0x09593, 0x09592, 0x0958E, 0x0968A,
+    0x0968E, 0x0968B, 0x0967D, 0x09685, 0x09686, 0x0968D, 0x09672, 0x09684, 0x096C1, 0x096C5, 0x096C4, 0x096C6,
+    0x096C7, 0x096EF, 0x096F2, 0x097CC, 0x09805, 0x09806, 0x09808, 0x098E7, 0x098EA, 0x098EF, 0x098E9, 0x098F2,
+    0x098ED, 0x099AE, 0x099AD, 0x09EC3, 0x09ECD, 0x09ED1, 0x04E82, 0x050AD, 0x050B5, 0x050B2, 0x050B3, 0x050C5,
+    0x050BE, 0x050AC, 0x050B7, 0x050BB, 0x050AF, 0x050C7, 0x0527F, 0x05277, 0x0527D, 0x052DF, 0x052E6, 0x052E4,
+    0x052E2, 0x052E3, 0x0532F, 0x055DF, 0x055E8, 0x055D3, 0x055E6, 0x055CE, 0x055DC, 0x055C7, 0x055D1, 0x055E3,
+    0x055E4, 0x055EF, 0x055DA, 0x055E1, 0x055C5, 0x055C6, 0x055E5, 0x055C9, 0x05712, 0x05713, 0x0585E, 0x05851,
+    0x05858, 0x05857, 0x0585A, 0x05854, 0x0586B, 0x0584C, 0x0586D, 0x0584A, 0x05862, 0x05852, 0x0584B, 0x05967,
+    0x05AC1, 0x05AC9, 0x05ACC, 0x05ABE, 0x05ABD, 0x05ABC, 0x05AB3, 0x05AC2, 0x05AB2, 0x05D69, 0x05D6F, 0x05E4C,
+    0x05E79, 0x05EC9, 0x05EC8, 0x05F12, 0x05F59, 0x05FAC, 0x05FAE, 0x0611A, 0x0610F, 0x06148, 0x0611F, 0x060F3,
+    0x0611B, 0x060F9, 0x06101, 0x06108, 0x0614E, 0x0614C, 0x06144, 0x0614D, 0x0613E, 0x06134, 0x06127, 0x0610D,
+    0x06106, 0x06137, 0x06221, 0x06222, 0x06413, 0x0643E, 0x0641E, 0x0642A, 0x0642D, 0x0643D, 0x0642C, 0x0640F,
+    0x0641C, 0x06414, 0x0640D, 0x06436, 0x06416, 0x06417, 0x06406, 0x0656C, 0x0659F, 0x065B0, 0x06697, 0x06689,
+    0x06687, 0x06688, 0x06696, 0x06684, 0x06698, 0x0668D, 0x06703, 0x06994, 0x0696D, 0x0695A, 0x06977, 0x06960,
+    0x06954, 0x06975, 0x06930, 0x06982, 0x0694A, 0x06968, 0x0696B, 0x0695E, 0x06953, 0x06979, 0x06986, 0x0695D,
+    0x06963, 0x0695B, 0x06B47, 0x06B72, 0x06BC0, 0x06BBF, 0x06BD3, 0x06BFD, 0x06EA2, 0x06EAF, 0x06ED3, 0x06EB6,
+    0x06EC2, 0x06E90, 0x06E9D, 0x06EC7, 0x06EC5, 0x06EA5, 0x06E98, 0x06EBC, 0x06EBA, 0x06EAB, 0x06ED1, 0x06E96,
+    0x06E9C, 0x06EC4, 0x06ED4, 0x06EAA, 0x06EA7, 0x06EB4, 0x0714E, 0x07159, 0x07169, 0x07164, 0x07149, 0x07167,
+    0x0715C, 0x0716C, 0x07166, 0x0714C, 0x07165, 0x0715E, 0x07146, 0x07168, 0x07156, 0x0723A, 0x07252, 0x07337,
+    0x07345, 0x0733F, 0x0733E, 0x0746F, 0x0745A, 0x07455, 0x0745F, 0x0745E, 0x07441, 0x0743F, 0x07459, 0x0745B,
+    0x0745C, 0x07576, 0x07578, 0x07600, 0x075F0, 0x07601, 0x075F2, 0x075F1, 0x075FA, 0x075FF, 0x075F4, 0x075F3,
+    0x076DE, 0x076DF, 0x0775B, 0x0776B, 0x07766, 0x0775E, 0x07763, 0x07779, 0x0776A, 0x0776C, 0x0775C, 0x07765,
+    0x07768, 0x07762, 0x077EE, 0x0788E, 0x078B0, 0x07897, 0x07898, 0x0788C, 0x07889, 0x0787C, 0x07891, 0x07893,
+    0x0787F, 0x0797A, 0x0797F, 0x07981, 0x0842C, 0x079BD, 0x07A1C, 0x07A1A, 0x07A20, 0x07A14, 0x07A1F, 0x07A1E,
+    0x07A9F, 0x07AA0, 0x07B77, 0x07BC0, 0x07B60, 0x07B6E, 0x07B67, 0x07CB1, 0x07CB3, 0x07CB5, 0x07D93, 0x07D79,
+    0x07D91, 0x07D81, 0x07D8F, 0x07D5B, 0x07F6E, 0x07F69, 0x07F6A, 0x07F72, 0x07FA9, 0x07FA8, 0x07FA4, 0x08056,
+    0x08058, 0x08086, 0x08084, 0x08171, 0x08170, 0x08178, 0x08165, 0x0816E, 0x08173, 0x0816B, 0x08179, 0x0817A,
+    0x08166, 0x08205, 0x08247, 0x08482, 0x08477, 0x0843D, 0x08431, 0x08475, 0x08466, 0x0846B, 0x08449, 0x0846C,
+    0x0845B, 0x0843C, 0x08435, 0x08461, 0x08463, 0x08469, 0x0846D, 0x08446, 0x0865E, 0x0865C, 0x0865F, 0x086F9,
+    0x08713, 0x08708, 0x08707, 0x08700, 0x086FE, 0x086FB, 0x08702, 0x08703, 0x08706, 0x0870A, 0x08859, 0x088DF,
+    0x088D4, 0x088D9, 0x088DC, 0x088D8, 0x088DD, 0x088E1, 0x088CA, 0x088D5, 0x088D2, 0x0899C, 0x089E3, 0x08A6B,
+    0x08A72, 0x08A73, 0x08A66, 0x08A69, 0x08A70, 0x08A87, 0x08A7C, 0x08A63, 0x08AA0, 0x08A71, 0x08A85, 0x08A6D,
+    0x08A62, 0x08A6E, 0x08A6C, 0x08A79, 0x08A7B, 0x08A3E, 0x08A68, 0x08C62, 0x08C8A, 0x08C89, 0x08CCA, 0x08CC7,
+    0x08CC8, 0x08CC4, 0x08CB2, 0x08CC3, 0x08CC2, 0x08CC5, 0x08DE1, 0x08DDF, 0x08DE8, 0x08DEF, 0x08DF3, 0x08DFA,
+    0x08DEA, 0x08DE4, 0x08DE6, 0x08EB2, 0x08F03, 0x08F09, 0x08EFE, 0x08F0A, 0x08F9F, 0x08FB2, 0x0904B, 0x0904A,
+    0x09053, 0x09042, 0x09054, 0x0903C, 0x09055, 0x09050, 0x09047, 0x0904F, 0x0904E, 0x0904D, 0x09051, 0x0903E,
+    0x09041, 0x09112, 0x09117, 0x0916C, 0x0916A, 0x09169, 0x091C9, 0x09237, 0x09257, 0x09238, 0x0923D, 0x09240,
+    0x0923E, 0x0925B, 0x0924B, 0x09264, 0x09251, 0x09234, 0x09249, 0x0924D, 0x09245, 0x09239, 0x0923F, 0x0925A,
+    0x09598, 0x09698, 0x09694, 0x09695, 0x096CD, 0x096CB, 0x096C9, 0x096CA, 0x096F7, 0x096FB, 0x096F9, 0x096F6,
+    0x09756, 0x09774, 0x09776, 0x09810, 0x09811, 0x09813, 0x0980A, 0x09812, 0x0980C, 0x098FC, 0x098F4, 0x098FD,
+    0x098FE, 0x099B3, 0x099B1, 0x099B4, 0x09AE1, 0x09CE9, 0x09E82, 0x09F0E, 0x09F13, 0x09F20, 0x050E7, 0x050EE,
+    0x050E5, 0x050D6, 0x050ED, 0x050DA, 0x050D5, 0x050CF, 0x050D1, 0x050F1, 0x050CE, 0x050E9, 0x05162, 0x051F3,
+    0x05283, 0x05282, 0x05331, 0x053AD, 0x055FE, 0x05600, 0x0561B, 0x05617, 0x055FD, 0x05614, 0x05606, 0x05609,
+    0x0560D, 0x0560E, 0x055F7, 0x05616, 0x0561F, 0x05608, 0x05610, 0x055F6, 0x05718, 0x05716, 0x05875, 0x0587E,
+    0x05883, 0x05893, 0x0588A, 0x05879, 0x05885, 0x0587D, 0x058FD, 0x05925, 0x05922, 0x05924, 0x0596A, 0x05969,
+    0x05AE1, 0x05AE6, 0x05AE9, 0x05AD7, 0x05AD6, 0x05AD8, 0x05AE3, 0x05B75, 0x05BDE, 0x05BE7, 0x05BE1, 0x05BE5,
+    0x05BE6, 0x05BE8, 0x05BE2, 0x05BE4, 0x05BDF, 0x05C0D, 0x05C62, 0x05D84, 0x05D87, 0x05E5B, 0x05E63, 0x05E55,
+    0x05E57, 0x05E54, 0x05ED3, 0x05ED6, 0x05F0A, 0x05F46, 0x05F70, 0x05FB9, 0x06147, 0x0613F, 0x0614B, 0x06177,
+    0x06162, 0x06163, 0x0615F, 0x0615A, 0x06158, 0x06175, 0x0622A, 0x06487, 0x06458, 0x06454, 0x064A4, 0x06478,
+    0x0645F, 0x0647A, 0x06451, 0x06467, 0x06434, 0x0646D, 0x0647B, 0x06572, 0x065A1, 0x065D7, 0x065D6, 0x066A2,
+    0x066A8, 0x0669D, 0x0699C, 0x069A8, 0x06995, 0x069C1, 0x069AE, 0x069D3, 0x069CB, 0x0699B, 0x069B7, 0x069BB,
+    0x069AB, 0x069B4, 0x069D0, 0x069CD, 0x069AD, 0x069CC, 0x069A6, 0x069C3, 0x069A3, 0x06B49, 0x06B4C, 0x06C33,
+    0x06F33, 0x06F14, 0x06EFE, 0x06F13, 0x06EF4, 0x06F29, 0x06F3E, 0x06F20, 0x06F2C, 0x06F0F, 0x06F02, 0x06F22,
+    0x06EFF, 0x06EEF, 0x06F06, 0x06F31, 0x06F38, 0x06F32, 0x06F23, 0x06F15, 0x06F2B, 0x06F2F, 0x06F88, 0x06F2A,
+    0x06EEC, 0x06F01, 0x06EF2, 0x06ECC, 0x06EF7, 0x07194, 0x07199, 0x0717D, 0x0718A, 0x07184, 0x07192, 0x0723E,
+    0x07292, 0x07296, 0x07344, 0x07350, 0x07464, 0x07463, 0x0746A, 0x07470, 0x0746D, 0x07504, 0x07591, 0x07627,
+    0x0760D, 0x0760B, 0x07609, 0x07613, 0x076E1, 0x076E3, 0x07784, 0x0777D, 0x0777F, 0x07761, 0x078C1, 0x0789F,
+    0x078A7, 0x078B3, 0x078A9, 0x078A3, 0x0798E, 0x0798F, 0x0798D, 0x07A2E, 0x07A31, 0x07AAA, 0x07AA9, 0x07AED,
+    0x07AEF, 0x07BA1, 0x07B95, 0x07B8B, 0x07B75, 0x07B97, 0x07B9D, 0x07B94, 0x07B8F, 0x07BB8, 0x07B87, 0x07B84,
+    0x07CB9, 0x07CBD, 0x07CBE, 0x07DBB, 0x07DB0, 0x07D9C, 0x07DBD, 0x07DBE, 0x07DA0, 0x07DCA, 0x07DB4, 0x07DB2,
+    0x07DB1, 0x07DBA, 0x07DA2, 0x07DBF, 0x07DB5, 0x07DB8, 0x07DAD, 0x07DD2, 0x07DC7, 0x07DAC, 0x07F70, 0x07FE0,
+    0x07FE1, 0x07FDF, 0x0805E, 0x0805A, 0x08087, 0x08150, 0x08180, 0x0818F, 0x08188, 0x0818A, 0x0817F, 0x08182,
+    0x081E7, 0x081FA, 0x08207, 0x08214, 0x0821E, 0x0824B, 0x084C9, 0x084BF, 0x084C6, 0x084C4, 0x08499, 0x0849E,
+    0x084B2, 0x0849C, 0x084CB, 0x084B8, 0x084C0, 0x084D3, 0x08490, 0x084BC, 0x084D1, 0x084CA, 0x0873F, 0x0871C,
+    0x0873B, 0x08722, 0x08725, 0x08734, 0x08718, 0x08755, 0x08737, 0x08729, 0x088F3, 0x08902, 0x088F4, 0x088F9,
+    0x088F8, 0x088FD, 0x088E8, 0x0891A, 0x088EF, 0x08AA6, 0x08A8C, 0x08A9E, 0x08AA3, 0x08A8D, 0x08AA1, 0x08A93,
+    0x08AA4, 0x08AAA, 0x08AA5, 0x08AA8, 0x08A98, 0x08A91, 0x08A9A, 0x08AA7, 0x08C6A, 0x08C8D, 0x08C8C, 0x08CD3,
+    0x08CD1, 0x08CD2, 0x08D6B, 0x08D99, 0x08D95, 0x08DFC, 0x08F14, 0x08F12, 0x08F15, 0x08F13, 0x08FA3, 0x09060,
+    0x09058, 0x0905C, 0x09063, 0x09059, 0x0905E, 0x09062, 0x0905D, 0x0905B, 0x09119, 0x09118, 0x0911E, 0x09175,
+    0x09178, 0x09177, 0x09174, 0x09278, 0x09280, 0x09285, 0x09298, 0x09296, 0x0927B, 0x09293, 0x0929C, 0x092A8,
+    0x0927C, 0x09291, 0x095A1, 0x095A8, 0x095A9, 0x095A3, 0x095A5, 0x095A4, 0x09699, 0x0969C, 0x0969B, 0x096CC,
+    0x096D2, 0x09700, 0x0977C, 0x09785, 0x097F6, 0x09817, 0x09818, 0x098AF, 0x098B1, 0x09903, 0x09905, 0x0990C,
+    0x09909, 0x099C1, 0x09AAF, 0x09AB0, 0x09AE6, 0x09B41, 0x09B42, 0x09CF4, 0x09CF6, 0x09CF3, 0x09EBC, 0x09F3B,
+    0x09F4A, 0x05104, 0x05100, 0x050FB, 0x050F5, 0x050F9, 0x05102, 0x05108, 0x05109, 0x05105, 0x051DC, 0x05287,
+    0x05288, 0x05289, 0x0528D, 0x0528A, 0x052F0, 0x053B2, 0x0562E, 0x0563B, 0x05639, 0x05632, 0x0563F, 0x05634,
+    0x05629, 0x05653, 0x0564E, 0x05657, 0x05674, 0x05636, 0x0562F, 0x05630, 0x05880, 0x0589F, 0x0589E, 0x058B3,
+    0x0589C, 0x058AE, 0x058A9, 0x058A6, 0x0596D, 0x05B09, 0x05AFB, 0x05B0B, 0x05AF5, 0x05B0C, 0x05B08, 0x05BEE,
+    0x05BEC, 0x05BE9, 0x05BEB, 0x05C64, 0x05C65, 0x05D9D, 0x05D94, 0x05E62, 0x05E5F, 0x05E61, 0x05EE2, 0x05EDA,
+    0x05EDF, 0x05EDD, 0x05EE3, 0x05EE0, 0x05F48, 0x05F71, 0x05FB7, 0x05FB5, 0x06176, 0x06167, 0x0616E, 0x0615D,
+    0x06155, 0x06182, 0x0617C, 0x06170, 0x0616B, 0x0617E, 0x061A7, 0x06190, 0x061AB, 0x0618E, 0x061AC, 0x0619A,
+    0x061A4, 0x06194, 0x061AE, 0x0622E, 0x06469, 0x0646F, 0x06479, 0x0649E, 0x064B2, 0x06488, 0x06490, 0x064B0,
+    0x064A5, 0x06493, 0x06495, 0x064A9, 0x06492, 0x064AE, 0x064AD, 0x064AB, 0x0649A, 0x064AC, 0x06499, 0x064A2,
+    0x064B3, 0x06575, 0x06577, 0x06578, 0x066AE, 0x066AB, 0x066B4, 0x066B1, 0x06A23, 0x06A1F, 0x069E8, 0x06A01,
+    0x06A1E, 0x06A19, 0x069FD, 0x06A21, 0x06A13, 0x06A0A, 0x069F3, 0x06A02, 0x06A05, 0x069ED, 0x06A11, 0x06B50,
+    0x06B4E, 0x06BA4, 0x06BC5, 0x06BC6, 0x06F3F, 0x06F7C, 0x06F84, 0x06F51, 0x06F66, 0x06F54, 0x06F86, 0x06F6D,
+    0x06F5B, 0x06F78, 0x06F6E, 0x06F8E, 0x06F7A, 0x06F70, 0x06F64, 0x06F97, 0x06F58, 0x06ED5, 0x06F6F, 0x06F60,
+    0x06F5F, 0x0719F, 0x071AC, 0x071B1, 0x071A8, 0x07256, 0x0729B, 0x0734E, 0x07357, 0x07469, 0x0748B, 0x07483,
+    0x0747E, 0x07480, 0x0757F, 0x07620, 0x07629, 0x0761F, 0x07624, 0x07626, 0x07621, 0x07622, 0x0769A, 0x076BA,
+    0x076E4, 0x0778E, 0x07787, 0x0778C, 0x07791, 0x0778B, 0x078CB, 0x078C5, 0x078BA, 0x078CA, 0x078BE, 0x078D5,
+    0x078BC, 0x078D0, 0x07A3F, 0x07A3C, 0x07A40, 0x07A3D, 0x07A37, 0x07A3B, 0x07AAF, 0x07AAE, 0x07BAD, 0x07BB1,
+    0x07BC4, 0x07BB4, 0x07BC6, 0x07BC7, 0x07BC1, 0x07BA0, 0x07BCC, 0x07CCA, 0x07DE0, 0x07DF4, 0x07DEF, 0x07DFB,
+    0x07DD8, 0x07DEC, 0x07DDD, 0x07DE8, 0x07DE3, 0x07DDA, 0x07DDE, 0x07DE9, 0x07D9E, 0x07DD9, 0x07DF2, 0x07DF9,
+    0x07F75, 0x07F77, 0x07FAF, 0x07FE9, 0x08026, 0x0819B, 0x0819C, 0x0819D, 0x081A0, 0x0819A, 0x08198, 0x08517,
+    0x0853D, 0x0851A, 0x084EE, 0x0852C, 0x0852D, 0x08513, 0x08511, 0x08523, 0x08521, 0x08514, 0x084EC, 0x08525,
+    0x084FF, 0x08506, 0x08782, 0x08774, 0x08776, 0x08760, 0x08766, 0x08778, 0x08768, 0x08759, 0x08757, 0x0874C,
+    0x08753, 0x0885B, 0x0885D, 0x08910, 0x08907, 0x08912, 0x08913, 0x08915, 0x0890A, 0x08ABC, 0x08AD2, 0x08AC7,
+    0x08AC4, 0x08A95, 0x08ACB, 0x08AF8, 0x08AB2, 0x08AC9, 0x08AC2, 0x08ABF, 0x08AB0, 0x08AD6, 0x08ACD, 0x08AB6,
+    0x08AB9, 0x08ADB, 0x08C4C, 0x08C4E, 0x08C6C, 0x08CE0, 0x08CDE, 0x08CE6, 0x08CE4, 0x08CEC, 0x08CED, 0x08CE2,
+    0x08CE3, 0x08CDC, 0x08CEA, 0x08CE1, 0x08D6D, 0x08D9F, 0x08DA3, 0x08E2B, 0x08E10, 0x08E1D, 0x08E22, 0x08E0F,
+    0x08E29, 0x08E1F, 0x08E21, 0x08E1E, 0x08EBA, 0x08F1D, 0x08F1B, 0x08F1F, 0x08F29, 0x08F26, 0x08F2A, 0x08F1C,
+    0x08F1E, 0x08F25, 0x09069, 0x0906E, 0x09068, 0x0906D, 0x09077, 0x09130, 0x0912D, 0x09127, 0x09131, 0x09187,
+    0x09189, 0x0918B, 0x09183, 0x092C5, 0x092BB, 0x092B7, 0x092EA, 0x092AC, 0x092E4, 0x092C1, 0x092B3, 0x092BC,
+    0x092D2, 0x092C7, 0x092F0, 0x092B2, 0x095AD, 0x095B1, 0x09704, 0x09706, 0x09707, 0x09709, 0x09760, 0x0978D,
+    0x0978B, 0x0978F, 0x09821, 0x0982B, 0x0981C, 0x098B3, 0x0990A, 0x09913, 0x09912, 0x09918, 0x099DD, 0x099D0,
+    0x099DF, 0x099DB, 0x099D1, 0x099D5, 0x099D2, 0x099D9, 0x09AB7, 0x09AEE, 0x09AEF, 0x09B27, 0x09B45, 0x09B44,
+    0x09B77, 0x09B6F, 0x09D06, 0x09D09, 0x09D03, 0x09EA9, 0x09EBE, 0x09ECE, 0x058A8, 0x09F52, 0x05112, 0x05118,
+    0x05114, 0x05110, 0x05115, 0x05180, 0x051AA, 0x051DD, 0x05291, 0x05293, 0x052F3, 0x05659, 0x0566B, 0x05679,
+    0x05669, 0x05664, 0x05678, 0x0566A, 0x05668, 0x05665, 0x05671, 0x0566F, 0x0566C, 0x05662, 0x05676, 0x058C1,
+    0x058BE, 0x058C7, 0x058C5, 0x0596E, 0x05B1D, 0x05B34, 0x05B78, 0x05BF0, 0x05C0E, 0x05F4A, 0x061B2, 0x06191,
+    0x061A9, 0x0618A, 0x061CD, 0x061B6, 0x061BE, 0x061CA, 0x061C8, 0x06230, 0x064C5, 0x064C1, 0x064CB, 0x064BB,
+    0x064BC, 0x064DA, 0x064C4, 0x064C7, 0x064C2, 0x064CD, 0x064BF, 0x064D2, 0x064D4, 0x064BE, 0x06574, 0x066C6,
+    0x066C9, 0x066B9, 0x066C4, 0x066C7, 0x066B8, 0x06A3D, 0x06A38, 0x06A3A, 0x06A59, 0x06A6B, 0x06A58, 0x06A39,
+    0x06A44, 0x06A62, 0x06A61, 0x06A4B, 0x06A47, 0x06A35, 0x06A5F, 0x06A48, 0x06B59, 0x06B77, 0x06C05, 0x06FC2,
+    0x06FB1, 0x06FA1, 0x06FC3, 0x06FA4, 0x06FC1, 0x06FA7, 0x06FB3, 0x06FC0, 0x06FB9, 0x06FB6, 0x06FA6, 0x06FA0,
+    0x06FB4, 0x071BE, 0x071C9, 0x071D0, 0x071D2, 0x071C8, 0x071D5, 0x071B9, 0x071CE, 0x071D9, 0x071DC, 0x071C3,
+    0x071C4, 0x07368, 0x0749C, 0x074A3, 0x07498, 0x0749F, 0x0749E, 0x074E2, 0x0750C, 0x0750D, 0x07634, 0x07638,
+    0x0763A, 0x076E7, 0x076E5, 0x077A0, 0x0779E, 0x0779F, 0x077A5, 0x078E8, 0x078DA, 0x078EC, 0x078E7, 0x079A6,
+    0x07A4D, 0x07A4E, 0x07A46, 0x07A4C, 0x07A4B, 0x07ABA, 0x07BD9, 0x07C11, 0x07BC9, 0x07BE4, 0x07BDB, 0x07BE1,
+    0x07BE9, 0x07BE6, 0x07CD5, 0x07CD6, 0x07E0A, 0x07E11, 0x07E08, 0x07E1B, 0x07E23, 0x07E1E, 0x07E1D, 0x07E09,
+    0x07E10, 0x07F79, 0x07FB2, 0x07FF0, 0x07FF1, 0x07FEE, 0x08028, 0x081B3, 0x081A9, 0x081A8, 0x081FB, 0x08208,
+    0x08258, 0x08259, 0x0854A, 0x08559, 0x08548, 0x08568, 0x08569, 0x08543, 0x08549, 0x0856D, 0x0856A, 0x0855E,
+    0x08783, 0x0879F, 0x0879E, 0x087A2, 0x0878D, 0x08861, 0x0892A, 0x08932, 0x08925, 0x0892B, 0x08921, 0x089AA,
+    0x089A6, 0x08AE6, 0x08AFA, 0x08AEB, 0x08AF1, 0x08B00, 0x08ADC, 0x08AE7, 0x08AEE, 0x08AFE, 0x08B01, 0x08B02,
+    0x08AF7, 0x08AED, 0x08AF3, 0x08AF6, 0x08AFC, 0x08C6B, 0x08C6D, 0x08C93, 0x08CF4, 0x08E44, 0x08E31, 0x08E34,
+    0x08E42, 0x08E39, 0x08E35, 0x08F3B, 0x08F2F, 0x08F38, 0x08F33, 0x08FA8, 0x08FA6, 0x09075, 0x09074, 0x09078,
+    0x09072, 0x0907C, 0x0907A, 0x09134, 0x09192, 0x09320, 0x09336, 0x092F8, 0x09333, 0x0932F, 0x09322, 0x092FC,
+    0x0932B, 0x09304, 0x0931A, 0x09310, 0x09326, 0x09321, 0x09315, 0x0932E, 0x09319, 0x095BB, 0x096A7, 0x096A8,
+    0x096AA, 0x096D5, 0x0970E, 0x09711, 0x09716, 0x0970D, 0x09713, 0x0970F, 0x0975B, 0x0975C, 0x09766, 0x09798,
+    0x09830, 0x09838, 0x0983B, 0x09837, 0x0982D, 0x09839, 0x09824, 0x09910, 0x09928, 0x0991E, 0x0991B, 0x09921,
+    0x0991A, 0x099ED, 0x099E2, 0x099F1, 0x09AB8, 0x09ABC, 0x09AFB, 0x09AED, 0x09B28, 0x09B91, 0x09D15, 0x09D23,
+    0x09D26, 0x09D28, 0x09D12, 0x09D1B, 0x09ED8, 0x09ED4, 0x09F8D, 0x09F9C, 0x0512A, 0x0511F, 0x05121, 0x05132,
+    0x052F5, 0x0568E, 0x05680, 0x05690, 0x05685, 0x05687, 0x0568F, 0x058D5, 0x058D3, 0x058D1, 0x058CE, 0x05B30,
+    0x05B2A, 0x05B24, 0x05B7A, 0x05C37, 0x05C68, 0x05DBC, 0x05DBA, 0x05DBD, 0x05DB8, 0x05E6B, 0x05F4C, 0x05FBD,
+    0x061C9, 0x061C2, 0x061C7, 0x061E6, 0x061CB, 0x06232, 0x06234, 0x064CE, 0x064CA, 0x064D8, 0x064E0, 0x064F0,
+    0x064E6, 0x064EC, 0x064F1, 0x064E2, 0x064ED, 0x06582, 0x06583, 0x066D9, 0x066D6, 0x06A80, 0x06A94, 0x06A84,
+    0x06AA2, 0x06A9C, 0x06ADB, 0x06AA3, 0x06A7E, 0x06A97, 0x06A90, 0x06AA0, 0x06B5C, 0x06BAE, 0x06BDA, 0x06C08,
+    0x06FD8, 0x06FF1, 0x06FDF, 0x06FE0, 0x06FDB, 0x06FE4, 0x06FEB, 0x06FEF, 0x06F80, 0x06FEC, 0x06FE1, 0x06FE9,
+    0x06FD5, 0x06FEE, 0x06FF0, 0x071E7, 0x071DF, 0x071EE, 0x071E6, 0x071E5, 0x071ED, 0x071EC, 0x071F4, 0x071E0,
+    0x07235, 0x07246, 0x07370, 0x07372, 0x074A9, 0x074B0, 0x074A6, 0x074A8, 0x07646, 0x07642, 0x0764C, 0x076EA,
+    0x077B3, 0x077AA, 0x077B0, 0x077AC, 0x077A7, 0x077AD, 0x077EF, 0x078F7, 0x078FA, 0x078F4, 0x078EF, 0x07901,
+    0x079A7, 0x079AA, 0x07A57, 0x07ABF, 0x07C07, 0x07C0D, 0x07BFE, 0x07BF7, 0x07C0C, 0x07BE0, 0x07CE0, 0x07CDC,
+    0x07CDE, 0x07CE2, 0x07CDF, 0x07CD9, 0x07CDD, 0x07E2E, 0x07E3E, 0x07E46, 0x07E37, 0x07E32, 0x07E43, 0x07E2B,
+    0x07E3D, 0x07E31, 0x07E45, 0x07E41, 0x07E34, 0x07E39, 0x07E48, 0x07E35, 0x07E3F, 0x07E2F, 0x07F44, 0x07FF3,
+    0x07FFC, 0x08071, 0x08072, 0x08070, 0x0806F, 0x08073, 0x081C6, 0x081C3, 0x081BA, 0x081C2, 0x081C0, 0x081BF,
+    0x081BD, 0x081C9, 0x081BE, 0x081E8, 0x08209, 0x08271, 0x085AA, 0x08584, 0x0857E, 0x0859C, 0x08591, 0x08594,
+    0x085AF, 0x0859B, 0x08587, 0x085A8, 0x0858A, 0x08667, 0x087C0, 0x087D1, 0x087B3, 0x087D2, 0x087C6, 0x087AB,
+    0x087BB, 0x087BA, 0x087C8, 0x087CB, 0x0893B, 0x08936, 0x08944, 0x08938, 0x0893D, 0x089AC, 0x08B0E, 0x08B17,
+    0x08B19, 0x08B1B, 0x08B0A, 0x08B20, 0x08B1D, 0x08B04, 0x08B10, 0x08C41, 0x08C3F, 0x08C73, 0x08CFA, 0x08CFD,
+    0x08CFC, 0x08CF8, 0x08CFB, 0x08DA8, 0x08E49, 0x08E4B, 0x08E48, 0x08E4A, 0x08F44, 0x08F3E, 0x08F42, 0x08F45,
+    0x08F3F, 0x0907F, 0x0907D, 0x09084, 0x09081, 0x09082, 0x09080, 0x09139, 0x091A3, 0x0919E, 0x0919C, 0x0934D,
+    0x09382, 0x09328, 0x09375, 0x0934A, 0x09365, 0x0934B, 0x09318, 0x0937E, 0x0936C, 0x0935B, 0x09370, 0x0935A,
+    0x09354, 0x095CA, 0x095CB, 0x095CC, 0x095C8, 0x095C6, 0x096B1, 0x096B8, 0x096D6, 0x0971C, 0x0971E, 0x097A0,
+    0x097D3, 0x09846, 0x098B6, 0x09935, 0x09A01, 0x099FF, 0x09BAE, 0x09BAB, 0x09BAA, 0x09BAD, 0x09D3B, 0x09D3F,
+    0x09E8B, 0x09ECF, 0x09EDE, 0x09EDC, 0x09EDD, 0x09EDB, 0x09F3E, 0x09F4B, 0x053E2, 0x05695, 0x056AE, 0x058D9,
+    0x058D8, 0x05B38, 0x05F5D, 0x061E3, 0x06233, 0x064F4, 0x064F2, 0x064FE, 0x06506, 0x064FA, 0x064FB, 0x064F7,
+    0x065B7, 0x066DC, 0x06726, 0x06AB3, 0x06AAC, 0x06AC3, 0x06ABB, 0x06AB8, 0x06AC2, 0x06AAE, 0x06AAF, 0x06B5F,
+    0x06B78, 0x06BAF, 0x07009, 0x0700B, 0x06FFE, 0x07006, 0x06FFA, 0x07011, 0x0700F, 0x071FB, 0x071FC, 0x071FE,
+    0x071F8, 0x07377, 0x07375, 0x074A7, 0x074BF, 0x07515, 0x07656, 0x07658, 0x07652, 0x077BD, 0x077BF, 0x077BB,
+    0x077BC, 0x0790E, 0x079AE, 0x07A61, 0x07A62, 0x07A60, 0x07AC4, 0x07AC5, 0x07C2B, 0x07C27, 0x07C2A, 0x07C1E,
+    0x07C23, 0x07C21, 0x07CE7, 0x07E54, 0x07E55, 0x07E5E, 0x07E5A, 0x07E61, 0x07E52, 0x07E59, 0x07F48, 0x07FF9,
+    0x07FFB, 0x08077, 0x08076, 0x081CD, 0x081CF, 0x0820A, 0x085CF, 0x085A9, 0x085CD, 0x085D0, 0x085C9, 0x085B0,
+    0x085BA, 0x085B9, 0x085A6, 0x087EF, 0x087EC, 0x087F2, 0x087E0, 0x08986, 0x089B2, 0x089F4, 0x08B28, 0x08B39,
+    0x08B2C, 0x08B2B, 0x08C50, 0x08D05, 0x08E59, 0x08E63, 0x08E66, 0x08E64, 0x08E5F, 0x08E55, 0x08EC0, 0x08F49,
+    0x08F4D, 0x09087, 0x09083, 0x09088, 0x091AB, 0x091AC, 0x091D0, 0x09394, 0x0938A, 0x09396, 0x093A2, 0x093B3,
+    0x093AE, 0x093AC, 0x093B0, 0x09398, 0x0939A, 0x09397, 0x095D4, 0x095D6, 0x095D0, 0x095D5, 0x096E2, 0x096DC,
+    0x096D9, 0x096DB, 0x096DE, 0x09724, 0x097A3, 0x097A6, 0x097AD, 0x097F9, 0x0984D, 0x0984F, 0x0984C, 0x0984E,
+    0x09853, 0x098BA, 0x0993E, 0x0993F, 0x0993D, 0x0992E, 0x099A5, 0x09A0E, 0x09AC1, 0x09B03, 0x09B06, 0x09B4F,
+    0x09B4E, 0x09B4D, 0x09BCA, 0x09BC9, 0x09BFD, 0x09BC8, 0x09BC0, 0x09D51, 0x09D5D, 0x09D60, 0x09EE0, 0x09F15,
+    0x09F2C, 0x05133, 0x056A5, 0x058DE, 0x058DF, 0x058E2, 0x05BF5, 0x09F90, 0x05EEC, 0x061F2, 0x061F7, 0x061F6,
+    0x061F5, 0x06500, 0x0650F, 0x066E0, 0x066DD, 0x06AE5, 0x06ADD, 0x06ADA, 0x06AD3, 0x0701B, 0x0701F, 0x07028,
+    0x0701A, 0x0701D, 0x07015, 0x07018, 0x07206, 0x0720D, 0x07258, 0x072A2, 0x07378, 0x0737A, 0x074BD, 0x074CA,
+    0x074E3, 0x07587, 0x07586, 0x0765F, 0x07661, 0x077C7, 0x07919, 0x079B1, 0x07A6B, 0x07A69, 0x07C3E, 0x07C3F,
+    0x07C38, 0x07C3D, 0x07C37, 0x07C40, 0x07E6B, 0x07E6D, 0x07E79, 0x07E69, 0x07E6A, 0x07F85, 0x07E73, 0x07FB6,
+    0x07FB9, 0x07FB8, 0x081D8, 0x085E9, 0x085DD, 0x085EA, 0x085D5, 0x085E4, 0x085E5, 0x085F7, 0x087FB, 0x08805,
+    0x0880D, 0x087F9, 0x087FE, 0x08960, 0x0895F, 0x08956, 0x0895E, 0x08B41, 0x08B5C, 0x08B58, 0x08B49, 0x08B5A,
+    0x08B4E, 0x08B4F, 0x08B46, 0x08B59, 0x08D08, 0x08D0A, 0x08E7C, 0x08E72, 0x08E87, 0x08E76, 0x08E6C, 0x08E7A,
+    0x08E74, 0x08F54, 0x08F4E, 0x08FAD, 0x0908A, 0x0908B, 0x091B1, 0x091AE, 0x093E1, 0x093D1, 0x093DF, 0x093C3,
+    0x093C8, 0x093DC, 0x093DD, 0x093D6, 0x093E2, 0x093CD, 0x093D8, 0x093E4, 0x093D7, 0x093E8, 0x095DC, 0x096B4,
+    0x096E3, 0x0972A, 0x09727, 0x09761, 0x097DC, 0x097FB, 0x0985E, 0x09858, 0x0985B, 0x098BC, 0x09945, 0x09949,
+    0x09A16, 0x09A19, 0x09B0D, 0x09BE8, 0x09BE7, 0x09BD6, 0x09BDB, 0x09D89, 0x09D61, 0x09D72, 0x09D6A, 0x09D6C,
+    0x09E92, 0x09E97, 0x09E93, 0x09EB4, 0x052F8, 0x056A8, 0x056B7, 0x056B6, 0x056B4, 0x056BC, 0x058E4, 0x05B40,
+    0x05B43, 0x05B7D, 0x05BF6, 0x05DC9, 0x061F8, 0x061FA, 0x06518, 0x06514, 0x06519, 0x066E6, 0x06727, 0x06AEC,
+    0x0703E, 0x07030, 0x07032, 0x07210, 0x0737B, 0x074CF, 0x07662, 0x07665, 0x07926, 0x0792A, 0x0792C, 0x0792B,
+    0x07AC7, 0x07AF6, 0x07C4C, 0x07C43, 0x07C4D, 0x07CEF, 0x07CF0, 0x08FAE, 0x07E7D, 0x07E7C, 0x07E82, 0x07F4C,
+    0x08000, 0x081DA, 0x08266, 0x085FB, 0x085F9, 0x08611, 0x085FA, 0x08606, 0x0860B, 0x08607, 0x0860A, 0x08814,
+    0x08815, 0x08964, 0x089BA, 0x089F8, 0x08B70, 0x08B6C, 0x08B66, 0x08B6F, 0x08B5F, 0x08B6B, 0x08D0F, 0x08D0D,
+    0x08E89, 0x08E81, 0x08E85, 0x08E82, 0x091B4, 0x091CB, 0x09418, 0x09403, 0x093FD, 0x095E1, 0x09730, 0x098C4,
+    0x09952, 0x09951, 0x099A8, 0x09A2B, 0x09A30, 0x09A37, 0x09A35, 0x09C13, 0x09C0D, 0x09E79, 0x09EB5, 0x09EE8,
+    0x09F2F, 0x09F5F, 0x09F63, 0x09F61, 0x05137, 0x05138, 0x056C1, 0x056C0, 0x056C2, 0x05914, 0x05C6C, 0x05DCD,
+    0x061FC, 0x061FE, 0x0651D, 0x0651C, 0x06595, 0x066E9, 0x06AFB, 0x06B04, 0x06AFA, 0x06BB2, 0x0704C, 0x0721B,
+    0x072A7, 0x074D6, 0x074D4, 0x07669, 0x077D3, 0x07C50, 0x07E8F, 0x07E8C, 0x07FBC, 0x08617, 0x0862D, 0x0861A,
+    0x08823, 0x08822, 0x08821, 0x0881F, 0x0896A, 0x0896C, 0x089BD, 0x08B74, 0x08B77, 0x08B7D, 0x08D13, 0x08E8A,
+    0x08E8D, 0x08E8B, 0x08F5F, 0x08FAF, 0x091BA, 0x0942E, 0x09433, 0x09435, 0x0943A, 0x09438, 0x09432, 0x0942B,
+    0x095E2, 0x09738, 0x09739, 0x09732, 0x097FF, 0x09867, 0x09865, 0x09957, 0x09A45, 0x09A43, 0x09A40, 0x09A3E,
+    0x09ACF, 0x09B54, 0x09B51, 0x09C2D, 0x09C25, 0x09DAF, 0x09DB4, 0x09DC2, 0x09DB8, 0x09E9D, 0x09EEF, 0x09F19,
+    0x09F5C, 0x09F66, 0x09F67, 0x0513C, 0x0513B, 0x056C8, 0x056CA, 0x056C9, 0x05B7F, 0x05DD4, 0x05DD2, 0x05F4E,
+    0x061FF, 0x06524, 0x06B0A, 0x06B61, 0x07051, 0x07058, 0x07380, 0x074E4, 0x0758A, 0x0766E, 0x0766C, 0x079B3,
+    0x07C60, 0x07C5F, 0x0807E, 0x0807D, 0x081DF, 0x08972, 0x0896F, 0x089FC, 0x08B80, 0x08D16, 0x08D17, 0x08E91,
+    0x08E93, 0x08F61, 0x09148, 0x09444, 0x09451, 0x09452, 0x0973D, 0x0973E, 0x097C3, 0x097C1, 0x0986B, 0x09955,
+    0x09A55, 0x09A4D, 0x09AD2, 0x09B1A, 0x09C49, 0x09C31, 0x09C3E, 0x09C3B, 0x09DD3, 0x09DD7, 0x09F34, 0x09F6C,
+    0x09F6A, 0x09F94, 0x056CC, 0x05DD6, 0x06200, 0x06523, 0x0652B, 0x0652A, 0x066EC, 0x06B10, 0x074DA, 0x07ACA,
+    0x07C64, 0x07C63, 0x07C65, 0x07E93, 0x07E96, 0x07E94, 0x081E2, 0x08638, 0x0863F, 0x08831, 0x08B8A, 0x09090,
+    0x0908F, 0x09463, 0x09460, 0x09464, 0x09768, 0x0986F, 0x0995C, 0x09A5A, 0x09A5B, 0x09A57, 0x09AD3, 0x09AD4,
+    0x09AD1, 0x09C54, 0x09C57, 0x09C56, 0x09DE5, 0x09E9F, 0x09EF4, 0x056D1, 0x058E9, 0x0652C, 0x0705E, 0x07671,
+    0x07672, 0x077D7, 0x07F50, 0x07F88, 0x08836, 0x08839, 0x08862, 0x08B93, 0x08B92, 0x08B96, 0x08277, 0x08D1B,
+    0x091C0, 0x0946A, 0x09742, 0x09748, 0x09744, 0x097C6, 0x09870, 0x09A5F, 0x09B22, 0x09B58, 0x09C5F, 0x09DF9,
+    0x09DFA, 0x09E7C, 0x09E7D, 0x09F07, 0x09F77, 0x09F72, 0x05EF3, 0x06B16, 0x07063, 0x07C6C, 0x07C6E, 0x0883B,
+    0x089C0, 0x08EA1, 0x091C1, 0x09472, 0x09470, 0x09871, 0x0995E, 0x09AD6, 0x09B23, 0x09ECC, 0x07064, 0x077DA,
+    0x08B9A, 0x09477, 0x097C9, 0x09A62, 0x09A65, 0x07E9C, 0x08B9C, 0x08EAA, 0x091C5, 0x0947D, 0x0947E, 0x0947C,
+    0x09C77, 0x09C78, 0x09EF7, 0x08C54, 0x0947F, 0x09E1A, 0x07228, 0x09A6A, 0x09B31, 0x09E1B, 0x09E1E, 0x07C72,
+    0x02460, 0x02461, 0x02462, 0x02463, 0x02464, 0x02465, 0x02466, 0x02467, 0x02468, 0x02469, 0x02474, 0x02475,
+    0x02476, 0x02477, 0x02478, 0x02479, 0x0247A, 0x0247B, 0x0247C, 0x0247D, 0x02170, 0x02171, 0x02172, 0x02173,
+    0x02174, 0x02175, 0x02176, 0x02177, 0x02178, 0x02179, 0x04E36, 0x04E3F, 0x04E85, 0x04EA0, 0x05182, 0x05196,
+    0x051AB, 0x052F9, 0x05338, 0x05369, 0x053B6, 0x0590A, 0x05B80, 0x05DDB, 0x02F33, 0x05E7F, 0x05EF4, 0x05F50,
+    0x05F61, 0x06534, 0x065E0, 0x07592, 0x07676, 0x08FB5, 0x096B6, 0x000A8, 0x002C6, 0x030FD, 0x030FE, 0x0309D,
+    0x0309E, 0x03003, 0x04EDD, 0x03005, 0x03006, 0x03007, 0x030FC, 0x0FF3B, 0x0FF3D, 0x0273D, 0x03041, 0x03042,
+    0x03043, 0x03044, 0x03045, 0x03046, 0x03047, 0x03048, 0x03049, 0x0304A, 0x0304B, 0x0304C, 0x0304D, 0x0304E,
+    0x0304F, 0x03050, 0x03051, 0x03052, 0x03053, 0x03054, 0x03055, 0x03056, 0x03057, 0x03058, 0x03059, 0x0305A,
+    0x0305B, 0x0305C, 0x0305D, 0x0305E, 0x0305F, 0x03060, 0x03061, 0x03062, 0x03063, 0x03064, 0x03065, 0x03066,
+    0x03067, 0x03068, 0x03069, 0x0306A, 0x0306B, 0x0306C, 0x0306D, 0x0306E, 0x0306F, 0x03070, 0x03071, 0x03072,
+    0x03073, 0x03074, 0x03075, 0x03076, 0x03077, 0x03078, 0x03079, 0x0307A, 0x0307B, 0x0307C, 0x0307D, 0x0307E,
+    0x0307F, 0x03080, 0x03081, 0x03082, 0x03083, 0x03084, 0x03085, 0x03086, 0x03087, 0x03088, 0x03089, 0x0308A,
+    0x0308B, 0x0308C, 0x0308D, 0x0308E, 0x0308F, 0x03090, 0x03091, 0x03092, 0x03093, 0x030A1, 0x030A2, 0x030A3,
+    0x030A4, 0x030A5, 0x030A6, 0x030A7, 0x030A8, 0x030A9, 0x030AA, 0x030AB, 0x030AC, 0x030AD, 0x030AE, 0x030AF,
+    0x030B0, 0x030B1, 0x030B2, 0x030B3, 0x030B4, 0x030B5, 0x030B6, 0x030B7, 0x030B8, 0x030B9, 0x030BA, 0x030BB,
+    0x030BC, 0x030BD, 0x030BE, 0x030BF, 0x030C0, 0x030C1, 0x030C2, 0x030C3, 0x030C4, 0x030C5, 0x030C6, 0x030C7,
+    0x030C8, 0x030C9, 0x030CA, 0x030CB, 0x030CC, 0x030CD, 0x030CE, 0x030CF, 0x030D0, 0x030D1, 0x030D2, 0x030D3,
+    0x030D4, 0x030D5, 0x030D6, 0x030D7, 0x030D8, 0x030D9, 0x030DA, 0x030DB, 0x030DC, 0x030DD, 0x030DE, 0x030DF,
+    0x030E0, 0x030E1, 0x030E2, 0x030E3, 0x030E4, 0x030E5, 0x030E6, 0x030E7, 0x030E8, 0x030E9, 0x030EA, 0x030EB,
+    0x030EC, 0x030ED, 0x030EE, 0x030EF, 0x030F0, 0x030F1, 0x030F2, 0x030F3, 0x030F4, 0x030F5, 0x030F6, 0x00410,
+    0x00411, 0x00412, 0x00413, 0x00414, 0x00415, 0x00401, 0x00416, 0x00417, 0x00418, 0x00419, 0x0041A, 0x0041B,
+    0x0041C, 0x0041D, 0x0041E, 0x0041F, 0x00420, 0x00421, 0x00422, 0x00423, 0x00424, 0x00425, 0x00426, 0x00427,
+    0x00428, 0x00429, 0x0042A, 0x0042B, 0x0042C, 0x0042D, 0x0042E, 0x0042F, 0x00430, 0x00431, 0x00432, 0x00433,
+    0x00434, 0x00435, 0x00451, 0x00436, 0x00437, 0x00438, 0x00439, 0x0043A, 0x0043B, 0x0043C, 0x0043D, 0x0043E,
+    0x0043F, 0x00440, 0x00441, 0x00442, 0x00443, 0x00444, 0x00445, 0x00446, 0x00447, 0x00448, 0x00449, 0x0044A,
+    0x0044B, 0x0044C, 0x0044D, 0x0044E, 0x0044F, 0x021E7, 0x021B8, 0x021B9, 0x031CF, 0x200CC, 0x04E5A, 0x2008A,
+    0x05202, 0x04491, 0x09FB0, 0x05188, 0x09FB1, 0x27607, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x0FFE2, 0x0FFE4,
+    0x0FF07, 0x0FF02, 0x03231, 0x02116, 0x02121, 0x0309B, 0x0309C, 0x02E80, 0x02E84, 0x02E86, 0x02E87, 0x02E88,
+    0x02E8A, 0x02E8C, 0x02E8D, 0x02E95, 0x02E9C, 0x02E9D, 0x02EA5, 0x02EA7, 0x02EAA, 0x02EAC, 0x02EAE, 0x02EB6,
+    0x02EBC, 0x02EBE, 0x02EC6, 0x02ECA, 0x02ECC, 0x02ECD, 0x02ECF, 0x02ED6, 0x02ED7, 0x02EDE, 0x02EE3, 0x00000,
+    0x00000, 0x00000, 0x00283, 0x00250, 0x0025B, 0x00254, 0x00275, 0x00153, 0x000F8, 0x0014B, 0x0028A, 0x0026A,
+    0x04E42, 0x04E5C, 0x051F5, 0x0531A, 0x05382, 0x04E07, 0x04E0C, 0x04E47, 0x04E8D, 0x056D7, 0x0FA0C, 0x05C6E,
+    0x05F73, 0x04E0F, 0x05187, 0x04E0E, 0x04E2E, 0x04E93, 0x04EC2, 0x04EC9, 0x04EC8, 0x05198, 0x052FC, 0x0536C,
+    0x053B9, 0x05720, 0x05903, 0x0592C, 0x05C10, 0x05DFF, 0x065E1, 0x06BB3, 0x06BCC, 0x06C14, 0x0723F, 0x04E31,
+    0x04E3C, 0x04EE8, 0x04EDC, 0x04EE9, 0x04EE1, 0x04EDD, 0x04EDA, 0x0520C, 0x0531C, 0x0534C, 0x05722, 0x05723,
+    0x05917, 0x0592F, 0x05B81, 0x05B84, 0x05C12, 0x05C3B, 0x05C74, 0x05C73, 0x05E04, 0x05E80, 0x05E82, 0x05FC9,
+    0x06209, 0x06250, 0x06C15, 0x06C36, 0x06C43, 0x06C3F, 0x06C3B, 0x072AE, 0x072B0, 0x0738A, 0x079B8, 0x0808A,
+    0x0961E, 0x04F0E, 0x04F18, 0x04F2C, 0x04EF5, 0x04F14, 0x04EF1, 0x04F00, 0x04EF7, 0x04F08, 0x04F1D, 0x04F02,
+    0x04F05, 0x04F22, 0x04F13, 0x04F04, 0x04EF4, 0x04F12, 0x051B1, 0x05213, 0x05209, 0x05210, 0x052A6, 0x05322,
+    0x0531F, 0x0534D, 0x0538A, 0x05407, 0x056E1, 0x056DF, 0x0572E, 0x0572A, 0x05734, 0x0593C, 0x05980, 0x0597C,
+    0x05985, 0x0597B, 0x0597E, 0x05977, 0x0597F, 0x05B56, 0x05C15, 0x05C25, 0x05C7C, 0x05C7A, 0x05C7B, 0x05C7E,
+    0x05DDF, 0x05E75, 0x05E84, 0x05F02, 0x05F1A, 0x05F74, 0x05FD5, 0x05FD4, 0x05FCF, 0x0625C, 0x0625E, 0x06264,
+    0x06261, 0x06266, 0x06262, 0x06259, 0x06260, 0x0625A, 0x06265, 0x065EF, 0x065EE, 0x0673E, 0x06739, 0x06738,
+    0x0673B, 0x0673A, 0x0673F, 0x0673C, 0x06733, 0x06C18, 0x06C46, 0x06C52, 0x06C5C, 0x06C4F, 0x06C4A, 0x06C54,
+    0x06C4B, 0x06C4C, 0x07071, 0x0725E, 0x072B4, 0x072B5, 0x0738E, 0x0752A, 0x0767F, 0x07A75, 0x07F51, 0x08278,
+    0x0827C, 0x08280, 0x0827D, 0x0827F, 0x0864D, 0x0897E, 0x09099, 0x09097, 0x09098, 0x0909B, 0x09094, 0x09622,
+    0x09624, 0x09620, 0x09623, 0x04F56, 0x04F3B, 0x04F62, 0x04F49, 0x04F53, 0x04F64, 0x04F3E, 0x04F67, 0x04F52,
+    0x04F5F, 0x04F41, 0x04F58, 0x04F2D, 0x04F33, 0x04F3F, 0x04F61, 0x0518F, 0x051B9, 0x0521C, 0x0521E, 0x05221,
+    0x052AD, 0x052AE, 0x05309, 0x05363, 0x05372, 0x0538E, 0x0538F, 0x05430, 0x05437, 0x0542A, 0x05454, 0x05445,
+    0x05419, 0x0541C, 0x05425, 0x05418, 0x0543D, 0x0544F, 0x05441, 0x05428, 0x05424, 0x05447, 0x056EE, 0x056E7,
+    0x056E5, 0x05741, 0x05745, 0x0574C, 0x05749, 0x0574B, 0x05752, 0x05906, 0x05940, 0x059A6, 0x05998, 0x059A0,
+    0x05997, 0x0598E, 0x059A2, 0x05990, 0x0598F, 0x059A7, 0x059A1, 0x05B8E, 0x05B92, 0x05C28, 0x05C2A, 0x05C8D,
+    0x05C8F, 0x05C88, 0x05C8B, 0x05C89, 0x05C92, 0x05C8A, 0x05C86, 0x05C93, 0x05C95, 0x05DE0, 0x05E0A, 0x05E0E,
+    0x05E8B, 0x05E89, 0x05E8C, 0x05E88, 0x05E8D, 0x05F05, 0x05F1D, 0x05F78, 0x05F76, 0x05FD2, 0x05FD1, 0x05FD0,
+    0x05FED, 0x05FE8, 0x05FEE, 0x05FF3, 0x05FE1, 0x05FE4, 0x05FE3, 0x05FFA, 0x05FEF, 0x05FF7, 0x05FFB, 0x06000,
+    0x05FF4, 0x0623A, 0x06283, 0x0628C, 0x0628E, 0x0628F, 0x06294, 0x06287, 0x06271, 0x0627B, 0x0627A, 0x06270,
+    0x06281, 0x06288, 0x06277, 0x0627D, 0x06272, 0x06274, 0x06537, 0x065F0, 0x065F4, 0x065F3, 0x065F2, 0x065F5,
+    0x06745, 0x06747, 0x06759, 0x06755, 0x0674C, 0x06748, 0x0675D, 0x0674D, 0x0675A, 0x0674B, 0x06BD0, 0x06C19,
+    0x06C1A, 0x06C78, 0x06C67, 0x06C6B, 0x06C84, 0x06C8B, 0x06C8F, 0x06C71, 0x06C6F, 0x06C69, 0x06C9A, 0x06C6D,
+    0x06C87, 0x06C95, 0x06C9C, 0x06C66, 0x06C73, 0x06C65, 0x06C7B, 0x06C8E, 0x07074, 0x0707A, 0x07263, 0x072BF,
+    0x072BD, 0x072C3, 0x072C6, 0x072C1, 0x072BA, 0x072C5, 0x07395, 0x07397, 0x07393, 0x07394, 0x07392, 0x0753A,
+    0x07539, 0x07594, 0x07595, 0x07681, 0x0793D, 0x08034, 0x08095, 0x08099, 0x08090, 0x08092, 0x0809C, 0x08290,
+    0x0828F, 0x08285, 0x0828E, 0x08291, 0x08293, 0x0828A, 0x08283, 0x08284, 0x08C78, 0x08FC9, 0x08FBF, 0x0909F,
+    0x090A1, 0x090A5, 0x0909E, 0x090A7, 0x090A0, 0x09630, 0x09628, 0x0962F, 0x0962D, 0x04E33, 0x04F98, 0x04F7C,
+    0x04F85, 0x04F7D, 0x04F80, 0x04F87, 0x04F76, 0x04F74, 0x04F89, 0x04F84, 0x04F77, 0x04F4C, 0x04F97, 0x04F6A,
+    0x04F9A, 0x04F79, 0x04F81, 0x04F78, 0x04F90, 0x04F9C, 0x04F94, 0x04F9E, 0x04F92, 0x04F82, 0x04F95, 0x04F6B,
+    0x04F6E, 0x0519E, 0x051BC, 0x051BE, 0x05235, 0x05232, 0x05233, 0x05246, 0x05231, 0x052BC, 0x0530A, 0x0530B,
+    0x0533C, 0x05392, 0x05394, 0x05487, 0x0547F, 0x05481, 0x05491, 0x05482, 0x05488, 0x0546B, 0x0547A, 0x0547E,
+    0x05465, 0x0546C, 0x05474, 0x05466, 0x0548D, 0x0546F, 0x05461, 0x05460, 0x05498, 0x05463, 0x05467, 0x05464,
+    0x056F7, 0x056F9, 0x0576F, 0x05772, 0x0576D, 0x0576B, 0x05771, 0x05770, 0x05776, 0x05780, 0x05775, 0x0577B,
+    0x05773, 0x05774, 0x05762, 0x05768, 0x0577D, 0x0590C, 0x05945, 0x059B5, 0x059BA, 0x059CF, 0x059CE, 0x059B2,
+    0x059CC, 0x059C1, 0x059B6, 0x059BC, 0x059C3, 0x059D6, 0x059B1, 0x059BD, 0x059C0, 0x059C8, 0x059B4, 0x059C7,
+    0x05B62, 0x05B65, 0x05B93, 0x05B95, 0x05C44, 0x05C47, 0x05CAE, 0x05CA4, 0x05CA0, 0x05CB5, 0x05CAF, 0x05CA8,
+    0x05CAC, 0x05C9F, 0x05CA3, 0x05CAD, 0x05CA2, 0x05CAA, 0x05CA7, 0x05C9D, 0x05CA5, 0x05CB6, 0x05CB0, 0x05CA6,
+    0x05E17, 0x05E14, 0x05E19, 0x05F28, 0x05F22, 0x05F23, 0x05F24, 0x05F54, 0x05F82, 0x05F7E, 0x05F7D, 0x05FDE,
+    0x05FE5, 0x0602D, 0x06026, 0x06019, 0x06032, 0x0600B, 0x06034, 0x0600A, 0x06017, 0x06033, 0x0601A, 0x0601E,
+    0x0602C, 0x06022, 0x0600D, 0x06010, 0x0602E, 0x06013, 0x06011, 0x0600C, 0x06009, 0x0601C, 0x06214, 0x0623D,
+    0x062AD, 0x062B4, 0x062D1, 0x062BE, 0x062AA, 0x062B6, 0x062CA, 0x062AE, 0x062B3, 0x062AF, 0x062BB, 0x062A9,
+    0x062B0, 0x062B8, 0x0653D, 0x065A8, 0x065BB, 0x06609, 0x065FC, 0x06604, 0x06612, 0x06608, 0x065FB, 0x06603,
+    0x0660B, 0x0660D, 0x06605, 0x065FD, 0x06611, 0x06610, 0x066F6, 0x0670A, 0x06785, 0x0676C, 0x0678E, 0x06792,
+    0x06776, 0x0677B, 0x06798, 0x06786, 0x06784, 0x06774, 0x0678D, 0x0678C, 0x0677A, 0x0679F, 0x06791, 0x06799,
+    0x06783, 0x0677D, 0x06781, 0x06778, 0x06779, 0x06794, 0x06B25, 0x06B80, 0x06B7E, 0x06BDE, 0x06C1D, 0x06C93,
+    0x06CEC, 0x06CEB, 0x06CEE, 0x06CD9, 0x06CB6, 0x06CD4, 0x06CAD, 0x06CE7, 0x06CB7, 0x06CD0, 0x06CC2, 0x06CBA,
+    0x06CC3, 0x06CC6, 0x06CED, 0x06CF2, 0x06CD2, 0x06CDD, 0x06CB4, 0x06C8A, 0x06C9D, 0x06C80, 0x06CDE, 0x06CC0,
+    0x06D30, 0x06CCD, 0x06CC7, 0x06CB0, 0x06CF9, 0x06CCF, 0x06CE9, 0x06CD1, 0x07094, 0x07098, 0x07085, 0x07093,
+    0x07086, 0x07084, 0x07091, 0x07096, 0x07082, 0x0709A, 0x07083, 0x0726A, 0x072D6, 0x072CB, 0x072D8, 0x072C9,
+    0x072DC, 0x072D2, 0x072D4, 0x072DA, 0x072CC, 0x072D1, 0x073A4, 0x073A1, 0x073AD, 0x073A6, 0x073A2, 0x073A0,
+    0x073AC, 0x0739D, 0x074DD, 0x074E8, 0x0753F, 0x07540, 0x0753E, 0x0758C, 0x07598, 0x076AF, 0x076F3, 0x076F1,
+    0x076F0, 0x076F5, 0x077F8, 0x077FC, 0x077F9, 0x077FB, 0x077FA, 0x077F7, 0x07942, 0x0793F, 0x079C5, 0x07A78,
+    0x07A7B, 0x07AFB, 0x07C75, 0x07CFD, 0x08035, 0x0808F, 0x080AE, 0x080A3, 0x080B8, 0x080B5, 0x080AD, 0x08220,
+    0x082A0, 0x082C0, 0x082AB, 0x0829A, 0x08298, 0x0829B, 0x082B5, 0x082A7, 0x082AE, 0x082BC, 0x0829E, 0x082BA,
+    0x082B4, 0x082A8, 0x082A1, 0x082A9, 0x082C2, 0x082A4, 0x082C3, 0x082B6, 0x082A2, 0x08670, 0x0866F, 0x0866D,
+    0x0866E, 0x08C56, 0x08FD2, 0x08FCB, 0x08FD3, 0x08FCD, 0x08FD6, 0x08FD5, 0x08FD7, 0x090B2, 0x090B4, 0x090AF,
+    0x090B3, 0x090B0, 0x09639, 0x0963D, 0x0963C, 0x0963A, 0x09643, 0x04FCD, 0x04FC5, 0x04FD3, 0x04FB2, 0x04FC9,
+    0x04FCB, 0x04FC1, 0x04FD4, 0x04FDC, 0x04FD9, 0x04FBB, 0x04FB3, 0x04FDB, 0x04FC7, 0x04FD6, 0x04FBA, 0x04FC0,
+    0x04FB9, 0x04FEC, 0x05244, 0x05249, 0x052C0, 0x052C2, 0x0533D, 0x0537C, 0x05397, 0x05396, 0x05399, 0x05398,
+    0x054BA, 0x054A1, 0x054AD, 0x054A5, 0x054CF, 0x054C3, 0x0830D, 0x054B7, 0x054AE, 0x054D6, 0x054B6, 0x054C5,
+    0x054C6, 0x054A0, 0x05470, 0x054BC, 0x054A2, 0x054BE, 0x05472, 0x054DE, 0x054B0, 0x057B5, 0x0579E, 0x0579F,
+    0x057A4, 0x0578C, 0x05797, 0x0579D, 0x0579B, 0x05794, 0x05798, 0x0578F, 0x05799, 0x057A5, 0x0579A, 0x05795,
+    0x058F4, 0x0590D, 0x05953, 0x059E1, 0x059DE, 0x059EE, 0x05A00, 0x059F1, 0x059DD, 0x059FA, 0x059FD, 0x059FC,
+    0x059F6, 0x059E4, 0x059F2, 0x059F7, 0x059DB, 0x059E9, 0x059F3, 0x059F5, 0x059E0, 0x059FE, 0x059F4, 0x059ED,
+    0x05BA8, 0x05C4C, 0x05CD0, 0x05CD8, 0x05CCC, 0x05CD7, 0x05CCB, 0x05CDB, 0x05CDE, 0x05CDA, 0x05CC9, 0x05CC7,
+    0x05CCA, 0x05CD6, 0x05CD3, 0x05CD4, 0x05CCF, 0x05CC8, 0x05CC6, 0x05CCE, 0x05CDF, 0x05CF8, 0x05DF9, 0x05E21,
+    0x05E22, 0x05E23, 0x05E20, 0x05E24, 0x05EB0, 0x05EA4, 0x05EA2, 0x05E9B, 0x05EA3, 0x05EA5, 0x05F07, 0x05F2E,
+    0x05F56, 0x05F86, 0x06037, 0x06039, 0x06054, 0x06072, 0x0605E, 0x06045, 0x06053, 0x06047, 0x06049, 0x0605B,
+    0x0604C, 0x06040, 0x06042, 0x0605F, 0x06024, 0x06044, 0x06058, 0x06066, 0x0606E, 0x06242, 0x06243, 0x062CF,
+    0x0630D, 0x0630B, 0x062F5, 0x0630E, 0x06303, 0x062EB, 0x062F9, 0x0630F, 0x0630C, 0x062F8, 0x062F6, 0x06300,
+    0x06313, 0x06314, 0x062FA, 0x06315, 0x062FB, 0x062F0, 0x06541, 0x06543, 0x065AA, 0x065BF, 0x06636, 0x06621,
+    0x06632, 0x06635, 0x0661C, 0x06626, 0x06622, 0x06633, 0x0662B, 0x0663A, 0x0661D, 0x06634, 0x06639, 0x0662E,
+    0x0670F, 0x06710, 0x067C1, 0x067F2, 0x067C8, 0x067BA, 0x067DC, 0x067BB, 0x067F8, 0x067D8, 0x067C0, 0x067B7,
+    0x067C5, 0x067EB, 0x067E4, 0x067DF, 0x067B5, 0x067CD, 0x067B3, 0x067F7, 0x067F6, 0x067EE, 0x067E3, 0x067C2,
+    0x067B9, 0x067CE, 0x067E7, 0x067F0, 0x067B2, 0x067FC, 0x067C6, 0x067ED, 0x067CC, 0x067AE, 0x067E6, 0x067DB,
+    0x067FA, 0x067C9, 0x067CA, 0x067C3, 0x067EA, 0x067CB, 0x06B28, 0x06B82, 0x06B84, 0x06BB6, 0x06BD6, 0x06BD8,
+    0x06BE0, 0x06C20, 0x06C21, 0x06D28, 0x06D34, 0x06D2D, 0x06D1F, 0x06D3C, 0x06D3F, 0x06D12, 0x06D0A, 0x06CDA,
+    0x06D33, 0x06D04, 0x06D19, 0x06D3A, 0x06D1A, 0x06D11, 0x06D00, 0x06D1D, 0x06D42, 0x06D01, 0x06D18, 0x06D37,
+    0x06D03, 0x06D0F, 0x06D40, 0x06D07, 0x06D20, 0x06D2C, 0x06D08, 0x06D22, 0x06D09, 0x06D10, 0x070B7, 0x0709F,
+    0x070BE, 0x070B1, 0x070B0, 0x070A1, 0x070B4, 0x070B5, 0x070A9, 0x07241, 0x07249, 0x0724A, 0x0726C, 0x07270,
+    0x07273, 0x0726E, 0x072CA, 0x072E4, 0x072E8, 0x072EB, 0x072DF, 0x072EA, 0x072E6, 0x072E3, 0x07385, 0x073CC,
+    0x073C2, 0x073C8, 0x073C5, 0x073B9, 0x073B6, 0x073B5, 0x073B4, 0x073EB, 0x073BF, 0x073C7, 0x073BE, 0x073C3,
+    0x073C6, 0x073B8, 0x073CB, 0x074EC, 0x074EE, 0x0752E, 0x07547, 0x07548, 0x075A7, 0x075AA, 0x07679, 0x076C4,
+    0x07708, 0x07703, 0x07704, 0x07705, 0x0770A, 0x076F7, 0x076FB, 0x076FA, 0x077E7, 0x077E8, 0x07806, 0x07811,
+    0x07812, 0x07805, 0x07810, 0x0780F, 0x0780E, 0x07809, 0x07803, 0x07813, 0x0794A, 0x0794C, 0x0794B, 0x07945,
+    0x07944, 0x079D5, 0x079CD, 0x079CF, 0x079D6, 0x079CE, 0x07A80, 0x07A7E, 0x07AD1, 0x07B00, 0x07B01, 0x07C7A,
+    0x07C78, 0x07C79, 0x07C7F, 0x07C80, 0x07C81, 0x07D03, 0x07D08, 0x07D01, 0x07F58, 0x07F91, 0x07F8D, 0x07FBE,
+    0x08007, 0x0800E, 0x0800F, 0x08014, 0x08037, 0x080D8, 0x080C7, 0x080E0, 0x080D1, 0x080C8, 0x080C2, 0x080D0,
+    0x080C5, 0x080E3, 0x080D9, 0x080DC, 0x080CA, 0x080D5, 0x080C9, 0x080CF, 0x080D7, 0x080E6, 0x080CD, 0x081FF,
+    0x08221, 0x08294, 0x082D9, 0x082FE, 0x082F9, 0x08307, 0x082E8, 0x08300, 0x082D5, 0x0833A, 0x082EB, 0x082D6,
+    0x082F4, 0x082EC, 0x082E1, 0x082F2, 0x082F5, 0x0830C, 0x082FB, 0x082F6, 0x082F0, 0x082EA, 0x082E4, 0x082E0,
+    0x082FA, 0x082F3, 0x082ED, 0x08677, 0x08674, 0x0867C, 0x08673, 0x08841, 0x0884E, 0x08867, 0x0886A, 0x08869,
+    0x089D3, 0x08A04, 0x08A07, 0x08D72, 0x08FE3, 0x08FE1, 0x08FEE, 0x08FE0, 0x090F1, 0x090BD, 0x090BF, 0x090D5,
+    0x090C5, 0x090BE, 0x090C7, 0x090CB, 0x090C8, 0x091D4, 0x091D3, 0x09654, 0x0964F, 0x09651, 0x09653, 0x0964A,
+    0x0964E, 0x0501E, 0x05005, 0x05007, 0x05013, 0x05022, 0x05030, 0x0501B, 0x04FF5, 0x04FF4, 0x05033, 0x05037,
+    0x0502C, 0x04FF6, 0x04FF7, 0x05017, 0x0501C, 0x05020, 0x05027, 0x05035, 0x0502F, 0x05031, 0x0500E, 0x0515A,
+    0x05194, 0x05193, 0x051CA, 0x051C4, 0x051C5, 0x051C8, 0x051CE, 0x05261, 0x0525A, 0x05252, 0x0525E, 0x0525F,
+    0x05255, 0x05262, 0x052CD, 0x0530E, 0x0539E, 0x05526, 0x054E2, 0x05517, 0x05512, 0x054E7, 0x054F3, 0x054E4,
+    0x0551A, 0x054FF, 0x05504, 0x05508, 0x054EB, 0x05511, 0x05505, 0x054F1, 0x0550A, 0x054FB, 0x054F7, 0x054F8,
+    0x054E0, 0x0550E, 0x05503, 0x0550B, 0x05701, 0x05702, 0x057CC, 0x05832, 0x057D5, 0x057D2, 0x057BA, 0x057C6,
+    0x057BD, 0x057BC, 0x057B8, 0x057B6, 0x057BF, 0x057C7, 0x057D0, 0x057B9, 0x057C1, 0x0590E, 0x0594A, 0x05A19,
+    0x05A16, 0x05A2D, 0x05A2E, 0x05A15, 0x05A0F, 0x05A17, 0x05A0A, 0x05A1E, 0x05A33, 0x05B6C, 0x05BA7, 0x05BAD,
+    0x05BAC, 0x05C03, 0x05C56, 0x05C54, 0x05CEC, 0x05CFF, 0x05CEE, 0x05CF1, 0x05CF7, 0x05D00, 0x05CF9, 0x05E29,
+    0x05E28, 0x05EA8, 0x05EAE, 0x05EAA, 0x05EAC, 0x05F33, 0x05F30, 0x05F67, 0x0605D, 0x0605A, 0x06067, 0x06041,
+    0x060A2, 0x06088, 0x06080, 0x06092, 0x06081, 0x0609D, 0x06083, 0x06095, 0x0609B, 0x06097, 0x06087, 0x0609C,
+    0x0608E, 0x06219, 0x06246, 0x062F2, 0x06310, 0x06356, 0x0632C, 0x06344, 0x06345, 0x06336, 0x06343, 0x063E4,
+    0x06339, 0x0634B, 0x0634A, 0x0633C, 0x06329, 0x06341, 0x06334, 0x06358, 0x06354, 0x06359, 0x0632D, 0x06347,
+    0x06333, 0x0635A, 0x06351, 0x06338, 0x06357, 0x06340, 0x06348, 0x0654A, 0x06546, 0x065C6, 0x065C3, 0x065C4,
+    0x065C2, 0x0664A, 0x0665F, 0x06647, 0x06651, 0x06712, 0x06713, 0x0681F, 0x0681A, 0x06849, 0x06832, 0x06833,
+    0x0683B, 0x0684B, 0x0684F, 0x06816, 0x06831, 0x0681C, 0x06835, 0x0682B, 0x0682D, 0x0682F, 0x0684E, 0x06844,
+    0x06834, 0x0681D, 0x06812, 0x06814, 0x06826, 0x06828, 0x0682E, 0x0684D, 0x0683A, 0x06825, 0x06820, 0x06B2C,
+    0x06B2F, 0x06B2D, 0x06B31, 0x06B34, 0x06B6D, 0x08082, 0x06B88, 0x06BE6, 0x06BE4, 0x06BE8, 0x06BE3, 0x06BE2,
+    0x06BE7, 0x06C25, 0x06D7A, 0x06D63, 0x06D64, 0x06D76, 0x06D0D, 0x06D61, 0x06D92, 0x06D58, 0x06D62, 0x06D6D,
+    0x06D6F, 0x06D91, 0x06D8D, 0x06DEF, 0x06D7F, 0x06D86, 0x06D5E, 0x06D67, 0x06D60, 0x06D97, 0x06D70, 0x06D7C,
+    0x06D5F, 0x06D82, 0x06D98, 0x06D2F, 0x06D68, 0x06D8B, 0x06D7E, 0x06D80, 0x06D84, 0x06D16, 0x06D83, 0x06D7B,
+    0x06D7D, 0x06D75, 0x06D90, 0x070DC, 0x070D3, 0x070D1, 0x070DD, 0x070CB, 0x07F39, 0x070E2, 0x070D7, 0x070D2,
+    0x070DE, 0x070E0, 0x070D4, 0x070CD, 0x070C5, 0x070C6, 0x070C7, 0x070DA, 0x070CE, 0x070E1, 0x07242, 0x07278,
+    0x07277, 0x07276, 0x07300, 0x072FA, 0x072F4, 0x072FE, 0x072F6, 0x072F3, 0x072FB, 0x07301, 0x073D3, 0x073D9,
+    0x073E5, 0x073D6, 0x073BC, 0x073E7, 0x073E3, 0x073E9, 0x073DC, 0x073D2, 0x073DB, 0x073D4, 0x073DD, 0x073DA,
+    0x073D7, 0x073D8, 0x073E8, 0x074DE, 0x074DF, 0x074F4, 0x074F5, 0x07521, 0x0755B, 0x0755F, 0x075B0, 0x075C1,
+    0x075BB, 0x075C4, 0x075C0, 0x075BF, 0x075B6, 0x075BA, 0x0768A, 0x076C9, 0x0771D, 0x0771B, 0x07710, 0x07713,
+    0x07712, 0x07723, 0x07711, 0x07715, 0x07719, 0x0771A, 0x07722, 0x07727, 0x07823, 0x0782C, 0x07822, 0x07835,
+    0x0782F, 0x07828, 0x0782E, 0x0782B, 0x07821, 0x07829, 0x07833, 0x0782A, 0x07831, 0x07954, 0x0795B, 0x0794F,
+    0x0795C, 0x07953, 0x07952, 0x07951, 0x079EB, 0x079EC, 0x079E0, 0x079EE, 0x079ED, 0x079EA, 0x079DC, 0x079DE,
+    0x079DD, 0x07A86, 0x07A89, 0x07A85, 0x07A8B, 0x07A8C, 0x07A8A, 0x07A87, 0x07AD8, 0x07B10, 0x07B04, 0x07B13,
+    0x07B05, 0x07B0F, 0x07B08, 0x07B0A, 0x07B0E, 0x07B09, 0x07B12, 0x07C84, 0x07C91, 0x07C8A, 0x07C8C, 0x07C88,
+    0x07C8D, 0x07C85, 0x07D1E, 0x07D1D, 0x07D11, 0x07D0E, 0x07D18, 0x07D16, 0x07D13, 0x07D1F, 0x07D12, 0x07D0F,
+    0x07D0C, 0x07F5C, 0x07F61, 0x07F5E, 0x07F60, 0x07F5D, 0x07F5B, 0x07F96, 0x07F92, 0x07FC3, 0x07FC2, 0x07FC0,
+    0x08016, 0x0803E, 0x08039, 0x080FA, 0x080F2, 0x080F9, 0x080F5, 0x08101, 0x080FB, 0x08100, 0x08201, 0x0822F,
+    0x08225, 0x08333, 0x0832D, 0x08344, 0x08319, 0x08351, 0x08325, 0x08356, 0x0833F, 0x08341, 0x08326, 0x0831C,
+    0x08322, 0x08342, 0x0834E, 0x0831B, 0x0832A, 0x08308, 0x0833C, 0x0834D, 0x08316, 0x08324, 0x08320, 0x08337,
+    0x0832F, 0x08329, 0x08347, 0x08345, 0x0834C, 0x08353, 0x0831E, 0x0832C, 0x0834B, 0x08327, 0x08348, 0x08653,
+    0x08652, 0x086A2, 0x086A8, 0x08696, 0x0868D, 0x08691, 0x0869E, 0x08687, 0x08697, 0x08686, 0x0868B, 0x0869A,
+    0x08685, 0x086A5, 0x08699, 0x086A1, 0x086A7, 0x08695, 0x08698, 0x0868E, 0x0869D, 0x08690, 0x08694, 0x08843,
+    0x08844, 0x0886D, 0x08875, 0x08876, 0x08872, 0x08880, 0x08871, 0x0887F, 0x0886F, 0x08883, 0x0887E, 0x08874,
+    0x0887C, 0x08A12, 0x08C47, 0x08C57, 0x08C7B, 0x08CA4, 0x08CA3, 0x08D76, 0x08D78, 0x08DB5, 0x08DB7, 0x08DB6,
+    0x08ED1, 0x08ED3, 0x08FFE, 0x08FF5, 0x09002, 0x08FFF, 0x08FFB, 0x09004, 0x08FFC, 0x08FF6, 0x090D6, 0x090E0,
+    0x090D9, 0x090DA, 0x090E3, 0x090DF, 0x090E5, 0x090D8, 0x090DB, 0x090D7, 0x090DC, 0x090E4, 0x09150, 0x0914E,
+    0x0914F, 0x091D5, 0x091E2, 0x091DA, 0x0965C, 0x0965F, 0x096BC, 0x098E3, 0x09ADF, 0x09B2F, 0x04E7F, 0x05070,
+    0x0506A, 0x05061, 0x0505E, 0x05060, 0x05053, 0x0504B, 0x0505D, 0x05072, 0x05048, 0x0504D, 0x05041, 0x0505B,
+    0x0504A, 0x05062, 0x05015, 0x05045, 0x0505F, 0x05069, 0x0506B, 0x05063, 0x05064, 0x05046, 0x05040, 0x0506E,
+    0x05073, 0x05057, 0x05051, 0x051D0, 0x0526B, 0x0526D, 0x0526C, 0x0526E, 0x052D6, 0x052D3, 0x0532D, 0x0539C,
+    0x05575, 0x05576, 0x0553C, 0x0554D, 0x05550, 0x05534, 0x0552A, 0x05551, 0x05562, 0x05536, 0x05535, 0x05530,
+    0x05552, 0x05545, 0x0550C, 0x05532, 0x05565, 0x0554E, 0x05539, 0x05548, 0x0552D, 0x0553B, 0x05540, 0x0554B,
+    0x0570A, 0x05707, 0x057FB, 0x05814, 0x057E2, 0x057F6, 0x057DC, 0x057F4, 0x05800, 0x057ED, 0x057FD, 0x05808,
+    0x057F8, 0x0580B, 0x057F3, 0x057CF, 0x05807, 0x057EE, 0x057E3, 0x057F2, 0x057E5, 0x057EC, 0x057E1, 0x0580E,
+    0x057FC, 0x05810, 0x057E7, 0x05801, 0x0580C, 0x057F1, 0x057E9, 0x057F0, 0x0580D, 0x05804, 0x0595C, 0x05A60,
+    0x05A58, 0x05A55, 0x05A67, 0x05A5E, 0x05A38, 0x05A35, 0x05A6D, 0x05A50, 0x05A5F, 0x05A65, 0x05A6C, 0x05A53,
+    0x05A64, 0x05A57, 0x05A43, 0x05A5D, 0x05A52, 0x05A44, 0x05A5B, 0x05A48, 0x05A8E, 0x05A3E, 0x05A4D, 0x05A39,
+    0x05A4C, 0x05A70, 0x05A69, 0x05A47, 0x05A51, 0x05A56, 0x05A42, 0x05A5C, 0x05B72, 0x05B6E, 0x05BC1, 0x05BC0,
+    0x05C59, 0x05D1E, 0x05D0B, 0x05D1D, 0x05D1A, 0x05D20, 0x05D0C, 0x05D28, 0x05D0D, 0x05D26, 0x05D25, 0x05D0F,
+    0x05D30, 0x05D12, 0x05D23, 0x05D1F, 0x05D2E, 0x05E3E, 0x05E34, 0x05EB1, 0x05EB4, 0x05EB9, 0x05EB2, 0x05EB3,
+    0x05F36, 0x05F38, 0x05F9B, 0x05F96, 0x05F9F, 0x0608A, 0x06090, 0x06086, 0x060BE, 0x060B0, 0x060BA, 0x060D3,
+    0x060D4, 0x060CF, 0x060E4, 0x060D9, 0x060DD, 0x060C8, 0x060B1, 0x060DB, 0x060B7, 0x060CA, 0x060BF, 0x060C3,
+    0x060CD, 0x060C0, 0x06332, 0x06365, 0x0638A, 0x06382, 0x0637D, 0x063BD, 0x0639E, 0x063AD, 0x0639D, 0x06397,
+    0x063AB, 0x0638E, 0x0636F, 0x06387, 0x06390, 0x0636E, 0x063AF, 0x06375, 0x0639C, 0x0636D, 0x063AE, 0x0637C,
+    0x063A4, 0x0633B, 0x0639F, 0x06378, 0x06385, 0x06381, 0x06391, 0x0638D, 0x06370, 0x06553, 0x065CD, 0x06665,
+    0x06661, 0x0665B, 0x06659, 0x0665C, 0x06662, 0x06718, 0x06879, 0x06887, 0x06890, 0x0689C, 0x0686D, 0x0686E,
+    0x068AE, 0x068AB, 0x06956, 0x0686F, 0x068A3, 0x068AC, 0x068A9, 0x06875, 0x06874, 0x068B2, 0x0688F, 0x06877,
+    0x06892, 0x0687C, 0x0686B, 0x06872, 0x068AA, 0x06880, 0x06871, 0x0687E, 0x0689B, 0x06896, 0x0688B, 0x068A0,
+    0x06889, 0x068A4, 0x06878, 0x0687B, 0x06891, 0x0688C, 0x0688A, 0x0687D, 0x06B36, 0x06B33, 0x06B37, 0x06B38,
+    0x06B91, 0x06B8F, 0x06B8D, 0x06B8E, 0x06B8C, 0x06C2A, 0x06DC0, 0x06DAB, 0x06DB4, 0x06DB3, 0x06E74, 0x06DAC,
+    0x06DE9, 0x06DE2, 0x06DB7, 0x06DF6, 0x06DD4, 0x06E00, 0x06DC8, 0x06DE0, 0x06DDF, 0x06DD6, 0x06DBE, 0x06DE5,
+    0x06DDC, 0x06DDD, 0x06DDB, 0x06DF4, 0x06DCA, 0x06DBD, 0x06DED, 0x06DF0, 0x06DBA, 0x06DD5, 0x06DC2, 0x06DCF,
+    0x06DC9, 0x06DD0, 0x06DF2, 0x06DD3, 0x06DFD, 0x06DD7, 0x06DCD, 0x06DE3, 0x06DBB, 0x070FA, 0x0710D, 0x070F7,
+    0x07117, 0x070F4, 0x0710C, 0x070F0, 0x07104, 0x070F3, 0x07110, 0x070FC, 0x070FF, 0x07106, 0x07113, 0x07100,
+    0x070F8, 0x070F6, 0x0710B, 0x07102, 0x0710E, 0x0727E, 0x0727B, 0x0727C, 0x0727F, 0x0731D, 0x07317, 0x07307,
+    0x07311, 0x07318, 0x0730A, 0x07308, 0x072FF, 0x0730F, 0x0731E, 0x07388, 0x073F6, 0x073F8, 0x073F5, 0x07404,
+    0x07401, 0x073FD, 0x07407, 0x07400, 0x073FA, 0x073FC, 0x073FF, 0x0740C, 0x0740B, 0x073F4, 0x07408, 0x07564,
+    0x07563, 0x075CE, 0x075D2, 0x075CF, 0x075CB, 0x075CC, 0x075D1, 0x075D0, 0x0768F, 0x07689, 0x076D3, 0x07739,
+    0x0772F, 0x0772D, 0x07731, 0x07732, 0x07734, 0x07733, 0x0773D, 0x07725, 0x0773B, 0x07735, 0x07848, 0x07852,
+    0x07849, 0x0784D, 0x0784A, 0x0784C, 0x07826, 0x07845, 0x07850, 0x07964, 0x07967, 0x07969, 0x0796A, 0x07963,
+    0x0796B, 0x07961, 0x079BB, 0x079FA, 0x079F8, 0x079F6, 0x079F7, 0x07A8F, 0x07A94, 0x07A90, 0x07B35, 0x07B47,
+    0x07B34, 0x07B25, 0x07B30, 0x07B22, 0x07B24, 0x07B33, 0x07B18, 0x07B2A, 0x07B1D, 0x07B31, 0x07B2B, 0x07B2D,
+    0x07B2F, 0x07B32, 0x07B38, 0x07B1A, 0x07B23, 0x07C94, 0x07C98, 0x07C96, 0x07CA3, 0x07D35, 0x07D3D, 0x07D38,
+    0x07D36, 0x07D3A, 0x07D45, 0x07D2C, 0x07D29, 0x07D41, 0x07D47, 0x07D3E, 0x07D3F, 0x07D4A, 0x07D3B, 0x07D28,
+    0x07F63, 0x07F95, 0x07F9C, 0x07F9D, 0x07F9B, 0x07FCA, 0x07FCB, 0x07FCD, 0x07FD0, 0x07FD1, 0x07FC7, 0x07FCF,
+    0x07FC9, 0x0801F, 0x0801E, 0x0801B, 0x08047, 0x08043, 0x08048, 0x08118, 0x08125, 0x08119, 0x0811B, 0x0812D,
+    0x0811F, 0x0812C, 0x0811E, 0x08121, 0x08115, 0x08127, 0x0811D, 0x08122, 0x08211, 0x08238, 0x08233, 0x0823A,
+    0x08234, 0x08232, 0x08274, 0x08390, 0x083A3, 0x083A8, 0x0838D, 0x0837A, 0x08373, 0x083A4, 0x08374, 0x0838F,
+    0x08381, 0x08395, 0x08399, 0x08375, 0x08394, 0x083A9, 0x0837D, 0x08383, 0x0838C, 0x0839D, 0x0839B, 0x083AA,
+    0x0838B, 0x0837E, 0x083A5, 0x083AF, 0x08388, 0x08397, 0x083B0, 0x0837F, 0x083A6, 0x08387, 0x083AE, 0x08376,
+    0x0839A, 0x08659, 0x08656, 0x086BF, 0x086B7, 0x086C2, 0x086C1, 0x086C5, 0x086BA, 0x086B0, 0x086C8, 0x086B9,
+    0x086B3, 0x086B8, 0x086CC, 0x086B4, 0x086BB, 0x086BC, 0x086C3, 0x086BD, 0x086BE, 0x08852, 0x08889, 0x08895,
+    0x088A8, 0x088A2, 0x088AA, 0x0889A, 0x08891, 0x088A1, 0x0889F, 0x08898, 0x088A7, 0x08899, 0x0889B, 0x08897,
+    0x088A4, 0x088AC, 0x0888C, 0x08893, 0x0888E, 0x08982, 0x089D6, 0x089D9, 0x089D5, 0x08A30, 0x08A27, 0x08A2C,
+    0x08A1E, 0x08C39, 0x08C3B, 0x08C5C, 0x08C5D, 0x08C7D, 0x08CA5, 0x08D7D, 0x08D7B, 0x08D79, 0x08DBC, 0x08DC2,
+    0x08DB9, 0x08DBF, 0x08DC1, 0x08ED8, 0x08EDE, 0x08EDD, 0x08EDC, 0x08ED7, 0x08EE0, 0x08EE1, 0x09024, 0x0900B,
+    0x09011, 0x0901C, 0x0900C, 0x09021, 0x090EF, 0x090EA, 0x090F0, 0x090F4, 0x090F2, 0x090F3, 0x090D4, 0x090EB,
+    0x090EC, 0x090E9, 0x09156, 0x09158, 0x0915A, 0x09153, 0x09155, 0x091EC, 0x091F4, 0x091F1, 0x091F3, 0x091F8,
+    0x091E4, 0x091F9, 0x091EA, 0x091EB, 0x091F7, 0x091E8, 0x091EE, 0x0957A, 0x09586, 0x09588, 0x0967C, 0x0966D,
+    0x0966B, 0x09671, 0x0966F, 0x096BF, 0x0976A, 0x09804, 0x098E5, 0x09997, 0x0509B, 0x05095, 0x05094, 0x0509E,
+    0x0508B, 0x050A3, 0x05083, 0x0508C, 0x0508E, 0x0509D, 0x05068, 0x0509C, 0x05092, 0x05082, 0x05087, 0x0515F,
+    0x051D4, 0x05312, 0x05311, 0x053A4, 0x053A7, 0x05591, 0x055A8, 0x055A5, 0x055AD, 0x05577, 0x05645, 0x055A2,
+    0x05593, 0x05588, 0x0558F, 0x055B5, 0x05581, 0x055A3, 0x05592, 0x055A4, 0x0557D, 0x0558C, 0x055A6, 0x0557F,
+    0x05595, 0x055A1, 0x0558E, 0x0570C, 0x05829, 0x05837, 0x05819, 0x0581E, 0x05827, 0x05823, 0x05828, 0x057F5,
+    0x05848, 0x05825, 0x0581C, 0x0581B, 0x05833, 0x0583F, 0x05836, 0x0582E, 0x05839, 0x05838, 0x0582D, 0x0582C,
+    0x0583B, 0x05961, 0x05AAF, 0x05A94, 0x05A9F, 0x05A7A, 0x05AA2, 0x05A9E, 0x05A78, 0x05AA6, 0x05A7C, 0x05AA5,
+    0x05AAC, 0x05A95, 0x05AAE, 0x05A37, 0x05A84, 0x05A8A, 0x05A97, 0x05A83, 0x05A8B, 0x05AA9, 0x05A7B, 0x05A7D,
+    0x05A8C, 0x05A9C, 0x05A8F, 0x05A93, 0x05A9D, 0x05BEA, 0x05BCD, 0x05BCB, 0x05BD4, 0x05BD1, 0x05BCA, 0x05BCE,
+    0x05C0C, 0x05C30, 0x05D37, 0x05D43, 0x05D6B, 0x05D41, 0x05D4B, 0x05D3F, 0x05D35, 0x05D51, 0x05D4E, 0x05D55,
+    0x05D33, 0x05D3A, 0x05D52, 0x05D3D, 0x05D31, 0x05D59, 0x05D42, 0x05D39, 0x05D49, 0x05D38, 0x05D3C, 0x05D32,
+    0x05D36, 0x05D40, 0x05D45, 0x05E44, 0x05E41, 0x05F58, 0x05FA6, 0x05FA5, 0x05FAB, 0x060C9, 0x060B9, 0x060CC,
+    0x060E2, 0x060CE, 0x060C4, 0x06114, 0x060F2, 0x0610A, 0x06116, 0x06105, 0x060F5, 0x06113, 0x060F8, 0x060FC,
+    0x060FE, 0x060C1, 0x06103, 0x06118, 0x0611D, 0x06110, 0x060FF, 0x06104, 0x0610B, 0x0624A, 0x06394, 0x063B1,
+    0x063B0, 0x063CE, 0x063E5, 0x063E8, 0x063EF, 0x063C3, 0x0649D, 0x063F3, 0x063CA, 0x063E0, 0x063F6, 0x063D5,
+    0x063F2, 0x063F5, 0x06461, 0x063DF, 0x063BE, 0x063DD, 0x063DC, 0x063C4, 0x063D8, 0x063D3, 0x063C2, 0x063C7,
+    0x063CC, 0x063CB, 0x063C8, 0x063F0, 0x063D7, 0x063D9, 0x06532, 0x06567, 0x0656A, 0x06564, 0x0655C, 0x06568,
+    0x06565, 0x0658C, 0x0659D, 0x0659E, 0x065AE, 0x065D0, 0x065D2, 0x0667C, 0x0666C, 0x0667B, 0x06680, 0x06671,
+    0x06679, 0x0666A, 0x06672, 0x06701, 0x0690C, 0x068D3, 0x06904, 0x068DC, 0x0692A, 0x068EC, 0x068EA, 0x068F1,
+    0x0690F, 0x068D6, 0x068F7, 0x068EB, 0x068E4, 0x068F6, 0x06913, 0x06910, 0x068F3, 0x068E1, 0x06907, 0x068CC,
+    0x06908, 0x06970, 0x068B4, 0x06911, 0x068EF, 0x068C6, 0x06914, 0x068F8, 0x068D0, 0x068FD, 0x068FC, 0x068E8,
+    0x0690B, 0x0690A, 0x06917, 0x068CE, 0x068C8, 0x068DD, 0x068DE, 0x068E6, 0x068F4, 0x068D1, 0x06906, 0x068D4,
+    0x068E9, 0x06915, 0x06925, 0x068C7, 0x06B39, 0x06B3B, 0x06B3F, 0x06B3C, 0x06B94, 0x06B97, 0x06B99, 0x06B95,
+    0x06BBD, 0x06BF0, 0x06BF2, 0x06BF3, 0x06C30, 0x06DFC, 0x06E46, 0x06E47, 0x06E1F, 0x06E49, 0x06E88, 0x06E3C,
+    0x06E3D, 0x06E45, 0x06E62, 0x06E2B, 0x06E3F, 0x06E41, 0x06E5D, 0x06E73, 0x06E1C, 0x06E33, 0x06E4B, 0x06E40,
+    0x06E51, 0x06E3B, 0x06E03, 0x06E2E, 0x06E5E, 0x06E68, 0x06E5C, 0x06E61, 0x06E31, 0x06E28, 0x06E60, 0x06E71,
+    0x06E6B, 0x06E39, 0x06E22, 0x06E30, 0x06E53, 0x06E65, 0x06E27, 0x06E78, 0x06E64, 0x06E77, 0x06E55, 0x06E79,
+    0x06E52, 0x06E66, 0x06E35, 0x06E36, 0x06E5A, 0x07120, 0x0711E, 0x0712F, 0x070FB, 0x0712E, 0x07131, 0x07123,
+    0x07125, 0x07122, 0x07132, 0x0711F, 0x07128, 0x0713A, 0x0711B, 0x0724B, 0x0725A, 0x07288, 0x07289, 0x07286,
+    0x07285, 0x0728B, 0x07312, 0x0730B, 0x07330, 0x07322, 0x07331, 0x07333, 0x07327, 0x07332, 0x0732D, 0x07326,
+    0x07323, 0x07335, 0x0730C, 0x0742E, 0x0742C, 0x07430, 0x0742B, 0x07416, 0x0741A, 0x07421, 0x0742D, 0x07431,
+    0x07424, 0x07423, 0x0741D, 0x07429, 0x07420, 0x07432, 0x074FB, 0x0752F, 0x0756F, 0x0756C, 0x075E7, 0x075DA,
+    0x075E1, 0x075E6, 0x075DD, 0x075DF, 0x075E4, 0x075D7, 0x07695, 0x07692, 0x076DA, 0x07746, 0x07747, 0x07744,
+    0x0774D, 0x07745, 0x0774A, 0x0774E, 0x0774B, 0x0774C, 0x077DE, 0x077EC, 0x07860, 0x07864, 0x07865, 0x0785C,
+    0x0786D, 0x07871, 0x0786A, 0x0786E, 0x07870, 0x07869, 0x07868, 0x0785E, 0x07862, 0x07974, 0x07973, 0x07972,
+    0x07970, 0x07A02, 0x07A0A, 0x07A03, 0x07A0C, 0x07A04, 0x07A99, 0x07AE6, 0x07AE4, 0x07B4A, 0x07B3B, 0x07B44,
+    0x07B48, 0x07B4C, 0x07B4E, 0x07B40, 0x07B58, 0x07B45, 0x07CA2, 0x07C9E, 0x07CA8, 0x07CA1, 0x07D58, 0x07D6F,
+    0x07D63, 0x07D53, 0x07D56, 0x07D67, 0x07D6A, 0x07D4F, 0x07D6D, 0x07D5C, 0x07D6B, 0x07D52, 0x07D54, 0x07D69,
+    0x07D51, 0x07D5F, 0x07D4E, 0x07F3E, 0x07F3F, 0x07F65, 0x07F66, 0x07FA2, 0x07FA0, 0x07FA1, 0x07FD7, 0x08051,
+    0x0804F, 0x08050, 0x080FE, 0x080D4, 0x08143, 0x0814A, 0x08152, 0x0814F, 0x08147, 0x0813D, 0x0814D, 0x0813A,
+    0x081E6, 0x081EE, 0x081F7, 0x081F8, 0x081F9, 0x08204, 0x0823C, 0x0823D, 0x0823F, 0x08275, 0x0833B, 0x083CF,
+    0x083F9, 0x08423, 0x083C0, 0x083E8, 0x08412, 0x083E7, 0x083E4, 0x083FC, 0x083F6, 0x08410, 0x083C6, 0x083C8,
+    0x083EB, 0x083E3, 0x083BF, 0x08401, 0x083DD, 0x083E5, 0x083D8, 0x083FF, 0x083E1, 0x083CB, 0x083CE, 0x083D6,
+    0x083F5, 0x083C9, 0x08409, 0x0840F, 0x083DE, 0x08411, 0x08406, 0x083C2, 0x083F3, 0x083D5, 0x083FA, 0x083C7,
+    0x083D1, 0x083EA, 0x08413, 0x083C3, 0x083EC, 0x083EE, 0x083C4, 0x083FB, 0x083D7, 0x083E2, 0x0841B, 0x083DB,
+    0x083FE, 0x086D8, 0x086E2, 0x086E6, 0x086D3, 0x086E3, 0x086DA, 0x086EA, 0x086DD, 0x086EB, 0x086DC, 0x086EC,
+    0x086E9, 0x086D7, 0x086E8, 0x086D1, 0x08848, 0x08856, 0x08855, 0x088BA, 0x088D7, 0x088B9, 0x088B8, 0x088C0,
+    0x088BE, 0x088B6, 0x088BC, 0x088B7, 0x088BD, 0x088B2, 0x08901, 0x088C9, 0x08995, 0x08998, 0x08997, 0x089DD,
+    0x089DA, 0x089DB, 0x08A4E, 0x08A4D, 0x08A39, 0x08A59, 0x08A40, 0x08A57, 0x08A58, 0x08A44, 0x08A45, 0x08A52,
+    0x08A48, 0x08A51, 0x08A4A, 0x08A4C, 0x08A4F, 0x08C5F, 0x08C81, 0x08C80, 0x08CBA, 0x08CBE, 0x08CB0, 0x08CB9,
+    0x08CB5, 0x08D84, 0x08D80, 0x08D89, 0x08DD8, 0x08DD3, 0x08DCD, 0x08DC7, 0x08DD6, 0x08DDC, 0x08DCF, 0x08DD5,
+    0x08DD9, 0x08DC8, 0x08DD7, 0x08DC5, 0x08EEF, 0x08EF7, 0x08EFA, 0x08EF9, 0x08EE6, 0x08EEE, 0x08EE5, 0x08EF5,
+    0x08EE7, 0x08EE8, 0x08EF6, 0x08EEB, 0x08EF1, 0x08EEC, 0x08EF4, 0x08EE9, 0x0902D, 0x09034, 0x0902F, 0x09106,
+    0x0912C, 0x09104, 0x090FF, 0x090FC, 0x09108, 0x090F9, 0x090FB, 0x09101, 0x09100, 0x09107, 0x09105, 0x09103,
+    0x09161, 0x09164, 0x0915F, 0x09162, 0x09160, 0x09201, 0x0920A, 0x09225, 0x09203, 0x0921A, 0x09226, 0x0920F,
+    0x0920C, 0x09200, 0x09212, 0x091FF, 0x091FD, 0x09206, 0x09204, 0x09227, 0x09202, 0x0921C, 0x09224, 0x09219,
+    0x09217, 0x09205, 0x09216, 0x0957B, 0x0958D, 0x0958C, 0x09590, 0x09687, 0x0967E, 0x09688, 0x09689, 0x09683,
+    0x09680, 0x096C2, 0x096C8, 0x096C3, 0x096F1, 0x096F0, 0x0976C, 0x09770, 0x0976E, 0x09807, 0x098A9, 0x098EB,
+    0x09CE6, 0x09EF9, 0x04E83, 0x04E84, 0x04EB6, 0x050BD, 0x050BF, 0x050C6, 0x050AE, 0x050C4, 0x050CA, 0x050B4,
+    0x050C8, 0x050C2, 0x050B0, 0x050C1, 0x050BA, 0x050B1, 0x050CB, 0x050C9, 0x050B6, 0x050B8, 0x051D7, 0x0527A,
+    0x05278, 0x0527B, 0x0527C, 0x055C3, 0x055DB, 0x055CC, 0x055D0, 0x055CB, 0x055CA, 0x055DD, 0x055C0, 0x055D4,
+    0x055C4, 0x055E9, 0x055BF, 0x055D2, 0x0558D, 0x055CF, 0x055D5, 0x055E2, 0x055D6, 0x055C8, 0x055F2, 0x055CD,
+    0x055D9, 0x055C2, 0x05714, 0x05853, 0x05868, 0x05864, 0x0584F, 0x0584D, 0x05849, 0x0586F, 0x05855, 0x0584E,
+    0x0585D, 0x05859, 0x05865, 0x0585B, 0x0583D, 0x05863, 0x05871, 0x058FC, 0x05AC7, 0x05AC4, 0x05ACB, 0x05ABA,
+    0x05AB8, 0x05AB1, 0x05AB5, 0x05AB0, 0x05ABF, 0x05AC8, 0x05ABB, 0x05AC6, 0x05AB7, 0x05AC0, 0x05ACA, 0x05AB4,
+    0x05AB6, 0x05ACD, 0x05AB9, 0x05A90, 0x05BD6, 0x05BD8, 0x05BD9, 0x05C1F, 0x05C33, 0x05D71, 0x05D63, 0x05D4A,
+    0x05D65, 0x05D72, 0x05D6C, 0x05D5E, 0x05D68, 0x05D67, 0x05D62, 0x05DF0, 0x05E4F, 0x05E4E, 0x05E4A, 0x05E4D,
+    0x05E4B, 0x05EC5, 0x05ECC, 0x05EC6, 0x05ECB, 0x05EC7, 0x05F40, 0x05FAF, 0x05FAD, 0x060F7, 0x06149, 0x0614A,
+    0x0612B, 0x06145, 0x06136, 0x06132, 0x0612E, 0x06146, 0x0612F, 0x0614F, 0x06129, 0x06140, 0x06220, 0x09168,
+    0x06223, 0x06225, 0x06224, 0x063C5, 0x063F1, 0x063EB, 0x06410, 0x06412, 0x06409, 0x06420, 0x06424, 0x06433,
+    0x06443, 0x0641F, 0x06415, 0x06418, 0x06439, 0x06437, 0x06422, 0x06423, 0x0640C, 0x06426, 0x06430, 0x06428,
+    0x06441, 0x06435, 0x0642F, 0x0640A, 0x0641A, 0x06440, 0x06425, 0x06427, 0x0640B, 0x063E7, 0x0641B, 0x0642E,
+    0x06421, 0x0640E, 0x0656F, 0x06592, 0x065D3, 0x06686, 0x0668C, 0x06695, 0x06690, 0x0668B, 0x0668A, 0x06699,
+    0x06694, 0x06678, 0x06720, 0x06966, 0x0695F, 0x06938, 0x0694E, 0x06962, 0x06971, 0x0693F, 0x06945, 0x0696A,
+    0x06939, 0x06942, 0x06957, 0x06959, 0x0697A, 0x06948, 0x06949, 0x06935, 0x0696C, 0x06933, 0x0693D, 0x06965,
+    0x068F0, 0x06978, 0x06934, 0x06969, 0x06940, 0x0696F, 0x06944, 0x06976, 0x06958, 0x06941, 0x06974, 0x0694C,
+    0x0693B, 0x0694B, 0x06937, 0x0695C, 0x0694F, 0x06951, 0x06932, 0x06952, 0x0692F, 0x0697B, 0x0693C, 0x06B46,
+    0x06B45, 0x06B43, 0x06B42, 0x06B48, 0x06B41, 0x06B9B, 0x0FA0D, 0x06BFB, 0x06BFC, 0x06BF9, 0x06BF7, 0x06BF8,
+    0x06E9B, 0x06ED6, 0x06EC8, 0x06E8F, 0x06EC0, 0x06E9F, 0x06E93, 0x06E94, 0x06EA0, 0x06EB1, 0x06EB9, 0x06EC6,
+    0x06ED2, 0x06EBD, 0x06EC1, 0x06E9E, 0x06EC9, 0x06EB7, 0x06EB0, 0x06ECD, 0x06EA6, 0x06ECF, 0x06EB2, 0x06EBE,
+    0x06EC3, 0x06EDC, 0x06ED8, 0x06E99, 0x06E92, 0x06E8E, 0x06E8D, 0x06EA4, 0x06EA1, 0x06EBF, 0x06EB3, 0x06ED0,
+    0x06ECA, 0x06E97, 0x06EAE, 0x06EA3, 0x07147, 0x07154, 0x07152, 0x07163, 0x07160, 0x07141, 0x0715D, 0x07162,
+    0x07172, 0x07178, 0x0716A, 0x07161, 0x07142, 0x07158, 0x07143, 0x0714B, 0x07170, 0x0715F, 0x07150, 0x07153,
+    0x07144, 0x0714D, 0x0715A, 0x0724F, 0x0728D, 0x0728C, 0x07291, 0x07290, 0x0728E, 0x0733C, 0x07342, 0x0733B,
+    0x0733A, 0x07340, 0x0734A, 0x07349, 0x07444, 0x0744A, 0x0744B, 0x07452, 0x07451, 0x07457, 0x07440, 0x0744F,
+    0x07450, 0x0744E, 0x07442, 0x07446, 0x0744D, 0x07454, 0x074E1, 0x074FF, 0x074FE, 0x074FD, 0x0751D, 0x07579,
+    0x07577, 0x06983, 0x075EF, 0x0760F, 0x07603, 0x075F7, 0x075FE, 0x075FC, 0x075F9, 0x075F8, 0x07610, 0x075FB,
+    0x075F6, 0x075ED, 0x075F5, 0x075FD, 0x07699, 0x076B5, 0x076DD, 0x07755, 0x0775F, 0x07760, 0x07752, 0x07756,
+    0x0775A, 0x07769, 0x07767, 0x07754, 0x07759, 0x0776D, 0x077E0, 0x07887, 0x0789A, 0x07894, 0x0788F, 0x07884,
+    0x07895, 0x07885, 0x07886, 0x078A1, 0x07883, 0x07879, 0x07899, 0x07880, 0x07896, 0x0787B, 0x0797C, 0x07982,
+    0x0797D, 0x07979, 0x07A11, 0x07A18, 0x07A19, 0x07A12, 0x07A17, 0x07A15, 0x07A22, 0x07A13, 0x07A1B, 0x07A10,
+    0x07AA3, 0x07AA2, 0x07A9E, 0x07AEB, 0x07B66, 0x07B64, 0x07B6D, 0x07B74, 0x07B69, 0x07B72, 0x07B65, 0x07B73,
+    0x07B71, 0x07B70, 0x07B61, 0x07B78, 0x07B76, 0x07B63, 0x07CB2, 0x07CB4, 0x07CAF, 0x07D88, 0x07D86, 0x07D80,
+    0x07D8D, 0x07D7F, 0x07D85, 0x07D7A, 0x07D8E, 0x07D7B, 0x07D83, 0x07D7C, 0x07D8C, 0x07D94, 0x07D84, 0x07D7D,
+    0x07D92, 0x07F6D, 0x07F6B, 0x07F67, 0x07F68, 0x07F6C, 0x07FA6, 0x07FA5, 0x07FA7, 0x07FDB, 0x07FDC, 0x08021,
+    0x08164, 0x08160, 0x08177, 0x0815C, 0x08169, 0x0815B, 0x08162, 0x08172, 0x06721, 0x0815E, 0x08176, 0x08167,
+    0x0816F, 0x08144, 0x08161, 0x0821D, 0x08249, 0x08244, 0x08240, 0x08242, 0x08245, 0x084F1, 0x0843F, 0x08456,
+    0x08476, 0x08479, 0x0848F, 0x0848D, 0x08465, 0x08451, 0x08440, 0x08486, 0x08467, 0x08430, 0x0844D, 0x0847D,
+    0x0845A, 0x08459, 0x08474, 0x08473, 0x0845D, 0x08507, 0x0845E, 0x08437, 0x0843A, 0x08434, 0x0847A, 0x08443,
+    0x08478, 0x08432, 0x08445, 0x08429, 0x083D9, 0x0844B, 0x0842F, 0x08442, 0x0842D, 0x0845F, 0x08470, 0x08439,
+    0x0844E, 0x0844C, 0x08452, 0x0846F, 0x084C5, 0x0848E, 0x0843B, 0x08447, 0x08436, 0x08433, 0x08468, 0x0847E,
+    0x08444, 0x0842B, 0x08460, 0x08454, 0x0846E, 0x08450, 0x0870B, 0x08704, 0x086F7, 0x0870C, 0x086FA, 0x086D6,
+    0x086F5, 0x0874D, 0x086F8, 0x0870E, 0x08709, 0x08701, 0x086F6, 0x0870D, 0x08705, 0x088D6, 0x088CB, 0x088CD,
+    0x088CE, 0x088DE, 0x088DB, 0x088DA, 0x088CC, 0x088D0, 0x08985, 0x0899B, 0x089DF, 0x089E5, 0x089E4, 0x089E1,
+    0x089E0, 0x089E2, 0x089DC, 0x089E6, 0x08A76, 0x08A86, 0x08A7F, 0x08A61, 0x08A3F, 0x08A77, 0x08A82, 0x08A84,
+    0x08A75, 0x08A83, 0x08A81, 0x08A74, 0x08A7A, 0x08C3C, 0x08C4B, 0x08C4A, 0x08C65, 0x08C64, 0x08C66, 0x08C86,
+    0x08C84, 0x08C85, 0x08CCC, 0x08D68, 0x08D69, 0x08D91, 0x08D8C, 0x08D8E, 0x08D8F, 0x08D8D, 0x08D93, 0x08D94,
+    0x08D90, 0x08D92, 0x08DF0, 0x08DE0, 0x08DEC, 0x08DF1, 0x08DEE, 0x08DD0, 0x08DE9, 0x08DE3, 0x08DE2, 0x08DE7,
+    0x08DF2, 0x08DEB, 0x08DF4, 0x08F06, 0x08EFF, 0x08F01, 0x08F00, 0x08F05, 0x08F07, 0x08F08, 0x08F02, 0x08F0B,
+    0x09052, 0x0903F, 0x09044, 0x09049, 0x0903D, 0x09110, 0x0910D, 0x0910F, 0x09111, 0x09116, 0x09114, 0x0910B,
+    0x0910E, 0x0916E, 0x0916F, 0x09248, 0x09252, 0x09230, 0x0923A, 0x09266, 0x09233, 0x09265, 0x0925E, 0x09283,
+    0x0922E, 0x0924A, 0x09246, 0x0926D, 0x0926C, 0x0924F, 0x09260, 0x09267, 0x0926F, 0x09236, 0x09261, 0x09270,
+    0x09231, 0x09254, 0x09263, 0x09250, 0x09272, 0x0924E, 0x09253, 0x0924C, 0x09256, 0x09232, 0x0959F, 0x0959C,
+    0x0959E, 0x0959B, 0x09692, 0x09693, 0x09691, 0x09697, 0x096CE, 0x096FA, 0x096FD, 0x096F8, 0x096F5, 0x09773,
+    0x09777, 0x09778, 0x09772, 0x0980F, 0x0980D, 0x0980E, 0x098AC, 0x098F6, 0x098F9, 0x099AF, 0x099B2, 0x099B0,
+    0x099B5, 0x09AAD, 0x09AAB, 0x09B5B, 0x09CEA, 0x09CED, 0x09CE7, 0x09E80, 0x09EFD, 0x050E6, 0x050D4, 0x050D7,
+    0x050E8, 0x050F3, 0x050DB, 0x050EA, 0x050DD, 0x050E4, 0x050D3, 0x050EC, 0x050F0, 0x050EF, 0x050E3, 0x050E0,
+    0x051D8, 0x05280, 0x05281, 0x052E9, 0x052EB, 0x05330, 0x053AC, 0x05627, 0x05615, 0x0560C, 0x05612, 0x055FC,
+    0x0560F, 0x0561C, 0x05601, 0x05613, 0x05602, 0x055FA, 0x0561D, 0x05604, 0x055FF, 0x055F9, 0x05889, 0x0587C,
+    0x05890, 0x05898, 0x05886, 0x05881, 0x0587F, 0x05874, 0x0588B, 0x0587A, 0x05887, 0x05891, 0x0588E, 0x05876,
+    0x05882, 0x05888, 0x0587B, 0x05894, 0x0588F, 0x058FE, 0x0596B, 0x05ADC, 0x05AEE, 0x05AE5, 0x05AD5, 0x05AEA,
+    0x05ADA, 0x05AED, 0x05AEB, 0x05AF3, 0x05AE2, 0x05AE0, 0x05ADB, 0x05AEC, 0x05ADE, 0x05ADD, 0x05AD9, 0x05AE8,
+    0x05ADF, 0x05B77, 0x05BE0, 0x05BE3, 0x05C63, 0x05D82, 0x05D80, 0x05D7D, 0x05D86, 0x05D7A, 0x05D81, 0x05D77,
+    0x05D8A, 0x05D89, 0x05D88, 0x05D7E, 0x05D7C, 0x05D8D, 0x05D79, 0x05D7F, 0x05E58, 0x05E59, 0x05E53, 0x05ED8,
+    0x05ED1, 0x05ED7, 0x05ECE, 0x05EDC, 0x05ED5, 0x05ED9, 0x05ED2, 0x05ED4, 0x05F44, 0x05F43, 0x05F6F, 0x05FB6,
+    0x0612C, 0x06128, 0x06141, 0x0615E, 0x06171, 0x06173, 0x06152, 0x06153, 0x06172, 0x0616C, 0x06180, 0x06174,
+    0x06154, 0x0617A, 0x0615B, 0x06165, 0x0613B, 0x0616A, 0x06161, 0x06156, 0x06229, 0x06227, 0x0622B, 0x0642B,
+    0x0644D, 0x0645B, 0x0645D, 0x06474, 0x06476, 0x06472, 0x06473, 0x0647D, 0x06475, 0x06466, 0x064A6, 0x0644E,
+    0x06482, 0x0645E, 0x0645C, 0x0644B, 0x06453, 0x06460, 0x06450, 0x0647F, 0x0643F, 0x0646C, 0x0646B, 0x06459,
+    0x06465, 0x06477, 0x06573, 0x065A0, 0x066A1, 0x066A0, 0x0669F, 0x06705, 0x06704, 0x06722, 0x069B1, 0x069B6,
+    0x069C9, 0x069A0, 0x069CE, 0x06996, 0x069B0, 0x069AC, 0x069BC, 0x06991, 0x06999, 0x0698E, 0x069A7, 0x0698D,
+    0x069A9, 0x069BE, 0x069AF, 0x069BF, 0x069C4, 0x069BD, 0x069A4, 0x069D4, 0x069B9, 0x069CA, 0x0699A, 0x069CF,
+    0x069B3, 0x06993, 0x069AA, 0x069A1, 0x0699E, 0x069D9, 0x06997, 0x06990, 0x069C2, 0x069B5, 0x069A5, 0x069C6,
+    0x06B4A, 0x06B4D, 0x06B4B, 0x06B9E, 0x06B9F, 0x06BA0, 0x06BC3, 0x06BC4, 0x06BFE, 0x06ECE, 0x06EF5, 0x06EF1,
+    0x06F03, 0x06F25, 0x06EF8, 0x06F37, 0x06EFB, 0x06F2E, 0x06F09, 0x06F4E, 0x06F19, 0x06F1A, 0x06F27, 0x06F18,
+    0x06F3B, 0x06F12, 0x06EED, 0x06F0A, 0x06F36, 0x06F73, 0x06EF9, 0x06EEE, 0x06F2D, 0x06F40, 0x06F30, 0x06F3C,
+    0x06F35, 0x06EEB, 0x06F07, 0x06F0E, 0x06F43, 0x06F05, 0x06EFD, 0x06EF6, 0x06F39, 0x06F1C, 0x06EFC, 0x06F3A,
+    0x06F1F, 0x06F0D, 0x06F1E, 0x06F08, 0x06F21, 0x07187, 0x07190, 0x07189, 0x07180, 0x07185, 0x07182, 0x0718F,
+    0x0717B, 0x07186, 0x07181, 0x07197, 0x07244, 0x07253, 0x07297, 0x07295, 0x07293, 0x07343, 0x0734D, 0x07351,
+    0x0734C, 0x07462, 0x07473, 0x07471, 0x07475, 0x07472, 0x07467, 0x0746E, 0x07500, 0x07502, 0x07503, 0x0757D,
+    0x07590, 0x07616, 0x07608, 0x0760C, 0x07615, 0x07611, 0x0760A, 0x07614, 0x076B8, 0x07781, 0x0777C, 0x07785,
+    0x07782, 0x0776E, 0x07780, 0x0776F, 0x0777E, 0x07783, 0x078B2, 0x078AA, 0x078B4, 0x078AD, 0x078A8, 0x0787E,
+    0x078AB, 0x0789E, 0x078A5, 0x078A0, 0x078AC, 0x078A2, 0x078A4, 0x07998, 0x0798A, 0x0798B, 0x07996, 0x07995,
+    0x07994, 0x07993, 0x07997, 0x07988, 0x07992, 0x07990, 0x07A2B, 0x07A4A, 0x07A30, 0x07A2F, 0x07A28, 0x07A26,
+    0x07AA8, 0x07AAB, 0x07AAC, 0x07AEE, 0x07B88, 0x07B9C, 0x07B8A, 0x07B91, 0x07B90, 0x07B96, 0x07B8D, 0x07B8C,
+    0x07B9B, 0x07B8E, 0x07B85, 0x07B98, 0x05284, 0x07B99, 0x07BA4, 0x07B82, 0x07CBB, 0x07CBF, 0x07CBC, 0x07CBA,
+    0x07DA7, 0x07DB7, 0x07DC2, 0x07DA3, 0x07DAA, 0x07DC1, 0x07DC0, 0x07DC5, 0x07D9D, 0x07DCE, 0x07DC4, 0x07DC6,
+    0x07DCB, 0x07DCC, 0x07DAF, 0x07DB9, 0x07D96, 0x07DBC, 0x07D9F, 0x07DA6, 0x07DAE, 0x07DA9, 0x07DA1, 0x07DC9,
+    0x07F73, 0x07FE2, 0x07FE3, 0x07FE5, 0x07FDE, 0x08024, 0x0805D, 0x0805C, 0x08189, 0x08186, 0x08183, 0x08187,
+    0x0818D, 0x0818C, 0x0818B, 0x08215, 0x08497, 0x084A4, 0x084A1, 0x0849F, 0x084BA, 0x084CE, 0x084C2, 0x084AC,
+    0x084AE, 0x084AB, 0x084B9, 0x084B4, 0x084C1, 0x084CD, 0x084AA, 0x0849A, 0x084B1, 0x084D0, 0x0849D, 0x084A7,
+    0x084BB, 0x084A2, 0x08494, 0x084C7, 0x084CC, 0x0849B, 0x084A9, 0x084AF, 0x084A8, 0x084D6, 0x08498, 0x084B6,
+    0x084CF, 0x084A0, 0x084D7, 0x084D4, 0x084D2, 0x084DB, 0x084B0, 0x08491, 0x08661, 0x08733, 0x08723, 0x08728,
+    0x0876B, 0x08740, 0x0872E, 0x0871E, 0x08721, 0x08719, 0x0871B, 0x08743, 0x0872C, 0x08741, 0x0873E, 0x08746,
+    0x08720, 0x08732, 0x0872A, 0x0872D, 0x0873C, 0x08712, 0x0873A, 0x08731, 0x08735, 0x08742, 0x08726, 0x08727,
+    0x08738, 0x08724, 0x0871A, 0x08730, 0x08711, 0x088F7, 0x088E7, 0x088F1, 0x088F2, 0x088FA, 0x088FE, 0x088EE,
+    0x088FC, 0x088F6, 0x088FB, 0x088F0, 0x088EC, 0x088EB, 0x0899D, 0x089A1, 0x0899F, 0x0899E, 0x089E9, 0x089EB,
+    0x089E8, 0x08AAB, 0x08A99, 0x08A8B, 0x08A92, 0x08A8F, 0x08A96, 0x08C3D, 0x08C68, 0x08C69, 0x08CD5, 0x08CCF,
+    0x08CD7, 0x08D96, 0x08E09, 0x08E02, 0x08DFF, 0x08E0D, 0x08DFD, 0x08E0A, 0x08E03, 0x08E07, 0x08E06, 0x08E05,
+    0x08DFE, 0x08E00, 0x08E04, 0x08F10, 0x08F11, 0x08F0E, 0x08F0D, 0x09123, 0x0911C, 0x09120, 0x09122, 0x0911F,
+    0x0911D, 0x0911A, 0x09124, 0x09121, 0x0911B, 0x0917A, 0x09172, 0x09179, 0x09173, 0x092A5, 0x092A4, 0x09276,
+    0x0929B, 0x0927A, 0x092A0, 0x09294, 0x092AA, 0x0928D, 0x092A6, 0x0929A, 0x092AB, 0x09279, 0x09297, 0x0927F,
+    0x092A3, 0x092EE, 0x0928E, 0x09282, 0x09295, 0x092A2, 0x0927D, 0x09288, 0x092A1, 0x0928A, 0x09286, 0x0928C,
+    0x09299, 0x092A7, 0x0927E, 0x09287, 0x092A9, 0x0929D, 0x0928B, 0x0922D, 0x0969E, 0x096A1, 0x096FF, 0x09758,
+    0x0977D, 0x0977A, 0x0977E, 0x09783, 0x09780, 0x09782, 0x0977B, 0x09784, 0x09781, 0x0977F, 0x097CE, 0x097CD,
+    0x09816, 0x098AD, 0x098AE, 0x09902, 0x09900, 0x09907, 0x0999D, 0x0999C, 0x099C3, 0x099B9, 0x099BB, 0x099BA,
+    0x099C2, 0x099BD, 0x099C7, 0x09AB1, 0x09AE3, 0x09AE7, 0x09B3E, 0x09B3F, 0x09B60, 0x09B61, 0x09B5F, 0x09CF1,
+    0x09CF2, 0x09CF5, 0x09EA7, 0x050FF, 0x05103, 0x05130, 0x050F8, 0x05106, 0x05107, 0x050F6, 0x050FE, 0x0510B,
+    0x0510C, 0x050FD, 0x0510A, 0x0528B, 0x0528C, 0x052F1, 0x052EF, 0x05648, 0x05642, 0x0564C, 0x05635, 0x05641,
+    0x0564A, 0x05649, 0x05646, 0x05658, 0x0565A, 0x05640, 0x05633, 0x0563D, 0x0562C, 0x0563E, 0x05638, 0x0562A,
+    0x0563A, 0x0571A, 0x058AB, 0x0589D, 0x058B1, 0x058A0, 0x058A3, 0x058AF, 0x058AC, 0x058A5, 0x058A1, 0x058FF,
+    0x05AFF, 0x05AF4, 0x05AFD, 0x05AF7, 0x05AF6, 0x05B03, 0x05AF8, 0x05B02, 0x05AF9, 0x05B01, 0x05B07, 0x05B05,
+    0x05B0F, 0x05C67, 0x05D99, 0x05D97, 0x05D9F, 0x05D92, 0x05DA2, 0x05D93, 0x05D95, 0x05DA0, 0x05D9C, 0x05DA1,
+    0x05D9A, 0x05D9E, 0x05E69, 0x05E5D, 0x05E60, 0x05E5C, 0x07DF3, 0x05EDB, 0x05EDE, 0x05EE1, 0x05F49, 0x05FB2,
+    0x0618B, 0x06183, 0x06179, 0x061B1, 0x061B0, 0x061A2, 0x06189, 0x0619B, 0x06193, 0x061AF, 0x061AD, 0x0619F,
+    0x06192, 0x061AA, 0x061A1, 0x0618D, 0x06166, 0x061B3, 0x0622D, 0x0646E, 0x06470, 0x06496, 0x064A0, 0x06485,
+    0x06497, 0x0649C, 0x0648F, 0x0648B, 0x0648A, 0x0648C, 0x064A3, 0x0649F, 0x06468, 0x064B1, 0x06498, 0x06576,
+    0x0657A, 0x06579, 0x0657B, 0x065B2, 0x065B3, 0x066B5, 0x066B0, 0x066A9, 0x066B2, 0x066B7, 0x066AA, 0x066AF,
+    0x06A00, 0x06A06, 0x06A17, 0x069E5, 0x069F8, 0x06A15, 0x069F1, 0x069E4, 0x06A20, 0x069FF, 0x069EC, 0x069E2,
+    0x06A1B, 0x06A1D, 0x069FE, 0x06A27, 0x069F2, 0x069EE, 0x06A14, 0x069F7, 0x069E7, 0x06A40, 0x06A08, 0x069E6,
+    0x069FB, 0x06A0D, 0x069FC, 0x069EB, 0x06A09, 0x06A04, 0x06A18, 0x06A25, 0x06A0F, 0x069F6, 0x06A26, 0x06A07,
+    0x069F4, 0x06A16, 0x06B51, 0x06BA5, 0x06BA3, 0x06BA2, 0x06BA6, 0x06C01, 0x06C00, 0x06BFF, 0x06C02, 0x06F41,
+    0x06F26, 0x06F7E, 0x06F87, 0x06FC6, 0x06F92, 0x06F8D, 0x06F89, 0x06F8C, 0x06F62, 0x06F4F, 0x06F85, 0x06F5A,
+    0x06F96, 0x06F76, 0x06F6C, 0x06F82, 0x06F55, 0x06F72, 0x06F52, 0x06F50, 0x06F57, 0x06F94, 0x06F93, 0x06F5D,
+    0x06F00, 0x06F61, 0x06F6B, 0x06F7D, 0x06F67, 0x06F90, 0x06F53, 0x06F8B, 0x06F69, 0x06F7F, 0x06F95, 0x06F63,
+    0x06F77, 0x06F6A, 0x06F7B, 0x071B2, 0x071AF, 0x0719B, 0x071B0, 0x071A0, 0x0719A, 0x071A9, 0x071B5, 0x0719D,
+    0x071A5, 0x0719E, 0x071A4, 0x071A1, 0x071AA, 0x0719C, 0x071A7, 0x071B3, 0x07298, 0x0729A, 0x07358, 0x07352,
+    0x0735E, 0x0735F, 0x07360, 0x0735D, 0x0735B, 0x07361, 0x0735A, 0x07359, 0x07362, 0x07487, 0x07489, 0x0748A,
+    0x07486, 0x07481, 0x0747D, 0x07485, 0x07488, 0x0747C, 0x07479, 0x07508, 0x07507, 0x0757E, 0x07625, 0x0761E,
+    0x07619, 0x0761D, 0x0761C, 0x07623, 0x0761A, 0x07628, 0x0761B, 0x0769C, 0x0769D, 0x0769E, 0x0769B, 0x0778D,
+    0x0778F, 0x07789, 0x07788, 0x078CD, 0x078BB, 0x078CF, 0x078CC, 0x078D1, 0x078CE, 0x078D4, 0x078C8, 0x078C3,
+    0x078C4, 0x078C9, 0x0799A, 0x079A1, 0x079A0, 0x0799C, 0x079A2, 0x0799B, 0x06B76, 0x07A39, 0x07AB2, 0x07AB4,
+    0x07AB3, 0x07BB7, 0x07BCB, 0x07BBE, 0x07BAC, 0x07BCE, 0x07BAF, 0x07BB9, 0x07BCA, 0x07BB5, 0x07CC5, 0x07CC8,
+    0x07CCC, 0x07CCB, 0x07DF7, 0x07DDB, 0x07DEA, 0x07DE7, 0x07DD7, 0x07DE1, 0x07E03, 0x07DFA, 0x07DE6, 0x07DF6,
+    0x07DF1, 0x07DF0, 0x07DEE, 0x07DDF, 0x07F76, 0x07FAC, 0x07FB0, 0x07FAD, 0x07FED, 0x07FEB, 0x07FEA, 0x07FEC,
+    0x07FE6, 0x07FE8, 0x08064, 0x08067, 0x081A3, 0x0819F, 0x0819E, 0x08195, 0x081A2, 0x08199, 0x08197, 0x08216,
+    0x0824F, 0x08253, 0x08252, 0x08250, 0x0824E, 0x08251, 0x08524, 0x0853B, 0x0850F, 0x08500, 0x08529, 0x0850E,
+    0x08509, 0x0850D, 0x0851F, 0x0850A, 0x08527, 0x0851C, 0x084FB, 0x0852B, 0x084FA, 0x08508, 0x0850C, 0x084F4,
+    0x0852A, 0x084F2, 0x08515, 0x084F7, 0x084EB, 0x084F3, 0x084FC, 0x08512, 0x084EA, 0x084E9, 0x08516, 0x084FE,
+    0x08528, 0x0851D, 0x0852E, 0x08502, 0x084FD, 0x0851E, 0x084F6, 0x08531, 0x08526, 0x084E7, 0x084E8, 0x084F0,
+    0x084EF, 0x084F9, 0x08518, 0x08520, 0x08530, 0x0850B, 0x08519, 0x0852F, 0x08662, 0x08756, 0x08763, 0x08764,
+    0x08777, 0x087E1, 0x08773, 0x08758, 0x08754, 0x0875B, 0x08752, 0x08761, 0x0875A, 0x08751, 0x0875E, 0x0876D,
+    0x0876A, 0x08750, 0x0874E, 0x0875F, 0x0875D, 0x0876F, 0x0876C, 0x0877A, 0x0876E, 0x0875C, 0x08765, 0x0874F,
+    0x0877B, 0x08775, 0x08762, 0x08767, 0x08769, 0x0885A, 0x08905, 0x0890C, 0x08914, 0x0890B, 0x08917, 0x08918,
+    0x08919, 0x08906, 0x08916, 0x08911, 0x0890E, 0x08909, 0x089A2, 0x089A4, 0x089A3, 0x089ED, 0x089F0, 0x089EC,
+    0x08ACF, 0x08AC6, 0x08AB8, 0x08AD3, 0x08AD1, 0x08AD4, 0x08AD5, 0x08ABB, 0x08AD7, 0x08ABE, 0x08AC0, 0x08AC5,
+    0x08AD8, 0x08AC3, 0x08ABA, 0x08ABD, 0x08AD9, 0x08C3E, 0x08C4D, 0x08C8F, 0x08CE5, 0x08CDF, 0x08CD9, 0x08CE8,
+    0x08CDA, 0x08CDD, 0x08CE7, 0x08DA0, 0x08D9C, 0x08DA1, 0x08D9B, 0x08E20, 0x08E23, 0x08E25, 0x08E24, 0x08E2E,
+    0x08E15, 0x08E1B, 0x08E16, 0x08E11, 0x08E19, 0x08E26, 0x08E27, 0x08E14, 0x08E12, 0x08E18, 0x08E13, 0x08E1C,
+    0x08E17, 0x08E1A, 0x08F2C, 0x08F24, 0x08F18, 0x08F1A, 0x08F20, 0x08F23, 0x08F16, 0x08F17, 0x09073, 0x09070,
+    0x0906F, 0x09067, 0x0906B, 0x0912F, 0x0912B, 0x09129, 0x0912A, 0x09132, 0x09126, 0x0912E, 0x09185, 0x09186,
+    0x0918A, 0x09181, 0x09182, 0x09184, 0x09180, 0x092D0, 0x092C3, 0x092C4, 0x092C0, 0x092D9, 0x092B6, 0x092CF,
+    0x092F1, 0x092DF, 0x092D8, 0x092E9, 0x092D7, 0x092DD, 0x092CC, 0x092EF, 0x092C2, 0x092E8, 0x092CA, 0x092C8,
+    0x092CE, 0x092E6, 0x092CD, 0x092D5, 0x092C9, 0x092E0, 0x092DE, 0x092E7, 0x092D1, 0x092D3, 0x092B5, 0x092E1,
+    0x092C6, 0x092B4, 0x0957C, 0x095AC, 0x095AB, 0x095AE, 0x095B0, 0x096A4, 0x096A2, 0x096D3, 0x09705, 0x09708,
+    0x09702, 0x0975A, 0x0978A, 0x0978E, 0x09788, 0x097D0, 0x097CF, 0x0981E, 0x0981D, 0x09826, 0x09829, 0x09828,
+    0x09820, 0x0981B, 0x09827, 0x098B2, 0x09908, 0x098FA, 0x09911, 0x09914, 0x09916, 0x09917, 0x09915, 0x099DC,
+    0x099CD, 0x099CF, 0x099D3, 0x099D4, 0x099CE, 0x099C9, 0x099D6, 0x099D8, 0x099CB, 0x099D7, 0x099CC, 0x09AB3,
+    0x09AEC, 0x09AEB, 0x09AF3, 0x09AF2, 0x09AF1, 0x09B46, 0x09B43, 0x09B67, 0x09B74, 0x09B71, 0x09B66, 0x09B76,
+    0x09B75, 0x09B70, 0x09B68, 0x09B64, 0x09B6C, 0x09CFC, 0x09CFA, 0x09CFD, 0x09CFF, 0x09CF7, 0x09D07, 0x09D00,
+    0x09CF9, 0x09CFB, 0x09D08, 0x09D05, 0x09D04, 0x09E83, 0x09ED3, 0x09F0F, 0x09F10, 0x0511C, 0x05113, 0x05117,
+    0x0511A, 0x05111, 0x051DE, 0x05334, 0x053E1, 0x05670, 0x05660, 0x0566E, 0x05673, 0x05666, 0x05663, 0x0566D,
+    0x05672, 0x0565E, 0x05677, 0x0571C, 0x0571B, 0x058C8, 0x058BD, 0x058C9, 0x058BF, 0x058BA, 0x058C2, 0x058BC,
+    0x058C6, 0x05B17, 0x05B19, 0x05B1B, 0x05B21, 0x05B14, 0x05B13, 0x05B10, 0x05B16, 0x05B28, 0x05B1A, 0x05B20,
+    0x05B1E, 0x05BEF, 0x05DAC, 0x05DB1, 0x05DA9, 0x05DA7, 0x05DB5, 0x05DB0, 0x05DAE, 0x05DAA, 0x05DA8, 0x05DB2,
+    0x05DAD, 0x05DAF, 0x05DB4, 0x05E67, 0x05E68, 0x05E66, 0x05E6F, 0x05EE9, 0x05EE7, 0x05EE6, 0x05EE8, 0x05EE5,
+    0x05F4B, 0x05FBC, 0x0619D, 0x061A8, 0x06196, 0x061C5, 0x061B4, 0x061C6, 0x061C1, 0x061CC, 0x061BA, 0x061BF,
+    0x061B8, 0x0618C, 0x064D7, 0x064D6, 0x064D0, 0x064CF, 0x064C9, 0x064BD, 0x06489, 0x064C3, 0x064DB, 0x064F3,
+    0x064D9, 0x06533, 0x0657F, 0x0657C, 0x065A2, 0x066C8, 0x066BE, 0x066C0, 0x066CA, 0x066CB, 0x066CF, 0x066BD,
+    0x066BB, 0x066BA, 0x066CC, 0x06723, 0x06A34, 0x06A66, 0x06A49, 0x06A67, 0x06A32, 0x06A68, 0x06A3E, 0x06A5D,
+    0x06A6D, 0x06A76, 0x06A5B, 0x06A51, 0x06A28, 0x06A5A, 0x06A3B, 0x06A3F, 0x06A41, 0x06A6A, 0x06A64, 0x06A50,
+    0x06A4F, 0x06A54, 0x06A6F, 0x06A69, 0x06A60, 0x06A3C, 0x06A5E, 0x06A56, 0x06A55, 0x06A4D, 0x06A4E, 0x06A46,
+    0x06B55, 0x06B54, 0x06B56, 0x06BA7, 0x06BAA, 0x06BAB, 0x06BC8, 0x06BC7, 0x06C04, 0x06C03, 0x06C06, 0x06FAD,
+    0x06FCB, 0x06FA3, 0x06FC7, 0x06FBC, 0x06FCE, 0x06FC8, 0x06F5E, 0x06FC4, 0x06FBD, 0x06F9E, 0x06FCA, 0x06FA8,
+    0x07004, 0x06FA5, 0x06FAE, 0x06FBA, 0x06FAC, 0x06FAA, 0x06FCF, 0x06FBF, 0x06FB8, 0x06FA2, 0x06FC9, 0x06FAB,
+    0x06FCD, 0x06FAF, 0x06FB2, 0x06FB0, 0x071C5, 0x071C2, 0x071BF, 0x071B8, 0x071D6, 0x071C0, 0x071C1, 0x071CB,
+    0x071D4, 0x071CA, 0x071C7, 0x071CF, 0x071BD, 0x071D8, 0x071BC, 0x071C6, 0x071DA, 0x071DB, 0x0729D, 0x0729E,
+    0x07369, 0x07366, 0x07367, 0x0736C, 0x07365, 0x0736B, 0x0736A, 0x0747F, 0x0749A, 0x074A0, 0x07494, 0x07492,
+    0x07495, 0x074A1, 0x0750B, 0x07580, 0x0762F, 0x0762D, 0x07631, 0x0763D, 0x07633, 0x0763C, 0x07635, 0x07632,
+    0x07630, 0x076BB, 0x076E6, 0x0779A, 0x0779D, 0x077A1, 0x0779C, 0x0779B, 0x077A2, 0x077A3, 0x07795, 0x07799,
+    0x07797, 0x078DD, 0x078E9, 0x078E5, 0x078EA, 0x078DE, 0x078E3, 0x078DB, 0x078E1, 0x078E2, 0x078ED, 0x078DF,
+    0x078E0, 0x079A4, 0x07A44, 0x07A48, 0x07A47, 0x07AB6, 0x07AB8, 0x07AB5, 0x07AB1, 0x07AB7, 0x07BDE, 0x07BE3,
+    0x07BE7, 0x07BDD, 0x07BD5, 0x07BE5, 0x07BDA, 0x07BE8, 0x07BF9, 0x07BD4, 0x07BEA, 0x07BE2, 0x07BDC, 0x07BEB,
+    0x07BD8, 0x07BDF, 0x07CD2, 0x07CD4, 0x07CD7, 0x07CD0, 0x07CD1, 0x07E12, 0x07E21, 0x07E17, 0x07E0C, 0x07E1F,
+    0x07E20, 0x07E13, 0x07E0E, 0x07E1C, 0x07E15, 0x07E1A, 0x07E22, 0x07E0B, 0x07E0F, 0x07E16, 0x07E0D, 0x07E14,
+    0x07E25, 0x07E24, 0x07F43, 0x07F7B, 0x07F7C, 0x07F7A, 0x07FB1, 0x07FEF, 0x0802A, 0x08029, 0x0806C, 0x081B1,
+    0x081A6, 0x081AE, 0x081B9, 0x081B5, 0x081AB, 0x081B0, 0x081AC, 0x081B4, 0x081B2, 0x081B7, 0x081A7, 0x081F2,
+    0x08255, 0x08256, 0x08257, 0x08556, 0x08545, 0x0856B, 0x0854D, 0x08553, 0x08561, 0x08558, 0x08540, 0x08546,
+    0x08564, 0x08541, 0x08562, 0x08544, 0x08551, 0x08547, 0x08563, 0x0853E, 0x0855B, 0x08571, 0x0854E, 0x0856E,
+    0x08575, 0x08555, 0x08567, 0x08560, 0x0858C, 0x08566, 0x0855D, 0x08554, 0x08565, 0x0856C, 0x08663, 0x08665,
+    0x08664, 0x0879B, 0x0878F, 0x08797, 0x08793, 0x08792, 0x08788, 0x08781, 0x08796, 0x08798, 0x08779, 0x08787,
+    0x087A3, 0x08785, 0x08790, 0x08791, 0x0879D, 0x08784, 0x08794, 0x0879C, 0x0879A, 0x08789, 0x0891E, 0x08926,
+    0x08930, 0x0892D, 0x0892E, 0x08927, 0x08931, 0x08922, 0x08929, 0x08923, 0x0892F, 0x0892C, 0x0891F, 0x089F1,
+    0x08AE0, 0x08AE2, 0x08AF2, 0x08AF4, 0x08AF5, 0x08ADD, 0x08B14, 0x08AE4, 0x08ADF, 0x08AF0, 0x08AC8, 0x08ADE,
+    0x08AE1, 0x08AE8, 0x08AFF, 0x08AEF, 0x08AFB, 0x08C91, 0x08C92, 0x08C90, 0x08CF5, 0x08CEE, 0x08CF1, 0x08CF0,
+    0x08CF3, 0x08D6C, 0x08D6E, 0x08DA5, 0x08DA7, 0x08E33, 0x08E3E, 0x08E38, 0x08E40, 0x08E45, 0x08E36, 0x08E3C,
+    0x08E3D, 0x08E41, 0x08E30, 0x08E3F, 0x08EBD, 0x08F36, 0x08F2E, 0x08F35, 0x08F32, 0x08F39, 0x08F37, 0x08F34,
+    0x09076, 0x09079, 0x0907B, 0x09086, 0x090FA, 0x09133, 0x09135, 0x09136, 0x09193, 0x09190, 0x09191, 0x0918D,
+    0x0918F, 0x09327, 0x0931E, 0x09308, 0x0931F, 0x09306, 0x0930F, 0x0937A, 0x09338, 0x0933C, 0x0931B, 0x09323,
+    0x09312, 0x09301, 0x09346, 0x0932D, 0x0930E, 0x0930D, 0x092CB, 0x0931D, 0x092FA, 0x09325, 0x09313, 0x092F9,
+    0x092F7, 0x09334, 0x09302, 0x09324, 0x092FF, 0x09329, 0x09339, 0x09335, 0x0932A, 0x09314, 0x0930C, 0x0930B,
+    0x092FE, 0x09309, 0x09300, 0x092FB, 0x09316, 0x095BC, 0x095CD, 0x095BE, 0x095B9, 0x095BA, 0x095B6, 0x095BF,
+    0x095B5, 0x095BD, 0x096A9, 0x096D4, 0x0970B, 0x09712, 0x09710, 0x09799, 0x09797, 0x09794, 0x097F0, 0x097F8,
+    0x09835, 0x0982F, 0x09832, 0x09924, 0x0991F, 0x09927, 0x09929, 0x0999E, 0x099EE, 0x099EC, 0x099E5, 0x099E4,
+    0x099F0, 0x099E3, 0x099EA, 0x099E9, 0x099E7, 0x09AB9, 0x09ABF, 0x09AB4, 0x09ABB, 0x09AF6, 0x09AFA, 0x09AF9,
+    0x09AF7, 0x09B33, 0x09B80, 0x09B85, 0x09B87, 0x09B7C, 0x09B7E, 0x09B7B, 0x09B82, 0x09B93, 0x09B92, 0x09B90,
+    0x09B7A, 0x09B95, 0x09B7D, 0x09B88, 0x09D25, 0x09D17, 0x09D20, 0x09D1E, 0x09D14, 0x09D29, 0x09D1D, 0x09D18,
+    0x09D22, 0x09D10, 0x09D19, 0x09D1F, 0x09E88, 0x09E86, 0x09E87, 0x09EAE, 0x09EAD, 0x09ED5, 0x09ED6, 0x09EFA,
+    0x09F12, 0x09F3D, 0x05126, 0x05125, 0x05122, 0x05124, 0x05120, 0x05129, 0x052F4, 0x05693, 0x0568C, 0x0568D,
+    0x05686, 0x05684, 0x05683, 0x0567E, 0x05682, 0x0567F, 0x05681, 0x058D6, 0x058D4, 0x058CF, 0x058D2, 0x05B2D,
+    0x05B25, 0x05B32, 0x05B23, 0x05B2C, 0x05B27, 0x05B26, 0x05B2F, 0x05B2E, 0x05B7B, 0x05BF1, 0x05BF2, 0x05DB7,
+    0x05E6C, 0x05E6A, 0x05FBE, 0x05FBB, 0x061C3, 0x061B5, 0x061BC, 0x061E7, 0x061E0, 0x061E5, 0x061E4, 0x061E8,
+    0x061DE, 0x064EF, 0x064E9, 0x064E3, 0x064EB, 0x064E4, 0x064E8, 0x06581, 0x06580, 0x065B6, 0x065DA, 0x066D2,
+    0x06A8D, 0x06A96, 0x06A81, 0x06AA5, 0x06A89, 0x06A9F, 0x06A9B, 0x06AA1, 0x06A9E, 0x06A87, 0x06A93, 0x06A8E,
+    0x06A95, 0x06A83, 0x06AA8, 0x06AA4, 0x06A91, 0x06A7F, 0x06AA6, 0x06A9A, 0x06A85, 0x06A8C, 0x06A92, 0x06B5B,
+    0x06BAD, 0x06C09, 0x06FCC, 0x06FA9, 0x06FF4, 0x06FD4, 0x06FE3, 0x06FDC, 0x06FED, 0x06FE7, 0x06FE6, 0x06FDE,
+    0x06FF2, 0x06FDD, 0x06FE2, 0x06FE8, 0x071E1, 0x071F1, 0x071E8, 0x071F2, 0x071E4, 0x071F0, 0x071E2, 0x07373,
+    0x0736E, 0x0736F, 0x07497, 0x074B2, 0x074AB, 0x07490, 0x074AA, 0x074AD, 0x074B1, 0x074A5, 0x074AF, 0x07510,
+    0x07511, 0x07512, 0x0750F, 0x07584, 0x07643, 0x07648, 0x07649, 0x07647, 0x076A4, 0x076E9, 0x077B5, 0x077AB,
+    0x077B2, 0x077B7, 0x077B6, 0x077B4, 0x077B1, 0x077A8, 0x077F0, 0x078F3, 0x078FD, 0x07902, 0x078FB, 0x078FC,
+    0x078F2, 0x07905, 0x078F9, 0x078FE, 0x07904, 0x079AB, 0x079A8, 0x07A5C, 0x07A5B, 0x07A56, 0x07A58, 0x07A54,
+    0x07A5A, 0x07ABE, 0x07AC0, 0x07AC1, 0x07C05, 0x07C0F, 0x07BF2, 0x07C00, 0x07BFF, 0x07BFB, 0x07C0E, 0x07BF4,
+    0x07C0B, 0x07BF3, 0x07C02, 0x07C09, 0x07C03, 0x07C01, 0x07BF8, 0x07BFD, 0x07C06, 0x07BF0, 0x07BF1, 0x07C10,
+    0x07C0A, 0x07CE8, 0x07E2D, 0x07E3C, 0x07E42, 0x07E33, 0x09848, 0x07E38, 0x07E2A, 0x07E49, 0x07E40, 0x07E47,
+    0x07E29, 0x07E4C, 0x07E30, 0x07E3B, 0x07E36, 0x07E44, 0x07E3A, 0x07F45, 0x07F7F, 0x07F7E, 0x07F7D, 0x07FF4,
+    0x07FF2, 0x0802C, 0x081BB, 0x081C4, 0x081CC, 0x081CA, 0x081C5, 0x081C7, 0x081BC, 0x081E9, 0x0825B, 0x0825A,
+    0x0825C, 0x08583, 0x08580, 0x0858F, 0x085A7, 0x08595, 0x085A0, 0x0858B, 0x085A3, 0x0857B, 0x085A4, 0x0859A,
+    0x0859E, 0x08577, 0x0857C, 0x08589, 0x085A1, 0x0857A, 0x08578, 0x08557, 0x0858E, 0x08596, 0x08586, 0x0858D,
+    0x08599, 0x0859D, 0x08581, 0x085A2, 0x08582, 0x08588, 0x08585, 0x08579, 0x08576, 0x08598, 0x08590, 0x0859F,
+    0x08668, 0x087BE, 0x087AA, 0x087AD, 0x087C5, 0x087B0, 0x087AC, 0x087B9, 0x087B5, 0x087BC, 0x087AE, 0x087C9,
+    0x087C3, 0x087C2, 0x087CC, 0x087B7, 0x087AF, 0x087C4, 0x087CA, 0x087B4, 0x087B6, 0x087BF, 0x087B8, 0x087BD,
+    0x087DE, 0x087B2, 0x08935, 0x08933, 0x0893C, 0x0893E, 0x08941, 0x08952, 0x08937, 0x08942, 0x089AD, 0x089AF,
+    0x089AE, 0x089F2, 0x089F3, 0x08B1E, 0x08B18, 0x08B16, 0x08B11, 0x08B05, 0x08B0B, 0x08B22, 0x08B0F, 0x08B12,
+    0x08B15, 0x08B07, 0x08B0D, 0x08B08, 0x08B06, 0x08B1C, 0x08B13, 0x08B1A, 0x08C4F, 0x08C70, 0x08C72, 0x08C71,
+    0x08C6F, 0x08C95, 0x08C94, 0x08CF9, 0x08D6F, 0x08E4E, 0x08E4D, 0x08E53, 0x08E50, 0x08E4C, 0x08E47, 0x08F43,
+    0x08F40, 0x09085, 0x0907E, 0x09138, 0x0919A, 0x091A2, 0x0919B, 0x09199, 0x0919F, 0x091A1, 0x0919D, 0x091A0,
+    0x093A1, 0x09383, 0x093AF, 0x09364, 0x09356, 0x09347, 0x0937C, 0x09358, 0x0935C, 0x09376, 0x09349, 0x09350,
+    0x09351, 0x09360, 0x0936D, 0x0938F, 0x0934C, 0x0936A, 0x09379, 0x09357, 0x09355, 0x09352, 0x0934F, 0x09371,
+    0x09377, 0x0937B, 0x09361, 0x0935E, 0x09363, 0x09367, 0x09380, 0x0934E, 0x09359, 0x095C7, 0x095C0, 0x095C9,
+    0x095C3, 0x095C5, 0x095B7, 0x096AE, 0x096B0, 0x096AC, 0x09720, 0x0971F, 0x09718, 0x0971D, 0x09719, 0x0979A,
+    0x097A1, 0x0979C, 0x0979E, 0x0979D, 0x097D5, 0x097D4, 0x097F1, 0x09841, 0x09844, 0x0984A, 0x09849, 0x09845,
+    0x09843, 0x09925, 0x0992B, 0x0992C, 0x0992A, 0x09933, 0x09932, 0x0992F, 0x0992D, 0x09931, 0x09930, 0x09998,
+    0x099A3, 0x099A1, 0x09A02, 0x099FA, 0x099F4, 0x099F7, 0x099F9, 0x099F8, 0x099F6, 0x099FB, 0x099FD, 0x099FE,
+    0x099FC, 0x09A03, 0x09ABE, 0x09AFE, 0x09AFD, 0x09B01, 0x09AFC, 0x09B48, 0x09B9A, 0x09BA8, 0x09B9E, 0x09B9B,
+    0x09BA6, 0x09BA1, 0x09BA5, 0x09BA4, 0x09B86, 0x09BA2, 0x09BA0, 0x09BAF, 0x09D33, 0x09D41, 0x09D67, 0x09D36,
+    0x09D2E, 0x09D2F, 0x09D31, 0x09D38, 0x09D30, 0x09D45, 0x09D42, 0x09D43, 0x09D3E, 0x09D37, 0x09D40, 0x09D3D,
+    0x07FF5, 0x09D2D, 0x09E8A, 0x09E89, 0x09E8D, 0x09EB0, 0x09EC8, 0x09EDA, 0x09EFB, 0x09EFF, 0x09F24, 0x09F23,
+    0x09F22, 0x09F54, 0x09FA0, 0x05131, 0x0512D, 0x0512E, 0x05698, 0x0569C, 0x05697, 0x0569A, 0x0569D, 0x05699,
+    0x05970, 0x05B3C, 0x05C69, 0x05C6A, 0x05DC0, 0x05E6D, 0x05E6E, 0x061D8, 0x061DF, 0x061ED, 0x061EE, 0x061F1,
+    0x061EA, 0x061F0, 0x061EB, 0x061D6, 0x061E9, 0x064FF, 0x06504, 0x064FD, 0x064F8, 0x06501, 0x06503, 0x064FC,
+    0x06594, 0x065DB, 0x066DA, 0x066DB, 0x066D8, 0x06AC5, 0x06AB9, 0x06ABD, 0x06AE1, 0x06AC6, 0x06ABA, 0x06AB6,
+    0x06AB7, 0x06AC7, 0x06AB4, 0x06AAD, 0x06B5E, 0x06BC9, 0x06C0B, 0x07007, 0x0700C, 0x0700D, 0x07001, 0x07005,
+    0x07014, 0x0700E, 0x06FFF, 0x07000, 0x06FFB, 0x07026, 0x06FFC, 0x06FF7, 0x0700A, 0x07201, 0x071FF, 0x071F9,
+    0x07203, 0x071FD, 0x07376, 0x074B8, 0x074C0, 0x074B5, 0x074C1, 0x074BE, 0x074B6, 0x074BB, 0x074C2, 0x07514,
+    0x07513, 0x0765C, 0x07664, 0x07659, 0x07650, 0x07653, 0x07657, 0x0765A, 0x076A6, 0x076BD, 0x076EC, 0x077C2,
+    0x077BA, 0x078FF, 0x0790C, 0x07913, 0x07914, 0x07909, 0x07910, 0x07912, 0x07911, 0x079AD, 0x079AC, 0x07A5F,
+    0x07C1C, 0x07C29, 0x07C19, 0x07C20, 0x07C1F, 0x07C2D, 0x07C1D, 0x07C26, 0x07C28, 0x07C22, 0x07C25, 0x07C30,
+    0x07E5C, 0x07E50, 0x07E56, 0x07E63, 0x07E58, 0x07E62, 0x07E5F, 0x07E51, 0x07E60, 0x07E57, 0x07E53, 0x07FB5,
+    0x07FB3, 0x07FF7, 0x07FF8, 0x08075, 0x081D1, 0x081D2, 0x081D0, 0x0825F, 0x0825E, 0x085B4, 0x085C6, 0x085C0,
+    0x085C3, 0x085C2, 0x085B3, 0x085B5, 0x085BD, 0x085C7, 0x085C4, 0x085BF, 0x085CB, 0x085CE, 0x085C8, 0x085C5,
+    0x085B1, 0x085B6, 0x085D2, 0x08624, 0x085B8, 0x085B7, 0x085BE, 0x08669, 0x087E7, 0x087E6, 0x087E2, 0x087DB,
+    0x087EB, 0x087EA, 0x087E5, 0x087DF, 0x087F3, 0x087E4, 0x087D4, 0x087DC, 0x087D3, 0x087ED, 0x087D8, 0x087E3,
+    0x087A4, 0x087D7, 0x087D9, 0x08801, 0x087F4, 0x087E8, 0x087DD, 0x08953, 0x0894B, 0x0894F, 0x0894C, 0x08946,
+    0x08950, 0x08951, 0x08949, 0x08B2A, 0x08B27, 0x08B23, 0x08B33, 0x08B30, 0x08B35, 0x08B47, 0x08B2F, 0x08B3C,
+    0x08B3E, 0x08B31, 0x08B25, 0x08B37, 0x08B26, 0x08B36, 0x08B2E, 0x08B24, 0x08B3B, 0x08B3D, 0x08B3A, 0x08C42,
+    0x08C75, 0x08C99, 0x08C98, 0x08C97, 0x08CFE, 0x08D04, 0x08D02, 0x08D00, 0x08E5C, 0x08E62, 0x08E60, 0x08E57,
+    0x08E56, 0x08E5E, 0x08E65, 0x08E67, 0x08E5B, 0x08E5A, 0x08E61, 0x08E5D, 0x08E69, 0x08E54, 0x08F46, 0x08F47,
+    0x08F48, 0x08F4B, 0x09128, 0x0913A, 0x0913B, 0x0913E, 0x091A8, 0x091A5, 0x091A7, 0x091AF, 0x091AA, 0x093B5,
+    0x0938C, 0x09392, 0x093B7, 0x0939B, 0x0939D, 0x09389, 0x093A7, 0x0938E, 0x093AA, 0x0939E, 0x093A6, 0x09395,
+    0x09388, 0x09399, 0x0939F, 0x0938D, 0x093B1, 0x09391, 0x093B2, 0x093A4, 0x093A8, 0x093B4, 0x093A3, 0x093A5,
+    0x095D2, 0x095D3, 0x095D1, 0x096B3, 0x096D7, 0x096DA, 0x05DC2, 0x096DF, 0x096D8, 0x096DD, 0x09723, 0x09722,
+    0x09725, 0x097AC, 0x097AE, 0x097A8, 0x097AB, 0x097A4, 0x097AA, 0x097A2, 0x097A5, 0x097D7, 0x097D9, 0x097D6,
+    0x097D8, 0x097FA, 0x09850, 0x09851, 0x09852, 0x098B8, 0x09941, 0x0993C, 0x0993A, 0x09A0F, 0x09A0B, 0x09A09,
+    0x09A0D, 0x09A04, 0x09A11, 0x09A0A, 0x09A05, 0x09A07, 0x09A06, 0x09AC0, 0x09ADC, 0x09B08, 0x09B04, 0x09B05,
+    0x09B29, 0x09B35, 0x09B4A, 0x09B4C, 0x09B4B, 0x09BC7, 0x09BC6, 0x09BC3, 0x09BBF, 0x09BC1, 0x09BB5, 0x09BB8,
+    0x09BD3, 0x09BB6, 0x09BC4, 0x09BB9, 0x09BBD, 0x09D5C, 0x09D53, 0x09D4F, 0x09D4A, 0x09D5B, 0x09D4B, 0x09D59,
+    0x09D56, 0x09D4C, 0x09D57, 0x09D52, 0x09D54, 0x09D5F, 0x09D58, 0x09D5A, 0x09E8E, 0x09E8C, 0x09EDF, 0x09F01,
+    0x09F00, 0x09F16, 0x09F25, 0x09F2B, 0x09F2A, 0x09F29, 0x09F28, 0x09F4C, 0x09F55, 0x05134, 0x05135, 0x05296,
+    0x052F7, 0x053B4, 0x056AB, 0x056AD, 0x056A6, 0x056A7, 0x056AA, 0x056AC, 0x058DA, 0x058DD, 0x058DB, 0x05912,
+    0x05B3D, 0x05B3E, 0x05B3F, 0x05DC3, 0x05E70, 0x05FBF, 0x061FB, 0x06507, 0x06510, 0x0650D, 0x06509, 0x0650C,
+    0x0650E, 0x06584, 0x065DE, 0x065DD, 0x066DE, 0x06AE7, 0x06AE0, 0x06ACC, 0x06AD1, 0x06AD9, 0x06ACB, 0x06ADF,
+    0x06ADC, 0x06AD0, 0x06AEB, 0x06ACF, 0x06ACD, 0x06ADE, 0x06B60, 0x06BB0, 0x06C0C, 0x07019, 0x07027, 0x07020,
+    0x07016, 0x0702B, 0x07021, 0x07022, 0x07023, 0x07029, 0x07017, 0x07024, 0x0701C, 0x0702A, 0x0720C, 0x0720A,
+    0x07207, 0x07202, 0x07205, 0x072A5, 0x072A6, 0x072A4, 0x072A3, 0x072A1, 0x074CB, 0x074C5, 0x074B7, 0x074C3,
+    0x07516, 0x07660, 0x077C9, 0x077CA, 0x077C4, 0x077F1, 0x0791D, 0x0791B, 0x07921, 0x0791C, 0x07917, 0x0791E,
+    0x079B0, 0x07A67, 0x07A68, 0x07C33, 0x07C3C, 0x07C39, 0x07C2C, 0x07C3B, 0x07CEC, 0x07CEA, 0x07E76, 0x07E75,
+    0x07E78, 0x07E70, 0x07E77, 0x07E6F, 0x07E7A, 0x07E72, 0x07E74, 0x07E68, 0x07F4B, 0x07F4A, 0x07F83, 0x07F86,
+    0x07FB7, 0x07FFD, 0x07FFE, 0x08078, 0x081D7, 0x081D5, 0x08264, 0x08261, 0x08263, 0x085EB, 0x085F1, 0x085ED,
+    0x085D9, 0x085E1, 0x085E8, 0x085DA, 0x085D7, 0x085EC, 0x085F2, 0x085F8, 0x085D8, 0x085DF, 0x085E3, 0x085DC,
+    0x085D1, 0x085F0, 0x085E6, 0x085EF, 0x085DE, 0x085E2, 0x08800, 0x087FA, 0x08803, 0x087F6, 0x087F7, 0x08809,
+    0x0880C, 0x0880B, 0x08806, 0x087FC, 0x08808, 0x087FF, 0x0880A, 0x08802, 0x08962, 0x0895A, 0x0895B, 0x08957,
+    0x08961, 0x0895C, 0x08958, 0x0895D, 0x08959, 0x08988, 0x089B7, 0x089B6, 0x089F6, 0x08B50, 0x08B48, 0x08B4A,
+    0x08B40, 0x08B53, 0x08B56, 0x08B54, 0x08B4B, 0x08B55, 0x08B51, 0x08B42, 0x08B52, 0x08B57, 0x08C43, 0x08C77,
+    0x08C76, 0x08C9A, 0x08D06, 0x08D07, 0x08D09, 0x08DAC, 0x08DAA, 0x08DAD, 0x08DAB, 0x08E6D, 0x08E78, 0x08E73,
+    0x08E6A, 0x08E6F, 0x08E7B, 0x08EC2, 0x08F52, 0x08F51, 0x08F4F, 0x08F50, 0x08F53, 0x08FB4, 0x09140, 0x0913F,
+    0x091B0, 0x091AD, 0x093DE, 0x093C7, 0x093CF, 0x093C2, 0x093DA, 0x093D0, 0x093F9, 0x093EC, 0x093CC, 0x093D9,
+    0x093A9, 0x093E6, 0x093CA, 0x093D4, 0x093EE, 0x093E3, 0x093D5, 0x093C4, 0x093CE, 0x093C0, 0x093D2, 0x093E7,
+    0x0957D, 0x095DA, 0x095DB, 0x096E1, 0x09729, 0x0972B, 0x0972C, 0x09728, 0x09726, 0x097B3, 0x097B7, 0x097B6,
+    0x097DD, 0x097DE, 0x097DF, 0x0985C, 0x09859, 0x0985D, 0x09857, 0x098BF, 0x098BD, 0x098BB, 0x098BE, 0x09948,
+    0x09947, 0x09943, 0x099A6, 0x099A7, 0x09A1A, 0x09A15, 0x09A25, 0x09A1D, 0x09A24, 0x09A1B, 0x09A22, 0x09A20,
+    0x09A27, 0x09A23, 0x09A1E, 0x09A1C, 0x09A14, 0x09AC2, 0x09B0B, 0x09B0A, 0x09B0E, 0x09B0C, 0x09B37, 0x09BEA,
+    0x09BEB, 0x09BE0, 0x09BDE, 0x09BE4, 0x09BE6, 0x09BE2, 0x09BF0, 0x09BD4, 0x09BD7, 0x09BEC, 0x09BDC, 0x09BD9,
+    0x09BE5, 0x09BD5, 0x09BE1, 0x09BDA, 0x09D77, 0x09D81, 0x09D8A, 0x09D84, 0x09D88, 0x09D71, 0x09D80, 0x09D78,
+    0x09D86, 0x09D8B, 0x09D8C, 0x09D7D, 0x09D6B, 0x09D74, 0x09D75, 0x09D70, 0x09D69, 0x09D85, 0x09D73, 0x09D7B,
+    0x09D82, 0x09D6F, 0x09D79, 0x09D7F, 0x09D87, 0x09D68, 0x09E94, 0x09E91, 0x09EC0, 0x09EFC, 0x09F2D, 0x09F40,
+    0x09F41, 0x09F4D, 0x09F56, 0x09F57, 0x09F58, 0x05337, 0x056B2, 0x056B5, 0x056B3, 0x058E3, 0x05B45, 0x05DC6,
+    0x05DC7, 0x05EEE, 0x05EEF, 0x05FC0, 0x05FC1, 0x061F9, 0x06517, 0x06516, 0x06515, 0x06513, 0x065DF, 0x066E8,
+    0x066E3, 0x066E4, 0x06AF3, 0x06AF0, 0x06AEA, 0x06AE8, 0x06AF9, 0x06AF1, 0x06AEE, 0x06AEF, 0x0703C, 0x07035,
+    0x0702F, 0x07037, 0x07034, 0x07031, 0x07042, 0x07038, 0x0703F, 0x0703A, 0x07039, 0x07040, 0x0703B, 0x07033,
+    0x07041, 0x07213, 0x07214, 0x072A8, 0x0737D, 0x0737C, 0x074BA, 0x076AB, 0x076AA, 0x076BE, 0x076ED, 0x077CC,
+    0x077CE, 0x077CF, 0x077CD, 0x077F2, 0x07925, 0x07923, 0x07927, 0x07928, 0x07924, 0x07929, 0x079B2, 0x07A6E,
+    0x07A6C, 0x07A6D, 0x07AF7, 0x07C49, 0x07C48, 0x07C4A, 0x07C47, 0x07C45, 0x07CEE, 0x07E7B, 0x07E7E, 0x07E81,
+    0x07E80, 0x07FBA, 0x07FFF, 0x08079, 0x081DB, 0x081D9, 0x0820B, 0x08268, 0x08269, 0x08622, 0x085FF, 0x08601,
+    0x085FE, 0x0861B, 0x08600, 0x085F6, 0x08604, 0x08609, 0x08605, 0x0860C, 0x085FD, 0x08819, 0x08810, 0x08811,
+    0x08817, 0x08813, 0x08816, 0x08963, 0x08966, 0x089B9, 0x089F7, 0x08B60, 0x08B6A, 0x08B5D, 0x08B68, 0x08B63,
+    0x08B65, 0x08B67, 0x08B6D, 0x08DAE, 0x08E86, 0x08E88, 0x08E84, 0x08F59, 0x08F56, 0x08F57, 0x08F55, 0x08F58,
+    0x08F5A, 0x0908D, 0x09143, 0x09141, 0x091B7, 0x091B5, 0x091B2, 0x091B3, 0x0940B, 0x09413, 0x093FB, 0x09420,
+    0x0940F, 0x09414, 0x093FE, 0x09415, 0x09410, 0x09428, 0x09419, 0x0940D, 0x093F5, 0x09400, 0x093F7, 0x09407,
+    0x0940E, 0x09416, 0x09412, 0x093FA, 0x09409, 0x093F8, 0x0940A, 0x093FF, 0x093FC, 0x0940C, 0x093F6, 0x09411,
+    0x09406, 0x095DE, 0x095E0, 0x095DF, 0x0972E, 0x0972F, 0x097B9, 0x097BB, 0x097FD, 0x097FE, 0x09860, 0x09862,
+    0x09863, 0x0985F, 0x098C1, 0x098C2, 0x09950, 0x0994E, 0x09959, 0x0994C, 0x0994B, 0x09953, 0x09A32, 0x09A34,
+    0x09A31, 0x09A2C, 0x09A2A, 0x09A36, 0x09A29, 0x09A2E, 0x09A38, 0x09A2D, 0x09AC7, 0x09ACA, 0x09AC6, 0x09B10,
+    0x09B12, 0x09B11, 0x09C0B, 0x09C08, 0x09BF7, 0x09C05, 0x09C12, 0x09BF8, 0x09C40, 0x09C07, 0x09C0E, 0x09C06,
+    0x09C17, 0x09C14, 0x09C09, 0x09D9F, 0x09D99, 0x09DA4, 0x09D9D, 0x09D92, 0x09D98, 0x09D90, 0x09D9B, 0x09DA0,
+    0x09D94, 0x09D9C, 0x09DAA, 0x09D97, 0x09DA1, 0x09D9A, 0x09DA2, 0x09DA8, 0x09D9E, 0x09DA3, 0x09DBF, 0x09DA9,
+    0x09D96, 0x09DA6, 0x09DA7, 0x09E99, 0x09E9B, 0x09E9A, 0x09EE5, 0x09EE4, 0x09EE7, 0x09EE6, 0x09F30, 0x09F2E,
+    0x09F5B, 0x09F60, 0x09F5E, 0x09F5D, 0x09F59, 0x09F91, 0x0513A, 0x05139, 0x05298, 0x05297, 0x056C3, 0x056BD,
+    0x056BE, 0x05B48, 0x05B47, 0x05DCB, 0x05DCF, 0x05EF1, 0x061FD, 0x0651B, 0x06B02, 0x06AFC, 0x06B03, 0x06AF8,
+    0x06B00, 0x07043, 0x07044, 0x0704A, 0x07048, 0x07049, 0x07045, 0x07046, 0x0721D, 0x0721A, 0x07219, 0x0737E,
+    0x07517, 0x0766A, 0x077D0, 0x0792D, 0x07931, 0x0792F, 0x07C54, 0x07C53, 0x07CF2, 0x07E8A, 0x07E87, 0x07E88,
+    0x07E8B, 0x07E86, 0x07E8D, 0x07F4D, 0x07FBB, 0x08030, 0x081DD, 0x08618, 0x0862A, 0x08626, 0x0861F, 0x08623,
+    0x0861C, 0x08619, 0x08627, 0x0862E, 0x08621, 0x08620, 0x08629, 0x0861E, 0x08625, 0x08829, 0x0881D, 0x0881B,
+    0x08820, 0x08824, 0x0881C, 0x0882B, 0x0884A, 0x0896D, 0x08969, 0x0896E, 0x0896B, 0x089FA, 0x08B79, 0x08B78,
+    0x08B45, 0x08B7A, 0x08B7B, 0x08D10, 0x08D14, 0x08DAF, 0x08E8E, 0x08E8C, 0x08F5E, 0x08F5B, 0x08F5D, 0x09146,
+    0x09144, 0x09145, 0x091B9, 0x0943F, 0x0943B, 0x09436, 0x09429, 0x0943D, 0x0943C, 0x09430, 0x09439, 0x0942A,
+    0x09437, 0x0942C, 0x09440, 0x09431, 0x095E5, 0x095E4, 0x095E3, 0x09735, 0x0973A, 0x097BF, 0x097E1, 0x09864,
+    0x098C9, 0x098C6, 0x098C0, 0x09958, 0x09956, 0x09A39, 0x09A3D, 0x09A46, 0x09A44, 0x09A42, 0x09A41, 0x09A3A,
+    0x09A3F, 0x09ACD, 0x09B15, 0x09B17, 0x09B18, 0x09B16, 0x09B3A, 0x09B52, 0x09C2B, 0x09C1D, 0x09C1C, 0x09C2C,
+    0x09C23, 0x09C28, 0x09C29, 0x09C24, 0x09C21, 0x09DB7, 0x09DB6, 0x09DBC, 0x09DC1, 0x09DC7, 0x09DCA, 0x09DCF,
+    0x09DBE, 0x09DC5, 0x09DC3, 0x09DBB, 0x09DB5, 0x09DCE, 0x09DB9, 0x09DBA, 0x09DAC, 0x09DC8, 0x09DB1, 0x09DAD,
+    0x09DCC, 0x09DB3, 0x09DCD, 0x09DB2, 0x09E7A, 0x09E9C, 0x09EEB, 0x09EEE, 0x09EED, 0x09F1B, 0x09F18, 0x09F1A,
+    0x09F31, 0x09F4E, 0x09F65, 0x09F64, 0x09F92, 0x04EB9, 0x056C6, 0x056C5, 0x056CB, 0x05971, 0x05B4B, 0x05B4C,
+    0x05DD5, 0x05DD1, 0x05EF2, 0x06521, 0x06520, 0x06526, 0x06522, 0x06B0B, 0x06B08, 0x06B09, 0x06C0D, 0x07055,
+    0x07056, 0x07057, 0x07052, 0x0721E, 0x0721F, 0x072A9, 0x0737F, 0x074D8, 0x074D5, 0x074D9, 0x074D7, 0x0766D,
+    0x076AD, 0x07935, 0x079B4, 0x07A70, 0x07A71, 0x07C57, 0x07C5C, 0x07C59, 0x07C5B, 0x07C5A, 0x07CF4, 0x07CF1,
+    0x07E91, 0x07F4F, 0x07F87, 0x081DE, 0x0826B, 0x08634, 0x08635, 0x08633, 0x0862C, 0x08632, 0x08636, 0x0882C,
+    0x08828, 0x08826, 0x0882A, 0x08825, 0x08971, 0x089BF, 0x089BE, 0x089FB, 0x08B7E, 0x08B84, 0x08B82, 0x08B86,
+    0x08B85, 0x08B7F, 0x08D15, 0x08E95, 0x08E94, 0x08E9A, 0x08E92, 0x08E90, 0x08E96, 0x08E97, 0x08F60, 0x08F62,
+    0x09147, 0x0944C, 0x09450, 0x0944A, 0x0944B, 0x0944F, 0x09447, 0x09445, 0x09448, 0x09449, 0x09446, 0x0973F,
+    0x097E3, 0x0986A, 0x09869, 0x098CB, 0x09954, 0x0995B, 0x09A4E, 0x09A53, 0x09A54, 0x09A4C, 0x09A4F, 0x09A48,
+    0x09A4A, 0x09A49, 0x09A52, 0x09A50, 0x09AD0, 0x09B19, 0x09B2B, 0x09B3B, 0x09B56, 0x09B55, 0x09C46, 0x09C48,
+    0x09C3F, 0x09C44, 0x09C39, 0x09C33, 0x09C41, 0x09C3C, 0x09C37, 0x09C34, 0x09C32, 0x09C3D, 0x09C36, 0x09DDB,
+    0x09DD2, 0x09DDE, 0x09DDA, 0x09DCB, 0x09DD0, 0x09DDC, 0x09DD1, 0x09DDF, 0x09DE9, 0x09DD9, 0x09DD8, 0x09DD6,
+    0x09DF5, 0x09DD5, 0x09DDD, 0x09EB6, 0x09EF0, 0x09F35, 0x09F33, 0x09F32, 0x09F42, 0x09F6B, 0x09F95, 0x09FA2,
+    0x0513D, 0x05299, 0x058E8, 0x058E7, 0x05972, 0x05B4D, 0x05DD8, 0x0882F, 0x05F4F, 0x06201, 0x06203, 0x06204,
+    0x06529, 0x06525, 0x06596, 0x066EB, 0x06B11, 0x06B12, 0x06B0F, 0x06BCA, 0x0705B, 0x0705A, 0x07222, 0x07382,
+    0x07381, 0x07383, 0x07670, 0x077D4, 0x07C67, 0x07C66, 0x07E95, 0x0826C, 0x0863A, 0x08640, 0x08639, 0x0863C,
+    0x08631, 0x0863B, 0x0863E, 0x08830, 0x08832, 0x0882E, 0x08833, 0x08976, 0x08974, 0x08973, 0x089FE, 0x08B8C,
+    0x08B8E, 0x08B8B, 0x08B88, 0x08C45, 0x08D19, 0x08E98, 0x08F64, 0x08F63, 0x091BC, 0x09462, 0x09455, 0x0945D,
+    0x09457, 0x0945E, 0x097C4, 0x097C5, 0x09800, 0x09A56, 0x09A59, 0x09B1E, 0x09B1F, 0x09B20, 0x09C52, 0x09C58,
+    0x09C50, 0x09C4A, 0x09C4D, 0x09C4B, 0x09C55, 0x09C59, 0x09C4C, 0x09C4E, 0x09DFB, 0x09DF7, 0x09DEF, 0x09DE3,
+    0x09DEB, 0x09DF8, 0x09DE4, 0x09DF6, 0x09DE1, 0x09DEE, 0x09DE6, 0x09DF2, 0x09DF0, 0x09DE2, 0x09DEC, 0x09DF4,
+    0x09DF3, 0x09DE8, 0x09DED, 0x09EC2, 0x09ED0, 0x09EF2, 0x09EF3, 0x09F06, 0x09F1C, 0x09F38, 0x09F37, 0x09F36,
+    0x09F43, 0x09F4F, 0x09F71, 0x09F70, 0x09F6E, 0x09F6F, 0x056D3, 0x056CD, 0x05B4E, 0x05C6D, 0x0652D, 0x066ED,
+    0x066EE, 0x06B13, 0x0705F, 0x07061, 0x0705D, 0x07060, 0x07223, 0x074DB, 0x074E5, 0x077D5, 0x07938, 0x079B7,
+    0x079B6, 0x07C6A, 0x07E97, 0x07F89, 0x0826D, 0x08643, 0x08838, 0x08837, 0x08835, 0x0884B, 0x08B94, 0x08B95,
+    0x08E9E, 0x08E9F, 0x08EA0, 0x08E9D, 0x091BE, 0x091BD, 0x091C2, 0x0946B, 0x09468, 0x09469, 0x096E5, 0x09746,
+    0x09743, 0x09747, 0x097C7, 0x097E5, 0x09A5E, 0x09AD5, 0x09B59, 0x09C63, 0x09C67, 0x09C66, 0x09C62, 0x09C5E,
+    0x09C60, 0x09E02, 0x09DFE, 0x09E07, 0x09E03, 0x09E06, 0x09E05, 0x09E00, 0x09E01, 0x09E09, 0x09DFF, 0x09DFD,
+    0x09E04, 0x09EA0, 0x09F1E, 0x09F46, 0x09F74, 0x09F75, 0x09F76, 0x056D4, 0x0652E, 0x065B8, 0x06B18, 0x06B19,
+    0x06B17, 0x06B1A, 0x07062, 0x07226, 0x072AA, 0x077D8, 0x077D9, 0x07939, 0x07C69, 0x07C6B, 0x07CF6, 0x07E9A,
+    0x07E98, 0x07E9B, 0x07E99, 0x081E0, 0x081E1, 0x08646, 0x08647, 0x08648, 0x08979, 0x0897A, 0x0897C, 0x0897B,
+    0x089FF, 0x08B98, 0x08B99, 0x08EA5, 0x08EA4, 0x08EA3, 0x0946E, 0x0946D, 0x0946F, 0x09471, 0x09473, 0x09749,
+    0x09872, 0x0995F, 0x09C68, 0x09C6E, 0x09C6D, 0x09E0B, 0x09E0D, 0x09E10, 0x09E0F, 0x09E12, 0x09E11, 0x09EA1,
+    0x09EF5, 0x09F09, 0x09F47, 0x09F78, 0x09F7B, 0x09F7A, 0x09F79, 0x0571E, 0x07066, 0x07C6F, 0x0883C, 0x08DB2,
+    0x08EA6, 0x091C3, 0x09474, 0x09478, 0x09476, 0x09475, 0x09A60, 0x09C74, 0x09C73, 0x09C71, 0x09C75, 0x09E14,
+    0x09E13, 0x09EF6, 0x09F0A, 0x09FA4, 0x07068, 0x07065, 0x07CF7, 0x0866A, 0x0883E, 0x0883D, 0x0883F, 0x08B9E,
+    0x08C9C, 0x08EA9, 0x08EC9, 0x0974B, 0x09873, 0x09874, 0x098CC, 0x09961, 0x099AB, 0x09A64, 0x09A66, 0x09A67,
+    0x09B24, 0x09E15, 0x09E17, 0x09F48, 0x06207, 0x06B1E, 0x07227, 0x0864C, 0x08EA8, 0x09482, 0x09480, 0x09481,
+    0x09A69, 0x09A68, 0x09B2E, 0x09E19, 0x07229, 0x0864B, 0x08B9F, 0x09483, 0x09C79, 0x09EB7, 0x07675, 0x09A6B,
+    0x09C7A, 0x09E1D, 0x07069, 0x0706A, 0x09EA4, 0x09F7E, 0x09F49, 0x09F98, 0x07881, 0x092B9, 0x088CF, 0x058BB,
+    0x06052, 0x07CA7, 0x05AFA, 0x02554, 0x02566, 0x02557, 0x02560, 0x0256C, 0x02563, 0x0255A, 0x02569, 0x0255D,
+    0x02552, 0x02564, 0x02555, 0x0255E, 0x0256A, 0x02561, 0x02558, 0x02567, 0x0255B, 0x02553, 0x02565, 0x02556,
+    0x0255F, 0x0256B, 0x02562, 0x02559, 0x02568, 0x0255C, 0x02551, 0x02550, 0x0256D, 0x0256E, 0x02570, 0x0256F,
+    0x0FFED, 0x20547, 0x092DB, 0x205DF, 0x23FC5, 0x0854C, 0x042B5, 0x073EF, 0x051B5, 0x03649, 0x24942, 0x289E4,
+    0x09344, 0x219DB, 0x082EE, 0x23CC8, 0x0783C, 0x06744, 0x062DF, 0x24933, 0x289AA, 0x202A0, 0x26BB3, 0x21305,
+    0x04FAB, 0x224ED, 0x05008, 0x26D29, 0x27A84, 0x23600, 0x24AB1, 0x22513, 0x05029, 0x2037E, 0x05FA4, 0x20380,
+    0x20347, 0x06EDB, 0x2041F, 0x0507D, 0x05101, 0x0347A, 0x0510E, 0x0986C, 0x03743, 0x08416, 0x249A4, 0x20487,
+    0x05160, 0x233B4, 0x0516A, 0x20BFF, 0x220FC, 0x202E5, 0x22530, 0x2058E, 0x23233, 0x21983, 0x05B82, 0x0877D,
+    0x205B3, 0x23C99, 0x051B2, 0x051B8, 0x09D34, 0x051C9, 0x051CF, 0x051D1, 0x03CDC, 0x051D3, 0x24AA6, 0x051B3,
+    0x051E2, 0x05342, 0x051ED, 0x083CD, 0x0693E, 0x2372D, 0x05F7B, 0x0520B, 0x05226, 0x0523C, 0x052B5, 0x05257,
+    0x05294, 0x052B9, 0x052C5, 0x07C15, 0x08542, 0x052E0, 0x0860D, 0x26B13, 0x05305, 0x28ADE, 0x05549, 0x06ED9,
+    0x23F80, 0x20954, 0x23FEC, 0x05333, 0x05344, 0x20BE2, 0x06CCB, 0x21726, 0x0681B, 0x073D5, 0x0604A, 0x03EAA,
+    0x038CC, 0x216E8, 0x071DD, 0x044A2, 0x0536D, 0x05374, 0x286AB, 0x0537E, 0x0537F, 0x21596, 0x21613, 0x077E6,
+    0x05393, 0x28A9B, 0x053A0, 0x053AB, 0x053AE, 0x073A7, 0x25772, 0x03F59, 0x0739C, 0x053C1, 0x053C5, 0x06C49,
+    0x04E49, 0x057FE, 0x053D9, 0x03AAB, 0x20B8F, 0x053E0, 0x23FEB, 0x22DA3, 0x053F6, 0x20C77, 0x05413, 0x07079,
+    0x0552B, 0x06657, 0x06D5B, 0x0546D, 0x26B53, 0x20D74, 0x0555D, 0x0548F, 0x054A4, 0x047A6, 0x2170D, 0x20EDD,
+    0x03DB4, 0x20D4D, 0x289BC, 0x22698, 0x05547, 0x04CED, 0x0542F, 0x07417, 0x05586, 0x055A9, 0x05605, 0x218D7,
+    0x2403A, 0x04552, 0x24435, 0x066B3, 0x210B4, 0x05637, 0x066CD, 0x2328A, 0x066A4, 0x066AD, 0x0564D, 0x0564F,
+    0x078F1, 0x056F1, 0x09787, 0x053FE, 0x05700, 0x056EF, 0x056ED, 0x28B66, 0x03623, 0x2124F, 0x05746, 0x241A5,
+    0x06C6E, 0x0708B, 0x05742, 0x036B1, 0x26C7E, 0x057E6, 0x21416, 0x05803, 0x21454, 0x24363, 0x05826, 0x24BF5,
+    0x0585C, 0x058AA, 0x03561, 0x058E0, 0x058DC, 0x2123C, 0x058FB, 0x05BFF, 0x05743, 0x2A150, 0x24278, 0x093D3,
+    0x035A1, 0x0591F, 0x068A6, 0x036C3, 0x06E59, 0x2163E, 0x05A24, 0x05553, 0x21692, 0x08505, 0x059C9, 0x20D4E,
+    0x26C81, 0x26D2A, 0x217DC, 0x059D9, 0x217FB, 0x217B2, 0x26DA6, 0x06D71, 0x21828, 0x216D5, 0x059F9, 0x26E45,
+    0x05AAB, 0x05A63, 0x036E6, 0x249A9, 0x05A77, 0x03708, 0x05A96, 0x07465, 0x05AD3, 0x26FA1, 0x22554, 0x03D85,
+    0x21911, 0x03732, 0x216B8, 0x05E83, 0x052D0, 0x05B76, 0x06588, 0x05B7C, 0x27A0E, 0x04004, 0x0485D, 0x20204,
+    0x05BD5, 0x06160, 0x21A34, 0x259CC, 0x205A5, 0x05BF3, 0x05B9D, 0x04D10, 0x05C05, 0x21B44, 0x05C13, 0x073CE,
+    0x05C14, 0x21CA5, 0x26B28, 0x05C49, 0x048DD, 0x05C85, 0x05CE9, 0x05CEF, 0x05D8B, 0x21DF9, 0x21E37, 0x05D10,
+    0x05D18, 0x05D46, 0x21EA4, 0x05CBA, 0x05DD7, 0x082FC, 0x0382D, 0x24901, 0x22049, 0x22173, 0x08287, 0x03836,
+    0x03BC2, 0x05E2E, 0x06A8A, 0x05E75, 0x05E7A, 0x244BC, 0x20CD3, 0x053A6, 0x04EB7, 0x05ED0, 0x053A8, 0x21771,
+    0x05E09, 0x05EF4, 0x28482, 0x05EF9, 0x05EFB, 0x038A0, 0x05EFC, 0x0683E, 0x0941B, 0x05F0D, 0x201C1, 0x2F894,
+    0x03ADE, 0x048AE, 0x2133A, 0x05F3A, 0x26888, 0x223D0, 0x05F58, 0x22471, 0x05F63, 0x097BD, 0x26E6E, 0x05F72,
+    0x09340, 0x28A36, 0x05FA7, 0x05DB6, 0x03D5F, 0x25250, 0x21F6A, 0x270F8, 0x22668, 0x091D6, 0x2029E, 0x28A29,
+    0x06031, 0x06685, 0x21877, 0x03963, 0x03DC7, 0x03639, 0x05790, 0x227B4, 0x07971, 0x03E40, 0x0609E, 0x060A4,
+    0x060B3, 0x24982, 0x2498F, 0x27A53, 0x074A4, 0x050E1, 0x05AA0, 0x06164, 0x08424, 0x06142, 0x2F8A6, 0x26ED2,
+    0x06181, 0x051F4, 0x20656, 0x06187, 0x05BAA, 0x23FB7, 0x2285F, 0x061D3, 0x28B9D, 0x2995D, 0x061D0, 0x03932,
+    0x22980, 0x228C1, 0x06023, 0x0615C, 0x0651E, 0x0638B, 0x20118, 0x062C5, 0x21770, 0x062D5, 0x22E0D, 0x0636C,
+    0x249DF, 0x03A17, 0x06438, 0x063F8, 0x2138E, 0x217FC, 0x06490, 0x06F8A, 0x22E36, 0x09814, 0x2408C, 0x2571D,
+    0x064E1, 0x064E5, 0x0947B, 0x03A66, 0x0643A, 0x03A57, 0x0654D, 0x06F16, 0x24A28, 0x24A23, 0x06585, 0x0656D,
+    0x0655F, 0x2307E, 0x065B5, 0x24940, 0x04B37, 0x065D1, 0x040D8, 0x21829, 0x065E0, 0x065E3, 0x05FDF, 0x23400,
+    0x06618, 0x231F7, 0x231F8, 0x06644, 0x231A4, 0x231A5, 0x0664B, 0x20E75, 0x06667, 0x251E6, 0x06673, 0x06674,
+    0x21E3D, 0x23231, 0x285F4, 0x231C8, 0x25313, 0x077C5, 0x228F7, 0x099A4, 0x06702, 0x2439C, 0x24A21, 0x03B2B,
+    0x069FA, 0x237C2, 0x0675E, 0x06767, 0x06762, 0x241CD, 0x290ED, 0x067D7, 0x044E9, 0x06822, 0x06E50, 0x0923C,
+    0x06801, 0x233E6, 0x26DA0, 0x0685D, 0x2346F, 0x069E1, 0x06A0B, 0x28ADF, 0x06973, 0x068C3, 0x235CD, 0x06901,
+    0x06900, 0x03D32, 0x03A01, 0x2363C, 0x03B80, 0x067AC, 0x06961, 0x28A4A, 0x042FC, 0x06936, 0x06998, 0x03BA1,
+    0x203C9, 0x08363, 0x05090, 0x069F9, 0x23659, 0x2212A, 0x06A45, 0x23703, 0x06A9D, 0x03BF3, 0x067B1, 0x06AC8,
+    0x2919C, 0x03C0D, 0x06B1D, 0x20923, 0x060DE, 0x06B35, 0x06B74, 0x227CD, 0x06EB5, 0x23ADB, 0x203B5, 0x21958,
+    0x03740, 0x05421, 0x23B5A, 0x06BE1, 0x23EFC, 0x06BDC, 0x06C37, 0x2248B, 0x248F1, 0x26B51, 0x06C5A, 0x08226,
+    0x06C79, 0x23DBC, 0x044C5, 0x23DBD, 0x241A4, 0x2490C, 0x24900, 0x23CC9, 0x036E5, 0x03CEB, 0x20D32, 0x09B83,
+    0x231F9, 0x22491, 0x07F8F, 0x06837, 0x26D25, 0x26DA1, 0x26DEB, 0x06D96, 0x06D5C, 0x06E7C, 0x06F04, 0x2497F,
+    0x24085, 0x26E72, 0x08533, 0x26F74, 0x051C7, 0x06C9C, 0x06E1D, 0x0842E, 0x28B21, 0x06E2F, 0x23E2F, 0x07453,
+    0x23F82, 0x079CC, 0x06E4F, 0x05A91, 0x2304B, 0x06FF8, 0x0370D, 0x06F9D, 0x23E30, 0x06EFA, 0x21497, 0x2403D,
+    0x04555, 0x093F0, 0x06F44, 0x06F5C, 0x03D4E, 0x06F74, 0x29170, 0x03D3B, 0x06F9F, 0x24144, 0x06FD3, 0x24091,
+    0x24155, 0x24039, 0x23FF0, 0x23FB4, 0x2413F, 0x051DF, 0x24156, 0x24157, 0x24140, 0x261DD, 0x0704B, 0x0707E,
+    0x070A7, 0x07081, 0x070CC, 0x070D5, 0x070D6, 0x070DF, 0x04104, 0x03DE8, 0x071B4, 0x07196, 0x24277, 0x0712B,
+    0x07145, 0x05A88, 0x0714A, 0x0716E, 0x05C9C, 0x24365, 0x0714F, 0x09362, 0x242C1, 0x0712C, 0x2445A, 0x24A27,
+    0x24A22, 0x071BA, 0x28BE8, 0x070BD, 0x0720E, 0x09442, 0x07215, 0x05911, 0x09443, 0x07224, 0x09341, 0x25605,
+    0x0722E, 0x07240, 0x24974, 0x068BD, 0x07255, 0x07257, 0x03E55, 0x23044, 0x0680D, 0x06F3D, 0x07282, 0x0732A,
+    0x0732B, 0x24823, 0x2882B, 0x048ED, 0x28804, 0x07328, 0x0732E, 0x073CF, 0x073AA, 0x20C3A, 0x26A2E, 0x073C9,
+    0x07449, 0x241E2, 0x216E7, 0x24A24, 0x06623, 0x036C5, 0x249B7, 0x2498D, 0x249FB, 0x073F7, 0x07415, 0x06903,
+    0x24A26, 0x07439, 0x205C3, 0x03ED7, 0x0745C, 0x228AD, 0x07460, 0x28EB2, 0x07447, 0x073E4, 0x07476, 0x083B9,
+    0x0746C, 0x03730, 0x07474, 0x093F1, 0x06A2C, 0x07482, 0x04953, 0x24A8C, 0x2415F, 0x24A79, 0x28B8F, 0x05B46,
+    0x28C03, 0x2189E, 0x074C8, 0x21988, 0x0750E, 0x074E9, 0x0751E, 0x28ED9, 0x21A4B, 0x05BD7, 0x28EAC, 0x09385,
+    0x0754D, 0x0754A, 0x07567, 0x0756E, 0x24F82, 0x03F04, 0x24D13, 0x0758E, 0x0745D, 0x0759E, 0x075B4, 0x07602,
+    0x0762C, 0x07651, 0x0764F, 0x0766F, 0x07676, 0x263F5, 0x07690, 0x081EF, 0x037F8, 0x26911, 0x2690E, 0x076A1,
+    0x076A5, 0x076B7, 0x076CC, 0x26F9F, 0x08462, 0x2509D, 0x2517D, 0x21E1C, 0x0771E, 0x07726, 0x07740, 0x064AF,
+    0x25220, 0x07758, 0x232AC, 0x077AF, 0x28964, 0x28968, 0x216C1, 0x077F4, 0x07809, 0x21376, 0x24A12, 0x068CA,
+    0x078AF, 0x078C7, 0x078D3, 0x096A5, 0x0792E, 0x255E0, 0x078D7, 0x07934, 0x078B1, 0x2760C, 0x08FB8, 0x08884,
+    0x28B2B, 0x26083, 0x2261C, 0x07986, 0x08900, 0x06902, 0x07980, 0x25857, 0x0799D, 0x27B39, 0x0793C, 0x079A9,
+    0x06E2A, 0x27126, 0x03EA8, 0x079C6, 0x2910D, 0x079D4
+];
+
+pub(crate) static BIG5_ENCODE: [u16; 14653_usize] = [
+    0x13EF, 0x2AC7, 0x1400, 0x1455, 0x1411, 0x13B0, 0x140F, 0x1410, 0x2C24, 0x2C25, 0x2C23, 0x2C1F, 0x2C21, 0x2C20, 0x2C27, 0x2C22,
+    0x2C1E, 0x2C26, 0x2AC8, 0x1536, 0x1534, 0x1535, 0x1537, 0x1403, 0x1533, 0x14DE, 0x14DF, 0x14E0, 0x14E1, 0x14E2, 0x14E3, 0x14E4,
+    0x14E5, 0x14E6, 0x14E7, 0x14E8, 0x14E9, 0x14EA, 0x14EB, 0x14EC, 0x14ED, 0x14EE, 0x14EF, 0x14F0, 0x14F1, 0x14F2, 0x14F3, 0x14F4,
+    0x14F5, 0x14F6, 0x14F7, 0x14F8, 0x14F9, 0x14FA, 0x14FB, 0x14FC, 0x14FD, 0x14FE, 0x14FF, 0x1500, 0x1501, 0x1502, 0x1503, 0x1504,
+    0x1505, 0x1506, 0x1507, 0x1508, 0x1509, 0x150A, 0x150B, 0x150C, 0x150D, 0x2B85, 0x2B7F, 0x2B80, 0x2B81, 0x2B82, 0x2B83, 0x2B84,
+    0x2B86, 0x2B87, 0x2B88, 0x2B89, 0x2B8A, 0x2B8B, 0x2B8C, 0x2B8D, 0x2B8E, 0x2B8F, 0x2B90, 0x2B91, 0x2B92, 0x2B93, 0x2B94, 0x2B95,
+    0x2B96, 0x2B97, 0x2B98, 0x2B99, 0x2B9A, 0x2B9B, 0x2B9C, 0x2B9D, 0x2B9E, 0x2B9F, 0x2BA0, 0x2BA1, 0x2BA2, 0x2BA3, 0x2BA4, 0x2BA5,
+    0x2BA7, 0x2BA8, 0x2BA9, 0x2BAA, 0x2BAB, 0x2BAC, 0x2BAD, 0x2BAE, 0x2BAF, 0x2BB0, 0x2BB1, 0x2BB2, 0x2BB3, 0x2BB4, 0x2BB5, 0x2BB6,
+    0x2BB7, 0x2BB8, 0x2BB9, 0x2BBA, 0x2BBB, 0x2BBC, 0x2BBD, 0x2BBE, 0x2BBF, 0x2BC0, 0x2BA6, 0x13B6, 0x13B8, 0x13E3, 0x13E4, 0x13E5,
+    0x13E6, 0x13AC, 0x13AB, 0x13A5, 0x13EA, 0x13E9, 0x13EE, 0x1559, 0x1447, 0x13FF, 0x1448, 0x2BFB, 0x2BFC, 0x1494, 0x1495, 0x1496,
+    0x1497, 0x1498, 0x1499, 0x149A, 0x149B, 0x149C, 0x149D, 0x2AA4, 0x2AA5, 0x2AA6, 0x2AA7, 0x2AA8, 0x2AA9, 0x2AAA, 0x2AAB, 0x2AAC,
+    0x2AAD, 0x1434, 0x1432, 0x1435, 0x1433, 0x1436, 0x1437, 0x1439, 0x1438, 0x2BC2, 0x2BC3, 0x2BC1, 0x143E, 0x1412, 0x1419, 0x1426,
+    0x1425, 0x143B, 0x143A, 0x1422, 0x1423, 0x142A, 0x142B, 0x142D, 0x142C, 0x141A, 0x1418, 0x141B, 0x1416, 0x1417, 0x1430, 0x1431,
+    0x1424, 0x1427, 0x1538, 0x1539, 0x153A, 0x153B, 0x153C, 0x153D, 0x153E, 0x153F, 0x1540, 0x1541, 0x1542, 0x1543, 0x1544, 0x1545,
+    0x1546, 0x1547, 0x1548, 0x1549, 0x154A, 0x154B, 0x154C, 0x154D, 0x154E, 0x154F, 0x1550, 0x1551, 0x1552, 0x1553, 0x1554, 0x1555,
+    0x1556, 0x1557, 0x1558, 0x2A90, 0x2A91, 0x2A92, 0x2A93, 0x2A94, 0x2A95, 0x2A96, 0x2A97, 0x2A98, 0x2A99, 0x2A9A, 0x2A9B, 0x2A9C,
+    0x2A9D, 0x2A9E, 0x2A9F, 0x2AA0, 0x2AA1, 0x2AA2, 0x2AA3, 0x1474, 0x1475, 0x1477, 0x1478, 0x1479, 0x147A, 0x1472, 0x1471, 0x1470,
+    0x146F, 0x146E, 0x4A2F, 0x4A2E, 0x4A1C, 0x4A25, 0x4A13, 0x4A1E, 0x4A27, 0x4A15, 0x4A22, 0x4A2B, 0x4A19, 0x4A24, 0x4A2D, 0x4A1B,
+    0x4A1F, 0x4A28, 0x4A16, 0x4A21, 0x4A2A, 0x4A18, 0x4A1D, 0x4A26, 0x4A14, 0x4A23, 0x4A2C, 0x4A1A, 0x4A20, 0x4A29, 0x4A17, 0x147B,
+    0x147C, 0x147E, 0x147D, 0x1487, 0x1488, 0x1489, 0x13BA, 0x145F, 0x1460, 0x1461, 0x1462, 0x1463, 0x1464, 0x1465, 0x1466, 0x146D,
+    0x146C, 0x146B, 0x146A, 0x1469, 0x1468, 0x1467, 0x1473, 0x1476, 0x13FB, 0x13FA, 0x13F4, 0x13F3, 0x13FD, 0x13FC, 0x13F9, 0x13F8,
+    0x13F1, 0x13F5, 0x13F2, 0x1483, 0x1484, 0x1486, 0x1485, 0x13F7, 0x13F6, 0x142E, 0x142F, 0x2AD5, 0x2BFF, 0x2C00, 0x2C01, 0x2C02,
+    0x2C03, 0x2C04, 0x2C05, 0x2C06, 0x2C07, 0x2C08, 0x2C09, 0x2C0A, 0x2C0B, 0x2C0C, 0x2C0D, 0x2C0E, 0x2C0F, 0x2C10, 0x2C11, 0x2C12,
+    0x2C13, 0x2C14, 0x2C15, 0x2C16, 0x2C17, 0x2C18, 0x2C19, 0x2C1A, 0x2ABC, 0x13A0, 0x13A2, 0x13A3, 0x13F0, 0x2ACF, 0x2AD0, 0x2AD1,
+    0x13D1, 0x13D2, 0x13CD, 0x13CE, 0x13D5, 0x13D6, 0x13D9, 0x13DA, 0x13C9, 0x13CA, 0x1442, 0x13C5, 0x13C6, 0x13E7, 0x13E8, 0x149E,
+    0x149F, 0x14A0, 0x14A1, 0x14A2, 0x14A3, 0x14A4, 0x14A5, 0x14A6, 0x2AD6, 0x2AD7, 0x2AD8, 0x2AD9, 0x2ADA, 0x2ADB, 0x2ADC, 0x2ADD,
+    0x2ADE, 0x2ADF, 0x2AE0, 0x2AE1, 0x2AE2, 0x2AE3, 0x2AE4, 0x2AE5, 0x2AE6, 0x2AE7, 0x2AE8, 0x2AE9, 0x2AEA, 0x2AEB, 0x2AEC, 0x2AED,
+    0x2AEE, 0x2AEF, 0x2AF0, 0x2AF1, 0x2AF2, 0x2AF3, 0x2AF4, 0x2AF5, 0x2AF6, 0x2AF7, 0x2AF8, 0x2AF9, 0x2AFA, 0x2AFB, 0x2AFC, 0x2AFD,
+    0x2AFE, 0x2AFF, 0x2B00, 0x2B01, 0x2B02, 0x2B03, 0x2B04, 0x2B05, 0x2B06, 0x2B07, 0x2B08, 0x2B09, 0x2B0A, 0x2B0B, 0x2B0C, 0x2B0D,
+    0x2B0E, 0x2B0F, 0x2B10, 0x2B11, 0x2B12, 0x2B13, 0x2B14, 0x2B15, 0x2B16, 0x2B17, 0x2B18, 0x2B19, 0x2B1A, 0x2B1B, 0x2B1C, 0x2B1D,
+    0x2B1E, 0x2B1F, 0x2B20, 0x2B21, 0x2B22, 0x2B23, 0x2B24, 0x2B25, 0x2B26, 0x2B27, 0x2B28, 0x2BFD, 0x2BFE, 0x2ACB, 0x2ACC, 0x2B29,
+    0x2B2A, 0x2B2B, 0x2B2C, 0x2B2D, 0x2B2E, 0x2B2F, 0x2B30, 0x2B31, 0x2B32, 0x2B33, 0x2B34, 0x2B35, 0x2B36, 0x2B37, 0x2B38, 0x2B39,
+    0x2B3A, 0x2B3B, 0x2B3C, 0x2B3D, 0x2B3E, 0x2B3F, 0x2B40, 0x2B41, 0x2B42, 0x2B43, 0x2B44, 0x2B45, 0x2B46, 0x2B47, 0x2B48, 0x2B49,
+    0x2B4A, 0x2B4B, 0x2B4C, 0x2B4D, 0x2B4E, 0x2B4F, 0x2B50, 0x2B51, 0x2B52, 0x2B53, 0x2B54, 0x2B55, 0x2B56, 0x2B57, 0x2B58, 0x2B59,
+    0x2B5A, 0x2B5B, 0x2B5C, 0x2B5D, 0x2B5E, 0x2B5F, 0x2B60, 0x2B61, 0x2B62, 0x2B63, 0x2B64, 0x2B65, 0x2B66, 0x2B67, 0x2B68, 0x2B69,
+    0x2B6A, 0x2B6B, 0x2B6C, 0x2B6D, 0x2B6E, 0x2B6F, 0x2B70, 0x2B71, 0x2B72, 0x2B73, 0x2B74, 0x2B75, 0x2B76, 0x2B77, 0x2B78, 0x2B79,
+    0x2B7A, 0x2B7B, 0x2B7C, 0x2B7D, 0x2B7E, 0x2AD2, 0x2AC9, 0x2ACA, 0x150E, 0x150F, 0x1510, 0x1511, 0x1512, 0x1513, 0x1514, 0x1515,
+    0x1516, 0x1517, 0x1518, 0x1519, 0x151A, 0x151B, 0x151C, 0x151D, 0x151E, 0x151F, 0x1520, 0x1521, 0x1522, 0x1523, 0x1524, 0x1525,
+    0x1526, 0x1527, 0x1528, 0x1529, 0x152A, 0x152B, 0x152C, 0x152D, 0x152E, 0x152F, 0x1530, 0x1531, 0x1532, 0x2BC4, 0x2BFA, 0x13FE,
+    0x1452, 0x1453, 0x144D, 0x144E, 0x144F, 0x1451, 0x1454, 0x1450, 0x1429, 0x1428, 0x144C, 0x4A5D, 0x4B02, 0x4B0C, 0x4AF0, 0x4B95,
+    0x4A3D, 0x4AF7, 0x4B0F, 0x4CCD, 0x4C4C, 0x4B26, 0x4B29, 0x4C6E, 0x4CE1, 0x4B31, 0x4C38, 0x4A60, 0x4D0C, 0x4B5A, 0x4B5F, 0x4B71,
+    0x4AA0, 0x4BB3, 0x4B93, 0x4C16, 0x4BC1, 0x4BD1, 0x4BCF, 0x4ABB, 0x4B78, 0x4BFB, 0x4C18, 0x4C1F, 0x4B60, 0x4C29, 0x4C2D, 0x4A78,
+    0x4C4D, 0x4C15, 0x4C7B, 0x4C78, 0x4B88, 0x4B2F, 0x4AD0, 0x4B94, 0x4C93, 0x4B99, 0x4CB6, 0x4D42, 0x4A9F, 0x4CD7, 0x4CFD, 0x4AB3,
+    0x4B39, 0x4BDE, 0x4C92, 0x4A3A, 0x4C1C, 0x2BC9, 0x4AA3, 0x4C46, 0x4C04, 0x4ADD, 0x4C74, 0x4ACD, 0x4B3A, 0x4B79, 0x4B4C, 0x4CBF,
+    0x4CE6, 0x4BDC, 0x4AD5, 0x4B43, 0x1577, 0x1579, 0x157A, 0x2C2D, 0x158D, 0x158B, 0x158E, 0x158C, 0x2C2E, 0x15B8, 0x2C37, 0x2C35,
+    0x15B7, 0x15B6, 0x1616, 0x1615, 0x1614, 0x1617, 0x1613, 0x1692, 0x1693, 0x184C, 0x158F, 0x15B9, 0x2C38, 0x15BA, 0x2C4B, 0x1739,
+    0x2DB1, 0x2AAE, 0x1590, 0x15BB, 0x1618, 0x2C4C, 0x2AAF, 0x2C28, 0x157B, 0x1592, 0x2C2F, 0x1593, 0x4AB8, 0x15BC, 0x1619, 0x161B,
+    0x161A, 0x1694, 0x1695, 0x184D, 0x1B56, 0x1578, 0x2BC6, 0x2C29, 0x157C, 0x1595, 0x1594, 0x1696, 0x184E, 0x1D1F, 0x32F2, 0x20E2,
+    0x381E, 0x381F, 0x2AB0, 0x157D, 0x15BE, 0x184F, 0x157E, 0x2C30, 0x1596, 0x15BF, 0x15C1, 0x2C39, 0x15C2, 0x15C0, 0x1697, 0x1850,
+    0x1851, 0x19C7, 0x2AB1, 0x1597, 0x15C3, 0x1698, 0x169A, 0x1699, 0x173A, 0x1852, 0x1853, 0x19C8, 0x19C9, 0x1B57, 0x3820, 0x4B68,
+    0x4835, 0x157F, 0x15C5, 0x15C4, 0x2C3A, 0x15C6, 0x15CC, 0x15C7, 0x15C8, 0x2C3C, 0x2C3B, 0x15CA, 0x15CB, 0x15C9, 0x161E, 0x161F,
+    0x1620, 0x1621, 0x161D, 0x1624, 0x2C52, 0x2C4E, 0x2ACE, 0x1625, 0x1635, 0x2C50, 0x1622, 0x1623, 0x161C, 0x2C4D, 0x2C4F, 0x16A7,
+    0x2C76, 0x16A4, 0x16A8, 0x2C80, 0x2C74, 0x16A5, 0x2C78, 0x16A6, 0x16A9, 0x169B, 0x2C77, 0x16AA, 0x2C7B, 0x2C7F, 0x2C7C, 0x2C79,
+    0x169C, 0x169E, 0x16AB, 0x16A0, 0x2C71, 0x16A3, 0x16A1, 0x16A2, 0x2C81, 0x2C7E, 0x2C75, 0x169F, 0x2C72, 0x169D, 0x2C7A, 0x2C7D,
+    0x2C73, 0x2CEB, 0x1750, 0x1743, 0x2CEC, 0x1740, 0x1752, 0x1748, 0x1747, 0x2CE0, 0x174B, 0x1746, 0x2CE5, 0x2CED, 0x2CE9, 0x1749,
+    0x174C, 0x173D, 0x1755, 0x2CE2, 0x2DBD, 0x173B, 0x1751, 0x173C, 0x1744, 0x1745, 0x2CE7, 0x2CE3, 0x174A, 0x1742, 0x2CDF, 0x173E,
+    0x2CEA, 0x1753, 0x1756, 0x1741, 0x174E, 0x1754, 0x173F, 0x2CE8, 0x174F, 0x2CEE, 0x2CE1, 0x174D, 0x2CE4, 0x2CE6, 0x1861, 0x2DBF,
+    0x2DCB, 0x1859, 0x2DCC, 0x1854, 0x185E, 0x1857, 0x2DB9, 0x185F, 0x2DB8, 0x2DBC, 0x2DC3, 0x2DC1, 0x1867, 0x1862, 0x2DB3, 0x2DB5,
+    0x1864, 0x1858, 0x2DB6, 0x2DC2, 0x2DC9, 0x185D, 0x2DBB, 0x2DB4, 0x185C, 0x2DB7, 0x1860, 0x2DBA, 0x185B, 0x1856, 0x1865, 0x2DC4,
+    0x1866, 0x2DC8, 0x2DC6, 0x2DCA, 0x1863, 0x2DBE, 0x2DB2, 0x2DC0, 0x185A, 0x2DC5, 0x1855, 0x2DC7, 0x4A4C, 0x19D8, 0x19CC, 0x2F1A,
+    0x2F22, 0x19CB, 0x19D3, 0x19DF, 0x2F28, 0x2F26, 0x2F21, 0x19CD, 0x2F27, 0x2F1D, 0x19DB, 0x19D2, 0x19DA, 0x2F18, 0x2F24, 0x2F1B,
+    0x19D6, 0x2F1C, 0x2F17, 0x19DD, 0x19D0, 0x19D9, 0x19CF, 0x2F19, 0x2F1E, 0x2F25, 0x19D7, 0x19D4, 0x2F20, 0x19DC, 0x2F23, 0x2F1F,
+    0x19D1, 0x19DE, 0x19D5, 0x19CE, 0x19CA, 0x2F29, 0x1B71, 0x1B5B, 0x1B6B, 0x1B70, 0x30E1, 0x30E0, 0x30E5, 0x30E6, 0x1B5E, 0x1B67,
+    0x1B74, 0x1B68, 0x30DA, 0x1B61, 0x30DB, 0x4A4E, 0x1B76, 0x1B6D, 0x1B58, 0x1B59, 0x30EE, 0x1D30, 0x1B66, 0x1B65, 0x30DC, 0x1B69,
+    0x3302, 0x1B60, 0x30E7, 0x1B6F, 0x1B6E, 0x1B64, 0x30DF, 0x30E8, 0x30D9, 0x1B63, 0x30E9, 0x1B6C, 0x30DD, 0x1B5A, 0x1B5D, 0x1B5C,
+    0x30EA, 0x1B6A, 0x1B5F, 0x1B73, 0x1B75, 0x30E4, 0x1B72, 0x30EC, 0x30DE, 0x30ED, 0x30E2, 0x30EB, 0x30E3, 0x1B62, 0x330A, 0x32FE,
+    0x1D24, 0x3303, 0x3309, 0x1D23, 0x32FC, 0x1D27, 0x3300, 0x32F9, 0x1D25, 0x32FD, 0x1D2A, 0x1D2F, 0x330E, 0x32F8, 0x1D2B, 0x330D,
+    0x1D26, 0x32FF, 0x1D22, 0x32FA, 0x32F6, 0x3304, 0x32F7, 0x32F5, 0x3301, 0x3307, 0x3308, 0x1D28, 0x3576, 0x3305, 0x32F4, 0x3306,
+    0x1D32, 0x330B, 0x1D31, 0x32F3, 0x32FB, 0x330C, 0x1D2D, 0x1D2C, 0x1D29, 0x1D2E, 0x1D20, 0x1D21, 0x1F10, 0x3579, 0x3572, 0x1F0D,
+    0x357A, 0x3570, 0x3573, 0x1F0C, 0x3574, 0x4C22, 0x1F0F, 0x3578, 0x356E, 0x356D, 0x1F11, 0x1F12, 0x1F0E, 0x1F13, 0x356C, 0x3577,
+    0x3575, 0x356F, 0x1F0B, 0x3571, 0x20E9, 0x20E3, 0x3824, 0x20EC, 0x382A, 0x382D, 0x20E5, 0x20E6, 0x3827, 0x20E4, 0x3830, 0x20EA,
+    0x3831, 0x382C, 0x20EB, 0x3821, 0x20E8, 0x3822, 0x382B, 0x3829, 0x3825, 0x20E7, 0x3823, 0x20ED, 0x3828, 0x382F, 0x3826, 0x382E,
+    0x22AC, 0x22A9, 0x22AA, 0x3ADA, 0x3AD2, 0x22A8, 0x22A5, 0x3AD3, 0x22A7, 0x3AD6, 0x3AD8, 0x3ADF, 0x4BA1, 0x3ADE, 0x3AD9, 0x22A4,
+    0x3AD1, 0x22A2, 0x3AD4, 0x22AD, 0x3AD7, 0x3ADB, 0x22A6, 0x22A3, 0x3ADD, 0x3ADC, 0x22AB, 0x3AD5, 0x2428, 0x3D41, 0x3D3E, 0x2429,
+    0x2427, 0x3D45, 0x3D42, 0x3D3B, 0x2426, 0x4A5C, 0x242A, 0x3D3C, 0x2425, 0x242D, 0x3D3F, 0x3D40, 0x242B, 0x242C, 0x3D46, 0x3D43,
+    0x3D44, 0x4A5E, 0x25B1, 0x3FC1, 0x25AE, 0x3FBE, 0x25B0, 0x25B2, 0x3FBF, 0x25AF, 0x3FC0, 0x3FBD, 0x26D9, 0x41FA, 0x26DA, 0x41F8,
+    0x41F9, 0x41F7, 0x41F6, 0x41FB, 0x26D8, 0x43CC, 0x43CD, 0x3D3D, 0x43CB, 0x26DB, 0x2899, 0x4539, 0x453A, 0x298C, 0x298D, 0x4783,
+    0x4782, 0x29E0, 0x29DF, 0x48CC, 0x1580, 0x1598, 0x15CE, 0x15CD, 0x1627, 0x1626, 0x16AE, 0x16AD, 0x16AF, 0x16AC, 0x1758, 0x1757,
+    0x1759, 0x1869, 0x1868, 0x186A, 0x19E0, 0x1456, 0x30EF, 0x1457, 0x1D33, 0x1459, 0x1458, 0x357B, 0x4A64, 0x145A, 0x22AE, 0x145B,
+    0x1581, 0x15CF, 0x16B0, 0x186B, 0x4A66, 0x1582, 0x15D2, 0x15D0, 0x15D1, 0x16B1, 0x175A, 0x186D, 0x186C, 0x186E, 0x1B77, 0x25B3,
+    0x2AB2, 0x2C36, 0x2BCB, 0x1628, 0x1629, 0x16B2, 0x2CEF, 0x19E2, 0x19E1, 0x30F1, 0x30F0, 0x1D34, 0x2AB3, 0x15D3, 0x2C3D, 0x2DCD,
+    0x19E3, 0x1B7A, 0x1B78, 0x1B79, 0x25B4, 0x2AB4, 0x162A, 0x16B3, 0x2C82, 0x4A72, 0x4A7B, 0x4A3C, 0x175B, 0x175C, 0x4A73, 0x2CF0,
+    0x2DCE, 0x186F, 0x2DCF, 0x30F3, 0x30F4, 0x1B7D, 0x4C60, 0x30F5, 0x4A75, 0x30F2, 0x1B7E, 0x1B7C, 0x1B7B, 0x30F6, 0x4A76, 0x330F,
+    0x4A77, 0x4A79, 0x357C, 0x3832, 0x3AE0, 0x242E, 0x25B5, 0x3FC2, 0x4C85, 0x1583, 0x1591, 0x4A7C, 0x4A7E, 0x1D35, 0x1F15, 0x22AF,
+    0x4BA9, 0x2C2A, 0x15D4, 0x162D, 0x162B, 0x162C, 0x1870, 0x1584, 0x1585, 0x2BC8, 0x1599, 0x15D5, 0x15D6, 0x15D7, 0x2C84, 0x162E,
+    0x4A83, 0x2C53, 0x16B7, 0x2C85, 0x16B5, 0x16B6, 0x2C83, 0x16B8, 0x16B4, 0x2CF1, 0x19AF, 0x2CF2, 0x2CF3, 0x175E, 0x175D, 0x4A84,
+    0x1761, 0x175F, 0x1760, 0x1876, 0x1875, 0x2DD4, 0x2DD1, 0x2DD2, 0x2DD0, 0x1877, 0x1873, 0x1872, 0x1874, 0x1871, 0x4A85, 0x1878,
+    0x19E5, 0x2F2A, 0x2DD3, 0x19EA, 0x2F2B, 0x19E6, 0x19E9, 0x19E8, 0x19E7, 0x19E4, 0x30F9, 0x1B81, 0x30FC, 0x1B7F, 0x4A87, 0x30F8,
+    0x1B82, 0x1B80, 0x1B83, 0x30FA, 0x30FB, 0x30F7, 0x30FD, 0x1F19, 0x1D36, 0x3310, 0x3312, 0x3311, 0x3313, 0x1D37, 0x1F16, 0x1F17,
+    0x1F18, 0x20EF, 0x3834, 0x3833, 0x3835, 0x3836, 0x20F0, 0x20EE, 0x3AE1, 0x3AE2, 0x22B1, 0x22B0, 0x3C34, 0x242F, 0x2430, 0x2431,
+    0x2433, 0x3D47, 0x3D48, 0x2432, 0x25B6, 0x25B7, 0x4A88, 0x453B, 0x4785, 0x4784, 0x48CD, 0x1586, 0x1630, 0x162F, 0x16B9, 0x2C86,
+    0x1763, 0x1764, 0x1762, 0x1765, 0x2CF4, 0x2CF5, 0x4A86, 0x4A89, 0x187A, 0x2DD5, 0x1879, 0x2F2C, 0x19EE, 0x2F2D, 0x19ED, 0x4A8A,
+    0x19EB, 0x19EC, 0x30FE, 0x4B34, 0x1D38, 0x3315, 0x1D3B, 0x3314, 0x1DF3, 0x1D3A, 0x1D39, 0x1F1C, 0x1F1B, 0x1F1A, 0x20F1, 0x4A8D,
+    0x20F4, 0x20F5, 0x20F3, 0x20F2, 0x3AE3, 0x3AE4, 0x3D4A, 0x2434, 0x3D49, 0x25B8, 0x41FC, 0x26DC, 0x453C, 0x292C, 0x2AB5, 0x159A,
+    0x15D8, 0x2C3E, 0x15D9, 0x15DA, 0x1631, 0x1632, 0x16BA, 0x2CF6, 0x2DD6, 0x2DD7, 0x19EF, 0x30FF, 0x1D3D, 0x1D3C, 0x357E, 0x357D,
+    0x1587, 0x15DB, 0x1633, 0x1D3E, 0x2C2B, 0x2C54, 0x1634, 0x2C88, 0x16BC, 0x16BB, 0x2C87, 0x1766, 0x1B84, 0x3316, 0x20F6, 0x3AE5,
+    0x22B2, 0x4A97, 0x3FC3, 0x4685, 0x2AB6, 0x15DC, 0x2DD8, 0x2F2E, 0x1D41, 0x1D3F, 0x1D40, 0x1588, 0x4A7D, 0x159B, 0x14A8, 0x15DF,
+    0x15DE, 0x15DD, 0x1637, 0x1636, 0x2C55, 0x2C89, 0x187E, 0x187B, 0x187D, 0x187C, 0x19F0, 0x1F1D, 0x1589, 0x15E0, 0x1639, 0x1638,
+    0x2CF7, 0x187F, 0x2AB7, 0x2C3F, 0x4AA4, 0x163B, 0x163A, 0x16BD, 0x16BE, 0x2CF8, 0x1767, 0x4AA5, 0x1768, 0x1880, 0x1881, 0x1882,
+    0x19F1, 0x2F2F, 0x4AA7, 0x1B85, 0x2C2C, 0x15E1, 0x2C8A, 0x2CF9, 0x2CFA, 0x2DD9, 0x4AAC, 0x2DDA, 0x2F31, 0x2F30, 0x2F33, 0x2F32,
+    0x19F2, 0x3317, 0x1B87, 0x3100, 0x1B86, 0x4AAE, 0x357F, 0x1F1E, 0x4B67, 0x3580, 0x4B6A, 0x4AAF, 0x3AE6, 0x22B3, 0x4AB0, 0x2435,
+    0x453D, 0x2AB8, 0x2C40, 0x163C, 0x4AB5, 0x1D42, 0x4AB6, 0x158A, 0x159C, 0x15E3, 0x15E2, 0x15E4, 0x1884, 0x1883, 0x1885, 0x4ABA,
+    0x19F3, 0x1B88, 0x4ABD, 0x3FC4, 0x27EC, 0x159D, 0x163E, 0x164D, 0x1648, 0x1643, 0x1642, 0x1649, 0x1647, 0x1640, 0x164E, 0x1641,
+    0x163D, 0x164C, 0x164B, 0x164A, 0x163F, 0x1646, 0x4AC0, 0x1645, 0x164F, 0x1644, 0x4AEB, 0x16C4, 0x16CA, 0x16C6, 0x16CC, 0x2C8B,
+    0x16C9, 0x16BF, 0x16C2, 0x16C5, 0x16C1, 0x16C8, 0x16CB, 0x16C0, 0x16C3, 0x16C7, 0x16CD, 0x4AC2, 0x2D03, 0x2D00, 0x1775, 0x2D01,
+    0x1769, 0x176B, 0x1783, 0x177E, 0x4C39, 0x2D08, 0x2D02, 0x176D, 0x176F, 0x2D07, 0x1776, 0x2CFD, 0x1782, 0x1784, 0x176A, 0x177B,
+    0x4AD6, 0x2CFB, 0x1781, 0x1772, 0x177C, 0x177D, 0x2CFC, 0x177A, 0x1778, 0x1779, 0x177F, 0x2D04, 0x176C, 0x1780, 0x2D06, 0x1774,
+    0x1771, 0x2CFF, 0x1770, 0x2D09, 0x1773, 0x1777, 0x176E, 0x2D05, 0x2CFE, 0x2DEB, 0x2DEA, 0x1897, 0x2DED, 0x2DEF, 0x2DE4, 0x2DE7,
+    0x2DEE, 0x1898, 0x2DE1, 0x2DE5, 0x4AC7, 0x2DE9, 0x2F42, 0x1893, 0x2F46, 0x1886, 0x2DE6, 0x1887, 0x1894, 0x188D, 0x1889, 0x2DE2,
+    0x188C, 0x1891, 0x189A, 0x2DE3, 0x2DDC, 0x188B, 0x2DDD, 0x2DDF, 0x188E, 0x1890, 0x2DDB, 0x2DE0, 0x1899, 0x1895, 0x2DE8, 0x189B,
+    0x4ACB, 0x1892, 0x2DDE, 0x188F, 0x188A, 0x1888, 0x2DEC, 0x1896, 0x2F41, 0x2F35, 0x2F44, 0x4ACC, 0x2F37, 0x19FA, 0x1A08, 0x19F6,
+    0x1A07, 0x19FF, 0x1A04, 0x19F4, 0x2F36, 0x2F3C, 0x1A03, 0x2F48, 0x1A05, 0x19FB, 0x2F3E, 0x2F3B, 0x19F9, 0x2F34, 0x1A06, 0x2F43,
+    0x19FE, 0x2F45, 0x1A09, 0x19F5, 0x1A00, 0x19FD, 0x2F39, 0x1A01, 0x2F3F, 0x2F40, 0x19FC, 0x1A02, 0x19F8, 0x19F7, 0x2F38, 0x2F3D,
+    0x2F47, 0x3114, 0x1B95, 0x3102, 0x3107, 0x1B8E, 0x1B99, 0x3105, 0x1B89, 0x1B93, 0x1B98, 0x310C, 0x1B94, 0x1B97, 0x310F, 0x1B8F,
+    0x3106, 0x3112, 0x3113, 0x1B91, 0x3111, 0x1B8D, 0x1B9C, 0x3109, 0x1B8B, 0x3116, 0x310A, 0x310E, 0x1B90, 0x1B9B, 0x310B, 0x1B96,
+    0x3110, 0x3117, 0x3326, 0x3115, 0x1B9D, 0x1B8A, 0x310D, 0x3104, 0x1B92, 0x3103, 0x3108, 0x3101, 0x1B9A, 0x331E, 0x4AC4, 0x1D55,
+    0x332C, 0x1D53, 0x1D50, 0x3323, 0x1D4C, 0x3327, 0x1D57, 0x331D, 0x3322, 0x3321, 0x1B8C, 0x1D52, 0x332A, 0x332D, 0x331A, 0x1F30,
+    0x332E, 0x1D58, 0x1D4A, 0x1D47, 0x3325, 0x1D44, 0x4AD4, 0x332B, 0x4A92, 0x1D4B, 0x332F, 0x331B, 0x3329, 0x1D4E, 0x331C, 0x331F,
+    0x3324, 0x4B13, 0x1D4F, 0x1D4D, 0x1D59, 0x1D54, 0x4ACA, 0x1D48, 0x1DE0, 0x1D49, 0x3320, 0x1D56, 0x1D51, 0x3328, 0x1D46, 0x1D45,
+    0x3318, 0x3319, 0x3585, 0x1F1F, 0x1F22, 0x3590, 0x1F36, 0x3593, 0x1F20, 0x358C, 0x1F26, 0x1F2C, 0x203F, 0x4AD8, 0x1F2A, 0x3589,
+    0x1F37, 0x1F23, 0x1F2B, 0x3591, 0x3844, 0x3596, 0x358A, 0x3581, 0x358E, 0x3588, 0x1F29, 0x3594, 0x1F25, 0x1F39, 0x1F32, 0x1F27,
+    0x1F24, 0x1F2F, 0x3595, 0x3587, 0x358D, 0x358F, 0x3583, 0x3592, 0x1F21, 0x3582, 0x4AD9, 0x1F28, 0x1F38, 0x1F34, 0x3584, 0x1F2E,
+    0x1F35, 0x1F31, 0x1F2D, 0x358B, 0x1F33, 0x3842, 0x383E, 0x384D, 0x3837, 0x3840, 0x2104, 0x2105, 0x20FD, 0x3849, 0x2107, 0x383C,
+    0x383B, 0x3839, 0x384B, 0x20FB, 0x3845, 0x383A, 0x20FE, 0x3843, 0x20F9, 0x383F, 0x3846, 0x3848, 0x384C, 0x2102, 0x3838, 0x20FC,
+    0x383D, 0x20F7, 0x2103, 0x3847, 0x20FF, 0x2100, 0x2106, 0x20FA, 0x145C, 0x20F8, 0x3841, 0x2101, 0x384A, 0x22C3, 0x22BE, 0x3AF5,
+    0x3AF1, 0x3AEB, 0x22B8, 0x22B4, 0x3AF4, 0x22B5, 0x3AEE, 0x3AF0, 0x3AF3, 0x4ADA, 0x22BA, 0x22C1, 0x22BB, 0x3AE9, 0x22BC, 0x22BD,
+    0x3AEC, 0x22C2, 0x3AEA, 0x3AEF, 0x22B9, 0x3AE8, 0x22BF, 0x22B7, 0x22B6, 0x3AED, 0x3AF2, 0x22C0, 0x3AE7, 0x243C, 0x3D5B, 0x3D58,
+    0x2436, 0x2442, 0x2443, 0x2439, 0x3D56, 0x243B, 0x3D4E, 0x2441, 0x4AE1, 0x3D5A, 0x2438, 0x3D5C, 0x2437, 0x3D57, 0x3D59, 0x243A,
+    0x3D55, 0x3D4F, 0x3D4C, 0x3586, 0x3D52, 0x3D4B, 0x3D51, 0x3D50, 0x3D4D, 0x4AE6, 0x243E, 0x4AE7, 0x243D, 0x243F, 0x3D53, 0x25B9,
+    0x3D54, 0x3FCD, 0x3FC6, 0x25C5, 0x3FCA, 0x25BD, 0x25C1, 0x3FC9, 0x25C0, 0x25BC, 0x25BF, 0x25BA, 0x25C4, 0x3FCB, 0x3FC7, 0x25C3,
+    0x3FC5, 0x25C2, 0x3FCC, 0x3FC8, 0x2440, 0x25C6, 0x3FCE, 0x25BE, 0x25BB, 0x4203, 0x4205, 0x26DE, 0x4206, 0x4204, 0x4202, 0x4201,
+    0x26E0, 0x4200, 0x26E1, 0x41FE, 0x41FF, 0x26DD, 0x26E2, 0x26DF, 0x41FD, 0x27ED, 0x43D0, 0x43CE, 0x43D3, 0x43D1, 0x43CF, 0x43D2,
+    0x289A, 0x4540, 0x4541, 0x292D, 0x4542, 0x453E, 0x4543, 0x453F, 0x27EE, 0x4686, 0x4688, 0x2930, 0x4687, 0x292F, 0x292E, 0x2931,
+    0x4787, 0x4788, 0x298F, 0x298E, 0x2990, 0x4786, 0x4837, 0x4836, 0x29E1, 0x29E3, 0x29E2, 0x4838, 0x2A1A, 0x493F, 0x2A43, 0x493E,
+    0x4987, 0x2C31, 0x1651, 0x1650, 0x16D0, 0x16CF, 0x2C8D, 0x16CE, 0x2C8C, 0x1787, 0x2D0C, 0x2D0B, 0x1785, 0x1788, 0x4AEE, 0x2D0A,
+    0x4AED, 0x1786, 0x4AE9, 0x2DF0, 0x2DF1, 0x189C, 0x1A0A, 0x4AEC, 0x3118, 0x3119, 0x1B9E, 0x1B9F, 0x3331, 0x1D5A, 0x1D5C, 0x3330,
+    0x1D5B, 0x3597, 0x1F3A, 0x2108, 0x2109, 0x384E, 0x22C5, 0x22C4, 0x3D5D, 0x3FD0, 0x3FCF, 0x49C3, 0x159E, 0x2C41, 0x2C56, 0x2C57,
+    0x16D3, 0x16D7, 0x2C8F, 0x16D5, 0x16D4, 0x2C8E, 0x16D6, 0x16D2, 0x16D1, 0x2C90, 0x1792, 0x178F, 0x178B, 0x2D0D, 0x4AF6, 0x4B08,
+    0x2D0E, 0x4AF2, 0x178D, 0x2D10, 0x1789, 0x2D11, 0x2D0F, 0x178C, 0x178E, 0x1791, 0x1790, 0x178A, 0x2D12, 0x18A1, 0x2DFE, 0x18A3,
+    0x18A2, 0x2DFF, 0x18A0, 0x189F, 0x2DF5, 0x2DF4, 0x2DF2, 0x2DF7, 0x2DF6, 0x2DF3, 0x2DFC, 0x2DFD, 0x2DFA, 0x2DF8, 0x189E, 0x2DFB,
+    0x18A4, 0x2E00, 0x2DF9, 0x1A0B, 0x189D, 0x1A0C, 0x2F4D, 0x2F53, 0x4B96, 0x1A12, 0x2F51, 0x2F57, 0x2F4E, 0x2F52, 0x2F54, 0x2F56,
+    0x2F50, 0x2F4F, 0x2F4A, 0x2F4B, 0x1A0D, 0x1A0F, 0x1A0E, 0x2F4C, 0x2F55, 0x1A11, 0x2F49, 0x3123, 0x3122, 0x3127, 0x311E, 0x3121,
+    0x3120, 0x3124, 0x3128, 0x1BA0, 0x1BA3, 0x311F, 0x3125, 0x1BA2, 0x311A, 0x1A10, 0x333F, 0x3126, 0x311D, 0x1BA1, 0x311C, 0x3336,
+    0x1D5D, 0x1D61, 0x3346, 0x3334, 0x3342, 0x1D62, 0x3344, 0x4AF9, 0x334A, 0x334E, 0x3345, 0x3339, 0x3341, 0x334F, 0x334D, 0x3343,
+    0x333E, 0x3337, 0x359F, 0x3335, 0x1D66, 0x333C, 0x1D67, 0x1D63, 0x3332, 0x3348, 0x333A, 0x4AB9, 0x3338, 0x334B, 0x1D64, 0x4AFB,
+    0x3351, 0x1D5E, 0x1D60, 0x3340, 0x333B, 0x1BA4, 0x1D5F, 0x333D, 0x334C, 0x3350, 0x3347, 0x3349, 0x3333, 0x359A, 0x35A3, 0x35A2,
+    0x1F42, 0x359B, 0x1F43, 0x1F41, 0x359D, 0x1F3E, 0x35A1, 0x4AFE, 0x359C, 0x359E, 0x3598, 0x1F3C, 0x35AB, 0x35AA, 0x35A7, 0x1F3B,
+    0x1F3F, 0x1F40, 0x311B, 0x35A4, 0x1F3D, 0x1D65, 0x35A6, 0x3599, 0x35A9, 0x35A8, 0x35AC, 0x385C, 0x35A5, 0x35A0, 0x3854, 0x2113,
+    0x2116, 0x2111, 0x3853, 0x3857, 0x3852, 0x210B, 0x2115, 0x384F, 0x210F, 0x3856, 0x210D, 0x210C, 0x3859, 0x210E, 0x385B, 0x4B00,
+    0x3858, 0x210A, 0x2114, 0x385D, 0x3851, 0x385A, 0x3850, 0x2110, 0x2112, 0x3855, 0x385E, 0x3AFD, 0x22C6, 0x3B03, 0x22CB, 0x3AFF,
+    0x3B06, 0x3AF7, 0x22CD, 0x22C7, 0x3AFC, 0x2444, 0x3AFB, 0x3B04, 0x22C8, 0x22CC, 0x3AFA, 0x3B00, 0x3B05, 0x3AF6, 0x22CA, 0x3AFE,
+    0x3B02, 0x3B08, 0x3AF8, 0x3B01, 0x22C9, 0x3B07, 0x3AF9, 0x2448, 0x3D5F, 0x2446, 0x2445, 0x3D61, 0x3D66, 0x3D62, 0x3D65, 0x244B,
+    0x25AC, 0x244A, 0x4B01, 0x3D5E, 0x3D64, 0x2449, 0x3D63, 0x3D60, 0x2447, 0x3FD5, 0x4A0F, 0x3FD7, 0x3FD2, 0x25C8, 0x3FD4, 0x25C7,
+    0x3FD6, 0x25CA, 0x3FD8, 0x25C9, 0x3FD1, 0x3FD3, 0x26E6, 0x4209, 0x26E5, 0x420A, 0x26E4, 0x4208, 0x26E3, 0x4207, 0x27F0, 0x27EF,
+    0x4544, 0x4546, 0x4B04, 0x4545, 0x289B, 0x289C, 0x4B03, 0x289D, 0x4689, 0x2932, 0x48CF, 0x48CE, 0x2A44, 0x159F, 0x15E5, 0x1793,
+    0x2F58, 0x1F44, 0x1F45, 0x4B06, 0x385F, 0x22CE, 0x3B09, 0x3D67, 0x2C42, 0x2D13, 0x2AB9, 0x2E01, 0x2F59, 0x3129, 0x1BA5, 0x4CAB,
+    0x4547, 0x2991, 0x15A0, 0x1652, 0x2C58, 0x16D8, 0x16D9, 0x18A5, 0x4B0D, 0x1D68, 0x22D0, 0x22D1, 0x22CF, 0x15A1, 0x15E6, 0x15E8,
+    0x15E7, 0x2C43, 0x15E9, 0x1653, 0x2C59, 0x1654, 0x16DA, 0x16DB, 0x2C91, 0x1794, 0x2D14, 0x18A9, 0x2E02, 0x18A7, 0x18A8, 0x18A6,
+    0x312A, 0x1A16, 0x1A15, 0x1A17, 0x1A14, 0x2F5A, 0x18AA, 0x1A13, 0x1BA6, 0x1BA7, 0x1BA8, 0x3352, 0x1F46, 0x35AD, 0x1D69, 0x2117,
+    0x22D3, 0x22D2, 0x3B0A, 0x244C, 0x25CB, 0x43D4, 0x4839, 0x48D0, 0x15A2, 0x1655, 0x1656, 0x2C97, 0x16DD, 0x16E0, 0x2C95, 0x2C93,
+    0x16DF, 0x2C96, 0x2C98, 0x2C92, 0x16E2, 0x16E1, 0x16DE, 0x16DC, 0x2C94, 0x179F, 0x179C, 0x2D19, 0x2D1C, 0x2D1B, 0x1796, 0x179E,
+    0x179B, 0x2D18, 0x2D16, 0x179A, 0x1795, 0x1798, 0x2D17, 0x2D1E, 0x2D1A, 0x1799, 0x179D, 0x17A0, 0x2D15, 0x2D1D, 0x1797, 0x18AF,
+    0x18B7, 0x2E0E, 0x2E07, 0x18B8, 0x2E12, 0x2E03, 0x2E0A, 0x18AE, 0x2E04, 0x18AC, 0x2E0B, 0x2E0F, 0x18AB, 0x2E10, 0x2E09, 0x2E0C,
+    0x18BA, 0x18B1, 0x2E13, 0x2E11, 0x4B16, 0x18B6, 0x18B4, 0x2E08, 0x18B3, 0x2E06, 0x2E05, 0x18B2, 0x18B0, 0x18B9, 0x18B5, 0x18AD,
+    0x2E0D, 0x1A19, 0x4B1B, 0x1A20, 0x2F68, 0x1A18, 0x2F60, 0x2F5C, 0x2F6C, 0x2F5B, 0x1A1B, 0x2F65, 0x1A1E, 0x1A21, 0x1A1C, 0x2F69,
+    0x1A1F, 0x1BAF, 0x2F6F, 0x2F5D, 0x2F5F, 0x2F66, 0x2F6A, 0x2F6E, 0x2F6B, 0x2F64, 0x2F67, 0x4B22, 0x2F61, 0x1A23, 0x2F63, 0x2F62,
+    0x2F6D, 0x1A1A, 0x2F5E, 0x1A22, 0x1A1D, 0x1BB5, 0x3132, 0x1BB4, 0x3130, 0x1BA9, 0x1BAE, 0x312F, 0x312C, 0x3131, 0x1BAA, 0x312B,
+    0x1BAD, 0x1BAB, 0x3133, 0x1BAC, 0x1BB0, 0x1BB1, 0x4B12, 0x1BB3, 0x1BB2, 0x312D, 0x312E, 0x3134, 0x3359, 0x1D6A, 0x35BB, 0x3358,
+    0x336B, 0x1D70, 0x3369, 0x1D6F, 0x1D6B, 0x3372, 0x3362, 0x3365, 0x1D73, 0x336F, 0x3367, 0x1D6C, 0x1D74, 0x336C, 0x336A, 0x335B,
+    0x3370, 0x3364, 0x335F, 0x3355, 0x3371, 0x3361, 0x3354, 0x1D72, 0x3366, 0x3373, 0x3363, 0x3357, 0x335C, 0x3353, 0x1D71, 0x4B25,
+    0x3360, 0x335D, 0x1D6D, 0x3356, 0x336E, 0x1D6E, 0x335E, 0x335A, 0x336D, 0x1F47, 0x35B4, 0x35B1, 0x35C2, 0x35B6, 0x35C3, 0x1F49,
+    0x35BF, 0x35BC, 0x4C99, 0x35BD, 0x35C0, 0x35C4, 0x3368, 0x35C6, 0x3873, 0x4C6B, 0x1F4A, 0x35C7, 0x35AF, 0x35B9, 0x4B2A, 0x35BE,
+    0x1F48, 0x1F4B, 0x35C5, 0x35C8, 0x35B3, 0x35B0, 0x4BA2, 0x35B2, 0x35B7, 0x35B5, 0x1F4C, 0x35C1, 0x4B24, 0x35B8, 0x35BA, 0x35AE,
+    0x3867, 0x3865, 0x2120, 0x211E, 0x386F, 0x3866, 0x3870, 0x386C, 0x3864, 0x3872, 0x3863, 0x386A, 0x211D, 0x211C, 0x211B, 0x3868,
+    0x386D, 0x2118, 0x211F, 0x3861, 0x386B, 0x3860, 0x3869, 0x2119, 0x386E, 0x3862, 0x211A, 0x3871, 0x4B2C, 0x3B0E, 0x22D8, 0x22D7,
+    0x22D9, 0x3B1A, 0x3B10, 0x3B16, 0x3B0B, 0x3B19, 0x3B18, 0x3B1C, 0x3B15, 0x22D4, 0x3B14, 0x22DA, 0x3B0D, 0x22D5, 0x3B1B, 0x22D6,
+    0x3B0F, 0x3B12, 0x3B17, 0x3B11, 0x3B0C, 0x3B13, 0x3D69, 0x2450, 0x3D6C, 0x3D6B, 0x3D6E, 0x3D70, 0x4A12, 0x244E, 0x3D6A, 0x3D68,
+    0x3D71, 0x3D6F, 0x3D6D, 0x3D73, 0x3D72, 0x2452, 0x244D, 0x244F, 0x2451, 0x3D74, 0x3FDF, 0x3FDE, 0x3FDD, 0x3FE0, 0x3FD9, 0x3FDA,
+    0x3FE2, 0x3FDB, 0x25CC, 0x3FE4, 0x3FE3, 0x3FDC, 0x420E, 0x26E9, 0x420C, 0x4211, 0x4210, 0x3FE1, 0x26E8, 0x420F, 0x420B, 0x4213,
+    0x4212, 0x26E7, 0x420D, 0x25CD, 0x27F1, 0x43D5, 0x4548, 0x4549, 0x454A, 0x2933, 0x2934, 0x468A, 0x4CEB, 0x478A, 0x4789, 0x483A,
+    0x483B, 0x48D1, 0x4940, 0x15A3, 0x15A4, 0x15A5, 0x15EA, 0x1657, 0x2C99, 0x16E3, 0x16E4, 0x17A3, 0x17A4, 0x17A2, 0x17A1, 0x18BB,
+    0x2E14, 0x18BD, 0x18BC, 0x2E15, 0x1A24, 0x1BB6, 0x3135, 0x3375, 0x1D75, 0x1F4E, 0x3374, 0x1F4D, 0x22DB, 0x4B35, 0x3B1D, 0x25CE,
+    0x26EA, 0x4214, 0x4B37, 0x2935, 0x29E4, 0x2ABA, 0x2C5A, 0x4A6E, 0x1658, 0x2C5B, 0x16E7, 0x16E5, 0x16E6, 0x16E8, 0x17A6, 0x17A5,
+    0x2D1F, 0x17A7, 0x2D20, 0x2E16, 0x2E17, 0x18BE, 0x18C0, 0x18C2, 0x18BF, 0x18C3, 0x18C1, 0x4B42, 0x1A28, 0x1A25, 0x1A27, 0x1A29,
+    0x1A26, 0x3136, 0x2F70, 0x4BAC, 0x3138, 0x3137, 0x1BBC, 0x1BB8, 0x1BB9, 0x1BBB, 0x1BBD, 0x1BBA, 0x1BBF, 0x1BBE, 0x1D7A, 0x3377,
+    0x3376, 0x1D79, 0x1D78, 0x1D77, 0x1D7B, 0x1D76, 0x35CE, 0x35CB, 0x1F50, 0x35CA, 0x35CF, 0x1F52, 0x35CD, 0x1F4F, 0x1F51, 0x35CC,
+    0x4B3C, 0x3874, 0x4CF5, 0x3875, 0x3876, 0x22DC, 0x22E4, 0x3B1E, 0x22DE, 0x22E2, 0x3B1F, 0x22E3, 0x22DF, 0x22E0, 0x22DD, 0x22E1,
+    0x2455, 0x35C9, 0x2456, 0x2454, 0x2453, 0x3FE5, 0x25CF, 0x4215, 0x4216, 0x4B41, 0x289E, 0x2936, 0x15A6, 0x16E9, 0x4B07, 0x1A2A,
+    0x3139, 0x1BC0, 0x4B44, 0x1D7E, 0x1D7D, 0x1D7C, 0x1F53, 0x1F54, 0x35D0, 0x22E5, 0x25D0, 0x15A7, 0x2C44, 0x15EB, 0x2C5C, 0x4B46,
+    0x4B48, 0x2C9A, 0x16EA, 0x18C4, 0x3877, 0x15A8, 0x15EC, 0x2C9B, 0x2D21, 0x2D22, 0x17A8, 0x35D1, 0x1F55, 0x3878, 0x26EB, 0x15A9,
+    0x15BD, 0x15ED, 0x2C5D, 0x1659, 0x17AC, 0x17AB, 0x17A9, 0x17AA, 0x2E18, 0x18C6, 0x18C7, 0x2E19, 0x18C5, 0x4B4B, 0x1A2E, 0x2F71,
+    0x1A2D, 0x1A2B, 0x1A2C, 0x1BC3, 0x1BC1, 0x313B, 0x1BC2, 0x313A, 0x1BB7, 0x3378, 0x1D80, 0x1D81, 0x1D7F, 0x22E6, 0x3B20, 0x2457,
+    0x2458, 0x3D75, 0x26EC, 0x43D6, 0x43D7, 0x2992, 0x4941, 0x2C33, 0x15EE, 0x15AA, 0x2C5F, 0x2C5E, 0x16EB, 0x2C9D, 0x2C9E, 0x2C9C,
+    0x2C9F, 0x4B4D, 0x2D2A, 0x2D25, 0x2D27, 0x2D29, 0x2D26, 0x17B0, 0x2D23, 0x2D24, 0x17AD, 0x17AE, 0x2D28, 0x2D2B, 0x17AF, 0x2D2C,
+    0x4C9C, 0x2E27, 0x2E21, 0x2E1C, 0x18C9, 0x2E24, 0x2E22, 0x2E1B, 0x2E28, 0x2E2B, 0x2E26, 0x2E1F, 0x18CB, 0x2E25, 0x18CC, 0x2E20,
+    0x2E23, 0x2E1A, 0x2E1E, 0x2E2A, 0x18CD, 0x18CE, 0x2E1D, 0x2E29, 0x18C8, 0x18CA, 0x4B57, 0x2F82, 0x2F7B, 0x2F81, 0x2F7A, 0x2F7C,
+    0x2F76, 0x2F74, 0x2F83, 0x2F80, 0x2F72, 0x1A30, 0x2F7E, 0x2F7F, 0x2F7D, 0x2F75, 0x2F73, 0x1A2F, 0x2F79, 0x2F77, 0x2F78, 0x2F84,
+    0x1BC8, 0x4B4E, 0x1BC7, 0x313C, 0x1BC4, 0x313E, 0x4B4F, 0x1BC9, 0x313F, 0x1BCC, 0x1BCA, 0x3140, 0x2F85, 0x3142, 0x1BC6, 0x1BC5,
+    0x313D, 0x3141, 0x1BCB, 0x1D83, 0x1D82, 0x337A, 0x337E, 0x3380, 0x1D84, 0x3383, 0x4B53, 0x1D88, 0x3385, 0x1D8A, 0x1D86, 0x1D8E,
+    0x4B54, 0x1D8B, 0x337C, 0x1D85, 0x337B, 0x3379, 0x3387, 0x337D, 0x1D87, 0x3386, 0x1D8C, 0x3382, 0x3381, 0x1D8D, 0x337F, 0x1D89,
+    0x3388, 0x3384, 0x35E0, 0x35E7, 0x35DC, 0x1F58, 0x35D8, 0x35E8, 0x35D2, 0x35E5, 0x35E3, 0x35DD, 0x35E6, 0x35DF, 0x35D7, 0x35E9,
+    0x35D5, 0x35E2, 0x35D3, 0x35EA, 0x4B55, 0x1F59, 0x35E4, 0x387B, 0x35D6, 0x1F56, 0x35DA, 0x1F57, 0x35D9, 0x35DE, 0x35DB, 0x35E1,
+    0x387F, 0x3882, 0x387A, 0x387C, 0x3881, 0x3880, 0x2121, 0x35D4, 0x387E, 0x2122, 0x3879, 0x387D, 0x3B27, 0x3B2E, 0x3B25, 0x3B2C,
+    0x3B23, 0x3B2B, 0x3B2F, 0x3B22, 0x3B26, 0x3B21, 0x22E7, 0x3B24, 0x22E8, 0x3B2A, 0x3B29, 0x3B28, 0x4B50, 0x3B2D, 0x3D79, 0x3D7B,
+    0x245A, 0x3D7C, 0x3D77, 0x3D76, 0x3D80, 0x3D7E, 0x2459, 0x3D81, 0x3D78, 0x3D7D, 0x3D7F, 0x3D7A, 0x3FE9, 0x3FEE, 0x3FE8, 0x3FED,
+    0x3FE6, 0x3FF0, 0x3FEC, 0x3FF1, 0x3FEB, 0x3FE7, 0x3FEF, 0x3FF2, 0x3FEA, 0x4B87, 0x4217, 0x26F0, 0x26EE, 0x26ED, 0x26EF, 0x43D8,
+    0x44E2, 0x454B, 0x468B, 0x468C, 0x2937, 0x478B, 0x2993, 0x478C, 0x483D, 0x29E6, 0x29E5, 0x483C, 0x2A1B, 0x4B58, 0x48D2, 0x2ABB,
+    0x15AB, 0x16EC, 0x2CA0, 0x2D2D, 0x183E, 0x1D8F, 0x15AC, 0x165C, 0x165B, 0x165A, 0x17B1, 0x1BCD, 0x3883, 0x15AD, 0x15AE, 0x15AF,
+    0x15EF, 0x1A31, 0x2F86, 0x1F5A, 0x15B0, 0x2C45, 0x165D, 0x165E, 0x2C60, 0x16ED, 0x4B6C, 0x2D2E, 0x17B2, 0x2D2F, 0x18D4, 0x2E2D,
+    0x18D2, 0x18D1, 0x2E2C, 0x18CF, 0x2E2E, 0x18D0, 0x18D3, 0x1A32, 0x1A34, 0x2F8A, 0x2F87, 0x2F88, 0x2F89, 0x2F8B, 0x1A33, 0x3144,
+    0x3143, 0x1BCF, 0x1BCE, 0x4B61, 0x1D92, 0x338A, 0x1D91, 0x1D93, 0x1D90, 0x1F5C, 0x3389, 0x1F5D, 0x35EC, 0x1F5E, 0x35EB, 0x1F5B,
+    0x3886, 0x3888, 0x2123, 0x3887, 0x3885, 0x3884, 0x3B32, 0x22ED, 0x22EB, 0x22EC, 0x3B30, 0x3B31, 0x22E9, 0x3D85, 0x3D83, 0x245C,
+    0x3D84, 0x245D, 0x245B, 0x22EA, 0x3FF5, 0x3FF3, 0x3FF4, 0x3D82, 0x4219, 0x26F1, 0x4218, 0x43D9, 0x43DA, 0x3FF6, 0x454C, 0x15B1,
+    0x165F, 0x16EF, 0x2CA1, 0x16EE, 0x18D5, 0x2124, 0x4B64, 0x15F0, 0x1660, 0x1A35, 0x1F5F, 0x2ABD, 0x2C61, 0x2C62, 0x4B33, 0x2CA2,
+    0x17B4, 0x2D33, 0x2D31, 0x17B5, 0x2D30, 0x2D32, 0x2D34, 0x17B3, 0x18D9, 0x18DA, 0x18D7, 0x18D6, 0x2F8F, 0x18D8, 0x1A36, 0x2F8E,
+    0x2F90, 0x2F8D, 0x2F91, 0x1A37, 0x1BD2, 0x3145, 0x3147, 0x1BD0, 0x3148, 0x1BD1, 0x3146, 0x2F8C, 0x338B, 0x338E, 0x338F, 0x338C,
+    0x1D97, 0x1D96, 0x1D94, 0x1D95, 0x338D, 0x1D98, 0x1F61, 0x1F62, 0x1F63, 0x3889, 0x388B, 0x388D, 0x2126, 0x2125, 0x1F60, 0x388C,
+    0x388A, 0x3B36, 0x4B69, 0x3B34, 0x3B3A, 0x22EE, 0x3B3B, 0x3B38, 0x22EF, 0x3B35, 0x3B33, 0x3B39, 0x245F, 0x3D87, 0x3B37, 0x2461,
+    0x3D88, 0x2460, 0x2463, 0x3D89, 0x245E, 0x2462, 0x3FFB, 0x3FF9, 0x3FF8, 0x3FFA, 0x3FF7, 0x28A0, 0x468D, 0x468E, 0x478D, 0x483E,
+    0x2A66, 0x2ABE, 0x18DB, 0x17B6, 0x4B6F, 0x1A38, 0x4B70, 0x4B72, 0x15B2, 0x15F1, 0x1661, 0x2CA3, 0x17B7, 0x2D35, 0x2F92, 0x1A39,
+    0x22F0, 0x15B3, 0x4B75, 0x16F0, 0x2127, 0x15B4, 0x15F2, 0x15F3, 0x1663, 0x1662, 0x2CA4, 0x16F1, 0x2D36, 0x17B8, 0x2E30, 0x2E31,
+    0x2E32, 0x18DC, 0x18DD, 0x2E2F, 0x18DE, 0x1A3A, 0x2F93, 0x314A, 0x1BD3, 0x3149, 0x1D99, 0x3390, 0x1D9A, 0x3391, 0x4B7B, 0x1F64,
+    0x388E, 0x3B3D, 0x3B3C, 0x22F1, 0x2464, 0x3D8A, 0x25D1, 0x3FFC, 0x26F2, 0x29E7, 0x48D4, 0x2ABF, 0x2E33, 0x2F94, 0x1D9B, 0x35ED,
+    0x2128, 0x27F2, 0x2AC0, 0x17BA, 0x4B80, 0x17B9, 0x1A3B, 0x314B, 0x1D9D, 0x1EAD, 0x1D9E, 0x1D9C, 0x1F65, 0x3B3E, 0x22F2, 0x2465,
+    0x4B83, 0x2C34, 0x2CA5, 0x2D38, 0x17BB, 0x2D37, 0x17BC, 0x4A82, 0x18E2, 0x2E36, 0x2E35, 0x18E1, 0x18DF, 0x18E0, 0x2E34, 0x1A3D,
+    0x2F95, 0x1A40, 0x1A3C, 0x1A42, 0x1A3E, 0x1A3F, 0x1A41, 0x1BD6, 0x1BD5, 0x1BD4, 0x3393, 0x1D9F, 0x1DA2, 0x1DA0, 0x3392, 0x1DA5,
+    0x1DA1, 0x3394, 0x1DA4, 0x1DA3, 0x4A56, 0x35EF, 0x35EE, 0x4B86, 0x1F68, 0x1F66, 0x1F67, 0x35F0, 0x2129, 0x3890, 0x212A, 0x388F,
+    0x3D8B, 0x2467, 0x3B3F, 0x2466, 0x22F3, 0x421B, 0x3FFD, 0x26F3, 0x421A, 0x454D, 0x468F, 0x4690, 0x15F4, 0x1664, 0x2C63, 0x17BE,
+    0x17C0, 0x2CA8, 0x2D3B, 0x2D3A, 0x2D39, 0x2CA7, 0x2CA6, 0x16F3, 0x17BF, 0x17BD, 0x16F2, 0x18E3, 0x2E37, 0x4BE2, 0x18E4, 0x2D40,
+    0x2D42, 0x2D41, 0x2E38, 0x2D3D, 0x17C4, 0x17C2, 0x2D3C, 0x2D3E, 0x2D44, 0x17C1, 0x2D3F, 0x2D48, 0x18E6, 0x2D45, 0x17C3, 0x2D43,
+    0x2D46, 0x18E5, 0x18E7, 0x2D47, 0x2E4C, 0x2E3F, 0x2E3D, 0x2E4B, 0x2E46, 0x1A47, 0x18E8, 0x2E47, 0x2E4A, 0x1A43, 0x2E49, 0x18E9,
+    0x18EE, 0x18EC, 0x2E40, 0x2E3B, 0x2E42, 0x18F3, 0x2E4D, 0x1A44, 0x2E43, 0x1A45, 0x18EF, 0x2E45, 0x4BB6, 0x2FA4, 0x1A46, 0x2E3A,
+    0x18F0, 0x1A48, 0x18F1, 0x18ED, 0x18F2, 0x2E44, 0x2E39, 0x2E48, 0x18EA, 0x4B90, 0x2E3C, 0x2E41, 0x2E3E, 0x18EB, 0x2F96, 0x2F97,
+    0x2FA1, 0x314F, 0x2FA2, 0x1A4E, 0x2FA5, 0x2F9B, 0x1A4D, 0x2F9D, 0x2F9E, 0x4A9E, 0x2FA0, 0x1A49, 0x1BDA, 0x4A10, 0x2F9C, 0x2F98,
+    0x1BDB, 0x2FA6, 0x1BD7, 0x314D, 0x2F9F, 0x314C, 0x2F9A, 0x2FA3, 0x1A4C, 0x1BD8, 0x1A52, 0x1BD9, 0x2FA7, 0x314E, 0x1A4B, 0x1BDD,
+    0x1A51, 0x1A50, 0x1A4F, 0x1BDC, 0x2FA8, 0x1BDE, 0x1A4A, 0x2F99, 0x1DA6, 0x3152, 0x3154, 0x3156, 0x1BDF, 0x1BE5, 0x3397, 0x315A,
+    0x3151, 0x1DA8, 0x3395, 0x1BE4, 0x1BE2, 0x315C, 0x3396, 0x3153, 0x1BE3, 0x3157, 0x1BE6, 0x3159, 0x1BE1, 0x3158, 0x315B, 0x3155,
+    0x4B9A, 0x1BE0, 0x1DA9, 0x3150, 0x1DA7, 0x4B9B, 0x1DAA, 0x3399, 0x33A2, 0x1F6B, 0x4B9C, 0x1DAC, 0x1DB1, 0x1F6C, 0x33A4, 0x1DB8,
+    0x35F2, 0x339A, 0x1DB0, 0x1DB3, 0x1DAE, 0x3398, 0x33A6, 0x33A9, 0x3601, 0x33A7, 0x35F6, 0x1DAF, 0x1DB6, 0x1DBA, 0x33A1, 0x35F1,
+    0x33A5, 0x1DAB, 0x35F3, 0x33A8, 0x35F5, 0x339D, 0x1F69, 0x339B, 0x339C, 0x1DB5, 0x1DB4, 0x339F, 0x1DB9, 0x33A3, 0x1DB2, 0x33A0,
+    0x4C30, 0x1DB7, 0x1F6D, 0x1F6A, 0x35F4, 0x339E, 0x1DAD, 0x1F72, 0x1F76, 0x35F8, 0x212F, 0x1F74, 0x35FC, 0x1F78, 0x3891, 0x35FE,
+    0x2131, 0x1F70, 0x1F73, 0x35FF, 0x3600, 0x3606, 0x1F7A, 0x2132, 0x3602, 0x3607, 0x35FB, 0x213C, 0x2133, 0x1F79, 0x35F9, 0x3608,
+    0x213B, 0x1F77, 0x212C, 0x3605, 0x1F7B, 0x35FD, 0x35F7, 0x1F71, 0x35FA, 0x3603, 0x212B, 0x2130, 0x1F6E, 0x3604, 0x212E, 0x1F6F,
+    0x213A, 0x3B41, 0x389C, 0x3894, 0x3B40, 0x3898, 0x389A, 0x3897, 0x2139, 0x3896, 0x213D, 0x3B50, 0x2138, 0x22F5, 0x389D, 0x3B42,
+    0x4BA5, 0x2136, 0x3895, 0x3899, 0x22F4, 0x212D, 0x3892, 0x3893, 0x22F6, 0x2135, 0x2137, 0x2134, 0x389B, 0x3B46, 0x3B47, 0x3B4C,
+    0x246C, 0x3B53, 0x22FC, 0x22FB, 0x3B4E, 0x4BB7, 0x246B, 0x3B43, 0x22FA, 0x4B3D, 0x3B52, 0x22F8, 0x22F9, 0x4BA3, 0x3B4F, 0x3D9C,
+    0x2469, 0x1F75, 0x3B51, 0x2470, 0x3B49, 0x246A, 0x246F, 0x3B44, 0x3B48, 0x3B45, 0x3B4B, 0x22FD, 0x2468, 0x22F7, 0x3D8E, 0x3B4D,
+    0x246E, 0x2471, 0x3B4A, 0x4BA8, 0x246D, 0x3D8D, 0x4BAB, 0x3D92, 0x25D5, 0x3D8C, 0x4009, 0x3D9B, 0x2475, 0x2473, 0x25D3, 0x3D98,
+    0x3D94, 0x2479, 0x4000, 0x2477, 0x3D93, 0x3FFE, 0x3D97, 0x3D9A, 0x3D91, 0x2478, 0x2472, 0x3FFF, 0x25D4, 0x3D99, 0x2474, 0x2476,
+    0x3D96, 0x247A, 0x3D95, 0x3D90, 0x3D8F, 0x25D2, 0x3D9D, 0x4002, 0x421D, 0x25D7, 0x4008, 0x4006, 0x421E, 0x25D8, 0x4007, 0x4004,
+    0x26F5, 0x421C, 0x4001, 0x4003, 0x26F6, 0x25DA, 0x26F4, 0x25D9, 0x26F8, 0x4005, 0x25D6, 0x4BB2, 0x4BAF, 0x43E3, 0x43DB, 0x4224,
+    0x43DC, 0x4220, 0x27F3, 0x4222, 0x4221, 0x26F7, 0x421F, 0x4223, 0x43E4, 0x43E0, 0x43E2, 0x43DD, 0x43DE, 0x43E1, 0x43DF, 0x28A1,
+    0x28A4, 0x28A3, 0x28A2, 0x2938, 0x4691, 0x2939, 0x454E, 0x2994, 0x478E, 0x2995, 0x29E8, 0x2A1C, 0x48D5, 0x48D6, 0x48D7, 0x49F0,
+    0x15F5, 0x2C64, 0x1665, 0x16F5, 0x16F6, 0x16F4, 0x16F7, 0x17C6, 0x17C5, 0x2E4E, 0x18F5, 0x18F4, 0x315D, 0x1DBB, 0x1DBC, 0x1F7C,
+    0x389E, 0x213E, 0x213F, 0x38A0, 0x38A2, 0x38A1, 0x3B55, 0x3B54, 0x22FE, 0x3B56, 0x3D9E, 0x247B, 0x25DB, 0x26F9, 0x27F4, 0x26FA,
+    0x15F6, 0x2D49, 0x2E4F, 0x18F7, 0x18F6, 0x18F8, 0x1A53, 0x2FA9, 0x2FAA, 0x315E, 0x1BE7, 0x1DBD, 0x1F7D, 0x3609, 0x15F7, 0x15B5,
+    0x15F8, 0x2C65, 0x1669, 0x1668, 0x1666, 0x1667, 0x16FA, 0x2CAF, 0x2CB1, 0x16F9, 0x2CA9, 0x2CAA, 0x2CB0, 0x2CAC, 0x2CAE, 0x16F8,
+    0x2CAB, 0x2CB2, 0x2CAD, 0x17CD, 0x17D6, 0x17D4, 0x2D53, 0x2D50, 0x2D58, 0x17D2, 0x2D59, 0x17CB, 0x2D56, 0x17D1, 0x2D52, 0x2D51,
+    0x17CF, 0x2D57, 0x17D0, 0x18F9, 0x17CA, 0x2D54, 0x2D4A, 0x17C7, 0x17DA, 0x2D4F, 0x2D55, 0x17CC, 0x17CE, 0x2D4B, 0x2D4C, 0x2D4D,
+    0x17D9, 0x17D3, 0x17D8, 0x2D4E, 0x17D7, 0x17C9, 0x17C8, 0x17D5, 0x1907, 0x2E5B, 0x2E54, 0x1902, 0x1915, 0x2E50, 0x2E57, 0x2E59,
+    0x2E5C, 0x1910, 0x2E58, 0x2E51, 0x190E, 0x2E55, 0x2E5D, 0x18FF, 0x2E5A, 0x1909, 0x1908, 0x2E53, 0x18FD, 0x18FE, 0x18FC, 0x4BBB,
+    0x1914, 0x190C, 0x1906, 0x18FA, 0x2E56, 0x1905, 0x18FB, 0x190D, 0x1916, 0x2FAB, 0x190A, 0x2E52, 0x1900, 0x1903, 0x1904, 0x4BBD,
+    0x1912, 0x1913, 0x1911, 0x190B, 0x190F, 0x1901, 0x1A54, 0x4A46, 0x2FB1, 0x1A60, 0x1A58, 0x1A5A, 0x1A5F, 0x2FBD, 0x1A5D, 0x315F,
+    0x1BE8, 0x1A62, 0x2FAE, 0x2FB6, 0x1A5E, 0x2FB5, 0x2FB2, 0x2FBA, 0x2FBC, 0x1A57, 0x1A5B, 0x1A61, 0x1BEA, 0x2FB7, 0x1A59, 0x1A64,
+    0x2FB0, 0x1A5C, 0x1BE9, 0x1A56, 0x2FAD, 0x2FB4, 0x2FAC, 0x2FAF, 0x2FB3, 0x3160, 0x1A63, 0x2FB8, 0x2FB9, 0x2FBB, 0x1A55, 0x1BF8,
+    0x316C, 0x1BF6, 0x1BF7, 0x3162, 0x3172, 0x1BED, 0x33AA, 0x3174, 0x316E, 0x3165, 0x3177, 0x3168, 0x1BF3, 0x33C1, 0x316B, 0x1BF5,
+    0x1BEC, 0x3179, 0x316D, 0x1BEF, 0x3166, 0x3163, 0x3164, 0x1BF0, 0x3173, 0x317A, 0x1BF2, 0x316A, 0x3169, 0x1BFA, 0x1BF9, 0x1BEB,
+    0x1BF1, 0x1BF4, 0x3176, 0x3170, 0x1BEE, 0x3161, 0x3178, 0x316F, 0x3171, 0x3175, 0x33AB, 0x1DC5, 0x1DD9, 0x1DD8, 0x1DCD, 0x4BBF,
+    0x33BD, 0x33B9, 0x33B6, 0x33C8, 0x1DC8, 0x1DC0, 0x33BB, 0x1F8D, 0x1DC4, 0x33C3, 0x1DDA, 0x1DD7, 0x33BF, 0x33AE, 0x1DD6, 0x33C5,
+    0x33AD, 0x1DCB, 0x1DCF, 0x33C4, 0x33B7, 0x1DD0, 0x1DCA, 0x33AC, 0x4BB9, 0x1F7F, 0x33C7, 0x33B5, 0x1DD5, 0x33B8, 0x33C6, 0x1DD4,
+    0x360A, 0x1DC1, 0x33B3, 0x1DC6, 0x1DD1, 0x1DCC, 0x33BC, 0x33B2, 0x33B0, 0x33C2, 0x1DBE, 0x1DD2, 0x1DC2, 0x1F7E, 0x33C0, 0x1DC3,
+    0x1DBF, 0x1DCE, 0x1DC9, 0x1DC7, 0x33B4, 0x1DD3, 0x33B1, 0x33BE, 0x33BA, 0x360C, 0x360B, 0x33AF, 0x361C, 0x1F81, 0x3622, 0x3611,
+    0x361F, 0x38A3, 0x1F84, 0x3623, 0x3626, 0x1F83, 0x3614, 0x3625, 0x3624, 0x1F85, 0x360D, 0x1F80, 0x1F88, 0x1F86, 0x3621, 0x3617,
+    0x1F8A, 0x3628, 0x3620, 0x3629, 0x1F92, 0x1F90, 0x361E, 0x361D, 0x361B, 0x3615, 0x1F89, 0x1F87, 0x3167, 0x360E, 0x38C1, 0x360F,
+    0x1F82, 0x1F8F, 0x38A5, 0x1F8B, 0x1F8C, 0x3610, 0x3627, 0x38A4, 0x3618, 0x3613, 0x1F8E, 0x3619, 0x3616, 0x4BC3, 0x1F93, 0x214E,
+    0x38A8, 0x38BB, 0x38C0, 0x38B4, 0x214A, 0x38C5, 0x2147, 0x38A6, 0x38A7, 0x2140, 0x2149, 0x38AE, 0x214C, 0x214D, 0x38AF, 0x38BC,
+    0x38C2, 0x2148, 0x2142, 0x38AD, 0x38A9, 0x38C4, 0x38B2, 0x38B3, 0x38AA, 0x38BE, 0x38B5, 0x38BF, 0x38B7, 0x2143, 0x3B57, 0x2146,
+    0x2144, 0x38C3, 0x38BA, 0x38B6, 0x38AB, 0x2308, 0x38B9, 0x214B, 0x38B1, 0x4BC2, 0x38B0, 0x4BD0, 0x2145, 0x2141, 0x3B6C, 0x38BD,
+    0x38B8, 0x38AC, 0x3B67, 0x3B58, 0x3B63, 0x3B6A, 0x2306, 0x1F91, 0x3B68, 0x2301, 0x2300, 0x3B6F, 0x3B59, 0x3B66, 0x3B5A, 0x3B65,
+    0x2304, 0x3B69, 0x361A, 0x3B70, 0x3B61, 0x2307, 0x3DAC, 0x247C, 0x3B6E, 0x3B6D, 0x2309, 0x3D9F, 0x247D, 0x3DA0, 0x3B5D, 0x3B5E,
+    0x3B5B, 0x3B60, 0x3B5C, 0x3B71, 0x2303, 0x247E, 0x2305, 0x230A, 0x3B5F, 0x3B6B, 0x3B64, 0x3DA3, 0x22FF, 0x2481, 0x4010, 0x3DA8,
+    0x3DA7, 0x3DA9, 0x3DA6, 0x2482, 0x2488, 0x2485, 0x2486, 0x3DA1, 0x3DA4, 0x3DAE, 0x248E, 0x248C, 0x3DA5, 0x3612, 0x247F, 0x3DAB,
+    0x3DA2, 0x248F, 0x3DAA, 0x2302, 0x2484, 0x3B62, 0x2487, 0x248B, 0x248D, 0x248A, 0x2489, 0x4D1B, 0x2483, 0x3DAD, 0x2480, 0x2490,
+    0x25DF, 0x25E0, 0x400F, 0x25E9, 0x25E6, 0x25DD, 0x25E4, 0x4011, 0x25E2, 0x25DC, 0x25E3, 0x400E, 0x26FC, 0x25DE, 0x25E5, 0x26FB,
+    0x400D, 0x400C, 0x25E7, 0x25E8, 0x400B, 0x400A, 0x26FD, 0x4014, 0x25E1, 0x4012, 0x26FE, 0x4BCC, 0x2703, 0x4227, 0x4229, 0x4BCD,
+    0x2700, 0x422A, 0x4226, 0x4228, 0x2701, 0x2704, 0x4225, 0x26FF, 0x2702, 0x27F6, 0x4013, 0x27F5, 0x27FB, 0x43E8, 0x27F9, 0x27FA,
+    0x43EB, 0x43E7, 0x27F7, 0x43E5, 0x28A5, 0x43E9, 0x43EA, 0x43E6, 0x27F8, 0x454F, 0x4552, 0x4553, 0x4551, 0x4554, 0x28A6, 0x4550,
+    0x4695, 0x293B, 0x4694, 0x4693, 0x4692, 0x293A, 0x293C, 0x478F, 0x2997, 0x2996, 0x4BB8, 0x4840, 0x483F, 0x4842, 0x2A1D, 0x29E9,
+    0x48D9, 0x4841, 0x48D8, 0x2A1F, 0x2A1E, 0x2A45, 0x4942, 0x4988, 0x15F9, 0x362A, 0x4015, 0x2AC1, 0x16FB, 0x2D5A, 0x17DD, 0x17DB,
+    0x17DC, 0x2E5E, 0x1917, 0x1A65, 0x2FBE, 0x2FBF, 0x1A66, 0x317C, 0x1BFB, 0x1BFC, 0x317B, 0x4BD2, 0x1DE1, 0x1DDD, 0x33C9, 0x1DE4,
+    0x1DE3, 0x1DDC, 0x1DDF, 0x1DE2, 0x1DDE, 0x362E, 0x1DDB, 0x1F94, 0x4BD8, 0x1F96, 0x1F97, 0x362D, 0x3630, 0x1F95, 0x362B, 0x362F,
+    0x362C, 0x214F, 0x4BD7, 0x38C6, 0x230B, 0x3B72, 0x25EA, 0x2491, 0x3DAF, 0x2492, 0x2493, 0x3DB1, 0x3DB0, 0x3DB2, 0x4017, 0x4016,
+    0x422C, 0x422B, 0x2705, 0x2706, 0x4555, 0x4BD6, 0x15FA, 0x4B36, 0x3631, 0x1F99, 0x1F98, 0x38C7, 0x43EC, 0x2998, 0x48DA, 0x15FB,
+    0x1BFD, 0x1DE6, 0x1DE5, 0x3632, 0x3633, 0x2150, 0x3B73, 0x230C, 0x4018, 0x15FC, 0x166A, 0x1918, 0x2E5F, 0x2FC0, 0x1A67, 0x1DE7,
+    0x3634, 0x1F9A, 0x2151, 0x3DB3, 0x3DB4, 0x4BDA, 0x422D, 0x27FC, 0x4989, 0x15FD, 0x2E60, 0x1919, 0x1A68, 0x2FC1, 0x1BFE, 0x3180,
+    0x317E, 0x317F, 0x1BFF, 0x317D, 0x1DE9, 0x1DEA, 0x33CA, 0x1DEB, 0x1DE8, 0x3635, 0x4BDD, 0x3636, 0x38C8, 0x230E, 0x230D, 0x422E,
+    0x43ED, 0x4557, 0x4556, 0x4696, 0x2AC2, 0x2C46, 0x1A69, 0x4BE1, 0x15FE, 0x166B, 0x16FD, 0x16FC, 0x16FE, 0x16FF, 0x2CB4, 0x2CB3,
+    0x2D5B, 0x17DE, 0x2D5E, 0x2D5D, 0x2D5C, 0x2D5F, 0x191A, 0x2E66, 0x2E62, 0x2E6B, 0x1921, 0x191F, 0x2E67, 0x2E63, 0x2E6A, 0x191E,
+    0x1925, 0x2E65, 0x2E61, 0x1924, 0x2E68, 0x191D, 0x2E69, 0x1920, 0x1922, 0x2E6D, 0x2E6C, 0x2E64, 0x191C, 0x191B, 0x1923, 0x4BE4,
+    0x2FC6, 0x2FCC, 0x1A6F, 0x1A6C, 0x2FC3, 0x2FC8, 0x4CCC, 0x1A72, 0x1A6A, 0x2FC7, 0x1A6D, 0x1A70, 0x2FCA, 0x1A6B, 0x2FCF, 0x1A6E,
+    0x1A71, 0x2FC4, 0x2FC9, 0x2FCD, 0x2FC5, 0x2FC2, 0x2FCE, 0x2FCB, 0x1C07, 0x1C00, 0x1C03, 0x4BE7, 0x1C06, 0x3183, 0x1C01, 0x3181,
+    0x4BEA, 0x1C05, 0x1C02, 0x3184, 0x1C04, 0x4AC5, 0x33CE, 0x1DED, 0x33CD, 0x33CF, 0x1DEC, 0x1DF1, 0x3182, 0x33CC, 0x33D0, 0x1DEE,
+    0x33CB, 0x1DF0, 0x4BEC, 0x1DEF, 0x363D, 0x3638, 0x1F9B, 0x1F9F, 0x1F9C, 0x363B, 0x363E, 0x4BEE, 0x1F9D, 0x1F9E, 0x1FA3, 0x38D1,
+    0x363C, 0x1FA1, 0x3639, 0x3637, 0x1FA2, 0x363A, 0x2157, 0x4B91, 0x38C9, 0x2154, 0x2155, 0x2153, 0x38CE, 0x38CD, 0x38CA, 0x2159,
+    0x38CC, 0x1FA0, 0x38D0, 0x38CB, 0x2156, 0x2152, 0x2158, 0x38CF, 0x2311, 0x3B76, 0x3B75, 0x3B74, 0x230F, 0x4AE4, 0x2310, 0x3DB7,
+    0x3DBA, 0x2495, 0x4AE5, 0x2494, 0x3DBB, 0x3DB6, 0x2497, 0x3DB8, 0x4ADF, 0x2496, 0x3DB5, 0x3DB9, 0x25F0, 0x25ED, 0x4021, 0x4020,
+    0x401F, 0x401A, 0x401B, 0x25EE, 0x25EB, 0x25EF, 0x4019, 0x25EC, 0x401C, 0x401D, 0x4022, 0x4AE2, 0x401E, 0x422F, 0x2708, 0x43F0,
+    0x2707, 0x43EE, 0x43EF, 0x27FD, 0x28A8, 0x4558, 0x28A7, 0x4698, 0x4699, 0x293D, 0x4697, 0x2999, 0x48DB, 0x2A20, 0x4943, 0x4944,
+    0x15FF, 0x1700, 0x1701, 0x17DF, 0x2E6E, 0x1A73, 0x1C08, 0x1DF2, 0x1D43, 0x1FA4, 0x1FA5, 0x1F14, 0x363F, 0x4BF8, 0x215A, 0x3B78,
+    0x3B77, 0x1600, 0x1702, 0x2E6F, 0x1927, 0x1926, 0x2FD0, 0x2FD1, 0x3185, 0x3186, 0x1C09, 0x1C0A, 0x1C0B, 0x33D1, 0x1DF4, 0x1FA7,
+    0x1FA6, 0x38D2, 0x39E0, 0x3B79, 0x4023, 0x27FE, 0x293E, 0x1601, 0x166E, 0x166F, 0x166D, 0x1670, 0x166C, 0x1705, 0x2CBC, 0x1704,
+    0x1706, 0x2CB7, 0x2CB6, 0x2CB9, 0x2CB8, 0x2CBB, 0x1703, 0x2CB5, 0x2CBA, 0x4A45, 0x2D60, 0x17E9, 0x2D61, 0x2D65, 0x17E8, 0x2D69,
+    0x2D64, 0x2D67, 0x17E1, 0x17E2, 0x17E3, 0x17E4, 0x17EB, 0x2D63, 0x17E6, 0x17EC, 0x2D62, 0x2D68, 0x17E5, 0x2D66, 0x17E7, 0x17E0,
+    0x17EA, 0x4C00, 0x4BFF, 0x193C, 0x2E71, 0x1928, 0x1932, 0x1933, 0x192B, 0x193D, 0x192D, 0x2E79, 0x1938, 0x2E74, 0x192E, 0x2E83,
+    0x2E84, 0x2E7C, 0x2E75, 0x193B, 0x2E81, 0x1936, 0x1934, 0x2E82, 0x2E80, 0x2E78, 0x2E70, 0x2E77, 0x192F, 0x1935, 0x1929, 0x2E7B,
+    0x2E7A, 0x2E72, 0x1937, 0x2E7E, 0x2E73, 0x193A, 0x2E85, 0x192A, 0x1931, 0x2E76, 0x2E7F, 0x1939, 0x192C, 0x1930, 0x2E7D, 0x4C19,
+    0x2FF1, 0x1A7B, 0x1A88, 0x4C2A, 0x2FEC, 0x2FE2, 0x1A81, 0x2FE0, 0x1A7A, 0x2FDB, 0x1A84, 0x2FE8, 0x2FD5, 0x2FD7, 0x2FDA, 0x2FD2,
+    0x2FE7, 0x2FF7, 0x1A7F, 0x2FDC, 0x2FEE, 0x2FD4, 0x2FF5, 0x2FF6, 0x2FF9, 0x2FF0, 0x2FE1, 0x2FE9, 0x1A85, 0x1A78, 0x1A80, 0x1A8C,
+    0x1A75, 0x1A77, 0x4C03, 0x2FD9, 0x1A89, 0x1A82, 0x2FF3, 0x2FD6, 0x1A8B, 0x1A86, 0x2FDF, 0x1A8A, 0x2FE6, 0x2FDE, 0x1A83, 0x2FF2,
+    0x2FEA, 0x1A7D, 0x2FF8, 0x2FDD, 0x1A79, 0x2FEF, 0x2FE5, 0x1A7E, 0x2FEB, 0x1A76, 0x2FD3, 0x1A87, 0x1C1A, 0x1A7C, 0x2FE4, 0x2FE3,
+    0x2FD8, 0x2FF4, 0x2FED, 0x1A74, 0x4C08, 0x4CB8, 0x319A, 0x1C21, 0x319B, 0x318F, 0x1C16, 0x1C22, 0x3188, 0x4A9C, 0x3191, 0x3199,
+    0x3187, 0x31A2, 0x1C0C, 0x4C05, 0x31A1, 0x319C, 0x319D, 0x1C14, 0x1C1F, 0x3193, 0x3194, 0x319E, 0x3195, 0x3190, 0x318A, 0x318B,
+    0x3198, 0x3192, 0x4C53, 0x1C0D, 0x1C11, 0x31A0, 0x318C, 0x1C1D, 0x1C19, 0x4B73, 0x1C1C, 0x1C23, 0x1C12, 0x1C1E, 0x3197, 0x1C20,
+    0x1C0F, 0x1C0E, 0x3189, 0x318D, 0x1C17, 0x319F, 0x3196, 0x318E, 0x1C1B, 0x1C18, 0x1C10, 0x1C13, 0x4C0B, 0x33E6, 0x33D6, 0x33D7,
+    0x33DB, 0x33EA, 0x33E7, 0x33E0, 0x33DF, 0x1DFB, 0x33E3, 0x33F2, 0x33D2, 0x33F3, 0x33E5, 0x33F7, 0x33EB, 0x1DFA, 0x33E9, 0x1DF5,
+    0x1E0A, 0x1E00, 0x1E04, 0x1E03, 0x33D3, 0x33F0, 0x33F6, 0x33EE, 0x33F5, 0x33E2, 0x33D4, 0x33F4, 0x33E4, 0x1DF8, 0x1E05, 0x33ED,
+    0x1DFE, 0x33EC, 0x33D5, 0x1E06, 0x1E08, 0x33EF, 0x1E09, 0x1DF7, 0x33DC, 0x33F1, 0x4B0E, 0x1DFD, 0x1E07, 0x33DE, 0x33E8, 0x33D9,
+    0x33DD, 0x1E02, 0x33D8, 0x1DF6, 0x1DFF, 0x1DFC, 0x33E1, 0x1C15, 0x3656, 0x1DF9, 0x4CB3, 0x4C11, 0x1E01, 0x3659, 0x366F, 0x3664,
+    0x1FBB, 0x4D27, 0x1FB6, 0x3653, 0x1FB7, 0x3663, 0x365C, 0x3669, 0x1FB3, 0x3641, 0x366B, 0x1FA9, 0x3649, 0x1FAC, 0x1FAB, 0x1FBC,
+    0x3643, 0x3665, 0x3666, 0x1FAE, 0x1FAA, 0x3651, 0x1FB5, 0x364C, 0x3667, 0x1FB1, 0x365F, 0x366C, 0x3646, 0x364B, 0x3645, 0x1FB0,
+    0x3658, 0x38E8, 0x3647, 0x1FB4, 0x3650, 0x3668, 0x1FAF, 0x364D, 0x364A, 0x365B, 0x1FB2, 0x1FA8, 0x1FBE, 0x365E, 0x365D, 0x4C14,
+    0x4C13, 0x4D39, 0x4CD3, 0x3642, 0x1FAD, 0x366A, 0x3652, 0x3654, 0x3661, 0x3660, 0x3640, 0x1FB8, 0x1FBA, 0x3648, 0x364F, 0x3657,
+    0x1FB9, 0x364E, 0x365A, 0x366D, 0x3662, 0x366E, 0x3644, 0x38FC, 0x2162, 0x38FA, 0x38E5, 0x38EA, 0x38E3, 0x4C1D, 0x38F6, 0x38D5,
+    0x38DC, 0x38F4, 0x38FE, 0x38E6, 0x4A80, 0x38D9, 0x38EC, 0x38F1, 0x38DD, 0x38EE, 0x38DA, 0x38E1, 0x38E2, 0x2164, 0x38F5, 0x38F3,
+    0x38D6, 0x38F8, 0x38F9, 0x38FB, 0x2168, 0x2160, 0x33DA, 0x38DE, 0x38F0, 0x38DF, 0x215D, 0x216D, 0x38F7, 0x216B, 0x2167, 0x38D4,
+    0x215F, 0x4C1A, 0x38D7, 0x216C, 0x38E7, 0x38D3, 0x2165, 0x38EB, 0x38DB, 0x2166, 0x38E4, 0x215C, 0x1FBD, 0x38ED, 0x3655, 0x38D8,
+    0x4C10, 0x38F2, 0x2161, 0x38EF, 0x215E, 0x38E9, 0x2169, 0x38E0, 0x38FD, 0x2163, 0x396D, 0x216A, 0x3B87, 0x3B85, 0x3B9B, 0x3B83,
+    0x3B95, 0x215B, 0x2314, 0x3B7F, 0x3B9A, 0x4C1E, 0x3B84, 0x3B92, 0x2319, 0x2312, 0x3B98, 0x3B7D, 0x3B97, 0x2324, 0x3B8E, 0x3B9E,
+    0x2322, 0x3B86, 0x2313, 0x3B88, 0x3B96, 0x231C, 0x3B81, 0x2320, 0x2316, 0x3B8A, 0x3B80, 0x3B7A, 0x3B94, 0x231D, 0x3B9D, 0x3B7B,
+    0x231A, 0x3B90, 0x231B, 0x3B82, 0x3B8D, 0x3B89, 0x3B8B, 0x2315, 0x3B9C, 0x2323, 0x3B8C, 0x3B9F, 0x3B7C, 0x3B91, 0x2318, 0x2321,
+    0x231F, 0x3B7E, 0x3B93, 0x231E, 0x2317, 0x3B8F, 0x3B99, 0x4C0D, 0x3DC7, 0x3DC3, 0x3DBF, 0x3DD3, 0x3DD0, 0x249A, 0x3DD7, 0x3DC6,
+    0x24A5, 0x3DCD, 0x3DC2, 0x3DCC, 0x24A2, 0x3DE0, 0x3DDD, 0x3DCF, 0x3DC0, 0x4C23, 0x4BFC, 0x3DD4, 0x3DD6, 0x249E, 0x3DCA, 0x3DC5,
+    0x3DBC, 0x249B, 0x24A3, 0x3DD9, 0x24A4, 0x3DBD, 0x3DDF, 0x3DD2, 0x3DD8, 0x24A1, 0x4C0E, 0x3DD5, 0x3DDC, 0x24A6, 0x24A0, 0x3DCE,
+    0x3DC1, 0x3DE1, 0x3DBE, 0x3DDA, 0x249D, 0x3DC8, 0x3DC9, 0x249C, 0x2499, 0x3DC4, 0x249F, 0x2498, 0x3DDB, 0x3DDE, 0x3DCB, 0x4030,
+    0x4CE4, 0x4028, 0x4024, 0x25FD, 0x25F2, 0x25F7, 0x25F3, 0x4032, 0x403D, 0x25F1, 0x402A, 0x4033, 0x3DD1, 0x4034, 0x25F8, 0x4C26,
+    0x4043, 0x25FC, 0x25FF, 0x4026, 0x25FB, 0x4041, 0x4042, 0x4038, 0x4037, 0x402F, 0x4039, 0x4040, 0x403F, 0x25F6, 0x25F4, 0x4031,
+    0x402E, 0x402B, 0x403E, 0x25FE, 0x403C, 0x25FA, 0x25F9, 0x4036, 0x4025, 0x4027, 0x4029, 0x403B, 0x4035, 0x25F5, 0x402C, 0x403A,
+    0x402D, 0x2710, 0x4241, 0x2709, 0x4232, 0x423D, 0x270B, 0x4244, 0x4239, 0x4234, 0x4B62, 0x4245, 0x4230, 0x423B, 0x2712, 0x4240,
+    0x4246, 0x423A, 0x270A, 0x423C, 0x4231, 0x2711, 0x4243, 0x4236, 0x270D, 0x4C28, 0x4238, 0x4235, 0x2713, 0x4237, 0x270C, 0x270F,
+    0x423F, 0x4233, 0x4242, 0x423E, 0x2800, 0x43FB, 0x2805, 0x2806, 0x27FF, 0x43FA, 0x43F7, 0x43F8, 0x2803, 0x43F2, 0x43F6, 0x2802,
+    0x43F3, 0x2804, 0x2801, 0x43F1, 0x43F5, 0x43F9, 0x4C2B, 0x455E, 0x455B, 0x4564, 0x4563, 0x4561, 0x455C, 0x28AC, 0x455D, 0x28AB,
+    0x270E, 0x4560, 0x28AA, 0x4565, 0x455F, 0x455A, 0x43F4, 0x28A9, 0x4559, 0x469D, 0x469C, 0x4562, 0x293F, 0x46A0, 0x46A1, 0x469B,
+    0x469F, 0x469A, 0x4793, 0x469E, 0x299C, 0x299A, 0x4791, 0x4794, 0x4790, 0x4792, 0x299B, 0x4844, 0x4845, 0x29EA, 0x4843, 0x48DE,
+    0x2A21, 0x48DC, 0x48DD, 0x4945, 0x2A67, 0x498C, 0x498A, 0x498B, 0x498D, 0x4C2E, 0x49F1, 0x1602, 0x1707, 0x193E, 0x2E86, 0x2FFA,
+    0x31A3, 0x31A5, 0x31A4, 0x31A6, 0x1E0B, 0x33F9, 0x31A7, 0x4C31, 0x33F8, 0x33FA, 0x33FB, 0x3670, 0x1FC0, 0x3671, 0x3673, 0x1FC1,
+    0x1FBF, 0x3672, 0x3904, 0x3902, 0x3901, 0x3900, 0x38FF, 0x216E, 0x3903, 0x2325, 0x3BA0, 0x3BA2, 0x2326, 0x3BA1, 0x24A8, 0x24A7,
+    0x3DE2, 0x4045, 0x4044, 0x4046, 0x2600, 0x4247, 0x2714, 0x43FC, 0x2807, 0x4566, 0x29EB, 0x1603, 0x1671, 0x1708, 0x17ED, 0x193F,
+    0x1940, 0x1A8D, 0x31A8, 0x216F, 0x4C32, 0x3E60, 0x2601, 0x2808, 0x1604, 0x1709, 0x2E88, 0x1941, 0x2E87, 0x2FFB, 0x1A8E, 0x2FFC,
+    0x1A8F, 0x31AA, 0x1C25, 0x1C24, 0x3400, 0x33FE, 0x33FF, 0x33FD, 0x33FC, 0x3674, 0x3677, 0x1FC3, 0x3675, 0x1FC2, 0x3676, 0x3905,
+    0x3BA3, 0x3BA4, 0x3BA5, 0x3DE5, 0x3DE4, 0x24A9, 0x3DE3, 0x3DE6, 0x4047, 0x4048, 0x4049, 0x4248, 0x2715, 0x2809, 0x4567, 0x299D,
+    0x2C47, 0x1A90, 0x2FFD, 0x1C26, 0x1E0C, 0x1FC4, 0x3678, 0x2171, 0x2170, 0x3BA6, 0x3BA7, 0x24AA, 0x24AB, 0x404B, 0x404A, 0x43FD,
+    0x48DF, 0x1605, 0x2C48, 0x1672, 0x17EE, 0x2D6A, 0x1A91, 0x2172, 0x1606, 0x2FFE, 0x1A92, 0x2FFF, 0x2716, 0x1607, 0x4C3D, 0x2E89,
+    0x3000, 0x4C3B, 0x31AF, 0x31AE, 0x31AC, 0x31AB, 0x31B0, 0x31AD, 0x1E0D, 0x1E0E, 0x1FC5, 0x3679, 0x367A, 0x367B, 0x390A, 0x390B,
+    0x3909, 0x3907, 0x3908, 0x2173, 0x3BA8, 0x3DE9, 0x3DE8, 0x3DE7, 0x3DEA, 0x404D, 0x404C, 0x2602, 0x404E, 0x2717, 0x4249, 0x43FE,
+    0x4568, 0x4846, 0x1608, 0x1674, 0x1673, 0x1942, 0x2C49, 0x2C66, 0x170A, 0x2CBD, 0x2D6B, 0x2D6C, 0x1943, 0x2E8A, 0x1A93, 0x3001,
+    0x3002, 0x1C27, 0x1C2B, 0x31B1, 0x1C2A, 0x1C28, 0x1C29, 0x3401, 0x1E0F, 0x1FC8, 0x1FC6, 0x1FC7, 0x367C, 0x2327, 0x1609, 0x2C67,
+    0x4C3E, 0x1675, 0x2C6A, 0x1678, 0x2C69, 0x1677, 0x1676, 0x17EF, 0x2C68, 0x2CBE, 0x4AB7, 0x2CC2, 0x2CC4, 0x2CC5, 0x1714, 0x1715,
+    0x2CC1, 0x1710, 0x2CBF, 0x2CC3, 0x1711, 0x170C, 0x170D, 0x4C42, 0x1713, 0x2CC0, 0x170B, 0x17F0, 0x170E, 0x170F, 0x1712, 0x2D7D,
+    0x2D7B, 0x2D6E, 0x17FC, 0x2D75, 0x17F7, 0x2D6F, 0x2D77, 0x4AF4, 0x2D74, 0x17FA, 0x2D73, 0x1801, 0x2D7C, 0x1803, 0x1805, 0x2D6D,
+    0x4C44, 0x17F8, 0x2D7E, 0x17FF, 0x1802, 0x2EA1, 0x17F2, 0x1809, 0x1800, 0x2D70, 0x17F5, 0x1804, 0x2D78, 0x17F3, 0x17F4, 0x2E9F,
+    0x2D71, 0x17FB, 0x1806, 0x2D7F, 0x2D72, 0x17F9, 0x17FE, 0x2E8B, 0x1807, 0x2D79, 0x17FD, 0x1808, 0x17F1, 0x2D76, 0x17F6, 0x2D7A,
+    0x2EA0, 0x194F, 0x195F, 0x2E92, 0x1956, 0x2EA7, 0x1947, 0x194A, 0x2E9E, 0x2E90, 0x2E94, 0x1952, 0x1954, 0x2E97, 0x195B, 0x194D,
+    0x194B, 0x194C, 0x195A, 0x2EA3, 0x1955, 0x2E96, 0x2E98, 0x1953, 0x1958, 0x2E99, 0x2EA6, 0x1A94, 0x195E, 0x4A9A, 0x1948, 0x2EA5,
+    0x2EA9, 0x2E95, 0x2EAB, 0x2E9C, 0x1951, 0x2E91, 0x1950, 0x1962, 0x1957, 0x2E8F, 0x300B, 0x195D, 0x1961, 0x2E9D, 0x2EA2, 0x1963,
+    0x195C, 0x194E, 0x1944, 0x1949, 0x2E93, 0x1945, 0x2EAA, 0x2E8D, 0x2E8C, 0x2E9A, 0x2E8E, 0x1960, 0x1C2C, 0x1959, 0x2E9B, 0x1946,
+    0x1AA3, 0x2EA8, 0x3012, 0x3015, 0x3018, 0x300D, 0x301B, 0x301E, 0x3020, 0x300A, 0x1A95, 0x1A9A, 0x31B6, 0x1AAA, 0x3019, 0x3021,
+    0x3011, 0x3009, 0x31D1, 0x1A9D, 0x3016, 0x300E, 0x3010, 0x1AA2, 0x3013, 0x1A9C, 0x3006, 0x301C, 0x301F, 0x1A99, 0x1AA5, 0x3003,
+    0x1AA7, 0x1A97, 0x1AAB, 0x301D, 0x3005, 0x1AA8, 0x31CB, 0x2EA4, 0x1A9B, 0x1A96, 0x300C, 0x3004, 0x1AA9, 0x1AA1, 0x3017, 0x1AA6,
+    0x1AA4, 0x300F, 0x1A9E, 0x3007, 0x1A9F, 0x1AA0, 0x3008, 0x301A, 0x1A98, 0x3014, 0x31B9, 0x1C34, 0x1C39, 0x4AC6, 0x4C58, 0x31C2,
+    0x31C8, 0x31C4, 0x31B7, 0x31BA, 0x31B3, 0x31B4, 0x1C40, 0x1C31, 0x31C3, 0x31CC, 0x1C3B, 0x1C2D, 0x1C36, 0x31BB, 0x1C38, 0x31BC,
+    0x31C6, 0x4B1F, 0x1C3A, 0x31D5, 0x31B5, 0x1C33, 0x1C32, 0x1C3E, 0x31B2, 0x31D3, 0x31C7, 0x31D4, 0x31CE, 0x31C0, 0x31CF, 0x31C9,
+    0x31D2, 0x31D0, 0x1C3F, 0x31C1, 0x1C30, 0x1C2F, 0x1C37, 0x1C3D, 0x31CD, 0x1C3C, 0x31BE, 0x1E10, 0x31D6, 0x31BD, 0x31B8, 0x1C35,
+    0x1C41, 0x1C2E, 0x4C57, 0x31C5, 0x31CA, 0x1E32, 0x3403, 0x3407, 0x1E1F, 0x1E1D, 0x1E14, 0x3405, 0x3404, 0x1E28, 0x340A, 0x1E22,
+    0x341C, 0x3428, 0x1E11, 0x3419, 0x3412, 0x1E34, 0x3402, 0x341E, 0x1E31, 0x1E25, 0x1E30, 0x1E1B, 0x340E, 0x3420, 0x3418, 0x1E1C,
+    0x1E16, 0x3426, 0x341F, 0x3421, 0x1E1E, 0x1E26, 0x3423, 0x340C, 0x341D, 0x3411, 0x3425, 0x1E2B, 0x1E13, 0x1E29, 0x3416, 0x3414,
+    0x3415, 0x1E20, 0x3410, 0x340F, 0x1E15, 0x3409, 0x3427, 0x1E17, 0x3413, 0x1E35, 0x1E2F, 0x3408, 0x1E2C, 0x1E2A, 0x1E33, 0x341A,
+    0x1E2E, 0x31BF, 0x341B, 0x1E2D, 0x3422, 0x1E12, 0x3417, 0x1E24, 0x340B, 0x1E23, 0x1E21, 0x1E19, 0x1E18, 0x367D, 0x3424, 0x340D,
+    0x3692, 0x1E1A, 0x1FE5, 0x1E27, 0x1FD3, 0x368C, 0x1FE1, 0x3680, 0x1FD0, 0x1FCC, 0x369E, 0x1FD2, 0x1FD6, 0x1FD1, 0x1FDA, 0x36A2,
+    0x3699, 0x4D40, 0x3687, 0x1FDF, 0x1FD9, 0x3693, 0x1FC9, 0x369F, 0x3698, 0x1FCD, 0x368D, 0x1FDC, 0x36AA, 0x36AB, 0x1FCA, 0x369D,
+    0x1FDE, 0x3691, 0x3683, 0x3684, 0x1FE2, 0x3688, 0x368F, 0x3689, 0x1FE0, 0x1FE8, 0x3685, 0x367E, 0x367F, 0x3681, 0x1FCF, 0x368E,
+    0x1FDD, 0x1FE6, 0x4C6A, 0x4C06, 0x3690, 0x36A8, 0x36A0, 0x1FCB, 0x36A6, 0x1FD7, 0x1FD5, 0x4B10, 0x36AC, 0x1FD4, 0x3696, 0x368A,
+    0x3694, 0x1FEB, 0x369A, 0x3697, 0x3686, 0x1FE7, 0x36A4, 0x36A1, 0x36A9, 0x1FCE, 0x3695, 0x1FEA, 0x369C, 0x1FD8, 0x1FDB, 0x369B,
+    0x1FE9, 0x368B, 0x3406, 0x36A5, 0x36A3, 0x36A7, 0x4C59, 0x3682, 0x1FE4, 0x392A, 0x3929, 0x390F, 0x2179, 0x3928, 0x3912, 0x3913,
+    0x2183, 0x3931, 0x217E, 0x3927, 0x390C, 0x2184, 0x217A, 0x391B, 0x3911, 0x3914, 0x392C, 0x2174, 0x3933, 0x392B, 0x217D, 0x3920,
+    0x2188, 0x2187, 0x2181, 0x3932, 0x2175, 0x391E, 0x3915, 0x3922, 0x392E, 0x2189, 0x4C34, 0x2177, 0x391D, 0x3916, 0x2180, 0x217F,
+    0x3919, 0x3923, 0x392D, 0x3910, 0x391A, 0x2178, 0x3924, 0x2185, 0x217C, 0x3917, 0x217B, 0x390E, 0x391C, 0x3930, 0x1FE3, 0x2343,
+    0x391F, 0x3BA9, 0x3921, 0x392F, 0x2182, 0x3918, 0x2176, 0x2186, 0x24BD, 0x390D, 0x3926, 0x4A93, 0x4A59, 0x3925, 0x3BC5, 0x2340,
+    0x3BBA, 0x3BBF, 0x2335, 0x3BAB, 0x2342, 0x232C, 0x3BAA, 0x3BCB, 0x2344, 0x3BAE, 0x3BBE, 0x4C71, 0x3BB0, 0x3BCE, 0x3BCA, 0x232A,
+    0x2334, 0x3E04, 0x2341, 0x2332, 0x3BAC, 0x4C5A, 0x3BC9, 0x2336, 0x3BC6, 0x3BD3, 0x3BB2, 0x3BBB, 0x3BD1, 0x3BC7, 0x2331, 0x3BB9,
+    0x232B, 0x2329, 0x233B, 0x4BD3, 0x3BB7, 0x3BB4, 0x3BB5, 0x3BCD, 0x3BD2, 0x3BD0, 0x232F, 0x3BD4, 0x2333, 0x233A, 0x3BAD, 0x3DEC,
+    0x3BB6, 0x232D, 0x233F, 0x233C, 0x2330, 0x3BC0, 0x3BB1, 0x233D, 0x3BC2, 0x2337, 0x2339, 0x2328, 0x3BC4, 0x3BBC, 0x3BAF, 0x2338,
+    0x3BCC, 0x3BCF, 0x3BB8, 0x3BC3, 0x4CB9, 0x232E, 0x24AC, 0x3BC1, 0x3DEB, 0x3BC8, 0x4C76, 0x3BB3, 0x3DF5, 0x3DFF, 0x24AF, 0x3DFE,
+    0x3E0A, 0x24B1, 0x3DFC, 0x3E00, 0x24BC, 0x3DF7, 0x24B4, 0x4C77, 0x3E03, 0x4056, 0x24C0, 0x24BF, 0x3E05, 0x3DF4, 0x3E0F, 0x24BA,
+    0x24B0, 0x3E08, 0x3E0C, 0x3E11, 0x3E06, 0x3DFA, 0x24B3, 0x24B6, 0x24BE, 0x24B9, 0x3DFD, 0x3BBD, 0x4C79, 0x3DF9, 0x3E10, 0x24B5,
+    0x24B8, 0x3E12, 0x24AD, 0x3E07, 0x3DED, 0x3E0D, 0x2720, 0x3DFB, 0x24AE, 0x3DF6, 0x24B2, 0x3DEE, 0x233E, 0x3DF2, 0x4BC7, 0x3E0B,
+    0x3DF3, 0x3DF1, 0x24B7, 0x3E09, 0x3DF0, 0x3E02, 0x3E01, 0x3E0E, 0x3DF8, 0x24BB, 0x4C6F, 0x4059, 0x4C7C, 0x260F, 0x2605, 0x4065,
+    0x4051, 0x2607, 0x405D, 0x260E, 0x2609, 0x405B, 0x424B, 0x4061, 0x4067, 0x4060, 0x404F, 0x405E, 0x4069, 0x406B, 0x2604, 0x406A,
+    0x260A, 0x2610, 0x260D, 0x4064, 0x260C, 0x405F, 0x4053, 0x4058, 0x4063, 0x260B, 0x2608, 0x2603, 0x2606, 0x4057, 0x3DEF, 0x4052,
+    0x4055, 0x4066, 0x405A, 0x4050, 0x424A, 0x4068, 0x4054, 0x4062, 0x4C7E, 0x424D, 0x2724, 0x2718, 0x271C, 0x424F, 0x4255, 0x4253,
+    0x271A, 0x271B, 0x2722, 0x4256, 0x424E, 0x271D, 0x4252, 0x4251, 0x4257, 0x2723, 0x271E, 0x2721, 0x4250, 0x2725, 0x271F, 0x2726,
+    0x2719, 0x4254, 0x424C, 0x440B, 0x4C6D, 0x280E, 0x4408, 0x440A, 0x280C, 0x4406, 0x4407, 0x4402, 0x405C, 0x4403, 0x280D, 0x43FF,
+    0x280A, 0x440C, 0x280B, 0x4400, 0x4401, 0x4405, 0x2810, 0x280F, 0x4404, 0x28B2, 0x456C, 0x4572, 0x28B3, 0x4569, 0x28B0, 0x28AD,
+    0x4574, 0x28B1, 0x28AE, 0x456B, 0x456E, 0x456F, 0x4570, 0x4573, 0x4409, 0x456A, 0x28AF, 0x4571, 0x4575, 0x456D, 0x46A4, 0x2941,
+    0x46A7, 0x2942, 0x46AF, 0x46A6, 0x46A3, 0x46A5, 0x46A9, 0x46AC, 0x46AB, 0x46AE, 0x46A2, 0x2940, 0x46AA, 0x46AD, 0x46B0, 0x46A8,
+    0x4795, 0x4796, 0x479A, 0x479B, 0x4798, 0x4799, 0x4797, 0x4C8A, 0x299E, 0x29EC, 0x484A, 0x4847, 0x4848, 0x4849, 0x29ED, 0x48E1,
+    0x48E0, 0x4948, 0x2A46, 0x4946, 0x4949, 0x4947, 0x498E, 0x2A68, 0x2A76, 0x49D9, 0x49C4, 0x49D8, 0x4A06, 0x4A07, 0x160A, 0x1716,
+    0x2CC6, 0x2D80, 0x180A, 0x180D, 0x4AC3, 0x2D81, 0x180B, 0x180C, 0x4C8B, 0x4C8D, 0x2EB4, 0x2EB6, 0x2EB1, 0x2EAE, 0x2EB0, 0x1967,
+    0x4AF5, 0x1965, 0x2EB2, 0x1966, 0x2EAF, 0x2EAC, 0x1964, 0x2EB3, 0x2EAD, 0x1968, 0x2EB5, 0x3023, 0x3027, 0x1AB4, 0x4C8C, 0x302A,
+    0x1AAC, 0x1AAF, 0x1AB1, 0x1AB3, 0x1AB0, 0x3026, 0x3025, 0x1AAE, 0x3028, 0x3029, 0x3022, 0x1AB2, 0x1AAD, 0x4CA7, 0x3024, 0x31E4,
+    0x31E5, 0x31E6, 0x1C46, 0x1C42, 0x31DB, 0x4C8E, 0x31E3, 0x31E8, 0x1C47, 0x31D9, 0x31DF, 0x31D8, 0x31E2, 0x4C8F, 0x4C90, 0x31DE,
+    0x1C43, 0x1C45, 0x31E7, 0x31D7, 0x31DA, 0x31E0, 0x4C91, 0x31E1, 0x31E9, 0x31DD, 0x1C44, 0x1E3A, 0x342F, 0x3431, 0x342D, 0x3439,
+    0x342B, 0x3438, 0x1E36, 0x3429, 0x36B0, 0x3433, 0x1E39, 0x3434, 0x3437, 0x343B, 0x3430, 0x3435, 0x1E37, 0x1E38, 0x343A, 0x342E,
+    0x342A, 0x343C, 0x3432, 0x3436, 0x342C, 0x1FEC, 0x1FED, 0x36BA, 0x1FF3, 0x36AE, 0x36B7, 0x36AD, 0x1FF0, 0x36B5, 0x36B3, 0x36B4,
+    0x1FEE, 0x36B8, 0x4C97, 0x4CA1, 0x36B1, 0x36AF, 0x1FEF, 0x36B2, 0x36B6, 0x1FF1, 0x36B9, 0x3939, 0x3940, 0x3942, 0x3948, 0x4C98,
+    0x2196, 0x3934, 0x218E, 0x4C9A, 0x3943, 0x2193, 0x3949, 0x218A, 0x4C9E, 0x3946, 0x3936, 0x3947, 0x3935, 0x2198, 0x3941, 0x218B,
+    0x394A, 0x2190, 0x393A, 0x2195, 0x3945, 0x3938, 0x393F, 0x393B, 0x3937, 0x218D, 0x2194, 0x2192, 0x218F, 0x2197, 0x218C, 0x393E,
+    0x2191, 0x1FF2, 0x3944, 0x393C, 0x393D, 0x3BDC, 0x2347, 0x3BD8, 0x3BDE, 0x3BDA, 0x2349, 0x3BD9, 0x3BDD, 0x3BD5, 0x3BD7, 0x2348,
+    0x3BDB, 0x3BD6, 0x234A, 0x2345, 0x4C95, 0x3BDF, 0x2346, 0x3E18, 0x3E15, 0x3E21, 0x3E1B, 0x3E1D, 0x24C1, 0x3E17, 0x3E1F, 0x3E1E,
+    0x3E1C, 0x3E22, 0x24C4, 0x3E19, 0x3E20, 0x24C2, 0x3E14, 0x3E16, 0x24C3, 0x3E13, 0x3E23, 0x4C94, 0x3E1A, 0x406F, 0x2617, 0x4CA5,
+    0x407A, 0x4078, 0x2611, 0x406E, 0x4071, 0x4072, 0x406D, 0x261B, 0x261C, 0x406C, 0x407B, 0x4076, 0x2615, 0x2612, 0x4075, 0x4073,
+    0x2618, 0x4077, 0x2613, 0x2614, 0x4074, 0x2616, 0x4070, 0x4079, 0x2619, 0x407C, 0x407D, 0x261A, 0x4AA2, 0x2728, 0x272F, 0x4258,
+    0x425E, 0x425C, 0x272B, 0x272A, 0x2727, 0x425A, 0x272D, 0x272C, 0x2729, 0x425D, 0x4259, 0x425B, 0x272E, 0x2814, 0x440F, 0x2811,
+    0x2812, 0x4411, 0x2813, 0x440E, 0x440D, 0x4579, 0x4410, 0x457A, 0x28B4, 0x4578, 0x4577, 0x4576, 0x28B5, 0x4CA8, 0x2943, 0x46B1,
+    0x46B2, 0x4CAA, 0x479E, 0x479D, 0x299F, 0x479C, 0x484B, 0x484C, 0x48E2, 0x494A, 0x4CAD, 0x498F, 0x49F2, 0x2A8A, 0x49FC, 0x160B,
+    0x1969, 0x196A, 0x4CB0, 0x1AB5, 0x2730, 0x160C, 0x196B, 0x1C48, 0x2199, 0x160D, 0x1E3B, 0x234B, 0x2C4A, 0x4CB1, 0x302B, 0x31EA,
+    0x3BE0, 0x2731, 0x160E, 0x196C, 0x302C, 0x302D, 0x36BB, 0x1FF4, 0x394B, 0x219A, 0x3BE1, 0x4CB4, 0x24C5, 0x4CB5, 0x28B6, 0x160F,
+    0x36BC, 0x1610, 0x1718, 0x2CC7, 0x1717, 0x1810, 0x180F, 0x180E, 0x2D82, 0x196D, 0x196E, 0x2EB7, 0x302E, 0x3031, 0x1AB7, 0x302F,
+    0x1AB6, 0x3030, 0x1AB8, 0x31ED, 0x31EC, 0x31EB, 0x1C49, 0x343E, 0x343F, 0x1E3C, 0x343D, 0x3440, 0x1FF6, 0x1E3D, 0x4CBA, 0x1FF5,
+    0x36C0, 0x36BF, 0x36BD, 0x36BE, 0x36C1, 0x394D, 0x394C, 0x3950, 0x394F, 0x394E, 0x234C, 0x3BE4, 0x3BE3, 0x234D, 0x3BE2, 0x3E24,
+    0x3E25, 0x24C6, 0x407E, 0x407F, 0x457F, 0x28B7, 0x457E, 0x457D, 0x457B, 0x457C, 0x29A0, 0x46B3, 0x484D, 0x4990, 0x1611, 0x2C6B,
+    0x1679, 0x2C6C, 0x2CC8, 0x2CC9, 0x2D88, 0x2D84, 0x2D83, 0x196F, 0x2D87, 0x1812, 0x2D85, 0x1811, 0x2D89, 0x2D86, 0x2EBB, 0x3032,
+    0x2EB9, 0x2EC0, 0x1970, 0x1973, 0x2EC1, 0x2EBD, 0x2EBE, 0x2EB8, 0x1972, 0x2EBA, 0x1971, 0x2EBF, 0x2EBC, 0x3036, 0x1ABA, 0x1ABB,
+    0x3039, 0x3033, 0x3038, 0x3034, 0x1AB9, 0x3037, 0x3035, 0x31F3, 0x31F0, 0x31F2, 0x1C4E, 0x1C4D, 0x1C4B, 0x31EF, 0x31F4, 0x1C4A,
+    0x1C4C, 0x31F1, 0x3448, 0x31EE, 0x31F5, 0x3443, 0x3447, 0x3446, 0x36C3, 0x36CE, 0x3449, 0x3444, 0x36C2, 0x1E41, 0x1E40, 0x3442,
+    0x3445, 0x1E42, 0x1E3F, 0x1E3E, 0x3441, 0x344A, 0x36C5, 0x36CC, 0x1FF8, 0x36CB, 0x36C8, 0x4CC1, 0x1FFA, 0x4CBB, 0x4CBC, 0x36CA,
+    0x4CC2, 0x36C4, 0x36C6, 0x36C9, 0x36C7, 0x1FF9, 0x36CD, 0x1FF7, 0x219B, 0x3954, 0x3953, 0x3951, 0x219E, 0x219D, 0x3955, 0x3952,
+    0x3BE5, 0x234E, 0x219C, 0x3957, 0x3956, 0x3BE8, 0x3BE6, 0x24C7, 0x234F, 0x3BE7, 0x3E27, 0x24C8, 0x3E26, 0x3E2F, 0x3E2E, 0x3E2C,
+    0x3E2B, 0x3E28, 0x3E29, 0x3E2A, 0x3E2D, 0x3E30, 0x4084, 0x4081, 0x4082, 0x261D, 0x4080, 0x4086, 0x4085, 0x4083, 0x4260, 0x4261,
+    0x2732, 0x2733, 0x425F, 0x2816, 0x4412, 0x2815, 0x28B8, 0x28B9, 0x2944, 0x46B5, 0x46B4, 0x479F, 0x484E, 0x29EE, 0x48E4, 0x48E3,
+    0x48E5, 0x167A, 0x303A, 0x1C4F, 0x1E43, 0x344B, 0x167B, 0x2C6D, 0x1612, 0x2CCA, 0x2D8E, 0x2D8C, 0x2D8D, 0x2D8A, 0x1813, 0x2D8B,
+    0x4AB4, 0x2EC9, 0x1976, 0x2EC7, 0x2EC3, 0x2EC6, 0x2EC2, 0x1978, 0x2EC5, 0x4AB1, 0x1975, 0x1974, 0x4CC4, 0x1977, 0x2EC8, 0x2EC4,
+    0x1ABF, 0x1AC2, 0x3042, 0x3041, 0x3040, 0x1ABC, 0x3049, 0x303F, 0x1ABE, 0x31FA, 0x3046, 0x3044, 0x1AC1, 0x303C, 0x3047, 0x303E,
+    0x3048, 0x3045, 0x303D, 0x4CC7, 0x1ABD, 0x304A, 0x303B, 0x1AC0, 0x4B47, 0x4CC3, 0x31FF, 0x31F6, 0x3201, 0x4A9D, 0x31F9, 0x3204,
+    0x3205, 0x31F7, 0x3203, 0x3200, 0x31FE, 0x3202, 0x1C55, 0x1C53, 0x31FC, 0x4CDD, 0x31F8, 0x31FB, 0x3206, 0x31FD, 0x1C54, 0x3043,
+    0x1C50, 0x1C52, 0x4A3B, 0x3459, 0x344E, 0x344C, 0x4CD1, 0x344D, 0x3454, 0x3455, 0x3451, 0x1E48, 0x3456, 0x3453, 0x3450, 0x1E46,
+    0x344F, 0x1E44, 0x1E47, 0x3452, 0x345A, 0x1C51, 0x1E45, 0x3458, 0x3457, 0x1E49, 0x4CD2, 0x36D3, 0x4AD7, 0x36D4, 0x2004, 0x36DA,
+    0x36DC, 0x36D5, 0x1FFE, 0x36D9, 0x36D8, 0x1FFF, 0x2005, 0x2006, 0x36DB, 0x1FFC, 0x36D2, 0x36D0, 0x36D6, 0x36CF, 0x2003, 0x36D1,
+    0x36D7, 0x36DD, 0x1FFD, 0x2002, 0x2000, 0x2001, 0x4CD5, 0x1FFB, 0x21A5, 0x395E, 0x21A4, 0x3962, 0x3958, 0x3963, 0x4CDC, 0x4CC8,
+    0x3959, 0x395A, 0x3964, 0x3961, 0x395F, 0x3960, 0x395C, 0x395B, 0x4C67, 0x3965, 0x21A1, 0x395D, 0x21A6, 0x21A0, 0x21A7, 0x21A8,
+    0x4D00, 0x21A3, 0x21A2, 0x4CDA, 0x3BE9, 0x2351, 0x2350, 0x4B2B, 0x3BEE, 0x24C9, 0x2352, 0x4CE0, 0x2354, 0x3BEF, 0x219F, 0x2353,
+    0x3BEB, 0x3BED, 0x3BEA, 0x4CE2, 0x3BEC, 0x4CDE, 0x3E3A, 0x3E39, 0x3E36, 0x24CC, 0x4087, 0x24CD, 0x3E35, 0x4CE5, 0x24CB, 0x3E37,
+    0x3E34, 0x3E31, 0x3E38, 0x3E32, 0x3E33, 0x24CA, 0x4265, 0x408B, 0x408A, 0x408C, 0x4262, 0x2620, 0x4088, 0x261E, 0x2622, 0x2621,
+    0x4089, 0x408D, 0x261F, 0x4BA0, 0x4269, 0x2736, 0x2817, 0x2737, 0x2734, 0x4266, 0x4264, 0x4267, 0x426A, 0x2735, 0x4268, 0x4263,
+    0x4415, 0x4418, 0x4582, 0x4413, 0x46B6, 0x4419, 0x28BA, 0x4417, 0x2818, 0x4414, 0x4416, 0x441A, 0x4583, 0x4581, 0x4CEE, 0x28BB,
+    0x4580, 0x2945, 0x29A2, 0x4850, 0x29A1, 0x4852, 0x484F, 0x4851, 0x2A22, 0x494B, 0x167C, 0x2ECA, 0x3207, 0x3208, 0x1E4A, 0x3966,
+    0x2623, 0x28BC, 0x29EF, 0x494C, 0x167D, 0x2ECB, 0x145D, 0x304B, 0x304C, 0x3209, 0x320A, 0x1E4B, 0x1E4C, 0x36DE, 0x3969, 0x3968,
+    0x3967, 0x3BF0, 0x3BF1, 0x3BF2, 0x2355, 0x3E3C, 0x3E3B, 0x408E, 0x2624, 0x2625, 0x4CF0, 0x426E, 0x426B, 0x426C, 0x426D, 0x441C,
+    0x441B, 0x2819, 0x4584, 0x47A0, 0x167E, 0x1AC3, 0x1E4D, 0x396A, 0x4CF2, 0x167F, 0x320B, 0x1E4E, 0x2007, 0x2008, 0x1680, 0x1681,
+    0x2CCB, 0x1815, 0x1814, 0x1AC4, 0x304D, 0x36DF, 0x1682, 0x1683, 0x1684, 0x1685, 0x1816, 0x1817, 0x2D90, 0x2D8F, 0x1979, 0x2ECE,
+    0x2ECC, 0x2ECD, 0x304E, 0x304F, 0x4CF9, 0x1AC8, 0x1AC6, 0x4CF8, 0x1AC7, 0x1AC5, 0x1C56, 0x1C5A, 0x1C59, 0x320C, 0x1C58, 0x1C57,
+    0x320D, 0x1E51, 0x345C, 0x345B, 0x1E4F, 0x1E50, 0x4CFA, 0x200A, 0x2009, 0x36E1, 0x4CFB, 0x36E0, 0x1E52, 0x21A9, 0x396C, 0x21AA,
+    0x396B, 0x3BF3, 0x3E3D, 0x24CE, 0x408F, 0x426F, 0x28BE, 0x28BD, 0x29F0, 0x1686, 0x2ECF, 0x4CFF, 0x1E53, 0x3BF4, 0x2356, 0x2AC3,
+    0x2D91, 0x2D92, 0x2ED0, 0x197B, 0x197C, 0x197A, 0x4D01, 0x1ACC, 0x1ACD, 0x1ACA, 0x1ACB, 0x3050, 0x3051, 0x1AC9, 0x320E, 0x1C5E,
+    0x1C5F, 0x4D02, 0x1E56, 0x3214, 0x1C64, 0x1C62, 0x3215, 0x3210, 0x1C61, 0x1C60, 0x1C5B, 0x3213, 0x3212, 0x320F, 0x1C63, 0x3211,
+    0x1C5C, 0x1C5D, 0x1E57, 0x3460, 0x3461, 0x1E58, 0x345D, 0x345F, 0x3463, 0x3462, 0x345E, 0x1E54, 0x1E55, 0x36E9, 0x200F, 0x200E,
+    0x36E3, 0x200C, 0x36E6, 0x2010, 0x36E7, 0x2011, 0x36E4, 0x200B, 0x200D, 0x36E8, 0x36E5, 0x36E2, 0x3979, 0x396E, 0x21AC, 0x21AF,
+    0x21AE, 0x21B3, 0x21B2, 0x397A, 0x3978, 0x3971, 0x3975, 0x3974, 0x21B0, 0x3977, 0x3973, 0x397B, 0x3972, 0x21B1, 0x21AB, 0x21AD,
+    0x4D03, 0x3970, 0x3BF6, 0x235A, 0x3BFA, 0x2359, 0x3BF7, 0x2358, 0x396F, 0x3976, 0x3BF9, 0x235B, 0x3BFB, 0x3BF8, 0x3BF5, 0x3E40,
+    0x3E44, 0x3E46, 0x3E42, 0x3E41, 0x3E3F, 0x24D1, 0x24CF, 0x24D4, 0x24D5, 0x3E43, 0x24D2, 0x3E3E, 0x24D3, 0x2357, 0x3E45, 0x24D0,
+    0x4D04, 0x4091, 0x4090, 0x4098, 0x4092, 0x4097, 0x4094, 0x2626, 0x4096, 0x2627, 0x2628, 0x4095, 0x4093, 0x2739, 0x4270, 0x2738,
+    0x4273, 0x4271, 0x4272, 0x273A, 0x4D06, 0x4420, 0x4D05, 0x281C, 0x4421, 0x281A, 0x4422, 0x281B, 0x441F, 0x4423, 0x441D, 0x28BF,
+    0x4585, 0x28C0, 0x2946, 0x441E, 0x2947, 0x29A3, 0x47A1, 0x29F2, 0x4853, 0x29F1, 0x4D07, 0x48E6, 0x2A47, 0x2A48, 0x4A02, 0x2AC4,
+    0x1ACE, 0x3052, 0x2012, 0x2013, 0x1687, 0x1719, 0x2CCC, 0x2D93, 0x1818, 0x197D, 0x1ACF, 0x1AD0, 0x1AD1, 0x3465, 0x3216, 0x1C65,
+    0x1E59, 0x3464, 0x4D0A, 0x36EB, 0x2015, 0x36EA, 0x2014, 0x397C, 0x24D6, 0x3E4A, 0x3E47, 0x3E48, 0x3E49, 0x4D0F, 0x4274, 0x4D10,
+    0x4424, 0x46B8, 0x46B7, 0x4854, 0x1688, 0x2ED1, 0x1C66, 0x2016, 0x397D, 0x4D11, 0x3BFC, 0x24D7, 0x4099, 0x4425, 0x46B9, 0x1689,
+    0x197E, 0x1AD4, 0x3053, 0x1AD5, 0x1AD3, 0x1AD2, 0x3217, 0x1C67, 0x4D12, 0x1C68, 0x1C69, 0x1E5B, 0x3466, 0x1E5A, 0x36EC, 0x1E5C,
+    0x2017, 0x397E, 0x21B4, 0x21B5, 0x235C, 0x235D, 0x24D8, 0x262A, 0x409A, 0x2629, 0x4275, 0x273B, 0x4426, 0x46BA, 0x168A, 0x1819,
+    0x2ED4, 0x2ED3, 0x197F, 0x2ED2, 0x1980, 0x2ED5, 0x3059, 0x1AD8, 0x1AD7, 0x305B, 0x305A, 0x1ADC, 0x1ADB, 0x1AD6, 0x3055, 0x3056,
+    0x3057, 0x1ADD, 0x3054, 0x1AD9, 0x3058, 0x1ADA, 0x321A, 0x321E, 0x321C, 0x321B, 0x321F, 0x3220, 0x3221, 0x3219, 0x3218, 0x4D18,
+    0x1C6B, 0x1C6C, 0x3222, 0x321D, 0x346F, 0x4D19, 0x3223, 0x1C6D, 0x1C6A, 0x3469, 0x3468, 0x346A, 0x346B, 0x346D, 0x346C, 0x3471,
+    0x1E60, 0x1E5D, 0x1E61, 0x3467, 0x1E62, 0x3470, 0x1E5F, 0x346E, 0x1E5E, 0x4D1A, 0x36EF, 0x36F1, 0x36ED, 0x36EE, 0x36F2, 0x36F4,
+    0x36F5, 0x36F0, 0x36F3, 0x2018, 0x3982, 0x3987, 0x397F, 0x3983, 0x4D1D, 0x3988, 0x3984, 0x21B6, 0x21BE, 0x21B9, 0x3980, 0x3981,
+    0x2361, 0x21C1, 0x21BA, 0x21BF, 0x21B8, 0x3986, 0x21C0, 0x3985, 0x21BC, 0x21B7, 0x21BD, 0x3989, 0x3C01, 0x3C03, 0x21BB, 0x3BFE,
+    0x235F, 0x3C04, 0x2360, 0x3C02, 0x3BFD, 0x3C00, 0x3C05, 0x235E, 0x3BFF, 0x24DA, 0x3E4E, 0x3E4D, 0x24DD, 0x24DB, 0x3E4B, 0x24D9,
+    0x3E4C, 0x24DC, 0x40A2, 0x40A4, 0x40A3, 0x409B, 0x409F, 0x409E, 0x409C, 0x262C, 0x262D, 0x262B, 0x409D, 0x40A0, 0x40A1, 0x262E,
+    0x2740, 0x427D, 0x273D, 0x4277, 0x273F, 0x2741, 0x4D1F, 0x273E, 0x427C, 0x4278, 0x273C, 0x427B, 0x4276, 0x427A, 0x4279, 0x4428,
+    0x281F, 0x2820, 0x281D, 0x281E, 0x4427, 0x4588, 0x4BF5, 0x28C1, 0x4586, 0x4587, 0x46BB, 0x46BE, 0x46BC, 0x46BD, 0x47A2, 0x29A4,
+    0x48E7, 0x494D, 0x2A49, 0x4991, 0x4992, 0x2A77, 0x168B, 0x1ADE, 0x36F6, 0x398A, 0x168C, 0x181A, 0x1981, 0x4AAB, 0x305C, 0x305D,
+    0x1C6E, 0x36F7, 0x2019, 0x21C2, 0x2742, 0x427E, 0x4589, 0x46BF, 0x168D, 0x4D23, 0x2EDB, 0x2ED6, 0x2ED8, 0x2EDA, 0x2ED9, 0x2ED7,
+    0x1982, 0x1ADF, 0x3066, 0x3061, 0x305E, 0x3065, 0x1AE1, 0x1AE2, 0x3064, 0x3063, 0x3062, 0x305F, 0x3060, 0x3067, 0x1AE0, 0x1C72,
+    0x1C78, 0x1C77, 0x322C, 0x3226, 0x3224, 0x1C75, 0x3478, 0x1C70, 0x3229, 0x322D, 0x322F, 0x322B, 0x3225, 0x1C76, 0x322A, 0x3228,
+    0x1C6F, 0x3230, 0x1C79, 0x322E, 0x1C73, 0x3227, 0x1C74, 0x1C71, 0x4A44, 0x1E64, 0x3479, 0x3472, 0x3474, 0x3476, 0x3477, 0x3475,
+    0x1E65, 0x347A, 0x3473, 0x36FB, 0x201A, 0x3703, 0x36F8, 0x3704, 0x36F9, 0x36FA, 0x3702, 0x3701, 0x36FE, 0x1E63, 0x201B, 0x36FC,
+    0x36FF, 0x201C, 0x3700, 0x36FD, 0x3995, 0x3999, 0x21C9, 0x3C0B, 0x21CC, 0x3997, 0x4A0C, 0x3994, 0x398F, 0x3991, 0x3992, 0x398B,
+    0x21C8, 0x21C7, 0x21C3, 0x398E, 0x21CA, 0x21CB, 0x398D, 0x3990, 0x3998, 0x21C5, 0x21C6, 0x3996, 0x398C, 0x3C0D, 0x2363, 0x3C0F,
+    0x3993, 0x3C11, 0x2367, 0x3C12, 0x3C0E, 0x2364, 0x3C0A, 0x2366, 0x3C07, 0x3C0C, 0x3C10, 0x3C09, 0x4D28, 0x21C4, 0x4D30, 0x3C06,
+    0x2365, 0x3C08, 0x24E0, 0x3E50, 0x24E4, 0x24E2, 0x2362, 0x3E57, 0x3E58, 0x24DF, 0x4D29, 0x3E56, 0x3E59, 0x24E1, 0x24DE, 0x3E52,
+    0x3E4F, 0x3E54, 0x3E51, 0x24E5, 0x3E53, 0x4D2A, 0x3E55, 0x24E3, 0x4D2E, 0x2630, 0x40AB, 0x40A5, 0x40A9, 0x40AF, 0x40B0, 0x40AC,
+    0x40AD, 0x40AA, 0x40A7, 0x2632, 0x262F, 0x40A6, 0x40A8, 0x2631, 0x40AE, 0x2746, 0x4AE8, 0x4284, 0x427F, 0x2745, 0x2743, 0x4286,
+    0x2744, 0x4282, 0x4283, 0x4280, 0x4287, 0x4429, 0x2747, 0x4281, 0x4288, 0x4285, 0x442D, 0x442A, 0x2821, 0x442E, 0x4430, 0x442F,
+    0x442B, 0x442C, 0x458E, 0x28C2, 0x458B, 0x458D, 0x458A, 0x458F, 0x458C, 0x46C1, 0x46C4, 0x46C0, 0x2948, 0x46C2, 0x46C3, 0x46C5,
+    0x2949, 0x294B, 0x294A, 0x47A3, 0x4D2C, 0x47A5, 0x47A4, 0x4D2F, 0x4855, 0x494E, 0x4993, 0x168E, 0x4D3E, 0x2D94, 0x1983, 0x2EDD,
+    0x1984, 0x1985, 0x2EDC, 0x306C, 0x306B, 0x1AE3, 0x1AE6, 0x1AE5, 0x1AE4, 0x3068, 0x306A, 0x3069, 0x3233, 0x1C7B, 0x3237, 0x3236,
+    0x3235, 0x3231, 0x1C7A, 0x1C7E, 0x1C81, 0x1C82, 0x3232, 0x3234, 0x1C80, 0x1C7F, 0x1C7D, 0x1C7C, 0x3481, 0x347F, 0x347B, 0x1E66,
+    0x347C, 0x1E67, 0x347D, 0x347E, 0x3480, 0x1E68, 0x3708, 0x4B98, 0x3707, 0x3706, 0x3705, 0x399D, 0x21CD, 0x399A, 0x399C, 0x21CE,
+    0x4D3A, 0x21CF, 0x399B, 0x4D37, 0x3C1B, 0x3C14, 0x3C15, 0x236A, 0x2368, 0x2369, 0x3C1D, 0x3C1C, 0x3C19, 0x3C18, 0x3C17, 0x3C16,
+    0x3C1A, 0x3C13, 0x3E5A, 0x3E5F, 0x3E5D, 0x4D3C, 0x3E5C, 0x3E5B, 0x3E5E, 0x40B1, 0x2633, 0x2748, 0x428A, 0x4D3F, 0x2749, 0x4289,
+    0x4432, 0x4431, 0x2822, 0x4590, 0x28C3, 0x46C6, 0x29F3, 0x4856, 0x4950, 0x494F, 0x2C6E, 0x1AE7, 0x1AE8, 0x3482, 0x21D1, 0x168F,
+    0x181D, 0x181C, 0x181B, 0x2EDE, 0x4D43, 0x1987, 0x1986, 0x1AEB, 0x4C69, 0x306E, 0x3071, 0x306F, 0x1AE9, 0x1AEA, 0x4D45, 0x306D,
+    0x3070, 0x1C89, 0x323E, 0x3240, 0x323F, 0x1C86, 0x323A, 0x1C84, 0x1C83, 0x1C87, 0x1C85, 0x1C88, 0x323D, 0x3238, 0x3239, 0x323C,
+    0x323B, 0x3485, 0x3486, 0x3484, 0x3483, 0x1E69, 0x2021, 0x3709, 0x370B, 0x370D, 0x2020, 0x201E, 0x370A, 0x201F, 0x370C, 0x201D,
+    0x39A7, 0x399E, 0x39A1, 0x39A5, 0x21D5, 0x39A3, 0x39A2, 0x399F, 0x39A0, 0x21D3, 0x39A6, 0x21D2, 0x21D7, 0x21D6, 0x21D4, 0x39A4,
+    0x3C23, 0x3C22, 0x3C1E, 0x236B, 0x3C21, 0x3C20, 0x236C, 0x24EA, 0x3E61, 0x24EB, 0x24E7, 0x24E9, 0x24E6, 0x24E8, 0x40B2, 0x2636,
+    0x40B4, 0x40B3, 0x3C1F, 0x2638, 0x2637, 0x2634, 0x2635, 0x428F, 0x428D, 0x274A, 0x428E, 0x4290, 0x428C, 0x428B, 0x4433, 0x2825,
+    0x2823, 0x2824, 0x4591, 0x4592, 0x28C5, 0x28C4, 0x46C8, 0x46C9, 0x46C7, 0x4857, 0x4858, 0x1690, 0x2CCD, 0x181E, 0x2EDF, 0x1989,
+    0x1988, 0x2EE0, 0x3073, 0x1AEC, 0x3072, 0x1AED, 0x1C8A, 0x3243, 0x3241, 0x3247, 0x1C8B, 0x3242, 0x3246, 0x3244, 0x3245, 0x3487,
+    0x3489, 0x1E6A, 0x3488, 0x1E6B, 0x2024, 0x2023, 0x2022, 0x370E, 0x39AA, 0x21D8, 0x21D9, 0x39A9, 0x39A8, 0x3C24, 0x236E, 0x236D,
+    0x3C25, 0x3C26, 0x24ED, 0x24EC, 0x40B8, 0x3E62, 0x3E64, 0x3E63, 0x40B7, 0x40B5, 0x40B9, 0x40B6, 0x2639, 0x4291, 0x274B, 0x4292,
+    0x4293, 0x2826, 0x2827, 0x294C, 0x2A23, 0x1691, 0x3074, 0x3248, 0x1C8C, 0x1F02, 0x1F01, 0x2026, 0x3710, 0x2025, 0x370F, 0x39AB,
+    0x236F, 0x3C27, 0x2370, 0x294D, 0x46CA, 0x171A, 0x198A, 0x2EE1, 0x1AEF, 0x1AEE, 0x3075, 0x3076, 0x324A, 0x324C, 0x1C8D, 0x324E,
+    0x3251, 0x324F, 0x3250, 0x324D, 0x3249, 0x1C8E, 0x3252, 0x324B, 0x3492, 0x1E71, 0x349B, 0x1E6E, 0x3494, 0x1E72, 0x1E6C, 0x348F,
+    0x349C, 0x3490, 0x348D, 0x1E70, 0x1E6D, 0x3493, 0x3496, 0x1E6F, 0x3497, 0x1E73, 0x3498, 0x348E, 0x3495, 0x3499, 0x3491, 0x348C,
+    0x348A, 0x349A, 0x3712, 0x3717, 0x3713, 0x3719, 0x2029, 0x348B, 0x3714, 0x2027, 0x3711, 0x202E, 0x3715, 0x202D, 0x3716, 0x202F,
+    0x202A, 0x2030, 0x202B, 0x202C, 0x2028, 0x3718, 0x21DC, 0x39B6, 0x39B9, 0x39AD, 0x39B2, 0x39AC, 0x21DE, 0x39B0, 0x39AE, 0x21DD,
+    0x39B5, 0x39B4, 0x39B1, 0x39B3, 0x39AF, 0x2374, 0x39B8, 0x21DA, 0x39B7, 0x3C37, 0x237B, 0x3C32, 0x237A, 0x3C28, 0x3C2A, 0x2373,
+    0x3C2F, 0x3C2E, 0x3C31, 0x2378, 0x3C2C, 0x3C2B, 0x2377, 0x2372, 0x3C2D, 0x2375, 0x3C33, 0x3C35, 0x3C30, 0x3C29, 0x2376, 0x24F5,
+    0x2371, 0x3C36, 0x3E68, 0x24EE, 0x3E6A, 0x24EF, 0x24F1, 0x3E6D, 0x3E65, 0x2379, 0x3E6B, 0x3E67, 0x21DB, 0x24F4, 0x24F0, 0x24F2,
+    0x24F3, 0x263C, 0x3E6C, 0x3E66, 0x24F6, 0x3E69, 0x40C3, 0x40BE, 0x40C8, 0x263A, 0x40C0, 0x263E, 0x40C6, 0x40BD, 0x40BA, 0x40C9,
+    0x2751, 0x263F, 0x40C5, 0x40BB, 0x263D, 0x40BF, 0x2641, 0x40BC, 0x40C1, 0x2640, 0x40C4, 0x40C7, 0x42A5, 0x42A6, 0x4296, 0x429D,
+    0x429B, 0x274F, 0x42A2, 0x40C2, 0x4299, 0x42A3, 0x274E, 0x4298, 0x4297, 0x42A1, 0x429E, 0x42A0, 0x4294, 0x42A4, 0x274C, 0x429F,
+    0x42A8, 0x429C, 0x2750, 0x274D, 0x429A, 0x4295, 0x42A7, 0x263B, 0x4A8B, 0x4436, 0x4434, 0x443A, 0x282B, 0x4438, 0x4437, 0x282D,
+    0x443D, 0x282C, 0x443E, 0x443B, 0x2829, 0x443C, 0x4435, 0x282A, 0x2828, 0x4596, 0x4439, 0x443F, 0x4593, 0x28CA, 0x28C8, 0x4595,
+    0x4597, 0x4594, 0x28C9, 0x28C6, 0x28C7, 0x28CB, 0x294F, 0x46CF, 0x46CE, 0x46CC, 0x46CB, 0x46CD, 0x294E, 0x2950, 0x29A5, 0x47A7,
+    0x47A6, 0x4859, 0x485B, 0x485D, 0x485C, 0x485A, 0x29F5, 0x29F4, 0x2A25, 0x2A24, 0x2A26, 0x48E9, 0x48E8, 0x4994, 0x4951, 0x4995,
+    0x2A69, 0x2A6A, 0x49C5, 0x2A8F, 0x171B, 0x2EE2, 0x3078, 0x3079, 0x3077, 0x1AF0, 0x307A, 0x307B, 0x307C, 0x3253, 0x3259, 0x3257,
+    0x1C8F, 0x3255, 0x3256, 0x3258, 0x3254, 0x1E74, 0x349D, 0x1E76, 0x349F, 0x1E75, 0x349E, 0x371B, 0x2031, 0x371D, 0x371A, 0x34A0,
+    0x2032, 0x4A11, 0x371C, 0x39BC, 0x21DF, 0x39BA, 0x21E0, 0x39BB, 0x21E1, 0x237C, 0x3C3B, 0x3C38, 0x3C3A, 0x237D, 0x237E, 0x3C39,
+    0x3E6E, 0x3E6F, 0x24F7, 0x3E71, 0x3E70, 0x145E, 0x40CD, 0x40CE, 0x40CA, 0x40CB, 0x2642, 0x2643, 0x40CC, 0x2757, 0x2753, 0x2758,
+    0x2754, 0x2756, 0x2752, 0x2755, 0x282E, 0x42A9, 0x4599, 0x4598, 0x46D0, 0x2951, 0x2952, 0x485F, 0x47A8, 0x485E, 0x4996, 0x49DA,
+    0x171C, 0x181F, 0x2EE3, 0x198B, 0x1AF3, 0x307F, 0x1AF1, 0x307D, 0x1AF6, 0x1AF2, 0x1AF7, 0x1AF5, 0x307E, 0x1AF4, 0x1C93, 0x1C92,
+    0x3264, 0x1C9B, 0x325D, 0x3263, 0x1C97, 0x325C, 0x3262, 0x3260, 0x1C96, 0x1C98, 0x325F, 0x1C91, 0x325E, 0x1C9C, 0x1C99, 0x1C9D,
+    0x1C9A, 0x325B, 0x325A, 0x3261, 0x1C94, 0x1C90, 0x1C95, 0x34AF, 0x34A8, 0x2037, 0x34A7, 0x1E7A, 0x1E81, 0x1E7E, 0x1E84, 0x1E83,
+    0x1E7F, 0x34A1, 0x34A4, 0x34A3, 0x1E7B, 0x34A5, 0x34AE, 0x1E7C, 0x34A2, 0x34AB, 0x34AC, 0x1E7D, 0x34A9, 0x1E82, 0x1E78, 0x1E80,
+    0x34A6, 0x1E77, 0x34AA, 0x34AD, 0x372E, 0x3725, 0x2034, 0x372C, 0x3729, 0x3721, 0x372A, 0x2036, 0x3722, 0x371E, 0x21E7, 0x3727,
+    0x2033, 0x372D, 0x203A, 0x203C, 0x3720, 0x203B, 0x3723, 0x2035, 0x372B, 0x3724, 0x3728, 0x3726, 0x2038, 0x371F, 0x203D, 0x1E79,
+    0x2039, 0x203E, 0x21E3, 0x39C3, 0x39C5, 0x39C7, 0x39CB, 0x39C1, 0x39BF, 0x21E5, 0x39C6, 0x39CA, 0x39C2, 0x39BE, 0x39BD, 0x39C8,
+    0x39C0, 0x39C4, 0x21E6, 0x21E4, 0x39CC, 0x21E2, 0x39C9, 0x3C4C, 0x2381, 0x3C44, 0x2504, 0x3C4E, 0x2384, 0x3C52, 0x238A, 0x3C3F,
+    0x3C4F, 0x3C3C, 0x3C51, 0x3C40, 0x2391, 0x238E, 0x3C50, 0x3C4A, 0x2380, 0x2388, 0x2387, 0x2386, 0x238C, 0x3C3D, 0x238D, 0x3C4B,
+    0x2389, 0x237F, 0x3C4D, 0x2382, 0x2383, 0x238B, 0x3C42, 0x3C41, 0x3C3E, 0x3C46, 0x3C43, 0x3C47, 0x2390, 0x3C53, 0x2385, 0x3C48,
+    0x3C49, 0x3C45, 0x238F, 0x3E76, 0x24FC, 0x2505, 0x2501, 0x3E73, 0x24FE, 0x2502, 0x3E7F, 0x24F8, 0x3E77, 0x2500, 0x3E7A, 0x3E75,
+    0x24FF, 0x2503, 0x3E74, 0x24FD, 0x3E7E, 0x24FA, 0x3E7D, 0x3E7C, 0x2506, 0x3D86, 0x24F9, 0x3E7B, 0x3E72, 0x2507, 0x3E79, 0x24FB,
+    0x3E78, 0x2646, 0x264B, 0x2644, 0x40DB, 0x40D2, 0x40DE, 0x40D6, 0x40DC, 0x264C, 0x2645, 0x40CF, 0x40D5, 0x40DF, 0x40D8, 0x40DD,
+    0x40D1, 0x40D9, 0x2647, 0x40D7, 0x264A, 0x2649, 0x40D3, 0x40D4, 0x40D0, 0x40DA, 0x2648, 0x40E1, 0x40E0, 0x42B4, 0x42B0, 0x275F,
+    0x42AA, 0x2759, 0x2769, 0x42B6, 0x2761, 0x275D, 0x42AD, 0x2764, 0x2767, 0x42B8, 0x275C, 0x42AF, 0x2765, 0x42BA, 0x42B7, 0x42AB,
+    0x2760, 0x275A, 0x2768, 0x42B2, 0x2763, 0x42AC, 0x275E, 0x42B9, 0x2762, 0x275B, 0x42B3, 0x2766, 0x42B1, 0x42B5, 0x4441, 0x4447,
+    0x2834, 0x444A, 0x282F, 0x2830, 0x4442, 0x4449, 0x4444, 0x2835, 0x2832, 0x4440, 0x2831, 0x4446, 0x4448, 0x2833, 0x4445, 0x4443,
+    0x45A3, 0x28CF, 0x28D0, 0x28CC, 0x28CD, 0x459F, 0x459D, 0x45A1, 0x28D2, 0x45A2, 0x459B, 0x459A, 0x459E, 0x459C, 0x28CE, 0x45A0,
+    0x46D1, 0x2955, 0x2954, 0x46D2, 0x46D4, 0x46D3, 0x2956, 0x47AD, 0x47AA, 0x47AB, 0x47A9, 0x47AC, 0x29A7, 0x47AE, 0x29A6, 0x4860,
+    0x2A27, 0x2A29, 0x48EA, 0x2A28, 0x4952, 0x4998, 0x499A, 0x4997, 0x4999, 0x2A7D, 0x171D, 0x1AF8, 0x31DC, 0x1C9E, 0x1E85, 0x372F,
+    0x3730, 0x40E2, 0x276A, 0x42BB, 0x2836, 0x45A5, 0x45A4, 0x2957, 0x47AF, 0x4861, 0x2A4A, 0x2CCE, 0x198C, 0x1820, 0x3080, 0x326A,
+    0x3265, 0x3269, 0x3267, 0x1C9F, 0x3268, 0x3266, 0x34B0, 0x3731, 0x3732, 0x39CF, 0x39D0, 0x21E9, 0x21EA, 0x39CE, 0x39D1, 0x39CD,
+    0x21E8, 0x2392, 0x21EB, 0x3C54, 0x2508, 0x3E80, 0x2509, 0x264D, 0x40E5, 0x40E3, 0x40E4, 0x42BE, 0x42BD, 0x42BC, 0x45A6, 0x28D1,
+    0x45A7, 0x4862, 0x2A4B, 0x4953, 0x171E, 0x198E, 0x198D, 0x3082, 0x1AF9, 0x4C52, 0x3081, 0x326C, 0x1CA0, 0x34B1, 0x326B, 0x1E87,
+    0x34B4, 0x34B2, 0x34B3, 0x1E86, 0x3734, 0x3735, 0x3733, 0x21EE, 0x39D3, 0x39D2, 0x39D4, 0x21ED, 0x21EC, 0x3E81, 0x3E83, 0x250A,
+    0x3E82, 0x40E6, 0x264E, 0x444C, 0x444B, 0x28D3, 0x45A8, 0x28D5, 0x28D4, 0x46D5, 0x47B0, 0x29A8, 0x171F, 0x3083, 0x1AFA, 0x326F,
+    0x1CA2, 0x326E, 0x326D, 0x1CA1, 0x34BA, 0x34BC, 0x34B5, 0x34B6, 0x1E88, 0x34B7, 0x1E89, 0x34BB, 0x34B8, 0x34B9, 0x1E8A, 0x2040,
+    0x2041, 0x3736, 0x39D5, 0x39D6, 0x3C58, 0x2395, 0x2393, 0x2394, 0x3C55, 0x3C56, 0x3C57, 0x3E88, 0x3E89, 0x250B, 0x3E86, 0x3E85,
+    0x3E87, 0x3E84, 0x2651, 0x40E7, 0x264F, 0x2650, 0x42C0, 0x276B, 0x42BF, 0x43BC, 0x444D, 0x444E, 0x2837, 0x2838, 0x276C, 0x45A9,
+    0x45AA, 0x46D6, 0x2958, 0x1720, 0x1721, 0x1AFB, 0x198F, 0x1CA3, 0x3084, 0x2042, 0x1722, 0x1AFD, 0x3085, 0x3086, 0x1AFC, 0x1AFE,
+    0x1723, 0x3087, 0x1CA5, 0x3270, 0x1CA7, 0x1CA4, 0x1CA6, 0x34BF, 0x1E8B, 0x34BE, 0x34BD, 0x39D7, 0x3C59, 0x250C, 0x2652, 0x40E9,
+    0x40E8, 0x42C1, 0x47B1, 0x1724, 0x2D95, 0x2EE4, 0x1AFF, 0x3088, 0x3272, 0x1CA8, 0x3271, 0x1CA9, 0x34C1, 0x1E8D, 0x34C0, 0x34C2,
+    0x1E8C, 0x3738, 0x3739, 0x3737, 0x2043, 0x21EF, 0x21F0, 0x2397, 0x3C5B, 0x3C5A, 0x2396, 0x3E8A, 0x3E8B, 0x40EA, 0x2770, 0x276F,
+    0x276D, 0x276E, 0x2771, 0x444F, 0x283A, 0x2839, 0x45AB, 0x46D7, 0x29F7, 0x29F6, 0x1725, 0x31A9, 0x21F2, 0x2044, 0x21F1, 0x2398,
+    0x1726, 0x2C6F, 0x1727, 0x1728, 0x2EE5, 0x2D98, 0x2D99, 0x1822, 0x2D96, 0x1821, 0x1824, 0x2D97, 0x1826, 0x1825, 0x2D9A, 0x1823,
+    0x1994, 0x1992, 0x2EE7, 0x1991, 0x1996, 0x1998, 0x1995, 0x2EEA, 0x2EE6, 0x1999, 0x1993, 0x1827, 0x1997, 0x2EE9, 0x2EE8, 0x1990,
+    0x308E, 0x1B03, 0x1B04, 0x3090, 0x308A, 0x308D, 0x3096, 0x3094, 0x1B05, 0x309A, 0x1B08, 0x3097, 0x308F, 0x308C, 0x373B, 0x3095,
+    0x1B00, 0x3098, 0x3089, 0x3092, 0x1B02, 0x1B07, 0x3093, 0x1B0B, 0x1B09, 0x308B, 0x1B06, 0x3091, 0x1B0A, 0x1B01, 0x3099, 0x1CAE,
+    0x1CB7, 0x1CAC, 0x1CAA, 0x3274, 0x1CB2, 0x1CAF, 0x3276, 0x1CB1, 0x3275, 0x3273, 0x3278, 0x1CB6, 0x1CB4, 0x373A, 0x3279, 0x3277,
+    0x1CAB, 0x1CAD, 0x1CB0, 0x1CB3, 0x1CB5, 0x34CC, 0x1E8F, 0x34C3, 0x34C5, 0x34C6, 0x34CE, 0x34CA, 0x34C8, 0x34CB, 0x34CF, 0x1E90,
+    0x1E94, 0x34C4, 0x34CD, 0x1E92, 0x1E91, 0x34C9, 0x34C7, 0x1E8E, 0x1E93, 0x204A, 0x3743, 0x3741, 0x204C, 0x373C, 0x39E5, 0x204B,
+    0x3740, 0x373D, 0x2047, 0x204D, 0x3742, 0x2049, 0x373F, 0x2399, 0x2048, 0x373E, 0x204E, 0x2046, 0x2045, 0x39DD, 0x39DB, 0x39E1,
+    0x39D9, 0x39E6, 0x39DE, 0x39D8, 0x21F6, 0x21FC, 0x39E3, 0x39DC, 0x21F9, 0x21F7, 0x39E4, 0x21F4, 0x21F3, 0x39DF, 0x21F8, 0x204F,
+    0x39E2, 0x39DA, 0x21F5, 0x21FA, 0x21FB, 0x239E, 0x239A, 0x239F, 0x3C5E, 0x3C5D, 0x3C5F, 0x239C, 0x3C5C, 0x239D, 0x3C62, 0x3C61,
+    0x3C60, 0x239B, 0x3E8F, 0x3E92, 0x2512, 0x3E91, 0x2511, 0x250D, 0x250E, 0x250F, 0x3E8E, 0x3E8D, 0x2510, 0x3E90, 0x3E8C, 0x40EC,
+    0x40F6, 0x2655, 0x2654, 0x40F0, 0x40F2, 0x40ED, 0x40F1, 0x40EB, 0x40F4, 0x2653, 0x40F3, 0x40EF, 0x40F5, 0x40EE, 0x2774, 0x42C2,
+    0x42C8, 0x2778, 0x277A, 0x2777, 0x2776, 0x2775, 0x2773, 0x42C3, 0x42C6, 0x2772, 0x42C7, 0x2779, 0x42C5, 0x42C4, 0x283B, 0x283C,
+    0x4452, 0x4450, 0x4451, 0x45AD, 0x45AC, 0x28D6, 0x46D9, 0x2959, 0x46D8, 0x47B2, 0x4863, 0x29F8, 0x499B, 0x499C, 0x2A2A, 0x1729,
+    0x199A, 0x3744, 0x23A0, 0x277B, 0x42C9, 0x172A, 0x1CB9, 0x1CB8, 0x3745, 0x4D0B, 0x40F7, 0x172B, 0x1B0C, 0x3746, 0x3747, 0x3748,
+    0x23A1, 0x2656, 0x172C, 0x199B, 0x309B, 0x1CBA, 0x327A, 0x1E95, 0x3749, 0x21FD, 0x23A2, 0x2657, 0x277C, 0x283D, 0x46DA, 0x172D,
+    0x199C, 0x1CBB, 0x34D0, 0x2050, 0x23A3, 0x3C63, 0x3E93, 0x172E, 0x2051, 0x39E7, 0x23A4, 0x172F, 0x2EEB, 0x309C, 0x1B0D, 0x327C,
+    0x4C43, 0x1CBE, 0x1CBC, 0x1CBD, 0x1CBF, 0x327B, 0x34D5, 0x34D2, 0x34D4, 0x1E96, 0x1E98, 0x1E97, 0x34D1, 0x1E99, 0x34D3, 0x374A,
+    0x374B, 0x374C, 0x39EA, 0x39EB, 0x39E9, 0x39EC, 0x21FE, 0x39E8, 0x23A5, 0x3E98, 0x3E94, 0x3E97, 0x3E99, 0x3E96, 0x3E95, 0x40F8,
+    0x40F9, 0x40FA, 0x2658, 0x2659, 0x42CB, 0x42CA, 0x42CC, 0x4454, 0x4453, 0x45AF, 0x45B0, 0x45AE, 0x295A, 0x46DB, 0x46DC, 0x4864,
+    0x48EB, 0x4954, 0x1730, 0x1828, 0x277D, 0x1731, 0x34D6, 0x374D, 0x2A52, 0x2CCF, 0x2CD0, 0x2CD2, 0x1732, 0x2CD3, 0x2CD1, 0x2DA2,
+    0x2DA3, 0x2D9D, 0x4B5E, 0x2DA1, 0x182A, 0x182B, 0x2D9E, 0x2D9C, 0x2D9B, 0x2D9F, 0x1829, 0x2DA0, 0x309D, 0x2EF0, 0x199F, 0x2EEF,
+    0x2EF1, 0x199E, 0x2EF6, 0x19A2, 0x2EEC, 0x2EFA, 0x2F00, 0x19A9, 0x2EFD, 0x19A6, 0x2EF3, 0x2EF9, 0x2EFB, 0x2EEE, 0x19A5, 0x19A0,
+    0x2EF4, 0x19A7, 0x19AA, 0x19A4, 0x199D, 0x2EF8, 0x2EF2, 0x2EFF, 0x19AC, 0x19A8, 0x19A3, 0x2EF7, 0x1CC0, 0x2EF5, 0x19A1, 0x19AB,
+    0x2EED, 0x2EFC, 0x2EFE, 0x1B1E, 0x1B18, 0x1B20, 0x1B1D, 0x30A4, 0x30A7, 0x1B19, 0x309E, 0x1B12, 0x1B1C, 0x1B1F, 0x1B21, 0x30B3,
+    0x30AA, 0x1B11, 0x30B2, 0x1B15, 0x1B13, 0x1B0E, 0x30A2, 0x30B1, 0x30A6, 0x30A9, 0x30B6, 0x4A42, 0x1B22, 0x30B0, 0x1B1A, 0x30AB,
+    0x30B5, 0x30A8, 0x30AC, 0x30AF, 0x30A0, 0x30B4, 0x30AE, 0x4B59, 0x309F, 0x30A3, 0x1B1B, 0x1B16, 0x1B0F, 0x1B14, 0x1B10, 0x1B23,
+    0x30A1, 0x328D, 0x1B17, 0x30AD, 0x2F3A, 0x3290, 0x1CCE, 0x3280, 0x328B, 0x3287, 0x329A, 0x3292, 0x3288, 0x3291, 0x3282, 0x3286,
+    0x329D, 0x1CD1, 0x3295, 0x328C, 0x1CC1, 0x329B, 0x327E, 0x3294, 0x1CD0, 0x1CCB, 0x327D, 0x1CC9, 0x1CC8, 0x1CCD, 0x3293, 0x1CC5,
+    0x1CCC, 0x30A5, 0x374E, 0x328E, 0x3284, 0x1CCF, 0x3285, 0x3289, 0x1CD2, 0x327F, 0x3297, 0x3296, 0x329E, 0x1CC7, 0x1CC4, 0x329C,
+    0x3298, 0x328F, 0x328A, 0x1CCA, 0x1CC6, 0x3281, 0x1CC2, 0x3299, 0x1CC3, 0x3283, 0x4C21, 0x34DC, 0x34DE, 0x34E3, 0x34F7, 0x1EA7,
+    0x1E9D, 0x34DB, 0x1EA8, 0x1EA9, 0x34E6, 0x34ED, 0x34F3, 0x34E0, 0x34E7, 0x1EAA, 0x34F5, 0x34F0, 0x1EA5, 0x1EA3, 0x34EC, 0x34E8,
+    0x34DA, 0x1E9A, 0x34DF, 0x34D7, 0x1EA2, 0x1EA4, 0x34E4, 0x34E1, 0x1E9F, 0x34F1, 0x1E9C, 0x34E2, 0x34F8, 0x34EA, 0x34E9, 0x1E9B,
+    0x1EA6, 0x1E9E, 0x34D8, 0x34DD, 0x34EE, 0x34F4, 0x1EAB, 0x34D9, 0x34E5, 0x34EB, 0x1EA1, 0x34F6, 0x34EF, 0x34F2, 0x4CDF, 0x1EA0,
+    0x375E, 0x3752, 0x2059, 0x376F, 0x3777, 0x377A, 0x2057, 0x375A, 0x3773, 0x375B, 0x3769, 0x2064, 0x3765, 0x2061, 0x4A7F, 0x3766,
+    0x374F, 0x3774, 0x206A, 0x3771, 0x3767, 0x377C, 0x3762, 0x3A0C, 0x377F, 0x2068, 0x3760, 0x376C, 0x206B, 0x2056, 0x3764, 0x377D,
+    0x375D, 0x3756, 0x3761, 0x3755, 0x3753, 0x2052, 0x3775, 0x375C, 0x3778, 0x3779, 0x205A, 0x205F, 0x205B, 0x2063, 0x3770, 0x205C,
+    0x3768, 0x3758, 0x2054, 0x3750, 0x3772, 0x377B, 0x3757, 0x2062, 0x3780, 0x3763, 0x375F, 0x2053, 0x2067, 0x376E, 0x2069, 0x376A,
+    0x205E, 0x2058, 0x2060, 0x2055, 0x2066, 0x376B, 0x3759, 0x376D, 0x3754, 0x3776, 0x4A61, 0x377E, 0x3751, 0x4BA4, 0x3A0B, 0x3A21,
+    0x21D0, 0x3A10, 0x4C63, 0x3A0E, 0x39F9, 0x2202, 0x3A09, 0x3A1D, 0x3A05, 0x220A, 0x3A1C, 0x3A03, 0x2065, 0x3A13, 0x3A04, 0x3A1A,
+    0x2209, 0x2201, 0x39EE, 0x39F6, 0x3A0F, 0x3A07, 0x3A20, 0x3A0A, 0x220F, 0x3A1B, 0x2206, 0x3A0D, 0x3A15, 0x39FA, 0x3A14, 0x3A25,
+    0x39F5, 0x3A16, 0x3A23, 0x39EF, 0x205D, 0x39FD, 0x39FC, 0x2208, 0x3A00, 0x3A02, 0x3A11, 0x3A22, 0x220B, 0x4D14, 0x220C, 0x39F4,
+    0x2204, 0x39F8, 0x3A1E, 0x220D, 0x2205, 0x2207, 0x220E, 0x3A24, 0x3A17, 0x3A12, 0x39FF, 0x39FE, 0x2203, 0x39F0, 0x2200, 0x3A08,
+    0x39F1, 0x3A06, 0x39FB, 0x3A1F, 0x21FF, 0x39F7, 0x39F3, 0x3A19, 0x39F2, 0x23B2, 0x3C8B, 0x3C7A, 0x3C64, 0x3C82, 0x23AA, 0x3C73,
+    0x3C7D, 0x23AD, 0x3C76, 0x23AB, 0x3C67, 0x3C85, 0x3C66, 0x3C79, 0x3C65, 0x3C77, 0x3C80, 0x3C7E, 0x3C72, 0x3C6D, 0x3C6B, 0x3C6C,
+    0x3C7F, 0x3C8A, 0x3C74, 0x23AC, 0x3C6F, 0x3C83, 0x23AF, 0x3C6E, 0x3C68, 0x3C78, 0x23B3, 0x23A7, 0x23B0, 0x3C70, 0x3C6A, 0x23A9,
+    0x3A18, 0x23A8, 0x3C7B, 0x23A6, 0x23B5, 0x23AE, 0x3C7C, 0x3C71, 0x3C69, 0x3C84, 0x3C75, 0x23B4, 0x3C88, 0x23B1, 0x3C87, 0x3C81,
+    0x3C86, 0x3C89, 0x3EC1, 0x3EC2, 0x3EB5, 0x3EB4, 0x3EB0, 0x251E, 0x2516, 0x3EC4, 0x3EC3, 0x39ED, 0x3EAD, 0x3EB1, 0x3EAB, 0x3EBE,
+    0x3EAF, 0x3EC5, 0x3EA8, 0x3EA6, 0x3EB2, 0x3EBC, 0x3EB7, 0x2520, 0x3E9D, 0x3EBB, 0x4B15, 0x2521, 0x3A01, 0x3EA9, 0x3EA0, 0x3EA3,
+    0x3EC9, 0x3EAA, 0x3EA1, 0x3E9F, 0x3E9C, 0x251A, 0x3EB3, 0x2519, 0x251D, 0x3EAE, 0x3EB6, 0x2513, 0x3EC6, 0x3ECA, 0x2515, 0x3EA5,
+    0x3EB9, 0x3EBD, 0x3EA2, 0x3EC7, 0x251C, 0x251B, 0x3E9A, 0x251F, 0x3EC0, 0x3EA4, 0x3EB8, 0x3E9E, 0x3EAC, 0x3EA7, 0x2517, 0x2518,
+    0x3EBA, 0x3ECB, 0x3EC8, 0x3EBF, 0x4C5E, 0x3E9B, 0x2514, 0x410B, 0x4102, 0x4105, 0x4A8C, 0x265F, 0x4107, 0x40FC, 0x4103, 0x4109,
+    0x265C, 0x2660, 0x265A, 0x4A39, 0x40FE, 0x410E, 0x4108, 0x40FF, 0x4117, 0x4111, 0x40FB, 0x42DF, 0x4101, 0x265B, 0x410C, 0x4116,
+    0x2663, 0x4113, 0x4100, 0x4106, 0x410A, 0x4104, 0x4118, 0x4115, 0x4112, 0x265D, 0x265E, 0x2662, 0x40FD, 0x4119, 0x2661, 0x410F,
+    0x410D, 0x4110, 0x42EC, 0x42D9, 0x42DE, 0x42EB, 0x42DD, 0x42D5, 0x42DA, 0x2780, 0x42CE, 0x42E6, 0x42E8, 0x42CD, 0x277F, 0x42EA,
+    0x42E2, 0x2786, 0x42E9, 0x42DB, 0x2788, 0x42D3, 0x4114, 0x42E3, 0x42E0, 0x42CF, 0x42EE, 0x2782, 0x2783, 0x42D1, 0x42E1, 0x42ED,
+    0x42E4, 0x42D7, 0x2785, 0x2781, 0x42E5, 0x42D8, 0x42EF, 0x42D2, 0x42DC, 0x42E7, 0x42D4, 0x42D6, 0x2846, 0x42D0, 0x2787, 0x283F,
+    0x277E, 0x2784, 0x2843, 0x4464, 0x445A, 0x4455, 0x445B, 0x4465, 0x4469, 0x4468, 0x2845, 0x2844, 0x445C, 0x446A, 0x445F, 0x4457,
+    0x4459, 0x4458, 0x445E, 0x4463, 0x4456, 0x445D, 0x4462, 0x2842, 0x4460, 0x2840, 0x4461, 0x283E, 0x2841, 0x45C0, 0x4466, 0x28DA,
+    0x45B8, 0x45BC, 0x45B4, 0x45B7, 0x45BF, 0x28D8, 0x45C4, 0x45BD, 0x45B5, 0x45C5, 0x45BE, 0x28DB, 0x28DC, 0x45C2, 0x45B6, 0x28D7,
+    0x28D9, 0x45B1, 0x45B9, 0x45B3, 0x45C3, 0x45C1, 0x45B2, 0x45BA, 0x46E3, 0x28DD, 0x45BB, 0x295C, 0x295E, 0x295B, 0x46E8, 0x46E0,
+    0x46DE, 0x46E2, 0x46DF, 0x46E4, 0x46E6, 0x295F, 0x2961, 0x46E5, 0x2962, 0x2960, 0x46E7, 0x4A8E, 0x295D, 0x29A9, 0x47B3, 0x47B9,
+    0x29AB, 0x46E1, 0x47B8, 0x47BF, 0x47B6, 0x47BD, 0x47BC, 0x46DD, 0x47B7, 0x4467, 0x47C0, 0x47B5, 0x47BA, 0x47BE, 0x47B4, 0x4868,
+    0x29AA, 0x47BB, 0x48F0, 0x4869, 0x4867, 0x4865, 0x4866, 0x486A, 0x2A2B, 0x48EE, 0x48EC, 0x48F1, 0x48EF, 0x48F2, 0x2A2C, 0x48ED,
+    0x4955, 0x499D, 0x499E, 0x499F, 0x49FD, 0x49F3, 0x2CD4, 0x19AD, 0x1B24, 0x32A0, 0x329F, 0x1CD3, 0x1EAC, 0x34FA, 0x34F9, 0x206C,
+    0x2211, 0x2210, 0x2212, 0x3C8C, 0x3ECC, 0x411A, 0x411C, 0x411B, 0x2789, 0x42F0, 0x446B, 0x49DB, 0x1733, 0x2F03, 0x2F04, 0x2F02,
+    0x2F01, 0x19AE, 0x30BA, 0x30B8, 0x30B7, 0x1B25, 0x1B27, 0x1B26, 0x30B9, 0x32AC, 0x32A9, 0x32A7, 0x1CD4, 0x32AA, 0x1CD9, 0x32A4,
+    0x32B3, 0x32B5, 0x32A5, 0x1CD6, 0x32B6, 0x32B1, 0x32A3, 0x32A8, 0x32B2, 0x32AE, 0x32AB, 0x1CDB, 0x32B4, 0x32A6, 0x32AF, 0x32A1,
+    0x1CDA, 0x1CD7, 0x32AD, 0x32B0, 0x32A2, 0x1CD8, 0x1CD5, 0x1EB6, 0x3501, 0x1EB5, 0x3504, 0x3507, 0x1EB2, 0x1EB0, 0x34FC, 0x3505,
+    0x3503, 0x3500, 0x3508, 0x3509, 0x350B, 0x350C, 0x34FB, 0x1EAF, 0x34FE, 0x34FD, 0x350A, 0x1EB1, 0x34FF, 0x1EB3, 0x1EAE, 0x3502,
+    0x1EB7, 0x1EB4, 0x3506, 0x2073, 0x378F, 0x3784, 0x2070, 0x3A2B, 0x378D, 0x3781, 0x206E, 0x3786, 0x2071, 0x378A, 0x3788, 0x2074,
+    0x206D, 0x3782, 0x3785, 0x2072, 0x3783, 0x378E, 0x378C, 0x3787, 0x3789, 0x378B, 0x206F, 0x3A2C, 0x3A32, 0x3A28, 0x3A2E, 0x2213,
+    0x3A2A, 0x2219, 0x2218, 0x2217, 0x3A31, 0x221A, 0x221B, 0x3A27, 0x3A34, 0x221C, 0x2216, 0x2215, 0x3A30, 0x221D, 0x3A26, 0x3A29,
+    0x3A33, 0x3A2F, 0x3CAC, 0x3CA1, 0x2214, 0x23BC, 0x3C95, 0x3CAA, 0x3C96, 0x23B7, 0x3C93, 0x3C9C, 0x3C94, 0x23B9, 0x3C8E, 0x3CA9,
+    0x23BA, 0x3CA6, 0x3CA7, 0x3C8F, 0x23BF, 0x3C9E, 0x3C98, 0x3C9F, 0x3C92, 0x3CAB, 0x3CA3, 0x3C9D, 0x3C8D, 0x23BB, 0x3CA4, 0x23BE,
+    0x3CA8, 0x3CA2, 0x23B8, 0x3CA0, 0x3C9A, 0x23B6, 0x3C91, 0x3C99, 0x3CA5, 0x3C97, 0x3C9B, 0x252B, 0x3A2D, 0x3EDE, 0x3EE7, 0x3EDD,
+    0x3ED9, 0x3ED6, 0x252C, 0x3ED4, 0x23BD, 0x3ECD, 0x252A, 0x3ED3, 0x2529, 0x3ED8, 0x3ED5, 0x3EE5, 0x3EE0, 0x3EDA, 0x3EDF, 0x2525,
+    0x3ED7, 0x3EEA, 0x3ECE, 0x3ECF, 0x3EE6, 0x2526, 0x3EEB, 0x2528, 0x3EEC, 0x3EDC, 0x3C90, 0x3EE2, 0x3EDB, 0x3EE4, 0x3EE1, 0x3ED2,
+    0x2523, 0x3EE9, 0x2524, 0x3ED0, 0x2527, 0x4126, 0x3EE3, 0x3EE8, 0x4A6F, 0x4123, 0x2522, 0x2664, 0x412D, 0x4129, 0x4127, 0x4122,
+    0x4131, 0x2668, 0x411E, 0x412A, 0x412B, 0x4121, 0x4120, 0x412E, 0x4124, 0x411F, 0x4125, 0x4130, 0x411D, 0x412F, 0x412C, 0x2666,
+    0x2665, 0x2667, 0x4128, 0x447C, 0x42F2, 0x278F, 0x42F6, 0x42F3, 0x42FA, 0x4300, 0x42F5, 0x4309, 0x278C, 0x4303, 0x42F8, 0x4304,
+    0x42FF, 0x4306, 0x42F7, 0x2791, 0x2790, 0x42F9, 0x4307, 0x42F1, 0x4305, 0x278A, 0x42FD, 0x42FC, 0x4301, 0x42F4, 0x278E, 0x2792,
+    0x42FB, 0x4302, 0x2793, 0x42FE, 0x278B, 0x278D, 0x4478, 0x4476, 0x447D, 0x447A, 0x447E, 0x446F, 0x4477, 0x4482, 0x4308, 0x4473,
+    0x284A, 0x3ED1, 0x446E, 0x447B, 0x4475, 0x4472, 0x446D, 0x446C, 0x4481, 0x4471, 0x4470, 0x2848, 0x4479, 0x2847, 0x2849, 0x4474,
+    0x4480, 0x45C9, 0x45CA, 0x28E1, 0x45C7, 0x28DE, 0x45CF, 0x28E2, 0x45D1, 0x45C6, 0x447F, 0x45D3, 0x45C8, 0x28DF, 0x45CE, 0x45D0,
+    0x45CB, 0x45D2, 0x45CD, 0x45CC, 0x28E0, 0x46EA, 0x46EB, 0x46ED, 0x2963, 0x2964, 0x46EE, 0x46EC, 0x46E9, 0x47C3, 0x47C6, 0x47C2,
+    0x29AF, 0x47C4, 0x29AE, 0x29AD, 0x29AC, 0x47C5, 0x486F, 0x486D, 0x486C, 0x47C1, 0x486E, 0x47C7, 0x486B, 0x48F5, 0x48D3, 0x48F3,
+    0x2A2D, 0x48F4, 0x48F6, 0x4958, 0x2A4C, 0x4957, 0x4956, 0x2A4D, 0x2A6B, 0x49C6, 0x49DD, 0x49DC, 0x49DE, 0x1734, 0x30BB, 0x32B7,
+    0x32B8, 0x3790, 0x47C8, 0x4959, 0x1735, 0x1B28, 0x30BC, 0x350D, 0x1EB8, 0x3792, 0x3791, 0x2075, 0x221E, 0x3EED, 0x252D, 0x252E,
+    0x2669, 0x2A4E, 0x1736, 0x30BD, 0x19B0, 0x30BF, 0x30BE, 0x1B29, 0x32B9, 0x32C0, 0x1CDC, 0x32BE, 0x32BC, 0x32C3, 0x32BA, 0x32BB,
+    0x1CDD, 0x1CE1, 0x32C4, 0x1CE0, 0x32C2, 0x32BF, 0x32BD, 0x1CDE, 0x1CDF, 0x32C1, 0x4D33, 0x1EBA, 0x350E, 0x1EBF, 0x351E, 0x1EBE,
+    0x3520, 0x3514, 0x1EBC, 0x351F, 0x350F, 0x1EBD, 0x351B, 0x3517, 0x3519, 0x3513, 0x351A, 0x1EB9, 0x3516, 0x3515, 0x3511, 0x351C,
+    0x3518, 0x3510, 0x3512, 0x1EBB, 0x351D, 0x2078, 0x379D, 0x3799, 0x379B, 0x3796, 0x3795, 0x3793, 0x379A, 0x379C, 0x3798, 0x3797,
+    0x2076, 0x2077, 0x379F, 0x2226, 0x3A36, 0x3A3C, 0x3A37, 0x3A38, 0x4A0E, 0x3A3D, 0x2228, 0x2220, 0x2227, 0x3A35, 0x3794, 0x2223,
+    0x2221, 0x3A3B, 0x3A3A, 0x2222, 0x2224, 0x3A39, 0x221F, 0x2225, 0x3CAE, 0x23C6, 0x3CB9, 0x3CB8, 0x3CB3, 0x23C8, 0x3CB7, 0x3CAF,
+    0x3CB0, 0x23C0, 0x23C2, 0x3CB5, 0x3CAD, 0x23C4, 0x23C3, 0x3CB1, 0x3CB6, 0x3CB4, 0x23C5, 0x3CB2, 0x4D38, 0x379E, 0x23C1, 0x3EEE,
+    0x3EF5, 0x2530, 0x3EF9, 0x2534, 0x3EF1, 0x3EEF, 0x3EF8, 0x252F, 0x3EF7, 0x2531, 0x2532, 0x3EF0, 0x2533, 0x3EF6, 0x3EF2, 0x3EF3,
+    0x3EF4, 0x23C7, 0x4132, 0x413E, 0x266E, 0x4139, 0x413B, 0x266C, 0x4133, 0x4137, 0x413A, 0x266A, 0x266D, 0x413D, 0x4135, 0x4136,
+    0x413C, 0x4134, 0x4138, 0x266B, 0x430B, 0x430A, 0x2795, 0x4310, 0x2797, 0x2794, 0x430C, 0x2798, 0x430D, 0x430E, 0x4311, 0x2796,
+    0x4487, 0x448A, 0x4484, 0x4486, 0x4485, 0x4488, 0x4489, 0x430F, 0x4483, 0x28E5, 0x45D7, 0x45DA, 0x45DC, 0x45D5, 0x45D6, 0x45D9,
+    0x45DB, 0x28E6, 0x28E4, 0x28E3, 0x45D8, 0x45D4, 0x46EF, 0x2965, 0x46F0, 0x47CA, 0x29B0, 0x47CC, 0x29B1, 0x47C9, 0x47CB, 0x29FA,
+    0x4870, 0x29F9, 0x48F9, 0x48F8, 0x48F7, 0x49A0, 0x49A1, 0x49A3, 0x49A2, 0x2CD5, 0x1737, 0x1B2A, 0x3521, 0x2079, 0x3A3E, 0x284B,
+    0x45DD, 0x182C, 0x1EC1, 0x1EC0, 0x37A0, 0x207A, 0x37A2, 0x37A1, 0x3A3F, 0x2229, 0x3CBA, 0x3CBD, 0x3CBC, 0x3CBB, 0x3EFA, 0x3EFC,
+    0x3EFB, 0x2670, 0x266F, 0x2799, 0x4312, 0x4314, 0x4313, 0x284C, 0x45DF, 0x45DE, 0x46F1, 0x2966, 0x29B2, 0x4872, 0x4871, 0x2A6C,
+    0x182D, 0x30C0, 0x1B2B, 0x3524, 0x3522, 0x3523, 0x37A4, 0x37A5, 0x3A46, 0x37A3, 0x3A40, 0x3A44, 0x3A43, 0x3A45, 0x222A, 0x3A42,
+    0x3A41, 0x3A47, 0x3CC0, 0x3CBE, 0x3CBF, 0x3EFF, 0x3EFD, 0x3EFE, 0x413F, 0x4315, 0x4316, 0x284D, 0x45E0, 0x46F2, 0x2967, 0x47CD,
+    0x4873, 0x29FB, 0x48FA, 0x49A4, 0x182E, 0x1B2D, 0x1B2E, 0x30C1, 0x30C2, 0x1B2C, 0x1CE7, 0x1CE5, 0x1CE4, 0x1CEB, 0x1CE3, 0x1CEC,
+    0x32C5, 0x1CE9, 0x1CE6, 0x1CEA, 0x1CE8, 0x1CE2, 0x1EC9, 0x1EC3, 0x3528, 0x1EC8, 0x1ECA, 0x1EC4, 0x1EC5, 0x3526, 0x1EC2, 0x3527,
+    0x1EC7, 0x3525, 0x1EC6, 0x2085, 0x2087, 0x37A8, 0x2086, 0x207B, 0x207F, 0x223D, 0x3A4C, 0x37AA, 0x2080, 0x37AD, 0x37AE, 0x2084,
+    0x37B0, 0x37B2, 0x37B3, 0x37A7, 0x37A6, 0x37B4, 0x2083, 0x37B1, 0x37AF, 0x2081, 0x207D, 0x2088, 0x37AB, 0x37AC, 0x37A9, 0x2082,
+    0x207E, 0x207C, 0x3A4B, 0x2238, 0x2233, 0x222E, 0x223E, 0x222F, 0x222B, 0x223A, 0x2237, 0x2239, 0x2230, 0x2235, 0x222C, 0x222D,
+    0x3A53, 0x3A50, 0x3A48, 0x3A4D, 0x223B, 0x3A54, 0x223C, 0x2232, 0x3A4A, 0x3A52, 0x3A4E, 0x3A51, 0x3A4F, 0x2236, 0x3A49, 0x2231,
+    0x3CC3, 0x23CA, 0x23CD, 0x3CC5, 0x23D5, 0x3CC4, 0x23CF, 0x2539, 0x3CC6, 0x23D4, 0x3CC2, 0x23D6, 0x23CB, 0x2234, 0x23CE, 0x23CC,
+    0x23D0, 0x23D2, 0x23C9, 0x23D7, 0x23D3, 0x23D1, 0x3CC1, 0x2540, 0x253C, 0x2543, 0x3F02, 0x2544, 0x3F0E, 0x3F07, 0x2535, 0x3F0F,
+    0x3F09, 0x253F, 0x3F0A, 0x253E, 0x3F0D, 0x2538, 0x3F0B, 0x3F01, 0x2537, 0x414A, 0x253D, 0x253A, 0x2542, 0x3F00, 0x3F04, 0x2536,
+    0x3F03, 0x3F05, 0x3F06, 0x2541, 0x3F08, 0x3F0C, 0x3F10, 0x2545, 0x2676, 0x4145, 0x414B, 0x4148, 0x4140, 0x414C, 0x4141, 0x4147,
+    0x2671, 0x2677, 0x414D, 0x2673, 0x267D, 0x2678, 0x414F, 0x4149, 0x2674, 0x4142, 0x267E, 0x4143, 0x4144, 0x267F, 0x267C, 0x253B,
+    0x2672, 0x4150, 0x2680, 0x2679, 0x414E, 0x2675, 0x267A, 0x267B, 0x27A1, 0x431B, 0x4324, 0x4321, 0x4323, 0x279E, 0x431C, 0x4322,
+    0x279A, 0x431E, 0x27A2, 0x431A, 0x431F, 0x4326, 0x4146, 0x4320, 0x4319, 0x279B, 0x4318, 0x279C, 0x4327, 0x279D, 0x4325, 0x27A0,
+    0x4317, 0x279F, 0x431D, 0x448D, 0x449B, 0x4496, 0x4498, 0x448C, 0x284E, 0x448B, 0x2851, 0x2850, 0x449A, 0x4492, 0x448F, 0x4495,
+    0x448E, 0x4490, 0x4499, 0x4497, 0x284F, 0x449E, 0x449C, 0x4493, 0x449D, 0x4494, 0x45E4, 0x28E7, 0x45EB, 0x47D0, 0x28EE, 0x4491,
+    0x45E2, 0x28EA, 0x45E3, 0x45E8, 0x28EC, 0x28ED, 0x45E1, 0x45EA, 0x45EC, 0x45E5, 0x45E7, 0x45E9, 0x45E6, 0x45ED, 0x28E9, 0x28EF,
+    0x28EB, 0x28E8, 0x46F5, 0x296C, 0x46F3, 0x46F7, 0x46F8, 0x296A, 0x46F9, 0x46F6, 0x46F4, 0x296D, 0x2969, 0x46FA, 0x296B, 0x2968,
+    0x29B3, 0x29B4, 0x47CF, 0x47CE, 0x47D1, 0x47D2, 0x29B5, 0x4874, 0x4879, 0x29FC, 0x4876, 0x4875, 0x4878, 0x4877, 0x48FE, 0x2A2E,
+    0x48FD, 0x48FB, 0x48FC, 0x2A50, 0x2A4F, 0x495A, 0x495B, 0x2A51, 0x49A5, 0x49A6, 0x2A78, 0x2A7E, 0x49DF, 0x49FE, 0x182F, 0x3529,
+    0x352A, 0x3A55, 0x3CC7, 0x3F11, 0x27A4, 0x27A3, 0x449F, 0x45EE, 0x48FF, 0x1830, 0x32C6, 0x1CED, 0x1ECB, 0x3A57, 0x3A56, 0x2546,
+    0x3F12, 0x2547, 0x4328, 0x2852, 0x2A87, 0x1831, 0x2F05, 0x32C7, 0x1ECC, 0x352B, 0x352C, 0x37B5, 0x2089, 0x223F, 0x3A59, 0x3A58,
+    0x3A5A, 0x3CC8, 0x3CC9, 0x23D8, 0x2681, 0x2548, 0x2682, 0x432C, 0x4329, 0x432B, 0x432A, 0x27A5, 0x44A0, 0x45F0, 0x45EF, 0x2DA4,
+    0x1CEF, 0x1CEE, 0x32C8, 0x352D, 0x37B7, 0x37B6, 0x208A, 0x3A5C, 0x3A5D, 0x3A5B, 0x2241, 0x2240, 0x23DA, 0x23D9, 0x3F13, 0x4153,
+    0x4151, 0x4152, 0x2683, 0x432E, 0x432D, 0x44A3, 0x44A2, 0x44A1, 0x45F1, 0x49E0, 0x1832, 0x1B2F, 0x1B30, 0x1CF0, 0x1CF1, 0x32CA,
+    0x32C9, 0x352E, 0x1ED2, 0x1ED0, 0x1ECD, 0x1ED1, 0x1ECF, 0x1ECE, 0x208B, 0x37BA, 0x2246, 0x208D, 0x2092, 0x37BC, 0x2094, 0x2093,
+    0x2096, 0x37BB, 0x37B8, 0x2090, 0x208C, 0x208E, 0x37B9, 0x2095, 0x2091, 0x208F, 0x2248, 0x2247, 0x2245, 0x2249, 0x2243, 0x2244,
+    0x2242, 0x3A5E, 0x3CCB, 0x23DC, 0x23DD, 0x23DB, 0x3CCA, 0x3CCC, 0x3F16, 0x3F18, 0x2551, 0x3F19, 0x254A, 0x3F15, 0x2549, 0x2553,
+    0x254F, 0x2550, 0x254C, 0x3F14, 0x254B, 0x3F1A, 0x3F17, 0x2552, 0x254D, 0x254E, 0x4155, 0x4157, 0x4156, 0x4158, 0x2684, 0x4154,
+    0x27A9, 0x432F, 0x27A6, 0x27AA, 0x27A8, 0x27A7, 0x44A4, 0x44A7, 0x44A6, 0x44A5, 0x2853, 0x45F2, 0x45F3, 0x28F0, 0x45F4, 0x28F1,
+    0x296F, 0x296E, 0x47D3, 0x29B6, 0x47D4, 0x487A, 0x29FD, 0x29FE, 0x4900, 0x2A53, 0x1833, 0x1ED4, 0x1ED3, 0x3A5F, 0x3A60, 0x23DE,
+    0x4159, 0x2554, 0x415A, 0x4330, 0x1834, 0x30C3, 0x1B32, 0x1B31, 0x32CB, 0x1CF2, 0x32CC, 0x3531, 0x3530, 0x352F, 0x37BE, 0x2099,
+    0x37BD, 0x2098, 0x37BF, 0x2097, 0x3A62, 0x3A65, 0x3A63, 0x3A64, 0x3A68, 0x3A61, 0x3A69, 0x3A66, 0x3A67, 0x23E0, 0x3CCD, 0x23DF,
+    0x3F1E, 0x3F1C, 0x2555, 0x3F1B, 0x3F1D, 0x2556, 0x415B, 0x415C, 0x27AB, 0x45F6, 0x45F8, 0x45F5, 0x45F7, 0x46FB, 0x47D5, 0x49C7,
+    0x1835, 0x1B33, 0x32CD, 0x32CF, 0x32CE, 0x3534, 0x1ED6, 0x3532, 0x1ED5, 0x3535, 0x3536, 0x3533, 0x37CB, 0x20A1, 0x37C3, 0x37C9,
+    0x209C, 0x209F, 0x37C2, 0x209A, 0x37C6, 0x3A6F, 0x209E, 0x37C1, 0x37C7, 0x37C4, 0x37CA, 0x37C0, 0x37C8, 0x209D, 0x20A0, 0x37C5,
+    0x209B, 0x224B, 0x3A6B, 0x224A, 0x3A72, 0x3A71, 0x2251, 0x2252, 0x3A73, 0x224C, 0x3A70, 0x2250, 0x3A75, 0x3A6C, 0x3A6E, 0x224D,
+    0x3A6A, 0x3A6D, 0x3A74, 0x224E, 0x3A76, 0x224F, 0x23E1, 0x3CD2, 0x3CD8, 0x3CD0, 0x3CD9, 0x3CCF, 0x3CD4, 0x3CDA, 0x3CD7, 0x3CD6,
+    0x3CD5, 0x3CCE, 0x3CD3, 0x3CD1, 0x255B, 0x2558, 0x3F27, 0x3F2C, 0x3F2E, 0x3F2B, 0x3F24, 0x3F26, 0x3F30, 0x3F2D, 0x3F28, 0x3F31,
+    0x3F25, 0x3F2F, 0x2559, 0x255F, 0x255D, 0x3F1F, 0x255E, 0x255A, 0x3F20, 0x3F22, 0x3F21, 0x3F29, 0x3F2A, 0x255C, 0x2557, 0x3F23,
+    0x4166, 0x2686, 0x415D, 0x2687, 0x268A, 0x4162, 0x415F, 0x2689, 0x4163, 0x4164, 0x415E, 0x4167, 0x4160, 0x4165, 0x2688, 0x2685,
+    0x4161, 0x4336, 0x27AE, 0x27AC, 0x27AF, 0x27AD, 0x4335, 0x4332, 0x4331, 0x4334, 0x4333, 0x44B5, 0x2859, 0x44AC, 0x44AB, 0x2854,
+    0x44B1, 0x44B0, 0x44A8, 0x44B3, 0x44AD, 0x2858, 0x44AA, 0x44B2, 0x44A9, 0x2855, 0x2857, 0x44AE, 0x2856, 0x44AF, 0x44B4, 0x45FC,
+    0x28F6, 0x45F9, 0x45FD, 0x28F3, 0x45FB, 0x28F8, 0x28F5, 0x45FA, 0x28F7, 0x45FE, 0x28F2, 0x2971, 0x2973, 0x46FE, 0x2972, 0x46FC,
+    0x28F4, 0x46FD, 0x2970, 0x29B7, 0x29B9, 0x47D7, 0x29B8, 0x47D6, 0x487F, 0x29FF, 0x487E, 0x2A00, 0x487C, 0x487B, 0x4880, 0x4881,
+    0x4901, 0x487D, 0x495F, 0x495C, 0x495D, 0x495E, 0x2A6D, 0x49A9, 0x49A8, 0x49A7, 0x49C8, 0x49F4, 0x49E1, 0x2A7F, 0x1836, 0x1CF3,
+    0x2253, 0x2560, 0x4168, 0x285A, 0x45FF, 0x49E2, 0x1837, 0x19B1, 0x1B35, 0x1B34, 0x1CF6, 0x32D0, 0x1CF4, 0x32D1, 0x1CF5, 0x353B,
+    0x3537, 0x1ED7, 0x353A, 0x3539, 0x3538, 0x1ED8, 0x353C, 0x353D, 0x37D2, 0x37D0, 0x37D4, 0x37D5, 0x37DB, 0x37D7, 0x37D9, 0x37D1,
+    0x37CC, 0x37D8, 0x37DA, 0x37D3, 0x37D6, 0x37CD, 0x20A3, 0x37CF, 0x37CE, 0x20A2, 0x20A4, 0x2256, 0x3A78, 0x3A7A, 0x3A79, 0x3A7E,
+    0x2254, 0x3A7B, 0x3A77, 0x3A7C, 0x3A7D, 0x2255, 0x2257, 0x3A7F, 0x3CDE, 0x3CDD, 0x3CDB, 0x3CDC, 0x23E3, 0x23E5, 0x23E2, 0x23E4,
+    0x3F38, 0x3F39, 0x3F34, 0x3F35, 0x2562, 0x2567, 0x2561, 0x2568, 0x2563, 0x3F36, 0x3F37, 0x3F33, 0x2569, 0x2565, 0x2564, 0x2566,
+    0x3F32, 0x416A, 0x268C, 0x416C, 0x268E, 0x416F, 0x416B, 0x4169, 0x416E, 0x268D, 0x416D, 0x268B, 0x27B1, 0x27B4, 0x4338, 0x27B2,
+    0x4337, 0x27B0, 0x27B3, 0x44B6, 0x44B7, 0x44B8, 0x285B, 0x44B9, 0x285C, 0x28FA, 0x4602, 0x4603, 0x4601, 0x4600, 0x4604, 0x28F9,
+    0x4702, 0x4700, 0x4701, 0x4703, 0x46FF, 0x4704, 0x47D9, 0x47DA, 0x47D8, 0x29BA, 0x4882, 0x2A01, 0x4883, 0x4903, 0x4902, 0x1838,
+    0x20A5, 0x2258, 0x23E6, 0x2690, 0x268F, 0x28FB, 0x2953, 0x29BB, 0x1839, 0x1CF7, 0x2259, 0x4605, 0x2AC5, 0x4D32, 0x2DA6, 0x183A,
+    0x183D, 0x183C, 0x183B, 0x2DA5, 0x2F07, 0x2F09, 0x19B2, 0x19B4, 0x2F06, 0x2F08, 0x19B3, 0x2F0B, 0x2F0A, 0x2F0C, 0x30C7, 0x30C5,
+    0x1B38, 0x30C4, 0x1B3D, 0x1B3A, 0x1B37, 0x1B3E, 0x1B39, 0x1B3C, 0x1B3B, 0x30C6, 0x1B36, 0x1CFD, 0x32D3, 0x32D9, 0x1CFA, 0x1D01,
+    0x1CFC, 0x32D6, 0x32D8, 0x1CFF, 0x32D2, 0x32D5, 0x1CFB, 0x1CF8, 0x32D4, 0x1CFE, 0x32D7, 0x1D00, 0x1CF9, 0x353F, 0x3542, 0x1EDA,
+    0x1EE4, 0x1EE0, 0x3540, 0x1EE8, 0x1EE1, 0x1EE6, 0x1EDC, 0x1ED9, 0x1EDB, 0x1EE7, 0x3541, 0x1EDF, 0x1EE2, 0x1EDE, 0x1EE3, 0x3543,
+    0x1EE5, 0x1EDD, 0x353E, 0x37DC, 0x20A6, 0x37DE, 0x20A8, 0x20AA, 0x37DD, 0x20A7, 0x20AB, 0x20A9, 0x225F, 0x3A84, 0x2267, 0x3A81,
+    0x2268, 0x225D, 0x3A82, 0x2262, 0x3A83, 0x225B, 0x225A, 0x2265, 0x2264, 0x2263, 0x2261, 0x2266, 0x3A80, 0x225C, 0x225E, 0x2260,
+    0x23E8, 0x23EB, 0x23EF, 0x23E9, 0x23EE, 0x23EC, 0x23E7, 0x23ED, 0x23EA, 0x3F3D, 0x256C, 0x256A, 0x3F3E, 0x256D, 0x256B, 0x3F3C,
+    0x3F3B, 0x2694, 0x3F3A, 0x2692, 0x2691, 0x4170, 0x256E, 0x2693, 0x4171, 0x2696, 0x4172, 0x2695, 0x27B6, 0x433A, 0x27B5, 0x27BA,
+    0x27B8, 0x27B9, 0x285E, 0x27B7, 0x4339, 0x4173, 0x285D, 0x285F, 0x28FC, 0x28FD, 0x4705, 0x2A30, 0x2A2F, 0x183F, 0x2CDA, 0x1D02,
+    0x2CD7, 0x2CD8, 0x2CD6, 0x2CD9, 0x2DAA, 0x2DA7, 0x2DAC, 0x2DA8, 0x1840, 0x1843, 0x2DA9, 0x1842, 0x2DAB, 0x1841, 0x2F0F, 0x2F11,
+    0x19B7, 0x2F0D, 0x2F10, 0x2F0E, 0x19B5, 0x19B8, 0x19B6, 0x30C9, 0x30CD, 0x30CA, 0x1B41, 0x1B42, 0x30CC, 0x30CE, 0x30D0, 0x1B3F,
+    0x30CF, 0x1B40, 0x354A, 0x30CB, 0x32DA, 0x32E3, 0x32E1, 0x32DC, 0x32DD, 0x32E2, 0x32E4, 0x1D04, 0x32DF, 0x32DB, 0x1D03, 0x1D05,
+    0x32DE, 0x32E5, 0x32E0, 0x1EE9, 0x354D, 0x3545, 0x354B, 0x354C, 0x1EEA, 0x3544, 0x3546, 0x30C8, 0x3548, 0x3549, 0x3547, 0x20AD,
+    0x37E5, 0x4174, 0x37E6, 0x37E3, 0x1EEB, 0x20AF, 0x37E2, 0x37E8, 0x37E7, 0x20AC, 0x37EB, 0x37E1, 0x37EA, 0x37DF, 0x37E9, 0x37E4,
+    0x20AE, 0x3A8B, 0x3A86, 0x3A8C, 0x3A87, 0x3A85, 0x3A88, 0x2269, 0x3A8A, 0x3A89, 0x226A, 0x23F1, 0x23F0, 0x3CE5, 0x3CE8, 0x3CE0,
+    0x3CE4, 0x23F2, 0x3CE3, 0x3CE1, 0x3CE7, 0x3CE2, 0x3CDF, 0x3CE6, 0x3F44, 0x2571, 0x44BA, 0x3F41, 0x3F42, 0x3F40, 0x37E0, 0x2570,
+    0x3F45, 0x3F3F, 0x256F, 0x2572, 0x3F43, 0x4175, 0x2697, 0x4176, 0x4177, 0x433B, 0x27BB, 0x44BB, 0x44BC, 0x44BD, 0x4607, 0x4606,
+    0x4707, 0x4706, 0x47DC, 0x47DD, 0x47DB, 0x4884, 0x2A02, 0x1844, 0x1B44, 0x1B43, 0x1D08, 0x1D07, 0x32E7, 0x32E8, 0x32E6, 0x1D06,
+    0x3551, 0x3552, 0x354E, 0x1EEC, 0x354F, 0x3550, 0x37EE, 0x37F0, 0x37EC, 0x37EF, 0x20B0, 0x37ED, 0x20B1, 0x389F, 0x226D, 0x226C,
+    0x226B, 0x3A8D, 0x3A8E, 0x3CEA, 0x3CEC, 0x23F6, 0x23F3, 0x23F5, 0x23F4, 0x3CEB, 0x3CE9, 0x3F4C, 0x3F49, 0x3F4A, 0x2576, 0x3F4B,
+    0x3F46, 0x3F47, 0x2573, 0x2574, 0x3F48, 0x2575, 0x417B, 0x417C, 0x4179, 0x417A, 0x2698, 0x4178, 0x433F, 0x433C, 0x433E, 0x27BE,
+    0x4342, 0x27BD, 0x4340, 0x4343, 0x4341, 0x433D, 0x27BC, 0x44BF, 0x44C0, 0x44BE, 0x44C2, 0x2860, 0x2861, 0x4609, 0x28FF, 0x44C1,
+    0x4608, 0x28FE, 0x470A, 0x470B, 0x2974, 0x4709, 0x4708, 0x47DE, 0x29BC, 0x4904, 0x4961, 0x4960, 0x2A54, 0x2A6E, 0x4962, 0x49C9,
+    0x2A80, 0x1845, 0x19B9, 0x226E, 0x2975, 0x1846, 0x1B45, 0x1EED, 0x20B2, 0x2862, 0x19BA, 0x30D2, 0x30D1, 0x32E9, 0x4B8D, 0x1D0B,
+    0x1D09, 0x1D0D, 0x32EB, 0x1D0C, 0x1D0A, 0x32EA, 0x1EF0, 0x3558, 0x1EEF, 0x1EF1, 0x355D, 0x1EF3, 0x355A, 0x355B, 0x3553, 0x1EF2,
+    0x355E, 0x3555, 0x3556, 0x3554, 0x1EEE, 0x355C, 0x3557, 0x3559, 0x37FC, 0x37FB, 0x37F9, 0x37F1, 0x3800, 0x37F4, 0x37FE, 0x3805,
+    0x37FD, 0x20BA, 0x20B6, 0x37F2, 0x37F8, 0x20B8, 0x37F7, 0x20B9, 0x20BB, 0x37FA, 0x20B3, 0x20B4, 0x3806, 0x3804, 0x3803, 0x37F5,
+    0x3801, 0x20B7, 0x20B5, 0x3802, 0x37F3, 0x37F6, 0x37FF, 0x3D0F, 0x3A98, 0x3A91, 0x3AA4, 0x3AAD, 0x3A94, 0x2279, 0x3AA1, 0x226F,
+    0x2271, 0x227D, 0x3A92, 0x4C07, 0x2272, 0x2274, 0x227E, 0x2273, 0x227C, 0x3A9A, 0x3A8F, 0x227A, 0x3A99, 0x2276, 0x3AAB, 0x227B,
+    0x3AA9, 0x3A9D, 0x3AA7, 0x2278, 0x3A90, 0x3AAA, 0x3AA5, 0x3AAC, 0x2270, 0x227F, 0x2275, 0x3A96, 0x3A9E, 0x3AA2, 0x3AA6, 0x2277,
+    0x3A95, 0x3A93, 0x3A9F, 0x3A9C, 0x3A9B, 0x3AA0, 0x3AA3, 0x3AA8, 0x3CEF, 0x23F7, 0x3CF9, 0x3CF1, 0x23FC, 0x2400, 0x3D02, 0x3D0A,
+    0x3CFB, 0x23F8, 0x3CFF, 0x3A97, 0x23F9, 0x3D06, 0x3D0B, 0x3D03, 0x3D05, 0x3D0E, 0x3D07, 0x3CF5, 0x3CFE, 0x2401, 0x23FD, 0x3CF3,
+    0x3D00, 0x23FB, 0x3CFA, 0x23FA, 0x3D08, 0x3CF7, 0x3CF0, 0x23FE, 0x3D0D, 0x3CF2, 0x3D04, 0x3D01, 0x3CFC, 0x3CEE, 0x3CED, 0x3CF6,
+    0x3D09, 0x23FF, 0x3D0C, 0x3CF4, 0x3CF8, 0x257B, 0x2583, 0x257E, 0x3F6D, 0x3F6A, 0x3F52, 0x2579, 0x4A0D, 0x2578, 0x257F, 0x3F50,
+    0x257D, 0x3F5C, 0x3F4E, 0x3F4F, 0x2577, 0x3F6C, 0x2581, 0x3F5F, 0x3F64, 0x3F5E, 0x418E, 0x3F5A, 0x3F62, 0x3F60, 0x3F53, 0x3F4D,
+    0x3F68, 0x2580, 0x3F69, 0x3F63, 0x3F58, 0x3F56, 0x3F51, 0x4A36, 0x3F59, 0x3F66, 0x3F55, 0x3F65, 0x3F6B, 0x257C, 0x3F61, 0x3F67,
+    0x3F5D, 0x3F57, 0x257A, 0x3CFD, 0x3F5B, 0x2582, 0x3F54, 0x4194, 0x269B, 0x4193, 0x4190, 0x41A3, 0x269F, 0x41A0, 0x4198, 0x41A2,
+    0x4189, 0x4196, 0x26A1, 0x4181, 0x417F, 0x41A1, 0x419F, 0x419E, 0x418D, 0x418C, 0x4182, 0x26A3, 0x4188, 0x4192, 0x419D, 0x26A6,
+    0x41A4, 0x27C6, 0x26A8, 0x26A2, 0x4186, 0x418F, 0x417E, 0x4180, 0x2699, 0x26A5, 0x269E, 0x4187, 0x4197, 0x4191, 0x26A4, 0x417D,
+    0x27C1, 0x4199, 0x419C, 0x26A0, 0x418B, 0x26A7, 0x269D, 0x269C, 0x4195, 0x419B, 0x269A, 0x4184, 0x419A, 0x4185, 0x4B84, 0x4CAE,
+    0x4A40, 0x418A, 0x4349, 0x434E, 0x27C3, 0x27C5, 0x4354, 0x27BF, 0x4363, 0x435A, 0x434F, 0x4350, 0x4359, 0x27CC, 0x4358, 0x4348,
+    0x4357, 0x434B, 0x4364, 0x27CB, 0x27C9, 0x434C, 0x435F, 0x4351, 0x435E, 0x4C9F, 0x4360, 0x4347, 0x27C4, 0x4361, 0x4355, 0x27C8,
+    0x4352, 0x27CA, 0x435B, 0x27C2, 0x434D, 0x435C, 0x4356, 0x4183, 0x435D, 0x434A, 0x27C7, 0x4362, 0x27C0, 0x4345, 0x4CF7, 0x44D0,
+    0x44C9, 0x2864, 0x44C4, 0x44D3, 0x44CB, 0x4353, 0x44D5, 0x44C5, 0x2863, 0x44CF, 0x2865, 0x286D, 0x286B, 0x44D1, 0x286C, 0x44C7,
+    0x44C8, 0x44CD, 0x44D2, 0x4344, 0x2866, 0x44DA, 0x44D7, 0x44DB, 0x44CE, 0x44CA, 0x44D8, 0x4614, 0x44CC, 0x2869, 0x2868, 0x4346,
+    0x286A, 0x44D4, 0x44D6, 0x2867, 0x44D9, 0x44C3, 0x44C6, 0x461D, 0x460D, 0x2903, 0x461B, 0x460B, 0x2904, 0x4616, 0x4612, 0x2909,
+    0x461C, 0x460C, 0x460F, 0x2901, 0x461E, 0x4B0B, 0x4617, 0x461A, 0x2907, 0x290C, 0x290A, 0x4613, 0x460E, 0x2905, 0x2906, 0x460A,
+    0x2902, 0x2900, 0x2908, 0x4619, 0x290B, 0x4615, 0x461F, 0x290D, 0x4611, 0x4618, 0x4C75, 0x4CE3, 0x4718, 0x4726, 0x471A, 0x4721,
+    0x4610, 0x471F, 0x470E, 0x4724, 0x2978, 0x4712, 0x4723, 0x4719, 0x2977, 0x4728, 0x471B, 0x4720, 0x4722, 0x470C, 0x4725, 0x4717,
+    0x471C, 0x4710, 0x4714, 0x4727, 0x471E, 0x470D, 0x4711, 0x4713, 0x471D, 0x2976, 0x4716, 0x4B74, 0x470F, 0x4715, 0x47E2, 0x47E7,
+    0x29C3, 0x47E9, 0x29BD, 0x47E5, 0x47EB, 0x29C2, 0x29BE, 0x29BF, 0x47E1, 0x47E8, 0x29C1, 0x47E6, 0x29C0, 0x47E0, 0x47E4, 0x47E3,
+    0x47DF, 0x47EA, 0x4CA9, 0x4CAC, 0x2A03, 0x488B, 0x488E, 0x488A, 0x488C, 0x488D, 0x4887, 0x4888, 0x4885, 0x4889, 0x4886, 0x2A04,
+    0x2A05, 0x4906, 0x4908, 0x4907, 0x4909, 0x2A32, 0x4905, 0x2A31, 0x2A33, 0x4964, 0x4965, 0x2A55, 0x4963, 0x49AB, 0x49AA, 0x49AC,
+    0x2A70, 0x49AD, 0x2A6F, 0x49AE, 0x49CA, 0x49CD, 0x49CC, 0x2A79, 0x49CB, 0x4BCE, 0x2A83, 0x2A81, 0x2A82, 0x2A88, 0x49F6, 0x49F7,
+    0x49F5, 0x49FF, 0x19BB, 0x355F, 0x3807, 0x3F6E, 0x4620, 0x19BC, 0x1B46, 0x1D0E, 0x3560, 0x3561, 0x1EF4, 0x20BE, 0x3809, 0x3808,
+    0x20C2, 0x20BD, 0x380A, 0x20BF, 0x20C1, 0x20C0, 0x20BC, 0x2280, 0x3AB1, 0x3AAF, 0x3AB0, 0x3AAE, 0x2402, 0x2405, 0x2407, 0x2406,
+    0x2403, 0x2404, 0x3F70, 0x3F6F, 0x2584, 0x3F71, 0x3F72, 0x2585, 0x41AC, 0x41AA, 0x436A, 0x41A8, 0x41A9, 0x26A9, 0x41A5, 0x41AD,
+    0x41A7, 0x41AB, 0x4366, 0x4368, 0x4369, 0x27D1, 0x4365, 0x27D0, 0x4367, 0x27CD, 0x27CE, 0x27CF, 0x41A6, 0x2870, 0x44DE, 0x44DC,
+    0x44DD, 0x286E, 0x2871, 0x286F, 0x4621, 0x4622, 0x290E, 0x4729, 0x472B, 0x472A, 0x2979, 0x29C4, 0x47EE, 0x47ED, 0x47EC, 0x19BD,
+    0x2C70, 0x2CDD, 0x1738, 0x2CDB, 0x2CDE, 0x2CDC, 0x2DAE, 0x184A, 0x184B, 0x2DB0, 0x1848, 0x2DAF, 0x2DAD, 0x1849, 0x1847, 0x2F12,
+    0x2F15, 0x19C0, 0x2F14, 0x2F13, 0x19BF, 0x19BE, 0x19C2, 0x2F16, 0x19C1, 0x30D7, 0x1B48, 0x1B49, 0x1B4A, 0x30D8, 0x30D4, 0x1B47,
+    0x30D5, 0x30D6, 0x30D3, 0x1D15, 0x1D12, 0x32EC, 0x1D13, 0x1D16, 0x32ED, 0x1D11, 0x1D0F, 0x1D10, 0x1D14, 0x1EF5, 0x3564, 0x1EFD,
+    0x3563, 0x3566, 0x1EF9, 0x3565, 0x20CA, 0x1EF7, 0x1EFA, 0x1EF6, 0x1EFB, 0x1EFC, 0x1EF8, 0x3562, 0x20C6, 0x380C, 0x3810, 0x380F,
+    0x20CB, 0x20C7, 0x20C8, 0x380B, 0x380D, 0x380E, 0x20C3, 0x20C5, 0x20C9, 0x20C4, 0x3AB4, 0x3AB2, 0x3AB3, 0x2282, 0x2283, 0x3AB5,
+    0x2281, 0x2408, 0x240A, 0x2409, 0x3D10, 0x3D11, 0x3F74, 0x3F73, 0x4D2B, 0x26AA, 0x26AB, 0x41AE, 0x26AC, 0x436D, 0x436B, 0x436C,
+    0x27D2, 0x44DF, 0x290F, 0x2AC6, 0x27D3, 0x19C3, 0x1D17, 0x32EE, 0x3567, 0x1EFE, 0x20CC, 0x3811, 0x3813, 0x20CE, 0x20CD, 0x20CF,
+    0x20D0, 0x3812, 0x2286, 0x2287, 0x2285, 0x240B, 0x2284, 0x3AB6, 0x240C, 0x3F75, 0x41AF, 0x26AD, 0x27D4, 0x44E0, 0x44E4, 0x2874,
+    0x44E1, 0x2875, 0x2873, 0x44E5, 0x2876, 0x44E3, 0x4623, 0x2872, 0x2910, 0x4966, 0x19C4, 0x1F00, 0x1EFF, 0x20D1, 0x3815, 0x3814,
+    0x20D2, 0x3ABA, 0x228B, 0x2288, 0x3AB9, 0x228A, 0x3AB7, 0x2289, 0x3AB8, 0x3D12, 0x240D, 0x3F78, 0x2586, 0x3F76, 0x2587, 0x2588,
+    0x3F77, 0x2589, 0x41B0, 0x26B1, 0x26AE, 0x26B3, 0x41B2, 0x26AF, 0x41B1, 0x26B2, 0x26B0, 0x4370, 0x4372, 0x27D5, 0x4371, 0x27D6,
+    0x436F, 0x436E, 0x44E7, 0x44E6, 0x2877, 0x44E8, 0x4628, 0x2912, 0x4627, 0x4624, 0x2911, 0x4625, 0x4626, 0x472C, 0x472D, 0x297A,
+    0x29C7, 0x47EF, 0x29C5, 0x29C6, 0x47F0, 0x2A06, 0x2A07, 0x488F, 0x2A56, 0x4968, 0x2A58, 0x4967, 0x4969, 0x2A57, 0x49AF, 0x49E3,
+    0x19C5, 0x228C, 0x3D13, 0x3F79, 0x26B4, 0x26B5, 0x19C6, 0x258A, 0x2913, 0x1B4B, 0x26B6, 0x2A34, 0x1B4C, 0x3568, 0x3816, 0x3818,
+    0x3817, 0x3ABE, 0x3ABB, 0x228D, 0x228E, 0x3ABC, 0x3ABD, 0x3D15, 0x3D1A, 0x240E, 0x3D14, 0x3D16, 0x3D1D, 0x3D18, 0x3D1C, 0x3D19,
+    0x3D17, 0x3D1B, 0x240F, 0x4AEA, 0x3F7C, 0x3F7A, 0x258C, 0x258B, 0x3F7B, 0x258D, 0x41B5, 0x41B4, 0x26B7, 0x41B3, 0x4373, 0x4375,
+    0x4377, 0x4376, 0x27D7, 0x4374, 0x44EF, 0x2878, 0x44ED, 0x44F0, 0x2879, 0x44EB, 0x44EE, 0x44EC, 0x44E9, 0x287A, 0x44EA, 0x4629,
+    0x462B, 0x462A, 0x472E, 0x472F, 0x4B81, 0x47F1, 0x2A09, 0x2A08, 0x490A, 0x490B, 0x2A59, 0x496A, 0x2A7A, 0x1B4D, 0x20D3, 0x3D1F,
+    0x3D1E, 0x3F7E, 0x3F7D, 0x27D8, 0x4379, 0x4378, 0x44F3, 0x44F1, 0x44F4, 0x44F2, 0x2914, 0x462C, 0x462D, 0x462E, 0x47F2, 0x4890,
+    0x496B, 0x1B4E, 0x41B6, 0x437A, 0x1B4F, 0x2410, 0x41B7, 0x287B, 0x44F5, 0x2915, 0x4730, 0x4731, 0x29C8, 0x490C, 0x1B50, 0x1F03,
+    0x1F04, 0x3569, 0x20D4, 0x20D5, 0x3819, 0x20D6, 0x2292, 0x2294, 0x3AC0, 0x3AC1, 0x3ABF, 0x228F, 0x2290, 0x2293, 0x2291, 0x4BC9,
+    0x3D20, 0x2411, 0x2412, 0x3F85, 0x2590, 0x3F80, 0x3F7F, 0x3F84, 0x258E, 0x26BE, 0x3F81, 0x3F86, 0x3F83, 0x3F82, 0x258F, 0x26BC,
+    0x41B9, 0x26B8, 0x41BA, 0x41B8, 0x26BB, 0x26B9, 0x26BD, 0x26BA, 0x437B, 0x4380, 0x437C, 0x437F, 0x27D9, 0x42AE, 0x437E, 0x437D,
+    0x287E, 0x287C, 0x287F, 0x287D, 0x44F6, 0x44F7, 0x44F8, 0x2880, 0x4632, 0x2917, 0x4630, 0x2918, 0x462F, 0x4631, 0x2916, 0x4735,
+    0x4732, 0x4733, 0x4734, 0x47F3, 0x29CA, 0x29C9, 0x4892, 0x4891, 0x2A0A, 0x4A5F, 0x2A35, 0x2A5A, 0x2A71, 0x49B0, 0x49E4, 0x49E5,
+    0x1B51, 0x381A, 0x3AC2, 0x3D21, 0x3D22, 0x2413, 0x2414, 0x3F87, 0x2591, 0x27DA, 0x44F9, 0x2881, 0x4635, 0x2919, 0x4634, 0x4636,
+    0x4633, 0x47F6, 0x4736, 0x4737, 0x297B, 0x47F5, 0x47F4, 0x4893, 0x49E6, 0x1B52, 0x1B53, 0x1D18, 0x32EF, 0x356A, 0x20D7, 0x20DA,
+    0x20D8, 0x381B, 0x20DC, 0x20D9, 0x20DB, 0x2296, 0x3AC3, 0x3AC4, 0x3F89, 0x2295, 0x2297, 0x2298, 0x3D24, 0x3D23, 0x2415, 0x2416,
+    0x3D25, 0x3F88, 0x2418, 0x2592, 0x2417, 0x26BF, 0x3F8A, 0x2594, 0x2593, 0x3F8B, 0x3F8E, 0x3F8C, 0x3F8D, 0x2595, 0x26C4, 0x26C2,
+    0x26C1, 0x41BC, 0x26C3, 0x41BB, 0x4381, 0x41BD, 0x26C0, 0x41BE, 0x4384, 0x4382, 0x4383, 0x4388, 0x2885, 0x4387, 0x438A, 0x4389,
+    0x4386, 0x4385, 0x27DB, 0x44FC, 0x44FB, 0x2884, 0x2882, 0x2883, 0x44FA, 0x4639, 0x291A, 0x4638, 0x4637, 0x291B, 0x473C, 0x473B,
+    0x4739, 0x4738, 0x297D, 0x297C, 0x473D, 0x4894, 0x2A0B, 0x47F8, 0x29CB, 0x47F7, 0x473A, 0x4895, 0x2A36, 0x2A72, 0x49B1, 0x49E7,
+    0x1B54, 0x356B, 0x438B, 0x1B55, 0x3D27, 0x3D26, 0x41BF, 0x438D, 0x438C, 0x4BF7, 0x2886, 0x463A, 0x463B, 0x297E, 0x49E8, 0x1D19,
+    0x20DE, 0x20DD, 0x3AC5, 0x3AC7, 0x229A, 0x3AC6, 0x2299, 0x229B, 0x3AC8, 0x3D29, 0x3D2B, 0x3D2A, 0x3D2D, 0x2419, 0x3D2C, 0x3D28,
+    0x3D2E, 0x3F95, 0x3F98, 0x3F9A, 0x3F90, 0x3F94, 0x3F91, 0x2597, 0x259A, 0x259C, 0x3F92, 0x3F93, 0x259B, 0x3F96, 0x3F99, 0x3F97,
+    0x259D, 0x2599, 0x3F8F, 0x2596, 0x2598, 0x26C6, 0x41C5, 0x41C3, 0x41C2, 0x41C8, 0x41C7, 0x41C6, 0x41C1, 0x26C5, 0x41C0, 0x41C4,
+    0x26C7, 0x4390, 0x4394, 0x4391, 0x4393, 0x4392, 0x438F, 0x4395, 0x4398, 0x4396, 0x4397, 0x27DD, 0x27DC, 0x438E, 0x4399, 0x4501,
+    0x4504, 0x4506, 0x4505, 0x44FF, 0x4503, 0x44FE, 0x4500, 0x2887, 0x44FD, 0x4502, 0x4648, 0x463D, 0x291C, 0x291D, 0x463C, 0x4641,
+    0x4647, 0x463F, 0x4646, 0x4643, 0x4642, 0x4645, 0x4640, 0x463E, 0x4644, 0x4744, 0x4742, 0x297F, 0x4741, 0x4747, 0x4745, 0x2980,
+    0x4740, 0x473E, 0x473F, 0x2982, 0x4743, 0x2981, 0x4746, 0x47F9, 0x47FF, 0x47FA, 0x29CF, 0x4800, 0x29CE, 0x47FE, 0x47FD, 0x29CD,
+    0x47FC, 0x29CC, 0x47FB, 0x489B, 0x489D, 0x489C, 0x4899, 0x2A0D, 0x4896, 0x489A, 0x489F, 0x489E, 0x4897, 0x4898, 0x2A0C, 0x490D,
+    0x2A39, 0x490E, 0x2A37, 0x2A38, 0x496C, 0x2A5B, 0x49CE, 0x2A7B, 0x49E9, 0x2A7C, 0x49EA, 0x49EB, 0x49F9, 0x49F8, 0x2A8B, 0x4A03,
+    0x1D1A, 0x3ACA, 0x3AC9, 0x241A, 0x241B, 0x3D2F, 0x3F9B, 0x41CB, 0x259E, 0x26C8, 0x41C9, 0x41CC, 0x26C9, 0x439A, 0x41CA, 0x4507,
+    0x2888, 0x4649, 0x474A, 0x4748, 0x4749, 0x4801, 0x29D0, 0x48A0, 0x2A3C, 0x2A0E, 0x2A3A, 0x2A3B, 0x496D, 0x2A73, 0x1D1B, 0x4508,
+    0x32F0, 0x229C, 0x3D30, 0x241C, 0x3D31, 0x3F9D, 0x3F9C, 0x26CB, 0x259F, 0x25A0, 0x3FA0, 0x3F9F, 0x3F9E, 0x41CD, 0x41D0, 0x41CF,
+    0x41CE, 0x26CA, 0x439E, 0x439C, 0x439B, 0x439D, 0x2889, 0x450A, 0x450B, 0x288A, 0x4509, 0x464B, 0x464A, 0x464D, 0x291E, 0x464C,
+    0x474B, 0x474D, 0x474C, 0x4802, 0x4805, 0x4803, 0x4804, 0x48A1, 0x2A0F, 0x490F, 0x4910, 0x4911, 0x2A5C, 0x2A74, 0x49EC, 0x1D1C,
+    0x25A1, 0x26CC, 0x450C, 0x48A2, 0x49FA, 0x32F1, 0x2A8C, 0x1D1D, 0x41D1, 0x450D, 0x464E, 0x4806, 0x48A3, 0x1D1E, 0x3D32, 0x3D33,
+    0x241D, 0x241E, 0x3FA2, 0x25A3, 0x25A2, 0x3FA1, 0x439F, 0x450E, 0x4510, 0x450F, 0x288D, 0x288C, 0x288B, 0x29D2, 0x4807, 0x29D1,
+    0x48A5, 0x48A4, 0x2A5D, 0x496E, 0x1F05, 0x3ACB, 0x3D36, 0x3D34, 0x3D35, 0x3FAB, 0x3FA6, 0x3FA3, 0x3FAA, 0x3FAC, 0x25A5, 0x3FA9,
+    0x3FA5, 0x3FA4, 0x3FA8, 0x3FA7, 0x25A4, 0x41DC, 0x41D7, 0x41D5, 0x41DE, 0x41D6, 0x41D2, 0x41D8, 0x4C4F, 0x41D3, 0x43A8, 0x41D4,
+    0x41DF, 0x41DB, 0x26CD, 0x41DA, 0x41D9, 0x41DD, 0x43A0, 0x43A3, 0x43A2, 0x43AA, 0x43A5, 0x43A9, 0x43A7, 0x43A6, 0x43A4, 0x43A1,
+    0x27E0, 0x27DF, 0x27E1, 0x27DE, 0x43AB, 0x4516, 0x4519, 0x4517, 0x451B, 0x451C, 0x4514, 0x2892, 0x4515, 0x4513, 0x451A, 0x4512,
+    0x4511, 0x2891, 0x288F, 0x288E, 0x4518, 0x4657, 0x465D, 0x2921, 0x4658, 0x465B, 0x465F, 0x2922, 0x465A, 0x4652, 0x4651, 0x465E,
+    0x4655, 0x4653, 0x465C, 0x4654, 0x2920, 0x291F, 0x464F, 0x4650, 0x4659, 0x4656, 0x4750, 0x4753, 0x2890, 0x4751, 0x4757, 0x4755,
+    0x474F, 0x475A, 0x474E, 0x2984, 0x4756, 0x4752, 0x2983, 0x4759, 0x4758, 0x480A, 0x4809, 0x4810, 0x480C, 0x480F, 0x29D4, 0x480D,
+    0x480E, 0x4808, 0x480B, 0x29D3, 0x2A11, 0x48B0, 0x48AB, 0x48AF, 0x48B2, 0x48AE, 0x48AA, 0x2A13, 0x48AD, 0x48B1, 0x2A12, 0x48A8,
+    0x4754, 0x48AC, 0x48A9, 0x48A6, 0x48A7, 0x2A10, 0x4915, 0x4917, 0x491A, 0x4916, 0x491B, 0x4914, 0x4912, 0x2A3D, 0x4918, 0x2A3F,
+    0x2A3E, 0x4913, 0x4919, 0x4973, 0x2A5E, 0x4974, 0x4972, 0x496F, 0x4971, 0x4970, 0x49B2, 0x49B4, 0x49B3, 0x49D1, 0x49D0, 0x49CF,
+    0x49D2, 0x2A84, 0x2A85, 0x4A00, 0x4A04, 0x1F06, 0x381C, 0x3ACE, 0x229D, 0x3ACC, 0x3ACD, 0x3D37, 0x3D38, 0x2421, 0x241F, 0x3D39,
+    0x2420, 0x3FB1, 0x3FB4, 0x3FAE, 0x3FB5, 0x3FAD, 0x3FAF, 0x3FB0, 0x3FB3, 0x25A8, 0x3FB8, 0x3FB7, 0x25A6, 0x3FB2, 0x3FB6, 0x25A7,
+    0x41E9, 0x26D2, 0x41E4, 0x26CE, 0x41E1, 0x41E7, 0x41EA, 0x26D3, 0x41E6, 0x41E3, 0x41EB, 0x41E2, 0x41E8, 0x26CF, 0x41E0, 0x26D0,
+    0x26D1, 0x41E5, 0x43BD, 0x43B0, 0x43B1, 0x43B4, 0x43B2, 0x43AC, 0x4A74, 0x43AF, 0x43B9, 0x43B3, 0x27E2, 0x43BB, 0x43B8, 0x27E3,
+    0x43BA, 0x43AD, 0x43B6, 0x43B7, 0x43B5, 0x4520, 0x4522, 0x4525, 0x451F, 0x2893, 0x4527, 0x451E, 0x4528, 0x4524, 0x4526, 0x452A,
+    0x4523, 0x452B, 0x4521, 0x451D, 0x2894, 0x4529, 0x2895, 0x2924, 0x43AE, 0x4679, 0x4670, 0x2926, 0x466C, 0x2927, 0x4675, 0x466F,
+    0x4665, 0x2925, 0x4672, 0x466D, 0x466E, 0x4660, 0x4667, 0x4676, 0x4673, 0x466B, 0x4677, 0x4666, 0x4661, 0x4674, 0x4663, 0x4671,
+    0x4668, 0x4678, 0x4664, 0x2923, 0x4662, 0x4669, 0x466A, 0x4761, 0x475F, 0x4764, 0x4770, 0x4767, 0x4760, 0x475C, 0x4769, 0x4762,
+    0x4765, 0x475E, 0x476C, 0x475B, 0x4763, 0x4768, 0x476A, 0x476D, 0x475D, 0x4771, 0x4772, 0x476B, 0x476F, 0x4766, 0x4820, 0x4823,
+    0x29D5, 0x4822, 0x4827, 0x4825, 0x29D6, 0x481C, 0x4812, 0x4811, 0x29D8, 0x481E, 0x481F, 0x481B, 0x4813, 0x4818, 0x476E, 0x4814,
+    0x29D7, 0x481A, 0x4819, 0x4815, 0x4821, 0x4816, 0x48B7, 0x4824, 0x4826, 0x481D, 0x4817, 0x48B8, 0x48BA, 0x48B4, 0x2A14, 0x48C1,
+    0x48BF, 0x2A15, 0x48BE, 0x48BD, 0x48B6, 0x48B3, 0x48B9, 0x48C2, 0x48B5, 0x48BB, 0x4924, 0x4929, 0x491F, 0x4922, 0x2A40, 0x4926,
+    0x492D, 0x48BC, 0x4920, 0x492A, 0x492E, 0x4925, 0x491E, 0x4928, 0x4927, 0x492C, 0x492B, 0x48C0, 0x4923, 0x491D, 0x4921, 0x2A5F,
+    0x2A60, 0x491C, 0x497F, 0x4976, 0x497E, 0x497B, 0x497C, 0x4975, 0x4978, 0x4980, 0x497A, 0x4979, 0x4977, 0x497D, 0x49B5, 0x49B6,
+    0x49B8, 0x49B7, 0x49BA, 0x49B9, 0x49D4, 0x49D3, 0x49ED, 0x49EE, 0x49FB, 0x2A89, 0x2A8D, 0x4A05, 0x2A8E, 0x1F07, 0x2985, 0x4828,
+    0x2A61, 0x2A62, 0x1F08, 0x3ACF, 0x229E, 0x3FB9, 0x41ED, 0x41EE, 0x41EC, 0x43BF, 0x43BE, 0x27E4, 0x452D, 0x43C0, 0x452C, 0x467B,
+    0x2928, 0x292A, 0x467A, 0x2929, 0x4773, 0x4775, 0x4774, 0x4829, 0x29D9, 0x2A41, 0x4981, 0x49BB, 0x4A08, 0x1F09, 0x3D3A, 0x25A9,
+    0x41F0, 0x41EF, 0x43C1, 0x292B, 0x2986, 0x48C3, 0x4A01, 0x1F0A, 0x2422, 0x25AA, 0x467C, 0x492F, 0x20DF, 0x43C2, 0x2A75, 0x20E0,
+    0x25AB, 0x27E5, 0x4930, 0x20E1, 0x3FBA, 0x26D5, 0x41F1, 0x41F2, 0x26D4, 0x43C3, 0x27E9, 0x27E7, 0x27E8, 0x27E6, 0x452E, 0x2896,
+    0x4777, 0x4776, 0x4779, 0x4778, 0x2987, 0x482A, 0x482C, 0x482B, 0x29DA, 0x48C4, 0x4931, 0x4932, 0x2A42, 0x49BC, 0x49D5, 0x2A86,
+    0x381D, 0x41F3, 0x43C4, 0x467D, 0x3AD0, 0x43C5, 0x4530, 0x452F, 0x4933, 0x2A63, 0x49BD, 0x49D6, 0x229F, 0x3FBB, 0x3FBC, 0x41F4,
+    0x22A0, 0x2897, 0x4531, 0x482E, 0x29DB, 0x482F, 0x482D, 0x4934, 0x4982, 0x22A1, 0x43C8, 0x43C7, 0x43C6, 0x4532, 0x4536, 0x4535,
+    0x4534, 0x4533, 0x2898, 0x467E, 0x477B, 0x2988, 0x477A, 0x4830, 0x48C7, 0x48C6, 0x2A16, 0x48C5, 0x4937, 0x4936, 0x4935, 0x2423,
+    0x41F5, 0x27EA, 0x467F, 0x4680, 0x48C8, 0x4938, 0x4983, 0x49BE, 0x49EF, 0x4A0A, 0x2424, 0x27EB, 0x4537, 0x4681, 0x4831, 0x4939,
+    0x25AD, 0x43C9, 0x4538, 0x4682, 0x4683, 0x4684, 0x4780, 0x477C, 0x29DC, 0x477F, 0x477E, 0x2989, 0x477D, 0x298B, 0x298A, 0x4833,
+    0x4832, 0x29DD, 0x29DE, 0x2A18, 0x48C9, 0x2A17, 0x493C, 0x493D, 0x493B, 0x493A, 0x2A65, 0x4984, 0x4985, 0x4986, 0x2A64, 0x49BF,
+    0x49C2, 0x49C1, 0x49C0, 0x4A09, 0x26D6, 0x289F, 0x4781, 0x4834, 0x2A19, 0x48CA, 0x4A0B, 0x26D7, 0x43CA, 0x48CB, 0x49D7, 0x2BCA,
+    0x2BCC, 0x2C32, 0x3906, 0x13AA, 0x13B7, 0x13B9, 0x13BB, 0x13BF, 0x13C0, 0x13C3, 0x13C4, 0x13C7, 0x13C8, 0x13CB, 0x13CC, 0x13CF,
+    0x13D0, 0x13D3, 0x13D4, 0x13D7, 0x13D8, 0x13DB, 0x13DC, 0x1404, 0x1405, 0x1408, 0x1409, 0x1406, 0x1407, 0x13BC, 0x13AD, 0x13AE,
+    0x13AF, 0x13B1, 0x13B2, 0x13B3, 0x13B4, 0x13DD, 0x13DE, 0x13DF, 0x13E0, 0x13E1, 0x13E2, 0x140A, 0x140B, 0x140C, 0x141C, 0x141D,
+    0x141E, 0x141F, 0x1420, 0x143F, 0x1449, 0x144A, 0x144B, 0x13A9, 0x2BF9, 0x13EB, 0x1440, 0x1445, 0x13EC, 0x2BF8, 0x13BD, 0x13BE,
+    0x13ED, 0x140D, 0x13A1, 0x140E, 0x13A4, 0x143C, 0x148A, 0x148B, 0x148C, 0x148D, 0x148E, 0x148F, 0x1490, 0x1491, 0x1492, 0x1493,
+    0x13A7, 0x13A6, 0x1413, 0x1415, 0x1414, 0x13A8, 0x1446, 0x14AA, 0x14AB, 0x14AC, 0x14AD, 0x14AE, 0x14AF, 0x14B0, 0x14B1, 0x14B2,
+    0x14B3, 0x14B4, 0x14B5, 0x14B6, 0x14B7, 0x14B8, 0x14B9, 0x14BA, 0x14BB, 0x14BC, 0x14BD, 0x14BE, 0x14BF, 0x14C0, 0x14C1, 0x14C2,
+    0x14C3, 0x2AD3, 0x143D, 0x2AD4, 0x1402, 0x14C4, 0x14C5, 0x14C6, 0x14C7, 0x14C8, 0x14C9, 0x14CA, 0x14CB, 0x14CC, 0x14CD, 0x14CE,
+    0x14CF, 0x14D0, 0x14D1, 0x14D2, 0x14D3, 0x14D4, 0x14D5, 0x14D6, 0x14D7, 0x14D8, 0x14D9, 0x14DA, 0x14DB, 0x14DC, 0x14DD, 0x13C1,
+    0x13B5, 0x13C2, 0x1421, 0x1443, 0x1444, 0x2BF6, 0x1401, 0x2BF7, 0x1441, 0x4A34, 0x2BC7, 0x2BC5, 0x4BBA, 0x4B76, 0x4B3B, 0x4B8E,
+    0x4A49, 0x4A69, 0x4A58, 0x4A55, 0x4A57, 0x4C36, 0x4C20, 0x4A5A, 0x4A63, 0x4A35, 0x4A6B, 0x4B40, 0x4A70, 0x4CD6, 0x4A37, 0x4BAA,
+    0x4C2F, 0x4A95, 0x4ABC, 0x4A99, 0x4A67, 0x4CC5, 0x4AC1, 0x4B66, 0x4C4E, 0x4AD1, 0x4B17, 0x4AC9, 0x4BEB, 0x4ACF, 0x4AE0, 0x4B05,
+    0x4AF1, 0x4A4B, 0x4B7A, 0x4D25, 0x4BC4, 0x4AFA, 0x4AFC, 0x4C72, 0x4AA9, 0x4AAA, 0x4B11, 0x4B14, 0x4B32, 0x4D22, 0x4B21, 0x4CCA,
+    0x4AA1, 0x4ACE, 0x4A9B, 0x4BBC, 0x4B6B, 0x4B1D, 0x4B1A, 0x4B1C, 0x4BC5, 0x4B20, 0x4BDF, 0x4B92, 0x4CED, 0x4ADB, 0x4B30, 0x4C37,
+    0x4A6D, 0x4CEF, 0x4A41, 0x4B3E, 0x4CF4, 0x4B45, 0x4B49, 0x4B51, 0x4D17, 0x4B52, 0x4BF0, 0x4B56, 0x4B8A, 0x4B5C, 0x4A68, 0x4C25,
+    0x4B5D, 0x4B7D, 0x4B7F, 0x4C3F, 0x4C51, 0x4A4D, 0x4A53, 0x4A6A, 0x4B2E, 0x4D36, 0x4B8C, 0x4AD3, 0x4B97, 0x4C33, 0x4BAE, 0x4CD9,
+    0x4BB5, 0x4BF6, 0x4BB4, 0x4ABF, 0x4BBE, 0x4BC8, 0x4CB7, 0x4C6C, 0x4BD9, 0x4BE8, 0x4BE9, 0x4BF3, 0x4BE5, 0x4BE6, 0x4C50, 0x4BF1,
+    0x4A6C, 0x4AE3, 0x4D1E, 0x4A65, 0x4C09, 0x4BE3, 0x4C0C, 0x4C12, 0x4A51, 0x4C17, 0x4C24, 0x4C27, 0x4A81, 0x4BFD, 0x4C35, 0x4C3A,
+    0x4A71, 0x4A43, 0x4C4B, 0x4C45, 0x4C47, 0x4C66, 0x4C70, 0x4C3C, 0x4A94, 0x4C68, 0x4C83, 0x4BAD, 0x4A38, 0x4ABE, 0x4A96, 0x4C82,
+    0x4C81, 0x4ADC, 0x4C73, 0x4C5C, 0x4BCA, 0x4C7F, 0x4C84, 0x4C88, 0x4C7D, 0x4C80, 0x4C86, 0x4C87, 0x4CE8, 0x4C48, 0x4AF3, 0x4C01,
+    0x4CC9, 0x4C96, 0x4B0A, 0x4CA0, 0x4AFD, 0x4C9D, 0x4BF9, 0x4ADE, 0x4CA2, 0x4B65, 0x4CBD, 0x4C40, 0x4C4A, 0x4B5B, 0x4C49, 0x4A47,
+    0x4BDB, 0x4A3E, 0x4CB2, 0x4C5B, 0x4B9D, 0x4CCF, 0x4B9E, 0x4A62, 0x4B27, 0x4CCE, 0x4BC0, 0x4CD0, 0x4D26, 0x4BFA, 0x4CA4, 0x4BD5,
+    0x4CCB, 0x4CD4, 0x4CA3, 0x4BD4, 0x4CE9, 0x4CE7, 0x4A7A, 0x4A52, 0x4AFF, 0x4CFE, 0x4CFC, 0x4D15, 0x4D16, 0x4BED, 0x4D1C, 0x4B89,
+    0x4BF4, 0x4D2D, 0x4CAF, 0x4BCB, 0x4AB2, 0x4D3B, 0x4B3F, 0x4D35, 0x4C89, 0x4D09, 0x4B7C, 0x4D0E, 0x4D0D, 0x4CC6, 0x4A8F, 0x4B4A,
+    0x4C41, 0x4AC8, 0x4A4A, 0x4AF8, 0x4B18, 0x4C54, 0x4A4F, 0x4B19, 0x4C0A, 0x4C55, 0x4B1E, 0x4C56, 0x4B23, 0x4B82, 0x4C5D, 0x4BA7,
+    0x4C5F, 0x4D13, 0x4B2D, 0x4B8B, 0x4D41, 0x2BCD, 0x4D31, 0x4B38, 0x4B9F, 0x4A50, 0x4D3D, 0x4B6E, 0x4BF2, 0x4AA6, 0x4CC0, 0x4CBE,
+    0x4D20, 0x4D21, 0x4A48, 0x4AD2, 0x4A3F, 0x4B8F, 0x4B85, 0x4C1B, 0x4AAD, 0x4A91, 0x4C0F, 0x4C64, 0x4D34, 0x4AEF, 0x4CEA, 0x4BB0,
+    0x4CA6, 0x4CEC, 0x4CF6, 0x4CDB, 0x4CF3, 0x4C02, 0x4D44, 0x4C7A, 0x4C2C, 0x4BB1, 0x4B09, 0x4B77, 0x4BA6
+];
@@ -0,0 +1,345 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+
+use crate::{
+    functors::{
+        universal::{
+            codings::{
+                tables::{
+                    single_byte, jis0208, jis0212, gb18030, big5, euc_kr, ebcdic,
+                    case_folding::{fold, SIMPLE_CASE_FOLDING}
+                }
+            }
+        }
+    }
+};
+
+use std::{
+    collections::{
+        HashMap
+    }
+};
+
+use encoding_rs::{
+    Encoding,
+    SHIFT_JIS, EUC_JP, ISO_2022_JP, GB18030, BIG5, EUC_KR
+};
+
+// The WHATWG tables are checked against encoding_rs, which implements the same indexes.
+fn decoded(encoding: &'static Encoding, bytes: &[u8]) -> u32 {
+    return match encoding.decode_without_bom_handling_and_without_replacement(bytes) {
+        Some(text) => { let mut chars = text.chars(); match (chars.next(), chars.next()) { (Some(code), None) => code as u32, _ => 0_u32 } },
+        None => 0_u32
+    };
+}
+
+type SingleByteTable = (&'static Encoding, &'static str, &'static [u16; 128_usize], &'static [(u16, u8)]);
+
+// WHATWG "index pointer": the first pointer mapped to each code point, among the ones `skip` keeps.
+fn first_pointers(table: &[u32], skip: impl Fn(usize) -> bool) -> HashMap<u32, usize> {
+    let mut pointers: HashMap<u32, usize> = HashMap::<u32, usize>::new();
+
+    for (pointer, &code) in table.iter().enumerate() {
+        if code != 0_u32 && !skip(pointer) { pointers.entry(code).or_insert(pointer); }
+    }
+
+    return pointers;
+}
+
+fn single_byte_encode_is_inverse(name: &str, table: &[u16; 128_usize], encode: &[(u16, u8)]) {
+    for window in encode.windows(2_usize) {
+        assert!(window[0_usize].0 < window[1_usize].0, "{}: unsorted entry U+{:04X}", name, window[1_usize].0);
+    }
+
+    assert_eq!(encode.len(), table.iter().filter(|&&code| code != 0_u16).count(), "{}", name);
+
+    for &(code, byte) in encode.iter() {
+        assert_eq!(table[(byte - 0x80) as usize], code, "{}: U+{:04X} encodes to {:02X}", name, code, byte);
+    }
+}
+
+#[test]
+fn single_byte_tables_match_whatwg() {
+    let tables: [SingleByteTable; 24_usize] = [
+        (encoding_rs::WINDOWS_1250, "windows-1250", &single_byte::WINDOWS_1250, &single_byte::WINDOWS_1250_ENCODE),
+        (encoding_rs::WINDOWS_1251, "windows-1251", &single_byte::WINDOWS_1251, &single_byte::WINDOWS_1251_ENCODE),
+        (encoding_rs::WINDOWS_1252, "windows-1252", &single_byte::WINDOWS_1252, &single_byte::WINDOWS_1252_ENCODE),
+        (encoding_rs::WINDOWS_1253, "windows-1253", &single_byte::WINDOWS_1253, &single_byte::WINDOWS_1253_ENCODE),
+        (encoding_rs::WINDOWS_1254, "windows-1254", &single_byte::WINDOWS_1254, &single_byte::WINDOWS_1254_ENCODE),
+        (encoding_rs::WINDOWS_1255, "windows-1255", &single_byte::WINDOWS_1255, &single_byte::WINDOWS_1255_ENCODE),
+        (encoding_rs::WINDOWS_1256, "windows-1256", &single_byte::WINDOWS_1256, &single_byte::WINDOWS_1256_ENCODE),
+        (encoding_rs::WINDOWS_1257, "windows-1257", &single_byte::WINDOWS_1257, &single_byte::WINDOWS_1257_ENCODE),
+        (encoding_rs::WINDOWS_1258, "windows-1258", &single_byte::WINDOWS_1258, &single_byte::WINDOWS_1258_ENCODE),
+        (encoding_rs::ISO_8859_2, "iso-8859-2", &single_byte::ISO_8859_2, &single_byte::ISO_8859_2_ENCODE),
+        (encoding_rs::ISO_8859_3, "iso-8859-3", &single_byte::ISO_8859_3, &single_byte::ISO_8859_3_ENCODE),
+        (encoding_rs::ISO_8859_4, "iso-8859-4", &single_byte::ISO_8859_4, &single_byte::ISO_8859_4_ENCODE),
+        (encoding_rs::ISO_8859_5, "iso-8859-5", &single_byte::ISO_8859_5, &single_byte::ISO_8859_5_ENCODE),
+        (encoding_rs::ISO_8859_6, "iso-8859-6", &single_byte::ISO_8859_6, &single_byte::ISO_8859_6_ENCODE),
+        (encoding_rs::ISO_8859_7, "iso-8859-7", &single_byte::ISO_8859_7, &single_byte::ISO_8859_7_ENCODE),
+        (encoding_rs::ISO_8859_8, "iso-8859-8", &single_byte::ISO_8859_8, &single_byte::ISO_8859_8_ENCODE),
+        (encoding_rs::ISO_8859_10, "iso-8859-10", &single_byte::ISO_8859_10, &single_byte::ISO_8859_10_ENCODE),
+        (encoding_rs::ISO_8859_13, "iso-8859-13", &single_byte::ISO_8859_13, &single_byte::ISO_8859_13_ENCODE),
+        (encoding_rs::ISO_8859_14, "iso-8859-14", &single_byte::ISO_8859_14, &single_byte::ISO_8859_14_ENCODE),
+        (encoding_rs::ISO_8859_15, "iso-8859-15", &single_byte::ISO_8859_15, &single_byte::ISO_8859_15_ENCODE),
+        (encoding_rs::ISO_8859_16, "iso-8859-16", &single_byte::ISO_8859_16, &single_byte::ISO_8859_16_ENCODE),
+        (encoding_rs::KOI8_R, "koi8-r", &single_byte::KOI8_R, &single_byte::KOI8_R_ENCODE),
+        (encoding_rs::KOI8_U, "koi8-u", &single_byte::KOI8_U, &single_byte::KOI8_U_ENCODE),
+        (encoding_rs::IBM866, "ibm866", &single_byte::IBM866, &single_byte::IBM866_ENCODE)
+    ];
+
+    for (encoding, name, table, encode) in tables {
+        for byte in 0x80_u8..=0xFF_u8 {
+            assert_eq!(table[(byte - 0x80) as usize] as u32, decoded(encoding, &[byte]), "{}: byte {:02X}", name, byte);
+        }
+
+        single_byte_encode_is_inverse(name, table, encode);
+    }
+}
+
+#[test]
+fn iso_8859_9_and_11_tables_match_iso() {
+    // ISO-8859-9 is ISO-8859-1 with six Turkish letters swapped in.
+    let turkish: [(u8, u16); 6_usize] = [(0xD0, 0x011E), (0xDD, 0x0130), (0xDE, 0x015E), (0xF0, 0x011F), (0xFD, 0x0131), (0xFE, 0x015F)];
+
+    for byte in 0x80_u8..=0xFF_u8 {
+        let expected: u16 = turkish.iter().find(|&&(from, _)| from == byte).map_or(byte as u16, |&(_, code)| code);
+
+        assert_eq!(single_byte::ISO_8859_9[(byte - 0x80) as usize], expected, "iso-8859-9: byte {:02X}", byte);
+    }
+
+    // ISO-8859-11 keeps the C1 controls where windows-874 has punctuation, and leaves 0xDB..=0xDE and 0xFC..=0xFF unmapped.
+    for byte in 0x80_u8..=0xFF_u8 {
+        let expected: u32 = match byte {
+            0x80..=0xA0 => byte as u32,
+            0xDB..=0xDE | 0xFC..=0xFF => 0_u32,
+            _ => decoded(encoding_rs::WINDOWS_874, &[byte])
+        };
+
+        assert_eq!(single_byte::ISO_8859_11[(byte - 0x80) as usize] as u32, expected, "iso-8859-11: byte {:02X}", byte);
+    }
+
+    single_byte_encode_is_inverse("iso-8859-9", &single_byte::ISO_8859_9, &single_byte::ISO_8859_9_ENCODE);
+    single_byte_encode_is_inverse("iso-8859-11", &single_byte::ISO_8859_11, &single_byte::ISO_8859_11_ENCODE);
+}
+
+#[test]
+fn jis0208_table_matches_whatwg() {
+    let table: Vec<u32> = (0_usize..jis0208::JIS0208.len()).map(jis0208::code_point).collect();
+
+    for (pointer, &code) in table.iter().enumerate() {
+        let (lead, trail): (usize, usize) = (pointer / 188_usize, pointer % 188_usize);
+        let bytes: [u8; 2_usize] = [(lead + if lead < 0x1F { 0x81 } else { 0xC1 }) as u8, (trail + if trail < 0x3F { 0x40 } else { 0x41 }) as u8];
+
+        // Shift_JIS decodes 8836..=10715 as user-defined characters, which the index leaves unmapped.
+        let expected: u32 = if (8836_usize..=10715_usize).contains(&pointer) { 0_u32 } else { decoded(SHIFT_JIS, &bytes) };
+
+        assert_eq!(code, expected, "jis0208: pointer {}", pointer);
+    }
+
+    let (pointers, shift_jis_pointers): (HashMap<u32, usize>, HashMap<u32, usize>) = (
+        first_pointers(&table, |_| false),
+        first_pointers(&table, |pointer| (8272_usize..=8835_usize).contains(&pointer))
+    );
+
+    for &code in table.iter().filter(|&&code| code != 0_u32) {
+        assert_eq!(jis0208::pointer(code), pointers.get(&code).copied(), "jis0208: U+{:04X}", code);
+        assert_eq!(jis0208::shift_jis_pointer(code), shift_jis_pointers.get(&code).copied(), "shift_jis: U+{:04X}", code);
+    }
+
+    // ISO-2022-JP encodes halfwidth katakana as their fullwidth forms.
+    for code in 0xFF61_u32..=0xFF9F_u32 {
+        let text: String = char::from_u32(code).unwrap().to_string();
+        let (bytes, _, _) = ISO_2022_JP.encode(&text);
+
+        assert_eq!(jis0208::katakana(code), decoded(ISO_2022_JP, &bytes), "katakana: U+{:04X}", code);
+    }
+}
+
+#[test]
+fn jis0212_table_matches_whatwg() {
+    for pointer in 0_usize..jis0212::JIS0212.len() {
+        let bytes: [u8; 3_usize] = [0x8F, (pointer / 94_usize + 0xA1) as u8, (pointer % 94_usize + 0xA1) as u8];
+
+        assert_eq!(jis0212::code_point(pointer), decoded(EUC_JP, &bytes), "jis0212: pointer {}", pointer);
+    }
+}
+
+#[test]
+fn gb18030_tables_match_whatwg() {
+    let table: Vec<u32> = (0_usize..gb18030::GB18030.len()).map(gb18030::code_point).collect();
+
+    for (pointer, &code) in table.iter().enumerate() {
+        let (lead, trail): (usize, usize) = (pointer / 190_usize, pointer % 190_usize);
+        let bytes: [u8; 2_usize] = [(lead + 0x81) as u8, (trail + if trail < 0x3F { 0x40 } else { 0x41 }) as u8];
+
+        assert_eq!(code, decoded(GB18030, &bytes), "gb18030: pointer {}", pointer);
+    }
+
+    let pointers: HashMap<u32, usize> = first_pointers(&table, |_| false);
+
+    for &code in table.iter().filter(|&&code| code != 0_u32) {
+        let pointer: usize = gb18030::pointer(code).unwrap_or_else(|| panic!("gb18030: U+{:04X} has no pointer", code));
+
+        assert_eq!(table[pointer], code, "gb18030: U+{:04X}", code);
+
+        // The 18 PUA code points that moved in GB18030-2005 encode through their new two-byte pointers.
+        if !gb18030::GB18030_PUA_ENCODE.iter().any(|&(pua, _)| pua as u32 == code) {
+            assert_eq!(Some(&pointer), pointers.get(&code), "gb18030: U+{:04X}", code);
+        }
+    }
+
+    for pointer in (0_usize..=39419_usize).chain(189000_usize..=1237575_usize).step_by(7_usize) {
+        let bytes: [u8; 4_usize] = [(pointer / 12600_usize + 0x81) as u8, (pointer / 1260_usize % 10_usize + 0x30) as u8, (pointer / 10_usize % 126_usize + 0x81) as u8, (pointer % 10_usize + 0x30) as u8];
+
+        let code: u32 = gb18030::ranges_code_point(pointer);
+
+        assert_eq!(code, decoded(GB18030, &bytes), "gb18030 ranges: pointer {}", pointer);
+
+        if code != 0_u32 { assert_eq!(gb18030::ranges_pointer(code), pointer, "gb18030 ranges: U+{:04X}", code); }
+    }
+}
+
+#[test]
+fn big5_table_matches_whatwg() {
+    let table: Vec<u32> = (0_usize..big5::BIG5.len()).map(big5::code_point).collect();
+
+    for (pointer, &code) in table.iter().enumerate() {
+        let (lead, trail): (usize, usize) = (pointer / 157_usize, pointer % 157_usize);
+        let bytes: [u8; 2_usize] = [(lead + 0x81) as u8, (trail + if trail < 0x3F { 0x40 } else { 0x62 }) as u8];
+
+        // These four pointers decode to a base letter followed by a combining mark, which the decoder emits itself.
+        let expected: u32 = if [1133_usize, 1135_usize, 1164_usize, 1166_usize].contains(&pointer) { 0_u32 } else { decoded(BIG5, &bytes) };
+
+        assert_eq!(code, expected, "big5: pointer {}", pointer);
+    }
+
+    let pointers: HashMap<u32, usize> = first_pointers(&table, |pointer| pointer < 5024_usize);
+
+    for &code in table.iter().filter(|&&code| code != 0_u32) {
+        let expected: Option<usize> = match code {
+            0x2550 | 0x255E | 0x2561 | 0x256A | 0x5341 | 0x5345 => (0_usize..table.len()).rev().find(|&pointer| table[pointer] == code),
+            _ => pointers.get(&code).copied()
+        };
+
+        assert_eq!(big5::pointer(code), expected, "big5: U+{:04X}", code);
+    }
+}
+
+#[test]
+fn euc_kr_table_matches_whatwg() {
+    let table: Vec<u32> = (0_usize..euc_kr::EUC_KR.len()).map(euc_kr::code_point).collect();
+
+    for (pointer, &code) in table.iter().enumerate() {
+        let bytes: [u8; 2_usize] = [(pointer / 190_usize + 0x81) as u8, (pointer % 190_usize + 0x41) as u8];
+
+        assert_eq!(code, decoded(EUC_KR, &bytes), "euc-kr: pointer {}", pointer);
+    }
+
+    let pointers: HashMap<u32, usize> = first_pointers(&table, |_| false);
+
+    for &code in table.iter().filter(|&&code| code != 0_u32) {
+        assert_eq!(euc_kr::pointer(code), pointers.get(&code).copied(), "euc-kr: U+{:04X}", code);
+    }
+}
+
+#[test]
+fn ebcdic_tables_are_bijective() {
+    let tables: [(&str, &[u16; 256_usize], &[u8; 256_usize]); 4_usize] = [
+        ("IBM037", &ebcdic::IBM037, &ebcdic::IBM037_ENCODE),
+        ("IBM500", &ebcdic::IBM500, &ebcdic::IBM500_ENCODE),
+        ("IBM1047", &ebcdic::IBM1047, &ebcdic::IBM1047_ENCODE),
+        ("IBM1140", &ebcdic::IBM1140, &ebcdic::IBM1140_ENCODE)
+    ];
+
+    for (name, table, encode) in tables {
+        for (byte, &code) in table.iter().enumerate() {
+            // Every codepage covers Latin-1 exactly once, except IBM1140 which trades U+00A4 for U+20AC.
+            if code < 0x0100 { assert_eq!(encode[code as usize] as usize, byte, "{}: U+{:04X}", name, code); }
+        }
+
+        assert_eq!(table.iter().filter(|&&code| code < 0x0100).count(), if name == "IBM1140" { 255_usize } else { 256_usize }, "{}", name);
+    }
+
+    // IBM1047 differs from IBM037 only in the brackets, the caret, the not sign and their neighbours.
+    let differences: Vec<usize> = (0_usize..256_usize).filter(|&byte| ebcdic::IBM037[byte] != ebcdic::IBM1047[byte]).collect();
+
+    assert_eq!(differences, [0x5F_usize, 0xAD_usize, 0xB0_usize, 0xBA_usize, 0xBB_usize, 0xBD_usize]);
+
+    // IBM1140 is IBM037 with the euro sign in place of the currency sign.
+    assert!((0_usize..256_usize).all(|byte| ebcdic::IBM1140[byte] == if byte == 0x9F { 0x20AC } else { ebcdic::IBM037[byte] }));
+
+    // 0x15 is NEL and 0x25 is LF on every codepage, as in IBM's CDRA tables.
+    assert!(tables.iter().all(|&(_, table, _)| table[0x15] == 0x0085 && table[0x25] == 0x000A));
+}
+
+#[test]
+fn case_folding_table_is_sorted_and_idempotent() {
+    for window in SIMPLE_CASE_FOLDING.windows(2_usize) {
+        assert!(window[0_usize].0 < window[1_usize].0, "unsorted entry U+{:04X}", window[1_usize].0);
+    }
+
+    for &(from, to) in SIMPLE_CASE_FOLDING.iter() {
+        assert_ne!(from, to, "identity entry U+{:04X}", from);
+        assert_eq!(fold(to), to, "U+{:04X} folds to U+{:04X}, which folds again", from, to);
+    }
+}
+
+#[test]
+fn case_folding_pairs_fold_together() {
+    for code in 0_u32..=0x10FFFF_u32 {
+        // U+0130 and U+0131 only fold together with i/I under the Turkic (T) rows.
+        if code == 0x0130_u32 || code == 0x0131_u32 { continue; }
+
+        let character: char = match char::from_u32(code) { Some(character) => character, None => continue };
+
+        let (mut lower, mut upper) = (character.to_lowercase(), character.to_uppercase());
+
+        for other in [(lower.next(), lower.next()), (upper.next(), upper.next())] {
+            let other: u32 = match other { (Some(other), None) => other as u32, _ => continue };
+
+            // Only pairs the table knows about: std may ship a newer Unicode version.
+            if fold(code) == code && fold(other) == other { continue; }
+
+            assert_eq!(fold(code), fold(other), "U+{:04X} and U+{:04X} fold apart", code, other);
+        }
+    }
+}
+
+#[test]
+fn case_folding_cherokee() {
+    for code in 0x13A0_u32..=0x13EF_u32 {
+        assert_eq!(fold(code), code);
+        assert_eq!(fold(code + 0x97D0_u32), code);
+    }
+
+    for code in 0x13F0_u32..=0x13F5_u32 {
+        assert_eq!(fold(code), code);
+        assert_eq!(fold(code + 0x08_u32), code);
+    }
+}
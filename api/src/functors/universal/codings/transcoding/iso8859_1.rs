@@ -0,0 +1,175 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        ISO8859_1,
+        UTF8,
+        UTF16
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+impl ISO8859_1 {
+    pub(crate) fn decode_utf8_from(array: &[u8], mut read: usize, stop: usize, output: &mut [u8], mut written: usize) -> (usize, usize) {
+        let capacity: usize = output.len();
+
+        while read < stop {
+            let code: u8 = array[read];
+
+            if code < 0x80 {
+                if written >= capacity { break; }
+
+                output[written] = code;
+
+                written += 1_usize;
+            } else {
+                if written + 1_usize >= capacity { break; }
+
+                output[written] = 0xC0 | (code >> 6);
+                output[written + 1_usize] = 0x80 | (code & 0x3F);
+
+                written += 2_usize;
+            }
+
+            read += 1_usize;
+        }
+
+        return (read, written);
+    }
+
+    pub(crate) fn decode_utf16_from(array: &[u8], mut read: usize, stop: usize, output: &mut [u16], mut written: usize, endian: bool) -> (usize, usize) {
+        let capacity: usize = output.len();
+
+        while read < stop && written < capacity {
+            output[written] = UTF16::store_code_unit(array[read] as u16, endian);
+
+            read += 1_usize; written += 1_usize;
+        }
+
+        return (read, written);
+    }
+
+    pub(crate) fn encode_utf8_from(array: &[u8], mut read: usize, stop: usize, output: &mut [u8], mut written: usize) -> Result<(usize, usize), EncodingError> {
+        let capacity: usize = output.len();
+
+        while read < stop && written < capacity {
+            let (code, sequence): (u32, usize) = UTF8::read_sequence(array, read, 0_usize)?;
+
+            if code > 0xFF { return Err(EncodingError::new(read, EncodingErrorKind::Unmappable)); }
+
+            output[written] = code as u8;
+
+            read += sequence; written += 1_usize;
+        }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn encode_utf16_from(array: &[u8], mut read: usize, stop: usize, output: &mut [u8], mut written: usize, endian: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 2_usize, output.len());
+
+        while read < stop && written < capacity {
+            let (code, sequence): (u32, usize) = UTF16::read_code_point(array, read, length, 0_usize, endian)?;
+
+            if code > 0xFF { return Err(EncodingError::new(read, EncodingErrorKind::Unmappable)); }
+
+            output[written] = code as u8;
+
+            read += sequence; written += 1_usize;
+        }
+
+        if read == length && length != array.len() { return Err(EncodingError::new(length, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok((read, written));
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn to_utf8_into(array: &[u8], output: &mut [u8]) -> (usize, usize) {
+        return ISO8859_1::decode_utf8_from(array, 0_usize, array.len(), output, 0_usize);
+    }
+
+    pub fn to_utf8(array: &[u8]) -> Vec<u8> {
+        let mut output: Vec<u8> = vec![0_u8; array.len() * 2_usize];
+
+        let (_, written): (usize, usize) = ISO8859_1::to_utf8_into(array, &mut output);
+
+        output.truncate(written);
+
+        return output;
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn to_utf16_into(array: &[u8], output: &mut [u16], endian: bool) -> (usize, usize) {
+        return ISO8859_1::decode_utf16_from(array, 0_usize, array.len(), output, 0_usize, endian);
+    }
+
+    pub fn to_utf16(array: &[u8], endian: bool) -> Vec<u16> {
+        let mut output: Vec<u16> = vec![0_u16; array.len()];
+
+        ISO8859_1::to_utf16_into(array, &mut output, endian);
+
+        return output;
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn from_utf8_into(array: &[u8], output: &mut [u8]) -> Result<(usize, usize), EncodingError> {
+        return ISO8859_1::encode_utf8_from(array, 0_usize, array.len(), output, 0_usize);
+    }
+
+    pub fn from_utf8(array: &[u8]) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len()];
+
+        let (_, written): (usize, usize) = ISO8859_1::from_utf8_into(array, &mut output)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn from_utf16_into(array: &[u8], output: &mut [u8], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return ISO8859_1::encode_utf16_from(array, 0_usize, array.len() - array.len() % 2_usize, output, 0_usize, endian);
+    }
+
+    pub fn from_utf16(array: &[u8], endian: bool) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len() / 2_usize];
+
+        let (_, written): (usize, usize) = ISO8859_1::from_utf16_into(array, &mut output, endian)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+}
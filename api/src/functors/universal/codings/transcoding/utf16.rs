@@ -0,0 +1,164 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF8,
+        UTF16,
+        UTF32
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+impl UTF16 {
+    pub(crate) const fn store_code_unit(unit: u16, endian: bool) -> u16 {
+        return if endian { unit.to_le() } else { unit.to_be() };
+    }
+
+    pub(crate) fn write_code_point(code: u32, output: &mut [u16], written: usize, endian: bool) -> usize {
+        if code < 0x10000 {
+            output[written] = UTF16::store_code_unit(code as u16, endian);
+
+            return written + 1_usize;
+        } else {
+            let offset: u32 = code - 0x10000;
+
+            output[written] = UTF16::store_code_unit(0xD800 | (offset >> 10) as u16, endian);
+            output[written + 1_usize] = UTF16::store_code_unit(0xDC00 | (offset & 0x03FF) as u16, endian);
+
+            return written + 2_usize;
+        }
+    }
+
+    pub(crate) const fn read_code_point_unchecked(array: &[u8], index: usize, length: usize, endian: bool) -> (u32, usize) {
+        let code: u16 = UTF16::read_code_unit(array, index, endian);
+
+        if (code & 0xFC00) != 0xD800 || index + 2_usize >= length { return (code as u32, 2_usize); }
+
+        return (0x10000 + ((((code & 0x03FF) as u32) << 10) | (UTF16::read_code_unit(array, index + 2_usize, endian) & 0x03FF) as u32), 4_usize);
+    }
+
+    pub(crate) fn transcode_bmp_utf8_from(array: &[u8], mut read: usize, stop: usize, output: &mut [u8], mut written: usize, endian: bool) -> usize {
+        while read < stop {
+            written = UTF8::write_code_point(UTF16::read_code_unit(array, read, endian) as u32, output, written);
+
+            read += 2_usize;
+        }
+
+        return written;
+    }
+
+    pub(crate) fn transcode_utf8_from(array: &[u8], mut read: usize, stop: usize, output: &mut [u8], mut written: usize, endian: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 2_usize, output.len());
+
+        while read < stop {
+            let (code, sequence): (u32, usize) = UTF16::read_code_point(array, read, length, 0_usize, endian)?;
+
+            if written + UTF8::length_of_code_point(code) > capacity { return Ok((read, written)); }
+
+            written = UTF8::write_code_point(code, output, written);
+
+            read += sequence;
+        }
+
+        if read == length && length != array.len() { return Err(EncodingError::new(length, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn transcode_utf32_from(array: &[u8], mut read: usize, stop: usize, output: &mut [u32], mut written: usize, endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 2_usize, output.len());
+
+        while read < stop && written < capacity {
+            let (code, sequence): (u32, usize) = if checked {
+                UTF16::read_code_point(array, read, length, 0_usize, endian)?
+            } else {
+                UTF16::read_code_point_unchecked(array, read, length, endian)
+            };
+
+            output[written] = UTF32::store_code_point(code, endian);
+
+            read += sequence; written += 1_usize;
+        }
+
+        if checked && read == length && length != array.len() { return Err(EncodingError::new(length, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok((read, written));
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn to_utf8_into(array: &[u8], output: &mut [u8], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return UTF16::transcode_utf8_from(array, 0_usize, array.len() - array.len() % 2_usize, output, 0_usize, endian);
+    }
+
+    pub fn to_utf8(array: &[u8], endian: bool) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len() / 2_usize * 3_usize];
+
+        let (_, written): (usize, usize) = UTF16::to_utf8_into(array, &mut output, endian)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn to_utf32_into(array: &[u8], output: &mut [u32], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return UTF16::transcode_utf32_from(array, 0_usize, array.len() - array.len() % 2_usize, output, 0_usize, endian, true);
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn to_utf32_into_unchecked(array: &[u8], output: &mut [u32], endian: bool) -> (usize, usize) {
+        return UTF16::transcode_utf32_from(array, 0_usize, array.len() - array.len() % 2_usize, output, 0_usize, endian, false).unwrap_or_default();
+    }
+
+    pub fn to_utf32(array: &[u8], endian: bool) -> Result<Vec<u32>, EncodingError> {
+        let mut output: Vec<u32> = vec![0_u32; array.len() / 2_usize];
+
+        let (_, written): (usize, usize) = UTF16::to_utf32_into(array, &mut output, endian)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn to_utf32_unchecked(array: &[u8], endian: bool) -> Vec<u32> {
+        let mut output: Vec<u32> = vec![0_u32; array.len() / 2_usize];
+
+        let (_, written): (usize, usize) = UTF16::to_utf32_into_unchecked(array, &mut output, endian);
+
+        output.truncate(written);
+
+        return output;
+    }
+}
@@ -0,0 +1,150 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF8,
+        UTF16,
+        UTF32
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+impl UTF32 {
+    pub(crate) const fn read_code_unit(array: &[u8], index: usize, endian: bool) -> u32 {
+        let bytes: [u8; 4_usize] = [array[index], array[index + 1_usize], array[index + 2_usize], array[index + 3_usize]];
+
+        return if endian { u32::from_le_bytes(bytes) } else { u32::from_be_bytes(bytes) };
+    }
+
+    pub(crate) const fn store_code_point(code: u32, endian: bool) -> u32 {
+        return if endian { code.to_le() } else { code.to_be() };
+    }
+
+    pub(crate) fn transcode_utf8_from(array: &[u8], mut read: usize, stop: usize, output: &mut [u8], mut written: usize, endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 4_usize, output.len());
+
+        while read < stop {
+            let code: u32 = if checked { UTF32::read_code_point(array, read, 0_usize, endian)? } else { UTF32::read_code_unit(array, read, endian) };
+
+            if written + UTF8::length_of_code_point(code) > capacity { return Ok((read, written)); }
+
+            written = UTF8::write_code_point(code, output, written);
+
+            read += 4_usize;
+        }
+
+        if checked && read == length && length != array.len() { return Err(EncodingError::new(length, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn transcode_utf16_from(array: &[u8], mut read: usize, stop: usize, output: &mut [u16], mut written: usize, endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 4_usize, output.len());
+
+        while read < stop && written < capacity {
+            let code: u32 = if checked { UTF32::read_code_point(array, read, 0_usize, endian)? } else { UTF32::read_code_unit(array, read, endian) };
+
+            if code > 0xFFFF && written + 2_usize > capacity { return Ok((read, written)); }
+
+            written = UTF16::write_code_point(code, output, written, endian);
+
+            read += 4_usize;
+        }
+
+        if checked && read == length && length != array.len() { return Err(EncodingError::new(length, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok((read, written));
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn to_utf8_into(array: &[u8], output: &mut [u8], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return UTF32::transcode_utf8_from(array, 0_usize, array.len() - array.len() % 4_usize, output, 0_usize, endian, true);
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn to_utf8_into_unchecked(array: &[u8], output: &mut [u8], endian: bool) -> (usize, usize) {
+        return UTF32::transcode_utf8_from(array, 0_usize, array.len() - array.len() % 4_usize, output, 0_usize, endian, false).unwrap_or_default();
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn to_utf16_into(array: &[u8], output: &mut [u16], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return UTF32::transcode_utf16_from(array, 0_usize, array.len() - array.len() % 4_usize, output, 0_usize, endian, true);
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn to_utf16_into_unchecked(array: &[u8], output: &mut [u16], endian: bool) -> (usize, usize) {
+        return UTF32::transcode_utf16_from(array, 0_usize, array.len() - array.len() % 4_usize, output, 0_usize, endian, false).unwrap_or_default();
+    }
+
+    pub fn to_utf8(array: &[u8], endian: bool) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len() - array.len() % 4_usize];
+
+        let (_, written): (usize, usize) = UTF32::to_utf8_into(array, &mut output, endian)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn to_utf8_unchecked(array: &[u8], endian: bool) -> Vec<u8> {
+        let mut output: Vec<u8> = vec![0_u8; array.len() - array.len() % 4_usize];
+
+        let (_, written): (usize, usize) = UTF32::to_utf8_into_unchecked(array, &mut output, endian);
+
+        output.truncate(written);
+
+        return output;
+    }
+
+    pub fn to_utf16(array: &[u8], endian: bool) -> Result<Vec<u16>, EncodingError> {
+        let mut output: Vec<u16> = vec![0_u16; array.len() / 4_usize * 2_usize];
+
+        let (_, written): (usize, usize) = UTF32::to_utf16_into(array, &mut output, endian)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn to_utf16_unchecked(array: &[u8], endian: bool) -> Vec<u16> {
+        let mut output: Vec<u16> = vec![0_u16; array.len() / 4_usize * 2_usize];
+
+        let (_, written): (usize, usize) = UTF32::to_utf16_into_unchecked(array, &mut output, endian);
+
+        output.truncate(written);
+
+        return output;
+    }
+}
@@ -0,0 +1,173 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF8,
+        UTF16,
+        UTF32
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+impl UTF8 {
+    pub(crate) const fn length_of_code_point(code: u32) -> usize {
+        return if code < 0x80 { 1_usize } else if code < 0x800 { 2_usize } else if code < 0x10000 { 3_usize } else { 4_usize };
+    }
+
+    pub(crate) fn write_code_point(code: u32, output: &mut [u8], written: usize) -> usize {
+        if code < 0x80 {
+            output[written] = code as u8;
+
+            return written + 1_usize;
+        } else if code < 0x800 {
+            output[written] = 0xC0 | (code >> 6) as u8;
+            output[written + 1_usize] = 0x80 | (code & 0x3F) as u8;
+
+            return written + 2_usize;
+        } else if code < 0x10000 {
+            output[written] = 0xE0 | (code >> 12) as u8;
+            output[written + 1_usize] = 0x80 | ((code >> 6) & 0x3F) as u8;
+            output[written + 2_usize] = 0x80 | (code & 0x3F) as u8;
+
+            return written + 3_usize;
+        } else {
+            output[written] = 0xF0 | (code >> 18) as u8;
+            output[written + 1_usize] = 0x80 | ((code >> 12) & 0x3F) as u8;
+            output[written + 2_usize] = 0x80 | ((code >> 6) & 0x3F) as u8;
+            output[written + 3_usize] = 0x80 | (code & 0x3F) as u8;
+
+            return written + 4_usize;
+        }
+    }
+
+    pub(crate) const fn read_sequence_unchecked(array: &[u8], index: usize) -> (u32, usize) {
+        let lead: u8 = array[index];
+
+        let following: usize = if lead < 0xC0 { 0_usize } else if lead < 0xE0 { 1_usize } else if lead < 0xF0 { 2_usize } else { 3_usize };
+
+        let following: usize = if index + following < array.len() { following } else { array.len() - index - 1_usize };
+
+        let (mut position, mut code): (usize, u32) = (1_usize, if following == 0_usize { lead as u32 } else { (lead & (0x3F >> following)) as u32 });
+
+        while position <= following { code = (code << 6) | (array[index + position] & 0x3F) as u32; position += 1_usize; }
+
+        return (code, following + 1_usize);
+    }
+
+    pub(crate) fn transcode_utf16_from(array: &[u8], mut read: usize, stop: usize, output: &mut [u16], mut written: usize, endian: bool) -> Result<(usize, usize), EncodingError> {
+        let capacity: usize = output.len();
+
+        while read < stop && written < capacity {
+            let lead: u8 = array[read];
+
+            if lead < 0x80 {
+                output[written] = UTF16::store_code_unit(lead as u16, endian);
+
+                read += 1_usize; written += 1_usize;
+
+                continue;
+            }
+
+            let (code, sequence): (u32, usize) = UTF8::read_sequence(array, read, 0_usize)?;
+
+            if code > 0xFFFF && written + 2_usize > capacity { break; }
+
+            written = UTF16::write_code_point(code, output, written, endian);
+
+            read += sequence;
+        }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn transcode_utf32_from(array: &[u8], mut read: usize, stop: usize, output: &mut [u32], mut written: usize, endian: bool, checked: bool) -> Result<(usize, usize), EncodingError> {
+        let capacity: usize = output.len();
+
+        while read < stop && written < capacity {
+            let (code, sequence): (u32, usize) = if checked { UTF8::read_sequence(array, read, 0_usize)? } else { UTF8::read_sequence_unchecked(array, read) };
+
+            output[written] = UTF32::store_code_point(code, endian);
+
+            read += sequence; written += 1_usize;
+        }
+
+        return Ok((read, written));
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn to_utf16_into(array: &[u8], output: &mut [u16], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return UTF8::transcode_utf16_from(array, 0_usize, array.len(), output, 0_usize, endian);
+    }
+
+    pub fn to_utf16(array: &[u8], endian: bool) -> Result<Vec<u16>, EncodingError> {
+        let mut output: Vec<u16> = vec![0_u16; array.len()];
+
+        let (_, written): (usize, usize) = UTF8::to_utf16_into(array, &mut output, endian)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn to_utf32_into(array: &[u8], output: &mut [u32], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return UTF8::transcode_utf32_from(array, 0_usize, array.len(), output, 0_usize, endian, true);
+    }
+
+    #[cfg(feature = "universal")]
+    pub fn to_utf32_into_unchecked(array: &[u8], output: &mut [u32], endian: bool) -> (usize, usize) {
+        return UTF8::transcode_utf32_from(array, 0_usize, array.len(), output, 0_usize, endian, false).unwrap_or_default();
+    }
+
+    pub fn to_utf32(array: &[u8], endian: bool) -> Result<Vec<u32>, EncodingError> {
+        let mut output: Vec<u32> = vec![0_u32; array.len()];
+
+        let (_, written): (usize, usize) = UTF8::to_utf32_into(array, &mut output, endian)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn to_utf32_unchecked(array: &[u8], endian: bool) -> Vec<u32> {
+        let mut output: Vec<u32> = vec![0_u32; array.len()];
+
+        let (_, written): (usize, usize) = UTF8::to_utf32_into_unchecked(array, &mut output, endian);
+
+        output.truncate(written);
+
+        return output;
+    }
+}
@@ -0,0 +1,260 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF7,
+        Utf7Variant,
+        Utf7State,
+        UTF8,
+        UTF16
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+
+impl UTF7 {
+    fn write_encoded(code: u32, shift: Utf7State, output: &mut [u8], mut written: usize, variant: Utf7Variant) -> Option<(usize, Utf7State)> {
+        let capacity: usize = output.len();
+
+        if code == variant.shift() as u32 || variant.is_encoded_directly(code) {
+            let (reset, size): (usize, usize) = (
+                if shift.active { if shift.bits != 0_u32 { 2_usize } else { 1_usize } } else { 0_usize },
+                if code == variant.shift() as u32 { 2_usize } else { 1_usize }
+            );
+
+            if written + reset + size > capacity { return None; }
+
+            written = UTF7::write_reset(shift, output, written, variant);
+
+            output[written] = code as u8;
+
+            if size == 2_usize { output[written + 1_usize] = 0x2D; }
+
+            return Some((written + size, Utf7State::new()));
+        }
+
+        let (units, bits): (u64, u32) = if code > 0xFFFF {
+            let offset: u32 = code - 0x10000;
+
+            ((((0xD800 | (offset >> 10)) as u64) << 16) | (0xDC00 | (offset & 0x03FF)) as u64, 32_u32)
+        } else {
+            (code as u64, 16_u32)
+        };
+
+        let total: u32 = shift.bits + bits;
+
+        let needed: usize = if shift.active { 0_usize } else { 1_usize } + (total / 6_u32) as usize + if total % 6_u32 != 0_u32 { 1_usize } else { 0_usize } + 1_usize;
+
+        if written + needed > capacity { return None; }
+
+        if !shift.active { output[written] = variant.shift(); written += 1_usize; }
+
+        let (buffer, mut count): (u64, u32) = (((shift.buffer as u64) << bits) | units, total);
+
+        while count >= 6_u32 {
+            count -= 6_u32;
+
+            output[written] = variant.encode_digit(((buffer >> count) & 0x3F) as u32);
+
+            written += 1_usize;
+        }
+
+        return Some((written, Utf7State { active: true, buffer: (buffer & ((1_u64 << count) - 1_u64)) as u32, bits: count }));
+    }
+
+    fn write_reset(shift: Utf7State, output: &mut [u8], mut written: usize, variant: Utf7Variant) -> usize {
+        if !shift.active { return written; }
+
+        if shift.bits != 0_u32 {
+            output[written] = variant.encode_digit((shift.buffer << (6_u32 - shift.bits)) & 0x3F);
+
+            written += 1_usize;
+        }
+
+        output[written] = 0x2D;
+
+        return written + 1_usize;
+    }
+
+    pub(crate) fn decode_utf8_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize, state: Option<Utf7State>, variant: Utf7Variant) -> Result<(usize, usize, Utf7State), EncodingError> {
+        let (length, capacity, mut shift, mut boundary): (usize, usize, Utf7State, (usize, usize)) = (array.len(), output.len(), state.unwrap_or_default(), (read, written));
+
+        while read < length || shift.active {
+            if !shift.active { boundary = (read, written); }
+
+            let (code, sequence, next): (Option<u32>, usize, Utf7State) = UTF7::read_sequence(array, read, shift, variant)?;
+
+            if let Some(code) = code {
+                // A full output stops right here and hands the shift state back, or, when the caller keeps no state, rewinds to the last '+'.
+                if written + UTF8::length_of_code_point(code) > capacity {
+                    return Ok(if state.is_none() && shift.active { (boundary.0, boundary.1, Utf7State::new()) } else { (read, written, shift) });
+                }
+
+                written = UTF8::write_code_point(code, output, written);
+            }
+
+            shift = next; read += sequence;
+        }
+
+        return Ok((read, written, shift));
+    }
+
+    pub(crate) fn decode_utf16_from(array: &[u8], mut read: usize, output: &mut [u16], mut written: usize, state: Option<Utf7State>, endian: bool, variant: Utf7Variant) -> Result<(usize, usize, Utf7State), EncodingError> {
+        let (length, capacity, mut shift, mut boundary): (usize, usize, Utf7State, (usize, usize)) = (array.len(), output.len(), state.unwrap_or_default(), (read, written));
+
+        while read < length || shift.active {
+            if !shift.active { boundary = (read, written); }
+
+            let (code, sequence, next): (Option<u32>, usize, Utf7State) = UTF7::read_sequence(array, read, shift, variant)?;
+
+            if let Some(code) = code {
+                // Stops or rewinds, as decode_utf8_from does.
+                if written + if code > 0xFFFF { 2_usize } else { 1_usize } > capacity {
+                    return Ok(if state.is_none() && shift.active { (boundary.0, boundary.1, Utf7State::new()) } else { (read, written, shift) });
+                }
+
+                written = UTF16::write_code_point(code, output, written, endian);
+            }
+
+            shift = next; read += sequence;
+        }
+
+        return Ok((read, written, shift));
+    }
+
+    pub(crate) fn encode_utf8_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize, variant: Utf7Variant) -> Result<(usize, usize), EncodingError> {
+        let (length, mut shift): (usize, Utf7State) = (array.len(), Utf7State::new());
+
+        while read < length {
+            let (code, sequence): (u32, usize) = UTF8::read_sequence(array, read, 0_usize)?;
+
+            (written, shift) = match UTF7::write_encoded(code, shift, output, written, variant) {
+                Some(encoded) => encoded,
+                None => break
+            };
+
+            read += sequence;
+        }
+
+        return Ok((read, UTF7::write_reset(shift, output, written, variant)));
+    }
+
+    pub(crate) fn encode_utf16_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize, endian: bool, variant: Utf7Variant) -> Result<(usize, usize), EncodingError> {
+        let (length, mut shift): (usize, Utf7State) = (array.len() - array.len() % 2_usize, Utf7State::new());
+
+        while read < length {
+            let (code, sequence): (u32, usize) = UTF16::read_code_point(array, read, length, 0_usize, endian)?;
+
+            (written, shift) = match UTF7::write_encoded(code, shift, output, written, variant) {
+                Some(encoded) => encoded,
+                None => break
+            };
+
+            read += sequence;
+        }
+
+        if read == length && length != array.len() { return Err(EncodingError::new(length, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok((read, UTF7::write_reset(shift, output, written, variant)));
+    }
+
+    pub fn to_utf8_into(array: &[u8], output: &mut [u8], variant: Utf7Variant) -> Result<(usize, usize), EncodingError> {
+        let (read, written, _): (usize, usize, Utf7State) = UTF7::decode_utf8_from(array, 0_usize, output, 0_usize, None, variant)?;
+
+        return Ok((read, written));
+    }
+
+    pub fn to_utf8_into_resumable(array: &[u8], output: &mut [u8], variant: Utf7Variant, state: Utf7State) -> Result<(usize, usize, Utf7State), EncodingError> {
+        return UTF7::decode_utf8_from(array, 0_usize, output, 0_usize, Some(state), variant);
+    }
+
+    pub fn to_utf8(array: &[u8], variant: Utf7Variant) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len() * 2_usize];
+
+        let (_, written): (usize, usize) = UTF7::to_utf8_into(array, &mut output, variant)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn to_utf16_into(array: &[u8], output: &mut [u16], endian: bool, variant: Utf7Variant) -> Result<(usize, usize), EncodingError> {
+        let (read, written, _): (usize, usize, Utf7State) = UTF7::decode_utf16_from(array, 0_usize, output, 0_usize, None, endian, variant)?;
+
+        return Ok((read, written));
+    }
+
+    pub fn to_utf16_into_resumable(array: &[u8], output: &mut [u16], endian: bool, variant: Utf7Variant, state: Utf7State) -> Result<(usize, usize, Utf7State), EncodingError> {
+        return UTF7::decode_utf16_from(array, 0_usize, output, 0_usize, Some(state), endian, variant);
+    }
+
+    pub fn to_utf16(array: &[u8], endian: bool, variant: Utf7Variant) -> Result<Vec<u16>, EncodingError> {
+        let mut output: Vec<u16> = vec![0_u16; array.len()];
+
+        let (_, written): (usize, usize) = UTF7::to_utf16_into(array, &mut output, endian, variant)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn from_utf8_into(array: &[u8], output: &mut [u8], variant: Utf7Variant) -> Result<(usize, usize), EncodingError> {
+        return UTF7::encode_utf8_from(array, 0_usize, output, 0_usize, variant);
+    }
+
+    pub fn from_utf8(array: &[u8], variant: Utf7Variant) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len() * 5_usize];
+
+        let (_, written): (usize, usize) = UTF7::from_utf8_into(array, &mut output, variant)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn from_utf16_into(array: &[u8], output: &mut [u8], endian: bool, variant: Utf7Variant) -> Result<(usize, usize), EncodingError> {
+        return UTF7::encode_utf16_from(array, 0_usize, output, 0_usize, endian, variant);
+    }
+
+    pub fn from_utf16(array: &[u8], endian: bool, variant: Utf7Variant) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len() / 2_usize * 5_usize];
+
+        let (_, written): (usize, usize) = UTF7::from_utf16_into(array, &mut output, endian, variant)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+}
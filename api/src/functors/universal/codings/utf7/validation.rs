@@ -0,0 +1,146 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF7,
+        Utf7Variant,
+        Utf7State
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+impl Utf7State {
+    pub const fn new() -> Utf7State {
+        return Utf7State { active: false, buffer: 0_u32, bits: 0_u32 };
+    }
+
+    pub const fn is_shifted(&self) -> bool {
+        return self.active;
+    }
+}
+
+impl Default for Utf7State {
+    fn default() -> Utf7State {
+        return Utf7State::new();
+    }
+}
+
+impl UTF7 {
+    pub(crate) const fn read_sequence(array: &[u8], mut index: usize, mut shift: Utf7State, variant: Utf7Variant) -> Result<(Option<u32>, usize, Utf7State), EncodingError> {
+        let length: usize = array.len();
+
+        if !shift.active {
+            let byte: u8 = array[index];
+
+            if byte == variant.shift() {
+                if index + 1_usize >= length { return Err(EncodingError::new(index, EncodingErrorKind::TruncatedSequence)); }
+                else if array[index + 1_usize] == 0x2D { return Ok((Some(byte as u32), 2_usize, shift)); }
+                else if variant.decode_digit(array[index + 1_usize]) == 0xFF { return Err(EncodingError::new(index, EncodingErrorKind::InvalidSequence)); }
+
+                return Ok((None, 1_usize, Utf7State { active: true, buffer: 0_u32, bits: 0_u32 }));
+            }
+
+            if !variant.is_direct(byte) { return Err(EncodingError::new(index, EncodingErrorKind::InvalidSequence)); }
+
+            return Ok((Some(byte as u32), 1_usize, shift));
+        }
+
+        let (start, mut lead): (usize, u32) = (index, 0_u32);
+
+        while index < length {
+            let digit: u8 = variant.decode_digit(array[index]);
+
+            if digit == 0xFF { break; }
+
+            shift.buffer = (shift.buffer << 6) | digit as u32; shift.bits += 6_u32; index += 1_usize;
+
+            if shift.bits < 16_u32 { continue; }
+
+            shift.bits -= 16_u32;
+
+            let unit: u32 = (shift.buffer >> shift.bits) & 0xFFFF;
+
+            shift.buffer &= (1_u32 << shift.bits) - 1_u32;
+
+            if lead != 0_u32 {
+                if (unit & 0xFC00) != 0xDC00 { return Err(EncodingError::new(index - 1_usize, EncodingErrorKind::UnpairedLead)); }
+
+                return Ok((Some(0x10000 + (((lead & 0x03FF) << 10) | (unit & 0x03FF))), index - start, shift));
+            }
+
+            if (unit & 0xFC00) == 0xD800 { lead = unit; continue; }
+            else if (unit & 0xFC00) == 0xDC00 { return Err(EncodingError::new(index - 1_usize, EncodingErrorKind::UnpairedTrail)); }
+            else if matches!(variant, Utf7Variant::Imap) && unit >= 0x20 && unit <= 0x7E { return Err(EncodingError::new(index - 1_usize, EncodingErrorKind::InvalidSequence)); }
+
+            return Ok((Some(unit), index - start, shift));
+        }
+
+        if lead != 0_u32 { return Err(EncodingError::new(index, EncodingErrorKind::UnpairedLead)); }
+        else if shift.bits >= 6_u32 || shift.buffer != 0_u32 { return Err(EncodingError::new(index, EncodingErrorKind::InvalidSequence)); }
+
+        let terminated: bool = index < length && array[index] == 0x2D;
+
+        if matches!(variant, Utf7Variant::Imap) && !terminated {
+            return Err(EncodingError::new(index, if index >= length { EncodingErrorKind::TruncatedSequence } else { EncodingErrorKind::InvalidSequence }));
+        }
+
+        return Ok((None, index - start + if terminated { 1_usize } else { 0_usize }, Utf7State::new()));
+    }
+
+    pub(crate) const fn locate_invalid_utf7(array: &[u8], mut index: usize, variant: Utf7Variant) -> Result<(), EncodingError> {
+        let (length, mut shift): (usize, Utf7State) = (array.len(), Utf7State::new());
+
+        while index < length || shift.active {
+            match UTF7::read_sequence(array, index, shift, variant) {
+                Ok((_, sequence, next)) => { index += sequence; shift = next; },
+                Err(error) => return Err(error)
+            }
+        }
+
+        return Ok(());
+    }
+
+    pub const fn is_utf7(array: &[u8], variant: Utf7Variant) -> bool {
+        if array.is_empty() { return false; }
+
+        return UTF7::locate_invalid_utf7(array, 0_usize, variant).is_ok();
+    }
+
+    pub const fn validate_utf7(array: &[u8], variant: Utf7Variant) -> Result<(), EncodingError> {
+        if array.is_empty() { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        return UTF7::locate_invalid_utf7(array, 0_usize, variant);
+    }
+}
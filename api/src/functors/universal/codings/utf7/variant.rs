@@ -0,0 +1,107 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use core::{
+    fmt::{
+        Display,
+        Formatter,
+        Result
+    }
+};
+
+pub use crate::{
+    essence::{
+        Utf7Variant
+    }
+};
+
+impl Utf7Variant {
+    pub const fn as_str(&self) -> &'static str {
+        return match self {
+            Utf7Variant::Standard => "utf-7",
+            Utf7Variant::Imap => "imap-utf-7"
+        };
+    }
+
+    pub fn from_label(label: &str) -> Option<Utf7Variant> {
+        return match label.trim().to_ascii_lowercase().as_str() {
+            "utf-7" | "utf7" | "unicode-1-1-utf-7" | "csunicode11utf7" => Some(Utf7Variant::Standard),
+            "imap-utf-7" | "imap-utf7" | "imap" | "utf-7-imap" | "x-imap4-modified-utf7" => Some(Utf7Variant::Imap),
+            _ => None
+        };
+    }
+
+    pub(crate) const fn shift(&self) -> u8 {
+        return match self {
+            Utf7Variant::Standard => 0x2B, // +
+            Utf7Variant::Imap => 0x26 // &
+        };
+    }
+
+    pub(crate) const fn decode_digit(&self, byte: u8) -> u8 {
+        return match byte {
+            0x41..=0x5A => byte - 0x41,
+            0x61..=0x7A => byte - 0x61 + 26,
+            0x30..=0x39 => byte - 0x30 + 52,
+            0x2B => 62,
+            0x2F => if matches!(self, Utf7Variant::Standard) { 63 } else { 0xFF },
+            0x2C => if matches!(self, Utf7Variant::Imap) { 63 } else { 0xFF },
+            _ => 0xFF
+        };
+    }
+
+    pub(crate) const fn encode_digit(&self, value: u32) -> u8 {
+        return match value {
+            0..=25 => 0x41 + value as u8,
+            26..=51 => 0x61 + (value - 26) as u8,
+            52..=61 => 0x30 + (value - 52) as u8,
+            62 => 0x2B,
+            _ => if matches!(self, Utf7Variant::Standard) { 0x2F } else { 0x2C }
+        };
+    }
+
+    pub(crate) const fn is_direct(&self, byte: u8) -> bool {
+        return match self {
+            Utf7Variant::Standard => (byte >= 0x20 && byte <= 0x7E) || byte == 0x09 || byte == 0x0A || byte == 0x0D,
+            Utf7Variant::Imap => byte >= 0x20 && byte <= 0x7E
+        };
+    }
+
+    pub(crate) const fn is_encoded_directly(&self, code: u32) -> bool {
+        return match self {
+            Utf7Variant::Standard => code < 0x80 && self.is_direct(code as u8) && code != 0x5C && code != 0x7E, // \ ~
+            Utf7Variant::Imap => code < 0x80 && self.is_direct(code as u8)
+        };
+    }
+}
+
+impl Display for Utf7Variant {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        return formatter.write_str(self.as_str());
+    }
+}
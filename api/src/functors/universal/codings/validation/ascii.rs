@@ -38,6 +38,13 @@ pub use crate::{
     }
 };
 
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
 impl ASCII {
     const fn is_not_ascii(code: u128) -> bool {
         return if (code & 0x80808080808080808080808080808080) != 0 { true } else { false };
@@ -78,4 +85,10 @@ impl ASCII {
 
         return result;
     }
+
+    pub const fn validate_ascii_from_byte_array(array: &[u8]) -> Result<(), EncodingError> {
+        if array.is_empty() { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        return ASCII::locate_invalid_ascii(array, 0_usize);
+    }
 }
@@ -38,6 +38,13 @@ pub use crate::{
     }
 };
 
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
 impl UTF16 {
     const __ENCODING_REGULAR_PAIR_BYTES:   usize = 2_usize;
     const __ENCODING_SURROGATE_PAIR_BYTES: usize = 4_usize;
@@ -47,7 +54,7 @@ impl UTF16 {
     }
 
     const fn is_not_lead_part_of_surrogate(code: u16) -> bool {
-        return if code < 0xD800 && code > 0xDBFF { true } else { false };
+        return if code < 0xD800 || code > 0xDBFF { true } else { false };
     }
 
     const fn is_part_of_surrogate(code: u16) -> bool {
@@ -212,4 +219,34 @@ impl UTF16 {
 
         return UTF16::is_utf16(unsafe { core::slice::from_raw_parts::<u16>(transmute::<*const u8, *const u16>(array.as_ptr()), length / UTF16::__ENCODING_REGULAR_PAIR_BYTES) }, endian, omp, only);
     }
+
+    pub(crate) const fn surrogate_free_prefix(array: &[u8], endian: bool) -> usize {
+        let mut index: usize = 0_usize;
+
+        while index + 2_usize <= array.len() {
+            if (array[if endian { index + 1_usize } else { index }] & 0xF8) == 0xD8 { break; }
+
+            index += 2_usize;
+        }
+
+        return index;
+    }
+
+    pub const fn validate_utf16_from_byte_array(array: &[u8], endian: bool, omp: bool, only: bool) -> Result<(), EncodingError> {
+        if array.is_empty() { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        return UTF16::locate_invalid_utf16(array, 0_usize, endian, omp, only);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UTF16;
+
+    #[test]
+    fn not_lead_part_of_surrogate() {
+        for code in 0_u16..=0xFFFF_u16 {
+            assert_eq!(UTF16::is_not_lead_part_of_surrogate(code), !UTF16::is_lead_part_of_surrogate(code), "{:04X}", code);
+        }
+    }
 }
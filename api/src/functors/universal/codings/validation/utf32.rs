@@ -38,6 +38,13 @@ pub use crate::{
     }
 };
 
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
 impl UTF32 {
     const __ENCODING_BYTES: usize = 4_usize;
 
@@ -130,4 +137,10 @@ impl UTF32 {
 
         return result;
     }
+
+    pub const fn validate_utf32_from_byte_array(array: &[u8], endian: bool) -> Result<(), EncodingError> {
+        if array.is_empty() { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        return UTF32::locate_invalid_utf32(array, 0_usize, endian);
+    }
 }
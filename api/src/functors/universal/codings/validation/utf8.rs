@@ -32,20 +32,28 @@ pub use crate::{
     }
 };
 
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
 impl UTF8 {
 
-    const fn is_lead(code: u8) -> bool {
+    pub(crate) const fn is_lead(code: u8) -> bool {
         return if (code & 0x80) == 0x80 { if (code & 0x40) == 0x40 { true } else { false } } else { true };
     }
 
-    const fn is_following(code: u8) -> bool {
+    pub(crate) const fn is_following(code: u8) -> bool {
         return if (code & 0xC0) == 0x80 { true } else { false };
     }
 
-    const fn is_not_following(code: u8) -> bool {
+    pub(crate) const fn is_not_following(code: u8) -> bool {
         return if (code & 0x80) == 0x00 || (code & 0xC0) == 0xC0 { true } else { false };
     }
 
+    #[cfg(feature = "universal")]
     pub const fn is_utf8(array: &[u8]) -> bool {
         let (mut index, length): (usize, usize) = (0_usize, array.len());
 
@@ -84,4 +92,11 @@ impl UTF8 {
 
         return true;
     }
+
+    #[cfg(feature = "universal")]
+    pub const fn validate_utf8(array: &[u8]) -> Result<(), EncodingError> {
+        if array.is_empty() { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        return UTF8::locate_invalid_utf8(array, 0_usize);
+    }
 }
@@ -0,0 +1,85 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        UTF8
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind
+    }
+};
+
+impl UTF8 {
+    pub(crate) const fn read_generalized_sequence(array: &[u8], index: usize) -> Result<(u32, usize), EncodingError> {
+        let length: usize = array.len();
+
+        if array[index] != 0xED { return UTF8::read_sequence(array, index, 0_usize); }
+
+        if index + 1_usize >= length { return Err(EncodingError::new(index, EncodingErrorKind::TruncatedSequence)); }
+        else if UTF8::is_not_following(array[index + 1_usize]) { return Err(EncodingError::new(index, EncodingErrorKind::UnpairedLead)); }
+        else if index + 2_usize >= length { return Err(EncodingError::new(index, EncodingErrorKind::TruncatedSequence)); }
+        else if UTF8::is_not_following(array[index + 2_usize]) { return Err(EncodingError::new(index, EncodingErrorKind::UnpairedLead)); }
+
+        return Ok((0xD000 | (((array[index + 1_usize] & 0x3F) as u32) << 6) | (array[index + 2_usize] & 0x3F) as u32, 3_usize));
+    }
+
+    pub(crate) const fn read_surrogate_pair(array: &[u8], index: usize) -> Result<(u32, usize), EncodingError> {
+        let (lead, sequence): (u32, usize) = match UTF8::read_generalized_sequence(array, index) {
+            Ok(decoded) => decoded,
+            Err(error) => return Err(error)
+        };
+
+        if lead < 0xD800 || lead > 0xDFFF { return Ok((lead, sequence)); }
+        else if lead > 0xDBFF { return Err(EncodingError::new(index, EncodingErrorKind::UnpairedTrail)); }
+        else if index + sequence >= array.len() || array[index + sequence] != 0xED { return Err(EncodingError::new(index, EncodingErrorKind::UnpairedLead)); }
+
+        let (trail, following): (u32, usize) = match UTF8::read_generalized_sequence(array, index + sequence) {
+            Ok(decoded) => decoded,
+            Err(error) => return Err(EncodingError::new(index, error.kind))
+        };
+
+        if trail < 0xDC00 || trail > 0xDFFF { return Err(EncodingError::new(index, EncodingErrorKind::UnpairedLead)); }
+
+        return Ok((0x10000 + (((lead & 0x03FF) << 10) | (trail & 0x03FF)), sequence + following));
+    }
+
+    pub(crate) fn write_surrogate_pair(code: u32, output: &mut [u8], written: usize) -> usize {
+        if code < 0x10000 { return UTF8::write_code_point(code, output, written); }
+
+        let offset: u32 = code - 0x10000;
+
+        let written: usize = UTF8::write_code_point(0xD800 | (offset >> 10), output, written);
+
+        return UTF8::write_code_point(0xDC00 | (offset & 0x03FF), output, written);
+    }
+}
@@ -0,0 +1,226 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        WTF8,
+        UTF8,
+        UTF16
+    }
+};
+
+use crate::{
+    essence::{
+        EncodingError,
+        EncodingErrorKind,
+        Policy
+    }
+};
+
+impl WTF8 {
+    pub(crate) const fn read_sequence(array: &[u8], index: usize) -> Result<(u32, usize), EncodingError> {
+        let (code, sequence): (u32, usize) = match UTF8::read_generalized_sequence(array, index) {
+            Ok(decoded) => decoded,
+            Err(error) => return Err(error)
+        };
+
+        if code >= 0xD800 && code <= 0xDBFF && index + sequence < array.len() && array[index + sequence] == 0xED {
+            if let Ok((trail, _)) = UTF8::read_generalized_sequence(array, index + sequence) {
+                if trail >= 0xDC00 && trail <= 0xDFFF { return Err(EncodingError::new(index, EncodingErrorKind::InvalidSequence)); }
+            }
+        }
+
+        return Ok((code, sequence));
+    }
+
+    pub(crate) const fn read_code_unit_sequence(array: &[u8], index: usize, length: usize, endian: bool) -> (u32, usize) {
+        let code: u16 = UTF16::read_code_unit(array, index, endian);
+
+        if (code & 0xFC00) != 0xD800 || index + 2_usize >= length { return (code as u32, 2_usize); }
+
+        let following: u16 = UTF16::read_code_unit(array, index + 2_usize, endian);
+
+        if (following & 0xFC00) != 0xDC00 { return (code as u32, 2_usize); }
+
+        return (0x10000 + ((((code & 0x03FF) as u32) << 10) | (following & 0x03FF) as u32), 4_usize);
+    }
+
+    pub(crate) const fn locate_invalid_wtf8(array: &[u8], mut index: usize) -> Result<(), EncodingError> {
+        let length: usize = array.len();
+
+        while index < length {
+            match WTF8::read_sequence(array, index) {
+                Ok((_, sequence)) => index += sequence,
+                Err(error) => return Err(error)
+            }
+        }
+
+        return Ok(());
+    }
+
+    pub(crate) fn decode_utf8_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize, policy: Policy) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        while read < length {
+            let (mut code, sequence): (u32, usize) = WTF8::read_sequence(array, read)?;
+
+            if (0xD800..=0xDFFF).contains(&code) {
+                code = match policy {
+                    Policy::Error => return Err(EncodingError::new(read, EncodingErrorKind::Surrogate)),
+                    Policy::Replacement => 0xFFFD
+                };
+            }
+
+            if written + UTF8::length_of_code_point(code) > capacity { break; }
+
+            written = UTF8::write_code_point(code, output, written);
+
+            read += sequence;
+        }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn decode_utf16_from(array: &[u8], mut read: usize, output: &mut [u16], mut written: usize, endian: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        while read < length {
+            let (code, sequence): (u32, usize) = WTF8::read_sequence(array, read)?;
+
+            if written + if code > 0xFFFF { 2_usize } else { 1_usize } > capacity { break; }
+
+            written = UTF16::write_code_point(code, output, written, endian);
+
+            read += sequence;
+        }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn encode_utf8_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len(), output.len());
+
+        while read < length {
+            let (_, sequence): (u32, usize) = UTF8::read_sequence(array, read, 0_usize)?;
+
+            if written + sequence > capacity { break; }
+
+            output[written..written + sequence].copy_from_slice(&array[read..read + sequence]);
+
+            read += sequence; written += sequence;
+        }
+
+        return Ok((read, written));
+    }
+
+    pub(crate) fn encode_utf16_from(array: &[u8], mut read: usize, output: &mut [u8], mut written: usize, endian: bool) -> Result<(usize, usize), EncodingError> {
+        let (length, capacity): (usize, usize) = (array.len() - array.len() % 2_usize, output.len());
+
+        while read < length {
+            let (code, sequence): (u32, usize) = WTF8::read_code_unit_sequence(array, read, length, endian);
+
+            if written + UTF8::length_of_code_point(code) > capacity { break; }
+
+            written = UTF8::write_code_point(code, output, written);
+
+            read += sequence;
+        }
+
+        if read == length && length != array.len() { return Err(EncodingError::new(length, EncodingErrorKind::TruncatedSequence)); }
+
+        return Ok((read, written));
+    }
+
+    pub const fn is_wtf8(array: &[u8]) -> bool {
+        if array.is_empty() { return false; }
+
+        return WTF8::locate_invalid_wtf8(array, 0_usize).is_ok();
+    }
+
+    pub const fn validate_wtf8(array: &[u8]) -> Result<(), EncodingError> {
+        if array.is_empty() { return Err(EncodingError::new(0_usize, EncodingErrorKind::Empty)); }
+
+        return WTF8::locate_invalid_wtf8(array, 0_usize);
+    }
+
+    pub fn to_utf8_into(array: &[u8], output: &mut [u8], policy: Policy) -> Result<(usize, usize), EncodingError> {
+        return WTF8::decode_utf8_from(array, 0_usize, output, 0_usize, policy);
+    }
+
+    pub fn to_utf8(array: &[u8], policy: Policy) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len()];
+
+        let (_, written): (usize, usize) = WTF8::to_utf8_into(array, &mut output, policy)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn to_utf16_into(array: &[u8], output: &mut [u16], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return WTF8::decode_utf16_from(array, 0_usize, output, 0_usize, endian);
+    }
+
+    pub fn to_utf16(array: &[u8], endian: bool) -> Result<Vec<u16>, EncodingError> {
+        let mut output: Vec<u16> = vec![0_u16; array.len()];
+
+        let (_, written): (usize, usize) = WTF8::to_utf16_into(array, &mut output, endian)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn from_utf8_into(array: &[u8], output: &mut [u8]) -> Result<(usize, usize), EncodingError> {
+        return WTF8::encode_utf8_from(array, 0_usize, output, 0_usize);
+    }
+
+    pub fn from_utf8(array: &[u8]) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len()];
+
+        let (_, written): (usize, usize) = WTF8::from_utf8_into(array, &mut output)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+
+    pub fn from_utf16_into(array: &[u8], output: &mut [u8], endian: bool) -> Result<(usize, usize), EncodingError> {
+        return WTF8::encode_utf16_from(array, 0_usize, output, 0_usize, endian);
+    }
+
+    pub fn from_utf16(array: &[u8], endian: bool) -> Result<Vec<u8>, EncodingError> {
+        let mut output: Vec<u8> = vec![0_u8; array.len() / 2_usize * 3_usize];
+
+        let (_, written): (usize, usize) = WTF8::from_utf16_into(array, &mut output, endian)?;
+
+        output.truncate(written);
+
+        return Ok(output);
+    }
+}
@@ -27,6 +27,5 @@
  */
 
 pub mod codings;
-
-#[cfg(feature = "universal")]
+pub mod dispatch;
 pub mod search;
@@ -29,12 +29,17 @@
 use core::{
     mem::{
         transmute
+    },
+    marker::{
+        PhantomData
     }
 };
 
 pub use crate::{
     essence::{
-        ByteSearch
+        ByteSearch,
+        ByteMatches,
+        ByteMatchesRev
     }
 };
 
@@ -69,22 +74,20 @@ impl<T: core::cmp::PartialEq> ByteSearch<T> {
         };
     }
 
-    pub fn search_single(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize> {
-        let mut search_result: Vec<usize> = Vec::<usize>::new();
-
+    pub(crate) fn search_first(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Option<usize> {
         let (array, pattern): (&[T], &[T]) = (
             unsafe { core::slice::from_raw_parts::<T>(transmute::<*const u8, *const T>(array_ptr.as_ptr()), array_ptr.len() / size_of::<T>()) },
             unsafe { core::slice::from_raw_parts::<T>(transmute::<*const u8, *const T>(pattern_ptr.as_ptr()), pattern_ptr.len() / size_of::<T>()) }
         );
 
-        let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<T>::is_search_possible(array, pattern, limit); if !is_search_possible { return search_result; }
+        let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<T>::is_search_possible(array, pattern, limit); if !is_search_possible { return None; }
 
         let (mut index, mut matches, mut start_index, last_pattern_index): (usize, usize, usize, usize) = (0_usize, 0_usize, 0_usize, pattern_length - 1_usize);
 
         if pattern_length == 1_usize {
             while index < array_length {
                 if array[index] != pattern[0_usize] { index += 1_usize }
-                else { search_result.push(index * size_of::<T>()); return search_result; }
+                else { return Some(index * size_of::<T>()); }
             }
         } else if pattern_length == 2_usize {
             while index < array_length {
@@ -93,7 +96,7 @@ impl<T: core::cmp::PartialEq> ByteSearch<T> {
                     let next_index: usize = index + 1_usize;
 
                     if array[next_index] != pattern[last_pattern_index] { if array[next_index] != pattern[0_usize] { index += 2_usize; continue; } else { index += 1_usize; continue; } }
-                    else { search_result.push(index * size_of::<T>()); return search_result; }
+                    else { return Some(index * size_of::<T>()); }
                 }
             }
         } else if pattern_length >= 3_usize {
@@ -105,10 +108,10 @@ impl<T: core::cmp::PartialEq> ByteSearch<T> {
                         while matches < last_pattern_index {
                             if array[index + pattern_index] == pattern[matches] {
                                 if matches != penultimate_pattern_index { matches += 1_usize; pattern_index += 1_usize; }
-                                else { search_result.push(start_index * size_of::<T>()); pattern_index = 0_usize; matches = 0_usize; return search_result; }
-                            } else { index += 1_usize; pattern_index = 0_usize; matches = 0_usize; break; }
+                                else { return Some(start_index * size_of::<T>()); }
+                            } else { index = start_index + 1_usize; pattern_index = 0_usize; matches = 0_usize; break; }
                         }
-                    } else { index += 1_usize; pattern_index = 0_usize; matches = 0_usize; continue; }
+                    } else { index = start_index + 1_usize; pattern_index = 0_usize; matches = 0_usize; continue; }
                 } else {
                     if index < array_length {
                         while start_index < array_length {
@@ -120,7 +123,34 @@ impl<T: core::cmp::PartialEq> ByteSearch<T> {
             }
         }
 
-        return search_result;
+        return None;
+    }
+
+    pub fn search_single(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize> {
+        return ByteSearch::<T>::search_first(array_ptr, pattern_ptr, limit).into_iter().collect::<Vec<usize>>();
+    }
+
+    pub(crate) fn search_last_offset(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Option<usize> {
+        let (array, pattern): (&[T], &[T]) = (
+            unsafe { core::slice::from_raw_parts::<T>(transmute::<*const u8, *const T>(array_ptr.as_ptr()), array_ptr.len() / size_of::<T>()) },
+            unsafe { core::slice::from_raw_parts::<T>(transmute::<*const u8, *const T>(pattern_ptr.as_ptr()), pattern_ptr.len() / size_of::<T>()) }
+        );
+
+        let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<T>::is_search_possible(array, pattern, limit); if !is_search_possible { return None; }
+
+        let (mut index, last_pattern_index): (usize, usize) = (array_length, pattern_length - 1_usize);
+
+        while index > 0_usize {
+            index -= 1_usize;
+
+            if array[index] == pattern[0_usize] && array[index + last_pattern_index] == pattern[last_pattern_index] && array[index..index + pattern_length] == *pattern { return Some(index * size_of::<T>()); }
+        }
+
+        return None;
+    }
+
+    pub fn search_last(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize> {
+        return ByteSearch::<T>::search_last_offset(array_ptr, pattern_ptr, limit).into_iter().collect::<Vec<usize>>();
     }
 
     pub fn search_all(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize> {
@@ -160,9 +190,9 @@ impl<T: core::cmp::PartialEq> ByteSearch<T> {
                             if array[index + pattern_index] == pattern[matches] {
                                 if matches != penultimate_pattern_index { matches += 1_usize; pattern_index += 1_usize; }
                                 else { search_result.push(start_index * size_of::<T>()); pattern_index = 0_usize; matches = 0_usize; index += last_pattern_index; break; }
-                            } else { index += 1_usize; pattern_index = 0_usize; matches = 0_usize; break; }
+                            } else { index = start_index + 1_usize; pattern_index = 0_usize; matches = 0_usize; break; }
                         }
-                    } else { index += 1_usize; pattern_index = 0_usize; matches = 0_usize; continue; }
+                    } else { index = start_index + 1_usize; pattern_index = 0_usize; matches = 0_usize; continue; }
                 } else {
                     if index < array_length {
                         while start_index < array_length {
@@ -230,4 +260,130 @@ impl<T: core::cmp::PartialEq> ByteSearch<T> {
 
         return search_result;
     }
+
+    pub fn find_iter<'a>(array: &'a [u8], pattern: &'a [u8]) -> ByteMatches<'a, T> {
+        return ByteMatches { array, pattern, position: 0_usize, overlapping: false, __marker: PhantomData };
+    }
+
+    pub fn find_iter_overlapping<'a>(array: &'a [u8], pattern: &'a [u8]) -> ByteMatches<'a, T> {
+        return ByteMatches { array, pattern, position: 0_usize, overlapping: true, __marker: PhantomData };
+    }
+
+    pub fn rfind_iter<'a>(array: &'a [u8], pattern: &'a [u8]) -> ByteMatchesRev<'a, T> {
+        return ByteMatchesRev { array, pattern, end: array.len(), overlapping: false, __marker: PhantomData };
+    }
+
+    pub fn rfind_iter_overlapping<'a>(array: &'a [u8], pattern: &'a [u8]) -> ByteMatchesRev<'a, T> {
+        return ByteMatchesRev { array, pattern, end: array.len(), overlapping: true, __marker: PhantomData };
+    }
+
+    pub fn count(array: &[u8], pattern: &[u8]) -> usize {
+        return ByteSearch::<T>::find_iter(array, pattern).count();
+    }
+
+    pub fn count_overlapping(array: &[u8], pattern: &[u8]) -> usize {
+        return ByteSearch::<T>::find_iter_overlapping(array, pattern).count();
+    }
 }
+
+impl<'a, T: core::cmp::PartialEq> Iterator for ByteMatches<'a, T> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let offset: usize = self.position + ByteSearch::<T>::search_first(&self.array[self.position..], self.pattern, None)?;
+
+        self.position = offset + if self.overlapping { size_of::<T>() } else { self.pattern.len() - self.pattern.len() % size_of::<T>() };
+
+        return Some(offset);
+    }
+}
+
+impl<'a, T: core::cmp::PartialEq> Iterator for ByteMatchesRev<'a, T> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let offset: usize = ByteSearch::<T>::search_last_offset(&self.array[..self.end], self.pattern, None)?;
+
+        self.end = if self.overlapping { offset + self.pattern.len() - self.pattern.len() % size_of::<T>() - size_of::<T>() } else { offset };
+
+        return Some(offset);
+    }
+}
+
+macro_rules! generate_folded {
+    ($t:ty) => {
+        impl ByteSearch<$t> {
+
+            const fn fold_ascii(value: $t) -> $t {
+                return if value > 0x40 && value < 0x5B { value | 0x20 } else { value };
+            }
+
+            fn is_folded_match(array: &[$t], index: usize, pattern: &[$t]) -> bool {
+                return array[index..index + pattern.len()].iter().zip(pattern.iter()).all(|(&value, &expected)| ByteSearch::<$t>::fold_ascii(value) == ByteSearch::<$t>::fold_ascii(expected));
+            }
+
+            pub(crate) fn search_first_ignore_ascii_case(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Option<usize> {
+                let (array, pattern): (&[$t], &[$t]) = (
+                    unsafe { core::slice::from_raw_parts::<$t>(array_ptr.as_ptr().cast::<$t>(), array_ptr.len() / size_of::<$t>()) },
+                    unsafe { core::slice::from_raw_parts::<$t>(pattern_ptr.as_ptr().cast::<$t>(), pattern_ptr.len() / size_of::<$t>()) }
+                );
+
+                let (array_length, _, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return None; }
+
+                let mut index: usize = 0_usize;
+
+                while index < array_length {
+                    if ByteSearch::<$t>::is_folded_match(array, index, pattern) { return Some(index * size_of::<$t>()); }
+
+                    index += 1_usize;
+                }
+
+                return None;
+            }
+
+            pub fn search_single_ignore_ascii_case(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>) -> Vec<usize> {
+                return ByteSearch::<$t>::search_first_ignore_ascii_case(array_ptr, pattern_ptr, limit).into_iter().collect::<Vec<usize>>();
+            }
+
+            pub fn search_all_ignore_ascii_case(array: &[u8], pattern: &[u8], limit: Option<usize>) -> Vec<usize> {
+                return ByteSearch::<$t>::search_ignore_ascii_case(array, pattern, limit, false);
+            }
+
+            pub fn search_all_overlapping_ignore_ascii_case(array: &[u8], pattern: &[u8], limit: Option<usize>) -> Vec<usize> {
+                return ByteSearch::<$t>::search_ignore_ascii_case(array, pattern, limit, true);
+            }
+
+            fn search_ignore_ascii_case(array_ptr: &[u8], pattern_ptr: &[u8], limit: Option<usize>, overlapping: bool) -> Vec<usize> {
+                let mut search_result: Vec<usize> = Vec::<usize>::new();
+
+                let (array, pattern): (&[$t], &[$t]) = (
+                    unsafe { core::slice::from_raw_parts::<$t>(array_ptr.as_ptr().cast::<$t>(), array_ptr.len() / size_of::<$t>()) },
+                    unsafe { core::slice::from_raw_parts::<$t>(pattern_ptr.as_ptr().cast::<$t>(), pattern_ptr.len() / size_of::<$t>()) }
+                );
+
+                let (array_length, pattern_length, is_search_possible): (usize, usize, bool) = ByteSearch::<$t>::is_search_possible(array, pattern, limit); if !is_search_possible { return search_result; }
+
+                let (array_ptr, step, mut position): (&[u8], usize, usize) = (
+                    &array_ptr[..(array_length + pattern_length - 1_usize) * size_of::<$t>()],
+                    if overlapping { 1_usize } else { pattern_length } * size_of::<$t>(),
+                    0_usize
+                );
+
+                while let Some(offset) = ByteSearch::<$t>::search_first_ignore_ascii_case(&array_ptr[position..], pattern_ptr, None) {
+                    search_result.push(position + offset);
+
+                    position += offset + step;
+                }
+
+                return search_result;
+            }
+        }
+    };
+}
+
+generate_folded!(u8);
+generate_folded!(u16);
+generate_folded!(u32);
+generate_folded!(i8);
+generate_folded!(i16);
+generate_folded!(i32);
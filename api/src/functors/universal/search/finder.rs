@@ -0,0 +1,237 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        Finder,
+        FinderMatches
+    }
+};
+
+use core::{
+    mem::{
+        transmute
+    }
+};
+
+impl<T: Copy + Ord> Finder<T> {
+
+    const __BYTE_FREQUENCY: [u8; 256_usize] = [
+         96,   8,   8,   8,   8,   8,   8,   8,   8, 160, 190,   8,   8, 150,   8,   8,
+          8,   8,   8,   8,   8,   8,   8,   8,   8,   8,   8,   8,   8,   8,   8,   8,
+        255, 130, 173, 124, 122, 120, 118, 176, 170, 167, 116, 114, 197, 194, 200, 188,
+        180, 178, 176, 174, 172, 170, 168, 166, 164, 162, 185, 182, 164, 179, 161, 128,
+        126, 156, 122, 138, 142, 160, 130, 128, 146, 152, 116, 118, 140, 134, 150, 154,
+        124, 112, 144, 148, 158, 136, 120, 132, 114, 126, 110, 158, 112, 155, 106, 191,
+        104, 246, 212, 228, 232, 250, 220, 218, 236, 242, 206, 208, 230, 224, 240, 244,
+        214, 202, 234, 238, 248, 226, 210, 222, 204, 216, 200, 152, 110, 149, 108, 120,
+         72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,
+         72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,
+         72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,
+         72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,  72,
+         56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,
+         56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,
+         56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,  56,
+         56,  56,  56,  56,  56,   4,   4,   4,   4,   4,   4,   4,   4,   4,   4,   4
+    ];
+
+    pub(crate) fn elements(array: &[u8]) -> &[T] {
+        return unsafe { core::slice::from_raw_parts::<T>(transmute::<*const u8, *const T>(array.as_ptr()), array.len() / size_of::<T>()) };
+    }
+
+    fn maximal_suffix(needle: &[T], reversed: bool) -> (usize, usize) {
+        let (mut left, mut right, mut offset, mut period): (usize, usize, usize, usize) = (0_usize, 1_usize, 0_usize, 1_usize);
+
+        while right + offset < needle.len() {
+            let (candidate, current): (T, T) = (needle[right + offset], needle[left + offset]);
+
+            if (candidate < current && !reversed) || (candidate > current && reversed) {
+                right += offset + 1_usize; offset = 0_usize; period = right - left;
+            } else if candidate == current {
+                if offset + 1_usize == period { right += offset + 1_usize; offset = 0_usize; } else { offset += 1_usize; }
+            } else {
+                left = right; right += 1_usize; offset = 0_usize; period = 1_usize;
+            }
+        }
+
+        return (left, period);
+    }
+
+    fn factorize(needle: &[T]) -> (usize, usize, bool) {
+        if needle.is_empty() { return (0_usize, 1_usize, true); }
+
+        let ((forward_position, forward_period), (reversed_position, reversed_period)): ((usize, usize), (usize, usize)) = (
+            Finder::<T>::maximal_suffix(needle, false),
+            Finder::<T>::maximal_suffix(needle, true)
+        );
+
+        let (critical_position, period): (usize, usize) = if forward_position > reversed_position { (forward_position, forward_period) } else { (reversed_position, reversed_period) };
+
+        if needle[..critical_position] == needle[period..period + critical_position] { return (critical_position, period, true); }
+
+        return (critical_position, critical_position.max(needle.len() - critical_position) + 1_usize, false);
+    }
+
+    fn rank(element: &[u8]) -> u8 {
+        return element.iter().filter(|&&byte| byte != 0x00).map(|&byte| Finder::<T>::__BYTE_FREQUENCY[byte as usize]).max().unwrap_or(Finder::<T>::__BYTE_FREQUENCY[0]);
+    }
+
+    fn rare_elements(needle: &[u8]) -> (usize, usize) {
+        let ranks: Vec<u8> = needle.chunks_exact(size_of::<T>()).map(Finder::<T>::rank).collect();
+
+        let first: usize = (0_usize..ranks.len()).min_by_key(|&index| ranks[index]).unwrap_or(0_usize);
+
+        return (first, (0_usize..ranks.len()).filter(|&index| index != first).min_by_key(|&index| ranks[index]).unwrap_or(first));
+    }
+
+    pub fn new(needle: &[u8]) -> Finder<T> {
+        let rare: (usize, usize) = Finder::<T>::rare_elements(needle);
+
+        let needle: Vec<T> = Finder::<T>::elements(needle).to_vec();
+
+        let (critical_position, period, periodic): (usize, usize, bool) = Finder::<T>::factorize(&needle);
+
+        return Finder { needle, critical_position, period, periodic, rare };
+    }
+
+    pub(crate) fn two_way(&self, array: &[T], mut position: usize, mut memory: usize, candidate: fn(&Finder<T>, &[T], usize) -> Option<usize>) -> Option<usize> {
+        let (needle, length): (&[T], usize) = (&self.needle, self.needle.len());
+
+        if length == 0_usize || array.len() < length { return None; }
+
+        'search: while position + length <= array.len() {
+            if memory == 0_usize { position = candidate(self, array, position)?; }
+
+            let mut index: usize = if self.periodic { self.critical_position.max(memory) } else { self.critical_position };
+
+            while index < length {
+                if needle[index] != array[position + index] {
+                    position += index - self.critical_position + 1_usize; memory = 0_usize;
+
+                    continue 'search;
+                }
+
+                index += 1_usize;
+            }
+
+            index = self.critical_position;
+
+            while index > memory {
+                index -= 1_usize;
+
+                if needle[index] != array[position + index] {
+                    position += self.period; memory = if self.periodic { length - self.period } else { 0_usize };
+
+                    continue 'search;
+                }
+            }
+
+            return Some(position);
+        }
+
+        return None;
+    }
+
+    pub(crate) fn resume(&self, position: usize, overlapping: bool) -> (usize, usize) {
+        if !overlapping { return (position + self.needle.len(), 0_usize); }
+
+        return (position + self.period, if self.periodic { self.needle.len() - self.period } else { 0_usize });
+    }
+
+    pub fn needle_length(&self) -> usize {
+        return self.needle.len() * size_of::<T>();
+    }
+}
+
+#[cfg(feature = "universal")]
+impl<T: Copy + Ord> Finder<T> {
+    pub(crate) fn next_candidate(&self, array: &[T], mut position: usize) -> Option<usize> {
+        let ((first, second), last_position): ((usize, usize), usize) = (self.rare, array.len() - self.needle.len());
+
+        while position <= last_position {
+            if array[position + first] == self.needle[first] && array[position + second] == self.needle[second] { return Some(position); }
+
+            position += 1_usize;
+        }
+
+        return None;
+    }
+}
+
+macro_rules! generate_finder {
+    ($t:ty) => {
+        impl Finder<$t> {
+            pub fn find(&self, array: &[u8]) -> Option<usize> {
+                return self.find_iter(array).next();
+            }
+
+            pub fn find_iter<'a, 'b>(&'b self, array: &'a [u8]) -> FinderMatches<'a, 'b, $t> {
+                return FinderMatches { finder: self, array, position: 0_usize, memory: 0_usize, overlapping: false };
+            }
+
+            pub fn find_iter_overlapping<'a, 'b>(&'b self, array: &'a [u8]) -> FinderMatches<'a, 'b, $t> {
+                return FinderMatches { finder: self, array, position: 0_usize, memory: 0_usize, overlapping: true };
+            }
+
+            pub fn count(&self, array: &[u8]) -> usize {
+                return self.find_iter(array).count();
+            }
+
+            pub fn count_overlapping(&self, array: &[u8]) -> usize {
+                return self.find_iter_overlapping(array).count();
+            }
+        }
+
+        impl<'a, 'b> Iterator for FinderMatches<'a, 'b, $t> {
+            type Item = usize;
+
+            fn next(&mut self) -> Option<usize> {
+                let position: usize = self.finder.two_way(Finder::<$t>::elements(self.array), self.position, self.memory, Finder::<$t>::next_candidate)?;
+
+                (self.position, self.memory) = self.finder.resume(position, self.overlapping);
+
+                return Some(position * size_of::<$t>());
+            }
+        }
+    };
+}
+
+pub(crate) use generate_finder;
+
+#[cfg(feature = "universal")]
+generate_finder!(u8);
+#[cfg(feature = "universal")]
+generate_finder!(u16);
+#[cfg(feature = "universal")]
+generate_finder!(u32);
+#[cfg(feature = "universal")]
+generate_finder!(i8);
+#[cfg(feature = "universal")]
+generate_finder!(i16);
+#[cfg(feature = "universal")]
+generate_finder!(i32);
@@ -26,6 +26,15 @@
  * THE SOFTWARE.
  */
 
+#[cfg(feature = "universal")]
 mod byte;
+mod multi;
+mod finder;
 
-pub use byte::{ByteSearch};
+#[cfg(feature = "universal")]
+pub use byte::{ByteSearch, ByteMatches, ByteMatchesRev};
+
+pub use multi::{MultiSearch, MultiSearcher};
+pub use finder::{Finder, FinderMatches};
+
+pub(crate) use finder::{generate_finder};
@@ -0,0 +1,279 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::{
+    essence::{
+        MultiSearch,
+        MultiSearcher,
+        Utf16Planes
+    }
+};
+
+use std::{
+    collections::{
+        VecDeque
+    }
+};
+
+impl MultiSearch {
+    pub const fn new() -> MultiSearch {
+        return MultiSearch { patterns: Vec::new(), width: 1_usize, overlapping: false };
+    }
+
+    pub fn pattern(mut self, pattern: &[u8]) -> MultiSearch {
+        self.patterns.push(pattern.to_vec());
+
+        return self;
+    }
+
+    pub fn patterns(mut self, patterns: &[&[u8]]) -> MultiSearch {
+        self.patterns.extend(patterns.iter().map(|pattern: &&[u8]| pattern.to_vec()));
+
+        return self;
+    }
+
+    pub const fn utf16(mut self, planes: Utf16Planes) -> MultiSearch {
+        self.width = if planes.omp() && planes.only() { 4_usize } else { 2_usize };
+
+        return self;
+    }
+
+    pub const fn utf32(mut self) -> MultiSearch {
+        self.width = 4_usize;
+
+        return self;
+    }
+
+    pub const fn overlapping(mut self, overlapping: bool) -> MultiSearch {
+        self.overlapping = overlapping;
+
+        return self;
+    }
+
+    pub fn build(&self) -> MultiSearcher {
+        let patterns: Vec<Vec<u8>> = self.patterns.iter().map(|pattern: &Vec<u8>| pattern[..pattern.len() - pattern.len() % self.width].to_vec()).collect();
+
+        let (mut longest, mut shortest): (usize, usize) = (0_usize, usize::MAX);
+
+        for pattern in patterns.iter().filter(|pattern: &&Vec<u8>| !pattern.is_empty()) {
+            longest = longest.max(pattern.len()); shortest = shortest.min(pattern.len());
+        }
+
+        let mut searcher: MultiSearcher = MultiSearcher {
+            patterns,
+            width: self.width,
+            overlapping: self.overlapping,
+            longest,
+            fingerprint: 0_usize,
+            masks: [[0_u8; 16_usize]; 6_usize],
+            root: [0_u32; 256_usize],
+            edges: vec![Vec::new()],
+            fail: vec![0_u32],
+            outputs: vec![Vec::new()]
+        };
+
+        if longest != 0_usize && searcher.patterns.len() <= MultiSearcher::__PACKED_PATTERNS {
+            searcher.fingerprint = shortest.min(MultiSearcher::__FINGERPRINT_LENGTH);
+
+            searcher.compile_masks();
+        }
+
+        searcher.compile_automaton();
+
+        return searcher;
+    }
+}
+
+impl Default for MultiSearch {
+    fn default() -> MultiSearch {
+        return MultiSearch::new();
+    }
+}
+
+impl MultiSearcher {
+
+    const __PACKED_PATTERNS:    usize = 32_usize;
+    const __FINGERPRINT_LENGTH: usize = 3_usize;
+    const __BUCKETS:            usize = 8_usize;
+
+    fn compile_masks(&mut self) {
+        for (id, pattern) in self.patterns.iter().enumerate().filter(|(_, pattern): &(usize, &Vec<u8>)| !pattern.is_empty()) {
+            let bucket: u8 = 1_u8 << (id % MultiSearcher::__BUCKETS);
+
+            for (position, &byte) in pattern.iter().take(self.fingerprint).enumerate() {
+                self.masks[position * 2_usize][(byte & 0x0F) as usize] |= bucket;
+                self.masks[position * 2_usize + 1_usize][(byte >> 4) as usize] |= bucket;
+            }
+        }
+    }
+
+    fn compile_automaton(&mut self) {
+        for (id, pattern) in self.patterns.iter().enumerate().filter(|(_, pattern): &(usize, &Vec<u8>)| !pattern.is_empty()) {
+            let mut state: usize = 0_usize;
+
+            for &byte in pattern.iter() {
+                state = match self.edges[state].iter().find(|(label, _): &&(u8, u32)| *label == byte) {
+                    Some(&(_, next)) => next as usize,
+                    None => {
+                        let next: usize = self.edges.len();
+
+                        self.edges[state].push((byte, next as u32));
+                        self.edges.push(Vec::new()); self.fail.push(0_u32); self.outputs.push(Vec::new());
+
+                        next
+                    }
+                };
+            }
+
+            self.outputs[state].push(id);
+        }
+
+        for &(byte, next) in self.edges[0_usize].iter() { self.root[byte as usize] = next; }
+
+        let mut queue: VecDeque<usize> = self.edges[0_usize].iter().map(|&(_, next): &(u8, u32)| next as usize).collect();
+
+        while let Some(state) = queue.pop_front() {
+            for index in 0_usize..self.edges[state].len() {
+                let (byte, next): (u8, u32) = self.edges[state][index];
+
+                let fail: u32 = self.step(self.fail[state], byte);
+
+                self.fail[next as usize] = fail;
+
+                let inherited: Vec<usize> = self.outputs[fail as usize].clone();
+
+                self.outputs[next as usize].extend(inherited);
+
+                queue.push_back(next as usize);
+            }
+        }
+    }
+
+    fn step(&self, mut state: u32, byte: u8) -> u32 {
+        loop {
+            if state == 0_u32 { return self.root[byte as usize]; }
+
+            if let Some(&(_, next)) = self.edges[state as usize].iter().find(|(label, _): &&(u8, u32)| *label == byte) { return next; }
+
+            state = self.fail[state as usize];
+        }
+    }
+
+    pub(crate) fn fingerprint_at(&self, array: &[u8], position: usize) -> u8 {
+        let mut buckets: u8 = 0xFF;
+
+        for offset in 0_usize..self.fingerprint {
+            let byte: u8 = array[position + offset];
+
+            buckets &= self.masks[offset * 2_usize][(byte & 0x0F) as usize] & self.masks[offset * 2_usize + 1_usize][(byte >> 4) as usize];
+        }
+
+        return buckets;
+    }
+
+    pub(crate) fn verify(&self, array: &[u8], position: usize, buckets: u8, all_matches: bool, resume: &mut usize, search_result: &mut Vec<(usize, usize)>) -> bool {
+        if position < *resume || position % self.width != 0_usize { return true; }
+
+        for (id, pattern) in self.patterns.iter().enumerate() {
+            if buckets & (1_u8 << (id % MultiSearcher::__BUCKETS)) == 0_u8 || pattern.is_empty() || position + pattern.len() > array.len() || array[position..position + pattern.len()] != *pattern { continue; }
+
+            search_result.push((id, position));
+
+            if !all_matches { return false; }
+
+            if !self.overlapping { *resume = position + pattern.len(); return true; }
+        }
+
+        return true;
+    }
+
+    pub(crate) fn packed_search_from(&self, array: &[u8], mut position: usize, all_matches: bool, resume: &mut usize, search_result: &mut Vec<(usize, usize)>) {
+        while position + self.fingerprint <= array.len() {
+            let buckets: u8 = self.fingerprint_at(array, position);
+
+            if buckets != 0_u8 && !self.verify(array, position, buckets, all_matches, resume, search_result) { return; }
+
+            position += 1_usize;
+        }
+    }
+
+    pub(crate) fn automaton_search(&self, array: &[u8], all_matches: bool) -> Vec<(usize, usize)> {
+        let mut search_result: Vec<(usize, usize)> = Vec::new();
+
+        if self.longest == 0_usize { return search_result; }
+
+        let (length, mut state): (usize, u32) = (array.len() - array.len() % self.width, 0_u32);
+
+        for (index, &byte) in array[..length].iter().enumerate() {
+            state = self.step(state, byte);
+
+            for &id in self.outputs[state as usize].iter() {
+                let position: usize = index + 1_usize - self.patterns[id].len();
+
+                if position % self.width == 0_usize { search_result.push((id, position)); }
+            }
+
+            if !all_matches && search_result.iter().any(|&(_, position): &(usize, usize)| index + 1_usize >= position + self.longest) { break; }
+        }
+
+        search_result.sort_unstable_by_key(|&(id, position): &(usize, usize)| (position, id));
+
+        if !all_matches {
+            search_result.truncate(1_usize);
+        } else if !self.overlapping {
+            let mut resume: usize = 0_usize;
+
+            search_result.retain(|&(id, position): &(usize, usize)| {
+                if position < resume { return false; }
+
+                resume = position + self.patterns[id].len();
+
+                return true;
+            });
+        }
+
+        return search_result;
+    }
+
+    #[cfg(feature = "universal")]
+    pub(crate) fn search_matches(&self, array: &[u8], all_matches: bool) -> Vec<(usize, usize)> {
+        return self.automaton_search(array, all_matches);
+    }
+
+    pub fn pattern_count(&self) -> usize {
+        return self.patterns.len();
+    }
+
+    pub fn search_single(&self, array: &[u8]) -> Option<(usize, usize)> {
+        return self.search_matches(array, false).first().copied();
+    }
+
+    pub fn search_all(&self, array: &[u8]) -> Vec<(usize, usize)> {
+        return self.search_matches(array, true);
+    }
+}
@@ -34,8 +34,6 @@
 #![deny(arithmetic_overflow)]
 #![deny(overflowing_literals)]
 
-#![cfg_attr(any(target_arch = "x86", target_arch = "x86_64"), feature(stdarch_x86_avx512))]
-
 mod essence;
 mod functors;
 
@@ -43,10 +41,28 @@ mod functors;
 pub use functors::{
     codings::{
         ASCII,
-        UTF8, UTF16, UTF32
+        ISO8859_1,
+        SingleByte, Codepage,
+        SHIFTJIS, EUCJP, ISO2022JP,
+        GB18030, GBK, BIG5,
+        EUCKR,
+        CESU8, MUTF8, WTF8,
+        UTF7, Utf7Variant, Utf7State,
+        EBCDIC, EbcdicCodepage, Newline,
+        Endian, Utf16Planes, SearchOptions,
+        UTF8, UTF16, UTF32,
+        Utf8Validator, Utf16Validator, Utf32Validator,
+        EncodingError, EncodingErrorKind, Policy,
+        Encoding, Detection,
+        Statistics
     },
     search::{
-        ByteSearch
+        ByteSearch, ByteMatches, ByteMatchesRev,
+        MultiSearch, MultiSearcher,
+        Finder, FinderMatches
+    },
+    dispatch::{
+        SimdLevel
     }
 };
 
@@ -62,17 +78,22 @@ pub use functors::{
 pub use functors::{
     codings::{
         ASCII,
-        UTF16, UTF32
+        UTF8, UTF16, UTF32
     },
     non_simd_codings::{
         *
     },
+    non_simd_search::{
+        MultiSearch, MultiSearcher,
+        Finder, FinderMatches
+    },
     search::{
-        ByteSearch
+        ByteSearch, ByteMatches, ByteMatchesRev
+    },
+    dispatch::{
+        SimdLevel
     }
 };
 
-#[cfg(any(
-    feature = "python",
-))]
+#[cfg(feature = "python")]
 mod bindings;
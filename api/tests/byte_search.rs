@@ -0,0 +1,66 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+#![cfg(not(feature = "python"))]
+#![allow(non_snake_case)]
+
+use COXave::ByteSearch;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "universal"))]
+type Element = i8;
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", feature = "universal")))]
+type Element = u8;
+
+fn naive_search(array: &[u8], pattern: &[u8]) -> Vec<usize> {
+    let (mut result, mut index): (Vec<usize>, usize) = (Vec::<usize>::new(), 0_usize);
+
+    while index + pattern.len() <= array.len() {
+        if array[index..index + pattern.len()] == *pattern { result.push(index); index += pattern.len(); } else { index += 1_usize; }
+    }
+
+    return result;
+}
+
+#[test]
+fn search_restarts_after_partial_match() {
+    let cases: [(&[u8], &[u8]); 5_usize] = [
+        (b"aaab", b"aab"),
+        (b"abababc", b"ababc"),
+        (b"xaaaaby", b"aaab"),
+        (b"aabaabaab", b"aab"),
+        (b"abcabcabd", b"abcabd")
+    ];
+
+    for (array, pattern) in cases {
+        let expected: Vec<usize> = naive_search(array, pattern);
+
+        assert_eq!(ByteSearch::<Element>::search_single(array, pattern, None), expected.first().copied().into_iter().collect::<Vec<usize>>(), "{:?} in {:?}", pattern, array);
+        assert_eq!(ByteSearch::<Element>::search_all(array, pattern, None), expected, "{:?} in {:?}", pattern, array);
+    }
+}
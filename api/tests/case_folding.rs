@@ -0,0 +1,77 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+
+#![cfg(not(feature = "python"))]
+#![allow(non_snake_case)]
+
+use COXave::{Endian, UTF8, UTF16, UTF32};
+
+#[test]
+fn case_folded_cherokee() {
+    let (array, pattern): (&str, &str) = ("xᏑᎳᏍᎦy", "ꮡꮃꮝꭶ");
+
+    assert_eq!(UTF8::search_pattern_case_folded(array.as_bytes(), pattern.as_bytes(), false, true, None), vec![1_usize]);
+    assert_eq!(UTF8::search_pattern_case_folded(pattern.as_bytes(), "ᏑᎳᏍᎦ".as_bytes(), false, true, None), vec![0_usize]);
+    assert_eq!(UTF8::search_pattern_case_folded(array.as_bytes(), "ꮡꮃꮝꮶ".as_bytes(), false, true, None), Vec::<usize>::new());
+
+    let (array, pattern): (Vec<u8>, Vec<u8>) = (array.encode_utf16().flat_map(u16::to_le_bytes).collect(), pattern.encode_utf16().flat_map(u16::to_le_bytes).collect());
+
+    assert_eq!(UTF16::search_pattern_case_folded(&array, &pattern, true, false, true, None), vec![2_usize]);
+
+    let (array, pattern): (Vec<u8>, Vec<u8>) = ("xᏑᎳᏍᎦy".chars().flat_map(|character| (character as u32).to_be_bytes()).collect(), "ꮡꮃꮝꭶ".chars().flat_map(|character| (character as u32).to_be_bytes()).collect());
+
+    assert_eq!(UTF32::search_pattern_case_folded(&array, &pattern, false, false, true, None), vec![4_usize]);
+}
+
+#[test]
+fn case_folded_across_windows() {
+    let array: String = (0_usize..3000_usize).map(|index| if index % 7_usize == 0_usize { "ΣΑς" } else { "σα" }).collect();
+    let pattern: &str = "σας";
+
+    let folded: String = array.chars().map(|character| if character == 'ς' { 'σ' } else { character.to_lowercase().next().unwrap() }).collect();
+
+    for overlapping in [false, true] {
+        let expected: Vec<usize> = (0_usize..folded.len()).filter(|&index| folded.is_char_boundary(index) && folded[index..].starts_with("σασ")).collect();
+
+        let expected: Vec<usize> = if overlapping { expected } else {
+            expected.iter().fold(Vec::<usize>::new(), |mut result, &index| { if result.last().is_none_or(|&last| index >= last + "σασ".len()) { result.push(index); } result })
+        };
+
+        assert!(expected.len() > 1000_usize);
+
+        assert_eq!(UTF8::search_pattern_case_folded(array.as_bytes(), pattern.as_bytes(), overlapping, true, None), expected);
+        assert_eq!(UTF8::search_pattern_case_folded(array.as_bytes(), pattern.as_bytes(), overlapping, false, None), vec![expected[0_usize]]);
+
+        let (array, pattern): (Vec<u8>, Vec<u8>) = (array.chars().flat_map(|character| (character as u32).to_be_bytes()).collect(), pattern.chars().flat_map(|character| (character as u32).to_be_bytes()).collect());
+
+        let expected: Vec<usize> = expected.iter().map(|&index| folded[..index].chars().count() * 4_usize).collect();
+
+        assert_eq!(UTF32::search_pattern_case_folded_with(&array, &pattern, Endian::Big, overlapping, true, None), expected);
+    }
+}
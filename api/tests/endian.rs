@@ -0,0 +1,62 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+
+#![cfg(not(feature = "python"))]
+#![allow(non_snake_case)]
+
+use COXave::{Endian, ISO8859_1, GBK, UTF7, Utf7Variant, UTF8, UTF16, UTF32};
+
+const TEXT: &str = "A\u{E9}\u{4E2D}\u{1F600}z";
+
+#[test]
+fn endian_entry_points_match_flags() {
+    for (endian, flag) in [(Endian::Little, true), (Endian::Big, false), (Endian::Native, cfg!(target_endian = "little"))] {
+        let utf16: Vec<u8> = UTF8::to_utf16(TEXT.as_bytes(), flag).unwrap().iter().flat_map(|unit| unit.to_ne_bytes()).collect();
+        let utf32: Vec<u8> = UTF8::to_utf32(TEXT.as_bytes(), flag).unwrap().iter().flat_map(|code| code.to_ne_bytes()).collect();
+
+        assert_eq!(UTF8::to_utf16_with(TEXT.as_bytes(), endian), UTF8::to_utf16(TEXT.as_bytes(), flag));
+        assert_eq!(UTF8::to_utf32_with(TEXT.as_bytes(), endian), UTF8::to_utf32(TEXT.as_bytes(), flag));
+
+        assert_eq!(UTF16::to_utf8_with(&utf16, endian).unwrap(), TEXT.as_bytes());
+        assert_eq!(UTF16::to_utf32_with(&utf16, endian).unwrap(), UTF16::to_utf32(&utf16, flag).unwrap());
+        assert_eq!(UTF16::count_chars_with(&utf16, endian), TEXT.chars().count());
+        assert_eq!(UTF16::utf8_length_from_utf16_with(&utf16, endian), TEXT.len());
+        assert_eq!(UTF16::to_string_lossy_with(&utf16, endian), TEXT);
+        assert_eq!(UTF16::lone_surrogates_with(&utf16, endian).unwrap(), Vec::<usize>::new());
+
+        assert_eq!(UTF32::to_utf8_with(&utf32, endian).unwrap(), TEXT.as_bytes());
+        assert_eq!(UTF32::to_utf16_with(&utf32, endian), UTF32::to_utf16(&utf32, flag));
+        assert_eq!(UTF32::utf16_length_from_utf32_with(&utf32, endian), TEXT.encode_utf16().count());
+        assert_eq!(UTF32::stats_with(&utf32, endian), UTF32::stats(&utf32, flag));
+
+        assert_eq!(ISO8859_1::to_utf16_with(b"A\xE9", endian), ISO8859_1::to_utf16(b"A\xE9", flag));
+        assert_eq!(GBK::to_utf16_with(b"\xD6\xD0", endian), GBK::to_utf16(b"\xD6\xD0", flag));
+        assert_eq!(UTF7::to_utf16_with(b"A+ZeVnLIqe-", endian, Utf7Variant::Standard), UTF7::to_utf16(b"A+ZeVnLIqe-", flag, Utf7Variant::Standard));
+    }
+}
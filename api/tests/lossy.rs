@@ -0,0 +1,95 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+
+#![cfg(not(feature = "python"))]
+#![allow(non_snake_case)]
+
+use COXave::{UTF16};
+
+fn units(seed: u64, length: usize) -> Vec<u16> {
+    let mut state: u64 = seed;
+
+    return (0_usize..length).map(|_| {
+        state = state.wrapping_mul(6364136223846793005_u64).wrapping_add(1442695040888963407_u64);
+
+        return match (state >> 60) as u8 {
+            0_u8 => 0xD800_u16 | ((state >> 32) as u16 & 0x03FF),
+            1_u8 => 0xDC00_u16 | ((state >> 32) as u16 & 0x03FF),
+            2_u8 => 0xFFFD_u16,
+            _ => (state >> 40) as u16 & 0x7FFF
+        };
+    }).collect();
+}
+
+fn lone_surrogates(units: &[u16]) -> Vec<usize> {
+    let (mut indices, mut index): (Vec<usize>, usize) = (Vec::<usize>::new(), 0_usize);
+
+    while index < units.len() {
+        if (units[index] & 0xFC00) == 0xD800 && index + 1_usize < units.len() && (units[index + 1_usize] & 0xFC00) == 0xDC00 { index += 2_usize; continue; }
+
+        if (units[index] & 0xF800) == 0xD800 { indices.push(index * 2_usize); }
+
+        index += 1_usize;
+    }
+
+    return indices;
+}
+
+#[test]
+fn lone_surrogates_match_scalar_reference() {
+    for seed in 0_u64..64_u64 {
+        for length in [1_usize, 7_usize, 8_usize, 9_usize, 31_usize, 32_usize, 33_usize, 100_usize, 1000_usize, 5000_usize] {
+            let mut units: Vec<u16> = units(seed, length);
+
+            if seed % 2_u64 == 0_u64 { for (index, unit) in units.iter_mut().enumerate() { if index % 97_usize > 1_usize { *unit &= 0x7FFF; } } }
+
+            let expected: Vec<usize> = lone_surrogates(&units);
+
+            for endian in [true, false] {
+                let array: Vec<u8> = units.iter().flat_map(|&unit| if endian { unit.to_le_bytes() } else { unit.to_be_bytes() }).collect();
+
+                assert_eq!(UTF16::lone_surrogates(&array, endian).unwrap(), expected, "seed {} length {}", seed, length);
+
+                let mut repaired: Vec<u16> = units.clone();
+
+                for &index in expected.iter() { repaired[index / 2_usize] = 0xFFFD_u16; }
+
+                let repaired: Vec<u8> = repaired.iter().flat_map(|&unit| if endian { unit.to_le_bytes() } else { unit.to_be_bytes() }).collect();
+
+                assert_eq!(UTF16::to_well_formed(&array, endian).unwrap(), repaired);
+            }
+        }
+    }
+
+    // Empty input is well-formed, only an odd length is rejected.
+    for endian in [true, false] {
+        assert_eq!((UTF16::lone_surrogates(b"", endian).unwrap(), UTF16::to_well_formed(b"", endian).unwrap()), (Vec::<usize>::new(), Vec::<u8>::new()));
+        assert_eq!((UTF16::lone_surrogates(b"\x00", endian).unwrap_err().offset(), UTF16::to_well_formed(b"\x00\xD8\x00", endian).unwrap_err().offset()), (0_usize, 2_usize));
+    }
+}
@@ -0,0 +1,267 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+
+#![cfg(not(feature = "python"))]
+#![allow(non_snake_case)]
+
+use COXave::{
+    ISO8859_1,
+    SingleByte, Codepage,
+    SHIFTJIS, EUCJP, ISO2022JP,
+    GB18030, GBK, BIG5,
+    EUCKR,
+    CESU8, MUTF8, WTF8,
+    UTF7, Utf7Variant, Utf7State,
+    EBCDIC, EbcdicCodepage, Newline,
+    Policy,
+    UTF8, UTF16, UTF32
+};
+
+type Codec = fn(&[u8]) -> Result<Vec<u8>, COXave::EncodingError>;
+
+// Every character the WHATWG encoder of `encoding` can write, as one text.
+fn encodable(encoding: &'static encoding_rs::Encoding, codes: impl Iterator<Item = u32>) -> String {
+    return codes.filter_map(char::from_u32).filter(|&code| {
+        let mut buffer: [u8; 4_usize] = [0_u8; 4_usize];
+
+        let (result, _, _) = encoding.new_encoder().encode_from_utf8_without_replacement(code.encode_utf8(&mut buffer), &mut [0_u8; 16_usize], true);
+
+        return result == encoding_rs::EncoderResult::InputEmpty;
+    }).collect::<String>();
+}
+
+fn sample() -> String {
+    return "Hello, \u{0}world!\r\n\u{E9}\u{3B1}\u{416}\u{4E2D}\u{65E5}\u{D55C}\u{FFFD}\u{10000}\u{1F600}\u{10FFFF}".repeat(3_usize);
+}
+
+// Empty to_utf8, to_utf16, from_utf8 and from_utf16 output, in that order.
+fn empty() -> (Vec<u8>, Vec<u16>, Vec<u8>, Vec<u8>) {
+    return (Vec::<u8>::new(), Vec::<u16>::new(), Vec::<u8>::new(), Vec::<u8>::new());
+}
+
+fn check_text_round_trip(name: &str, text: &str, encode: Codec, decode: Codec) {
+    let encoded: Vec<u8> = encode(text.as_bytes()).unwrap_or_else(|error| panic!("{}: encode failed at {}", name, error.offset()));
+
+    assert_eq!(String::from_utf8(decode(&encoded).unwrap()).unwrap(), text, "{}", name);
+}
+
+fn check_whatwg(name: &str, encoding: &'static encoding_rs::Encoding, codes: impl Iterator<Item = u32>, encode: Codec, decode: Codec) {
+    let text: String = encodable(encoding, codes);
+
+    assert!(!text.is_empty(), "{}", name);
+
+    let (expected, _, _) = encoding.encode(&text);
+
+    assert_eq!(encode(text.as_bytes()).unwrap(), expected.as_ref(), "{}", name);
+
+    // WHATWG encoders are lossy for a few characters (U+00A5 is written as 0x5C in Shift_JIS), so decoding is compared, not the text.
+    let (decoded, _) = encoding.decode_without_bom_handling(&expected);
+
+    assert_eq!(decode(&expected).unwrap(), decoded.as_bytes(), "{}", name);
+}
+
+#[test]
+fn unicode_round_trip() {
+    let text: String = sample();
+
+    for endian in [true, false] {
+        let utf16: Vec<u8> = UTF8::to_utf16(text.as_bytes(), endian).unwrap().iter().flat_map(|unit| unit.to_ne_bytes()).collect();
+        let utf32: Vec<u8> = UTF8::to_utf32(text.as_bytes(), endian).unwrap().iter().flat_map(|code| code.to_ne_bytes()).collect();
+
+        assert_eq!(UTF16::to_utf8(&utf16, endian).unwrap(), text.as_bytes());
+        assert_eq!(UTF32::to_utf8(&utf32, endian).unwrap(), text.as_bytes());
+        assert_eq!(UTF16::to_utf32(&utf16, endian).unwrap().iter().flat_map(|code| code.to_ne_bytes()).collect::<Vec<u8>>(), utf32);
+        assert_eq!(UTF32::to_utf16(&utf32, endian).unwrap().iter().flat_map(|unit| unit.to_ne_bytes()).collect::<Vec<u8>>(), utf16);
+    }
+
+    // Empty input is empty output, not an error.
+    for endian in [true, false] {
+        assert_eq!((UTF8::to_utf16_into(b"", &mut [], endian).unwrap(), UTF16::to_utf8_into(b"", &mut [], endian).unwrap()), ((0_usize, 0_usize), (0_usize, 0_usize)));
+        assert_eq!((UTF8::to_utf16(b"", endian).unwrap(), UTF16::to_utf8(b"", endian).unwrap()), (Vec::<u16>::new(), Vec::<u8>::new()));
+        assert_eq!((UTF8::to_utf32_into(b"", &mut [], endian).unwrap(), UTF16::to_utf32_into(b"", &mut [], endian).unwrap()), ((0_usize, 0_usize), (0_usize, 0_usize)));
+        assert_eq!((UTF32::to_utf8_into(b"", &mut [], endian).unwrap(), UTF32::to_utf16_into(b"", &mut [], endian).unwrap()), ((0_usize, 0_usize), (0_usize, 0_usize)));
+        assert_eq!((UTF8::to_utf32(b"", endian).unwrap(), UTF32::to_utf8(b"", endian).unwrap()), (Vec::<u32>::new(), Vec::<u8>::new()));
+    }
+}
+
+#[test]
+fn unicode_variants_round_trip() {
+    let text: String = sample();
+
+    check_text_round_trip("CESU-8", &text, CESU8::from_utf8, CESU8::to_utf8);
+    check_text_round_trip("MUTF-8", &text, MUTF8::from_utf8, MUTF8::to_utf8);
+    check_text_round_trip("WTF-8", &text, WTF8::from_utf8, |array| WTF8::to_utf8(array, Policy::Error));
+    check_text_round_trip("UTF-7", &text, |array| UTF7::from_utf8(array, Utf7Variant::Standard), |array| UTF7::to_utf8(array, Utf7Variant::Standard));
+    check_text_round_trip("UTF-7 IMAP", &text, |array| UTF7::from_utf8(array, Utf7Variant::Imap), |array| UTF7::to_utf8(array, Utf7Variant::Imap));
+
+    // The resumable UTF-7 decoders stop exactly where the output fills and hand back the shift state for the next call.
+    for variant in [Utf7Variant::Standard, Utf7Variant::Imap] {
+        let encoded: Vec<u8> = UTF7::from_utf8(text.as_bytes(), variant).unwrap();
+
+        for capacity in [4_usize, 5_usize, 7_usize, 16_usize] {
+            let (mut utf8, mut utf16, mut read, mut state): (Vec<u8>, Vec<u16>, usize, Utf7State) = (Vec::<u8>::new(), Vec::<u16>::new(), 0_usize, Utf7State::new());
+
+            while read < encoded.len() || state.is_shifted() {
+                let mut output: Vec<u8> = vec![0_u8; capacity];
+                let (next, written, resumed): (usize, usize, Utf7State) = UTF7::to_utf8_into_resumable(&encoded[read..], &mut output, variant, state).unwrap();
+
+                assert!(next > 0_usize || written > 0_usize, "{:?} stalled at {} with capacity {}", variant, read, capacity);
+
+                utf8.extend_from_slice(&output[..written]); read += next; state = resumed;
+            }
+
+            (read, state) = (0_usize, Utf7State::new());
+
+            while read < encoded.len() || state.is_shifted() {
+                let mut output: Vec<u16> = vec![0_u16; capacity];
+                let (next, written, resumed): (usize, usize, Utf7State) = UTF7::to_utf16_into_resumable(&encoded[read..], &mut output, true, variant, state).unwrap();
+
+                assert!(next > 0_usize || written > 0_usize, "{:?} stalled at {} with capacity {}", variant, read, capacity);
+
+                utf16.extend_from_slice(&output[..written]); read += next; state = resumed;
+            }
+
+            assert_eq!(utf8, text.as_bytes(), "{:?} capacity {}", variant, capacity);
+            assert_eq!(utf16, UTF8::to_utf16(text.as_bytes(), true).unwrap(), "{:?} capacity {}", variant, capacity);
+        }
+
+        // The stateless decoders rewind to the last '+' instead, so a call resumes from the returned offset as long as one shift run fits.
+        let (mut utf8, mut read): (Vec<u8>, usize) = (Vec::<u8>::new(), 0_usize);
+
+        while read < encoded.len() {
+            let mut output: Vec<u8> = vec![0_u8; 64_usize];
+            let (next, written): (usize, usize) = UTF7::to_utf8_into(&encoded[read..], &mut output, variant).unwrap();
+
+            assert!(next > 0_usize, "{:?} stalled at {}", variant, read);
+
+            utf8.extend_from_slice(&output[..written]); read += next;
+        }
+
+        assert_eq!(utf8, text.as_bytes(), "{:?}", variant);
+    }
+
+    // Empty input is empty output, not an error, with or without a shift state to carry.
+    for variant in [Utf7Variant::Standard, Utf7Variant::Imap] {
+        assert_eq!((UTF7::to_utf8(b"", variant).unwrap(), UTF7::to_utf16(b"", true, variant).unwrap(), UTF7::from_utf8(b"", variant).unwrap(), UTF7::from_utf16(b"", true, variant).unwrap()), empty(), "{:?}", variant);
+
+        let ((utf8_read, utf8_written, _), (utf16_read, utf16_written, _)): ((usize, usize, Utf7State), (usize, usize, Utf7State)) = (UTF7::to_utf8_into_resumable(b"", &mut [], variant, Utf7State::new()).unwrap(), UTF7::to_utf16_into_resumable(b"", &mut [], true, variant, Utf7State::new()).unwrap());
+
+        assert_eq!((utf8_read, utf8_written, utf16_read, utf16_written), (0_usize, 0_usize, 0_usize, 0_usize), "{:?}", variant);
+    }
+
+    // MUTF-8 writes NUL as C0 80 and CESU-8 writes supplementary characters as surrogate pairs.
+    assert_eq!(MUTF8::from_utf8(b"\x00").unwrap(), b"\xC0\x80");
+    assert_eq!(CESU8::from_utf8("\u{10000}".as_bytes()).unwrap(), b"\xED\xA0\x80\xED\xB0\x80");
+
+    // Empty input is empty output, not an error.
+    assert_eq!((CESU8::to_utf8(b"").unwrap(), CESU8::to_utf16(b"", true).unwrap(), CESU8::from_utf8(b"").unwrap(), CESU8::from_utf16(b"", true).unwrap()), empty());
+    assert_eq!((MUTF8::to_utf8(b"").unwrap(), MUTF8::to_utf16(b"", true).unwrap(), MUTF8::from_utf8(b"").unwrap(), MUTF8::from_utf16(b"", true).unwrap()), empty());
+    assert_eq!((WTF8::to_utf8(b"", Policy::Error).unwrap(), WTF8::to_utf16(b"", true).unwrap(), WTF8::from_utf8(b"").unwrap(), WTF8::from_utf16(b"", true).unwrap()), empty());
+}
+
+#[test]
+fn single_byte_round_trip() {
+    let latin1: Vec<u8> = (0_u8..=255_u8).collect();
+
+    assert_eq!(ISO8859_1::from_utf8(&ISO8859_1::to_utf8(&latin1)).unwrap(), latin1);
+
+    // Empty input is empty output in both directions.
+    assert_eq!((ISO8859_1::to_utf8(b""), ISO8859_1::to_utf16(b"", true)), (Vec::<u8>::new(), Vec::<u16>::new()));
+    assert_eq!((ISO8859_1::from_utf8(b"").unwrap(), ISO8859_1::from_utf16(b"", true).unwrap()), (Vec::<u8>::new(), Vec::<u8>::new()));
+
+    let codepages: [Codepage; 26_usize] = [
+        Codepage::WINDOWS1250, Codepage::WINDOWS1251, Codepage::WINDOWS1252, Codepage::WINDOWS1253, Codepage::WINDOWS1254,
+        Codepage::WINDOWS1255, Codepage::WINDOWS1256, Codepage::WINDOWS1257, Codepage::WINDOWS1258,
+        Codepage::ISO8859_2, Codepage::ISO8859_3, Codepage::ISO8859_4, Codepage::ISO8859_5, Codepage::ISO8859_6,
+        Codepage::ISO8859_7, Codepage::ISO8859_8, Codepage::ISO8859_9, Codepage::ISO8859_10, Codepage::ISO8859_11,
+        Codepage::ISO8859_13, Codepage::ISO8859_14, Codepage::ISO8859_15, Codepage::ISO8859_16,
+        Codepage::KOI8R, Codepage::KOI8U, Codepage::IBM866
+    ];
+
+    for codepage in codepages {
+        let mapped: Vec<u8> = (0_u8..=255_u8).filter(|&byte| SingleByte::is_single_byte(&[byte], codepage)).collect();
+
+        let text: Vec<u8> = SingleByte::to_utf8(&mapped, codepage, Policy::Error).unwrap();
+
+        assert_eq!(SingleByte::from_utf8(&text, codepage, Policy::Error).unwrap(), mapped, "{:?}", codepage);
+
+        assert_eq!((SingleByte::to_utf8(b"", codepage, Policy::Error).unwrap(), SingleByte::to_utf16(b"", codepage, true, Policy::Error).unwrap()), (Vec::<u8>::new(), Vec::<u16>::new()), "{:?}", codepage);
+        assert_eq!((SingleByte::from_utf8(b"", codepage, Policy::Error).unwrap(), SingleByte::from_utf16(b"", codepage, true, Policy::Error).unwrap()), (Vec::<u8>::new(), Vec::<u8>::new()), "{:?}", codepage);
+    }
+
+    for codepage in [EbcdicCodepage::IBM037, EbcdicCodepage::IBM500, EbcdicCodepage::IBM1047, EbcdicCodepage::IBM1140] {
+        for newline in [Newline::Nel, Newline::Lf] {
+            assert_eq!(EBCDIC::from_utf8(&EBCDIC::to_utf8(&latin1, codepage, newline), codepage, newline).unwrap(), latin1, "{:?} {:?}", codepage, newline);
+
+            // Empty input is empty output, not an error.
+            assert_eq!((EBCDIC::to_utf8(b"", codepage, newline), EBCDIC::to_ascii(b"", codepage, newline).unwrap(), EBCDIC::from_utf8(b"", codepage, newline).unwrap(), EBCDIC::from_ascii(b"", codepage, newline).unwrap()), (Vec::<u8>::new(), Vec::<u8>::new(), Vec::<u8>::new(), Vec::<u8>::new()), "{:?} {:?}", codepage, newline);
+        }
+    }
+}
+
+#[test]
+fn japanese_round_trip() {
+    check_whatwg("Shift_JIS", encoding_rs::SHIFT_JIS, 0_u32..0x10000_u32, SHIFTJIS::from_utf8, SHIFTJIS::to_utf8);
+    check_whatwg("EUC-JP", encoding_rs::EUC_JP, 0_u32..0x10000_u32, EUCJP::from_utf8, EUCJP::to_utf8);
+    check_whatwg("ISO-2022-JP", encoding_rs::ISO_2022_JP, 0_u32..0x10000_u32, ISO2022JP::from_utf8, ISO2022JP::to_utf8);
+
+    // Empty input is empty output, not an error.
+    assert_eq!((SHIFTJIS::to_utf8(b"").unwrap(), SHIFTJIS::to_utf16(b"", true).unwrap(), SHIFTJIS::from_utf8(b"").unwrap(), SHIFTJIS::from_utf16(b"", true).unwrap()), empty());
+    assert_eq!((EUCJP::to_utf8(b"").unwrap(), EUCJP::to_utf16(b"", true).unwrap(), EUCJP::from_utf8(b"").unwrap(), EUCJP::from_utf16(b"", true).unwrap()), empty());
+    assert_eq!((ISO2022JP::to_utf8(b"").unwrap(), ISO2022JP::to_utf16(b"", true).unwrap(), ISO2022JP::from_utf8(b"").unwrap(), ISO2022JP::from_utf16(b"", true).unwrap()), empty());
+}
+
+#[test]
+fn chinese_round_trip() {
+    check_whatwg("GB18030", encoding_rs::GB18030, (0_u32..0x10000_u32).chain((0x10000_u32..=0x10FFFF_u32).step_by(257_usize)), GB18030::from_utf8, GB18030::to_utf8);
+    check_whatwg("GBK", encoding_rs::GBK, 0_u32..0x10000_u32, GBK::from_utf8, GBK::to_utf8);
+    check_whatwg("Big5", encoding_rs::BIG5, 0_u32..0x30000_u32, BIG5::from_utf8, BIG5::to_utf8);
+
+    // Empty input is empty output, not an error.
+    assert_eq!((GB18030::to_utf8(b"").unwrap(), GB18030::to_utf16(b"", true).unwrap(), GB18030::from_utf8(b"").unwrap(), GB18030::from_utf16(b"", true).unwrap()), empty());
+    assert_eq!((GBK::to_utf8(b"").unwrap(), GBK::to_utf16(b"", true).unwrap(), GBK::from_utf8(b"").unwrap(), GBK::from_utf16(b"", true).unwrap()), empty());
+    assert_eq!((BIG5::to_utf8(b"").unwrap(), BIG5::to_utf16(b"", true).unwrap(), BIG5::from_utf8(b"").unwrap(), BIG5::from_utf16(b"", true).unwrap()), empty());
+
+    // The WHATWG GBK decoder is the GB18030 one, so four-byte sequences decode too; the validators are usable in const context.
+    const FOUR_BYTE: &[u8] = b"\x81\x30\x81\x30\xE0\x31\x93\x32\x90\x30\x81\x30";
+    const { assert!(GBK::is_gbk(FOUR_BYTE) && GB18030::is_gb18030(FOUR_BYTE) && !BIG5::is_big5(FOUR_BYTE)); }
+
+    let (expected, _) = encoding_rs::GBK.decode_without_bom_handling(FOUR_BYTE);
+
+    assert_eq!(GBK::to_utf8(FOUR_BYTE).unwrap(), expected.as_bytes());
+    assert_eq!(GB18030::to_utf8(FOUR_BYTE).unwrap(), expected.as_bytes());
+}
+
+#[test]
+fn korean_round_trip() {
+    check_whatwg("EUC-KR", encoding_rs::EUC_KR, 0_u32..0x10000_u32, |array| EUCKR::from_utf8(array, Policy::Error), |array| EUCKR::to_utf8(array, Policy::Error));
+
+    // Empty input is empty output, not an error.
+    assert_eq!((EUCKR::to_utf8(b"", Policy::Error).unwrap(), EUCKR::to_utf16(b"", true, Policy::Error).unwrap(), EUCKR::from_utf8(b"", Policy::Error).unwrap(), EUCKR::from_utf16(b"", true, Policy::Error).unwrap()), empty());
+}
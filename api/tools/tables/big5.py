@@ -0,0 +1,55 @@
+#!/usr/bin/env python3
+#
+# Generates src/functors/universal/codings/tables/big5.rs from the WHATWG big5
+# index:
+#
+#     https://encoding.spec.whatwg.org/index-big5.txt
+#
+# Pointers 1133, 1135, 1164 and 1166 decode to two code points and are left
+# as zero for the decoder to special-case. BIG5_ENCODE follows the WHATWG
+# encoder: HKSCS pointers below (0xA1 - 0x81) * 157 are skipped and the last
+# pointer is used for U+2550, U+255E, U+2561, U+256A, U+5341 and U+5345.
+#
+# Usage: python3 tools/tables/big5.py index-big5.txt
+
+import sys
+
+from common import array, read_index, write
+
+FUNCTIONS = """pub(crate) fn code_point(pointer: usize) -> u32 {
+    return if pointer < BIG5.len() { BIG5[pointer] } else { 0_u32 };
+}
+
+pub(crate) fn pointer(code: u32) -> Option<usize> {
+    return match BIG5_ENCODE.binary_search_by_key(&code, |&pointer| BIG5[pointer as usize]) {
+        Ok(index) => Some(BIG5_ENCODE[index] as usize),
+        Err(_) => None
+    };
+}
+"""
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit("usage: %s index-big5.txt" % sys.argv[0])
+
+    index = read_index(sys.argv[1])
+    for pointer in (1133, 1135, 1164, 1166):
+        index.pop(pointer, None)
+
+    encode = {}
+    for pointer in sorted(index):
+        if pointer < (0xA1 - 0x81) * 157:
+            continue
+        if index[pointer] in (0x2550, 0x255E, 0x2561, 0x256A, 0x5341, 0x5345):
+            encode[index[pointer]] = pointer
+        else:
+            encode.setdefault(index[pointer], pointer)
+
+    write("big5.rs", [
+        FUNCTIONS,
+        array("BIG5", "u32", ["0x%05X" % index.get(pointer, 0) for pointer in range(19782)], 12),
+        array("BIG5_ENCODE", "u16", ["0x%04X" % pointer for _, pointer in sorted(encode.items())], 16)
+    ])
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,63 @@
+#!/usr/bin/env python3
+#
+# Generates src/functors/universal/codings/tables/case_folding.rs from the
+# Unicode Character Database CaseFolding.txt:
+#
+#     https://www.unicode.org/Public/UCD/latest/ucd/CaseFolding.txt
+#
+# Only the C (common) and S (simple) status rows are used, which together give
+# the simple case folding. F (full) and T (Turkic) rows are ignored. ASCII rows
+# are skipped because fold() handles them without a table lookup.
+#
+# Usage: python3 tools/tables/case_folding.py CaseFolding.txt
+
+import os
+import sys
+
+HEADER = os.path.join(os.path.dirname(__file__), "header.txt")
+OUTPUT = os.path.join(os.path.dirname(__file__), "..", "..", "src", "functors", "universal", "codings", "tables", "case_folding.rs")
+
+def parse(path):
+    pairs = {}
+    with open(path, encoding="utf-8") as source:
+        for line in source:
+            line = line.split("#", 1)[0].strip()
+            if not line:
+                continue
+            fields = [field.strip() for field in line.split(";")]
+            code, status, mapping = int(fields[0], 16), fields[1], fields[2].split()
+            if status not in ("C", "S"):
+                continue
+            if len(mapping) != 1:
+                raise ValueError("simple folding of U+%04X maps to %d code points" % (code, len(mapping)))
+            if code in pairs:
+                raise ValueError("duplicate simple folding for U+%04X" % code)
+            pairs[code] = int(mapping[0], 16)
+    return sorted((code, folded) for code, folded in pairs.items() if code >= 0x80)
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit("usage: %s CaseFolding.txt" % sys.argv[0])
+
+    pairs = parse(sys.argv[1])
+    out = [open(HEADER, encoding="utf-8").read().rstrip("\n") + "\n\n"]
+    out.append(
+        "pub(crate) fn fold(code: u32) -> u32 {\n"
+        "    if code < 0x80 { return if (0x41..=0x5A).contains(&code) { code | 0x20 } else { code }; }\n\n"
+        "    return match SIMPLE_CASE_FOLDING.binary_search_by_key(&code, |&(from, _)| from) {\n"
+        "        Ok(index) => SIMPLE_CASE_FOLDING[index].1,\n"
+        "        Err(_) => code\n"
+        "    };\n"
+        "}\n\n"
+    )
+    out.append("pub(crate) static SIMPLE_CASE_FOLDING: [(u32, u32); %d_usize] = [\n" % len(pairs))
+    items = ["(0x%04X, 0x%04X)" % pair for pair in pairs]
+    for index in range(0, len(items), 8):
+        out.append("    " + ", ".join(items[index:index + 8]) + (",\n" if index + 8 < len(items) else "\n"))
+    out.append("];\n")
+
+    with open(OUTPUT, "w", encoding="utf-8") as target:
+        target.write("".join(out))
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,43 @@
+#
+# Shared helpers for the table generators in this directory.
+#
+# The WHATWG index files have one "pointer<TAB>code point<TAB>..." row per
+# mapping and "#" comment lines, see https://encoding.spec.whatwg.org/#indexes
+#
+
+import os
+
+HEADER = os.path.join(os.path.dirname(__file__), "header.txt")
+TABLES = os.path.join(os.path.dirname(__file__), "..", "..", "src", "functors", "universal", "codings", "tables")
+
+def read_index(path):
+    index = {}
+    with open(path, encoding="utf-8") as source:
+        for line in source:
+            if not line.strip() or line.startswith("#"):
+                continue
+            fields = line.split("\t")
+            pointer, code = int(fields[0].strip()), int(fields[1].strip(), 16)
+            if pointer in index:
+                raise ValueError("duplicate pointer %d in %s" % (pointer, path))
+            index[pointer] = code
+    return index
+
+def first_pointers(index, skip=lambda pointer: False):
+    pointers = {}
+    for pointer in sorted(index):
+        if not skip(pointer):
+            pointers.setdefault(index[pointer], pointer)
+    return pointers
+
+def array(name, kind, items, per_line, qualifier="static"):
+    out = ["pub(crate) %s %s: [%s; %d_usize] = [\n" % (qualifier, name, kind, len(items))]
+    for index in range(0, len(items), per_line):
+        out.append("    " + ", ".join(items[index:index + per_line]) + (",\n" if index + per_line < len(items) else "\n"))
+    out.append("];\n")
+    return "".join(out)
+
+def write(file_name, sections):
+    header = open(HEADER, encoding="utf-8").read().rstrip("\n")
+    with open(os.path.join(TABLES, file_name), "w", encoding="utf-8") as target:
+        target.write(header + "\n\n" + "\n".join(sections))
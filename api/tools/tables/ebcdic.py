@@ -0,0 +1,42 @@
+#!/usr/bin/env python3
+#
+# Generates src/functors/universal/codings/tables/ebcdic.rs from Python's
+# cp037, cp500 and cp1140 codecs (the IBM CDRA mappings). Python has no
+# cp1047, so IBM1047 is cp037 with the six code points that differ between
+# the two code pages. Like IBM037, it maps 0x15 to NEL and 0x25 to LF.
+#
+# The *_ENCODE tables are the inverse for U+0000 to U+00FF, code points the
+# code page lacks are left as zero.
+#
+# Usage: python3 tools/tables/ebcdic.py
+
+import sys
+
+from common import array, write
+
+IBM1047_FROM_IBM037 = {0x5F: 0x005E, 0xAD: 0x005B, 0xB0: 0x00AC, 0xBA: 0x00DD, 0xBB: 0x00A8, 0xBD: 0x005D}
+
+def decode(codec):
+    return [ord(bytes([byte]).decode(codec)) for byte in range(256)]
+
+def main():
+    if len(sys.argv) != 1:
+        sys.exit("usage: %s" % sys.argv[0])
+
+    ibm1047 = decode("cp037")
+    for byte, code in IBM1047_FROM_IBM037.items():
+        ibm1047[byte] = code
+
+    sections = []
+    for name, table in [("IBM037", decode("cp037")), ("IBM500", decode("cp500")), ("IBM1047", ibm1047), ("IBM1140", decode("cp1140"))]:
+        encode = [0] * 256
+        for byte, code in enumerate(table):
+            if code < 256:
+                encode[code] = byte
+        sections.append(array(name, "u16", ["0x%04X" % code for code in table], 16))
+        sections.append(array(name + "_ENCODE", "u8", ["0x%02X" % byte for byte in encode], 16))
+
+    write("ebcdic.rs", sections)
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,43 @@
+#!/usr/bin/env python3
+#
+# Generates src/functors/universal/codings/tables/euc_kr.rs from the WHATWG
+# euc-kr index:
+#
+#     https://encoding.spec.whatwg.org/index-euc-kr.txt
+#
+# EUC_KR_ENCODE lists the pointers sorted by code point.
+#
+# Usage: python3 tools/tables/euc_kr.py index-euc-kr.txt
+
+import sys
+
+from common import array, first_pointers, read_index, write
+
+FUNCTIONS = """pub(crate) const fn code_point(pointer: usize) -> u32 {
+    return if pointer < EUC_KR.len() { EUC_KR[pointer] as u32 } else { 0_u32 };
+}
+
+pub(crate) fn pointer(code: u32) -> Option<usize> {
+    if code > 0xFFFF { return None; }
+
+    return match EUC_KR_ENCODE.binary_search_by_key(&(code as u16), |&pointer| EUC_KR[pointer as usize]) {
+        Ok(index) => Some(EUC_KR_ENCODE[index] as usize),
+        Err(_) => None
+    };
+}
+"""
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit("usage: %s index-euc-kr.txt" % sys.argv[0])
+
+    index = read_index(sys.argv[1])
+
+    write("euc_kr.rs", [
+        FUNCTIONS,
+        array("EUC_KR", "u16", ["0x%04X" % index.get(pointer, 0) for pointer in range(23940)], 16),
+        array("EUC_KR_ENCODE", "u16", ["0x%04X" % pointer for _, pointer in sorted(first_pointers(index).items())], 16)
+    ])
+
+if __name__ == "__main__":
+    main()
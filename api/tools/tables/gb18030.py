@@ -0,0 +1,84 @@
+#!/usr/bin/env python3
+#
+# Generates src/functors/universal/codings/tables/gb18030.rs from the WHATWG
+# gb18030 and gb18030-ranges indexes:
+#
+#     https://encoding.spec.whatwg.org/index-gb18030.txt
+#     https://encoding.spec.whatwg.org/index-gb18030-ranges.txt
+#
+# GB18030_ENCODE lists the pointers sorted by code point, keeping the first
+# pointer of duplicated code points. Range rows at pointer 189000 and above
+# are left out, ranges_code_point() and ranges_pointer() compute them. The
+# PUA rows keep encoding the code points GB18030-2005 assigned to pointers
+# whose index entries moved out of the Private Use Area.
+#
+# Usage: python3 tools/tables/gb18030.py index-gb18030.txt index-gb18030-ranges.txt
+
+import sys
+
+from common import array, first_pointers, read_index, write
+
+PUA = [
+    (0xE78D, 7182), (0xE78E, 7183), (0xE78F, 7184), (0xE790, 7185), (0xE791, 7186), (0xE792, 7187),
+    (0xE793, 7188), (0xE794, 7201), (0xE795, 7202), (0xE796, 7208), (0xE81E, 23775), (0xE826, 23783),
+    (0xE82B, 23788), (0xE82C, 23789), (0xE832, 23795), (0xE843, 23812), (0xE854, 23829), (0xE864, 23845)
+]
+
+FUNCTIONS = """pub(crate) fn code_point(pointer: usize) -> u32 {
+    return if pointer < GB18030.len() { GB18030[pointer] as u32 } else { 0_u32 };
+}
+
+pub(crate) fn pointer(code: u32) -> Option<usize> {
+    if code > 0xFFFF { return None; }
+
+    if (0xE78D..=0xE864).contains(&code) {
+        if let Ok(index) = GB18030_PUA_ENCODE.binary_search_by_key(&(code as u16), |&(pua, _)| pua) {
+            return Some(GB18030_PUA_ENCODE[index].1 as usize);
+        }
+    }
+
+    return match GB18030_ENCODE.binary_search_by_key(&(code as u16), |&pointer| GB18030[pointer as usize]) {
+        Ok(index) => Some(GB18030_ENCODE[index] as usize),
+        Err(_) => None
+    };
+}
+
+pub(crate) fn ranges_code_point(pointer: usize) -> u32 {
+    if pointer == 7457_usize { return 0xE7C7; }
+
+    if (189000_usize..=1237575_usize).contains(&pointer) { return 0x10000 + (pointer - 189000_usize) as u32; }
+
+    if pointer > 39419_usize { return 0_u32; }
+
+    let index: usize = GB18030_RANGES.partition_point(|&(start, _)| start as usize <= pointer) - 1_usize;
+
+    return GB18030_RANGES[index].1 + (pointer - GB18030_RANGES[index].0 as usize) as u32;
+}
+
+pub(crate) fn ranges_pointer(code: u32) -> usize {
+    if code == 0xE7C7 { return 7457_usize; }
+
+    if code >= 0x10000 { return 189000_usize + (code - 0x10000) as usize; }
+
+    let index: usize = GB18030_RANGES.partition_point(|&(_, start)| start <= code) - 1_usize;
+
+    return GB18030_RANGES[index].0 as usize + (code - GB18030_RANGES[index].1) as usize;
+}
+"""
+
+def main():
+    if len(sys.argv) != 3:
+        sys.exit("usage: %s index-gb18030.txt index-gb18030-ranges.txt" % sys.argv[0])
+
+    index, ranges = read_index(sys.argv[1]), read_index(sys.argv[2])
+
+    write("gb18030.rs", [
+        FUNCTIONS,
+        array("GB18030", "u16", ["0x%04X" % index.get(pointer, 0) for pointer in range(23940)], 16),
+        array("GB18030_ENCODE", "u16", ["0x%04X" % pointer for _, pointer in sorted(first_pointers(index).items())], 16),
+        array("GB18030_PUA_ENCODE", "(u16, u16)", ["(0x%04X, %d)" % pair for pair in PUA], 8, "const"),
+        array("GB18030_RANGES", "(u32, u32)", ["(%d, 0x%04X)" % pair for pair in sorted(ranges.items()) if pair[0] < 189000], 8, "const")
+    ])
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,63 @@
+#!/usr/bin/env python3
+#
+# Generates src/functors/universal/codings/tables/jis0208.rs from the WHATWG
+# jis0208 and ISO-2022-JP katakana indexes:
+#
+#     https://encoding.spec.whatwg.org/index-jis0208.txt
+#     https://encoding.spec.whatwg.org/index-iso-2022-jp-katakana.txt
+#
+# JIS0208_ENCODE holds the first pointer of each code point (the index
+# pointer), JIS0208_SHIFT_JIS_ENCODE only the code points whose Shift_JIS
+# pointer differs from it, because Shift_JIS skips pointers 8272 to 8835.
+#
+# Usage: python3 tools/tables/jis0208.py index-jis0208.txt index-iso-2022-jp-katakana.txt
+
+import sys
+
+from common import array, first_pointers, read_index, write
+
+FUNCTIONS = """pub(crate) const fn code_point(pointer: usize) -> u32 {
+    return if pointer < JIS0208.len() { JIS0208[pointer] as u32 } else { 0_u32 };
+}
+
+pub(crate) const fn katakana(code: u32) -> u32 {
+    return KATAKANA[(code - 0xFF61) as usize] as u32;
+}
+
+pub(crate) fn pointer(code: u32) -> Option<usize> {
+    if code > 0xFFFF { return None; }
+
+    return match JIS0208_ENCODE.binary_search_by_key(&(code as u16), |&(unit, _)| unit) {
+        Ok(index) => Some(JIS0208_ENCODE[index].1 as usize),
+        Err(_) => None
+    };
+}
+
+pub(crate) fn shift_jis_pointer(code: u32) -> Option<usize> {
+    if code > 0xFFFF { return None; }
+
+    return match JIS0208_SHIFT_JIS_ENCODE.binary_search_by_key(&(code as u16), |&(unit, _)| unit) {
+        Ok(index) => Some(JIS0208_SHIFT_JIS_ENCODE[index].1 as usize),
+        Err(_) => pointer(code)
+    };
+}
+"""
+
+def main():
+    if len(sys.argv) != 3:
+        sys.exit("usage: %s index-jis0208.txt index-iso-2022-jp-katakana.txt" % sys.argv[0])
+
+    index, katakana = read_index(sys.argv[1]), read_index(sys.argv[2])
+    encode = first_pointers(index)
+    shift_jis = first_pointers(index, lambda pointer: 8272 <= pointer <= 8835)
+
+    write("jis0208.rs", [
+        FUNCTIONS,
+        array("JIS0208", "u16", ["0x%04X" % index.get(pointer, 0) for pointer in range(11280)], 16),
+        array("JIS0208_ENCODE", "(u16, u16)", ["(0x%04X, 0x%04X)" % pair for pair in sorted(encode.items())], 8),
+        array("JIS0208_SHIFT_JIS_ENCODE", "(u16, u16)", ["(0x%04X, 0x%04X)" % pair for pair in sorted(shift_jis.items()) if encode[pair[0]] != pair[1]], 8, "const"),
+        array("KATAKANA", "u16", ["0x%04X" % katakana[pointer] for pointer in range(63)], 16, "const")
+    ])
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,31 @@
+#!/usr/bin/env python3
+#
+# Generates src/functors/universal/codings/tables/jis0212.rs from the WHATWG
+# jis0212 index, which is decode-only:
+#
+#     https://encoding.spec.whatwg.org/index-jis0212.txt
+#
+# Usage: python3 tools/tables/jis0212.py index-jis0212.txt
+
+import sys
+
+from common import array, read_index, write
+
+FUNCTIONS = """pub(crate) const fn code_point(pointer: usize) -> u32 {
+    return if pointer < JIS0212.len() { JIS0212[pointer] as u32 } else { 0_u32 };
+}
+"""
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit("usage: %s index-jis0212.txt" % sys.argv[0])
+
+    index = read_index(sys.argv[1])
+
+    write("jis0212.rs", [
+        FUNCTIONS,
+        array("JIS0212", "u16", ["0x%04X" % index.get(pointer, 0) for pointer in range(8836)], 16)
+    ])
+
+if __name__ == "__main__":
+    main()
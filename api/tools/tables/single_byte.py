@@ -0,0 +1,57 @@
+#!/usr/bin/env python3
+#
+# Generates src/functors/universal/codings/tables/single_byte.rs from the
+# WHATWG single-byte indexes:
+#
+#     https://encoding.spec.whatwg.org/index-<name>.txt
+#
+# WHATWG maps the ISO-8859-9 and ISO-8859-11 labels to windows-1254 and
+# windows-874, so those two tables come from Python's iso8859_9 and iso8859_11
+# codecs (the Unicode ISO/IEC 8859 mappings) instead, keeping the C1 controls.
+#
+# Usage: python3 tools/tables/single_byte.py <directory with index-*.txt>
+
+import os
+import sys
+
+from common import array, read_index, write
+
+TABLES = [
+    ("WINDOWS_1250", "windows-1250"), ("WINDOWS_1251", "windows-1251"), ("WINDOWS_1252", "windows-1252"),
+    ("WINDOWS_1253", "windows-1253"), ("WINDOWS_1254", "windows-1254"), ("WINDOWS_1255", "windows-1255"),
+    ("WINDOWS_1256", "windows-1256"), ("WINDOWS_1257", "windows-1257"), ("WINDOWS_1258", "windows-1258"),
+    ("ISO_8859_2", "iso-8859-2"), ("ISO_8859_3", "iso-8859-3"), ("ISO_8859_4", "iso-8859-4"),
+    ("ISO_8859_5", "iso-8859-5"), ("ISO_8859_6", "iso-8859-6"), ("ISO_8859_7", "iso-8859-7"),
+    ("ISO_8859_8", "iso-8859-8"), ("ISO_8859_9", None), ("ISO_8859_10", "iso-8859-10"),
+    ("ISO_8859_11", None), ("ISO_8859_13", "iso-8859-13"), ("ISO_8859_14", "iso-8859-14"),
+    ("ISO_8859_15", "iso-8859-15"), ("ISO_8859_16", "iso-8859-16"), ("KOI8_R", "koi8-r"),
+    ("KOI8_U", "koi8-u"), ("IBM866", "ibm866")
+]
+
+def codec_index(codec):
+    index = {}
+    for pointer in range(128):
+        try:
+            index[pointer] = ord(bytes([0x80 + pointer]).decode(codec))
+        except UnicodeDecodeError:
+            pass
+    return index
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit("usage: %s <directory with index-*.txt>" % sys.argv[0])
+
+    sections = []
+    for name, label in TABLES:
+        if label is None:
+            index = codec_index(name.lower().replace("iso_", "iso"))
+        else:
+            index = read_index(os.path.join(sys.argv[1], "index-%s.txt" % label))
+        sections.append(array(name, "u16", ["0x%04X" % index.get(pointer, 0) for pointer in range(128)], 8, "const"))
+        pairs = sorted((code, 0x80 + pointer) for pointer, code in index.items())
+        sections.append(array(name + "_ENCODE", "(u16, u8)", ["(0x%04X, 0x%02X)" % pair for pair in pairs], 6, "const"))
+
+    write("single_byte.rs", sections)
+
+if __name__ == "__main__":
+    main()